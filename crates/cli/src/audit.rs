@@ -0,0 +1,66 @@
+//! `oatty audit` subcommands.
+
+use anyhow::Result;
+use chrono::{SecondsFormat, Utc};
+use clap::ArgMatches;
+use oatty_mcp::PluginEngine;
+use oatty_mcp::logging::{AuditEntry, AuditQuery, parse_audit_since};
+
+/// Print audit entries matching `--since`, `--catalog`, and `--status`, oldest first.
+pub fn query_audit_log(plugin_engine: &PluginEngine, json_output: bool, matches: &ArgMatches) -> Result<()> {
+    let query = AuditQuery {
+        since: matches
+            .get_one::<String>("since")
+            .map(|since| parse_audit_since(since, Utc::now()))
+            .transpose()?,
+        catalog: matches.get_one::<String>("catalog").cloned(),
+        status: matches.get_one::<String>("status").map(|status| status.parse()).transpose()?,
+        limit: matches.get_one::<usize>("limit").copied(),
+    };
+    let logger = plugin_engine.log_manager().audit_logger();
+    let entries = logger.query(&query)?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+    if entries.is_empty() {
+        println!("No audit entries match ({}).", logger.log_path().display());
+        return Ok(());
+    }
+    for entry in &entries {
+        println!("{}", format_audit_entry(entry));
+    }
+    Ok(())
+}
+
+fn format_audit_entry(entry: &AuditEntry) -> String {
+    let invocation = &entry.invocation;
+    let initiator = invocation
+        .initiator
+        .map(|initiator| format!("{initiator:?}").to_lowercase())
+        .unwrap_or_else(|| "-".to_string());
+    let status = invocation
+        .status
+        .map(|status| status.to_string())
+        .unwrap_or_else(|| "-".to_string());
+    let duration = invocation
+        .duration_ms
+        .map(|duration| format!("{duration}ms"))
+        .unwrap_or_else(|| "-".to_string());
+    let mut line = format!(
+        "{}  {:<9} {:<16} {:<20} {:<8} {:>4} {:>8}  {}",
+        entry.timestamp.to_rfc3339_opts(SecondsFormat::Secs, true),
+        initiator,
+        entry.plugin_name,
+        format!("{:?}", entry.action),
+        format!("{:?}", entry.result),
+        status,
+        duration,
+        entry.subject(),
+    );
+    if let Some(client) = invocation.client.as_deref() {
+        line.push_str(&format!("  [{client}]"));
+    }
+    line
+}
@@ -0,0 +1,207 @@
+//! `oatty diff`: run one command against two targets and compare the results.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result, anyhow, bail};
+use clap::ArgMatches;
+use oatty_mcp::PluginEngine;
+use oatty_registry::{CommandRegistry, build_clap};
+use oatty_util::{JsonChange, JsonDiffOptions, diff_json_with_options, find_alignment_key, id_like_keys};
+use serde_json::{Value, json};
+
+use crate::{
+    CommandInvocation, collect_positional_values, collect_request_body, execute_command_once, extract_command_and_matches,
+    extract_group_and_matches, resolve_command_context,
+};
+
+/// Execute the command against the left and right targets and print the structural diff.
+pub async fn run_diff(
+    registry: Arc<Mutex<CommandRegistry>>,
+    plugin_engine: &PluginEngine,
+    json_output: bool,
+    matches: &ArgMatches,
+) -> Result<()> {
+    let command_line: Vec<String> = matches.get_many::<String>("command").into_iter().flatten().cloned().collect();
+    let command_matches = build_clap(Arc::clone(&registry))
+        .try_get_matches_from(std::iter::once("oatty".to_string()).chain(command_line.iter().cloned()))
+        .with_context(|| format!("invalid command '{}'", command_line.join(" ")))?;
+    let (group, group_matches) = extract_group_and_matches(&command_matches)?;
+    let (command_name, command_matches) = extract_command_and_matches(group_matches)?;
+
+    let (command_spec, selected_base_url, headers) = resolve_command_context(&registry, group, command_name)?;
+    let positional_values = collect_positional_values(&command_spec, command_matches);
+    let request_body = collect_request_body(&command_spec, command_matches)?;
+    if !command_spec.http().is_some_and(|http| http.method.eq_ignore_ascii_case("GET")) {
+        bail!(
+            "diff only runs read-only HTTP GET commands; '{}' is not one",
+            command_spec.canonical_id()
+        );
+    }
+    let left_target = matches.get_one::<String>("left");
+    let right_target = matches.get_one::<String>("right");
+    let base_urls = catalog_base_urls(&registry, command_spec.catalog_identifier);
+    let left_base_url = resolve_target(left_target, &selected_base_url, &base_urls)?;
+    let right_base_url = resolve_target(right_target, &selected_base_url, &base_urls)?;
+
+    let mut results = Vec::with_capacity(2);
+    for base_url in [&left_base_url, &right_base_url] {
+        let invocation = CommandInvocation {
+            registry: &registry,
+            plugin_engine,
+            command_spec: &command_spec,
+            base_url,
+            headers: &headers,
+            positional_values: &positional_values,
+            request_body: &request_body,
+        };
+        let response = execute_command_once(&invocation).await?;
+        response.ensure_success()?;
+        results.push(response);
+    }
+    let (left, right) = (&results[0].payload, &results[1].payload);
+
+    let array_key = matches
+        .get_one::<String>("key")
+        .cloned()
+        .or_else(|| default_alignment_key(left, right));
+    let changes = diff_json_with_options(
+        left,
+        right,
+        &JsonDiffOptions {
+            array_key: array_key.clone(),
+        },
+    );
+
+    if json_output {
+        let output = json!({
+            "left": {"target": left_base_url, "status": results[0].heading},
+            "right": {"target": right_base_url, "status": results[1].heading},
+            "key": array_key,
+            "changes": changes.iter().map(JsonChange::to_json).collect::<Vec<Value>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!(
+            "{}",
+            format_diff_report(
+                (&left_base_url, &results[0].heading),
+                (&right_base_url, &results[1].heading),
+                array_key.as_deref(),
+                &changes
+            )
+        );
+    }
+    Ok(())
+}
+
+fn catalog_base_urls(registry: &Arc<Mutex<CommandRegistry>>, catalog_identifier: usize) -> Vec<String> {
+    registry
+        .lock()
+        .ok()
+        .and_then(|registry| {
+            registry
+                .config
+                .catalogs
+                .as_ref()
+                .and_then(|catalogs| catalogs.get(catalog_identifier))
+                .map(|catalog| catalog.base_urls.clone())
+        })
+        .unwrap_or_default()
+}
+
+/// A target is a base URL or an index into the catalog's base URLs; absent means the selected one.
+///
+/// URLs must be one of the catalog's base URLs, since the catalog's auth headers are sent to the target.
+fn resolve_target(target: Option<&String>, selected_base_url: &str, base_urls: &[String]) -> Result<String> {
+    let Some(target) = target.map(|target| target.trim()) else {
+        return Ok(selected_base_url.to_string());
+    };
+    if let Ok(index) = target.parse::<usize>() {
+        return base_urls.get(index).cloned().ok_or_else(|| {
+            anyhow!(
+                "base URL index {index} is out of range; the catalog defines {} base URL(s)",
+                base_urls.len()
+            )
+        });
+    }
+    if target.starts_with("http://") || target.starts_with("https://") {
+        let target = target.trim_end_matches('/');
+        return base_urls
+            .iter()
+            .map(|base_url| base_url.trim_end_matches('/'))
+            .find(|base_url| base_url.eq_ignore_ascii_case(target))
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("base URL '{target}' is not one of the catalog's base URLs"));
+    }
+    bail!("invalid target '{target}': expected a base URL or a base URL index")
+}
+
+fn default_alignment_key(left: &Value, right: &Value) -> Option<String> {
+    let first_item = [left, right]
+        .into_iter()
+        .filter_map(Value::as_array)
+        .flatten()
+        .find_map(Value::as_object)?;
+    find_alignment_key(left, right, id_like_keys(first_item))
+}
+
+fn format_diff_report(left: (&str, &str), right: (&str, &str), array_key: Option<&str>, changes: &[JsonChange]) -> String {
+    let mut lines = vec![
+        format!("--- left:  {} ({})", left.0, left.1),
+        format!("+++ right: {} ({})", right.0, right.1),
+    ];
+    if let Some(key) = array_key {
+        lines.push(format!("arrays aligned by: {key}"));
+    }
+    if changes.is_empty() {
+        lines.push("No differences.".to_string());
+        return lines.join("\n");
+    }
+    lines.extend(changes.iter().map(ToString::to_string));
+    lines.push(format!("{} change(s)", changes.len()));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oatty_util::diff_json;
+
+    #[test]
+    fn resolve_target_accepts_urls_and_indices() {
+        let base_urls = vec!["https://staging.example.com".to_string(), "https://api.example.com".to_string()];
+        let selected = "https://api.example.com";
+        assert_eq!(resolve_target(None, selected, &base_urls).unwrap(), selected);
+        assert_eq!(
+            resolve_target(Some(&"0".to_string()), selected, &base_urls).unwrap(),
+            "https://staging.example.com"
+        );
+        assert_eq!(
+            resolve_target(Some(&"https://staging.example.com/".to_string()), selected, &base_urls).unwrap(),
+            "https://staging.example.com"
+        );
+        assert!(resolve_target(Some(&"https://other.example.com".to_string()), selected, &base_urls).is_err());
+        assert!(resolve_target(Some(&"5".to_string()), selected, &base_urls).is_err());
+        assert!(resolve_target(Some(&"prod".to_string()), selected, &base_urls).is_err());
+    }
+
+    #[test]
+    fn default_alignment_key_uses_id_like_fields() {
+        let left = json!([{"id": "a", "name": "one"}]);
+        let right = json!([{"id": "b", "name": "one"}]);
+        assert_eq!(default_alignment_key(&left, &right), Some("id".to_string()));
+        assert_eq!(default_alignment_key(&json!({"id": 1}), &json!({"id": 2})), None);
+    }
+
+    #[test]
+    fn diff_report_lists_changes_and_summary() {
+        let changes = diff_json(&json!({"plan": "basic"}), &json!({"plan": "pro"}));
+        let report = format_diff_report(("https://a", "200 OK"), ("https://b", "200 OK"), None, &changes);
+        assert_eq!(
+            report,
+            "--- left:  https://a (200 OK)\n+++ right: https://b (200 OK)\n~ .plan: \"basic\" -> \"pro\"\n1 change(s)"
+        );
+        let empty = format_diff_report(("https://a", "200 OK"), ("https://a", "200 OK"), Some("id"), &[]);
+        assert!(empty.ends_with("arrays aligned by: id\nNo differences."));
+    }
+}
@@ -1,3 +1,9 @@
+mod audit;
+mod diff;
+mod mcp_tokens;
+mod scheduler;
+mod watch;
+
 use std::{
     collections::HashMap,
     fs,
@@ -10,10 +16,14 @@ use clap::ArgMatches;
 use indexmap::IndexSet;
 use oatty_api::OattyClient;
 use oatty_engine::workflow::document::{build_runtime_catalog, runtime_workflow_from_definition};
+use oatty_engine::workflow::graph::WorkflowGraph;
+use oatty_engine::workflow::lint::{WorkflowLintReport, lint_workflow_definition, to_sarif};
+use oatty_engine::workflow::testing::{WorkflowTestReport, WorkflowTestSuite, run_workflow_test_suite};
 use oatty_engine::{
     ProviderBindingOutcome, ProviderResolutionEvent, ProviderResolutionSource, RegistryCommandRunner, StepResult, StepStatus,
     WorkflowRunState,
 };
+use oatty_mcp::logging::{AuditEntry, AuditInitiator, AuditInvocation, AuditLogger, AuditResult, AuditTrail, AuditedCommandRunner};
 use oatty_mcp::{PluginEngine, config::load_config};
 use oatty_registry::workflows::load_workflows_from_directory;
 use oatty_registry::{
@@ -23,7 +33,7 @@ use oatty_registry::{
 use oatty_types::{
     EnvVar, ExecOutcome, RuntimeWorkflow,
     command::{CommandExecution, CommandFlag, CommandSpec},
-    workflow::{WorkflowCatalogRequirementSourceType, WorkflowDefinition, collect_missing_catalog_requirements, validate_candidate_value},
+    workflow::{WorkflowCatalogRequirementSourceType, WorkflowDefinition, collect_missing_catalog_requirements},
 };
 use oatty_util::{
     DEFAULT_HISTORY_PROFILE, HistoryKey, HistoryStore, InMemoryHistoryStore, JsonHistoryStore, build_path, has_meaningful_value,
@@ -33,7 +43,7 @@ use reqwest::{Method, Url};
 use serde_json::{Map, Number, Value, json};
 use std::io::{self, Write};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::time::timeout;
 use tracing::warn;
 use tracing_subscriber::fmt;
@@ -184,15 +194,16 @@ fn resolve_runtime_workflow(registry: Arc<Mutex<CommandRegistry>>, matches: &Arg
 }
 
 fn load_runtime_workflow_from_file(path: &Path) -> Result<RuntimeWorkflow> {
+    runtime_workflow_from_definition(&load_workflow_definition_from_file(path)?)
+}
+
+fn load_workflow_definition_from_file(path: &Path) -> Result<WorkflowDefinition> {
     let content = fs::read_to_string(path).with_context(|| format!("read workflow {}", path.display()))?;
-    let definition: WorkflowDefinition = if matches!(path.extension().and_then(|ext| ext.to_str()), Some(ext) if ext.eq_ignore_ascii_case("json"))
-    {
-        serde_json::from_str(&content).with_context(|| format!("parse workflow json {}", path.display()))?
+    if matches!(path.extension().and_then(|ext| ext.to_str()), Some(ext) if ext.eq_ignore_ascii_case("json")) {
+        serde_json::from_str(&content).with_context(|| format!("parse workflow json {}", path.display()))
     } else {
-        serde_yaml::from_str(&content).with_context(|| format!("parse workflow yaml {}", path.display()))?
-    };
-
-    runtime_workflow_from_definition(&definition)
+        serde_yaml::from_str(&content).with_context(|| format!("parse workflow yaml {}", path.display()))
+    }
 }
 
 fn output_workflow_json(state: &WorkflowRunState, results: &[StepResult]) -> Result<()> {
@@ -310,9 +321,7 @@ fn seed_history_defaults_for_cli(state: &mut WorkflowRunState, store: &dyn Histo
                 if stored.value.is_null() || value_contains_secret(&stored.value) {
                     continue;
                 }
-                if let Some(validation) = &definition.validate
-                    && let Err(error) = validate_candidate_value(&stored.value, validation)
-                {
+                if let Err(error) = definition.validate_value(&stored.value) {
                     warn!(
                         input = %input_name,
                         workflow = %state.workflow.identifier,
@@ -350,9 +359,7 @@ fn persist_history_after_cli_run(state: &WorkflowRunState, store: &dyn HistorySt
             continue;
         }
 
-        if let Some(validation) = &definition.validate
-            && let Err(error) = validate_candidate_value(value, validation)
-        {
+        if let Err(error) = definition.validate_value(value) {
             warn!(
                 input = %input_name,
                 workflow = %state.workflow.identifier,
@@ -453,6 +460,31 @@ async fn run_command(registry: Arc<Mutex<CommandRegistry>>, matches: &ArgMatches
         let (subcommand, sub_matches) = extract_command_and_matches(group_matches)?;
         return handle_workflow_command(Arc::clone(&registry), matches, subcommand, sub_matches);
     }
+    if group == "scheduler" {
+        let (subcommand, sub_matches) = extract_command_and_matches(group_matches)?;
+        return match subcommand {
+            "run" => scheduler::run_scheduler(Arc::clone(&registry), sub_matches).await,
+            other => bail!("Unsupported scheduler subcommand: {other}"),
+        };
+    }
+    if group == "audit" {
+        let (subcommand, sub_matches) = extract_command_and_matches(group_matches)?;
+        return match subcommand {
+            "query" => audit::query_audit_log(&plugin_engine, matches.get_flag("json"), sub_matches),
+            other => bail!("Unsupported audit subcommand: {other}"),
+        };
+    }
+    if group == "mcp" {
+        let (subcommand, sub_matches) = extract_command_and_matches(group_matches)?;
+        if subcommand != "token" {
+            bail!("Unsupported mcp subcommand: {subcommand}");
+        }
+        let (action, action_matches) = extract_command_and_matches(sub_matches)?;
+        return mcp_tokens::run_token_command(action, action_matches, matches.get_flag("json"));
+    }
+    if group == "diff" {
+        return diff::run_diff(Arc::clone(&registry), &plugin_engine, matches.get_flag("json"), group_matches).await;
+    }
     if group == "import" {
         return handle_import_command(Arc::clone(&registry), matches, group_matches).await;
     }
@@ -464,48 +496,160 @@ async fn run_command(registry: Arc<Mutex<CommandRegistry>>, matches: &ArgMatches
     let (command_spec, base_url, headers) = resolve_command_context(&registry, group, command_name)?;
     let positional_values = collect_positional_values(&command_spec, command_matches);
     let request_body = collect_request_body(&command_spec, command_matches)?;
-    let body_value = (!request_body.is_empty()).then_some(Value::Object(request_body.clone()));
     let json_output = matches.get_flag("json");
+    let invocation = CommandInvocation {
+        registry: &registry,
+        plugin_engine: &plugin_engine,
+        command_spec: &command_spec,
+        base_url: &base_url,
+        headers: &headers,
+        positional_values: &positional_values,
+        request_body: &request_body,
+    };
+
+    if let Some(options) = watch::watch_options(command_matches)? {
+        return watch::watch_command(&invocation, &options, json_output).await;
+    }
+    let response = execute_command_once(&invocation).await?;
+    print_command_response(&response, json_output)
+}
+
+/// Everything needed to execute a resolved catalog command, possibly more than once.
+struct CommandInvocation<'a> {
+    registry: &'a Arc<Mutex<CommandRegistry>>,
+    plugin_engine: &'a PluginEngine,
+    command_spec: &'a CommandSpec,
+    base_url: &'a str,
+    headers: &'a IndexSet<EnvVar>,
+    positional_values: &'a HashMap<String, String>,
+    request_body: &'a Map<String, Value>,
+}
+
+/// Result of a single command execution.
+struct CommandResponse {
+    /// HTTP status line, or the MCP log entry.
+    heading: String,
+    /// Raw response body for HTTP commands.
+    body: Option<String>,
+    /// Parsed payload used by watch mode for diffing and `--until`.
+    payload: Value,
+    /// Error description for HTTP responses with a 4xx or 5xx status.
+    failure: Option<String>,
+}
+
+impl CommandResponse {
+    /// Fail when the response carried a 4xx or 5xx status, for callers that compare payloads.
+    fn ensure_success(&self) -> Result<()> {
+        match &self.failure {
+            Some(failure) => bail!("{failure}"),
+            None => Ok(()),
+        }
+    }
+}
 
+/// Execute the command once, auditing the attempt.
+async fn execute_command_once(invocation: &CommandInvocation<'_>) -> Result<CommandResponse> {
+    let command_spec = invocation.command_spec;
     match command_spec.execution() {
         CommandExecution::Http(http) => {
-            let client = OattyClient::new(&base_url, &headers)?;
+            let client = OattyClient::new(invocation.base_url, invocation.headers)?;
             let method = Method::from_bytes(http.method.as_bytes())?;
-            let path = build_request_path(&http.path, &positional_values);
+            let path = build_request_path(&http.path, invocation.positional_values);
             let mut builder = client.request(method, &path);
-            if let Some(ref b) = body_value {
-                builder = builder.json(b);
+            if !invocation.request_body.is_empty() {
+                builder = builder.json(&Value::Object(invocation.request_body.clone()));
             }
 
-            let resp = builder.send().await?;
+            let started_at = Instant::now();
+            let response = builder.send().await;
+            let audit_invocation = AuditInvocation {
+                path: Some(path.clone()),
+                status: response.as_ref().ok().map(|resp| resp.status().as_u16()),
+                ..command_audit_invocation(invocation.registry, command_spec)
+            }
+            .with_duration(started_at.elapsed());
+            audit_command_execution(invocation.plugin_engine, audit_invocation, response.is_ok()).await;
+
+            let resp = response?;
             let status = resp.status();
             let text = resp.text().await.unwrap_or_default();
-            if json_output {
-                output_json_or_text(&text)?;
-            } else {
-                println!("{}\n{}", status, text);
-            }
-            Ok(())
+            let failure = (status.is_client_error() || status.is_server_error())
+                .then(|| format!("{} {} failed with {}\n{}", http.method.to_ascii_uppercase(), path, status, text));
+            let payload = serde_json::from_str(&text).unwrap_or_else(|_| Value::String(text.clone()));
+            Ok(CommandResponse {
+                heading: status.to_string(),
+                body: Some(text),
+                payload,
+                failure,
+            })
         }
         CommandExecution::Mcp(_) => {
-            let mut arguments = request_body;
+            let mut arguments = invocation.request_body.clone();
             for positional_argument in &command_spec.positional_args {
-                if let Some(value) = positional_values.get(&positional_argument.name) {
+                if let Some(value) = invocation.positional_values.get(&positional_argument.name) {
                     arguments.insert(positional_argument.name.clone(), Value::String(value.clone()));
                 }
             }
 
-            let outcome = plugin_engine.execute_tool(&command_spec, &arguments, 0).await?;
-            match outcome {
-                ExecOutcome::Mcp { log_entry, .. } => println!("{}", log_entry),
-                ExecOutcome::Log(log) => println!("{}", log),
-                other => println!("{:?}", other),
-            }
-            Ok(())
+            let started_at = Instant::now();
+            let outcome = invocation.plugin_engine.execute_tool(command_spec, &arguments, 0).await;
+            let audit_invocation = command_audit_invocation(invocation.registry, command_spec).with_duration(started_at.elapsed());
+            audit_command_execution(invocation.plugin_engine, audit_invocation, outcome.is_ok()).await;
+            let (heading, payload) = match outcome? {
+                ExecOutcome::Mcp { log_entry, payload, .. } => (log_entry, payload),
+                ExecOutcome::Log(log) => (log, Value::Null),
+                other => (format!("{:?}", other), Value::Null),
+            };
+            Ok(CommandResponse {
+                heading,
+                body: None,
+                payload,
+                failure: None,
+            })
         }
     }
 }
 
+/// Print a command response the way a single (non-watch) run does.
+fn print_command_response(response: &CommandResponse, json_output: bool) -> Result<()> {
+    match &response.body {
+        Some(text) if json_output => output_json_or_text(text)?,
+        Some(text) => println!("{}\n{}", response.heading, text),
+        None => println!("{}", response.heading),
+    }
+    Ok(())
+}
+
+/// Audit fields known before a command runs: initiator, canonical id, catalog, and method.
+fn command_audit_invocation(registry: &Arc<Mutex<CommandRegistry>>, command_spec: &CommandSpec) -> AuditInvocation {
+    let catalog = registry.lock().ok().and_then(|registry| {
+        registry
+            .config
+            .catalogs
+            .as_ref()
+            .and_then(|catalogs| catalogs.get(command_spec.catalog_identifier))
+            .map(|catalog| catalog.title.clone())
+    });
+    AuditInvocation {
+        canonical_id: Some(command_spec.canonical_id()),
+        catalog: catalog.or_else(|| command_spec.mcp().map(|mcp| mcp.plugin_name.clone())),
+        method: command_spec.http().map(|http| http.method.to_ascii_uppercase()),
+        ..AuditInvocation::new(AuditInitiator::Human)
+    }
+}
+
+async fn audit_command_execution(plugin_engine: &PluginEngine, invocation: AuditInvocation, completed: bool) {
+    let result = match invocation.status {
+        Some(status) => AuditResult::from_http_status(status),
+        None if completed => AuditResult::Success,
+        None => AuditResult::Failure,
+    };
+    let entry = AuditEntry::command_execution("cli".to_string(), invocation, result);
+    if let Err(error) = plugin_engine.log_manager().log_audit(entry).await {
+        warn!(error = %error, "failed to write command audit entry");
+    }
+}
+
 /// Extract the CLI group and its matches from the parsed arguments.
 fn extract_group_and_matches(matches: &ArgMatches) -> Result<(&str, &ArgMatches)> {
     matches.subcommand().context("expected a resource group subcommand")
@@ -1040,6 +1184,9 @@ fn handle_workflow_command(
         "list" => list_workflows(registry, json_output),
         "preview" => preview_workflow(registry, json_output, sub_matches),
         "run" => run_workflow(registry, json_output, sub_matches),
+        "test" => test_workflow(registry, json_output, sub_matches),
+        "lint" => lint_workflows(registry, json_output, sub_matches),
+        "graph" => graph_workflow(registry, json_output, sub_matches),
         other => bail!("Unsupported workflow subcommand: {other}"),
     }
 }
@@ -1109,6 +1256,26 @@ fn preview_workflow(registry: Arc<Mutex<CommandRegistry>>, json_output: bool, ma
     Ok(())
 }
 
+fn graph_workflow(registry: Arc<Mutex<CommandRegistry>>, json_output: bool, matches: &ArgMatches) -> Result<()> {
+    let graph = WorkflowGraph::from_workflow(&resolve_runtime_workflow(registry, matches)?);
+    let rendered = if json_output {
+        serde_json::to_string_pretty(&graph)?
+    } else {
+        match matches.get_one::<String>("format").map(String::as_str) {
+            Some("dot") => graph.to_dot(),
+            _ => graph.to_mermaid(),
+        }
+    };
+
+    match matches.get_one::<String>("output") {
+        Some(output_path) => {
+            fs::write(output_path, format!("{rendered}\n")).with_context(|| format!("write workflow graph {output_path}"))?
+        }
+        None => println!("{rendered}"),
+    }
+    Ok(())
+}
+
 fn run_workflow(registry: Arc<Mutex<CommandRegistry>>, json_output: bool, matches: &ArgMatches) -> Result<()> {
     let mut state = WorkflowRunState::new(resolve_runtime_workflow(Arc::clone(&registry), matches)?);
 
@@ -1125,13 +1292,37 @@ fn run_workflow(registry: Arc<Mutex<CommandRegistry>>, json_output: bool, matche
     if let Some(overrides) = matches.get_many::<String>("input") {
         for raw in overrides {
             let (key, value) = raw.split_once('=').context("workflow input overrides must use KEY=VALUE syntax")?;
-            state.set_input_value(key.trim(), Value::String(value.trim().to_string()));
+            let key = key.trim();
+            let raw_value = Value::String(value.trim().to_string());
+            let value = match state.workflow.inputs.get(key) {
+                Some(definition) => definition
+                    .coerce_value(raw_value)
+                    .with_context(|| format!("invalid value for workflow input '{key}'"))?,
+                None => raw_value,
+            };
+            state.set_input_value(key, value);
         }
     }
 
     state.apply_input_defaults();
     state.evaluate_input_providers()?;
 
+    let input_violations = state
+        .workflow
+        .inputs
+        .iter()
+        .filter_map(|(input_name, definition)| {
+            let value = state.run_context.inputs.get(input_name)?;
+            definition
+                .validate_value(value)
+                .err()
+                .map(|error| format!("- input '{input_name}': {error}"))
+        })
+        .collect::<Vec<String>>();
+    if !input_violations.is_empty() {
+        bail!("workflow inputs failed validation:\n{}", input_violations.join("\n"));
+    }
+
     if let Some(blocked) = state
         .telemetry()
         .provider_resolution_events()
@@ -1151,7 +1342,7 @@ fn run_workflow(registry: Arc<Mutex<CommandRegistry>>, json_output: bool, matche
         guard.clone()
     };
 
-    let runner = RegistryCommandRunner::new(registry_snapshot);
+    let runner = RegistryCommandRunner::new(registry_snapshot.clone());
     let preflight_violations = runner.validate_workflow_execution_readiness(&state.workflow);
     if !preflight_violations.is_empty() {
         let details = preflight_violations
@@ -1167,6 +1358,8 @@ fn run_workflow(registry: Arc<Mutex<CommandRegistry>>, json_output: bool, matche
         bail!("workflow run blocked by preflight validation:\n{details}");
     }
 
+    let audit_trail = AuditTrail::new(AuditLogger::new()?, "cli", AuditInvocation::new(AuditInitiator::Human));
+    let runner = AuditedCommandRunner::new(runner, registry_snapshot, audit_trail).with_workflow_id(state.workflow.identifier.clone());
     let results = state.execute_with_runner(&runner)?;
     let run_succeeded = results.iter().all(|result| result.status != StepStatus::Failed);
 
@@ -1182,6 +1375,129 @@ fn run_workflow(registry: Arc<Mutex<CommandRegistry>>, json_output: bool, matche
 
     Ok(())
 }
+
+fn test_workflow(registry: Arc<Mutex<CommandRegistry>>, json_output: bool, matches: &ArgMatches) -> Result<()> {
+    let suite_path = Path::new(matches.get_one::<String>("suite").context("a test suite path is required")?);
+    let content = fs::read_to_string(suite_path).with_context(|| format!("read test suite {}", suite_path.display()))?;
+    let suite: WorkflowTestSuite = serde_yaml::from_str(&content).with_context(|| format!("parse test suite {}", suite_path.display()))?;
+
+    let workflow_path = suite_path.parent().unwrap_or_else(|| Path::new(".")).join(&suite.workflow);
+    let workflow = if workflow_path.is_file() {
+        load_runtime_workflow_from_file(&workflow_path)?
+    } else {
+        let definitions = {
+            let guard = registry.lock().expect("could not obtain lock on registry");
+            guard.workflows.clone()
+        };
+        build_runtime_catalog(&definitions)?
+            .get(&suite.workflow)
+            .cloned()
+            .with_context(|| format!("test suite references unknown workflow '{}'", suite.workflow))?
+    };
+
+    let report = run_workflow_test_suite(&workflow, &suite);
+    let format = if json_output {
+        "json"
+    } else {
+        matches.get_one::<String>("format").map(String::as_str).unwrap_or("human")
+    };
+    let rendered = match format {
+        "json" => serde_json::to_string_pretty(&report)?,
+        "junit" => report.to_junit_xml(),
+        _ => format_workflow_test_report(&report),
+    };
+
+    match matches.get_one::<String>("output") {
+        Some(output_path) => fs::write(output_path, &rendered).with_context(|| format!("write test report {output_path}"))?,
+        None => println!("{}", rendered.trim_end()),
+    }
+
+    if !report.is_success() {
+        bail!("{} of {} workflow test case(s) failed", report.failed, report.cases.len());
+    }
+    Ok(())
+}
+
+fn format_workflow_test_report(report: &WorkflowTestReport) -> String {
+    let mut lines = vec![format!("Workflow '{}' tests:", report.workflow)];
+    for case in &report.cases {
+        let marker = if case.passed { "PASS" } else { "FAIL" };
+        lines.push(format!("- [{marker}] {} ({} ms)", case.name, case.duration_ms));
+        for failure in &case.failures {
+            lines.push(format!("    {failure}"));
+        }
+    }
+    lines.push(format!("{} passed, {} failed", report.passed, report.failed));
+    lines.join("\n")
+}
+
+fn lint_workflows(registry: Arc<Mutex<CommandRegistry>>, json_output: bool, matches: &ArgMatches) -> Result<()> {
+    let registry_snapshot = registry.lock().expect("could not obtain lock on registry").clone();
+    let paths: Vec<&String> = matches.get_many::<String>("files").map(Iterator::collect).unwrap_or_default();
+    let mut linted = Vec::new();
+    if paths.is_empty() {
+        for definition in &registry_snapshot.workflows {
+            linted.push((
+                lint_workflow_definition(definition, Some(&registry_snapshot)),
+                definition.workflow.clone(),
+            ));
+        }
+    } else {
+        for path in paths {
+            let definition = load_workflow_definition_from_file(Path::new(path))?;
+            linted.push((lint_workflow_definition(&definition, Some(&registry_snapshot)), path.clone()));
+        }
+    }
+
+    let format = if json_output {
+        "json"
+    } else {
+        matches.get_one::<String>("format").map(String::as_str).unwrap_or("human")
+    };
+    let rendered = match format {
+        "json" => serde_json::to_string_pretty(&linted.iter().map(|(report, _)| report).collect::<Vec<_>>())?,
+        "sarif" => serde_json::to_string_pretty(&to_sarif(linted.iter().map(|(report, source)| (report, source.as_str()))))?,
+        _ => linted
+            .iter()
+            .map(|(report, source)| format_workflow_lint_report(report, source))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+
+    match matches.get_one::<String>("output") {
+        Some(output_path) => fs::write(output_path, &rendered).with_context(|| format!("write lint report {output_path}"))?,
+        None => println!("{}", rendered.trim_end()),
+    }
+
+    let error_count: usize = linted.iter().map(|(report, _)| report.error_count()).sum();
+    if error_count > 0 {
+        bail!("workflow lint reported {error_count} error(s)");
+    }
+    Ok(())
+}
+
+fn format_workflow_lint_report(report: &WorkflowLintReport, source: &str) -> String {
+    if report.findings.is_empty() {
+        return format!("{source}: workflow '{}' has no lint findings", report.workflow_id);
+    }
+    let mut lines = vec![format!(
+        "{source}: workflow '{}' ({} error(s), {} warning(s))",
+        report.workflow_id,
+        report.error_count(),
+        report.warning_count()
+    )];
+    for finding in &report.findings {
+        lines.push(format!(
+            "  {} [{}] {}: {}",
+            finding.severity.as_str(),
+            finding.rule,
+            finding.path,
+            finding.message
+        ));
+    }
+    lines.join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1314,6 +1630,55 @@ mod tests {
         assert_eq!(format_step_status(StepStatus::Skipped), "skipped");
     }
 
+    #[test]
+    fn format_workflow_test_report_lists_failures_under_cases() {
+        let report = WorkflowTestReport {
+            workflow: "cert_audit".into(),
+            passed: 1,
+            failed: 1,
+            cases: vec![
+                oatty_engine::workflow::testing::WorkflowTestCaseResult {
+                    name: "renews".into(),
+                    passed: true,
+                    failures: Vec::new(),
+                    steps: IndexMap::new(),
+                    duration_ms: 2,
+                },
+                oatty_engine::workflow::testing::WorkflowTestCaseResult {
+                    name: "skips".into(),
+                    passed: false,
+                    failures: vec!["step 'renew': expected skipped, got succeeded".into()],
+                    steps: IndexMap::new(),
+                    duration_ms: 1,
+                },
+            ],
+        };
+
+        let rendered = format_workflow_test_report(&report);
+        assert!(rendered.contains("- [PASS] renews"));
+        assert!(rendered.contains("- [FAIL] skips (1 ms)\n    step 'renew'"));
+        assert!(rendered.ends_with("1 passed, 1 failed"));
+    }
+
+    #[test]
+    fn format_workflow_lint_report_lists_findings_with_rule_and_path() {
+        let report = WorkflowLintReport {
+            workflow_id: "cleanup".into(),
+            findings: vec![oatty_engine::workflow::lint::LintFinding {
+                rule: "repeat-unbounded".into(),
+                severity: oatty_engine::workflow::lint::LintSeverity::Warning,
+                message: "step 'poll' repeats without `timeout` or `max_attempts`".into(),
+                path: "$.steps[0].repeat".into(),
+                step_id: Some("poll".into()),
+                input: None,
+            }],
+        };
+
+        let rendered = format_workflow_lint_report(&report, "cleanup.yaml");
+        assert!(rendered.starts_with("cleanup.yaml: workflow 'cleanup' (0 error(s), 1 warning(s))"));
+        assert!(rendered.contains("  warning [repeat-unbounded] $.steps[0].repeat: step 'poll'"));
+    }
+
     fn history_enabled_run_state() -> WorkflowRunState {
         let mut inputs = IndexMap::new();
         inputs.insert(
@@ -1334,6 +1699,7 @@ mod tests {
             steps: Vec::new(),
             final_output: None,
             requires: None,
+            schedule: None,
         };
         WorkflowRunState::new(workflow)
     }
@@ -0,0 +1,65 @@
+//! `oatty mcp token` subcommands.
+
+use anyhow::{Result, bail};
+use chrono::SecondsFormat;
+use clap::ArgMatches;
+use oatty_mcp::config::default_config_path;
+use oatty_mcp::{create_client_token, list_client_tokens, load_http_server_config, revoke_client_token};
+
+/// Create, revoke, or list MCP HTTP server client tokens in the default MCP config file.
+pub fn run_token_command(action: &str, matches: &ArgMatches, json_output: bool) -> Result<()> {
+    let config_path = default_config_path();
+    match action {
+        "create" => {
+            let name = client_name(matches)?;
+            let token = create_client_token(&config_path, name)?;
+            if json_output {
+                println!("{}", serde_json::json!({ "name": name, "token": token }));
+                return Ok(());
+            }
+            println!("Created token for '{name}'. Copy it now; it cannot be shown again:\n\n  {token}\n");
+            println!("Clients send it as `Authorization: Bearer <token>`.");
+            let http_server = load_http_server_config(&config_path)?;
+            if http_server.tls.is_none() {
+                println!(
+                    "Set `httpServer.tls` in {} before binding to a non-loopback address.",
+                    config_path.display()
+                );
+            }
+            Ok(())
+        }
+        "revoke" => {
+            let name = client_name(matches)?;
+            revoke_client_token(&config_path, name)?;
+            println!("Revoked token for '{name}'.");
+            Ok(())
+        }
+        "list" => {
+            let clients = list_client_tokens(&config_path)?;
+            if json_output {
+                println!("{}", serde_json::to_string_pretty(&clients)?);
+                return Ok(());
+            }
+            if clients.is_empty() {
+                println!("No client tokens; the MCP HTTP server accepts unauthenticated loopback connections.");
+                return Ok(());
+            }
+            for client in clients {
+                println!(
+                    "{:<24} created {}",
+                    client.name,
+                    client.created_at.to_rfc3339_opts(SecondsFormat::Secs, true)
+                );
+            }
+            Ok(())
+        }
+        other => bail!("Unsupported mcp token subcommand: {other}"),
+    }
+}
+
+fn client_name(matches: &ArgMatches) -> Result<&str> {
+    match matches.get_one::<String>("name") {
+        Some(name) => Ok(name.as_str()),
+        None => bail!("a client name is required"),
+    }
+}
@@ -0,0 +1,412 @@
+//! Foreground scheduler that executes workflows on their declared cron schedules.
+//!
+//! `oatty scheduler run` loads every workflow with an enabled `schedule:` block, waits until
+//! the next one is due, and executes it through the same asynchronous runner the TUI uses.
+//! Each finished run, and each due run skipped because the previous one was still active, is
+//! appended to the workflow history so the TUI can surface the last run alongside the next
+//! scheduled time.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use clap::ArgMatches;
+use oatty_engine::workflow::document::build_runtime_catalog;
+use oatty_engine::workflow::runner::drive_workflow_run;
+use oatty_engine::workflow::schedule::{ScheduleDecision, WorkflowSchedulePlanner};
+use oatty_engine::{ProviderBindingOutcome, RegistryCommandRunner, WorkflowRunState};
+use oatty_mcp::logging::{AuditInitiator, AuditInvocation, AuditLogger, AuditTrail, AuditedCommandRunner};
+use oatty_mcp::{WorkflowHistoryEntry, append_history_entry};
+use oatty_registry::CommandRegistry;
+use oatty_types::workflow::{RuntimeWorkflow, WorkflowRunControl, WorkflowRunEvent, WorkflowRunRequest, WorkflowRunStatus};
+use serde_json::{Map, Value};
+use tokio::sync::mpsc::{self, UnboundedSender};
+use tracing::warn;
+
+/// Trigger recorded in workflow history for runs started by the scheduler.
+const SCHEDULE_TRIGGER: &str = "schedule";
+
+/// History status recorded when a due run is dropped by the `skip` overlap policy.
+const SKIPPED_STATUS: &str = "skipped";
+
+/// Longest time the scheduler sleeps before re-checking due schedules.
+///
+/// Capping the sleep keeps the loop responsive to wall-clock jumps such as a laptop waking up.
+const MAX_IDLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Grace period granted to in-flight runs after a shutdown request.
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+/// Runs the scheduler loop until interrupted with Ctrl+C.
+pub async fn run_scheduler(registry: Arc<Mutex<CommandRegistry>>, matches: &ArgMatches) -> Result<()> {
+    let workflow_filter: Vec<String> = matches
+        .get_many::<String>("workflow")
+        .map(|values| values.cloned().collect())
+        .unwrap_or_default();
+
+    let definitions = {
+        let guard = registry.lock().expect("could not obtain lock on registry");
+        guard.workflows.clone()
+    };
+    let catalog = build_runtime_catalog(&definitions)?;
+    let workflows = catalog
+        .into_values()
+        .filter(|workflow| workflow_filter.is_empty() || workflow_filter.contains(&workflow.identifier));
+
+    let (mut planner, schedule_errors) = WorkflowSchedulePlanner::new(workflows, Utc::now());
+    for (workflow_id, error) in schedule_errors {
+        warn!(workflow = %workflow_id, error = %error, "ignoring workflow with invalid schedule");
+    }
+    if planner.is_empty() {
+        bail!("no workflows declare an enabled schedule");
+    }
+
+    println!("Scheduler started. Press Ctrl+C to stop.");
+    for entry in planner.entries() {
+        println!("- {} next run: {}", entry.workflow.identifier, describe_next_run(entry.next_due));
+    }
+
+    let (completion_tx, mut completion_rx) = mpsc::unbounded_channel::<String>();
+    let mut active_runs: HashMap<String, UnboundedSender<WorkflowRunControl>> = HashMap::new();
+    let shutdown = tokio::signal::ctrl_c();
+    tokio::pin!(shutdown);
+
+    loop {
+        let idle_interval = planner
+            .next_wakeup()
+            .and_then(|next_due| (next_due - Utc::now()).to_std().ok())
+            .unwrap_or_default()
+            .min(MAX_IDLE_INTERVAL);
+
+        tokio::select! {
+            _ = &mut shutdown => break,
+            Some(workflow_id) = completion_rx.recv() => {
+                active_runs.remove(&workflow_id);
+                if let Some(workflow) = planner.mark_finished(&workflow_id) {
+                    println!("Starting queued run for '{}'.", workflow_id);
+                    start_or_release(&registry, &mut active_runs, workflow, &completion_tx);
+                }
+            }
+            _ = tokio::time::sleep(idle_interval) => {
+                for decision in planner.poll_due(Utc::now()) {
+                    match decision {
+                        ScheduleDecision::Start { workflow, due_at } => {
+                            println!("Starting scheduled run for '{}' (due {}).", workflow.identifier, due_at.to_rfc3339());
+                            start_or_release(&registry, &mut active_runs, *workflow, &completion_tx);
+                        }
+                        ScheduleDecision::Skipped { workflow_id, due_at } => {
+                            println!("Skipping run for '{}' due {}: previous run still in progress.", workflow_id, due_at.to_rfc3339());
+                            record_history(
+                                &workflow_id,
+                                &format!("run-{}-{}", workflow_id, due_at.timestamp_millis()),
+                                SKIPPED_STATUS,
+                                Value::Null,
+                            );
+                        }
+                        ScheduleDecision::Queued { workflow_id, due_at } => {
+                            println!("Queued run for '{}' due {} behind the active run.", workflow_id, due_at.to_rfc3339());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    println!("Scheduler stopping.");
+    if active_runs.is_empty() {
+        return Ok(());
+    }
+    for control in active_runs.values() {
+        let _ = control.send(WorkflowRunControl::Cancel);
+    }
+    let drain = async {
+        while !active_runs.is_empty() {
+            match completion_rx.recv().await {
+                Some(workflow_id) => {
+                    active_runs.remove(&workflow_id);
+                }
+                None => break,
+            }
+        }
+    };
+    if tokio::time::timeout(SHUTDOWN_GRACE_PERIOD, drain).await.is_err() {
+        warn!("scheduled runs did not finish canceling before shutdown");
+    }
+    Ok(())
+}
+
+/// Starts a run, reporting it as finished right away when it cannot be prepared.
+fn start_or_release(
+    registry: &Arc<Mutex<CommandRegistry>>,
+    active_runs: &mut HashMap<String, UnboundedSender<WorkflowRunControl>>,
+    workflow: RuntimeWorkflow,
+    completion_tx: &UnboundedSender<String>,
+) {
+    let workflow_id = workflow.identifier.clone();
+    match spawn_scheduled_run(registry, workflow, completion_tx.clone()) {
+        Ok(control_tx) => {
+            active_runs.insert(workflow_id, control_tx);
+        }
+        Err(error) => {
+            eprintln!("Scheduled run for '{}' could not start: {error:#}", workflow_id);
+            record_history(
+                &workflow_id,
+                &format!("run-{}-{}", workflow_id, Utc::now().timestamp_millis()),
+                "failed",
+                Value::Null,
+            );
+            let _ = completion_tx.send(workflow_id);
+        }
+    }
+}
+
+/// Prepares run state for a scheduled workflow and spawns it on the runtime.
+fn spawn_scheduled_run(
+    registry: &Arc<Mutex<CommandRegistry>>,
+    workflow: RuntimeWorkflow,
+    completion_tx: UnboundedSender<String>,
+) -> Result<UnboundedSender<WorkflowRunControl>> {
+    let state = prepare_scheduled_run_state(workflow)?;
+
+    let registry_snapshot = {
+        let guard = registry.lock().expect("could not obtain lock on registry");
+        guard.clone()
+    };
+    let runner = RegistryCommandRunner::new(registry_snapshot.clone());
+    let preflight_violations = runner.validate_workflow_execution_readiness(&state.workflow);
+    if let Some(violation) = preflight_violations.first() {
+        bail!(
+            "step '{}' [{}]: {} (next: {})",
+            violation.step_id,
+            violation.code,
+            violation.message,
+            violation.suggested_action
+        );
+    }
+
+    let workflow_id = state.workflow.identifier.clone();
+    let audit_trail = AuditTrail::new(AuditLogger::new()?, "scheduler", AuditInvocation::new(AuditInitiator::Scheduler));
+    let runner = AuditedCommandRunner::new(runner, registry_snapshot, audit_trail).with_workflow_id(workflow_id.clone());
+    let run_id = format!("run-{}-{}", workflow_id, Utc::now().timestamp_millis());
+    let redacted_inputs = redacted_inputs(&state);
+    let request = WorkflowRunRequest {
+        run_id: run_id.clone(),
+        workflow: state.workflow.clone(),
+        inputs: state.run_context.inputs.clone(),
+        environment: state.run_context.environment_variables.clone(),
+        step_outputs: state.run_context.steps.clone(),
+    };
+
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let (control_tx, control_rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        let run = tokio::spawn(drive_workflow_run(request, Arc::new(runner), control_rx, event_tx));
+
+        let mut final_status = WorkflowRunStatus::Failed;
+        let mut final_error = None;
+        while let Some(event) = event_rx.recv().await {
+            if let WorkflowRunEvent::RunCompleted { status, error, .. } = event {
+                final_status = status;
+                final_error = error;
+            }
+        }
+        match run.await {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => final_error = Some(error.to_string()),
+            Err(error) => final_error = Some(error.to_string()),
+        }
+
+        let status = history_status(final_status);
+        match final_error {
+            Some(error) => println!("Scheduled run '{}' finished with status {}: {}", run_id, status, error),
+            None => println!("Scheduled run '{}' finished with status {}.", run_id, status),
+        }
+        record_history(&workflow_id, &run_id, status, redacted_inputs);
+        let _ = completion_tx.send(workflow_id);
+    });
+
+    Ok(control_tx)
+}
+
+/// Builds run state from the workflow's schedule inputs, environment, and input defaults.
+fn prepare_scheduled_run_state(workflow: RuntimeWorkflow) -> Result<WorkflowRunState> {
+    let schedule = workflow.schedule.clone();
+    let mut state = WorkflowRunState::new(workflow);
+
+    if let Some(schedule) = schedule {
+        state
+            .run_context
+            .environment_variables
+            .extend(schedule.environment.iter().map(|(key, value)| (key.clone(), value.clone())));
+
+        for (input_name, raw_value) in &schedule.inputs {
+            let value = match state.workflow.inputs.get(input_name) {
+                Some(definition) => definition
+                    .coerce_value(raw_value.clone())
+                    .with_context(|| format!("invalid scheduled value for workflow input '{input_name}'"))?,
+                None => raw_value.clone(),
+            };
+            state.set_input_value(input_name, value);
+        }
+    }
+
+    state.apply_input_defaults();
+    state.evaluate_input_providers()?;
+
+    let mut violations = Vec::new();
+    for (input_name, definition) in &state.workflow.inputs {
+        match state.run_context.inputs.get(input_name) {
+            Some(value) => {
+                if let Err(error) = definition.validate_value(value) {
+                    violations.push(format!("- input '{input_name}': {error}"));
+                }
+            }
+            None if definition.is_required() => violations.push(format!("- input '{input_name}': a value is required")),
+            None => {}
+        }
+    }
+    if !violations.is_empty() {
+        bail!("workflow inputs failed validation:\n{}", violations.join("\n"));
+    }
+
+    if let Some(blocked) = state
+        .telemetry()
+        .provider_resolution_events()
+        .iter()
+        .find(|event| matches!(event.outcome, ProviderBindingOutcome::Prompt(_) | ProviderBindingOutcome::Error(_)))
+    {
+        bail!(
+            "provider argument {}.{} requires operator input, which scheduled runs cannot provide",
+            blocked.input,
+            blocked.argument
+        );
+    }
+
+    Ok(state)
+}
+
+fn redacted_inputs(state: &WorkflowRunState) -> Value {
+    let map: Map<String, Value> = state
+        .run_context
+        .inputs
+        .iter()
+        .map(|(input_name, value)| {
+            let redacted = match state.workflow.inputs.get(input_name) {
+                Some(definition) => definition.redact_value(value),
+                None => value.clone(),
+            };
+            (input_name.clone(), redacted)
+        })
+        .collect();
+    Value::Object(map)
+}
+
+fn history_status(status: WorkflowRunStatus) -> &'static str {
+    match status {
+        WorkflowRunStatus::Succeeded => "succeeded",
+        WorkflowRunStatus::Canceled | WorkflowRunStatus::CancelRequested => "canceled",
+        _ => "failed",
+    }
+}
+
+fn record_history(workflow_id: &str, run_id: &str, status: &str, inputs: Value) {
+    let entry = WorkflowHistoryEntry {
+        workflow_id: workflow_id.to_string(),
+        run_id: run_id.to_string(),
+        status: status.to_string(),
+        timestamp: Utc::now(),
+        inputs,
+        trigger: Some(SCHEDULE_TRIGGER.to_string()),
+    };
+    if let Err(error) = append_history_entry(&entry) {
+        warn!(workflow = %workflow_id, error = %error, "failed to record scheduled run history");
+    }
+}
+
+fn describe_next_run(next_due: Option<DateTime<Utc>>) -> String {
+    next_due
+        .map(|next_due| next_due.to_rfc3339())
+        .unwrap_or_else(|| "never".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::IndexMap;
+    use oatty_types::workflow::{WorkflowInputDefinition, WorkflowSchedule, WorkflowStepDefinition};
+    use serde_json::json;
+
+    fn scheduled_workflow(inputs: IndexMap<String, Value>, environment: IndexMap<String, String>) -> RuntimeWorkflow {
+        let mut definitions = IndexMap::new();
+        definitions.insert(
+            "days".to_string(),
+            WorkflowInputDefinition {
+                r#type: Some("integer".into()),
+                ..Default::default()
+            },
+        );
+        RuntimeWorkflow {
+            identifier: "cert_audit".into(),
+            title: None,
+            description: None,
+            inputs: definitions,
+            steps: vec![WorkflowStepDefinition {
+                id: "audit".into(),
+                run: "certs:list".into(),
+                description: None,
+                depends_on: Vec::new(),
+                r#if: None,
+                with: IndexMap::new(),
+                body: Value::Null,
+                repeat: None,
+                output_contract: None,
+            }],
+            final_output: None,
+            requires: None,
+            schedule: Some(WorkflowSchedule {
+                cron: "0 2 * * *".into(),
+                timezone: Default::default(),
+                inputs,
+                environment,
+                overlap: Default::default(),
+                enabled: true,
+            }),
+        }
+    }
+
+    #[test]
+    fn prepare_scheduled_run_state_applies_inputs_and_environment() {
+        let mut inputs = IndexMap::new();
+        inputs.insert("days".to_string(), json!("30"));
+        let mut environment = IndexMap::new();
+        environment.insert("REGION".to_string(), "us".to_string());
+
+        let state = prepare_scheduled_run_state(scheduled_workflow(inputs, environment)).expect("state prepared");
+        assert_eq!(state.run_context.inputs.get("days"), Some(&json!(30)));
+        assert_eq!(
+            state.run_context.environment_variables.get("REGION").map(String::as_str),
+            Some("us")
+        );
+    }
+
+    #[test]
+    fn prepare_scheduled_run_state_rejects_invalid_inputs() {
+        let mut inputs = IndexMap::new();
+        inputs.insert("days".to_string(), json!("thirty"));
+
+        let error = prepare_scheduled_run_state(scheduled_workflow(inputs, IndexMap::new())).expect_err("coercion should fail");
+        assert!(error.to_string().contains("days"));
+    }
+
+    #[test]
+    fn history_status_maps_terminal_states() {
+        assert_eq!(history_status(WorkflowRunStatus::Succeeded), "succeeded");
+        assert_eq!(history_status(WorkflowRunStatus::Canceled), "canceled");
+        assert_eq!(history_status(WorkflowRunStatus::Failed), "failed");
+    }
+}
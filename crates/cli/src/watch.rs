@@ -0,0 +1,117 @@
+//! `--watch` / `--until` support for catalog commands.
+
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use chrono::{Local, SecondsFormat, Utc};
+use clap::ArgMatches;
+use oatty_engine::watch::{eval_watch_condition, parse_watch_interval};
+use oatty_registry::clap_builder::{UNTIL_ARG_ID, WATCH_ARG_ID};
+use oatty_util::{JsonChange, diff_json};
+use serde_json::{Value, json};
+
+use crate::{CommandInvocation, execute_command_once, print_command_response};
+
+/// Parsed `--watch` and `--until` values.
+pub struct WatchOptions {
+    interval: Duration,
+    until: Option<String>,
+}
+
+/// Read watch options from command matches; `None` when `--watch` was not given.
+pub fn watch_options(command_matches: &ArgMatches) -> Result<Option<WatchOptions>> {
+    let Some(raw_interval) = command_matches.try_get_one::<String>(WATCH_ARG_ID).ok().flatten() else {
+        return Ok(None);
+    };
+    let interval = parse_watch_interval(raw_interval)
+        .ok_or_else(|| anyhow!("invalid --watch interval '{raw_interval}' (expected e.g. 5s, 1m, or seconds)"))?;
+    let until = command_matches.try_get_one::<String>(UNTIL_ARG_ID).ok().flatten().cloned();
+    Ok(Some(WatchOptions { interval, until }))
+}
+
+/// Re-run the command every interval, printing what changed between runs.
+///
+/// The first run prints the full response; later runs print the structural
+/// changes against the previous payload. With `--json`, each run is emitted as
+/// one NDJSON line. Stops when `--until` holds, a run fails, or on Ctrl+C.
+pub async fn watch_command(invocation: &CommandInvocation<'_>, options: &WatchOptions, json_output: bool) -> Result<()> {
+    let mut previous: Option<Value> = None;
+    let mut run = 0u32;
+    loop {
+        run += 1;
+        let response = execute_command_once(invocation).await?;
+        response.ensure_success()?;
+        let changes = previous.as_ref().map(|previous| diff_json(previous, &response.payload));
+        if json_output {
+            println!("{}", watch_run_json(run, &response.payload, changes.as_deref()));
+        } else {
+            match &changes {
+                None => print_command_response(&response, false)?,
+                Some(changes) => println!("{}", format_watch_run(run, &response.heading, changes)),
+            }
+        }
+
+        if let Some(condition) = options.until.as_deref()
+            && eval_watch_condition(condition, &response.payload).map_err(|error| anyhow!("--until: {error}"))?
+        {
+            if !json_output {
+                println!("Condition met after {run} run(s).");
+            }
+            return Ok(());
+        }
+        previous = Some(response.payload);
+
+        tokio::select! {
+            _ = tokio::time::sleep(options.interval) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+    }
+}
+
+fn format_watch_run(run: u32, heading: &str, changes: &[JsonChange]) -> String {
+    let timestamp = Local::now().format("%H:%M:%S");
+    let mut lines = vec![match changes.len() {
+        0 => format!("--- run {run} at {timestamp} ({heading}): no changes"),
+        count => format!("--- run {run} at {timestamp} ({heading}): {count} change(s)"),
+    }];
+    lines.extend(changes.iter().map(|change| format!("  {change}")));
+    lines.join("\n")
+}
+
+fn watch_run_json(run: u32, payload: &Value, changes: Option<&[JsonChange]>) -> Value {
+    let mut line = json!({
+        "run": run,
+        "timestamp": Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        "payload": payload,
+    });
+    if let Some(changes) = changes {
+        line["changes"] = changes.iter().map(JsonChange::to_json).collect();
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watch_run_lists_changes_under_a_heading() {
+        let changes = diff_json(&json!({"status": "running"}), &json!({"status": "done"}));
+        let output = format_watch_run(2, "200 OK", &changes);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[0].starts_with("--- run 2 at "));
+        assert!(lines[0].ends_with("(200 OK): 1 change(s)"));
+        assert_eq!(lines[1], "  ~ .status: \"running\" -> \"done\"");
+    }
+
+    #[test]
+    fn watch_run_json_includes_changes_after_first_run() {
+        let first = watch_run_json(1, &json!({"ok": true}), None);
+        assert!(first.get("changes").is_none());
+
+        let changes = diff_json(&json!([1]), &json!([1, 2]));
+        let second = watch_run_json(2, &json!([1, 2]), Some(&changes));
+        assert_eq!(second["changes"][0]["path"], "[1]");
+        assert_eq!(second["changes"][0]["kind"], "added");
+    }
+}
@@ -136,7 +136,8 @@ where
     result
 }
 
-fn parse_repeat_interval(raw_interval: &str) -> Option<Duration> {
+/// Parses a repeat interval such as `5s`, `2m`, or a bare number of seconds.
+pub(crate) fn parse_repeat_interval(raw_interval: &str) -> Option<Duration> {
     let trimmed = raw_interval.trim();
     if trimmed.is_empty() {
         return None;
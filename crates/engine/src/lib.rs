@@ -53,6 +53,7 @@ pub mod model;
 pub mod provider;
 pub mod resolve;
 pub mod templates;
+pub mod watch;
 pub mod workflow;
 
 // Re-export commonly used types for convenience
@@ -20,7 +20,7 @@ mod suggestion_builder;
 mod value_provider;
 
 use anyhow::Result;
-pub use fetch::ProviderValueFetcher;
+pub use fetch::{DefaultHttpFetcher, ProviderValueFetcher};
 pub(crate) use identifier::ProviderIdentifier;
 pub use identifier::parse_provider_group_and_command;
 pub use null::NullProvider;
@@ -101,13 +101,19 @@ fn fetch_and_cache(
         let spec = registry_lock
             .find_by_group_and_cmd_cloned(&identifier.group, &identifier.name)?
             .clone();
-        let base_url = registry_lock
-            .resolve_base_url_for_command(&spec)
-            .ok_or_else(|| anyhow!("missing base URL for command '{}'", spec.name))?;
-        let headers = registry_lock
-            .resolve_headers_for_command(&spec)
-            .ok_or_else(|| anyhow!("could not determine headers for command: {}", &spec.canonical_id()))?
-            .clone();
+        // Only HTTP-backed providers talk to a catalog; MCP-backed ones are routed by the fetcher.
+        let (base_url, headers) = if spec.http().is_some() {
+            let base_url = registry_lock
+                .resolve_base_url_for_command(&spec)
+                .ok_or_else(|| anyhow!("missing base URL for command '{}'", spec.name))?;
+            let headers = registry_lock
+                .resolve_headers_for_command(&spec)
+                .ok_or_else(|| anyhow!("could not determine headers for command: {}", &spec.canonical_id()))?
+                .clone();
+            (base_url, headers)
+        } else {
+            (String::new(), Default::default())
+        };
         debug!(
             provider_id = %provider_id,
             command = %spec.canonical_id(),
@@ -116,7 +116,7 @@ impl ProviderSuggestionBuilder {
                         score,
                     });
                 }
-                items.sort_by(|a, b| b.score.cmp(&a.score));
+                items.sort_by_key(|item| std::cmp::Reverse(item.score));
                 ProviderSuggestionSet::ready(items)
             }
             CacheLookupOutcome::Pending(pending) => ProviderSuggestionSet::with_pending(Vec::new(), pending),
@@ -253,6 +253,7 @@ mod tests {
                 default_value: None,
                 description: None,
                 provider: None,
+                schema: None,
             }],
             HttpCommandSpec::new("GET", "/apps", None, None),
             0,
@@ -0,0 +1,62 @@
+//! Watch-mode helpers shared by the CLI `--watch` flag and the TUI results view.
+//!
+//! A watched command is re-executed on an interval; an optional `--until`
+//! condition uses the workflow `eval_condition` syntax and sees the latest
+//! payload as the `result` step (`steps.result.status == "succeeded"`).
+
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::executor::parse_repeat_interval;
+use crate::resolve::{RunContext, eval_condition, find_unresolved_references_in_condition};
+
+/// Step identifier under which the latest watched payload is exposed to conditions.
+pub const WATCH_RESULT_STEP_ID: &str = "result";
+
+/// Parses a watch interval such as `5s`, `2m`, or a bare number of seconds.
+///
+/// Returns `None` for malformed or zero intervals.
+pub fn parse_watch_interval(raw_interval: &str) -> Option<Duration> {
+    parse_repeat_interval(raw_interval).filter(|interval| !interval.is_zero())
+}
+
+/// Evaluates an `--until` condition against the latest watched payload.
+///
+/// References that cannot be resolved against the payload are reported as an
+/// error, mirroring how repeat steps treat unresolved `until` references.
+pub fn eval_watch_condition(expression: &str, payload: &Value) -> Result<bool, String> {
+    let mut context = RunContext::default();
+    context.steps.insert(WATCH_RESULT_STEP_ID.to_string(), payload.clone());
+    let unresolved_references = find_unresolved_references_in_condition(expression, &context);
+    if !unresolved_references.is_empty() {
+        return Err(format!("unresolved references in condition: {}", unresolved_references.join(", ")));
+    }
+    Ok(eval_condition(expression, &context))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_intervals_and_rejects_zero() {
+        assert_eq!(parse_watch_interval("5s"), Some(Duration::from_secs(5)));
+        assert_eq!(parse_watch_interval("2m"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_watch_interval("10"), Some(Duration::from_secs(10)));
+        assert_eq!(parse_watch_interval("0s"), None);
+        assert_eq!(parse_watch_interval("soon"), None);
+    }
+
+    #[test]
+    fn condition_sees_payload_as_result_step() {
+        let payload = json!({"status": "succeeded", "jobs": [{"state": "done"}]});
+        assert_eq!(eval_watch_condition("steps.result.status == \"succeeded\"", &payload), Ok(true));
+        assert_eq!(
+            eval_watch_condition("steps.result.jobs[0].state == \"running\"", &payload),
+            Ok(false)
+        );
+        assert!(eval_watch_condition("steps.result.missing == \"x\"", &payload).is_err());
+    }
+}
@@ -8,6 +8,7 @@
 //! authoring order.
 
 use crate::workflow::condition_syntax::{normalize_condition_expression, validate_condition_expression};
+use crate::workflow::schedule::CronSchedule;
 use anyhow::{Context, Result, anyhow, bail};
 use indexmap::IndexMap;
 use oatty_types::{
@@ -31,6 +32,9 @@ pub fn runtime_workflow_from_definition(definition: &WorkflowDefinition) -> Resu
 
     validate_provider_dependency_bindings(&identifier, &inputs)?;
     validate_step_condition_expressions(&identifier, &steps)?;
+    if let Some(schedule) = definition.schedule.as_ref() {
+        CronSchedule::parse(&schedule.cron).with_context(|| format!("workflow '{}' has an invalid schedule", identifier))?;
+    }
 
     Ok(RuntimeWorkflow {
         identifier,
@@ -40,6 +44,7 @@ pub fn runtime_workflow_from_definition(definition: &WorkflowDefinition) -> Resu
         steps,
         final_output: definition.final_output.clone(),
         requires: definition.requires.clone(),
+        schedule: definition.schedule.clone(),
     })
 }
 
@@ -189,12 +194,47 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let error = runtime_workflow_from_definition(&definition).expect_err("expected identifier error");
         assert!(error.to_string().contains("workflow definition is missing"));
     }
 
+    #[test]
+    fn rejects_workflows_with_invalid_schedule() {
+        let definition = WorkflowDefinition {
+            workflow: "nightly".into(),
+            title: None,
+            description: None,
+            inputs: IndexMap::new(),
+            steps: vec![WorkflowStepDefinition {
+                id: "step".into(),
+                run: "apps:list".into(),
+                description: None,
+                depends_on: Vec::new(),
+                r#if: None,
+                with: IndexMap::new(),
+                body: serde_json::Value::Null,
+                repeat: None,
+                output_contract: None,
+            }],
+            final_output: None,
+            requires: None,
+            schedule: Some(oatty_types::WorkflowSchedule {
+                cron: "0 25 * * *".into(),
+                timezone: Default::default(),
+                inputs: IndexMap::new(),
+                environment: IndexMap::new(),
+                overlap: Default::default(),
+                enabled: true,
+            }),
+        };
+
+        let error = runtime_workflow_from_definition(&definition).expect_err("expected schedule error");
+        assert!(error.to_string().contains("invalid schedule"));
+    }
+
     #[test]
     fn rejects_workflows_without_steps() {
         let definition = WorkflowDefinition {
@@ -205,6 +245,7 @@ mod tests {
             steps: Vec::new(),
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let error = runtime_workflow_from_definition(&definition).expect_err("expected missing steps error");
@@ -245,6 +286,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let error = runtime_workflow_from_definition(&definition).expect_err("expected depends_on error");
@@ -294,6 +336,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let runtime = runtime_workflow_from_definition(&definition).expect("definition should be valid");
@@ -320,6 +363,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let error = runtime_workflow_from_definition(&definition).expect_err("expected strict operator error");
@@ -359,6 +403,7 @@ mod tests {
             ],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let runtime = runtime_workflow_from_definition(&definition).expect("definition should be valid");
@@ -390,6 +435,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let error = runtime_workflow_from_definition(&definition).expect_err("expected output root error");
@@ -421,6 +467,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let runtime = runtime_workflow_from_definition(&definition).expect("definition should be valid");
@@ -452,6 +499,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let runtime = runtime_workflow_from_definition(&definition).expect("definition should be valid");
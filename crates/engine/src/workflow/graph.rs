@@ -0,0 +1,359 @@
+//! Workflow step dependency graph.
+//!
+//! Steps execute in declaration order unless `depends_on` says otherwise, but templates can also
+//! read earlier outputs via `${{ steps.<id>... }}` without declaring a dependency. This module
+//! builds a DAG containing both kinds of edges, assigns each step to a layer (longest path from
+//! a root), and exports the result as Mermaid or Graphviz DOT for documentation and reviews.
+
+use oatty_types::workflow::{RuntimeWorkflow, WorkflowStepDefinition};
+use serde::Serialize;
+
+/// Origin of an edge between two steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowGraphEdgeKind {
+    /// Declared through the dependent step's `depends_on` list.
+    DependsOn,
+    /// Inferred from a `steps.<id>` reference in `with`, `body`, `if`, or `repeat.until`.
+    Reference,
+}
+
+/// Step node placed on a graph layer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WorkflowGraphNode {
+    /// Step identifier.
+    pub id: String,
+    /// Command executed by the step.
+    pub run: String,
+    /// Optional `if` condition guarding the step.
+    pub condition: Option<String>,
+    /// Zero-based layer; every edge points from a lower layer to a higher one.
+    pub layer: usize,
+}
+
+/// Directed edge from an upstream step to the step that consumes it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WorkflowGraphEdge {
+    /// Upstream step identifier.
+    pub from: String,
+    /// Downstream step identifier.
+    pub to: String,
+    /// Whether the edge is declared or inferred.
+    pub kind: WorkflowGraphEdgeKind,
+}
+
+/// Dependency graph for a workflow's steps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WorkflowGraph {
+    /// Identifier of the workflow the graph was built from.
+    pub workflow_id: String,
+    /// Nodes in declaration order.
+    pub nodes: Vec<WorkflowGraphNode>,
+    /// Edges in declaration order of the downstream step.
+    pub edges: Vec<WorkflowGraphEdge>,
+}
+
+impl WorkflowGraph {
+    /// Builds the graph for a runtime workflow.
+    pub fn from_workflow(workflow: &RuntimeWorkflow) -> Self {
+        Self::from_steps(&workflow.identifier, &workflow.steps)
+    }
+
+    /// Builds the graph from step definitions.
+    ///
+    /// Unknown dependencies and self references are ignored; a reference edge is only added
+    /// when the same pair is not already connected through `depends_on`.
+    pub fn from_steps(workflow_id: &str, steps: &[WorkflowStepDefinition]) -> Self {
+        let is_known_step = |candidate: &str| steps.iter().any(|step| step.id == candidate);
+        let mut edges: Vec<WorkflowGraphEdge> = Vec::new();
+
+        for step in steps {
+            for dependency in &step.depends_on {
+                if dependency != &step.id && is_known_step(dependency) && !has_edge(&edges, dependency, &step.id) {
+                    edges.push(WorkflowGraphEdge {
+                        from: dependency.clone(),
+                        to: step.id.clone(),
+                        kind: WorkflowGraphEdgeKind::DependsOn,
+                    });
+                }
+            }
+            for referenced in referenced_step_ids(step) {
+                if referenced != step.id && is_known_step(&referenced) && !has_edge(&edges, &referenced, &step.id) {
+                    edges.push(WorkflowGraphEdge {
+                        from: referenced,
+                        to: step.id.clone(),
+                        kind: WorkflowGraphEdgeKind::Reference,
+                    });
+                }
+            }
+        }
+
+        let mut layers = vec![0usize; steps.len()];
+        let index_of = |identifier: &str| steps.iter().position(|step| step.id == identifier);
+        // Longest-path relaxation; bounded by the step count so cycles cannot loop forever.
+        for _ in 0..steps.len() {
+            let mut changed = false;
+            for edge in &edges {
+                let (Some(from), Some(to)) = (index_of(&edge.from), index_of(&edge.to)) else {
+                    continue;
+                };
+                if layers[to] <= layers[from] && layers[from] + 1 < steps.len() {
+                    layers[to] = layers[from] + 1;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let nodes = steps
+            .iter()
+            .zip(layers)
+            .map(|(step, layer)| WorkflowGraphNode {
+                id: step.id.clone(),
+                run: step.run.clone(),
+                condition: step.r#if.clone(),
+                layer,
+            })
+            .collect();
+
+        Self {
+            workflow_id: workflow_id.to_string(),
+            nodes,
+            edges,
+        }
+    }
+
+    /// Groups nodes by layer, preserving declaration order within each layer.
+    pub fn layers(&self) -> Vec<Vec<&WorkflowGraphNode>> {
+        let layer_count = self.nodes.iter().map(|node| node.layer + 1).max().unwrap_or(0);
+        let mut layers = vec![Vec::new(); layer_count];
+        for node in &self.nodes {
+            layers[node.layer].push(node);
+        }
+        layers
+    }
+
+    /// Returns edges that end at the given step.
+    pub fn incoming<'a>(&'a self, step_id: &'a str) -> impl Iterator<Item = &'a WorkflowGraphEdge> + 'a {
+        self.edges.iter().filter(move |edge| edge.to == step_id)
+    }
+
+    /// Returns `true` when at least one edge starts at the given step.
+    pub fn has_outgoing(&self, step_id: &str) -> bool {
+        self.edges.iter().any(|edge| edge.from == step_id)
+    }
+
+    /// Renders the graph as a Mermaid flowchart.
+    ///
+    /// Conditional steps use the hexagon shape; inferred references use dotted arrows.
+    pub fn to_mermaid(&self) -> String {
+        let mut lines = vec!["flowchart TD".to_string()];
+        for node in &self.nodes {
+            let label = format!("{}<br/>{}", node.id, node.run).replace('"', "#quot;");
+            let node_id = mermaid_identifier(&node.id);
+            match node.condition.as_deref() {
+                Some(condition) => {
+                    let condition = condition.replace('"', "#quot;");
+                    lines.push(format!("    {node_id}{{{{\"{label}<br/>if {condition}\"}}}}"));
+                }
+                None => lines.push(format!("    {node_id}[\"{label}\"]")),
+            }
+        }
+        for edge in &self.edges {
+            let arrow = match edge.kind {
+                WorkflowGraphEdgeKind::DependsOn => "-->",
+                WorkflowGraphEdgeKind::Reference => "-.->",
+            };
+            lines.push(format!(
+                "    {} {arrow} {}",
+                mermaid_identifier(&edge.from),
+                mermaid_identifier(&edge.to)
+            ));
+        }
+        lines.join("\n")
+    }
+
+    /// Renders the graph as a Graphviz DOT digraph.
+    ///
+    /// Conditional steps are drawn as hexagons; inferred references use dashed edges.
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec![
+            format!("digraph \"{}\" {{", escape_dot(&self.workflow_id)),
+            "    rankdir=TB;".to_string(),
+            "    node [shape=box, style=rounded];".to_string(),
+        ];
+        for node in &self.nodes {
+            let mut label = format!("{}\\n{}", escape_dot(&node.id), escape_dot(&node.run));
+            let mut attributes = String::new();
+            if let Some(condition) = node.condition.as_deref() {
+                label.push_str(&format!("\\nif {}", escape_dot(condition)));
+                attributes.push_str(", shape=hexagon");
+            }
+            lines.push(format!("    \"{}\" [label=\"{label}\"{attributes}];", escape_dot(&node.id)));
+        }
+        for edge in &self.edges {
+            let style = match edge.kind {
+                WorkflowGraphEdgeKind::DependsOn => "",
+                WorkflowGraphEdgeKind::Reference => " [style=dashed]",
+            };
+            lines.push(format!(
+                "    \"{}\" -> \"{}\"{style};",
+                escape_dot(&edge.from),
+                escape_dot(&edge.to)
+            ));
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+fn has_edge(edges: &[WorkflowGraphEdge], from: &str, to: &str) -> bool {
+    edges.iter().any(|edge| edge.from == from && edge.to == to)
+}
+
+/// Collects step identifiers referenced as `steps.<id>` in templates and conditions.
+fn referenced_step_ids(step: &WorkflowStepDefinition) -> Vec<String> {
+    let mut sources = vec![serde_json::to_string(&step.with).unwrap_or_default(), step.body.to_string()];
+    sources.extend(step.r#if.clone());
+    if let Some(repeat) = step.repeat.as_ref() {
+        sources.extend(repeat.until.clone());
+    }
+
+    let mut identifiers: Vec<String> = Vec::new();
+    for source in &sources {
+        for (start, _) in source.match_indices("steps.") {
+            let preceded_by_identifier = source[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|character| character.is_ascii_alphanumeric() || character == '_');
+            if preceded_by_identifier {
+                continue;
+            }
+            let identifier: String = source[start + "steps.".len()..]
+                .chars()
+                .take_while(|character| character.is_ascii_alphanumeric() || matches!(character, '_' | '-'))
+                .collect();
+            if !identifier.is_empty() && !identifiers.contains(&identifier) {
+                identifiers.push(identifier);
+            }
+        }
+    }
+    identifiers
+}
+
+/// Mermaid node identifiers must avoid `-` and other punctuation that collides with edge syntax.
+fn mermaid_identifier(step_id: &str) -> String {
+    let sanitized: String = step_id
+        .chars()
+        .map(|character| if character.is_ascii_alphanumeric() { character } else { '_' })
+        .collect();
+    format!("step_{sanitized}")
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oatty_types::workflow::WorkflowDefinition;
+
+    use crate::workflow::document::runtime_workflow_from_definition;
+
+    fn graph_from_yaml(manifest: &str) -> WorkflowGraph {
+        let definition: WorkflowDefinition = serde_yaml::from_str(manifest).expect("parse manifest");
+        WorkflowGraph::from_workflow(&runtime_workflow_from_definition(&definition).expect("runtime workflow"))
+    }
+
+    const MANIFEST: &str = r#"
+workflow: provision
+steps:
+  - id: create-app
+    run: apps create
+  - id: create_db
+    run: addons create
+    depends_on: [create-app]
+  - id: attach
+    run: addons attach
+    with:
+      app: ${{ steps.create-app.id }}
+      addon: ${{ steps.create_db.id }}
+  - id: notify
+    run: webhooks send
+    if: steps.attach.state == "attached"
+"#;
+
+    #[test]
+    fn builds_layers_from_dependencies_and_references() {
+        let graph = graph_from_yaml(MANIFEST);
+
+        let layers: Vec<Vec<&str>> = graph
+            .layers()
+            .into_iter()
+            .map(|layer| layer.into_iter().map(|node| node.id.as_str()).collect())
+            .collect();
+        assert_eq!(layers, vec![vec!["create-app"], vec!["create_db"], vec!["attach"], vec!["notify"]]);
+
+        let kinds: Vec<(&str, &str, WorkflowGraphEdgeKind)> = graph
+            .edges
+            .iter()
+            .map(|edge| (edge.from.as_str(), edge.to.as_str(), edge.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("create-app", "create_db", WorkflowGraphEdgeKind::DependsOn),
+                ("create-app", "attach", WorkflowGraphEdgeKind::Reference),
+                ("create_db", "attach", WorkflowGraphEdgeKind::Reference),
+                ("attach", "notify", WorkflowGraphEdgeKind::Reference),
+            ]
+        );
+        assert!(graph.has_outgoing("attach"));
+        assert!(!graph.has_outgoing("notify"));
+    }
+
+    #[test]
+    fn exports_mermaid_with_sanitized_identifiers() {
+        let mermaid = graph_from_yaml(MANIFEST).to_mermaid();
+
+        assert!(mermaid.starts_with("flowchart TD\n"));
+        assert!(mermaid.contains("    step_create_app[\"create-app<br/>apps create\"]"));
+        assert!(mermaid.contains("    step_notify{{\"notify<br/>webhooks send<br/>if steps.attach.state == #quot;attached#quot;\"}}"));
+        assert!(mermaid.contains("    step_create_app --> step_create_db"));
+        assert!(mermaid.contains("    step_attach -.-> step_notify"));
+    }
+
+    #[test]
+    fn exports_dot_with_dashed_reference_edges() {
+        let dot = graph_from_yaml(MANIFEST).to_dot();
+
+        assert!(dot.starts_with("digraph \"provision\" {"));
+        assert!(dot.contains("    \"create-app\" -> \"create_db\";"));
+        assert!(dot.contains("    \"create_db\" -> \"attach\" [style=dashed];"));
+        assert!(dot.contains("shape=hexagon"));
+        assert!(dot.contains("if steps.attach.state == \\\"attached\\\""));
+        assert!(dot.ends_with('}'));
+    }
+
+    #[test]
+    fn ignores_self_references_and_unknown_steps() {
+        let graph = graph_from_yaml(
+            r#"
+workflow: poll
+steps:
+  - id: wait
+    run: builds info
+    with:
+      build: ${{ steps.missing.id }}
+    repeat:
+      until: steps.wait.status == "done"
+      every: 5s
+"#,
+        );
+        assert!(graph.edges.is_empty());
+        assert_eq!(graph.nodes[0].layer, 0);
+    }
+}
@@ -8,6 +8,10 @@
 pub mod bindings;
 pub mod condition_syntax;
 pub mod document;
+pub mod graph;
+pub mod lint;
 pub mod runner;
 pub mod runtime;
+pub mod schedule;
 pub mod state;
+pub mod testing;
@@ -408,6 +408,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         }
     }
 
@@ -477,6 +478,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let request = WorkflowRunRequest {
@@ -534,6 +536,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let mut step_outputs = HashMap::new();
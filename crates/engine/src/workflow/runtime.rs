@@ -116,6 +116,7 @@ mod tests {
             steps,
             final_output: None,
             requires: None,
+            schedule: None,
         }
     }
 
@@ -193,6 +194,7 @@ mod tests {
             steps: vec![step],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let spec = workflow_spec_from_runtime(&runtime);
@@ -229,6 +231,7 @@ mod tests {
             steps: vec![step],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let spec = workflow_spec_from_runtime(&runtime);
@@ -0,0 +1,482 @@
+//! Cron parsing and due-run planning for scheduled workflows.
+//!
+//! Workflows may declare a `schedule:` block with a five-field cron expression. This module
+//! parses those expressions, computes upcoming fire times in UTC or the local time zone, and
+//! tracks which workflows are due so a long-running scheduler can start them while honoring
+//! each schedule's overlap policy. The planner is deliberately free of I/O so callers own the
+//! clock, the runner, and history persistence.
+
+use anyhow::{Result, anyhow, bail};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+use indexmap::IndexMap;
+use oatty_types::workflow::{RuntimeWorkflow, WorkflowSchedule, WorkflowScheduleOverlapPolicy, WorkflowScheduleTimezone};
+
+/// Upper bound on the number of days searched when computing the next fire time.
+///
+/// Four years covers every valid combination, including `29 2 *` style leap-day schedules.
+const MAX_SEARCH_DAYS: i64 = 366 * 4 + 1;
+
+const MONTH_NAMES: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// Parsed five-field cron expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    days_of_week: u8,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    /// Parses a cron expression such as `0 2 * * 1-5` or a macro such as `@daily`.
+    ///
+    /// Fields support `*`, single values, ranges (`1-5`), steps (`*/15`, `0-30/10`), lists
+    /// (`1,15`), and three-letter month and weekday names. Day-of-week accepts both `0` and `7`
+    /// for Sunday. When both day fields are restricted (neither starts with `*`), a time matches if
+    /// either one matches.
+    pub fn parse(expression: &str) -> Result<Self> {
+        let trimmed = expression.trim();
+        let expanded = match trimmed.to_ascii_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *".to_string(),
+            "@monthly" => "0 0 1 * *".to_string(),
+            "@weekly" => "0 0 * * 0".to_string(),
+            "@daily" | "@midnight" => "0 0 * * *".to_string(),
+            "@hourly" => "0 * * * *".to_string(),
+            other if other.starts_with('@') => bail!("unsupported cron macro '{}'", trimmed),
+            _ => trimmed.to_string(),
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        if fields.len() != 5 {
+            bail!(
+                "cron expression '{}' must have five fields (minute hour day-of-month month day-of-week)",
+                trimmed
+            );
+        }
+
+        let minutes = parse_field(fields[0], 0, 59, None).map_err(|error| anyhow!("minute field: {error}"))?;
+        let hours = parse_field(fields[1], 0, 23, None).map_err(|error| anyhow!("hour field: {error}"))?;
+        let days_of_month = parse_field(fields[2], 1, 31, None).map_err(|error| anyhow!("day-of-month field: {error}"))?;
+        let months = parse_field(fields[3], 1, 12, Some(&MONTH_NAMES)).map_err(|error| anyhow!("month field: {error}"))?;
+        let mut days_of_week = parse_field(fields[4], 0, 7, Some(&WEEKDAY_NAMES)).map_err(|error| anyhow!("day-of-week field: {error}"))?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            minutes,
+            hours: hours as u32,
+            days_of_month: days_of_month as u32,
+            months: months as u16,
+            days_of_week: days_of_week as u8,
+            day_of_month_restricted: !fields[2].starts_with('*'),
+            day_of_week_restricted: !fields[4].starts_with('*'),
+        })
+    }
+
+    /// Returns the first fire time strictly after `after`, evaluated in `after`'s time zone.
+    ///
+    /// Local times skipped by a daylight-saving transition are ignored; ambiguous local times
+    /// fire once, at their earliest instant.
+    pub fn next_after<Tz: TimeZone>(&self, after: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let timezone = after.timezone();
+        let start = after.naive_local().with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let search_limit = start + Duration::days(MAX_SEARCH_DAYS);
+        let mut candidate = start;
+
+        while candidate < search_limit {
+            if !self.matches_month(candidate.month()) {
+                candidate = first_minute_of_next_month(candidate)?;
+                continue;
+            }
+            if !self.matches_day(candidate.date()) {
+                candidate = candidate.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.matches_hour(candidate.hour()) {
+                candidate = candidate.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+            if !self.matches_minute(candidate.minute()) {
+                candidate += Duration::minutes(1);
+                continue;
+            }
+            if let Some(resolved) = timezone.from_local_datetime(&candidate).earliest()
+                && resolved > *after
+            {
+                return Some(resolved);
+            }
+            candidate += Duration::minutes(1);
+        }
+        None
+    }
+
+    fn matches_minute(&self, minute: u32) -> bool {
+        self.minutes & (1 << minute) != 0
+    }
+
+    fn matches_hour(&self, hour: u32) -> bool {
+        self.hours & (1 << hour) != 0
+    }
+
+    fn matches_month(&self, month: u32) -> bool {
+        self.months & (1 << month) != 0
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day_of_month = self.days_of_month & (1 << date.day()) != 0;
+        let day_of_week = self.days_of_week & (1 << date.weekday().num_days_from_sunday()) != 0;
+        match (self.day_of_month_restricted, self.day_of_week_restricted) {
+            (true, true) => day_of_month || day_of_week,
+            (true, false) => day_of_month,
+            (false, true) => day_of_week,
+            (false, false) => true,
+        }
+    }
+}
+
+/// Computes the next fire time for a workflow schedule, expressed in UTC.
+pub fn next_scheduled_run(schedule: &WorkflowSchedule, after: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+    let cron = CronSchedule::parse(&schedule.cron)?;
+    Ok(next_fire_time(&cron, schedule.timezone, after))
+}
+
+fn next_fire_time(cron: &CronSchedule, timezone: WorkflowScheduleTimezone, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    match timezone {
+        WorkflowScheduleTimezone::Utc => cron.next_after(&after),
+        WorkflowScheduleTimezone::Local => cron.next_after(&after.with_timezone(&Local)).map(|next| next.with_timezone(&Utc)),
+    }
+}
+
+fn first_minute_of_next_month(current: NaiveDateTime) -> Option<NaiveDateTime> {
+    let (year, month) = if current.month() == 12 {
+        (current.year() + 1, 1)
+    } else {
+        (current.year(), current.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)
+}
+
+fn parse_field(field: &str, min: u32, max: u32, names: Option<&[&str]>) -> Result<u64> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        if part.is_empty() {
+            bail!("empty list entry in '{}'", field);
+        }
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse().map_err(|_| anyhow!("invalid step '{}'", step))?;
+                if step == 0 {
+                    bail!("step must be greater than zero");
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((low, high)) = range.split_once('-') {
+            (parse_value(low, min, max, names)?, parse_value(high, min, max, names)?)
+        } else {
+            let value = parse_value(range, min, max, names)?;
+            // `5/15` means "every 15 starting at 5", matching common cron implementations.
+            if part.contains('/') { (value, max) } else { (value, value) }
+        };
+
+        if start > end {
+            bail!("range '{}' is reversed", range);
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_value(raw: &str, min: u32, max: u32, names: Option<&[&str]>) -> Result<u32> {
+    let lowered = raw.to_ascii_lowercase();
+    let named = names.and_then(|names| names.iter().position(|name| *name == lowered)).map(|index| {
+        // Month names are one-based; weekday names start at Sunday = 0.
+        if min == 1 { index as u32 + 1 } else { index as u32 }
+    });
+    let value = match named {
+        Some(value) => value,
+        None => raw.parse::<u32>().map_err(|_| anyhow!("invalid value '{}'", raw))?,
+    };
+    if value < min || value > max {
+        bail!("value {} is outside {}-{}", value, min, max);
+    }
+    Ok(value)
+}
+
+/// Outcome of checking a scheduled workflow that has come due.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScheduleDecision {
+    /// The workflow should start now.
+    Start {
+        workflow: Box<RuntimeWorkflow>,
+        due_at: DateTime<Utc>,
+    },
+    /// The due run was dropped because a previous run is still executing.
+    Skipped { workflow_id: String, due_at: DateTime<Utc> },
+    /// The due run will start once the previous run finishes.
+    Queued { workflow_id: String, due_at: DateTime<Utc> },
+}
+
+/// Scheduling state for one workflow.
+#[derive(Debug, Clone)]
+pub struct ScheduledWorkflowState {
+    /// Workflow executed when the schedule fires.
+    pub workflow: RuntimeWorkflow,
+    /// Next time the schedule fires, if any.
+    pub next_due: Option<DateTime<Utc>>,
+    /// Whether a run for this workflow is in progress.
+    pub running: bool,
+    /// Runs waiting for the in-progress run to finish.
+    pub queued: usize,
+    cron: CronSchedule,
+    timezone: WorkflowScheduleTimezone,
+    overlap: WorkflowScheduleOverlapPolicy,
+}
+
+/// Tracks due times and in-flight runs for every enabled workflow schedule.
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowSchedulePlanner {
+    entries: IndexMap<String, ScheduledWorkflowState>,
+}
+
+impl WorkflowSchedulePlanner {
+    /// Builds a planner from runtime workflows, ignoring unscheduled and disabled workflows.
+    ///
+    /// Returns the planner together with `(workflow_id, error)` pairs for schedules whose cron
+    /// expression could not be parsed.
+    pub fn new(workflows: impl IntoIterator<Item = RuntimeWorkflow>, now: DateTime<Utc>) -> (Self, Vec<(String, String)>) {
+        let mut entries = IndexMap::new();
+        let mut errors = Vec::new();
+        for workflow in workflows {
+            let Some(schedule) = workflow.schedule.clone().filter(|schedule| schedule.enabled) else {
+                continue;
+            };
+            match CronSchedule::parse(&schedule.cron) {
+                Ok(cron) => {
+                    let next_due = next_fire_time(&cron, schedule.timezone, now);
+                    entries.insert(
+                        workflow.identifier.clone(),
+                        ScheduledWorkflowState {
+                            workflow,
+                            next_due,
+                            running: false,
+                            queued: 0,
+                            cron,
+                            timezone: schedule.timezone,
+                            overlap: schedule.overlap,
+                        },
+                    );
+                }
+                Err(error) => errors.push((workflow.identifier.clone(), error.to_string())),
+            }
+        }
+        (Self { entries }, errors)
+    }
+
+    /// Returns the scheduled workflows in declaration order.
+    pub fn entries(&self) -> impl Iterator<Item = &ScheduledWorkflowState> {
+        self.entries.values()
+    }
+
+    /// Returns true when no enabled schedules are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the earliest upcoming fire time across all schedules.
+    pub fn next_wakeup(&self) -> Option<DateTime<Utc>> {
+        self.entries.values().filter_map(|entry| entry.next_due).min()
+    }
+
+    /// Collects decisions for every schedule due at or before `now` and advances their due times.
+    ///
+    /// Several missed fire times for the same workflow (for example after the machine slept)
+    /// collapse into a single decision.
+    pub fn poll_due(&mut self, now: DateTime<Utc>) -> Vec<ScheduleDecision> {
+        let mut decisions = Vec::new();
+        for (workflow_id, entry) in self.entries.iter_mut() {
+            let Some(due_at) = entry.next_due.filter(|due_at| *due_at <= now) else {
+                continue;
+            };
+            entry.next_due = next_fire_time(&entry.cron, entry.timezone, now);
+
+            if !entry.running {
+                entry.running = true;
+                decisions.push(ScheduleDecision::Start {
+                    workflow: Box::new(entry.workflow.clone()),
+                    due_at,
+                });
+                continue;
+            }
+
+            match entry.overlap {
+                WorkflowScheduleOverlapPolicy::Skip => decisions.push(ScheduleDecision::Skipped {
+                    workflow_id: workflow_id.clone(),
+                    due_at,
+                }),
+                WorkflowScheduleOverlapPolicy::Queue => {
+                    entry.queued += 1;
+                    decisions.push(ScheduleDecision::Queued {
+                        workflow_id: workflow_id.clone(),
+                        due_at,
+                    });
+                }
+            }
+        }
+        decisions
+    }
+
+    /// Marks the in-progress run as finished and returns the workflow to start next when a run
+    /// was queued behind it.
+    pub fn mark_finished(&mut self, workflow_id: &str) -> Option<RuntimeWorkflow> {
+        let entry = self.entries.get_mut(workflow_id)?;
+        if entry.queued > 0 {
+            entry.queued -= 1;
+            entry.running = true;
+            return Some(entry.workflow.clone());
+        }
+        entry.running = false;
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::IndexMap;
+    use oatty_types::workflow::WorkflowStepDefinition;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0).unwrap()
+    }
+
+    fn scheduled_workflow(identifier: &str, cron: &str, overlap: WorkflowScheduleOverlapPolicy) -> RuntimeWorkflow {
+        RuntimeWorkflow {
+            identifier: identifier.to_string(),
+            title: None,
+            description: None,
+            inputs: IndexMap::new(),
+            steps: vec![WorkflowStepDefinition {
+                id: "noop".into(),
+                run: "apps:list".into(),
+                description: None,
+                depends_on: Vec::new(),
+                r#if: None,
+                with: IndexMap::new(),
+                body: serde_json::Value::Null,
+                repeat: None,
+                output_contract: None,
+            }],
+            final_output: None,
+            requires: None,
+            schedule: Some(WorkflowSchedule {
+                cron: cron.to_string(),
+                timezone: WorkflowScheduleTimezone::Utc,
+                inputs: IndexMap::new(),
+                environment: IndexMap::new(),
+                overlap,
+                enabled: true,
+            }),
+        }
+    }
+
+    #[test]
+    fn parses_lists_ranges_steps_and_names() {
+        let cron = CronSchedule::parse("*/15 9-17 * jan,jul mon-fri").expect("valid cron");
+        let after = utc(2024, 1, 5, 17, 50); // Friday
+        assert_eq!(cron.next_after(&after), Some(utc(2024, 1, 8, 9, 0)));
+        assert_eq!(cron.next_after(&utc(2024, 1, 8, 9, 0)), Some(utc(2024, 1, 8, 9, 15)));
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        assert!(CronSchedule::parse("* * *").is_err());
+        assert!(CronSchedule::parse("61 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("5-1 * * * *").is_err());
+        assert!(CronSchedule::parse("@often").is_err());
+    }
+
+    #[test]
+    fn macros_expand_to_standard_fields() {
+        let cron = CronSchedule::parse("@daily").expect("valid macro");
+        assert_eq!(cron.next_after(&utc(2024, 3, 10, 12, 0)), Some(utc(2024, 3, 11, 0, 0)));
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either_day() {
+        // Fires on the 1st of the month and on Sundays.
+        let cron = CronSchedule::parse("0 0 1 * 7").expect("valid cron");
+        assert_eq!(cron.next_after(&utc(2024, 5, 28, 0, 0)), Some(utc(2024, 6, 1, 0, 0)));
+        assert_eq!(cron.next_after(&utc(2024, 6, 1, 0, 0)), Some(utc(2024, 6, 2, 0, 0)));
+    }
+
+    #[test]
+    fn stepped_day_fields_are_unrestricted_wildcards() {
+        // `*/2` in day-of-month must not turn the Monday restriction into an either-day match.
+        let cron = CronSchedule::parse("0 0 */2 * mon").expect("valid cron");
+        assert_eq!(cron.next_after(&utc(2024, 6, 1, 0, 0)), Some(utc(2024, 6, 3, 0, 0)));
+        assert_eq!(cron.next_after(&utc(2024, 6, 3, 0, 0)), Some(utc(2024, 6, 10, 0, 0)));
+    }
+
+    #[test]
+    fn leap_day_schedules_are_found() {
+        let cron = CronSchedule::parse("0 12 29 2 *").expect("valid cron");
+        assert_eq!(cron.next_after(&utc(2025, 3, 1, 0, 0)), Some(utc(2028, 2, 29, 12, 0)));
+    }
+
+    #[test]
+    fn planner_skips_overlapping_runs() {
+        let workflows = vec![scheduled_workflow("audit", "*/5 * * * *", WorkflowScheduleOverlapPolicy::Skip)];
+        let (mut planner, errors) = WorkflowSchedulePlanner::new(workflows, utc(2024, 1, 1, 0, 1));
+        assert!(errors.is_empty());
+        assert_eq!(planner.next_wakeup(), Some(utc(2024, 1, 1, 0, 5)));
+
+        let decisions = planner.poll_due(utc(2024, 1, 1, 0, 5));
+        assert!(matches!(decisions.as_slice(), [ScheduleDecision::Start { .. }]));
+
+        let decisions = planner.poll_due(utc(2024, 1, 1, 0, 10));
+        assert!(matches!(decisions.as_slice(), [ScheduleDecision::Skipped { .. }]));
+        assert!(planner.mark_finished("audit").is_none());
+    }
+
+    #[test]
+    fn planner_queues_overlapping_runs() {
+        let workflows = vec![scheduled_workflow("sync", "* * * * *", WorkflowScheduleOverlapPolicy::Queue)];
+        let (mut planner, _) = WorkflowSchedulePlanner::new(workflows, utc(2024, 1, 1, 0, 0));
+
+        planner.poll_due(utc(2024, 1, 1, 0, 1));
+        let decisions = planner.poll_due(utc(2024, 1, 1, 0, 2));
+        assert!(matches!(decisions.as_slice(), [ScheduleDecision::Queued { .. }]));
+
+        let queued = planner.mark_finished("sync").expect("queued run should start");
+        assert_eq!(queued.identifier, "sync");
+        assert!(planner.mark_finished("sync").is_none());
+    }
+
+    #[test]
+    fn planner_reports_invalid_and_ignores_disabled_schedules() {
+        let mut disabled = scheduled_workflow("disabled", "* * * * *", WorkflowScheduleOverlapPolicy::Skip);
+        disabled.schedule.as_mut().unwrap().enabled = false;
+        let workflows = vec![
+            disabled,
+            scheduled_workflow("broken", "not cron", WorkflowScheduleOverlapPolicy::Skip),
+        ];
+
+        let (planner, errors) = WorkflowSchedulePlanner::new(workflows, utc(2024, 1, 1, 0, 0));
+        assert!(planner.is_empty());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, "broken");
+    }
+}
@@ -375,6 +375,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         }
     }
 
@@ -555,6 +556,7 @@ mod tests {
             steps: Vec::new(),
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let mut state = WorkflowRunState::new(workflow);
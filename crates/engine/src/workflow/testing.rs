@@ -0,0 +1,714 @@
+//! Declarative workflow test suites.
+//!
+//! A test suite pins workflow inputs, stubs the output or error of each `run` command, and
+//! asserts on the resulting step statuses, rendered `with`/`body` payloads, and
+//! `final_output`. Suites execute through the regular executor with a [`StubCommandRunner`],
+//! so branching `if` logic and dependency handling behave exactly as they would against live
+//! APIs. Results can be rendered as JSON (via `serde`) or as a JUnit XML report for CI.
+
+use std::{sync::Mutex, time::Instant};
+
+use anyhow::{Result, anyhow};
+use indexmap::IndexMap;
+use oatty_types::workflow::RuntimeWorkflow;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    RunContext,
+    executor::{CommandRunner, StepStatus},
+    resolve::interpolate_value,
+    workflow::state::WorkflowRunState,
+};
+
+/// Test suite file targeting a single workflow.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowTestSuite {
+    /// Workflow under test: a manifest path relative to the suite file or a registry identifier.
+    pub workflow: String,
+    /// Test cases executed in declaration order.
+    #[serde(default)]
+    pub cases: Vec<WorkflowTestCase>,
+}
+
+/// Single test case with pinned inputs, command stubs, and expectations.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowTestCase {
+    /// Human-readable case name used in reports.
+    pub name: String,
+    /// Input values supplied to the run. Text values are coerced to the declared input type.
+    #[serde(default)]
+    pub inputs: IndexMap<String, Value>,
+    /// Environment variables exposed as `env.*`.
+    #[serde(default)]
+    pub environment: IndexMap<String, String>,
+    /// Stubbed command responses. Commands without a matching stub fail their step.
+    #[serde(default)]
+    pub stubs: Vec<CommandStub>,
+    /// Assertions evaluated after the run.
+    #[serde(default)]
+    pub expect: WorkflowTestExpectations,
+}
+
+/// Stubbed response for a `run` command.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommandStub {
+    /// Command identifier matched against the step's `run` value.
+    pub run: String,
+    /// Optional subset matcher applied to the rendered `with` payload.
+    #[serde(default)]
+    pub with: Option<Value>,
+    /// Output returned when the stub matches.
+    #[serde(default)]
+    pub output: Option<Value>,
+    /// Error message returned instead of an output.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Successive responses for repeated calls; the last entry is reused once exhausted.
+    #[serde(default)]
+    pub sequence: Vec<StubResponse>,
+}
+
+/// One response within a stub sequence.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct StubResponse {
+    /// Output returned for this call.
+    #[serde(default)]
+    pub output: Option<Value>,
+    /// Error message returned for this call.
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Assertions evaluated against a finished test run.
+///
+/// Payload and `final_output` expectations use subset matching: every key in an expected
+/// object must be present with a matching value, while arrays and scalars must be equal.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct WorkflowTestExpectations {
+    /// Expected overall outcome.
+    #[serde(default)]
+    pub status: Option<WorkflowTestRunStatus>,
+    /// Expected status per step identifier.
+    #[serde(default)]
+    pub steps: IndexMap<String, WorkflowTestStepStatus>,
+    /// Expected rendered `with` payload per step identifier.
+    #[serde(default)]
+    pub with: IndexMap<String, Value>,
+    /// Expected rendered `body` payload per step identifier.
+    #[serde(default)]
+    pub body: IndexMap<String, Value>,
+    /// Expected rendered `final_output`.
+    #[serde(default)]
+    pub final_output: Option<Value>,
+}
+
+/// Overall run outcome asserted by a test case.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowTestRunStatus {
+    /// No step failed.
+    Succeeded,
+    /// At least one step failed.
+    Failed,
+}
+
+/// Step outcome asserted by a test case.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowTestStepStatus {
+    /// The step ran and its command returned successfully.
+    Succeeded,
+    /// The step ran and failed.
+    Failed,
+    /// The step did not run because of its condition or dependencies.
+    Skipped,
+}
+
+impl WorkflowTestRunStatus {
+    /// Returns the serialized name of the status.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl WorkflowTestStepStatus {
+    /// Returns the serialized name of the status.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+        }
+    }
+}
+
+impl From<StepStatus> for WorkflowTestStepStatus {
+    fn from(status: StepStatus) -> Self {
+        match status {
+            StepStatus::Succeeded => Self::Succeeded,
+            StepStatus::Failed => Self::Failed,
+            StepStatus::Skipped => Self::Skipped,
+        }
+    }
+}
+
+/// Aggregated results for a test suite.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowTestReport {
+    /// Identifier of the workflow under test.
+    pub workflow: String,
+    /// Number of passing cases.
+    pub passed: usize,
+    /// Number of failing cases.
+    pub failed: usize,
+    /// Per-case results in execution order.
+    pub cases: Vec<WorkflowTestCaseResult>,
+}
+
+/// Result for a single test case.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowTestCaseResult {
+    /// Case name.
+    pub name: String,
+    /// Whether every assertion held.
+    pub passed: bool,
+    /// Human-readable assertion failures.
+    pub failures: Vec<String>,
+    /// Observed status per step, in execution order.
+    pub steps: IndexMap<String, WorkflowTestStepStatus>,
+    /// Wall-clock duration in milliseconds.
+    pub duration_ms: u128,
+}
+
+impl WorkflowTestReport {
+    /// Returns true when every case passed.
+    pub fn is_success(&self) -> bool {
+        self.failed == 0
+    }
+
+    /// Renders the report as a JUnit XML document.
+    pub fn to_junit_xml(&self) -> String {
+        let total_seconds: f64 = self.cases.iter().map(|case| case.duration_ms as f64 / 1000.0).sum();
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+            self.cases.len(),
+            self.failed,
+            total_seconds
+        ));
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+            escape_xml(&self.workflow),
+            self.cases.len(),
+            self.failed,
+            total_seconds
+        ));
+        for case in &self.cases {
+            let open_tag = format!(
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+                escape_xml(&case.name),
+                escape_xml(&self.workflow),
+                case.duration_ms as f64 / 1000.0
+            );
+            if case.passed {
+                xml.push_str(&open_tag);
+                xml.push_str("/>\n");
+                continue;
+            }
+            xml.push_str(&open_tag);
+            xml.push_str(">\n");
+            xml.push_str(&format!(
+                "      <failure message=\"{}\">{}</failure>\n",
+                escape_xml(case.failures.first().map(String::as_str).unwrap_or("assertion failed")),
+                escape_xml(&case.failures.join("\n"))
+            ));
+            xml.push_str("    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n</testsuites>\n");
+        xml
+    }
+}
+
+/// Runs every case in a suite against the provided workflow.
+pub fn run_workflow_test_suite(workflow: &RuntimeWorkflow, suite: &WorkflowTestSuite) -> WorkflowTestReport {
+    let cases: Vec<WorkflowTestCaseResult> = suite.cases.iter().map(|case| run_workflow_test_case(workflow, case)).collect();
+    let passed = cases.iter().filter(|case| case.passed).count();
+    WorkflowTestReport {
+        workflow: workflow.identifier.clone(),
+        passed,
+        failed: cases.len() - passed,
+        cases,
+    }
+}
+
+/// Runs a single test case and evaluates its expectations.
+pub fn run_workflow_test_case(workflow: &RuntimeWorkflow, case: &WorkflowTestCase) -> WorkflowTestCaseResult {
+    let started_at = Instant::now();
+    let mut failures = Vec::new();
+    let mut observed_steps = IndexMap::new();
+
+    match execute_case(workflow, case) {
+        Ok(execution) => {
+            for (step_id, status) in &execution.statuses {
+                observed_steps.insert(step_id.clone(), *status);
+            }
+            failures.extend(evaluate_expectations(&case.expect, &execution));
+            for unmatched in execution.unmatched_commands {
+                failures.push(format!("no stub matched command '{unmatched}'"));
+            }
+        }
+        Err(error) => failures.push(format!("{error:#}")),
+    }
+
+    WorkflowTestCaseResult {
+        name: case.name.clone(),
+        passed: failures.is_empty(),
+        failures,
+        steps: observed_steps,
+        duration_ms: started_at.elapsed().as_millis(),
+    }
+}
+
+struct CaseExecution {
+    statuses: IndexMap<String, WorkflowTestStepStatus>,
+    rendered_with: IndexMap<String, Value>,
+    rendered_body: IndexMap<String, Value>,
+    final_output: Option<Value>,
+    unmatched_commands: Vec<String>,
+}
+
+fn execute_case(workflow: &RuntimeWorkflow, case: &WorkflowTestCase) -> Result<CaseExecution> {
+    let mut state = WorkflowRunState::new(workflow.clone());
+    state
+        .run_context
+        .environment_variables
+        .extend(case.environment.iter().map(|(key, value)| (key.clone(), value.clone())));
+
+    for (input_name, raw_value) in &case.inputs {
+        let value = match workflow.inputs.get(input_name) {
+            Some(definition) => definition
+                .coerce_value(raw_value.clone())
+                .map_err(|error| anyhow!("invalid value for input '{input_name}': {error}"))?,
+            None => raw_value.clone(),
+        };
+        state.set_input_value(input_name, value);
+    }
+    state.apply_input_defaults();
+
+    let runner = StubCommandRunner::new(case.stubs.clone());
+    let results = state.execute_with_runner(&runner)?;
+    let (calls, unmatched_commands) = runner.into_parts();
+
+    // The executor runs steps sequentially, so calls are attributed to steps in execution order.
+    // Each executed step issued one call per attempt; the last one carries its final payload.
+    let mut rendered_with = IndexMap::new();
+    let mut rendered_body = IndexMap::new();
+    let mut remaining_calls = calls.into_iter();
+    for result in results.iter().filter(|result| result.status != StepStatus::Skipped) {
+        let Some(call) = remaining_calls.by_ref().take(result.attempts as usize).last() else {
+            continue;
+        };
+        if let Some(with) = call.with {
+            rendered_with.insert(result.id.clone(), with);
+        }
+        if let Some(body) = call.body {
+            rendered_body.insert(result.id.clone(), body);
+        }
+    }
+
+    Ok(CaseExecution {
+        statuses: results
+            .iter()
+            .map(|result| (result.id.clone(), WorkflowTestStepStatus::from(result.status)))
+            .collect(),
+        rendered_with,
+        rendered_body,
+        final_output: workflow
+            .final_output
+            .as_ref()
+            .map(|template| interpolate_value(template, &state.run_context)),
+        unmatched_commands,
+    })
+}
+
+fn evaluate_expectations(expect: &WorkflowTestExpectations, execution: &CaseExecution) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    if let Some(expected_status) = expect.status {
+        let any_failed = execution.statuses.values().any(|status| *status == WorkflowTestStepStatus::Failed);
+        let actual_status = if any_failed {
+            WorkflowTestRunStatus::Failed
+        } else {
+            WorkflowTestRunStatus::Succeeded
+        };
+        if actual_status != expected_status {
+            failures.push(format!(
+                "expected run status {}, got {}",
+                expected_status.as_str(),
+                actual_status.as_str()
+            ));
+        }
+    }
+
+    for (step_id, expected_status) in &expect.steps {
+        match execution.statuses.get(step_id) {
+            Some(actual_status) if actual_status == expected_status => {}
+            Some(actual_status) => failures.push(format!(
+                "step '{step_id}': expected {}, got {}",
+                expected_status.as_str(),
+                actual_status.as_str()
+            )),
+            None => failures.push(format!("step '{step_id}' does not exist in the workflow")),
+        }
+    }
+
+    for (label, expected_payloads, actual_payloads) in [
+        ("with", &expect.with, &execution.rendered_with),
+        ("body", &expect.body, &execution.rendered_body),
+    ] {
+        for (step_id, expected) in expected_payloads {
+            match actual_payloads.get(step_id) {
+                Some(actual) => {
+                    if let Some(mismatch) = find_subset_mismatch(expected, actual, "") {
+                        failures.push(format!("step '{step_id}' {label}: {mismatch}"));
+                    }
+                }
+                None => failures.push(format!("step '{step_id}' did not render a {label} payload")),
+            }
+        }
+    }
+
+    if let Some(expected) = expect.final_output.as_ref() {
+        match execution.final_output.as_ref() {
+            Some(actual) => {
+                if let Some(mismatch) = find_subset_mismatch(expected, actual, "") {
+                    failures.push(format!("final_output: {mismatch}"));
+                }
+            }
+            None => failures.push("workflow does not declare final_output".to_string()),
+        }
+    }
+
+    failures
+}
+
+/// Returns a description of the first place `actual` does not satisfy `expected`.
+fn find_subset_mismatch(expected: &Value, actual: &Value, path: &str) -> Option<String> {
+    let location = if path.is_empty() { "value" } else { path };
+    match (expected, actual) {
+        (Value::Object(expected_map), Value::Object(actual_map)) => expected_map.iter().find_map(|(key, expected_value)| {
+            let child_path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+            match actual_map.get(key) {
+                Some(actual_value) => find_subset_mismatch(expected_value, actual_value, &child_path),
+                None => Some(format!("missing key '{child_path}'")),
+            }
+        }),
+        _ if expected == actual => None,
+        _ => Some(format!("{location}: expected {expected}, got {actual}")),
+    }
+}
+
+/// Command invocation captured by [`StubCommandRunner`] with the payload it was rendered with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StubCall {
+    pub run: String,
+    pub with: Option<Value>,
+    pub body: Option<Value>,
+}
+
+/// Command runner that answers `run` commands from declarative stubs.
+///
+/// The first stub whose `run` matches (and whose `with` matcher, when present, is a subset of
+/// the rendered payload) answers the call. Every call is recorded with its rendered payload, and
+/// calls without a matching stub fail and are listed so test reports can point at missing fixtures.
+pub struct StubCommandRunner {
+    stubs: Vec<CommandStub>,
+    call_counts: Mutex<Vec<usize>>,
+    calls: Mutex<Vec<StubCall>>,
+    unmatched: Mutex<Vec<String>>,
+}
+
+impl StubCommandRunner {
+    /// Creates a runner backed by the provided stubs.
+    pub fn new(stubs: Vec<CommandStub>) -> Self {
+        let call_counts = Mutex::new(vec![0; stubs.len()]);
+        Self {
+            stubs,
+            call_counts,
+            calls: Mutex::new(Vec::new()),
+            unmatched: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Consumes the runner and returns every recorded call, in order, plus the commands that had
+    /// no matching stub.
+    pub fn into_parts(self) -> (Vec<StubCall>, Vec<String>) {
+        (
+            self.calls.into_inner().unwrap_or_default(),
+            self.unmatched.into_inner().unwrap_or_default(),
+        )
+    }
+}
+
+impl CommandRunner for StubCommandRunner {
+    fn run(&self, run: &str, with: Option<&Value>, body: Option<&Value>, _run_context: &RunContext) -> Result<Value> {
+        if let Ok(mut calls) = self.calls.lock() {
+            calls.push(StubCall {
+                run: run.to_string(),
+                with: with.cloned(),
+                body: body.cloned(),
+            });
+        }
+        let empty_with = Value::Object(Default::default());
+        let rendered_with = with.unwrap_or(&empty_with);
+        let Some(index) = self.stubs.iter().position(|stub| {
+            stub.run == run
+                && stub
+                    .with
+                    .as_ref()
+                    .is_none_or(|matcher| find_subset_mismatch(matcher, rendered_with, "").is_none())
+        }) else {
+            if let Ok(mut unmatched) = self.unmatched.lock() {
+                unmatched.push(run.to_string());
+            }
+            return Err(anyhow!("no stub matches command '{run}'"));
+        };
+
+        let stub = &self.stubs[index];
+        let call_index = {
+            let mut counts = self.call_counts.lock().map_err(|_| anyhow!("stub runner state is poisoned"))?;
+            let current = counts[index];
+            counts[index] += 1;
+            current
+        };
+
+        let (output, error) = match stub.sequence.get(call_index).or_else(|| stub.sequence.last()) {
+            Some(response) => (response.output.as_ref(), response.error.as_ref()),
+            None => (stub.output.as_ref(), stub.error.as_ref()),
+        };
+        match error {
+            Some(message) => Err(anyhow!(message.clone())),
+            None => Ok(output.cloned().unwrap_or(Value::Null)),
+        }
+    }
+}
+
+fn escape_xml(raw: &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for character in raw.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oatty_types::workflow::WorkflowDefinition;
+    use serde_json::json;
+
+    use crate::workflow::document::runtime_workflow_from_definition;
+
+    fn certificate_workflow() -> RuntimeWorkflow {
+        let definition: WorkflowDefinition = serde_yaml::from_str(
+            r#"
+workflow: cert_audit
+inputs:
+  app:
+    type: string
+  days:
+    type: integer
+steps:
+  - id: list
+    run: certs:list
+    with:
+      app: ${{ inputs.app }}
+  - id: renew
+    run: certs:renew
+    depends_on: [list]
+    if: steps.list.expiring == true
+    body:
+      app: ${{ inputs.app }}
+      days: ${{ inputs.days }}
+final_output:
+  app: ${{ inputs.app }}
+  expiring: ${{ steps.list.expiring }}
+"#,
+        )
+        .expect("workflow parses");
+        runtime_workflow_from_definition(&definition).expect("workflow is valid")
+    }
+
+    fn suite(yaml: &str) -> WorkflowTestSuite {
+        serde_yaml::from_str(yaml).expect("suite parses")
+    }
+
+    #[test]
+    fn passing_cases_cover_both_branches() {
+        let suite = suite(
+            r#"
+workflow: cert_audit
+cases:
+  - name: renews expiring certificates
+    inputs: { app: billing, days: "30" }
+    stubs:
+      - run: certs:list
+        output: { expiring: true }
+      - run: certs:renew
+        output: { renewed: true }
+    expect:
+      status: succeeded
+      steps: { list: succeeded, renew: succeeded }
+      with:
+        list: { app: billing }
+      body:
+        renew: { app: billing }
+      final_output: { app: billing }
+  - name: skips renewal when nothing expires
+    inputs: { app: billing, days: 30 }
+    stubs:
+      - run: certs:list
+        output: { expiring: false }
+    expect:
+      steps: { renew: skipped }
+"#,
+        );
+
+        let report = run_workflow_test_suite(&certificate_workflow(), &suite);
+        assert!(report.is_success(), "unexpected failures: {:?}", report.cases);
+        assert_eq!(report.passed, 2);
+    }
+
+    #[test]
+    fn failing_expectations_and_errors_are_reported() {
+        let suite = suite(
+            r#"
+workflow: cert_audit
+cases:
+  - name: listing fails
+    inputs: { app: billing }
+    stubs:
+      - run: certs:list
+        error: service unavailable
+    expect:
+      status: succeeded
+      steps: { list: succeeded }
+"#,
+        );
+
+        let report = run_workflow_test_suite(&certificate_workflow(), &suite);
+        assert_eq!(report.failed, 1);
+        let case = &report.cases[0];
+        assert_eq!(case.steps.get("list"), Some(&WorkflowTestStepStatus::Failed));
+        assert_eq!(case.steps.get("renew"), Some(&WorkflowTestStepStatus::Skipped));
+        assert_eq!(case.failures.len(), 2);
+    }
+
+    #[test]
+    fn unstubbed_commands_fail_the_case() {
+        let suite = suite(
+            r#"
+workflow: cert_audit
+cases:
+  - name: missing stub
+    inputs: { app: billing }
+"#,
+        );
+
+        let report = run_workflow_test_suite(&certificate_workflow(), &suite);
+        assert!(report.cases[0].failures.iter().any(|failure| failure.contains("certs:list")));
+    }
+
+    #[test]
+    fn stub_sequences_advance_per_call() {
+        let runner = StubCommandRunner::new(vec![CommandStub {
+            run: "status:get".into(),
+            sequence: vec![
+                StubResponse {
+                    output: Some(json!({ "state": "pending" })),
+                    error: None,
+                },
+                StubResponse {
+                    output: Some(json!({ "state": "ready" })),
+                    error: None,
+                },
+            ],
+            ..Default::default()
+        }]);
+        let context = RunContext::default();
+
+        let first = runner.run("status:get", None, None, &context).expect("first call");
+        let second = runner.run("status:get", None, None, &context).expect("second call");
+        let third = runner.run("status:get", None, None, &context).expect("third call");
+        assert_eq!(first["state"], "pending");
+        assert_eq!(second["state"], "ready");
+        assert_eq!(third["state"], "ready");
+    }
+
+    #[test]
+    fn stub_runner_records_payloads_at_call_time() {
+        let runner = StubCommandRunner::new(vec![CommandStub {
+            run: "apps:update".into(),
+            output: Some(json!({})),
+            ..Default::default()
+        }]);
+        let context = RunContext::default();
+
+        runner
+            .run(
+                "apps:update",
+                Some(&json!({ "app": "billing" })),
+                Some(&json!({ "size": 1 })),
+                &context,
+            )
+            .expect("first call");
+        runner
+            .run(
+                "apps:update",
+                Some(&json!({ "app": "billing" })),
+                Some(&json!({ "size": 2 })),
+                &context,
+            )
+            .expect("second call");
+        let _ = runner.run("apps:delete", None, None, &context);
+
+        let (calls, unmatched) = runner.into_parts();
+        assert_eq!(calls.len(), 3);
+        assert_eq!(calls[0].body, Some(json!({ "size": 1 })));
+        assert_eq!(calls[1].body, Some(json!({ "size": 2 })));
+        assert_eq!(calls[2].run, "apps:delete");
+        assert_eq!(unmatched, vec!["apps:delete".to_string()]);
+    }
+
+    #[test]
+    fn junit_report_escapes_failures() {
+        let report = WorkflowTestReport {
+            workflow: "cert_audit".into(),
+            passed: 0,
+            failed: 1,
+            cases: vec![WorkflowTestCaseResult {
+                name: "a <b> case".into(),
+                passed: false,
+                failures: vec!["expected \"x\" & got y".into()],
+                steps: IndexMap::new(),
+                duration_ms: 5,
+            }],
+        };
+
+        let xml = report.to_junit_xml();
+        assert!(xml.contains("<testcase name=\"a &lt;b&gt; case\""));
+        assert!(xml.contains("expected &quot;x&quot; &amp; got y"));
+        assert!(xml.contains("failures=\"1\""));
+    }
+}
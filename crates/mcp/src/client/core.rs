@@ -3,24 +3,29 @@
 use std::{process::Stdio, sync::Arc, time::Duration};
 
 use anyhow::Result;
-use rmcp::model::InitializeResult;
+use rmcp::model::{
+    GetPromptRequestParams, GetPromptResult, InitializeResult, Prompt, ReadResourceRequestParams, ReadResourceResult, Resource,
+    ResourceTemplate, ServerCapabilities, SubscribeRequestParams,
+};
 use rmcp::{
-    RoleClient,
     model::CallToolRequestParams,
-    service::{RunningService, ServiceExt as _},
+    service::ServiceExt as _,
     transport::{StreamableHttpClientTransport, TokioChildProcess, streamable_http_client::StreamableHttpClientTransportConfig},
 };
-use tokio::time::timeout;
+use tokio::{sync::broadcast, time::timeout};
 
 use crate::{
     config::McpServer,
     logging::LogManager,
-    types::{HealthStatus, McpToolMetadata, PluginStatus},
+    types::{HealthStatus, McpPluginContent, McpToolMetadata, PluginStatus},
 };
 
 use super::{
+    gateway::ClientGatewayEvent,
+    handler::{PluginClientHandler, PluginService, advertises, fetch_plugin_content},
     health::HealthCheckResult,
     http::{build_http_client_with_auth, resolve_streamable_endpoint},
+    oauth::{OAUTH_AUTHORIZATION_REQUIRED, OAuthHttpClient, static_bearer_token},
     stdio::{build_stdio_command, spawn_stderr_logger},
 };
 
@@ -36,11 +41,15 @@ pub struct McpClient {
     /// Aggregated health info for UI.
     pub(crate) health: HealthStatus,
     /// Underlying rmcp running service when connected.
-    pub(crate) service: Option<RunningService<RoleClient, ()>>,
+    pub(crate) service: Option<PluginService>,
     /// Shared log manager for capturing plugin logs (e.g., stderr).
     pub(crate) log_manager: Arc<LogManager>,
     /// Last known list of tools exposed by the plugin.
     pub(crate) tools: Arc<Vec<McpToolMetadata>>,
+    /// Last known resources, resource templates, and prompts exposed by the plugin.
+    pub(crate) content: Arc<McpPluginContent>,
+    /// Gateway channel that receives resource and prompt notifications from the plugin.
+    pub(crate) events: Option<broadcast::Sender<ClientGatewayEvent>>,
 }
 
 /// Maximum amount of time to wait for a tool invocation before returning a timeout error.
@@ -57,15 +66,23 @@ impl McpClient {
             service: None,
             log_manager,
             tools: Arc::new(Vec::new()),
+            content: Arc::new(McpPluginContent::default()),
+            events: None,
         }
     }
 
+    /// Forward plugin notifications (resource updates, list changes) to the given gateway channel.
+    pub(crate) fn with_events(mut self, events: broadcast::Sender<ClientGatewayEvent>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
     /// Connect using rmcp via stdio or http transport.
     pub async fn connect(&mut self) -> Result<Arc<Vec<McpToolMetadata>>> {
         self.status = PluginStatus::Starting;
         let start_time = std::time::Instant::now();
 
-        let service: RunningService<RoleClient, ()> = if self.server.is_stdio() {
+        let service: PluginService = if self.server.is_stdio() {
             self.connect_stdio().await?
         } else if self.server.is_http() {
             self.connect_http().await?
@@ -74,6 +91,7 @@ impl McpClient {
         };
         self.service = Some(service);
         let tools = self.refresh_tools().await?;
+        self.refresh_content().await;
         self.status = PluginStatus::Running;
         self.health.mark_healthy();
         self.health.handshake_latency = Some(start_time.elapsed().as_millis() as u64);
@@ -88,6 +106,7 @@ impl McpClient {
         self.status = PluginStatus::Stopped;
         self.health.mark_unhealthy("Disconnected".to_string());
         self.tools = Arc::new(Vec::new());
+        self.content = Arc::new(McpPluginContent::default());
         Ok(())
     }
 
@@ -96,6 +115,11 @@ impl McpClient {
         Arc::clone(&self.tools)
     }
 
+    /// Latest discovered resources, resource templates, and prompts for this client.
+    pub fn content(&self) -> Arc<McpPluginContent> {
+        Arc::clone(&self.content)
+    }
+
     /// Refresh and return the current tool snapshot from the active MCP service.
     pub async fn refresh_tool_snapshot(&mut self) -> Result<Arc<Vec<McpToolMetadata>>> {
         self.refresh_tools().await
@@ -126,7 +150,7 @@ impl McpClient {
     }
 
     /// Connect via stdio using a spawned child process.
-    async fn connect_stdio(&self) -> Result<RunningService<RoleClient, ()>> {
+    async fn connect_stdio(&self) -> Result<PluginService> {
         let command = build_stdio_command(&self.server)?;
         // Use builder to capture stderr for logging
         let (transport, stderr_opt) = TokioChildProcess::builder(command).stderr(Stdio::piped()).spawn()?;
@@ -135,16 +159,43 @@ impl McpClient {
             spawn_stderr_logger(self.name.clone(), self.log_manager.clone(), stderr);
         }
 
-        Ok(().serve(transport).await?)
+        Ok(self.handler().serve(transport).await?)
     }
 
     /// Connect via Streamable HTTP using rmcp's reqwest transport.
-    async fn connect_http(&self) -> Result<RunningService<RoleClient, ()>> {
+    ///
+    /// OAuth plugins without a static token connect through [`OAuthHttpClient`], which supplies
+    /// and refreshes the stored credentials.
+    async fn connect_http(&self) -> Result<PluginService> {
         let endpoint = resolve_streamable_endpoint(&self.server)?;
-        let http_client = build_http_client_with_auth(&self.server).await?;
+        let http_client = build_http_client_with_auth(&self.server)?;
         let config = StreamableHttpClientTransportConfig::with_uri(endpoint);
-        let transport = StreamableHttpClientTransport::with_client(http_client, config);
-        Ok(().serve(transport).await?)
+        if !self.server.uses_oauth() || static_bearer_token(&self.server).is_some() {
+            let transport = StreamableHttpClientTransport::with_client(http_client, config);
+            return Ok(self.handler().serve(transport).await?);
+        }
+
+        let Some(oauth_client) = OAuthHttpClient::from_store(&self.server, http_client).await? else {
+            anyhow::bail!(
+                "{OAUTH_AUTHORIZATION_REQUIRED} for plugin '{}'; authorize it from the plugin editor",
+                self.name
+            );
+        };
+        let transport = StreamableHttpClientTransport::with_client(oauth_client.clone(), config);
+        match self.handler().serve(transport).await {
+            Ok(service) => Ok(service),
+            Err(_) if oauth_client.was_rejected() => {
+                anyhow::bail!(
+                    "{OAUTH_AUTHORIZATION_REQUIRED} for plugin '{}'; the stored token was rejected",
+                    self.name
+                )
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn handler(&self) -> PluginClientHandler {
+        PluginClientHandler::new(self.name.clone(), self.events.clone())
     }
 
     /// Fetch the current tool list from the active service and update the local snapshot.
@@ -167,6 +218,13 @@ impl McpClient {
         Ok(snapshot)
     }
 
+    /// Fetch resources, resource templates, and prompts from the active service and update the local snapshot.
+    async fn refresh_content(&mut self) {
+        if let Some(service) = self.service.as_ref() {
+            self.content = Arc::new(fetch_plugin_content(&self.name, service.peer()).await);
+        }
+    }
+
     /// Invoke a tool provided by this plugin and return the raw MCP response payload.
     pub async fn call_tool(
         &self,
@@ -181,9 +239,106 @@ impl McpClient {
         Self::call_tool_with_service(service, tool_name, arguments).await
     }
 
+    /// List the resources published by this plugin; empty when it does not advertise resources.
+    pub async fn list_resources(&self) -> Result<Vec<Resource>> {
+        let service = self.connected_service()?;
+        if !Self::advertises(service, |capabilities| capabilities.resources.is_some()) {
+            return Ok(Vec::new());
+        }
+        self.with_request_timeout("resources/list", service.list_all_resources()).await
+    }
+
+    /// List the resource templates published by this plugin; empty when it does not advertise resources.
+    pub async fn list_resource_templates(&self) -> Result<Vec<ResourceTemplate>> {
+        let service = self.connected_service()?;
+        if !Self::advertises(service, |capabilities| capabilities.resources.is_some()) {
+            return Ok(Vec::new());
+        }
+        self.with_request_timeout("resources/templates/list", service.list_all_resource_templates())
+            .await
+    }
+
+    /// Subscribe to update notifications for a resource.
+    ///
+    /// Returns `false` without contacting the server when it does not advertise resource subscriptions.
+    pub async fn subscribe_resource(&self, uri: &str) -> Result<bool> {
+        let service = self.connected_service()?;
+        let supports_subscribe = Self::advertises(service, |capabilities| {
+            capabilities
+                .resources
+                .as_ref()
+                .is_some_and(|resources| resources.subscribe.unwrap_or(false))
+        });
+        if !supports_subscribe {
+            return Ok(false);
+        }
+        let request = SubscribeRequestParams {
+            meta: None,
+            uri: uri.to_string(),
+        };
+        self.with_request_timeout("resources/subscribe", service.subscribe(request)).await?;
+        Ok(true)
+    }
+
+    /// Read a resource published by this plugin.
+    pub async fn read_resource(&self, uri: &str) -> Result<ReadResourceResult> {
+        let service = self.connected_service()?;
+        let request = ReadResourceRequestParams {
+            meta: None,
+            uri: uri.to_string(),
+        };
+        self.with_request_timeout("resources/read", service.read_resource(request)).await
+    }
+
+    /// List the prompts published by this plugin; empty when it does not advertise prompts.
+    pub async fn list_prompts(&self) -> Result<Vec<Prompt>> {
+        let service = self.connected_service()?;
+        if !Self::advertises(service, |capabilities| capabilities.prompts.is_some()) {
+            return Ok(Vec::new());
+        }
+        self.with_request_timeout("prompts/list", service.list_all_prompts()).await
+    }
+
+    /// Render a prompt published by this plugin.
+    pub async fn get_prompt(
+        &self,
+        prompt_name: &str,
+        arguments: Option<serde_json::Map<String, serde_json::Value>>,
+    ) -> Result<GetPromptResult> {
+        let service = self.connected_service()?;
+        let request = GetPromptRequestParams {
+            meta: None,
+            name: prompt_name.to_string(),
+            arguments,
+        };
+        self.with_request_timeout("prompts/get", service.get_prompt(request)).await
+    }
+
+    fn connected_service(&self) -> Result<&PluginService> {
+        self.service
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("plugin '{}' is not connected", self.name))
+    }
+
+    fn advertises(service: &PluginService, capability: impl Fn(&ServerCapabilities) -> bool) -> bool {
+        advertises(service.peer(), capability)
+    }
+
+    async fn with_request_timeout<T, E: std::fmt::Display>(&self, method: &str, request: impl Future<Output = Result<T, E>>) -> Result<T> {
+        match timeout(TOOL_INVOCATION_TIMEOUT, request).await {
+            Ok(Ok(result)) => Ok(result),
+            Ok(Err(err)) => Err(anyhow::anyhow!("{method} failed for '{}': {err}", self.name)),
+            Err(_) => Err(anyhow::anyhow!(
+                "{method} for '{}' timed out after {:?}",
+                self.name,
+                TOOL_INVOCATION_TIMEOUT
+            )),
+        }
+    }
+
     /// Invoke a tool using a previously captured running service handle.
-    pub async fn call_tool_with_service(
-        service: &RunningService<RoleClient, ()>,
+    pub(crate) async fn call_tool_with_service(
+        service: &PluginService,
         tool_name: &str,
         arguments: &serde_json::Map<String, serde_json::Value>,
     ) -> Result<rmcp::model::CallToolResult> {
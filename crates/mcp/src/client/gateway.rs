@@ -6,7 +6,7 @@ use std::{
 };
 
 use anyhow::Result;
-use rmcp::model::InitializeResult;
+use rmcp::model::{GetPromptResult, InitializeResult, Prompt, ReadResourceResult, Resource, ResourceTemplate};
 use serde_json::Map as JsonMap;
 use thiserror::Error;
 use tokio::{
@@ -17,7 +17,7 @@ use tokio::{
 use crate::{
     config::McpConfig,
     logging::{AuditEntry, LogManager},
-    types::{HealthStatus, McpToolMetadata, PluginStatus},
+    types::{HealthStatus, McpPluginContent, McpToolMetadata, PluginStatus},
 };
 
 use super::core::McpClient;
@@ -123,6 +123,10 @@ impl McpClientGateway {
                     name: stopped.clone(),
                     tools: Arc::new(Vec::new()),
                 });
+                let _ = self.event_tx.send(ClientGatewayEvent::ContentUpdated {
+                    name: stopped.clone(),
+                    content: Arc::new(McpPluginContent::default()),
+                });
             }
         }
         Ok(())
@@ -161,7 +165,7 @@ impl McpClientGateway {
         let _ = self.event_tx.send(ClientGatewayEvent::Starting { name: plugin_name.clone() });
 
         let connect_result = async {
-            let mut client = McpClient::new(plugin_name.clone(), server, self.log_manager.clone());
+            let mut client = McpClient::new(plugin_name.clone(), server, self.log_manager.clone()).with_events(self.event_tx.clone());
             client
                 .connect()
                 .await
@@ -177,6 +181,7 @@ impl McpClientGateway {
 
         match connect_result {
             Ok((client, tools)) => {
+                let content = client.content();
                 self.active_clients
                     .lock()
                     .await
@@ -187,6 +192,10 @@ impl McpClientGateway {
                     name: plugin_name.clone(),
                     tools,
                 });
+                let _ = self.event_tx.send(ClientGatewayEvent::ContentUpdated {
+                    name: plugin_name.clone(),
+                    content,
+                });
 
                 let _ = self
                     .log_manager
@@ -235,6 +244,10 @@ impl McpClientGateway {
                 name: name.to_string(),
                 tools: Arc::new(Vec::new()),
             });
+            let _ = self.event_tx.send(ClientGatewayEvent::ContentUpdated {
+                name: name.to_string(),
+                content: Arc::new(McpPluginContent::default()),
+            });
         }
         // Audit stop event (best-effort)
         let _ = self
@@ -357,6 +370,79 @@ impl McpClientGateway {
             .await
             .map_err(|err| ClientGatewayError::ConnectionError { message: err.to_string() })
     }
+
+    /// List the resources published by a running plugin.
+    pub async fn list_resources(&self, name: &str) -> Result<Vec<Resource>, ClientGatewayError> {
+        let handle = self.running_client(name).await?;
+        let client = handle.lock().await;
+        client
+            .list_resources()
+            .await
+            .map_err(|err| ClientGatewayError::ConnectionError { message: err.to_string() })
+    }
+
+    /// List the resource templates published by a running plugin.
+    pub async fn list_resource_templates(&self, name: &str) -> Result<Vec<ResourceTemplate>, ClientGatewayError> {
+        let handle = self.running_client(name).await?;
+        let client = handle.lock().await;
+        client
+            .list_resource_templates()
+            .await
+            .map_err(|err| ClientGatewayError::ConnectionError { message: err.to_string() })
+    }
+
+    /// Subscribe to update notifications for a resource published by a running plugin.
+    ///
+    /// Returns `false` when the plugin does not support resource subscriptions.
+    pub async fn subscribe_resource(&self, name: &str, uri: &str) -> Result<bool, ClientGatewayError> {
+        let handle = self.running_client(name).await?;
+        let client = handle.lock().await;
+        client
+            .subscribe_resource(uri)
+            .await
+            .map_err(|err| ClientGatewayError::ConnectionError { message: err.to_string() })
+    }
+
+    /// Read a resource published by a running plugin.
+    pub async fn read_resource(&self, name: &str, uri: &str) -> Result<ReadResourceResult, ClientGatewayError> {
+        let handle = self.running_client(name).await?;
+        let client = handle.lock().await;
+        client
+            .read_resource(uri)
+            .await
+            .map_err(|err| ClientGatewayError::ConnectionError { message: err.to_string() })
+    }
+
+    /// List the prompts published by a running plugin.
+    pub async fn list_prompts(&self, name: &str) -> Result<Vec<Prompt>, ClientGatewayError> {
+        let handle = self.running_client(name).await?;
+        let client = handle.lock().await;
+        client
+            .list_prompts()
+            .await
+            .map_err(|err| ClientGatewayError::ConnectionError { message: err.to_string() })
+    }
+
+    /// Render a prompt published by a running plugin.
+    pub async fn get_prompt(
+        &self,
+        name: &str,
+        prompt: &str,
+        arguments: Option<JsonMap<String, serde_json::Value>>,
+    ) -> Result<GetPromptResult, ClientGatewayError> {
+        let handle = self.running_client(name).await?;
+        let client = handle.lock().await;
+        client
+            .get_prompt(prompt, arguments)
+            .await
+            .map_err(|err| ClientGatewayError::ConnectionError { message: err.to_string() })
+    }
+
+    async fn running_client(&self, name: &str) -> Result<Arc<Mutex<McpClient>>, ClientGatewayError> {
+        self.get_client(name)
+            .await
+            .ok_or_else(|| ClientGatewayError::ClientNotFound { name: name.to_string() })
+    }
 }
 
 /// Lifecycle events emitted by [`McpClientGateway`] for plugin state transitions.
@@ -368,6 +454,10 @@ pub enum ClientGatewayEvent {
     Started { name: String },
     /// The available tool list for a plugin changed.
     ToolsUpdated { name: String, tools: Arc<Vec<McpToolMetadata>> },
+    /// The resources, resource templates, or prompts exposed by a plugin changed.
+    ContentUpdated { name: String, content: Arc<McpPluginContent> },
+    /// A plugin reported that a subscribed resource changed.
+    ResourceUpdated { name: String, uri: String },
     /// A plugin failed to start.
     StartFailed { name: String, error: String },
     /// A plugin is in the process of shutting down.
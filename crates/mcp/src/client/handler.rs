@@ -0,0 +1,97 @@
+//! Client-side handler for notifications sent by plugin servers.
+
+use std::sync::Arc;
+
+use rmcp::{
+    ClientHandler, Peer, RoleClient,
+    model::{ResourceUpdatedNotificationParam, ServerCapabilities},
+    service::{NotificationContext, RunningService},
+};
+use tokio::sync::broadcast;
+
+use crate::types::McpPluginContent;
+
+use super::gateway::ClientGatewayEvent;
+
+/// Running rmcp service for a plugin connection.
+pub(crate) type PluginService = RunningService<RoleClient, PluginClientHandler>;
+
+/// Forwards resource and prompt notifications from a plugin to gateway listeners.
+#[derive(Debug, Clone)]
+pub(crate) struct PluginClientHandler {
+    /// Plugin that owns the connection.
+    plugin_name: String,
+    /// Gateway event channel; notifications are dropped when absent.
+    events: Option<broadcast::Sender<ClientGatewayEvent>>,
+}
+
+impl PluginClientHandler {
+    pub(crate) fn new(plugin_name: String, events: Option<broadcast::Sender<ClientGatewayEvent>>) -> Self {
+        Self { plugin_name, events }
+    }
+
+    fn emit(&self, event: ClientGatewayEvent) {
+        if let Some(events) = &self.events {
+            let _ = events.send(event);
+        }
+    }
+
+    async fn refresh_content(&self, peer: &Peer<RoleClient>) {
+        let content = fetch_plugin_content(&self.plugin_name, peer).await;
+        self.emit(ClientGatewayEvent::ContentUpdated {
+            name: self.plugin_name.clone(),
+            content: Arc::new(content),
+        });
+    }
+}
+
+impl ClientHandler for PluginClientHandler {
+    async fn on_resource_updated(&self, params: ResourceUpdatedNotificationParam, _context: NotificationContext<RoleClient>) {
+        self.emit(ClientGatewayEvent::ResourceUpdated {
+            name: self.plugin_name.clone(),
+            uri: params.uri,
+        });
+    }
+
+    async fn on_resource_list_changed(&self, context: NotificationContext<RoleClient>) {
+        self.refresh_content(&context.peer).await;
+    }
+
+    async fn on_prompt_list_changed(&self, context: NotificationContext<RoleClient>) {
+        self.refresh_content(&context.peer).await;
+    }
+}
+
+/// Whether the connected server advertises a capability.
+pub(crate) fn advertises(peer: &Peer<RoleClient>, capability: impl Fn(&ServerCapabilities) -> bool) -> bool {
+    peer.peer_info().is_some_and(|info| capability(&info.capabilities))
+}
+
+/// Fetch resources, resource templates, and prompts from a plugin.
+///
+/// Listings are best-effort: capabilities the server does not advertise are skipped and failures are
+/// logged so a misbehaving listing never prevents the plugin from starting.
+pub(crate) async fn fetch_plugin_content(plugin_name: &str, peer: &Peer<RoleClient>) -> McpPluginContent {
+    let (resources, resource_templates) = if advertises(peer, |capabilities| capabilities.resources.is_some()) {
+        let resources = peer.list_all_resources().await.unwrap_or_else(|err| {
+            tracing::warn!(plugin = %plugin_name, error = %err, "resources/list failed");
+            Vec::new()
+        });
+        let templates = peer.list_all_resource_templates().await.unwrap_or_else(|err| {
+            tracing::debug!(plugin = %plugin_name, error = %err, "resources/templates/list failed");
+            Vec::new()
+        });
+        (resources, templates)
+    } else {
+        (Vec::new(), Vec::new())
+    };
+    let prompts = if advertises(peer, |capabilities| capabilities.prompts.is_some()) {
+        peer.list_all_prompts().await.unwrap_or_else(|err| {
+            tracing::warn!(plugin = %plugin_name, error = %err, "prompts/list failed");
+            Vec::new()
+        })
+    } else {
+        Vec::new()
+    };
+    McpPluginContent::from_listings(resources, resource_templates, prompts)
+}
@@ -3,9 +3,10 @@
 use crate::config::McpServer;
 use anyhow::Result;
 use oatty_types::EnvVar;
-use oatty_util::{SecretsBackend, secrets_backend};
 use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderName, HeaderValue};
 
+use super::oauth::static_bearer_token;
+
 /// Resolve the fully-qualified endpoint used for Streamable HTTP transport.
 pub(crate) fn resolve_streamable_endpoint(server: &McpServer) -> Result<String> {
     server
@@ -15,29 +16,22 @@ pub(crate) fn resolve_streamable_endpoint(server: &McpServer) -> Result<String>
         .ok_or_else(|| anyhow::anyhow!("base_url required for HTTP transport"))
 }
 
-/// Build a reqwest client injecting configured headers and OAuth bearer if available.
-pub(crate) async fn build_http_client_with_auth(server: &McpServer) -> Result<reqwest::Client> {
+/// Build a reqwest client injecting configured headers and a static OAuth bearer if available.
+///
+/// Plugins authorized through the interactive OAuth flow get their bearer from
+/// [`super::oauth::OAuthHttpClient`] instead, which also handles refresh.
+pub(crate) fn build_http_client_with_auth(server: &McpServer) -> Result<reqwest::Client> {
     let mut headers = HeaderMap::new();
     for EnvVar { key, value, .. } in &server.headers {
         if let (Ok(name), Ok(value)) = (HeaderName::try_from(key.as_str()), HeaderValue::try_from(value.as_str())) {
             headers.insert(name, value);
         }
     }
-    // OAuth bearer from explicit auth token first, then keyring fallback.
-    if let Some(auth) = &server.auth
-        && (auth.scheme.eq_ignore_ascii_case("oauth") || auth.scheme.eq_ignore_ascii_case("oauth2"))
+    // Explicit OAuth token first, then a raw token stored by earlier releases.
+    if let Some(token) = static_bearer_token(server)
+        && let Ok(value) = HeaderValue::from_str(&format!("Bearer {}", token))
     {
-        let oauth_token = if let Some(configured_token) = auth.token.clone() {
-            Some(configured_token)
-        } else {
-            load_oauth_token_from_keyring(server).await?
-        };
-
-        if let Some(token) = oauth_token
-            && let Ok(value) = HeaderValue::from_str(&format!("Bearer {}", token))
-        {
-            headers.insert(AUTHORIZATION, value);
-        }
+        headers.insert(AUTHORIZATION, value);
     }
     let client = reqwest::Client::builder()
         .default_headers(headers)
@@ -46,29 +40,6 @@ pub(crate) async fn build_http_client_with_auth(server: &McpServer) -> Result<re
     Ok(client)
 }
 
-/// Retrieve a bearer token from the OS keyring for the given server base URL.
-async fn load_oauth_token_from_keyring(server: &McpServer) -> Result<Option<String>> {
-    if secrets_backend() == SecretsBackend::Environment {
-        return Ok(None);
-    }
-    // Compose a stable key based on base_url host + path
-    let service = "oatty-mcp-oauth";
-    let account = if let Some(url) = &server.base_url {
-        format!("{}://{}{}", url.scheme(), url.host_str().unwrap_or(""), url.path())
-    } else {
-        "stdio".to_string()
-    };
-    let entry = keyring::Entry::new(service, &account)?;
-    match entry.get_password() {
-        Ok(p) => Ok(Some(p)),
-        Err(keyring::Error::NoEntry) => Ok(None),
-        Err(e) => {
-            tracing::warn!("keyring error: {}", e);
-            Ok(None)
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,8 +5,10 @@
 
 mod core;
 mod gateway;
+mod handler;
 mod health;
 mod http;
+pub(crate) mod oauth;
 mod stdio;
 
 pub use core::McpClient;
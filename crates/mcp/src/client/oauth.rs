@@ -0,0 +1,619 @@
+//! OAuth 2.1 authorization for HTTP plugins, following the MCP authorization specification.
+//!
+//! Authorization is interactive and runs once per server:
+//!
+//! 1. Discover the authorization server through protected-resource metadata (RFC 9728), falling
+//!    back to authorization-server metadata (RFC 8414) at the plugin's origin.
+//! 2. Register a public client dynamically (RFC 7591) with a loopback redirect URI.
+//! 3. Open the browser on the authorization-code + PKCE (S256) URL and wait for the redirect on
+//!    a short-lived listener bound to `127.0.0.1`.
+//! 4. Exchange the code and store the credentials in the OS keystore.
+//!
+//! Connections load the stored credentials, refresh them before they expire, and refresh once
+//! more when the server answers `401` before reporting that authorization is required again.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    net::{Ipv4Addr, SocketAddr},
+    sync::{
+        Arc, Mutex as StdMutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+use async_trait::async_trait;
+use axum::{
+    Router,
+    extract::{Query, State},
+    response::Html,
+    routing::get,
+};
+use futures_util::stream::BoxStream;
+use http::{HeaderName, HeaderValue};
+use oatty_util::{SecretsBackend, secrets_backend};
+use once_cell::sync::Lazy;
+use rmcp::{
+    model::ClientJsonRpcMessage,
+    transport::{
+        AuthClient, AuthError, AuthorizationManager, AuthorizationSession,
+        auth::{CredentialStore, StoredCredentials},
+        streamable_http_client::{SseError, StreamableHttpClient, StreamableHttpError, StreamableHttpPostResponse},
+    },
+};
+use serde::Deserialize;
+use tokio::{net::TcpListener, sync::oneshot};
+use tracing::warn;
+
+use crate::{config::McpServer, types::AuthStatus};
+
+/// Keystore service that holds OAuth credentials for HTTP plugins.
+const OAUTH_KEYRING_SERVICE: &str = "oatty-mcp-oauth";
+/// Client name sent during dynamic client registration.
+const OAUTH_CLIENT_NAME: &str = "Oatty";
+/// Path the loopback listener accepts the authorization redirect on.
+const REDIRECT_PATH: &str = "/callback";
+/// How long to wait for the user to finish authorizing in the browser.
+const AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Message prefix used when a plugin needs (re-)authorization; status reporting matches on it.
+pub(crate) const OAUTH_AUTHORIZATION_REQUIRED: &str = "OAuth authorization required";
+
+/// Credentials kept in memory when the environment secrets backend disables keystore access.
+static SESSION_CREDENTIALS: Lazy<StdMutex<HashMap<String, String>>> = Lazy::new(|| StdMutex::new(HashMap::new()));
+
+/// Keystore account for a plugin: the endpoint URL without query or fragment.
+fn credential_account(server: &McpServer) -> Option<String> {
+    let url = server.base_url.as_ref()?;
+    let authority = match url.port() {
+        Some(port) => format!("{}:{port}", url.host_str().unwrap_or("")),
+        None => url.host_str().unwrap_or("").to_string(),
+    };
+    Some(format!("{}://{}{}", url.scheme(), authority, url.path()))
+}
+
+/// Credential store backed by the OS keystore, one entry per plugin endpoint.
+///
+/// With `OATTY_SECRETS_BACKEND=env` the keystore is never touched and credentials only live for
+/// the current process.
+#[derive(Debug, Clone)]
+pub(crate) struct KeyringCredentialStore {
+    account: String,
+}
+
+impl KeyringCredentialStore {
+    pub(crate) fn for_server(server: &McpServer) -> Result<Self> {
+        let account = credential_account(server).ok_or_else(|| anyhow!("base_url required for OAuth authorization"))?;
+        Ok(Self { account })
+    }
+
+    fn read_raw(&self) -> Result<Option<String>, AuthError> {
+        if secrets_backend() == SecretsBackend::Environment {
+            let credentials = SESSION_CREDENTIALS
+                .lock()
+                .map_err(|_| keystore_error("credential cache poisoned"))?;
+            return Ok(credentials.get(&self.account).cloned());
+        }
+        let entry = keyring::Entry::new(OAUTH_KEYRING_SERVICE, &self.account).map_err(keystore_error)?;
+        match entry.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(error) => Err(keystore_error(error)),
+        }
+    }
+
+    fn write_raw(&self, value: Option<&str>) -> Result<(), AuthError> {
+        if secrets_backend() == SecretsBackend::Environment {
+            let mut credentials = SESSION_CREDENTIALS
+                .lock()
+                .map_err(|_| keystore_error("credential cache poisoned"))?;
+            match value {
+                Some(value) => credentials.insert(self.account.clone(), value.to_string()),
+                None => credentials.remove(&self.account),
+            };
+            return Ok(());
+        }
+        let entry = keyring::Entry::new(OAUTH_KEYRING_SERVICE, &self.account).map_err(keystore_error)?;
+        match value {
+            Some(value) => entry.set_password(value).map_err(keystore_error),
+            None => match entry.delete_credential() {
+                Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+                Err(error) => Err(keystore_error(error)),
+            },
+        }
+    }
+
+    /// Bearer token stored by earlier releases, which kept the raw token instead of credentials.
+    fn legacy_token(&self) -> Option<String> {
+        let raw = self.read_raw().ok().flatten()?;
+        serde_json::from_str::<StoredCredentials>(&raw).is_err().then_some(raw)
+    }
+
+    /// Whether the store holds usable credentials, in either format.
+    fn has_credentials(&self) -> bool {
+        match self.read_raw() {
+            Ok(Some(raw)) => serde_json::from_str::<StoredCredentials>(&raw).map_or(true, |stored| stored.token_response.is_some()),
+            Ok(None) => false,
+            Err(error) => {
+                warn!(account = %self.account, error = %error, "failed to read OAuth credentials");
+                false
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CredentialStore for KeyringCredentialStore {
+    async fn load(&self) -> Result<Option<StoredCredentials>, AuthError> {
+        Ok(self.read_raw()?.and_then(|raw| serde_json::from_str(&raw).ok()))
+    }
+
+    async fn save(&self, credentials: StoredCredentials) -> Result<(), AuthError> {
+        let raw = serde_json::to_string(&credentials).map_err(keystore_error)?;
+        self.write_raw(Some(&raw))
+    }
+
+    async fn clear(&self) -> Result<(), AuthError> {
+        self.write_raw(None)
+    }
+}
+
+fn keystore_error(error: impl std::fmt::Display) -> AuthError {
+    AuthError::InternalError(format!("keystore error: {error}"))
+}
+
+/// Bearer token to send without running the OAuth client: an explicit `auth.token`, or a raw
+/// token stored by earlier releases.
+pub(crate) fn static_bearer_token(server: &McpServer) -> Option<String> {
+    let auth = server.auth.as_ref().filter(|auth| auth.is_oauth())?;
+    auth.token
+        .clone()
+        .or_else(|| KeyringCredentialStore::for_server(server).ok()?.legacy_token())
+}
+
+/// Authorization status derived from configuration and the keystore.
+///
+/// Plugins without OAuth report [`AuthStatus::Unknown`]; OAuth plugins are authorized once a
+/// token is configured or stored.
+pub(crate) fn stored_auth_status(server: &McpServer) -> AuthStatus {
+    if !server.uses_oauth() {
+        return AuthStatus::Unknown;
+    }
+    let configured = server.auth.as_ref().is_some_and(|auth| auth.token.is_some());
+    let stored = KeyringCredentialStore::for_server(server).is_ok_and(|store| store.has_credentials());
+    if configured || stored {
+        AuthStatus::Authorized
+    } else {
+        AuthStatus::Required
+    }
+}
+
+/// Run the interactive authorization flow for `server` and store the resulting credentials.
+///
+/// `open_browser` receives the authorization URL; it should open it for the user (and may also
+/// surface it so the user can open it by hand).
+pub(crate) async fn authorize(server: &McpServer, open_browser: impl FnOnce(&str) -> Result<()>) -> Result<()> {
+    let store = KeyringCredentialStore::for_server(server)?;
+    authorize_with_store(server, store, open_browser).await
+}
+
+/// Authorization flow against an arbitrary credential store.
+pub(crate) async fn authorize_with_store<S>(server: &McpServer, store: S, open_browser: impl FnOnce(&str) -> Result<()>) -> Result<()>
+where
+    S: CredentialStore + 'static,
+{
+    let base_url = server
+        .base_url
+        .as_ref()
+        .ok_or_else(|| anyhow!("base_url required for OAuth authorization"))?;
+
+    let mut manager = AuthorizationManager::new(base_url.as_str()).await?;
+    manager.set_credential_store(store);
+    let metadata = manager
+        .discover_metadata()
+        .await
+        .with_context(|| format!("failed to discover the authorization server for {base_url}"))?;
+    manager.set_metadata(metadata);
+
+    let listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))
+        .await
+        .context("failed to bind the OAuth redirect listener")?;
+    let redirect_uri = format!("http://{}{REDIRECT_PATH}", listener.local_addr()?);
+
+    let configured_scopes = server.auth.as_ref().and_then(|auth| auth.scopes.clone()).unwrap_or_default();
+    let scopes = if configured_scopes.is_empty() {
+        manager.select_scopes(None, &[])
+    } else {
+        configured_scopes
+    };
+    let scope_refs: Vec<&str> = scopes.iter().map(String::as_str).collect();
+
+    let session = AuthorizationSession::new(manager, &scope_refs, &redirect_uri, Some(OAUTH_CLIENT_NAME), None).await?;
+    let (callback_tx, callback_rx) = oneshot::channel();
+    let server_handle = tokio::spawn(serve_redirect(listener, callback_tx));
+
+    let outcome = async {
+        open_browser(session.get_authorization_url())?;
+        let callback = tokio::time::timeout(AUTHORIZATION_TIMEOUT, callback_rx)
+            .await
+            .map_err(|_| anyhow!("timed out waiting for the browser to complete authorization"))?
+            .map_err(|_| anyhow!("authorization redirect listener stopped unexpectedly"))?;
+        let (code, state) = callback.into_code()?;
+        session.handle_callback(&code, &state).await?;
+        Ok(())
+    }
+    .await;
+
+    server_handle.abort();
+    outcome
+}
+
+/// Query parameters sent to the loopback redirect URI.
+#[derive(Debug, Default, Deserialize)]
+struct RedirectParams {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+impl RedirectParams {
+    fn into_code(self) -> Result<(String, String)> {
+        if let Some(error) = self.error {
+            match self.error_description {
+                Some(description) => bail!("authorization denied: {error} ({description})"),
+                None => bail!("authorization denied: {error}"),
+            }
+        }
+        match (self.code, self.state) {
+            (Some(code), Some(state)) => Ok((code, state)),
+            _ => bail!("authorization redirect did not include a code and state"),
+        }
+    }
+}
+
+type RedirectSender = Arc<StdMutex<Option<oneshot::Sender<RedirectParams>>>>;
+
+/// Serve the loopback redirect until the first callback arrives.
+async fn serve_redirect(listener: TcpListener, callback_tx: oneshot::Sender<RedirectParams>) {
+    let sender: RedirectSender = Arc::new(StdMutex::new(Some(callback_tx)));
+    let router = Router::new().route(REDIRECT_PATH, get(handle_redirect)).with_state(sender);
+    if let Err(error) = axum::serve(listener, router).await {
+        warn!(error = %error, "OAuth redirect listener failed");
+    }
+}
+
+async fn handle_redirect(State(sender): State<RedirectSender>, Query(params): Query<RedirectParams>) -> Html<&'static str> {
+    let succeeded = params.error.is_none() && params.code.is_some();
+    if let Some(sender) = sender.lock().ok().and_then(|mut guard| guard.take()) {
+        let _ = sender.send(params);
+    }
+    if succeeded {
+        Html("<html><body><h3>Oatty is authorized.</h3><p>You can close this window and return to the terminal.</p></body></html>")
+    } else {
+        Html("<html><body><h3>Authorization failed.</h3><p>Return to the terminal for details.</p></body></html>")
+    }
+}
+
+/// Open `url` with the platform's default browser.
+pub(crate) fn open_in_browser(url: &str) -> Result<()> {
+    #[cfg(target_os = "macos")]
+    let mut command = std::process::Command::new("open");
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    };
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = std::process::Command::new("xdg-open");
+
+    command
+        .arg(url)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .context("failed to launch a browser")?;
+    Ok(())
+}
+
+/// Streamable HTTP client that authorizes requests with stored OAuth credentials.
+///
+/// Tokens are refreshed ahead of expiry by [`AuthClient`]; a `401` triggers one more refresh and a
+/// retry. When that fails too, the client records the rejection so the connection error can ask
+/// the user to authorize again.
+#[derive(Clone)]
+pub(crate) struct OAuthHttpClient {
+    inner: AuthClient<reqwest::Client>,
+    rejected: Arc<AtomicBool>,
+}
+
+impl OAuthHttpClient {
+    /// Build a client from stored credentials, or `None` when the plugin has not been authorized.
+    pub(crate) async fn from_store(server: &McpServer, http_client: reqwest::Client) -> Result<Option<Self>> {
+        let base_url = server
+            .base_url
+            .as_ref()
+            .ok_or_else(|| anyhow!("base_url required for OAuth authorization"))?;
+        let mut manager = AuthorizationManager::new(base_url.as_str()).await?;
+        manager.set_credential_store(KeyringCredentialStore::for_server(server)?);
+        if !manager.initialize_from_store().await? {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            inner: AuthClient::new(http_client, manager),
+            rejected: Arc::new(AtomicBool::new(false)),
+        }))
+    }
+
+    /// Whether the server rejected the credentials and refreshing did not help.
+    pub(crate) fn was_rejected(&self) -> bool {
+        self.rejected.load(Ordering::SeqCst)
+    }
+
+    async fn refresh(&self) -> bool {
+        let manager = self.inner.auth_manager.lock().await;
+        match manager.refresh_token().await {
+            Ok(_) => true,
+            Err(error) => {
+                warn!(error = %error, "OAuth token refresh after 401 failed");
+                false
+            }
+        }
+    }
+
+    /// Run `attempt`, refreshing the token and retrying once when the server answers `401`.
+    async fn with_refresh<T, F, Fut>(&self, attempt: F) -> Result<T, StreamableHttpError<reqwest::Error>>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, StreamableHttpError<reqwest::Error>>>,
+    {
+        let result = match attempt().await {
+            Err(StreamableHttpError::AuthRequired(error)) => {
+                if self.refresh().await {
+                    attempt().await
+                } else {
+                    Err(StreamableHttpError::AuthRequired(error))
+                }
+            }
+            other => other,
+        };
+        if matches!(
+            result,
+            Err(StreamableHttpError::AuthRequired(_)) | Err(StreamableHttpError::Auth(AuthError::AuthorizationRequired))
+        ) {
+            self.rejected.store(true, Ordering::SeqCst);
+        }
+        result
+    }
+}
+
+impl StreamableHttpClient for OAuthHttpClient {
+    type Error = reqwest::Error;
+
+    fn post_message(
+        &self,
+        uri: Arc<str>,
+        message: ClientJsonRpcMessage,
+        session_id: Option<Arc<str>>,
+        auth_header: Option<String>,
+        custom_headers: HashMap<HeaderName, HeaderValue>,
+    ) -> impl Future<Output = Result<StreamableHttpPostResponse, StreamableHttpError<Self::Error>>> + Send + '_ {
+        self.with_refresh(move || {
+            self.inner.post_message(
+                uri.clone(),
+                message.clone(),
+                session_id.clone(),
+                auth_header.clone(),
+                custom_headers.clone(),
+            )
+        })
+    }
+
+    fn delete_session(
+        &self,
+        uri: Arc<str>,
+        session_id: Arc<str>,
+        auth_header: Option<String>,
+        custom_headers: HashMap<HeaderName, HeaderValue>,
+    ) -> impl Future<Output = Result<(), StreamableHttpError<Self::Error>>> + Send + '_ {
+        self.with_refresh(move || {
+            self.inner
+                .delete_session(uri.clone(), session_id.clone(), auth_header.clone(), custom_headers.clone())
+        })
+    }
+
+    fn get_stream(
+        &self,
+        uri: Arc<str>,
+        session_id: Arc<str>,
+        last_event_id: Option<String>,
+        auth_header: Option<String>,
+        custom_headers: HashMap<HeaderName, HeaderValue>,
+    ) -> impl Future<Output = Result<BoxStream<'static, Result<sse_stream::Sse, SseError>>, StreamableHttpError<Self::Error>>> + Send + '_
+    {
+        self.with_refresh(move || {
+            self.inner.get_stream(
+                uri.clone(),
+                session_id.clone(),
+                last_event_id.clone(),
+                auth_header.clone(),
+                custom_headers.clone(),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axum::{
+        Form, Json,
+        extract::Query,
+        http::{StatusCode, header},
+        response::{IntoResponse, Redirect, Response},
+        routing::post,
+    };
+    use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+    use rmcp::transport::auth::InMemoryCredentialStore;
+    use serde_json::json;
+    use sha2::{Digest, Sha256};
+    use url::Url;
+
+    use super::*;
+    use crate::config::McpAuthConfig;
+
+    type Challenges = Arc<StdMutex<HashMap<String, String>>>;
+
+    /// Authorization server that also plays the protected MCP resource.
+    async fn spawn_authorization_server() -> String {
+        let listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, 0))).await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let challenges: Challenges = Arc::default();
+
+        let resource_base = base.clone();
+        let metadata_base = base.clone();
+        let router = Router::new()
+            .route(
+                "/mcp",
+                get(move || async move {
+                    let challenge = format!("Bearer resource_metadata=\"{resource_base}/.well-known/oauth-protected-resource\"");
+                    (StatusCode::UNAUTHORIZED, [(header::WWW_AUTHENTICATE, challenge)]).into_response()
+                }),
+            )
+            .route(
+                "/.well-known/oauth-protected-resource",
+                get({
+                    let base = base.clone();
+                    move || async move { Json(json!({ "resource": format!("{base}/mcp"), "authorization_servers": [base] })) }
+                }),
+            )
+            .route(
+                "/.well-known/oauth-authorization-server",
+                get(move || async move {
+                    Json(json!({
+                        "issuer": metadata_base,
+                        "authorization_endpoint": format!("{metadata_base}/authorize"),
+                        "token_endpoint": format!("{metadata_base}/token"),
+                        "registration_endpoint": format!("{metadata_base}/register"),
+                        "response_types_supported": ["code"],
+                        "code_challenge_methods_supported": ["S256"],
+                    }))
+                }),
+            )
+            .route(
+                "/register",
+                post(|Json(request): Json<serde_json::Value>| async move {
+                    Json(json!({ "client_id": "oatty-test", "redirect_uris": request["redirect_uris"] }))
+                }),
+            )
+            .route("/authorize", get(authorize_endpoint))
+            .route("/token", post(token_endpoint))
+            .with_state(challenges);
+
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        base
+    }
+
+    async fn authorize_endpoint(State(challenges): State<Challenges>, Query(params): Query<HashMap<String, String>>) -> Response {
+        assert_eq!(params.get("code_challenge_method").map(String::as_str), Some("S256"));
+        challenges
+            .lock()
+            .unwrap()
+            .insert("code-1".to_string(), params["code_challenge"].clone());
+        let mut redirect = Url::parse(&params["redirect_uri"]).unwrap();
+        redirect
+            .query_pairs_mut()
+            .append_pair("code", "code-1")
+            .append_pair("state", &params["state"]);
+        Redirect::to(redirect.as_str()).into_response()
+    }
+
+    async fn token_endpoint(State(challenges): State<Challenges>, Form(form): Form<HashMap<String, String>>) -> Response {
+        let challenge = challenges.lock().unwrap().remove(&form["code"]);
+        let verified = URL_SAFE_NO_PAD.encode(Sha256::digest(form["code_verifier"].as_bytes()));
+        if challenge.as_deref() != Some(verified.as_str()) {
+            return (StatusCode::BAD_REQUEST, Json(json!({ "error": "invalid_grant" }))).into_response();
+        }
+        Json(json!({
+            "access_token": "access-1",
+            "token_type": "Bearer",
+            "expires_in": 3600,
+            "refresh_token": "refresh-1",
+        }))
+        .into_response()
+    }
+
+    fn oauth_server(base_url: &str) -> McpServer {
+        McpServer {
+            base_url: Some(Url::parse(base_url).unwrap()),
+            auth: Some(McpAuthConfig::oauth()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn authorization_code_flow_stores_tokens() {
+        let base = spawn_authorization_server().await;
+        let server = oauth_server(&format!("{base}/mcp"));
+        let store = InMemoryCredentialStore::new();
+
+        authorize_with_store(&server, store.clone(), |url| {
+            let url = url.to_string();
+            tokio::spawn(async move { reqwest::get(url).await });
+            Ok(())
+        })
+        .await
+        .expect("authorization succeeds");
+
+        let credentials = store.load().await.unwrap().expect("credentials stored");
+        assert_eq!(credentials.client_id, "oatty-test");
+        let token = serde_json::to_value(credentials.token_response.expect("token response")).unwrap();
+        assert_eq!(token["access_token"], "access-1");
+        assert_eq!(token["refresh_token"], "refresh-1");
+    }
+
+    #[tokio::test]
+    async fn denied_authorization_reports_the_error() {
+        let base = spawn_authorization_server().await;
+        let server = oauth_server(&format!("{base}/mcp"));
+
+        let error = authorize_with_store(&server, InMemoryCredentialStore::new(), |url| {
+            let redirect_uri = Url::parse(url)
+                .unwrap()
+                .query_pairs()
+                .find(|(key, _)| key == "redirect_uri")
+                .map(|(_, value)| value.into_owned())
+                .unwrap();
+            tokio::spawn(async move { reqwest::get(format!("{redirect_uri}?error=access_denied")).await });
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+
+        assert!(error.to_string().contains("access_denied"), "{error}");
+    }
+
+    #[test]
+    fn credential_account_ignores_query_and_fragment() {
+        let server = oauth_server("https://mcp.example.com:8443/v1/mcp?tenant=a#frag");
+        assert_eq!(credential_account(&server).as_deref(), Some("https://mcp.example.com:8443/v1/mcp"));
+    }
+
+    #[test]
+    fn oauth_status_is_unknown_for_other_schemes() {
+        let mut server = oauth_server("https://mcp.example.com/mcp");
+        server.auth = None;
+        assert_eq!(stored_auth_status(&server), AuthStatus::Unknown);
+
+        server.auth = Some(McpAuthConfig {
+            token: Some("static".to_string()),
+            ..McpAuthConfig::oauth()
+        });
+        assert_eq!(stored_auth_status(&server), AuthStatus::Authorized);
+        assert_eq!(static_bearer_token(&server).as_deref(), Some("static"));
+    }
+}
@@ -9,5 +9,8 @@ mod validation;
 
 pub use interpolation::interpolate_config;
 pub use io::{default_config_path, load_config, load_config_from_path, save_config_to_path};
-pub use model::{ConfigError, McpAuthConfig, McpConfig, McpServer};
+pub use model::{
+    ConfigError, McpAuthConfig, McpConfig, McpHttpClientToken, McpHttpServerConfig, McpHttpTlsConfig, McpRateLimitRule, McpRateLimits,
+    McpServer,
+};
 pub use validation::{ValidationError, validate_config, validate_server_name};
@@ -1,5 +1,6 @@
 //! Data models for MCP configuration.
 
+use chrono::{DateTime, Utc};
 use indexmap::{IndexMap, IndexSet};
 use oatty_types::{EnvSource, EnvVar};
 use oatty_util::InterpolationError;
@@ -28,6 +29,42 @@ pub struct McpHttpServerConfig {
     pub auto_start: bool,
     /// Optional bind address (for example, "127.0.0.1:0"). When omitted, a safe localhost default is used.
     pub bind_address: Option<String>,
+    /// Optional path to a policy file (YAML or JSON) that scopes what connected clients may do.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy_file: Option<PathBuf>,
+    /// Clients issued a bearer token. When non-empty, every request must present a valid token.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clients: Vec<McpHttpClientToken>,
+    /// Optional certificate and key used to serve the endpoint over HTTPS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<McpHttpTlsConfig>,
+    /// Re-export the tools, resources, and prompts of running plugins, namespaced as
+    /// `<plugin>__<name>`, so a client can reach every downstream server through this endpoint.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub gateway: bool,
+    /// Optional limits on how fast and how much connected clients may execute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limits: Option<McpRateLimits>,
+}
+
+impl McpHttpServerConfig {
+    /// Whether clients must authenticate with a bearer token.
+    pub fn requires_client_tokens(&self) -> bool {
+        !self.clients.is_empty()
+    }
+
+    /// Whether the server may listen on a non-loopback interface.
+    ///
+    /// Remote access needs both TLS, so tokens never cross the network in clear text, and
+    /// client tokens, so only known clients can drive the registry.
+    pub fn allows_remote_access(&self) -> bool {
+        self.tls.is_some() && self.requires_client_tokens()
+    }
+
+    /// Looks up a client token entry by name.
+    pub fn client(&self, name: &str) -> Option<&McpHttpClientToken> {
+        self.clients.iter().find(|client| client.name == name)
+    }
 }
 
 impl Default for McpHttpServerConfig {
@@ -35,10 +72,69 @@ impl Default for McpHttpServerConfig {
         Self {
             auto_start: false,
             bind_address: Some("127.0.0.1:62889".to_string()),
+            policy_file: None,
+            clients: Vec::new(),
+            tls: None,
+            gateway: false,
+            rate_limits: None,
         }
     }
 }
 
+/// Execution limits for the MCP HTTP server.
+///
+/// Limits count command executions (`run_*` tools, gateway tool calls) and workflow runs; discovery
+/// and authoring tools are never limited.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct McpRateLimits {
+    /// Limits applied to each MCP session on its own.
+    #[serde(default)]
+    pub session: McpRateLimitRule,
+    /// Limits shared by all sessions, keyed by a glob over the catalog title (or plugin name).
+    /// Every matching entry applies.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub catalogs: IndexMap<String, McpRateLimitRule>,
+}
+
+/// One set of limits; omitted fields are unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct McpRateLimitRule {
+    /// Executions started within any rolling 60-second window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calls_per_minute: Option<u32>,
+    /// Executions in flight at the same time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent: Option<u32>,
+    /// Write or destructive executions in total: per session for `session`, since the server
+    /// started for catalog entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_write_calls: Option<u32>,
+}
+
+/// A client allowed to connect to the MCP HTTP server.
+///
+/// Only the name is kept in the config file; the token digest lives in the OS keystore.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct McpHttpClientToken {
+    /// Client name, also used as the identity for policy rules and the audit trail.
+    pub name: String,
+    /// When the token was issued.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Certificate and private key (PEM) for serving the MCP HTTP server over TLS.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct McpHttpTlsConfig {
+    /// Certificate chain in PEM format, leaf certificate first.
+    pub cert_file: PathBuf,
+    /// Private key in PEM format (PKCS#8, PKCS#1, or SEC1).
+    pub key_file: PathBuf,
+}
+
 /// Configuration for a single MCP server.
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -88,6 +184,11 @@ impl McpServer {
         self.base_url.is_some()
     }
 
+    /// Check if this server authorizes through OAuth.
+    pub fn uses_oauth(&self) -> bool {
+        self.is_http() && self.auth.as_ref().is_some_and(McpAuthConfig::is_oauth)
+    }
+
     /// Check if this server is disabled.
     pub fn is_disabled(&self) -> bool {
         self.disabled
@@ -139,7 +240,7 @@ where
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct McpAuthConfig {
-    /// Authorization scheme. Currently supports "basic".
+    /// Authorization scheme: "basic", or "oauth2" for the MCP authorization-code + PKCE flow.
     pub scheme: String,
     /// Username (supports interpolation like ${env:NAME} or ${secret:NAME}).
     pub username: Option<String>,
@@ -152,6 +253,29 @@ pub struct McpAuthConfig {
     pub header_name: Option<String>,
     /// Allow interactive prompting on failure.
     pub interactive: Option<bool>,
+    /// OAuth scopes to request. When omitted, scopes advertised by the server metadata are used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+}
+
+impl McpAuthConfig {
+    /// Build an OAuth configuration that relies entirely on server discovery.
+    pub fn oauth() -> Self {
+        Self {
+            scheme: "oauth2".to_string(),
+            username: None,
+            password: None,
+            token: None,
+            header_name: None,
+            interactive: Some(true),
+            scopes: None,
+        }
+    }
+
+    /// Whether this configuration uses the OAuth authorization flow.
+    pub fn is_oauth(&self) -> bool {
+        self.scheme.eq_ignore_ascii_case("oauth") || self.scheme.eq_ignore_ascii_case("oauth2")
+    }
 }
 
 /// Transport type for MCP servers.
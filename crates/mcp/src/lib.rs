@@ -12,9 +12,14 @@ pub mod provider;
 pub mod server;
 pub mod types;
 
-pub use config::{ConfigError, McpConfig, McpServer};
+pub use config::{ConfigError, McpConfig, McpHttpClientToken, McpHttpServerConfig, McpHttpTlsConfig, McpServer};
 pub use plugin::PluginEngine;
-pub use server::{McpHttpLogEntry, McpHttpServer, RunningMcpHttpServer, resolve_bind_address};
+pub use server::{
+    McpClientAuthenticator, McpConfirmationRequest, McpHttpLogEntry, McpHttpServer, McpPendingToolCall, McpPolicy, McpUsageSnapshot,
+    RunningMcpHttpServer, WorkflowHistoryEntry, WorkflowManifestFormat, WorkflowValidateRequest, append_history_entry, create_client_token,
+    find_manifest_record, latest_history_entry, list_client_tokens, load_http_server_config, remove_manifest, resolve_bind_address,
+    revoke_client_token, serialize_definition, validate_workflow, write_manifest,
+};
 pub use types::{
     AuthStatus, EnvSource, EnvVar, HealthStatus, LogLevel, LogSource, McpLogEntry, PluginDetail, PluginStatus, PluginToolSummary,
     TransportStatus,
@@ -1,16 +1,20 @@
-//! Audit logging for MCP plugin lifecycle events.
+//! Audit logging for plugin lifecycle events, MCP tool calls, command executions, and workflow
+//! steps.
 
+use crate::logging::AuditQuery;
 use chrono::{DateTime, Utc};
 use oatty_util::redact_sensitive_with;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use thiserror::Error;
 use tokio::fs::OpenOptions;
 use tokio::io::AsyncWriteExt;
 use tracing::debug;
 
-/// Audit logger for tracking plugin lifecycle events.
-#[derive(Debug)]
+/// Audit logger appending redacted JSONL entries with size/age rotation.
+#[derive(Debug, Clone)]
 pub struct AuditLogger {
     /// Path to the audit log file.
     log_path: PathBuf,
@@ -39,6 +43,72 @@ pub struct AuditEntry {
 
     /// Result of the action (success, failure, etc.).
     pub result: AuditResult,
+
+    /// Who triggered the action and what it touched.
+    #[serde(flatten)]
+    pub invocation: AuditInvocation,
+}
+
+/// Request details recorded for tool calls, command executions, and workflow steps.
+///
+/// Every field is optional so plugin lifecycle entries and older log lines keep parsing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditInvocation {
+    /// Whether an agent, a human, or the scheduler triggered the action.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiator: Option<AuditInitiator>,
+    /// MCP `clientInfo.name` of the calling session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+    /// MCP session identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// Canonical command identifier (`<group> <command>`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canonical_id: Option<String>,
+    /// Catalog title, or plugin name for MCP-backed commands.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catalog: Option<String>,
+    /// HTTP method.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// Request path, resolved when the inputs are known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// HTTP status code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Wall-clock duration in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+}
+
+impl AuditInvocation {
+    /// Start an invocation record for the given initiator.
+    pub fn new(initiator: AuditInitiator) -> Self {
+        Self {
+            initiator: Some(initiator),
+            ..Self::default()
+        }
+    }
+
+    /// Record the elapsed wall-clock time.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration_ms = Some(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX));
+        self
+    }
+}
+
+/// Who triggered an audited action.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditInitiator {
+    /// An MCP client (typically an AI agent) calling the Oatty MCP server.
+    Agent,
+    /// A person using the CLI or TUI.
+    Human,
+    /// The local workflow scheduler.
+    Scheduler,
 }
 
 /// Actions that can be audited.
@@ -66,6 +136,16 @@ pub enum AuditAction {
     HealthCheck,
     /// Secret was accessed.
     SecretAccess,
+    /// An operator approved or denied a tool call that required confirmation.
+    OperatorConfirmation,
+    /// A catalog command was executed from the CLI, TUI, or MCP server.
+    CommandExecute,
+    /// A workflow step executed a catalog command.
+    WorkflowStep,
+    /// A client read a plugin resource through the gateway.
+    ResourceRead,
+    /// A client rendered a plugin prompt through the gateway.
+    PromptGet,
 }
 
 /// Result of an audited action.
@@ -79,6 +159,19 @@ pub enum AuditResult {
     Skipped,
     /// Action is in progress.
     InProgress,
+    /// Action was denied by policy or by an operator.
+    Denied,
+}
+
+impl AuditResult {
+    /// Classify an HTTP status code.
+    pub fn from_http_status(status: u16) -> Self {
+        if (200..400).contains(&status) {
+            AuditResult::Success
+        } else {
+            AuditResult::Failure
+        }
+    }
 }
 
 impl AuditLogger {
@@ -114,9 +207,7 @@ impl AuditLogger {
             self.rotate_log().await?;
         }
 
-        // Redact sensitive fields in the audit entry before writing
-        let redacted_entry = redact_audit_entry(entry);
-        let json_line = serde_json::to_string(&redacted_entry).map_err(|e| AuditError::Serialization(e.to_string()))?;
+        let (redacted_entry, json_line) = encode_entry(entry)?;
 
         let mut file = OpenOptions::new()
             .create(true)
@@ -137,13 +228,30 @@ impl AuditLogger {
         file.write_all(json_line.as_bytes()).await.map_err(AuditError::Io)?;
         file.write_all(b"\n").await.map_err(AuditError::Io)?;
 
-        debug!(
-            "Audit log entry: {} {} {}",
-            redacted_entry.plugin_name,
-            serde_json::to_string(&redacted_entry.action).unwrap_or_default(),
-            serde_json::to_string(&redacted_entry.result).unwrap_or_default()
-        );
+        log_written_entry(&redacted_entry);
+        Ok(())
+    }
+
+    /// Log an audit entry from synchronous code, such as a workflow command runner.
+    pub fn log_blocking(&self, entry: AuditEntry) -> Result<(), AuditError> {
+        if self.log_path.exists() && self.is_rotation_due(&std::fs::metadata(&self.log_path)?)? {
+            let rotated_path = self.rotated_path();
+            std::fs::rename(&self.log_path, &rotated_path)?;
+            debug!("Rotated audit log: {} -> {}", self.log_path.display(), rotated_path.display());
+        }
+
+        let (redacted_entry, json_line) = encode_entry(entry)?;
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&self.log_path)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&self.log_path, std::fs::Permissions::from_mode(0o600))?;
+        }
 
+        file.write_all(json_line.as_bytes())?;
+        file.write_all(b"\n")?;
+        log_written_entry(&redacted_entry);
         Ok(())
     }
 
@@ -153,9 +261,13 @@ impl AuditLogger {
             return Ok(false);
         }
 
-        // Check file size
         let metadata = tokio::fs::metadata(&self.log_path).await.map_err(AuditError::Io)?;
+        self.is_rotation_due(&metadata)
+    }
 
+    /// Whether the current file exceeds the size limit or is older than the age limit.
+    fn is_rotation_due(&self, metadata: &std::fs::Metadata) -> Result<bool, AuditError> {
+        // Check file size
         if metadata.len() > self.max_size {
             return Ok(true);
         }
@@ -167,11 +279,7 @@ impl AuditLogger {
             .duration_since(modified)
             .map_err(|e| AuditError::Io(std::io::Error::other(e)))?;
 
-        if age.as_secs() > self.max_age_days * 24 * 60 * 60 {
-            return Ok(true);
-        }
-
-        Ok(false)
+        Ok(age.as_secs() > self.max_age_days * 24 * 60 * 60)
     }
 
     /// Rotate the log file.
@@ -180,8 +288,7 @@ impl AuditLogger {
             return Ok(());
         }
 
-        let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
-        let rotated_path = self.log_path.with_extension(format!("{}.jsonl", timestamp));
+        let rotated_path = self.rotated_path();
 
         tokio::fs::rename(&self.log_path, &rotated_path).await.map_err(AuditError::Io)?;
 
@@ -190,6 +297,64 @@ impl AuditLogger {
         Ok(())
     }
 
+    /// Path the current file is renamed to on rotation, for example `mcp-audit.20260101_120000.jsonl`.
+    fn rotated_path(&self) -> PathBuf {
+        let timestamp = Utc::now().format("%Y%m%d_%H%M%S%3f");
+        let mut rotated = self.log_path.with_extension(format!("{}.jsonl", timestamp));
+        let mut sequence = 1;
+        while rotated.exists() {
+            rotated = self.log_path.with_extension(format!("{}_{}.jsonl", timestamp, sequence));
+            sequence += 1;
+        }
+        rotated
+    }
+
+    /// Rotated files, oldest first, followed by the current file when it exists.
+    fn log_files(&self) -> Result<Vec<PathBuf>, AuditError> {
+        let mut files = Vec::new();
+        let stem = self.log_path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+        let rotated_prefix = format!("{stem}.");
+        if let Some(directory) = self.log_path.parent().filter(|directory| directory.is_dir()) {
+            for entry in std::fs::read_dir(directory)? {
+                let path = entry?.path();
+                let is_rotated = path != self.log_path
+                    && path.file_name().and_then(|name| name.to_str()).is_some_and(|name| {
+                        name.starts_with(&rotated_prefix) && name.ends_with(".jsonl") && name.len() > rotated_prefix.len() + ".jsonl".len()
+                    });
+                if is_rotated {
+                    files.push(path);
+                }
+            }
+        }
+        // Rotation timestamps sort lexicographically.
+        files.sort();
+        if self.log_path.exists() {
+            files.push(self.log_path.clone());
+        }
+        Ok(files)
+    }
+
+    /// Read entries matching `query` from the current and rotated files, oldest first.
+    ///
+    /// When the query sets a limit, only the most recent matching entries are returned.
+    pub fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditError> {
+        let mut entries = Vec::new();
+        for path in self.log_files()? {
+            if let Some(since) = query.since
+                && modified_before(&path, since)
+            {
+                continue;
+            }
+            entries.extend(read_entries(&path)?.into_iter().filter(|entry| query.matches(entry)));
+        }
+        entries.sort_by_key(|entry| entry.timestamp);
+        if let Some(limit) = query.limit {
+            let excess = entries.len().saturating_sub(limit);
+            entries.drain(..excess);
+        }
+        Ok(entries)
+    }
+
     /// Read recent audit entries.
     pub async fn read_recent(&self, count: usize) -> Result<Vec<AuditEntry>, AuditError> {
         if !self.log_path.exists() {
@@ -217,6 +382,42 @@ impl AuditLogger {
     }
 }
 
+/// Redact an entry and serialize it as a single JSON line.
+fn encode_entry(entry: AuditEntry) -> Result<(AuditEntry, String), AuditError> {
+    let redacted_entry = redact_audit_entry(entry);
+    let json_line = serde_json::to_string(&redacted_entry).map_err(|e| AuditError::Serialization(e.to_string()))?;
+    Ok((redacted_entry, json_line))
+}
+
+fn log_written_entry(entry: &AuditEntry) {
+    debug!(
+        "Audit log entry: {} {} {}",
+        entry.plugin_name,
+        serde_json::to_string(&entry.action).unwrap_or_default(),
+        serde_json::to_string(&entry.result).unwrap_or_default()
+    );
+}
+
+/// Whether a file was last written before `since`, meaning none of its entries can match.
+fn modified_before(path: &Path, since: DateTime<Utc>) -> bool {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map(|modified| DateTime::<Utc>::from(modified) < since)
+        .unwrap_or(false)
+}
+
+/// Parse every well-formed entry in a JSONL file, skipping lines that do not parse.
+fn read_entries(path: &Path) -> Result<Vec<AuditEntry>, AuditError> {
+    let file = std::fs::File::open(path)?;
+    let mut entries = Vec::new();
+    for line in BufReader::new(file).lines() {
+        if let Ok(entry) = serde_json::from_str::<AuditEntry>(&line?) {
+            entries.push(entry);
+        }
+    }
+    Ok(entries)
+}
+
 /// Redact sensitive values in an AuditEntry's metadata and any string fields.
 fn redact_audit_entry(mut entry: AuditEntry) -> AuditEntry {
     // Redact metadata string values and known sensitive keys
@@ -225,6 +426,7 @@ fn redact_audit_entry(mut entry: AuditEntry) -> AuditEntry {
         redacted.insert(k, redact_json_value(v));
     }
     entry.metadata = redacted;
+    entry.invocation.path = entry.invocation.path.map(|path| redact_sensitive_with(&path, "[REDACTED]"));
     entry
 }
 
@@ -272,6 +474,9 @@ pub enum AuditError {
 
     #[error("Deserialization error: {0}")]
     Deserialization(String),
+
+    #[error("Invalid audit query: {0}")]
+    InvalidQuery(String),
 }
 
 impl From<std::io::Error> for AuditError {
@@ -290,6 +495,7 @@ impl AuditEntry {
             action: AuditAction::Start,
             metadata,
             result: AuditResult::Success,
+            invocation: AuditInvocation::default(),
         }
     }
 
@@ -301,6 +507,7 @@ impl AuditEntry {
             action: AuditAction::Stop,
             metadata,
             result: AuditResult::Success,
+            invocation: AuditInvocation::default(),
         }
     }
 
@@ -315,6 +522,89 @@ impl AuditEntry {
             action: AuditAction::ToolInvoke,
             metadata,
             result,
+            invocation: AuditInvocation::default(),
+        }
+    }
+
+    /// Create an audit entry for a tool call served by the Oatty MCP server.
+    pub fn mcp_tool_call(source: String, tool_name: String, invocation: AuditInvocation, result: AuditResult) -> Self {
+        Self {
+            invocation,
+            ..Self::tool_invoke(source, tool_name, result)
+        }
+    }
+
+    /// Create a command execution audit entry.
+    pub fn command_execution(source: String, invocation: AuditInvocation, result: AuditResult) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            plugin_name: source,
+            action: AuditAction::CommandExecute,
+            metadata: serde_json::Map::new(),
+            result,
+            invocation,
+        }
+    }
+
+    /// Create an audit entry for a workflow step that executed a command.
+    pub fn workflow_step(source: String, invocation: AuditInvocation, result: AuditResult) -> Self {
+        Self {
+            action: AuditAction::WorkflowStep,
+            ..Self::command_execution(source, invocation, result)
+        }
+    }
+
+    /// Create an audit entry for a plugin resource read through the Oatty MCP server gateway.
+    pub fn resource_read(source: String, uri: String, invocation: AuditInvocation, result: AuditResult) -> Self {
+        Self {
+            action: AuditAction::ResourceRead,
+            ..Self::command_execution(source, invocation, result)
+        }
+        .with_metadata("uri", serde_json::Value::String(uri))
+    }
+
+    /// Create an audit entry for a plugin prompt rendered through the Oatty MCP server gateway.
+    pub fn prompt_get(source: String, prompt_name: String, invocation: AuditInvocation, result: AuditResult) -> Self {
+        Self {
+            action: AuditAction::PromptGet,
+            ..Self::command_execution(source, invocation, result)
+        }
+        .with_metadata("prompt_name", serde_json::Value::String(prompt_name))
+    }
+
+    /// What the entry is about: `METHOD path`, the canonical command, the tool, resource or prompt, or the action.
+    pub fn subject(&self) -> String {
+        let invocation = &self.invocation;
+        match (invocation.method.as_deref(), invocation.path.as_deref()) {
+            (Some(method), Some(path)) => format!("{method} {path}"),
+            _ => invocation
+                .canonical_id
+                .clone()
+                .or_else(|| {
+                    ["tool_name", "uri", "prompt_name"]
+                        .iter()
+                        .find_map(|key| self.metadata.get(*key).and_then(|value| value.as_str()))
+                        .map(str::to_string)
+                })
+                .unwrap_or_else(|| format!("{:?}", self.action)),
+        }
+    }
+
+    /// Attach a metadata value.
+    pub fn with_metadata(mut self, key: &str, value: serde_json::Value) -> Self {
+        self.metadata.insert(key.to_string(), value);
+        self
+    }
+
+    /// Create an operator confirmation audit entry for a call made through the MCP HTTP server.
+    pub fn operator_confirmation(source: String, metadata: serde_json::Map<String, serde_json::Value>, approved: bool) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            plugin_name: source,
+            action: AuditAction::OperatorConfirmation,
+            metadata,
+            result: if approved { AuditResult::Success } else { AuditResult::Denied },
+            invocation: AuditInvocation::default(),
         }
     }
 
@@ -332,6 +622,7 @@ impl AuditEntry {
             action: AuditAction::HealthCheck,
             metadata,
             result: if healthy { AuditResult::Success } else { AuditResult::Failure },
+            invocation: AuditInvocation::default(),
         }
     }
 }
@@ -358,6 +649,35 @@ mod tests {
         assert_eq!(entries[0].action, AuditAction::Start);
     }
 
+    #[test]
+    fn query_reads_rotated_files_and_keeps_the_latest_matches() {
+        let temp_dir = TempDir::new().unwrap();
+        let logger = AuditLogger::with_settings(temp_dir.path().join("audit.jsonl"), 1, 7);
+        let command = |status| {
+            let invocation = AuditInvocation {
+                status: Some(status),
+                ..AuditInvocation::new(AuditInitiator::Agent)
+            };
+            AuditEntry::command_execution("mcp-http-server".to_string(), invocation, AuditResult::from_http_status(status))
+        };
+
+        logger.log_blocking(command(500)).unwrap();
+        logger.log_blocking(command(200)).unwrap();
+        logger.log_blocking(command(503)).unwrap();
+
+        let all = logger.query(&AuditQuery::default()).unwrap();
+        assert_eq!(all.len(), 3);
+        let latest_failure = logger
+            .query(&AuditQuery {
+                status: Some(crate::logging::AuditStatusFilter::Result(AuditResult::Failure)),
+                limit: Some(1),
+                ..AuditQuery::default()
+            })
+            .unwrap();
+        assert_eq!(latest_failure.len(), 1);
+        assert_eq!(latest_failure[0].invocation.status, Some(503));
+    }
+
     #[test]
     fn test_audit_entry_creation() {
         let entry = AuditEntry::tool_invoke("test-plugin".to_string(), "test-tool".to_string(), AuditResult::Success);
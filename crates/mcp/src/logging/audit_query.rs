@@ -0,0 +1,164 @@
+//! Filters for reading the audit trail back, shared by `oatty audit query` and the TUI.
+
+use crate::logging::{AuditEntry, AuditError, AuditResult};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use std::str::FromStr;
+
+/// Criteria for selecting audit entries. Unset fields match everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditQuery {
+    /// Only entries recorded at or after this instant.
+    pub since: Option<DateTime<Utc>>,
+    /// Catalog title or plugin name, compared case-insensitively.
+    pub catalog: Option<String>,
+    /// Outcome or HTTP status filter.
+    pub status: Option<AuditStatusFilter>,
+    /// Keep only the most recent N matches.
+    pub limit: Option<usize>,
+}
+
+impl AuditQuery {
+    /// Whether `entry` satisfies every populated criterion.
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        if self.since.is_some_and(|since| entry.timestamp < since) {
+            return false;
+        }
+        if let Some(catalog) = self.catalog.as_deref() {
+            let entry_catalog = entry.invocation.catalog.as_deref().unwrap_or(entry.plugin_name.as_str());
+            if !entry_catalog.eq_ignore_ascii_case(catalog) {
+                return false;
+            }
+        }
+        self.status.as_ref().is_none_or(|status| status.matches(entry))
+    }
+}
+
+/// Status filter accepted by `--status`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditStatusFilter {
+    /// Outcome name: `success`, `failure`, `denied`, `skipped`, or `in_progress`.
+    Result(AuditResult),
+    /// Exact HTTP status, for example `404`.
+    Code(u16),
+    /// HTTP status class, for example `5xx` (stored as `5`).
+    Class(u16),
+}
+
+impl AuditStatusFilter {
+    fn matches(&self, entry: &AuditEntry) -> bool {
+        match self {
+            AuditStatusFilter::Result(result) => entry.result == *result,
+            AuditStatusFilter::Code(code) => entry.invocation.status == Some(*code),
+            AuditStatusFilter::Class(class) => entry.invocation.status.is_some_and(|status| status / 100 == *class),
+        }
+    }
+}
+
+impl FromStr for AuditStatusFilter {
+    type Err = AuditError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let normalized = value.trim().to_ascii_lowercase();
+        let result = match normalized.as_str() {
+            "success" | "ok" => Some(AuditResult::Success),
+            "failure" | "failed" | "error" => Some(AuditResult::Failure),
+            "denied" => Some(AuditResult::Denied),
+            "skipped" => Some(AuditResult::Skipped),
+            "in_progress" => Some(AuditResult::InProgress),
+            _ => None,
+        };
+        if let Some(result) = result {
+            return Ok(AuditStatusFilter::Result(result));
+        }
+        if let Some(class) = normalized.strip_suffix("xx")
+            && let Ok(class @ 1..=5) = class.parse::<u16>()
+        {
+            return Ok(AuditStatusFilter::Class(class));
+        }
+        match normalized.parse::<u16>() {
+            Ok(code @ 100..=599) => Ok(AuditStatusFilter::Code(code)),
+            _ => Err(AuditError::InvalidQuery(format!(
+                "unknown status '{value}'; use success, failure, denied, an HTTP status such as 404, or a class such as 5xx"
+            ))),
+        }
+    }
+}
+
+/// Parse `--since` as a relative age (`30s`, `15m`, `2h`, `7d`) or an RFC 3339 timestamp.
+pub fn parse_audit_since(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, AuditError> {
+    let trimmed = value.trim();
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(trimmed) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+
+    let invalid = || {
+        AuditError::InvalidQuery(format!(
+            "invalid --since '{value}'; use a duration such as 15m, 2h, 7d or an RFC 3339 timestamp"
+        ))
+    };
+    let split_at = trimmed.find(|character: char| !character.is_ascii_digit()).ok_or_else(invalid)?;
+    let (amount, unit) = trimmed.split_at(split_at);
+    let amount = amount.parse::<i64>().map_err(|_| invalid())?;
+    let age = match unit {
+        "s" => ChronoDuration::try_seconds(amount),
+        "m" => ChronoDuration::try_minutes(amount),
+        "h" => ChronoDuration::try_hours(amount),
+        "d" => ChronoDuration::try_days(amount),
+        "w" => ChronoDuration::try_weeks(amount),
+        _ => None,
+    }
+    .ok_or_else(invalid)?;
+    now.checked_sub_signed(age).ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logging::{AuditInitiator, AuditInvocation};
+
+    fn entry(catalog: &str, status: u16) -> AuditEntry {
+        let invocation = AuditInvocation {
+            catalog: Some(catalog.to_string()),
+            status: Some(status),
+            ..AuditInvocation::new(AuditInitiator::Human)
+        };
+        AuditEntry::command_execution("cli".to_string(), invocation, AuditResult::from_http_status(status))
+    }
+
+    #[test]
+    fn status_filter_accepts_outcomes_codes_and_classes() {
+        assert_eq!(
+            "denied".parse::<AuditStatusFilter>().unwrap(),
+            AuditStatusFilter::Result(AuditResult::Denied)
+        );
+        assert_eq!("404".parse::<AuditStatusFilter>().unwrap(), AuditStatusFilter::Code(404));
+        assert_eq!("5XX".parse::<AuditStatusFilter>().unwrap(), AuditStatusFilter::Class(5));
+        assert!("teapot".parse::<AuditStatusFilter>().is_err());
+    }
+
+    #[test]
+    fn query_matches_catalog_and_status_class() {
+        let query = AuditQuery {
+            catalog: Some("heroku".to_string()),
+            status: Some(AuditStatusFilter::Class(5)),
+            ..AuditQuery::default()
+        };
+
+        assert!(query.matches(&entry("Heroku", 503)));
+        assert!(!query.matches(&entry("Heroku", 200)));
+        assert!(!query.matches(&entry("GitHub", 503)));
+    }
+
+    #[test]
+    fn since_accepts_relative_ages_and_timestamps() {
+        let now = DateTime::parse_from_rfc3339("2026-01-10T12:00:00Z").unwrap().with_timezone(&Utc);
+
+        assert_eq!(parse_audit_since("2h", now).unwrap().to_rfc3339(), "2026-01-10T10:00:00+00:00");
+        assert_eq!(parse_audit_since("7d", now).unwrap().to_rfc3339(), "2026-01-03T12:00:00+00:00");
+        assert_eq!(
+            parse_audit_since("2026-01-01T00:00:00Z", now).unwrap().to_rfc3339(),
+            "2026-01-01T00:00:00+00:00"
+        );
+        assert!(parse_audit_since("yesterday", now).is_err());
+    }
+}
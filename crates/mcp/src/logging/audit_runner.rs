@@ -0,0 +1,159 @@
+//! Workflow step auditing through a [`CommandRunner`] decorator.
+
+use crate::logging::{AuditEntry, AuditInvocation, AuditLogger, AuditResult};
+use anyhow::Result;
+use oatty_engine::{CommandRunner, RunContext};
+use oatty_registry::CommandRegistry;
+use oatty_util::http::build_path;
+use serde_json::Value;
+use std::time::Instant;
+use tracing::warn;
+
+/// Where audit entries go and who they are attributed to.
+#[derive(Debug, Clone)]
+pub struct AuditTrail {
+    logger: AuditLogger,
+    source: String,
+    invocation: AuditInvocation,
+}
+
+impl AuditTrail {
+    /// Attribute entries to `source` (for example `cli` or `mcp-http-server`) with the given
+    /// initiator and client details.
+    pub fn new(logger: AuditLogger, source: impl Into<String>, invocation: AuditInvocation) -> Self {
+        Self {
+            logger,
+            source: source.into(),
+            invocation,
+        }
+    }
+
+    /// The logger entries are written to.
+    pub fn logger(&self) -> &AuditLogger {
+        &self.logger
+    }
+
+    /// Source recorded on every entry.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Initiator and client details copied into every entry.
+    pub fn invocation(&self) -> &AuditInvocation {
+        &self.invocation
+    }
+}
+
+/// Runner that records a [`AuditAction::WorkflowStep`](crate::logging::AuditAction::WorkflowStep)
+/// entry for every command the inner runner executes.
+pub struct AuditedCommandRunner<R> {
+    inner: R,
+    registry: CommandRegistry,
+    trail: AuditTrail,
+    workflow_id: Option<String>,
+}
+
+impl<R: CommandRunner> AuditedCommandRunner<R> {
+    /// Wrap `inner`, resolving method, path, and catalog from `registry`.
+    pub fn new(inner: R, registry: CommandRegistry, trail: AuditTrail) -> Self {
+        Self {
+            inner,
+            registry,
+            trail,
+            workflow_id: None,
+        }
+    }
+
+    /// Record the workflow identifier on every step entry.
+    pub fn with_workflow_id(mut self, workflow_id: impl Into<String>) -> Self {
+        self.workflow_id = Some(workflow_id.into());
+        self
+    }
+
+    /// The wrapped runner.
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    fn step_invocation(&self, run: &str, with: Option<&Value>) -> AuditInvocation {
+        let mut invocation = AuditInvocation {
+            canonical_id: Some(run.to_string()),
+            ..self.trail.invocation.clone()
+        };
+        let Some((group, name)) = run.split_once(char::is_whitespace) else {
+            return invocation;
+        };
+        let Ok(command_spec) = self.registry.find_by_group_and_cmd_ref(group, name.trim()) else {
+            return invocation;
+        };
+        invocation.catalog = self
+            .registry
+            .config
+            .catalogs
+            .as_ref()
+            .and_then(|catalogs| catalogs.get(command_spec.catalog_identifier))
+            .map(|catalog| catalog.title.clone());
+        if let Some(http) = command_spec.http() {
+            invocation.method = Some(http.method.to_ascii_uppercase());
+            invocation.path = Some(match with {
+                Some(Value::Object(inputs)) => build_path(&http.path, inputs),
+                _ => http.path.clone(),
+            });
+        }
+        invocation
+    }
+}
+
+impl<R: CommandRunner> CommandRunner for AuditedCommandRunner<R> {
+    fn run(&self, run: &str, with: Option<&Value>, body: Option<&Value>, run_context: &RunContext) -> Result<Value> {
+        let started_at = Instant::now();
+        let outcome = self.inner.run(run, with, body, run_context);
+
+        let invocation = self.step_invocation(run, with).with_duration(started_at.elapsed());
+        let result = if outcome.is_ok() {
+            AuditResult::Success
+        } else {
+            AuditResult::Failure
+        };
+        let mut entry = AuditEntry::workflow_step(self.trail.source.clone(), invocation, result);
+        if let Some(workflow_id) = self.workflow_id.as_ref() {
+            entry = entry.with_metadata("workflow_id", Value::String(workflow_id.clone()));
+        }
+        if let Err(error) = outcome.as_ref() {
+            entry = entry.with_metadata("error", Value::String(format!("{error:#}")));
+        }
+        if let Err(error) = self.trail.logger.log_blocking(entry) {
+            warn!(error = %error, run = %run, "failed to write workflow step audit entry");
+        }
+
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logging::{AuditAction, AuditInitiator, AuditQuery};
+    use oatty_engine::executor::NoopRunner;
+    use tempfile::TempDir;
+
+    #[test]
+    fn every_step_is_recorded_with_initiator_and_workflow() {
+        let temp_dir = TempDir::new().unwrap();
+        let logger = AuditLogger::with_settings(temp_dir.path().join("audit.jsonl"), 1024 * 1024, 7);
+        let trail = AuditTrail::new(logger.clone(), "cli", AuditInvocation::new(AuditInitiator::Human));
+        let runner = AuditedCommandRunner::new(NoopRunner, CommandRegistry::default(), trail).with_workflow_id("deploy");
+
+        runner
+            .run("apps apps:list", None, None, &RunContext::default())
+            .expect("noop runner succeeds");
+
+        let entries = logger.query(&AuditQuery::default()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, AuditAction::WorkflowStep);
+        assert_eq!(entries[0].invocation.initiator, Some(AuditInitiator::Human));
+        assert_eq!(entries[0].invocation.canonical_id.as_deref(), Some("apps apps:list"));
+        assert_eq!(entries[0].metadata["workflow_id"], "deploy");
+        assert!(entries[0].invocation.duration_ms.is_some());
+    }
+}
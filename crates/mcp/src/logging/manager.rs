@@ -11,12 +11,15 @@ use tokio::sync::Mutex;
 
 const DEFAULT_MAX_LOG_ENTRIES_PER_PLUGIN: usize = 1000;
 
+/// Environment variable overriding the audit log location.
+pub const AUDIT_LOG_PATH_ENV: &str = "OATTY_AUDIT_LOG_PATH";
+
 /// Stores and manages logs for all active plugins.
 #[derive(Debug)]
 pub struct LogManager {
     /// Ring buffers keyed by plugin name.
     buffers: Arc<Mutex<HashMap<String, LogRingBuffer>>>,
-    /// Audit logger for plugin lifecycle events.
+    /// Audit logger for plugin lifecycle events, tool calls, and command executions.
     audit_logger: AuditLogger,
     /// Formatter used for serialization and redaction.
     formatter: LogFormatter,
@@ -122,8 +125,11 @@ impl Default for LogManager {
     }
 }
 
-/// Returns the default path for MCP audit logs.
+/// Returns the default path for the audit log, honoring [`AUDIT_LOG_PATH_ENV`].
 pub fn default_audit_log_path() -> PathBuf {
+    if let Some(path) = std::env::var_os(AUDIT_LOG_PATH_ENV).filter(|path| !path.is_empty()) {
+        return PathBuf::from(path);
+    }
     config_dir()
         .unwrap_or_else(|| PathBuf::from("."))
         .join("oatty")
@@ -1,11 +1,15 @@
-//! Logging system for MCP plugins.
+//! Logging system for MCP plugins and the audit trail.
 
 mod audit;
+mod audit_query;
+mod audit_runner;
 mod formatter;
 mod manager;
 mod ring_buffer;
 
-pub use audit::{AuditAction, AuditEntry, AuditLogger, AuditResult};
+pub use audit::{AuditAction, AuditEntry, AuditError, AuditInitiator, AuditInvocation, AuditLogger, AuditResult};
+pub use audit_query::{AuditQuery, AuditStatusFilter, parse_audit_since};
+pub use audit_runner::{AuditTrail, AuditedCommandRunner};
 pub use formatter::{LogFormatter, RedactionRules};
-pub use manager::{LogManager, default_audit_log_path, sanitize_log_text};
+pub use manager::{AUDIT_LOG_PATH_ENV, LogManager, default_audit_log_path, sanitize_log_text};
 pub use ring_buffer::LogRingBuffer;
@@ -0,0 +1,279 @@
+//! Synthetic commands and payload shaping for plugin resources and prompts.
+//!
+//! Tools map one-to-one onto palette commands (see [`super::PluginEngine`]). Resources and prompts are
+//! exposed alongside them under the plugin's own command group:
+//!
+//! - `<plugin> resources:read <uri>` reads any resource (or templated URI) the plugin publishes. The
+//!   same command backs workflow value providers, so a provider can list items from a resource.
+//! - `<plugin> prompts:<name>` renders a prompt template, with required prompt arguments as
+//!   positionals and optional ones as flags.
+
+use oatty_registry::CommandSpec;
+use oatty_types::{CommandFlag, McpCommandKind, McpCommandSpec, PositionalArgument};
+use rmcp::model::{GetPromptResult, PromptMessageContent, ReadResourceResult, ResourceContents};
+use serde_json::{Map, Value, json};
+
+use crate::types::{McpPluginContent, PluginPromptSummary};
+
+use super::PluginEngine;
+
+/// Command name for the synthetic resource reader.
+pub(crate) const RESOURCE_READ_COMMAND: &str = "resources:read";
+/// MCP method recorded as the tool name for resource reads.
+pub(crate) const RESOURCE_READ_METHOD: &str = "resources/read";
+/// Positional argument carrying the resource URI.
+pub(crate) const RESOURCE_URI_ARGUMENT: &str = "uri";
+/// Prefix applied to synthetic prompt command names.
+const PROMPT_COMMAND_PREFIX: &str = "prompts";
+
+/// Convert a plugin's resources and prompts into synthetic command specifications.
+pub(crate) fn synthesize_content_specs(plugin_name: &str, content: &McpPluginContent, auth_message: Option<&str>) -> Vec<CommandSpec> {
+    let mut specs = Vec::with_capacity(content.prompts.len() + 1);
+
+    if content.has_readable_resources() {
+        specs.push(CommandSpec::new_mcp(
+            plugin_name.to_string(),
+            RESOURCE_READ_COMMAND.to_string(),
+            with_auth_message(&format!("Read a resource published by {plugin_name}"), auth_message),
+            vec![PositionalArgument {
+                name: RESOURCE_URI_ARGUMENT.to_string(),
+                help: Some(resource_uri_help(content)),
+                provider: None,
+            }],
+            Vec::new(),
+            mcp_spec(plugin_name, RESOURCE_READ_METHOD, McpCommandKind::ResourceRead, auth_message),
+        ));
+    }
+
+    for prompt in &content.prompts {
+        let formatted = PluginEngine::format_command_segments(&prompt.name);
+        let command_name = format!(
+            "{PROMPT_COMMAND_PREFIX}:{}",
+            if formatted.is_empty() {
+                prompt.name.as_str()
+            } else {
+                formatted.as_str()
+            }
+        );
+        let body = prompt
+            .description
+            .as_deref()
+            .or(prompt.title.as_deref())
+            .unwrap_or(prompt.name.as_str());
+        let (positionals, flags) = prompt_inputs(prompt);
+        specs.push(CommandSpec::new_mcp(
+            plugin_name.to_string(),
+            command_name,
+            with_auth_message(body, auth_message),
+            positionals,
+            flags,
+            mcp_spec(plugin_name, &prompt.name, McpCommandKind::Prompt, auth_message),
+        ));
+    }
+
+    specs.sort_by(|a, b| a.name.cmp(&b.name));
+    specs
+}
+
+/// Convert command arguments into prompt arguments, which MCP requires to be strings.
+pub(crate) fn prompt_arguments(arguments: &Map<String, Value>) -> Map<String, Value> {
+    arguments
+        .iter()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(name, value)| {
+            let text = match value {
+                Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            (name.clone(), Value::String(text))
+        })
+        .collect()
+}
+
+/// Shape a `resources/read` result into a JSON payload.
+///
+/// Text contents that hold JSON are parsed so providers and result views can work with structured
+/// data; other text is kept verbatim. A single content entry is returned unwrapped.
+pub(crate) fn resource_payload(result: ReadResourceResult) -> Value {
+    let mut contents: Vec<Value> = result
+        .contents
+        .into_iter()
+        .map(|contents| match contents {
+            ResourceContents::TextResourceContents { text, .. } => serde_json::from_str(&text).unwrap_or(Value::String(text)),
+            ResourceContents::BlobResourceContents { uri, mime_type, blob, .. } => json!({
+                "uri": uri,
+                "mime_type": mime_type,
+                "blob": blob,
+            }),
+        })
+        .collect();
+
+    if contents.len() == 1 {
+        contents.remove(0)
+    } else {
+        Value::Array(contents)
+    }
+}
+
+/// Shape a `prompts/get` result into a JSON payload of `{ description, messages: [{ role, text }] }`.
+pub(crate) fn prompt_payload(result: GetPromptResult) -> Value {
+    let messages: Vec<Value> = result
+        .messages
+        .into_iter()
+        .map(|message| {
+            let role = serde_json::to_value(message.role).unwrap_or(Value::Null);
+            match message.content {
+                PromptMessageContent::Text { text } => json!({ "role": role, "text": text }),
+                other => json!({ "role": role, "content": serde_json::to_value(other).unwrap_or(Value::Null) }),
+            }
+        })
+        .collect();
+
+    let mut payload = Map::new();
+    if let Some(description) = result.description {
+        payload.insert("description".to_string(), Value::String(description));
+    }
+    payload.insert("messages".to_string(), Value::Array(messages));
+    Value::Object(payload)
+}
+
+fn mcp_spec(plugin_name: &str, tool_name: &str, kind: McpCommandKind, auth_message: Option<&str>) -> McpCommandSpec {
+    McpCommandSpec {
+        plugin_name: plugin_name.to_string(),
+        tool_name: tool_name.to_string(),
+        kind,
+        auth_summary: auth_message.map(String::from),
+        output_schema: None,
+        render_hint: None,
+        read_only_hint: false,
+    }
+}
+
+fn with_auth_message(body: &str, auth_message: Option<&str>) -> String {
+    match auth_message {
+        Some(message) if !message.is_empty() => format!("{message} — {body}"),
+        _ => body.to_string(),
+    }
+}
+
+fn resource_uri_help(content: &McpPluginContent) -> String {
+    let examples: Vec<&str> = content
+        .resources
+        .iter()
+        .map(|resource| resource.uri.as_str())
+        .chain(content.resource_templates.iter().map(|template| template.uri_template.as_str()))
+        .take(3)
+        .collect();
+    format!("Resource URI (for example: {}).", examples.join(", "))
+}
+
+fn prompt_inputs(prompt: &PluginPromptSummary) -> (Vec<PositionalArgument>, Vec<CommandFlag>) {
+    let mut positionals = Vec::new();
+    let mut flags = Vec::new();
+    for argument in &prompt.arguments {
+        if argument.required {
+            positionals.push(PositionalArgument {
+                name: argument.name.clone(),
+                help: argument.description.clone(),
+                provider: None,
+            });
+        } else {
+            flags.push(CommandFlag {
+                name: argument.name.clone(),
+                short_name: None,
+                required: false,
+                r#type: "string".to_string(),
+                enum_values: Vec::new(),
+                default_value: None,
+                description: argument.description.clone(),
+                provider: None,
+                schema: None,
+            });
+        }
+    }
+    (positionals, flags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PluginPromptArgument, PluginResourceSummary};
+    use rmcp::model::{PromptMessage, PromptMessageRole};
+
+    fn content() -> McpPluginContent {
+        McpPluginContent {
+            resources: vec![PluginResourceSummary {
+                uri: "file:///notes.md".to_string(),
+                name: "notes".to_string(),
+                ..Default::default()
+            }],
+            resource_templates: Vec::new(),
+            prompts: vec![PluginPromptSummary {
+                name: "code_review".to_string(),
+                title: None,
+                description: Some("Review a diff".to_string()),
+                arguments: vec![
+                    PluginPromptArgument {
+                        name: "diff".to_string(),
+                        description: None,
+                        required: true,
+                    },
+                    PluginPromptArgument {
+                        name: "tone".to_string(),
+                        description: None,
+                        required: false,
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn synthesizes_resource_reader_and_prompt_commands() {
+        let specs = synthesize_content_specs("docs", &content(), None);
+        let names: Vec<&str> = specs.iter().map(|spec| spec.name.as_str()).collect();
+        assert_eq!(names, vec!["prompts:code:review", "resources:read"]);
+
+        let prompt = &specs[0];
+        assert_eq!(prompt.group, "docs");
+        assert_eq!(prompt.positional_args[0].name, "diff");
+        assert_eq!(prompt.flags[0].name, "tone");
+        let mcp = prompt.mcp().expect("mcp spec");
+        assert_eq!(mcp.kind, McpCommandKind::Prompt);
+        assert_eq!(mcp.tool_name, "code_review");
+
+        let reader = specs[1].mcp().expect("mcp spec");
+        assert_eq!(reader.kind, McpCommandKind::ResourceRead);
+        assert_eq!(specs[1].positional_args[0].name, RESOURCE_URI_ARGUMENT);
+    }
+
+    #[test]
+    fn resource_payload_parses_json_text_and_keeps_plain_text() {
+        let json_result = ReadResourceResult {
+            contents: vec![ResourceContents::text(r#"[{"id":1}]"#, "db://rows")],
+        };
+        assert_eq!(resource_payload(json_result), json!([{ "id": 1 }]));
+
+        let text_result = ReadResourceResult {
+            contents: vec![ResourceContents::text("a", "file:///a"), ResourceContents::text("b", "file:///b")],
+        };
+        assert_eq!(resource_payload(text_result), json!(["a", "b"]));
+    }
+
+    #[test]
+    fn prompt_payload_flattens_text_messages_and_stringifies_arguments() {
+        let result = GetPromptResult {
+            description: Some("Review".to_string()),
+            messages: vec![PromptMessage::new_text(PromptMessageRole::User, "Please review")],
+        };
+        assert_eq!(
+            prompt_payload(result),
+            json!({ "description": "Review", "messages": [{ "role": "user", "text": "Please review" }] })
+        );
+
+        let mut arguments = Map::new();
+        arguments.insert("count".to_string(), json!(3));
+        arguments.insert("skip".to_string(), Value::Null);
+        assert_eq!(prompt_arguments(&arguments), json!({ "count": "3" }).as_object().cloned().unwrap());
+    }
+}
@@ -1,13 +1,15 @@
 //! Plugin engine implementation.
 
 use crate::McpServer;
-use crate::client::{ClientGatewayEvent, McpClientGateway};
+use crate::client::oauth::{self, OAUTH_AUTHORIZATION_REQUIRED};
+use crate::client::{ClientGatewayError, ClientGatewayEvent, McpClientGateway};
 use crate::config::McpConfig;
 use crate::logging::{AuditEntry, AuditResult, LogManager};
+use crate::plugin::content::{RESOURCE_URI_ARGUMENT, prompt_arguments, prompt_payload, resource_payload, synthesize_content_specs};
 use crate::plugin::{LifecycleManager, PluginRegistry, RegistryError};
-use crate::types::{AuthStatus, McpToolMetadata, PluginDetail, PluginStatus, PluginToolSummary};
+use crate::types::{AuthStatus, McpLogEntry, McpPluginContent, McpToolMetadata, PluginDetail, PluginStatus, PluginToolSummary};
 use oatty_registry::{CommandRegistry, CommandSpec};
-use oatty_types::{CommandFlag, ExecOutcome, McpCommandSpec, PositionalArgument};
+use oatty_types::{CommandFlag, ExecOutcome, McpCommandKind, McpCommandSpec, PositionalArgument};
 use oatty_util::resolve_output_schema;
 use rmcp::model::InitializeResult;
 use serde_json::Value;
@@ -40,6 +42,15 @@ pub struct PluginEngine {
     /// Synthetic command specifications synthesized from MCP tools.
     synthetic_specs: Arc<TokioMutex<HashMap<String, Arc<[CommandSpec]>>>>,
 
+    /// Cache of resources, resource templates, and prompts discovered per plugin.
+    content_cache: Arc<TokioMutex<HashMap<String, Arc<McpPluginContent>>>>,
+
+    /// Synthetic command specifications synthesized from MCP resources and prompts.
+    content_specs: Arc<TokioMutex<HashMap<String, Arc<[CommandSpec]>>>>,
+
+    /// Resource URIs (per plugin) that already have an update subscription.
+    resource_subscriptions: Arc<TokioMutex<HashSet<(String, String)>>>,
+
     /// Lifecycle manager for plugin lifecycle.
     lifecycle_manager: LifecycleManager,
 
@@ -66,6 +77,9 @@ impl PluginEngine {
             plugin_registry: Arc::new(TokioMutex::new(None)),
             tool_cache: Arc::new(TokioMutex::new(HashMap::new())),
             synthetic_specs: Arc::new(TokioMutex::new(HashMap::new())),
+            content_cache: Arc::new(TokioMutex::new(HashMap::new())),
+            content_specs: Arc::new(TokioMutex::new(HashMap::new())),
+            resource_subscriptions: Arc::new(TokioMutex::new(HashSet::new())),
             lifecycle_manager,
             config: RwLock::new(config),
             command_registry,
@@ -117,6 +131,10 @@ impl PluginEngine {
         let tool_cache = Arc::clone(&self.tool_cache);
         let synthetic_specs = Arc::clone(&self.synthetic_specs);
         let command_registry = Arc::clone(&self.command_registry);
+        let content_cache = Arc::clone(&self.content_cache);
+        let content_specs = Arc::clone(&self.content_specs);
+        let resource_subscriptions = Arc::clone(&self.resource_subscriptions);
+        let log_manager = Arc::clone(&self.log_manager);
 
         let handle = tokio::spawn(async move {
             loop {
@@ -156,17 +174,74 @@ impl PluginEngine {
                             }
                         }
                     }
+                    Ok(ClientGatewayEvent::ContentUpdated { name, content }) => {
+                        {
+                            let mut cache = content_cache.lock().await;
+                            if content.is_empty() {
+                                cache.remove(&name);
+                                resource_subscriptions.lock().await.retain(|(plugin, _)| plugin != &name);
+                            } else {
+                                cache.insert(name.clone(), Arc::clone(&content));
+                            }
+                        }
+
+                        let auth_message = registry
+                            .get_plugin(&name)
+                            .and_then(|detail| PluginEngine::format_auth_summary(detail.auth_status));
+                        let synthesized = synthesize_content_specs(&name, content.as_ref(), auth_message.as_deref());
+
+                        let mut content_specs_lock = content_specs.lock().await;
+                        if let Ok(mut registry_lock) = command_registry.lock() {
+                            if let Some(previous) = content_specs_lock.remove(&name) {
+                                registry_lock.remove_commands(previous.iter().map(|spec| spec.canonical_id()).collect());
+                            }
+                            if !synthesized.is_empty() {
+                                let syn_arc: Arc<[CommandSpec]> = Arc::from(synthesized);
+                                registry_lock.insert_commands(syn_arc.clone());
+                                content_specs_lock.insert(name.clone(), syn_arc);
+                            }
+                        }
+                    }
+                    Ok(ClientGatewayEvent::ResourceUpdated { name, uri }) => {
+                        {
+                            let mut cache = content_cache.lock().await;
+                            if let Some(content) = cache.get_mut(&name) {
+                                let content = Arc::make_mut(content);
+                                let updated_at = chrono::Utc::now();
+                                content
+                                    .resources
+                                    .iter_mut()
+                                    .filter(|resource| resource.uri == uri)
+                                    .for_each(|resource| resource.updated_at = Some(updated_at));
+                            }
+                        }
+                        let entry = McpLogEntry::system(format!("Resource updated: {uri}"), name.clone());
+                        let _ = log_manager.add_log(&name, entry).await;
+                    }
                     Ok(event) => {
                         let (name, status) = match event {
                             ClientGatewayEvent::Starting { name } => (name, PluginStatus::Starting),
-                            ClientGatewayEvent::Started { name } => (name, PluginStatus::Running),
+                            ClientGatewayEvent::Started { name } => {
+                                if registry
+                                    .get_plugin(&name)
+                                    .is_some_and(|detail| detail.auth_status != AuthStatus::Unknown)
+                                {
+                                    let _ = registry.set_plugin_auth_status(&name, AuthStatus::Authorized);
+                                }
+                                (name, PluginStatus::Running)
+                            }
                             ClientGatewayEvent::StartFailed { name, error } => {
                                 tracing::warn!(plugin = %name, error = %error, "Plugin failed to start");
+                                if error.contains(OAUTH_AUTHORIZATION_REQUIRED) {
+                                    let _ = registry.set_plugin_auth_status(&name, AuthStatus::Required);
+                                }
                                 (name, PluginStatus::Error)
                             }
                             ClientGatewayEvent::Stopping { name } => (name, PluginStatus::Stopping),
                             ClientGatewayEvent::Stopped { name } => (name, PluginStatus::Stopped),
-                            ClientGatewayEvent::ToolsUpdated { .. } => unreachable!("tools updates handled above"),
+                            ClientGatewayEvent::ToolsUpdated { .. }
+                            | ClientGatewayEvent::ContentUpdated { .. }
+                            | ClientGatewayEvent::ResourceUpdated { .. } => unreachable!("tool and content updates handled above"),
                         };
                         if let Err(update_err) = registry.set_plugin_status(&name, status) {
                             tracing::warn!(plugin = %name, error = %update_err, "Failed to update registry status");
@@ -268,6 +343,63 @@ impl PluginEngine {
         Ok(())
     }
 
+    /// Run the interactive OAuth flow for an HTTP plugin, then (re)start it with the new credentials.
+    ///
+    /// The authorization URL is opened in the default browser and also written to the plugin log
+    /// so it can be opened by hand.
+    pub async fn authorize_plugin(&self, name: &str) -> Result<(), PluginEngineError> {
+        let server = self
+            .config
+            .read()
+            .await
+            .mcp_servers
+            .get(name)
+            .cloned()
+            .ok_or_else(|| PluginEngineError::PluginNotFound { name: name.to_string() })?;
+        if !server.uses_oauth() {
+            return Err(PluginEngineError::ConfigurationError {
+                message: format!("plugin '{name}' is not configured for OAuth"),
+            });
+        }
+
+        let log_manager = Arc::clone(&self.log_manager);
+        let plugin_name = name.to_string();
+        let result = oauth::authorize(&server, move |url| {
+            let entry = McpLogEntry::system(format!("Authorize in your browser: {url}"), plugin_name.clone());
+            tokio::spawn(async move {
+                let _ = log_manager.add_log(&plugin_name, entry).await;
+            });
+            if let Err(error) = oauth::open_in_browser(url) {
+                tracing::warn!(error = %error, "could not open a browser for OAuth authorization");
+            }
+            Ok(())
+        })
+        .await;
+
+        let auth_status = if result.is_ok() {
+            AuthStatus::Authorized
+        } else {
+            AuthStatus::Failed
+        };
+        if let Ok(mut registry) = self.prepare_registry().await {
+            let _ = registry.set_plugin_auth_status(name, auth_status);
+        }
+        let message = match &result {
+            Ok(()) => "OAuth authorization completed".to_string(),
+            Err(error) => format!("OAuth authorization failed: {error:#}"),
+        };
+        let _ = self.log_manager.add_log(name, McpLogEntry::system(message, name.to_string())).await;
+        result.map_err(|error| PluginEngineError::ClientManagerError(format!("{error:#}")))?;
+
+        if !server.is_disabled() {
+            self.client_manager
+                .restart_plugin(name)
+                .await
+                .map_err(|error| PluginEngineError::ClientManagerError(error.to_string()))?;
+        }
+        Ok(())
+    }
+
     /// Restart a plugin.
     pub async fn restart_plugin(&self, name: &str) -> Result<(), PluginEngineError> {
         let Ok(mut registry) = self.prepare_registry().await else {
@@ -335,6 +467,11 @@ impl PluginEngine {
         plugin_detail.logs = logs;
         plugin_detail.tool_count = tool_summaries.len();
         plugin_detail.tools = tool_summaries;
+        if let Some(content) = self.plugin_content(name).await {
+            plugin_detail.resources = content.resources.clone();
+            plugin_detail.resource_templates = content.resource_templates.clone();
+            plugin_detail.prompts = content.prompts.clone();
+        }
 
         Ok(plugin_detail)
     }
@@ -439,6 +576,10 @@ impl PluginEngine {
             overlay.clear();
         }
 
+        self.content_cache.lock().await.clear();
+        self.content_specs.lock().await.clear();
+        self.resource_subscriptions.lock().await.clear();
+
         // Update client manager configuration
         self.client_manager
             .update_config(config.clone())
@@ -478,6 +619,18 @@ impl PluginEngine {
         cache.get(name).cloned()
     }
 
+    /// Return the current resources, resource templates, and prompts for the requested plugin.
+    ///
+    /// Falls back to the live client snapshot when the content event has not been observed yet.
+    pub async fn plugin_content(&self, name: &str) -> Option<Arc<McpPluginContent>> {
+        if let Some(content) = self.content_cache.lock().await.get(name).cloned() {
+            return Some(content);
+        }
+        let client_handle = self.client_manager.get_client(name).await?;
+        let content = client_handle.lock().await.content();
+        (!content.is_empty()).then_some(content)
+    }
+
     /// Load tool summaries for a plugin using cache-first semantics with a live-client fallback.
     ///
     /// This protects details rendering when startup tool events were emitted before the
@@ -543,11 +696,22 @@ impl PluginEngine {
         let plugin_name = mcp.plugin_name.clone();
         let tool_name = mcp.tool_name.clone();
 
-        let call_result = self.client_manager.call_tool(&plugin_name, &tool_name, arguments).await;
+        let call_result = match mcp.kind {
+            McpCommandKind::Tool => self
+                .client_manager
+                .call_tool(&plugin_name, &tool_name, arguments)
+                .await
+                .map(Self::normalize_tool_result),
+            McpCommandKind::ResourceRead => self.read_resource(&plugin_name, arguments).await,
+            McpCommandKind::Prompt => self
+                .client_manager
+                .get_prompt(&plugin_name, &tool_name, Some(prompt_arguments(arguments)))
+                .await
+                .map(|result| (false, prompt_payload(result))),
+        };
 
         let (is_error, payload) = match call_result {
-            Ok(result) => {
-                let (is_error, payload) = Self::normalize_tool_result(result);
+            Ok((is_error, payload)) => {
                 let audit_result = if is_error { AuditResult::Failure } else { AuditResult::Success };
                 let entry = AuditEntry::tool_invoke(plugin_name.clone(), tool_name.clone(), audit_result);
                 let _ = self.log_manager.log_audit(entry).await;
@@ -582,6 +746,32 @@ impl PluginEngine {
         })
     }
 
+    /// Read the resource named by the `uri` argument, subscribing to its updates on first read.
+    async fn read_resource(
+        &self,
+        plugin_name: &str,
+        arguments: &serde_json::Map<String, Value>,
+    ) -> Result<(bool, Value), ClientGatewayError> {
+        let uri = arguments
+            .get(RESOURCE_URI_ARGUMENT)
+            .and_then(Value::as_str)
+            .filter(|uri| !uri.trim().is_empty())
+            .ok_or_else(|| ClientGatewayError::ConnectionError {
+                message: format!("resources/read requires a '{RESOURCE_URI_ARGUMENT}' argument"),
+            })?;
+
+        let result = self.client_manager.read_resource(plugin_name, uri).await?;
+
+        let subscription = (plugin_name.to_string(), uri.to_string());
+        let newly_tracked = self.resource_subscriptions.lock().await.insert(subscription.clone());
+        if newly_tracked && let Err(error) = self.client_manager.subscribe_resource(plugin_name, uri).await {
+            tracing::debug!(plugin = %plugin_name, uri = %uri, error = %error, "Failed to subscribe to resource updates");
+            self.resource_subscriptions.lock().await.remove(&subscription);
+        }
+
+        Ok((false, resource_payload(result)))
+    }
+
     /// Convert MCP tool metadata into synthetic CLI command specifications.
     ///
     /// When every tool name shares the same prefix (up to the first underscore), the prefix is
@@ -622,13 +812,21 @@ impl PluginEngine {
                     .and_then(|value| value.as_str().map(String::from)),
                 _ => None,
             });
+            let read_only_hint = tool
+                .annotations
+                .as_ref()
+                .and_then(|annotations| annotations.get("readOnlyHint"))
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
 
             let mcp_spec = McpCommandSpec {
                 plugin_name: plugin_name.to_string(),
                 tool_name: tool.name.clone(),
+                kind: McpCommandKind::Tool,
                 auth_summary: auth_message.map(String::from),
                 output_schema,
                 render_hint,
+                read_only_hint,
             };
 
             specs.push(CommandSpec::new_mcp(group, command_name, summary, positionals, flags, mcp_spec));
@@ -652,6 +850,8 @@ impl PluginEngine {
             plugin_detail.transport_type = server.transport_type().to_string();
             plugin_detail.tags = server.tags.clone().unwrap_or_default();
             plugin_detail.enabled = !server.is_disabled();
+            plugin_detail.auth_scheme = server.auth.as_ref().map(|auth| auth.scheme.clone());
+            plugin_detail.auth_status = oauth::stored_auth_status(server);
             plugin_detail.env = if server.is_stdio() {
                 server.env.clone()
             } else {
@@ -687,7 +887,7 @@ impl PluginEngine {
     }
 
     /// Convert underscore separated tool identifiers into colon delimited command names.
-    fn format_command_segments(raw_name: &str) -> String {
+    pub(super) fn format_command_segments(raw_name: &str) -> String {
         let segments: Vec<&str> = raw_name.split('_').filter(|segment| !segment.is_empty()).collect();
         if segments.is_empty() { String::new() } else { segments.join(":") }
     }
@@ -895,6 +1095,7 @@ impl FieldDescriptor {
             default_value: self.default_value,
             description: self.description,
             provider: None,
+            schema: None,
         }
     }
 
@@ -1,5 +1,6 @@
 //! Plugin engine for managing MCP plugins.
 
+mod content;
 mod engine;
 mod lifecycle;
 mod registry;
@@ -1,6 +1,6 @@
 //! Plugin registry for managing plugin metadata.
 
-use crate::types::{PluginDetail, PluginStatus};
+use crate::types::{AuthStatus, PluginDetail, PluginStatus};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
@@ -106,6 +106,19 @@ impl PluginRegistry {
         }
     }
 
+    /// Update the authorization status reported for a plugin.
+    pub fn set_plugin_auth_status(&mut self, name: &str, auth_status: AuthStatus) -> Result<(), RegistryError> {
+        let mut plugins = self.plugins.write().map_err(|_| RegistryError::OperationFailed {
+            reason: LOCK_ERROR_MSG.to_string(),
+        })?;
+        if let Some(detail) = plugins.get_mut(name) {
+            detail.auth_status = auth_status;
+            Ok(())
+        } else {
+            Err(RegistryError::PluginNotFound { name: name.to_string() })
+        }
+    }
+
     /// Get all plugin names.
     pub fn get_plugin_names(&self) -> Vec<String> {
         let Ok(plugins) = self.plugins.read() else {
@@ -0,0 +1,91 @@
+//! Provider value fetcher that understands MCP-backed commands.
+
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use indexmap::IndexSet;
+use oatty_engine::provider::{DefaultHttpFetcher, ProviderValueFetcher};
+use oatty_registry::CommandSpec;
+use oatty_types::{EnvVar, ExecOutcome};
+use oatty_util::{block_on_future, http::extract_provider_collection_items};
+use serde_json::{Map as JsonMap, Value};
+
+use crate::plugin::PluginEngine;
+
+/// Fetches provider values from HTTP catalogs and running MCP plugins.
+///
+/// HTTP-backed commands are delegated to [`DefaultHttpFetcher`]. MCP-backed commands (tools,
+/// `resources:read`, prompts) execute through the [`PluginEngine`], so workflow inputs can list values
+/// straight from a plugin resource.
+pub struct McpProviderFetcher {
+    plugin_engine: Arc<PluginEngine>,
+    http: DefaultHttpFetcher,
+}
+
+impl McpProviderFetcher {
+    /// Create a fetcher backed by the given plugin engine.
+    pub fn new(plugin_engine: Arc<PluginEngine>) -> Self {
+        Self {
+            plugin_engine,
+            http: DefaultHttpFetcher,
+        }
+    }
+}
+
+impl ProviderValueFetcher for McpProviderFetcher {
+    fn fetch_list(
+        &self,
+        spec: CommandSpec,
+        args: &JsonMap<String, Value>,
+        base_url: &str,
+        headers: &IndexSet<EnvVar>,
+    ) -> anyhow::Result<Vec<Value>> {
+        if spec.mcp().is_none() {
+            return self.http.fetch_list(spec, args, base_url, headers);
+        }
+
+        let spec_name = spec.name.clone();
+        let plugin_engine = Arc::clone(&self.plugin_engine);
+        let arguments = args.clone();
+        let outcome = block_on_future(async move { plugin_engine.execute_tool(&spec, &arguments, 0).await.map_err(anyhow::Error::from) })?;
+
+        match outcome {
+            ExecOutcome::Mcp { payload, .. } => {
+                mcp_provider_items(payload).ok_or_else(|| anyhow!("provider command '{}' returned non-array payload", spec_name))
+            }
+            _ => Err(anyhow!("provider command '{}' returned non-mcp outcome", spec_name)),
+        }
+    }
+}
+
+/// Extract provider items from an MCP payload; plain text resources yield one item per non-empty line.
+fn mcp_provider_items(payload: Value) -> Option<Vec<Value>> {
+    match payload {
+        Value::String(text) => Some(
+            text.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(|line| Value::String(line.to_string()))
+                .collect(),
+        ),
+        other => extract_provider_collection_items(&other, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn mcp_provider_items_split_text_and_unwrap_collections() {
+        assert_eq!(
+            mcp_provider_items(Value::String("alpha\n\n beta \n".to_string())),
+            Some(vec![json!("alpha"), json!("beta")])
+        );
+        assert_eq!(
+            mcp_provider_items(json!({ "tables": [{ "name": "users" }] })),
+            Some(vec![json!({ "name": "users" })])
+        );
+    }
+}
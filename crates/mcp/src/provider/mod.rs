@@ -1,9 +1,11 @@
 //! MCP provider integration for the engine.
 
 mod adapter;
+mod fetcher;
 mod mcp_provider;
 mod registry;
 
 pub use adapter::{AdapterError, McpProviderAdapter};
+pub use fetcher::McpProviderFetcher;
 pub use mcp_provider::McpProvider;
 pub use registry::{McpProviderError, McpProviderOps, McpProviderRegistry};
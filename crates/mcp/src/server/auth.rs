@@ -0,0 +1,295 @@
+//! Bearer-token authentication for the MCP HTTP server.
+//!
+//! Each client is issued a random token once, at creation time. The config file only lists
+//! client names (`httpServer.clients`); the SHA-256 digest of each token is kept in the OS
+//! keystore, so neither the config file nor the keystore ever hold a usable credential.
+//!
+//! The running server re-reads the client list whenever the config file changes on disk,
+//! which makes revocations from the CLI or the TUI take effect without a restart.
+
+use std::fmt::Write as _;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::Utc;
+use oatty_util::{SecretsBackend, remove_secret, resolve_secret, secrets_backend, store_secret};
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use crate::config::{McpConfig, McpHttpClientToken, McpHttpServerConfig, save_config_to_path, validate_server_name};
+
+/// Prefix that makes issued tokens recognizable in logs and secret scanners.
+const CLIENT_TOKEN_PREFIX: &str = "oatty_mcp_";
+/// Number of random bytes in an issued token.
+const CLIENT_TOKEN_BYTES: usize = 32;
+
+/// Identity of the client that authenticated a request, attached to the request extensions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpAuthenticatedClient(pub String);
+
+/// Issue a new token for `name`, store its digest in the keystore, and record the client in
+/// the config file at `config_path`.
+///
+/// Returns the plain token. It is not stored anywhere and cannot be shown again.
+pub fn create_client_token(config_path: &Path, name: &str) -> Result<String> {
+    validate_server_name(name).map_err(|error| anyhow!("invalid client name: {error}"))?;
+    if secrets_backend() == SecretsBackend::Environment {
+        bail!("client tokens are stored in the OS keychain; unset OATTY_SECRETS_BACKEND to create one");
+    }
+
+    let mut config = read_raw_config(config_path)?;
+    if config.http_server.client(name).is_some() {
+        bail!("a client named '{name}' already has a token; revoke it first to issue a new one");
+    }
+
+    let token = generate_client_token()?;
+    store_secret(&client_secret_name(name), &token_digest(&token))?;
+    config.http_server.clients.push(McpHttpClientToken {
+        name: name.to_string(),
+        created_at: Utc::now(),
+    });
+    save_config_to_path(&mut config, config_path)?;
+    Ok(token)
+}
+
+/// Remove the client `name` from the config file and delete its digest from the keystore.
+pub fn revoke_client_token(config_path: &Path, name: &str) -> Result<()> {
+    let mut config = read_raw_config(config_path)?;
+    let Some(index) = config.http_server.clients.iter().position(|client| client.name == name) else {
+        bail!("no client named '{name}' has a token");
+    };
+
+    config.http_server.clients.remove(index);
+    save_config_to_path(&mut config, config_path)?;
+    if let Err(error) = remove_secret(&client_secret_name(name)) {
+        warn!(client = %name, error = %error, "revoked client token had no keystore entry");
+    }
+    Ok(())
+}
+
+/// Clients that currently hold a token, in issue order.
+pub fn list_client_tokens(config_path: &Path) -> Result<Vec<McpHttpClientToken>> {
+    Ok(load_http_server_config(config_path)?.clients)
+}
+
+/// Read only the `httpServer` section, without interpolating plugin secrets.
+pub fn load_http_server_config(config_path: &Path) -> Result<McpHttpServerConfig> {
+    Ok(read_raw_config(config_path)?.http_server)
+}
+
+fn read_raw_config(config_path: &Path) -> Result<McpConfig> {
+    if !config_path.exists() {
+        return Ok(McpConfig::default());
+    }
+    let content = read_to_string(config_path).with_context(|| format!("failed to read {}", config_path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("failed to parse {}", config_path.display()))
+}
+
+/// Validates bearer tokens against the clients listed in the config file.
+#[derive(Debug)]
+pub struct McpClientAuthenticator {
+    config_path: PathBuf,
+    clients: RwLock<LoadedClients>,
+}
+
+#[derive(Debug, Default)]
+struct LoadedClients {
+    modified: Option<SystemTime>,
+    /// Whether a client has been listed, even one whose digest is missing from the keystore.
+    configured: bool,
+    digests: Vec<ClientDigest>,
+}
+
+#[derive(Debug, Clone)]
+struct ClientDigest {
+    name: String,
+    digest: String,
+}
+
+impl McpClientAuthenticator {
+    /// Load the clients listed in `config_path` and their token digests.
+    pub fn load(config_path: PathBuf) -> Result<Self> {
+        let authenticator = Self {
+            config_path,
+            clients: RwLock::new(LoadedClients::default()),
+        };
+        let loaded = authenticator.read_clients()?;
+        *authenticator.clients.write().map_err(|_| anyhow!("client token lock poisoned"))? = loaded;
+        Ok(authenticator)
+    }
+
+    /// Return the name of the client owning `token`, or `None` when the token is unknown or revoked.
+    pub fn authenticate(&self, token: &str) -> Option<String> {
+        self.reload_if_changed();
+        let presented = token_digest(token);
+        let clients = self.clients.read().ok()?;
+        clients
+            .digests
+            .iter()
+            .find(|client| digests_match(&client.digest, &presented))
+            .map(|client| client.name.clone())
+    }
+
+    /// Whether any client has been configured since the server started.
+    ///
+    /// Tokens created while the server runs switch authentication on; it stays on afterwards.
+    pub fn has_clients(&self) -> bool {
+        self.reload_if_changed();
+        self.clients.read().map(|clients| clients.configured).unwrap_or(true)
+    }
+
+    fn reload_if_changed(&self) {
+        let modified = config_modified_at(&self.config_path);
+        let unchanged = self.clients.read().map(|clients| clients.modified == modified).unwrap_or(false);
+        if unchanged {
+            return;
+        }
+        match self.read_clients() {
+            Ok(mut loaded) => {
+                if let Ok(mut clients) = self.clients.write() {
+                    // Once a client existed, revoking the last one must not reopen the server.
+                    loaded.configured |= clients.configured;
+                    *clients = loaded;
+                }
+            }
+            // Keep the previous set: failing open on a half-written config would be worse.
+            Err(error) => warn!(error = %error, "failed to reload MCP client tokens"),
+        }
+    }
+
+    fn read_clients(&self) -> Result<LoadedClients> {
+        let modified = config_modified_at(&self.config_path);
+        let config = load_http_server_config(&self.config_path)?;
+        let configured = !config.clients.is_empty();
+        let mut digests = Vec::with_capacity(config.clients.len());
+        for client in config.clients {
+            match resolve_secret(&client_secret_name(&client.name)) {
+                Ok(digest) => digests.push(ClientDigest { name: client.name, digest }),
+                Err(error) => warn!(client = %client.name, error = %error, "MCP client token digest missing from keystore"),
+            }
+        }
+        Ok(LoadedClients {
+            modified,
+            configured,
+            digests,
+        })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_tokens(tokens: &[(&str, &str)]) -> Self {
+        let digests = tokens
+            .iter()
+            .map(|(name, token)| ClientDigest {
+                name: name.to_string(),
+                digest: token_digest(token),
+            })
+            .collect();
+        Self {
+            config_path: PathBuf::new(),
+            clients: RwLock::new(LoadedClients {
+                modified: None,
+                configured: !tokens.is_empty(),
+                digests,
+            }),
+        }
+    }
+}
+
+/// Extract the token from an `Authorization: Bearer <token>` header value.
+pub(crate) fn bearer_token(authorization: &str) -> Option<&str> {
+    let (scheme, token) = authorization.trim().split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then_some(token)
+}
+
+fn config_modified_at(config_path: &Path) -> Option<SystemTime> {
+    config_path.metadata().and_then(|metadata| metadata.modified()).ok()
+}
+
+fn client_secret_name(name: &str) -> String {
+    format!("mcp-http-client-{name}")
+}
+
+fn generate_client_token() -> Result<String> {
+    let mut bytes = [0u8; CLIENT_TOKEN_BYTES];
+    getrandom::fill(&mut bytes).map_err(|error| anyhow!("failed to generate client token: {error}"))?;
+    Ok(format!("{CLIENT_TOKEN_PREFIX}{}", hex(&bytes)))
+}
+
+fn token_digest(token: &str) -> String {
+    hex(&Sha256::digest(token.as_bytes()))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::with_capacity(bytes.len() * 2), |mut encoded, byte| {
+        let _ = write!(encoded, "{byte:02x}");
+        encoded
+    })
+}
+
+/// Compare digests without short-circuiting on the first differing byte.
+fn digests_match(expected: &str, presented: &str) -> bool {
+    expected.len() == presented.len()
+        && expected
+            .bytes()
+            .zip(presented.bytes())
+            .fold(0u8, |difference, (left, right)| difference | (left ^ right))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_tokens_are_prefixed_and_unique() {
+        let first = generate_client_token().unwrap();
+        let second = generate_client_token().unwrap();
+
+        assert!(first.starts_with(CLIENT_TOKEN_PREFIX));
+        assert_eq!(first.len(), CLIENT_TOKEN_PREFIX.len() + CLIENT_TOKEN_BYTES * 2);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn authenticate_resolves_the_owning_client() {
+        let authenticator = McpClientAuthenticator::with_tokens(&[("laptop", "oatty_mcp_a"), ("dev-vm", "oatty_mcp_b")]);
+
+        assert_eq!(authenticator.authenticate("oatty_mcp_b").as_deref(), Some("dev-vm"));
+        assert_eq!(authenticator.authenticate("oatty_mcp_c"), None);
+    }
+
+    #[test]
+    fn bearer_token_requires_the_bearer_scheme() {
+        assert_eq!(bearer_token("Bearer oatty_mcp_a"), Some("oatty_mcp_a"));
+        assert_eq!(bearer_token("bearer  oatty_mcp_a "), Some("oatty_mcp_a"));
+        assert_eq!(bearer_token("Basic dXNlcjpwYXNz"), None);
+        assert_eq!(bearer_token("Bearer "), None);
+    }
+
+    #[test]
+    fn load_http_server_config_ignores_plugin_secrets() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("mcp.json");
+        std::fs::write(
+            &path,
+            r#"{
+  "mcpServers": { "api": { "command": "api", "env": { "TOKEN": "${secret:missing}" }, "disabled": false } },
+  "httpServer": {
+    "autoStart": false,
+    "bindAddress": "0.0.0.0:62889",
+    "clients": [{ "name": "dev-vm", "createdAt": "2026-01-01T00:00:00Z" }],
+    "tls": { "certFile": "cert.pem", "keyFile": "key.pem" }
+  }
+}"#,
+        )
+        .unwrap();
+
+        let config = load_http_server_config(&path).unwrap();
+        assert!(config.allows_remote_access());
+        assert_eq!(list_client_tokens(&path).unwrap()[0].name, "dev-vm");
+    }
+}
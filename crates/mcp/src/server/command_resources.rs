@@ -0,0 +1,384 @@
+//! Catalog and command MCP resources served from the [`CommandRegistry`].
+//!
+//! These mirror `list_command_topics` and `get_command` as addressable, cacheable reads:
+//! `oatty://catalog/{title}`, `oatty://command/{canonical_id}`, and
+//! `oatty://command/{canonical_id}/output-schema`. Path segments are percent-decoded, so a
+//! canonical ID such as `apps apps:list` may be written `apps%20apps:list`.
+
+use std::sync::{Arc, Mutex};
+
+use oatty_registry::{CommandRegistry, CommandRegistryEvent};
+use percent_encoding::{AsciiSet, CONTROLS, percent_decode_str, utf8_percent_encode};
+use rmcp::model::{
+    AnnotateAble, ErrorData, RawResource, RawResourceTemplate, ReadResourceResult, Resource, ResourceContents, ResourceTemplate,
+};
+use serde_json::Value;
+
+use crate::server::core::{
+    build_command_summary, command_catalog_title, command_output_schema, list_command_summaries_by_catalog, prune_sparse_json,
+    resolve_command_spec,
+};
+use crate::server::policy::McpPolicyScope;
+use crate::server::schemas::{OutputSchemaDetail, ProviderMetadataDetail};
+use crate::server::workflow::errors::{internal_error, not_found_error};
+
+const CATALOG_URI_PREFIX: &str = "oatty://catalog/";
+const COMMAND_URI_PREFIX: &str = "oatty://command/";
+const OUTPUT_SCHEMA_SUFFIX: &str = "/output-schema";
+/// Resources that summarize every command and change whenever commands are added or removed.
+const COMMAND_INVENTORY_URIS: [&str; 2] = ["oatty://workflow/command-catalog", "oatty://workflow/provider-catalog"];
+
+/// Characters escaped in generated URIs: controls, spaces, and URI delimiters.
+const URI_SEGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'%').add(b'/').add(b'?');
+
+/// A registry-backed resource addressed by URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CommandResource {
+    Catalog(String),
+    Command(String),
+    CommandOutputSchema(String),
+}
+
+impl CommandResource {
+    /// Parse a catalog or command URI; `None` for URIs outside these namespaces.
+    pub fn parse(uri: &str) -> Option<Self> {
+        if let Some(title) = uri.strip_prefix(CATALOG_URI_PREFIX) {
+            return Some(Self::Catalog(decode_segment(title)));
+        }
+        let command = uri.strip_prefix(COMMAND_URI_PREFIX)?;
+        Some(match command.strip_suffix(OUTPUT_SCHEMA_SUFFIX) {
+            Some(canonical_id) => Self::CommandOutputSchema(decode_segment(canonical_id)),
+            None => Self::Command(decode_segment(command)),
+        })
+    }
+
+    /// Canonical URI for this resource.
+    pub fn uri(&self) -> String {
+        match self {
+            Self::Catalog(title) => format!("{CATALOG_URI_PREFIX}{}", encode_segment(title)),
+            Self::Command(canonical_id) => format!("{COMMAND_URI_PREFIX}{}", encode_segment(canonical_id)),
+            Self::CommandOutputSchema(canonical_id) => {
+                format!("{COMMAND_URI_PREFIX}{}{OUTPUT_SCHEMA_SUFFIX}", encode_segment(canonical_id))
+            }
+        }
+    }
+}
+
+fn decode_segment(segment: &str) -> String {
+    percent_decode_str(segment).decode_utf8_lossy().into_owned()
+}
+
+fn encode_segment(segment: &str) -> String {
+    utf8_percent_encode(segment, URI_SEGMENT).to_string()
+}
+
+/// Resource templates for catalogs and commands.
+pub(crate) fn command_resource_templates() -> Vec<ResourceTemplate> {
+    vec![
+        template(
+            "oatty://catalog/{title}",
+            "catalog",
+            "Command catalog by title",
+            "Catalog metadata and command summaries (arguments, flags, and output field paths).",
+        ),
+        template(
+            "oatty://command/{canonical_id}",
+            "command",
+            "Command by canonical id",
+            "Full command detail, equivalent to get_command with provider metadata. Percent-encode the space in `<group> <command>`.",
+        ),
+        template(
+            "oatty://command/{canonical_id}/output-schema",
+            "command.output_schema",
+            "Command output schema",
+            "Full output schema for a command, equivalent to get_command with output_schema_detail=full.",
+        ),
+    ]
+}
+
+fn template(uri_template: &str, name: &str, title: &str, description: &str) -> ResourceTemplate {
+    RawResourceTemplate {
+        uri_template: uri_template.to_string(),
+        name: name.to_string(),
+        title: Some(title.to_string()),
+        description: Some(description.to_string()),
+        mime_type: Some("application/json".to_string()),
+        icons: None,
+    }
+    .no_annotation()
+}
+
+/// One concrete resource per enabled catalog visible to the session.
+pub(crate) fn list_catalog_resources(registry: &Arc<Mutex<CommandRegistry>>, scope: &McpPolicyScope) -> Vec<Resource> {
+    let Ok(registry) = registry.lock() else {
+        return Vec::new();
+    };
+    registry
+        .config
+        .catalogs
+        .iter()
+        .flatten()
+        .filter(|catalog| catalog.is_enabled && scope.allows_catalog(&catalog.title))
+        .map(|catalog| {
+            RawResource {
+                uri: CommandResource::Catalog(catalog.title.clone()).uri(),
+                name: format!("catalog.{}", catalog.title),
+                title: Some(catalog.title.clone()),
+                description: (!catalog.description.trim().is_empty()).then(|| catalog.description.clone()),
+                mime_type: Some("application/json".to_string()),
+                size: None,
+                icons: None,
+                meta: None,
+            }
+            .no_annotation()
+        })
+        .collect()
+}
+
+/// Read a catalog or command resource, applying the session policy.
+pub(crate) fn read_command_resource(
+    resource: &CommandResource,
+    uri: &str,
+    registry: &Arc<Mutex<CommandRegistry>>,
+    scope: &McpPolicyScope,
+) -> Result<ReadResourceResult, ErrorData> {
+    let payload = match resource {
+        CommandResource::Catalog(title) => {
+            scope.check_catalog(title)?;
+            catalog_payload(registry, title, scope, uri)?
+        }
+        CommandResource::Command(canonical_id) => {
+            let command = resolve_command_spec(registry, canonical_id)?;
+            check_command(registry, &command, scope, uri)?;
+            build_command_summary(&command, OutputSchemaDetail::Paths, ProviderMetadataDetail::Full)
+        }
+        CommandResource::CommandOutputSchema(canonical_id) => {
+            let command = resolve_command_spec(registry, canonical_id)?;
+            check_command(registry, &command, scope, uri)?;
+            let schema = command_output_schema(&command)
+                .and_then(|schema| serde_json::to_value(schema).ok())
+                .and_then(prune_sparse_json)
+                .ok_or_else(|| {
+                    not_found_error(
+                        "COMMAND_OUTPUT_SCHEMA_NOT_FOUND",
+                        format!("command '{canonical_id}' does not declare an output schema"),
+                        serde_json::json!({ "uri": uri, "canonical_id": canonical_id }),
+                        "Read oatty://command/{canonical_id} for the available output field paths.",
+                    )
+                })?;
+            serde_json::json!({ "canonical_id": canonical_id, "output_schema": schema })
+        }
+    };
+
+    Ok(ReadResourceResult {
+        contents: vec![ResourceContents::TextResourceContents {
+            uri: uri.to_string(),
+            mime_type: Some("application/json".to_string()),
+            text: serde_json::to_string_pretty(&payload).unwrap_or_else(|_| "{}".to_string()),
+            meta: None,
+        }],
+    })
+}
+
+fn check_command(
+    registry: &Arc<Mutex<CommandRegistry>>,
+    command: &oatty_types::CommandSpec,
+    scope: &McpPolicyScope,
+    uri: &str,
+) -> Result<(), ErrorData> {
+    let registry = registry.lock().map_err(|error| lock_error(error, uri))?;
+    scope.check_command(command, command_catalog_title(&registry, command))
+}
+
+fn catalog_payload(registry: &Arc<Mutex<CommandRegistry>>, title: &str, scope: &McpPolicyScope, uri: &str) -> Result<Value, ErrorData> {
+    let metadata = {
+        let registry = registry.lock().map_err(|error| lock_error(error, uri))?;
+        let catalog = registry
+            .config
+            .catalogs
+            .iter()
+            .flatten()
+            .find(|catalog| catalog.title == title)
+            .ok_or_else(|| {
+                not_found_error(
+                    "CATALOG_NOT_FOUND",
+                    format!("catalog '{title}' was not found"),
+                    serde_json::json!({ "uri": uri, "catalog_title": title }),
+                    "Call resources/list or list_command_topics to inspect available catalog titles.",
+                )
+            })?;
+        serde_json::json!({
+            "title": catalog.title,
+            "vendor": catalog.manifest.as_ref().map(|manifest| manifest.vendor.clone()).or_else(|| catalog.vendor.clone()),
+            "description": catalog.description,
+            "enabled": catalog.is_enabled,
+            "base_url": catalog.base_urls.get(catalog.base_url_index),
+        })
+    };
+
+    let commands = list_command_summaries_by_catalog(registry, title, scope).map_err(|error| {
+        internal_error(
+            "CATALOG_RESOURCE_READ_FAILED",
+            error.to_string(),
+            serde_json::json!({ "uri": uri, "catalog_title": title }),
+            "Retry the resource read.",
+        )
+    })?;
+
+    let mut payload = metadata;
+    payload["command_count"] = Value::from(commands.len());
+    payload["commands"] = Value::Array(commands);
+    Ok(payload)
+}
+
+fn lock_error(error: impl std::fmt::Display, uri: &str) -> ErrorData {
+    internal_error(
+        "COMMAND_RESOURCE_LOCK_FAILED",
+        format!("registry lock failed: {error}"),
+        serde_json::json!({ "uri": uri }),
+        "Retry the resource read.",
+    )
+}
+
+/// Resource URIs whose content changes with a registry event.
+pub(crate) fn registry_event_resource_uris(event: &CommandRegistryEvent, registry: &CommandRegistry) -> Vec<String> {
+    let commands = match event {
+        CommandRegistryEvent::CatalogChanged(title) => return vec![CommandResource::Catalog(title.clone()).uri()],
+        CommandRegistryEvent::CommandsAdded(commands) | CommandRegistryEvent::CommandsRemoved(commands) => commands,
+        CommandRegistryEvent::WorkflowsAdded(_) | CommandRegistryEvent::WorkflowsRemoved(_) => return Vec::new(),
+    };
+
+    let mut uris: Vec<String> = COMMAND_INVENTORY_URIS.iter().map(ToString::to_string).collect();
+    for command in commands.iter() {
+        let canonical_id = command.canonical_id();
+        uris.push(CommandResource::Command(canonical_id.clone()).uri());
+        uris.push(CommandResource::CommandOutputSchema(canonical_id).uri());
+        if command.http().is_some()
+            && let Some(title) = command_catalog_title(registry, command)
+        {
+            uris.push(CommandResource::Catalog(title.to_string()).uri());
+        }
+    }
+    uris.sort();
+    uris.dedup();
+    uris
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexSet;
+    use oatty_types::{CommandSpec, HttpCommandSpec, SchemaProperty, manifest::RegistryCatalog};
+
+    use super::*;
+
+    fn registry_with_catalog() -> Arc<Mutex<CommandRegistry>> {
+        let output_schema = SchemaProperty {
+            r#type: "array".to_string(),
+            description: "Apps".to_string(),
+            properties: None,
+            required: Vec::new(),
+            items: None,
+            enum_values: Vec::new(),
+            format: None,
+            tags: Vec::new(),
+        };
+        let command = CommandSpec::new_http(
+            "apps".to_string(),
+            "apps:list".to_string(),
+            "List apps".to_string(),
+            Vec::new(),
+            Vec::new(),
+            HttpCommandSpec::new("GET", "/apps", Some(output_schema), None),
+            0,
+        );
+        let mut registry = CommandRegistry::default().with_commands(vec![command]);
+        registry.config.catalogs = Some(vec![RegistryCatalog {
+            title: "Heroku Platform".to_string(),
+            description: "Heroku API".to_string(),
+            vendor: Some("heroku".to_string()),
+            manifest_path: String::new(),
+            import_source: None,
+            import_source_type: None,
+            headers: IndexSet::new(),
+            base_urls: vec!["https://api.heroku.com".to_string()],
+            base_url_index: 0,
+            manifest: None,
+            is_enabled: true,
+        }]);
+        Arc::new(Mutex::new(registry))
+    }
+
+    fn read_json(resource: &CommandResource, registry: &Arc<Mutex<CommandRegistry>>) -> Result<Value, ErrorData> {
+        let result = read_command_resource(resource, &resource.uri(), registry, &McpPolicyScope::unrestricted())?;
+        let ResourceContents::TextResourceContents { text, .. } = &result.contents[0] else {
+            panic!("expected text contents");
+        };
+        Ok(serde_json::from_str(text).expect("resource text is JSON"))
+    }
+
+    #[test]
+    fn reads_catalog_command_and_output_schema() {
+        let registry = registry_with_catalog();
+
+        let catalog = read_json(&CommandResource::Catalog("Heroku Platform".to_string()), &registry).expect("catalog");
+        assert_eq!(catalog["base_url"], "https://api.heroku.com");
+        assert_eq!(catalog["command_count"], 1);
+        assert_eq!(catalog["commands"][0]["canonical_id"], "apps apps:list");
+
+        let command = read_json(&CommandResource::Command("apps apps:list".to_string()), &registry).expect("command");
+        assert_eq!(command["http_method"], "GET");
+
+        let schema = read_json(&CommandResource::CommandOutputSchema("apps apps:list".to_string()), &registry).expect("schema");
+        assert_eq!(schema["output_schema"]["type"], "array");
+
+        assert!(read_json(&CommandResource::Catalog("missing".to_string()), &registry).is_err());
+    }
+
+    #[test]
+    fn command_events_map_to_command_and_catalog_uris() {
+        let registry = registry_with_catalog();
+        let registry = registry.lock().unwrap();
+        let uris = registry_event_resource_uris(
+            &CommandRegistryEvent::CommandsAdded(Arc::from(registry.commands.clone())),
+            &registry,
+        );
+        assert!(uris.contains(&"oatty://catalog/Heroku%20Platform".to_string()));
+        assert!(uris.contains(&"oatty://command/apps%20apps:list".to_string()));
+        assert!(uris.contains(&"oatty://command/apps%20apps:list/output-schema".to_string()));
+        assert!(uris.contains(&"oatty://workflow/command-catalog".to_string()));
+    }
+
+    #[test]
+    fn parses_catalog_and_command_uris() {
+        assert_eq!(
+            CommandResource::parse("oatty://catalog/Heroku%20Platform"),
+            Some(CommandResource::Catalog("Heroku Platform".to_string()))
+        );
+        assert_eq!(
+            CommandResource::parse("oatty://command/apps%20apps:list"),
+            Some(CommandResource::Command("apps apps:list".to_string()))
+        );
+        assert_eq!(
+            CommandResource::parse("oatty://command/apps apps:list/output-schema"),
+            Some(CommandResource::CommandOutputSchema("apps apps:list".to_string()))
+        );
+        assert_eq!(CommandResource::parse("oatty://workflow/spec"), None);
+    }
+
+    #[test]
+    fn generated_uris_round_trip() {
+        let resource = CommandResource::CommandOutputSchema("apps apps:list".to_string());
+        let uri = resource.uri();
+        assert_eq!(uri, "oatty://command/apps%20apps:list/output-schema");
+        assert_eq!(CommandResource::parse(&uri), Some(resource));
+    }
+
+    #[test]
+    fn catalog_events_map_to_catalog_uri() {
+        let uris = registry_event_resource_uris(
+            &CommandRegistryEvent::CatalogChanged("Heroku Platform".to_string()),
+            &CommandRegistry::default(),
+        );
+        assert_eq!(uris, vec!["oatty://catalog/Heroku%20Platform".to_string()]);
+    }
+}
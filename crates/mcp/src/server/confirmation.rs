@@ -0,0 +1,270 @@
+//! Operator confirmation for sensitive MCP tool calls.
+//!
+//! When a `requireConfirmation` policy rule matches a call, the call pauses until a human
+//! decides. A TUI-hosted server forwards the decision to the operator through
+//! [`McpConfirmationRequest`]; otherwise the server asks the connected client with an MCP form
+//! elicitation. Calls are denied when neither channel is available or no answer arrives in time.
+
+use std::time::Duration;
+
+use rmcp::model::{CreateElicitationRequestParams, ElicitationAction, ElicitationSchema};
+use rmcp::service::ElicitationMode;
+use rmcp::{Peer, RoleServer};
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::oneshot;
+
+/// How long a pending call waits for an operator decision before it is denied, unless the
+/// policy sets `confirmationTimeoutSecs`.
+pub const DEFAULT_CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A tool call awaiting an operator decision.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct McpPendingToolCall {
+    /// `clientInfo.name` of the requesting session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+    /// MCP tool name.
+    pub tool: String,
+    /// Canonical command ID for command execution tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canonical_id: Option<String>,
+    /// Owning catalog title (or plugin name for MCP-backed commands).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog: Option<String>,
+    /// Resolved HTTP method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_method: Option<String>,
+    /// Resolved request URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Redacted request body or tool arguments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+impl McpPendingToolCall {
+    /// One-line description, for example `DELETE https://api.example.com/apps/web`.
+    pub fn summary(&self) -> String {
+        match (self.http_method.as_deref(), self.url.as_deref()) {
+            (Some(method), Some(url)) => format!("{method} {url}"),
+            _ => match (self.canonical_id.as_deref(), self.catalog.as_deref()) {
+                (Some(canonical_id), _) => format!("{} {canonical_id}", self.tool),
+                (None, Some(catalog)) => format!("{} {catalog}", self.tool),
+                (None, None) => self.tool.clone(),
+            },
+        }
+    }
+
+    /// Multi-line description including the redacted body.
+    pub fn describe(&self) -> String {
+        let mut text = format!(
+            "MCP client '{}' wants to run:\n{}",
+            self.client.as_deref().unwrap_or("unknown"),
+            self.summary()
+        );
+        if let Some(body) = self.body.as_ref().filter(|body| !is_empty_body(body)) {
+            let rendered = serde_json::to_string_pretty(body).unwrap_or_default();
+            text.push_str(&format!("\nBody: {rendered}"));
+        }
+        text
+    }
+}
+
+fn is_empty_body(body: &Value) -> bool {
+    match body {
+        Value::Null => true,
+        Value::Object(map) => map.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+/// Confirmation request forwarded to the UI hosting the server.
+#[derive(Debug)]
+pub struct McpConfirmationRequest {
+    /// The call awaiting a decision.
+    pub call: McpPendingToolCall,
+    responder: oneshot::Sender<bool>,
+}
+
+impl McpConfirmationRequest {
+    /// Send the operator's decision back to the waiting tool call.
+    pub fn respond(self, approved: bool) {
+        let _ = self.responder.send(approved);
+    }
+
+    /// Whether the waiting call already gave up, for example because its timeout expired.
+    pub fn is_expired(&self) -> bool {
+        self.responder.is_closed()
+    }
+}
+
+/// Where an operator decision came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmationChannel {
+    /// The TUI hosting the server.
+    Operator,
+    /// An MCP elicitation answered by the client.
+    Elicitation,
+    /// No channel could reach a human.
+    Unavailable,
+}
+
+impl ConfirmationChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConfirmationChannel::Operator => "tui",
+            ConfirmationChannel::Elicitation => "elicitation",
+            ConfirmationChannel::Unavailable => "unavailable",
+        }
+    }
+}
+
+/// Outcome of a confirmation round-trip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfirmationDecision {
+    pub approved: bool,
+    pub channel: ConfirmationChannel,
+    pub timed_out: bool,
+}
+
+/// Ask a human to approve `call`, preferring the hosting UI over client elicitation.
+///
+/// A call that gets no answer within `timeout` is denied.
+pub(crate) async fn request_confirmation(
+    call: &McpPendingToolCall,
+    operator: Option<&UnboundedSender<McpConfirmationRequest>>,
+    peer: Option<&Peer<RoleServer>>,
+    timeout: Duration,
+) -> ConfirmationDecision {
+    if let Some(operator) = operator {
+        let (responder, receiver) = oneshot::channel();
+        let request = McpConfirmationRequest {
+            call: call.clone(),
+            responder,
+        };
+        if operator.send(request).is_ok() {
+            let outcome = tokio::time::timeout(timeout, receiver).await;
+            return ConfirmationDecision {
+                approved: matches!(outcome, Ok(Ok(true))),
+                channel: ConfirmationChannel::Operator,
+                timed_out: outcome.is_err(),
+            };
+        }
+    }
+
+    if let Some(peer) = peer.filter(|peer| peer.supported_elicitation_modes().contains(&ElicitationMode::Form)) {
+        let outcome = peer.create_elicitation_with_timeout(elicitation_params(call), Some(timeout)).await;
+        let approved = outcome.as_ref().is_ok_and(|result| {
+            result.action == ElicitationAction::Accept
+                && result
+                    .content
+                    .as_ref()
+                    .and_then(|content| content.get("approve"))
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false)
+        });
+        return ConfirmationDecision {
+            approved,
+            channel: ConfirmationChannel::Elicitation,
+            timed_out: matches!(outcome, Err(rmcp::service::ServiceError::Timeout { .. })),
+        };
+    }
+
+    ConfirmationDecision {
+        approved: false,
+        channel: ConfirmationChannel::Unavailable,
+        timed_out: false,
+    }
+}
+
+fn elicitation_params(call: &McpPendingToolCall) -> CreateElicitationRequestParams {
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "approve": {
+                "type": "boolean",
+                "title": "Approve",
+                "description": "Allow this call to run."
+            }
+        },
+        "required": ["approve"]
+    });
+    let requested_schema = match schema {
+        Value::Object(object) => ElicitationSchema::from_json_schema(object).unwrap_or_else(|_| ElicitationSchema::new(Default::default())),
+        _ => ElicitationSchema::new(Default::default()),
+    };
+    CreateElicitationRequestParams::FormElicitationParams {
+        meta: None,
+        message: format!("{}\n\nApprove this call?", call.describe()),
+        requested_schema,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delete_call() -> McpPendingToolCall {
+        McpPendingToolCall {
+            client: Some("claude-code".to_string()),
+            tool: "run_destructive_command".to_string(),
+            canonical_id: Some("apps apps:delete".to_string()),
+            catalog: Some("Heroku".to_string()),
+            http_method: Some("DELETE".to_string()),
+            url: Some("https://api.heroku.com/apps/web".to_string()),
+            body: Some(serde_json::json!({})),
+        }
+    }
+
+    #[test]
+    fn describe_lists_method_url_and_non_empty_body() {
+        let mut call = delete_call();
+        assert_eq!(
+            call.describe(),
+            "MCP client 'claude-code' wants to run:\nDELETE https://api.heroku.com/apps/web"
+        );
+
+        call.body = Some(serde_json::json!({ "force": true }));
+        assert!(call.describe().contains("\"force\": true"));
+    }
+
+    #[tokio::test]
+    async fn operator_decision_is_returned_to_the_waiting_call() {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<McpConfirmationRequest>();
+        let operator = tokio::spawn(async move {
+            let request = receiver.recv().await.expect("confirmation request");
+            assert_eq!(request.call.summary(), "DELETE https://api.heroku.com/apps/web");
+            request.respond(false);
+        });
+
+        let decision = request_confirmation(&delete_call(), Some(&sender), None, DEFAULT_CONFIRMATION_TIMEOUT).await;
+        operator.await.expect("operator task");
+
+        assert!(!decision.approved);
+        assert_eq!(decision.channel, ConfirmationChannel::Operator);
+    }
+
+    #[tokio::test]
+    async fn calls_are_denied_without_a_confirmation_channel() {
+        let decision = request_confirmation(&delete_call(), None, None, DEFAULT_CONFIRMATION_TIMEOUT).await;
+
+        assert!(!decision.approved);
+        assert_eq!(decision.channel, ConfirmationChannel::Unavailable);
+    }
+
+    #[tokio::test]
+    async fn unanswered_calls_are_denied_when_the_timeout_expires() {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<McpConfirmationRequest>();
+        let pending = tokio::spawn(async move { receiver.recv().await });
+
+        let decision = request_confirmation(&delete_call(), Some(&sender), None, Duration::from_millis(10)).await;
+
+        assert!(!decision.approved);
+        assert!(decision.timed_out);
+        assert_eq!(decision.channel, ConfirmationChannel::Operator);
+        drop(pending.await.expect("operator task"));
+    }
+}
@@ -1,32 +1,49 @@
 use crate::PluginEngine;
+use crate::logging::{AuditEntry, AuditInitiator, AuditInvocation, AuditResult, AuditTrail};
+use crate::server::auth::McpAuthenticatedClient;
 use crate::server::catalog::{
     apply_catalog_patch_runtime, edit_catalog_headers, get_catalog_masked_headers, import_openapi_catalog, preview_openapi_import,
     remove_catalog_runtime, set_catalog_base_url, set_catalog_enabled_state, validate_openapi_source,
 };
+use crate::server::command_resources::{CommandResource, command_resource_templates, list_catalog_resources, read_command_resource};
+use crate::server::confirmation::{
+    ConfirmationChannel, ConfirmationDecision, McpConfirmationRequest, McpPendingToolCall, request_confirmation,
+};
+use crate::server::gateway::{
+    gateway_tools, get_gateway_prompt, list_gateway_prompts, list_gateway_resources, plugin_request_error, read_gateway_resource,
+    redact_tool_result, resolve_gateway_tool, split_gateway_resource_uri, unknown_gateway_capability,
+};
 use crate::server::http::McpHttpLogEntry;
+use crate::server::limits::{ExecutionPermit, McpRateLimiter, SessionLimiter};
 use crate::server::log_payload::{build_log_payload, build_parsed_response_payload};
+use crate::server::policy::{McpPolicy, McpPolicyScope};
 use crate::server::schemas::{
     CatalogApplyPatchRequest, CatalogEditHeadersRequest, CatalogGetMaskedHeadersRequest, CatalogImportOpenApiRequest,
     CatalogPreviewImportRequest, CatalogRemoveRequest, CatalogSetBaseUrlRequest, CatalogSetEnabledRequest, CatalogValidateOpenApiRequest,
     CommandDetailRequest, CommandSummariesRequest, OutputSchemaDetail, ProviderMetadataDetail, RunCommandRequestParam, SearchInputsDetail,
     SearchRequestParam,
 };
+use crate::server::subscriptions::ResourceSubscriptions;
 use crate::server::workflow::{
-    errors::{conflict_error, not_found_error},
+    errors::{conflict_error, not_found_error, operator_denied_error, policy_denied_error},
     prompts::{get_prompt as get_workflow_prompt, list_prompts as list_workflow_prompts},
     resources::{
         list_resource_templates as list_workflow_resource_templates, list_resources as list_workflow_resources,
-        read_resource as read_workflow_resource,
+        read_resource as read_workflow_resource, read_run_resource, workflow_run_id,
+    },
+    services::{
+        guard::{StepConfirmer, WorkflowRunGuard},
+        runs::{WorkflowRunObserver, WorkflowRunRegistry},
     },
     tools::{
         author_and_run, delete_workflow, export_workflow, get_workflow, import_workflow, list_workflows, preview_inputs, preview_rendered,
-        purge_workflow_history, rename_workflow, repair_and_rerun, resolve_inputs, run_with_task_capability_guard, save_workflow,
-        step_plan,
+        purge_workflow_history, rename_workflow, repair_and_rerun, resolve_inputs, run_status, run_with_task_capability_guard,
+        save_workflow, step_plan,
         types::{
             WorkflowAuthorAndRunRequest, WorkflowCancelRequest, WorkflowDeleteRequest, WorkflowExportRequest, WorkflowGetRequest,
             WorkflowImportRequest, WorkflowPreviewInputsRequest, WorkflowPreviewRenderedRequest, WorkflowPurgeHistoryRequest,
-            WorkflowRenameRequest, WorkflowRepairAndRerunRequest, WorkflowResolveInputsRequest, WorkflowRunRequest, WorkflowSaveRequest,
-            WorkflowStepPlanRequest, WorkflowValidateRequest,
+            WorkflowRenameRequest, WorkflowRepairAndRerunRequest, WorkflowResolveInputsRequest, WorkflowRunRequest,
+            WorkflowRunStatusRequest, WorkflowSaveRequest, WorkflowStepPlanRequest, WorkflowValidateRequest,
         },
         validate_workflow,
     },
@@ -34,22 +51,25 @@ use crate::server::workflow::{
 use anyhow::Result;
 use oatty_registry::{CommandRegistry, SearchHandle, suggest_nearest_canonical_ids};
 use oatty_types::{CommandSpec, ExecOutcome, SearchResult};
-use oatty_util::http::exec_remote_for_provider;
+use oatty_util::http::{build_path, exec_remote_for_provider};
+use oatty_util::redact_json;
 use reqwest::Method;
-use rmcp::handler::server::tool::ToolRouter;
+use rmcp::handler::server::tool::{ToolCallContext, ToolRouter};
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::{
-    CallToolResult, ErrorData, ErrorData as McpError, GetPromptRequestParams, GetPromptResult, Implementation, ListPromptsResult,
-    ListResourceTemplatesResult, ListResourcesResult, PaginatedRequestParams, ProtocolVersion, ReadResourceRequestParams,
-    ReadResourceResult, ServerCapabilities, ServerInfo,
+    CallToolRequestParams, CallToolResult, ErrorData, ErrorData as McpError, GetPromptRequestParams, GetPromptResult, Implementation,
+    InitializeRequestParams, InitializeResult, ListPromptsResult, ListResourceTemplatesResult, ListResourcesResult, ListToolsResult,
+    PaginatedRequestParams, ProtocolVersion, ReadResourceRequestParams, ReadResourceResult, ServerCapabilities, ServerInfo,
+    SubscribeRequestParams, Tool, UnsubscribeRequestParams,
 };
 use rmcp::task_handler;
 use rmcp::task_manager::OperationProcessor;
-use rmcp::{ServerHandler, service::RequestContext, tool, tool_handler, tool_router};
+use rmcp::{Peer, RoleServer, ServerHandler, service::RequestContext, tool, tool_router};
 use serde_json::{Map, Value};
 use std::collections::HashMap;
 use std::str::FromStr;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc::UnboundedSender;
 
 /// Shared services for MCP tool handlers.
@@ -94,8 +114,30 @@ pub struct OattyMcpCore {
     log_sender: Option<UnboundedSender<McpHttpLogEntry>>,
     services: Arc<McpToolServices>,
     task_processor: Arc<tokio::sync::Mutex<OperationProcessor>>,
+    policy: Option<Arc<McpPolicy>>,
+    /// Policy scope for this session, resolved from the client identity during `initialize`.
+    policy_scope: Arc<OnceLock<McpPolicyScope>>,
+    /// Operator channel used for confirmations when the server is hosted by the TUI.
+    confirmation_sender: Option<UnboundedSender<McpConfirmationRequest>>,
+    /// Client peer captured during `initialize`, used for elicitation requests.
+    peer: Arc<OnceLock<Peer<RoleServer>>>,
+    /// Token-authenticated client name, or `clientInfo.name`, captured during `initialize`.
+    client_name: Arc<OnceLock<String>>,
+    /// `Mcp-Session-Id` captured from the first tool call, recorded in audit entries.
+    session_id: Arc<OnceLock<String>>,
+    /// Whether running plugins' tools, resources, and prompts are re-exported under namespaced names.
+    gateway: bool,
+    /// Workflow runs started in this session, for progress lookups and cancellation.
+    workflow_runs: WorkflowRunRegistry,
+    /// Resource URIs this session subscribed to for `resources/updated` notifications.
+    resource_subscriptions: ResourceSubscriptions,
+    /// Session view of the server's rate limits; `None` when no limits are configured.
+    rate_limiter: Option<SessionLimiter>,
 }
 
+/// Audit source recorded for actions taken on behalf of MCP HTTP server clients.
+const MCP_SERVER_AUDIT_SOURCE: &str = "mcp-http-server";
+
 #[tool_router]
 impl OattyMcpCore {
     /// Create a new MCP core handler with shared service dependencies.
@@ -105,9 +147,43 @@ impl OattyMcpCore {
             log_sender,
             services,
             task_processor: Arc::new(tokio::sync::Mutex::new(OperationProcessor::new())),
+            policy: None,
+            policy_scope: Arc::new(OnceLock::new()),
+            confirmation_sender: None,
+            peer: Arc::new(OnceLock::new()),
+            client_name: Arc::new(OnceLock::new()),
+            session_id: Arc::new(OnceLock::new()),
+            gateway: false,
+            workflow_runs: WorkflowRunRegistry::default(),
+            resource_subscriptions: ResourceSubscriptions::default(),
+            rate_limiter: None,
         }
     }
 
+    /// Route operator confirmations to the UI hosting this server instead of client elicitation.
+    pub fn with_confirmation_sender(mut self, confirmation_sender: Option<UnboundedSender<McpConfirmationRequest>>) -> Self {
+        self.confirmation_sender = confirmation_sender;
+        self
+    }
+
+    /// Enforce the provided access policy for this session.
+    pub fn with_policy(mut self, policy: Option<Arc<McpPolicy>>) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Count executions in this session against the server's rate limits.
+    pub fn with_rate_limiter(mut self, rate_limiter: Option<&McpRateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter.map(McpRateLimiter::session);
+        self
+    }
+
+    /// Re-export running plugins' tools, resources, and prompts under namespaced names.
+    pub fn with_gateway(mut self, gateway: bool) -> Self {
+        self.gateway = gateway;
+        self
+    }
+
     #[tool(
         annotations(read_only_hint = true),
         description = "Find executable commands by intent. Use first before any run_* call. Use during workflow authoring to discover valid step `run` values (canonical command IDs in `<group> <command>` format, for example `apps apps:list`). Input: query, optional vendor, optional limit, optional include_inputs(none|required_only|full). Canonical direct-hit queries return exactly one result when found. include_inputs=none returns minimal discovery metadata (canonical_id, execution_type, http_method). include_inputs=required_only adds required input fields, compact provider_inputs hints, and compact output_fields. include_inputs=full adds complete positional_args, flags, provider_inputs, and output_fields. For full nested output schema, call get_command with `output_schema_detail=full`. For exact single-command inspection after discovery, use get_command with canonical_id. Authoring decision rule: if required commands are still not discoverable after two focused searches, switch to catalog_validate_openapi -> catalog_preview_import -> catalog_import_openapi before drafting workflow steps. Efficiency rule: after candidate canonical IDs are found, stop fuzzy search and switch to get_command; use at most one include_inputs=full search per vendor/intent. Routing: GET -> run_safe_command, POST/PUT/PATCH -> run_command, DELETE -> run_destructive_command, MCP -> run_safe_command or run_command."
@@ -146,6 +222,17 @@ impl OattyMcpCore {
                         )
                     })?
             };
+            {
+                let registry_guard = self.services.command_registry.lock().map_err(|error| {
+                    internal_error_with_next_step(
+                        error.to_string(),
+                        serde_json::json!({ "query": param.0.query }),
+                        "Retry search_commands. If this persists, verify registry availability and MCP server health.",
+                    )
+                })?;
+                let scope = self.policy_scope();
+                results.retain(|result| search_result_allowed(&registry_guard, scope, result));
+            }
             if let Some(vendor_name) = param.0.vendor.as_deref()
                 && results.is_empty()
                 && !vendor_has_enabled_command_catalog(&self.services.command_registry, vendor_name).map_err(|error| {
@@ -200,10 +287,11 @@ impl OattyMcpCore {
     )]
     async fn get_command(&self, param: Parameters<CommandDetailRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = resolve_command_spec(&self.services.command_registry, &param.0.canonical_id).map(|command_spec| {
+        let result = resolve_command_spec(&self.services.command_registry, &param.0.canonical_id).and_then(|command_spec| {
+            self.authorize_command(&command_spec)?;
             let output_schema_detail = param.0.output_schema_detail.unwrap_or_default();
             let provider_metadata_detail = param.0.include_providers.unwrap_or_default();
-            build_command_summary(&command_spec, output_schema_detail, provider_metadata_detail)
+            Ok(build_command_summary(&command_spec, output_schema_detail, provider_metadata_detail))
         });
         Ok(self.finalize_structured_tool_call("get_command", request_payload, result))
     }
@@ -213,9 +301,19 @@ impl OattyMcpCore {
         description = "List available command catalogs/topics by vendor. Use when you need a catalog title for get_command_summaries_by_catalog. Only entries with type='command' support catalog summary lookups."
     )]
     async fn list_command_topics(&self) -> Result<CallToolResult, ErrorData> {
+        let scope = self.policy_scope();
         let result = list_registry_catalogs(&self.services.command_registry, &self.services.plugin_engine)
             .await
-            .map(|catalogs| serde_json::json!(catalogs))
+            .map(|catalogs| {
+                let visible: Vec<Value> = catalogs
+                    .into_iter()
+                    .filter(|catalog| {
+                        let key = if catalog["type"] == "plugin" { "vendor" } else { "title" };
+                        catalog[key].as_str().is_some_and(|name| scope.allows_catalog(name))
+                    })
+                    .collect();
+                serde_json::json!(visible)
+            })
             .map_err(|error| {
                 internal_error_with_next_step(
                     error.to_string(),
@@ -232,14 +330,18 @@ impl OattyMcpCore {
     )]
     async fn get_command_summaries_by_catalog(&self, param: Parameters<CommandSummariesRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = list_command_summaries_by_catalog(&self.services.command_registry, param.0.catalog_title.as_str())
-            .map(|summaries| serde_json::json!(summaries))
-            .map_err(|error| {
-                invalid_params_with_next_step(
-                    error.to_string(),
-                    serde_json::json!({ "catalog_title": param.0.catalog_title }),
-                    "Use list_command_topics and choose an entry with type='command'; for plugin entries use search_commands with vendor instead.",
-                )
+        let result = self
+            .authorize_tool_call("get_command_summaries_by_catalog", request_payload.as_ref())
+            .and_then(|()| {
+                list_command_summaries_by_catalog(&self.services.command_registry, param.0.catalog_title.as_str(), self.policy_scope())
+                    .map(|summaries| serde_json::json!(summaries))
+                    .map_err(|error| {
+                        invalid_params_with_next_step(
+                            error.to_string(),
+                            serde_json::json!({ "catalog_title": param.0.catalog_title }),
+                            "Use list_command_topics and choose an entry with type='command'; for plugin entries use search_commands with vendor instead.",
+                        )
+                    })
             });
         Ok(self.finalize_structured_tool_call("get_command_summaries_by_catalog", request_payload, result))
     }
@@ -292,7 +394,10 @@ impl OattyMcpCore {
     )]
     async fn catalog_preview_import(&self, param: Parameters<CatalogPreviewImportRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = preview_openapi_import(&param.0).await;
+        let result = match self.authorize_tool_call("catalog_preview_import", request_payload.as_ref()) {
+            Ok(()) => preview_openapi_import(&param.0).await,
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("catalog_preview_import", request_payload, result))
     }
 
@@ -303,7 +408,10 @@ impl OattyMcpCore {
     )]
     async fn catalog_import_openapi(&self, param: Parameters<CatalogImportOpenApiRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = import_openapi_catalog(&self.services.command_registry, &param.0).await;
+        let result = match self.guard_tool_call("catalog_import_openapi", request_payload.as_ref()).await {
+            Ok(()) => import_openapi_catalog(&self.services.command_registry, &param.0).await,
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("catalog_import_openapi", request_payload, result))
     }
 
@@ -314,7 +422,10 @@ impl OattyMcpCore {
     )]
     async fn catalog_apply_patch(&self, param: Parameters<CatalogApplyPatchRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = apply_catalog_patch_runtime(&self.services.command_registry, &param.0);
+        let result = match self.guard_tool_call("catalog_apply_patch", request_payload.as_ref()).await {
+            Ok(()) => apply_catalog_patch_runtime(&self.services.command_registry, &param.0),
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("catalog_apply_patch", request_payload, result))
     }
 
@@ -325,7 +436,10 @@ impl OattyMcpCore {
     )]
     async fn catalog_set_enabled(&self, param: Parameters<CatalogSetEnabledRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = set_catalog_enabled_state(&self.services.command_registry, &param.0);
+        let result = match self.guard_tool_call("catalog_set_enabled", request_payload.as_ref()).await {
+            Ok(()) => set_catalog_enabled_state(&self.services.command_registry, &param.0),
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("catalog_set_enabled", request_payload, result))
     }
 
@@ -336,7 +450,10 @@ impl OattyMcpCore {
     )]
     async fn catalog_set_base_url(&self, param: Parameters<CatalogSetBaseUrlRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = set_catalog_base_url(&self.services.command_registry, &param.0);
+        let result = match self.guard_tool_call("catalog_set_base_url", request_payload.as_ref()).await {
+            Ok(()) => set_catalog_base_url(&self.services.command_registry, &param.0),
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("catalog_set_base_url", request_payload, result))
     }
 
@@ -347,7 +464,10 @@ impl OattyMcpCore {
     )]
     async fn catalog_edit_headers(&self, param: Parameters<CatalogEditHeadersRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = edit_catalog_headers(&self.services.command_registry, &param.0);
+        let result = match self.guard_tool_call("catalog_edit_headers", request_payload.as_ref()).await {
+            Ok(()) => edit_catalog_headers(&self.services.command_registry, &param.0),
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("catalog_edit_headers", request_payload, result))
     }
 
@@ -358,7 +478,9 @@ impl OattyMcpCore {
     )]
     async fn catalog_get_masked_headers(&self, param: Parameters<CatalogGetMaskedHeadersRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = get_catalog_masked_headers(&self.services.command_registry, &param.0);
+        let result = self
+            .authorize_tool_call("catalog_get_masked_headers", request_payload.as_ref())
+            .and_then(|()| get_catalog_masked_headers(&self.services.command_registry, &param.0));
         Ok(self.finalize_structured_tool_call("catalog_get_masked_headers", request_payload, result))
     }
 
@@ -369,7 +491,10 @@ impl OattyMcpCore {
     )]
     async fn catalog_remove(&self, param: Parameters<CatalogRemoveRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = remove_catalog_runtime(&self.services.command_registry, &param.0);
+        let result = match self.guard_tool_call("catalog_remove", request_payload.as_ref()).await {
+            Ok(()) => remove_catalog_runtime(&self.services.command_registry, &param.0),
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("catalog_remove", request_payload, result))
     }
 
@@ -379,7 +504,17 @@ impl OattyMcpCore {
         description = "List filesystem-backed workflow manifests with path, format, and version metadata."
     )]
     async fn workflow_list(&self) -> Result<CallToolResult, ErrorData> {
-        Ok(self.finalize_structured_tool_call("workflow_list", None, list_workflows()))
+        let scope = self.policy_scope();
+        let result = list_workflows().map(|payload| match payload {
+            Value::Array(workflows) => Value::Array(
+                workflows
+                    .into_iter()
+                    .filter(|workflow| workflow["workflow_id"].as_str().is_some_and(|id| scope.allows_workflow(id)))
+                    .collect(),
+            ),
+            other => other,
+        });
+        Ok(self.finalize_structured_tool_call("workflow_list", None, result))
     }
 
     #[tool(
@@ -389,17 +524,22 @@ impl OattyMcpCore {
     )]
     async fn workflow_get(&self, param: Parameters<WorkflowGetRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        Ok(self.finalize_structured_tool_call("workflow_get", request_payload, get_workflow(&param.0)))
+        let result = self
+            .authorize_tool_call("workflow_get", request_payload.as_ref())
+            .and_then(|()| get_workflow(&param.0));
+        Ok(self.finalize_structured_tool_call("workflow_get", request_payload, result))
     }
 
     #[tool(
         name = "workflow_validate",
         annotations(read_only_hint = true),
-        description = "Validate workflow YAML/JSON without saving. Input: workflow_id OR manifest_content (inline) OR input_path (absolute file path), optional format. Includes schema checks and command/catalog preflight checks, and returns structured validation errors with violations[]. Condition syntax guidance: use `==`/`!=` (not `===`/`!==`), and use roots `inputs.*`, `steps.*`, or `env.*` (`output.*` is unsupported in conditions). Authoring guidance: for manual/free-text inputs, include `placeholder`, `hint`, and `example` metadata to improve collector UX; provider-backed inputs must use explicit scalar `select.value_field` paths."
+        description = "Validate workflow YAML/JSON without saving. Input: workflow_id OR manifest_content (inline) OR input_path (absolute file path), optional format. Includes schema checks and command/catalog preflight checks, and returns structured validation errors with violations[]. Shape, preflight, and lint checks run in one pass: error-severity lint findings (unreachable steps, hard-coded secrets) fail validation alongside other violations, while warnings (unused inputs, unguarded DELETE steps, unbounded repeats, provider inputs without on_error) are returned under lint; set lint_format=sarif to also receive a SARIF log. Condition syntax guidance: use `==`/`!=` (not `===`/`!==`), and use roots `inputs.*`, `steps.*`, or `env.*` (`output.*` is unsupported in conditions). Authoring guidance: for manual/free-text inputs, include `placeholder`, `hint`, and `example` metadata to improve collector UX; provider-backed inputs must use explicit scalar `select.value_field` paths."
     )]
     async fn workflow_validate(&self, param: Parameters<WorkflowValidateRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = validate_workflow(&param.0, &self.services.command_registry);
+        let result = self
+            .authorize_tool_call("workflow_validate", request_payload.as_ref())
+            .and_then(|()| validate_workflow(&param.0, &self.services.command_registry));
         Ok(self.finalize_structured_tool_call("workflow_validate", request_payload, result))
     }
 
@@ -410,7 +550,10 @@ impl OattyMcpCore {
     )]
     async fn workflow_save(&self, param: Parameters<WorkflowSaveRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = save_workflow(&param.0, &self.services.command_registry);
+        let result = match self.guard_tool_call("workflow_save", request_payload.as_ref()).await {
+            Ok(()) => save_workflow(&param.0, &self.services.command_registry),
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("workflow_save", request_payload, result))
     }
 
@@ -421,7 +564,11 @@ impl OattyMcpCore {
     )]
     async fn workflow_export(&self, param: Parameters<WorkflowExportRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        Ok(self.finalize_structured_tool_call("workflow_export", request_payload, export_workflow(&param.0)))
+        let result = match self.guard_tool_call("workflow_export", request_payload.as_ref()).await {
+            Ok(()) => export_workflow(&param.0),
+            Err(error) => Err(error),
+        };
+        Ok(self.finalize_structured_tool_call("workflow_export", request_payload, result))
     }
 
     #[tool(
@@ -431,7 +578,10 @@ impl OattyMcpCore {
     )]
     async fn workflow_import(&self, param: Parameters<WorkflowImportRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = import_workflow(&param.0, &self.services.command_registry);
+        let result = match self.guard_tool_call("workflow_import", request_payload.as_ref()).await {
+            Ok(()) => import_workflow(&param.0, &self.services.command_registry),
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("workflow_import", request_payload, result))
     }
 
@@ -442,7 +592,10 @@ impl OattyMcpCore {
     )]
     async fn workflow_rename(&self, param: Parameters<WorkflowRenameRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = rename_workflow(&param.0, &self.services.command_registry);
+        let result = match self.guard_tool_call("workflow_rename", request_payload.as_ref()).await {
+            Ok(()) => rename_workflow(&param.0, &self.services.command_registry),
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("workflow_rename", request_payload, result))
     }
 
@@ -453,7 +606,10 @@ impl OattyMcpCore {
     )]
     async fn workflow_delete(&self, param: Parameters<WorkflowDeleteRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = delete_workflow(&param.0, &self.services.command_registry);
+        let result = match self.guard_tool_call("workflow_delete", request_payload.as_ref()).await {
+            Ok(()) => delete_workflow(&param.0, &self.services.command_registry),
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("workflow_delete", request_payload, result))
     }
 
@@ -464,7 +620,10 @@ impl OattyMcpCore {
     )]
     async fn workflow_preview_inputs(&self, param: Parameters<WorkflowPreviewInputsRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        Ok(self.finalize_structured_tool_call("workflow_preview_inputs", request_payload, preview_inputs(&param.0)))
+        let result = self
+            .authorize_tool_call("workflow_preview_inputs", request_payload.as_ref())
+            .and_then(|()| preview_inputs(&param.0));
+        Ok(self.finalize_structured_tool_call("workflow_preview_inputs", request_payload, result))
     }
 
     #[tool(
@@ -474,20 +633,56 @@ impl OattyMcpCore {
     )]
     async fn workflow_resolve_inputs(&self, param: Parameters<WorkflowResolveInputsRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        Ok(self.finalize_structured_tool_call("workflow_resolve_inputs", request_payload, resolve_inputs(&param.0)))
+        let result = self
+            .authorize_tool_call("workflow_resolve_inputs", request_payload.as_ref())
+            .and_then(|()| resolve_inputs(&param.0));
+        Ok(self.finalize_structured_tool_call("workflow_resolve_inputs", request_payload, result))
     }
 
     #[tool(
         name = "workflow_run",
         annotations(open_world_hint = true),
-        description = "Execute workflow by identifier or inline manifest. Input: workflow_id|manifest_content, format?, inputs?, execution_mode(sync|auto|task), include_results?(default true), include_outputs?(default false). Mode guidance: task for long/uncertain runs or when progress/cancel is needed; sync for short immediate runs; auto when unsure."
+        description = "Execute workflow by identifier or inline manifest. Input: workflow_id|manifest_content, format?, inputs?, execution_mode(sync|auto|task), include_results?(default true), include_outputs?(default false). Mode guidance: task for long/uncertain runs or when progress/cancel is needed; sync for short immediate runs; auto when unsure. Send a progressToken to receive per-step progress notifications; cancelling the request cancels the run after the active step."
     )]
-    async fn workflow_run(&self, param: Parameters<WorkflowRunRequest>) -> Result<CallToolResult, ErrorData> {
+    async fn workflow_run(
+        &self,
+        param: Parameters<WorkflowRunRequest>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = run_with_task_capability_guard(&param.0, &self.services.command_registry);
+        let result = match self.guard_tool_call("workflow_run", request_payload.as_ref()).await {
+            Ok(()) => {
+                let observer = WorkflowRunObserver::default()
+                    .with_registry(self.workflow_runs.clone(), Some(context.id.to_string()))
+                    .with_progress(context.peer.clone(), context.meta.get_progress_token())
+                    .with_resource_updates(context.peer.clone(), self.resource_subscriptions.clone())
+                    .with_cancellation(context.ct.clone());
+                run_with_task_capability_guard(
+                    &param.0,
+                    &self.services.command_registry,
+                    &self.audit_trail(),
+                    &self.workflow_run_guard("workflow_run"),
+                    observer,
+                )
+            }
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("workflow_run", request_payload, result))
     }
 
+    #[tool(
+        name = "workflow_run_status",
+        annotations(read_only_hint = true),
+        description = "Report the live status of a workflow_run started in this session: current step and attempt, completed/total steps, and results for steps finished so far. Input: run_id|operation_id, include_results?(default true)."
+    )]
+    async fn workflow_run_status(&self, param: Parameters<WorkflowRunStatusRequest>) -> Result<CallToolResult, ErrorData> {
+        let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
+        let result = self
+            .authorize_tool_call("workflow_run_status", request_payload.as_ref())
+            .and_then(|()| run_status(&param.0, &self.workflow_runs));
+        Ok(self.finalize_structured_tool_call("workflow_run_status", request_payload, result))
+    }
+
     #[tool(
         name = "workflow_step_plan",
         annotations(read_only_hint = true),
@@ -495,7 +690,10 @@ impl OattyMcpCore {
     )]
     async fn workflow_step_plan(&self, param: Parameters<WorkflowStepPlanRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        Ok(self.finalize_structured_tool_call("workflow_step_plan", request_payload, step_plan(&param.0)))
+        let result = self
+            .authorize_tool_call("workflow_step_plan", request_payload.as_ref())
+            .and_then(|()| step_plan(&param.0));
+        Ok(self.finalize_structured_tool_call("workflow_step_plan", request_payload, result))
     }
 
     #[tool(
@@ -505,7 +703,10 @@ impl OattyMcpCore {
     )]
     async fn workflow_preview_rendered(&self, param: Parameters<WorkflowPreviewRenderedRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        Ok(self.finalize_structured_tool_call("workflow_preview_rendered", request_payload, preview_rendered(&param.0)))
+        let result = self
+            .authorize_tool_call("workflow_preview_rendered", request_payload.as_ref())
+            .and_then(|()| preview_rendered(&param.0));
+        Ok(self.finalize_structured_tool_call("workflow_preview_rendered", request_payload, result))
     }
 
     #[tool(
@@ -515,7 +716,9 @@ impl OattyMcpCore {
     )]
     async fn workflow_cancel(&self, param: Parameters<WorkflowCancelRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result: Result<Value, ErrorData> = {
+        let result: Result<Value, ErrorData> = if let Err(error) = self.guard_tool_call("workflow_cancel", request_payload.as_ref()).await {
+            Err(error)
+        } else {
             let mut processor = self.task_processor.lock().await;
             if processor
                 .peek_completed()
@@ -529,11 +732,14 @@ impl OattyMcpCore {
                     "Inspect task result and start a new run if needed.",
                 ))
             } else {
+                // Aborting the task cannot interrupt a step in progress, so the runner is also told to stop.
+                let runner_signalled = self.workflow_runs.cancel(&param.0.operation_id);
                 let cancelled = processor.cancel_task(&param.0.operation_id);
-                if cancelled {
+                if cancelled || runner_signalled {
                     Ok(serde_json::json!({
                         "cancelled": true,
                         "operation_id": param.0.operation_id,
+                        "runner_signalled": runner_signalled,
                     }))
                 } else if processor
                     .peek_completed()
@@ -566,7 +772,11 @@ impl OattyMcpCore {
     )]
     async fn workflow_purge_history(&self, param: Parameters<WorkflowPurgeHistoryRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        Ok(self.finalize_structured_tool_call("workflow_purge_history", request_payload, purge_workflow_history(&param.0)))
+        let result = match self.guard_tool_call("workflow_purge_history", request_payload.as_ref()).await {
+            Ok(()) => purge_workflow_history(&param.0),
+            Err(error) => Err(error),
+        };
+        Ok(self.finalize_structured_tool_call("workflow_purge_history", request_payload, result))
     }
 
     #[tool(
@@ -576,7 +786,15 @@ impl OattyMcpCore {
     )]
     async fn workflow_author_and_run(&self, param: Parameters<WorkflowAuthorAndRunRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = author_and_run(&param.0, &self.services.command_registry);
+        let result = match self.guard_tool_call("workflow_author_and_run", request_payload.as_ref()).await {
+            Ok(()) => author_and_run(
+                &param.0,
+                &self.services.command_registry,
+                &self.audit_trail(),
+                &self.workflow_run_guard("workflow_author_and_run"),
+            ),
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("workflow_author_and_run", request_payload, result))
     }
 
@@ -587,7 +805,15 @@ impl OattyMcpCore {
     )]
     async fn workflow_repair_and_rerun(&self, param: Parameters<WorkflowRepairAndRerunRequest>) -> Result<CallToolResult, ErrorData> {
         let request_payload = Some(serde_json::to_value(&param.0).unwrap_or(Value::Null));
-        let result = repair_and_rerun(&param.0, &self.services.command_registry);
+        let result = match self.guard_tool_call("workflow_repair_and_rerun", request_payload.as_ref()).await {
+            Ok(()) => repair_and_rerun(
+                &param.0,
+                &self.services.command_registry,
+                &self.audit_trail(),
+                &self.workflow_run_guard("workflow_repair_and_rerun"),
+            ),
+            Err(error) => Err(error),
+        };
         Ok(self.finalize_structured_tool_call("workflow_repair_and_rerun", request_payload, result))
     }
 
@@ -596,7 +822,9 @@ impl OattyMcpCore {
         param: &RunCommandRequestParam,
         method_guard: HttpMethodGuard,
     ) -> Result<CallToolResult, ErrorData> {
+        self.authorize_tool_call(method_guard.tool_name(), None)?;
         let command_spec = resolve_command_spec(&self.services.command_registry, &param.canonical_id)?;
+        self.authorize_command(&command_spec)?;
         if let Some(http_spec) = command_spec.http() {
             let method = Method::from_str(&http_spec.method).map_err(|error| {
                 invalid_params_with_next_step(
@@ -609,38 +837,401 @@ impl OattyMcpCore {
                 )
             })?;
             method_guard.ensure_allowed(&method)?;
+        } else if command_spec.mcp().is_some() {
+            method_guard.ensure_mcp_allowed()?;
+        } else {
+            return Err(ErrorData::invalid_params(
+                "command execution type is unsupported by the MCP server",
+                Some(serde_json::json!({
+                    "canonical_id": param.canonical_id,
+                    "next_step": "Use search_commands and execute only commands with execution_type=http or execution_type=mcp."
+                })),
+            ));
+        }
 
-            let exec_outcome = execute_http_command(&self.services.command_registry, &command_spec, param).await?;
-            let structured = exec_outcome_to_value(exec_outcome)?;
-            return Ok(build_structured_tool_result(structured));
+        // Prompt only for calls every other check already admits.
+        let pending_call = self.pending_command_call(method_guard.tool_name(), &command_spec, param);
+        self.confirm_tool_call(pending_call.clone()).await?;
+        let _permit = self.acquire_execution(pending_call.catalog.as_deref(), !matches!(method_guard, HttpMethodGuard::SafeGet))?;
+        let started_at = Instant::now();
+        let exec_outcome = match command_spec.mcp() {
+            Some(_) => self.execute_mcp_command(&command_spec, param).await,
+            None => execute_http_command(&self.services.command_registry, &command_spec, param).await,
+        };
+        self.audit_command_execution(&pending_call, exec_outcome.as_ref(), started_at.elapsed())
+            .await;
+        let structured = exec_outcome_to_value(exec_outcome?)?;
+        Ok(build_structured_tool_result(structured))
+    }
+
+    async fn execute_mcp_command(&self, command_spec: &CommandSpec, param: &RunCommandRequestParam) -> Result<ExecOutcome, ErrorData> {
+        let arguments = build_mcp_arguments(command_spec, param)?;
+        self.services
+            .plugin_engine
+            .execute_tool(command_spec, &arguments, 0)
+            .await
+            .map_err(|error| {
+                internal_error_with_next_step(
+                    error.to_string(),
+                    serde_json::json!({ "canonical_id": command_spec.canonical_id() }),
+                    "Inspect plugin health and required arguments, then retry the MCP command.",
+                )
+            })
+    }
+
+    /// Admit an execution under the configured rate limits; the permit is held until it finishes.
+    fn acquire_execution(&self, catalog: Option<&str>, is_write: bool) -> Result<Option<ExecutionPermit>, ErrorData> {
+        self.rate_limiter
+            .as_ref()
+            .map(|limiter| limiter.acquire(catalog, is_write))
+            .transpose()
+    }
+
+    /// Session restrictions checked against the workflow definition a run actually executes.
+    fn workflow_run_guard(&self, tool_name: &str) -> WorkflowRunGuard {
+        let core = self.clone();
+        let confirmer: StepConfirmer = Arc::new(move |call| {
+            let core = core.clone();
+            Box::pin(async move { core.confirm_tool_call(call).await })
+        });
+        WorkflowRunGuard::new(self.policy_scope().clone())
+            .with_rate_limiter(self.rate_limiter.clone())
+            .with_confirmer(tool_name, confirmer)
+    }
+
+    /// Initiator, client, and session details for audit entries written by this session.
+    fn audit_invocation(&self) -> AuditInvocation {
+        AuditInvocation {
+            client: self.client_name.get().cloned(),
+            session_id: self.session_id.get().cloned(),
+            ..AuditInvocation::new(AuditInitiator::Agent)
         }
+    }
 
-        if command_spec.mcp().is_some() {
-            method_guard.ensure_mcp_allowed()?;
-            let arguments = build_mcp_arguments(&command_spec, param)?;
-            let exec_outcome = self
+    /// Audit trail handed to workflow tools so each step is recorded against this session.
+    fn audit_trail(&self) -> AuditTrail {
+        AuditTrail::new(
+            self.services.plugin_engine.log_manager().audit_logger().clone(),
+            MCP_SERVER_AUDIT_SOURCE,
+            self.audit_invocation(),
+        )
+    }
+
+    async fn audit_command_execution(&self, call: &McpPendingToolCall, outcome: Result<&ExecOutcome, &ErrorData>, elapsed: Duration) {
+        let status = match outcome {
+            Ok(ExecOutcome::Http { status_code, .. }) => Some(*status_code),
+            _ => None,
+        };
+        let result = match (outcome, status) {
+            (Err(_), _) => AuditResult::Failure,
+            (Ok(_), Some(status)) => AuditResult::from_http_status(status),
+            (Ok(_), None) => AuditResult::Success,
+        };
+        let error = outcome.err().map(|error| error.message.to_string());
+        self.record_command_execution(call, status, result, error, elapsed).await;
+    }
+
+    async fn record_command_execution(
+        &self,
+        call: &McpPendingToolCall,
+        status: Option<u16>,
+        result: AuditResult,
+        error: Option<String>,
+        elapsed: Duration,
+    ) {
+        let invocation = AuditInvocation {
+            canonical_id: call.canonical_id.clone(),
+            catalog: call.catalog.clone(),
+            method: call.http_method.clone(),
+            path: call
+                .url
+                .as_deref()
+                .and_then(|url| url::Url::parse(url).ok())
+                .map(|url| url.path().to_string()),
+            status,
+            ..self.audit_invocation()
+        }
+        .with_duration(elapsed);
+        let mut entry = AuditEntry::command_execution(MCP_SERVER_AUDIT_SOURCE.to_string(), invocation, result)
+            .with_metadata("tool_name", Value::String(call.tool.clone()));
+        if let Some(error) = error {
+            entry = entry.with_metadata("error", Value::String(error));
+        }
+        let _ = self.services.plugin_engine.log_manager().log_audit(entry).await;
+    }
+
+    /// Forwards a namespaced plugin tool call in gateway mode.
+    ///
+    /// The call goes through the same policy, confirmation, audit, and log redaction steps as
+    /// `run_command`. The plugin's result is forwarded with its text and structured content
+    /// redacted; binary content is passed through as is.
+    async fn call_gateway_tool(&self, name: &str, arguments: Option<Map<String, Value>>) -> CallToolResult {
+        // Plugin arguments often carry credentials, so the logged copy is redacted.
+        let request_payload = arguments.as_ref().map(|arguments| redact_json(&Value::Object(arguments.clone())));
+        let result = async {
+            let tool = resolve_gateway_tool(&self.services.plugin_engine, &self.services.command_registry, name)
+                .await
+                .ok_or_else(|| unknown_gateway_capability("tool", name))?;
+            tool.check(self.policy_scope())?;
+            let arguments = arguments.unwrap_or_default();
+            let pending_call = McpPendingToolCall {
+                tool: name.to_string(),
+                catalog: Some(tool.plugin_name.clone()),
+                canonical_id: tool.command.as_ref().map(CommandSpec::canonical_id),
+                body: Some(redact_json(&Value::Object(arguments.clone()))),
+                ..McpPendingToolCall::default()
+            };
+            self.confirm_tool_call(pending_call.clone()).await?;
+            let _permit = self.acquire_execution(Some(&tool.plugin_name), !tool.is_read_only())?;
+
+            let started_at = Instant::now();
+            let outcome = self
                 .services
                 .plugin_engine
-                .execute_tool(&command_spec, &arguments, 0)
+                .client_manager()
+                .call_tool(&tool.plugin_name, &tool.metadata.name, &arguments)
                 .await
-                .map_err(|error| {
-                    internal_error_with_next_step(
-                        error.to_string(),
-                        serde_json::json!({ "canonical_id": command_spec.canonical_id() }),
-                        "Inspect plugin health and required arguments, then retry the MCP command.",
-                    )
-                })?;
-            let structured = exec_outcome_to_value(exec_outcome)?;
-            return Ok(build_structured_tool_result(structured));
+                .map(|mut call_result| {
+                    redact_tool_result(&mut call_result);
+                    call_result
+                })
+                .map_err(|error| plugin_request_error(&tool.plugin_name, "tools/call", error.to_string()));
+            let (audit_result, error) = match &outcome {
+                Ok(call_result) if call_result.is_error == Some(true) => (AuditResult::Failure, None),
+                Ok(_) => (AuditResult::Success, None),
+                Err(error) => (AuditResult::Failure, Some(error.message.to_string())),
+            };
+            self.record_command_execution(&pending_call, None, audit_result, error, started_at.elapsed())
+                .await;
+            outcome
+        }
+        .await;
+        self.finalize_tool_call(name, request_payload, result)
+    }
+
+    /// Records a gateway resource read or prompt render; policy denials are audited as `Denied`.
+    async fn audit_gateway_access<T>(
+        &self,
+        plugin_name: &str,
+        outcome: &Result<T, ErrorData>,
+        elapsed: Duration,
+        entry: impl FnOnce(AuditInvocation, AuditResult) -> AuditEntry,
+    ) {
+        let result = match outcome {
+            Ok(_) => AuditResult::Success,
+            Err(error) if error.data.as_ref().and_then(|data| data.get("category")).and_then(Value::as_str) == Some("policy") => {
+                AuditResult::Denied
+            }
+            Err(_) => AuditResult::Failure,
+        };
+        let invocation = AuditInvocation {
+            catalog: Some(plugin_name.to_string()),
+            ..self.audit_invocation()
         }
+        .with_duration(elapsed);
+        let _ = self.services.plugin_engine.log_manager().log_audit(entry(invocation, result)).await;
+    }
 
-        Err(ErrorData::invalid_params(
-            "command execution type is unsupported by the MCP server",
-            Some(serde_json::json!({
-                "canonical_id": param.canonical_id,
-                "next_step": "Use search_commands and execute only commands with execution_type=http or execution_type=mcp."
-            })),
-        ))
+    /// Downstream tools this session may call, when gateway mode is on.
+    async fn list_gateway_tools(&self) -> Vec<Tool> {
+        if !self.gateway {
+            return Vec::new();
+        }
+        let scope = self.policy_scope();
+        gateway_tools(&self.services.plugin_engine, &self.services.command_registry)
+            .await
+            .into_iter()
+            .filter(|tool| tool.check(scope).is_ok())
+            .filter_map(|tool| tool.to_tool())
+            .collect()
+    }
+
+    async fn audit_tool_call(
+        &self,
+        tool_name: &str,
+        arguments: Option<&Map<String, Value>>,
+        outcome: &Result<CallToolResult, ErrorData>,
+        elapsed: Duration,
+    ) {
+        let result = match outcome {
+            Ok(call_result) if call_result.is_error != Some(true) => AuditResult::Success,
+            Ok(call_result) if is_policy_denial(call_result) => AuditResult::Denied,
+            _ => AuditResult::Failure,
+        };
+        let argument = |key: &str| {
+            arguments
+                .and_then(|arguments| arguments.get(key))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+        let invocation = AuditInvocation {
+            canonical_id: argument("canonical_id"),
+            catalog: argument("catalog_title").or_else(|| argument("catalog_id")),
+            ..self.audit_invocation()
+        }
+        .with_duration(elapsed);
+        let entry = AuditEntry::mcp_tool_call(MCP_SERVER_AUDIT_SOURCE.to_string(), tool_name.to_string(), invocation, result);
+        let _ = self.services.plugin_engine.log_manager().log_audit(entry).await;
+    }
+
+    /// Router used by the tool handler; times and audits every call.
+    fn audited_tool_router(&self) -> AuditedToolRouter<'_> {
+        AuditedToolRouter { core: self }
+    }
+
+    /// Returns the policy scope for this session.
+    fn policy_scope(&self) -> &McpPolicyScope {
+        self.policy_scope.get_or_init(|| self.resolve_policy_scope(None, None))
+    }
+
+    /// Resolves the session scope; named client rules apply only to a token-authenticated client.
+    fn resolve_policy_scope(&self, authenticated_client: Option<&str>, reported_name: Option<&str>) -> McpPolicyScope {
+        match (self.policy.as_deref(), authenticated_client) {
+            (Some(policy), Some(client_name)) => policy.scope_for_authenticated_client(client_name),
+            (Some(policy), None) => policy.scope_for_unauthenticated_client(reported_name),
+            (None, _) => McpPolicyScope::unrestricted(),
+        }
+    }
+
+    /// Applies the catalog, canonical-id, and HTTP method allowlists to a single command.
+    fn authorize_command(&self, command: &CommandSpec) -> Result<(), ErrorData> {
+        let registry = self.services.command_registry.lock().map_err(|error| {
+            internal_error_with_next_step(
+                format!("registry lock failed: {error}"),
+                serde_json::json!({ "canonical_id": command.canonical_id() }),
+                "Retry the command. If this persists, restart the MCP server session.",
+            )
+        })?;
+        self.policy_scope()
+            .check_command(command, command_catalog_title(&registry, command))
+    }
+
+    /// Applies tool-level policy checks, then asks for operator confirmation when a rule matches.
+    async fn guard_tool_call(&self, tool_name: &str, request: Option<&Value>) -> Result<(), ErrorData> {
+        self.authorize_tool_call(tool_name, request)?;
+        let catalog = request.and_then(|request| {
+            ["catalog_id", "catalog_title"]
+                .iter()
+                .find_map(|key| request.get(*key).and_then(Value::as_str))
+        });
+        self.confirm_tool_call(McpPendingToolCall {
+            tool: tool_name.to_string(),
+            catalog: catalog.map(str::to_string),
+            body: request.map(redact_json),
+            ..McpPendingToolCall::default()
+        })
+        .await
+    }
+
+    /// Describes a command execution for confirmation rules and the operator prompt.
+    fn pending_command_call(&self, tool_name: &str, command_spec: &CommandSpec, param: &RunCommandRequestParam) -> McpPendingToolCall {
+        let mut call = McpPendingToolCall {
+            tool: tool_name.to_string(),
+            canonical_id: Some(command_spec.canonical_id()),
+            ..McpPendingToolCall::default()
+        };
+        if let Some(mcp) = command_spec.mcp() {
+            call.catalog = Some(mcp.plugin_name.clone());
+            call.body = build_mcp_arguments(command_spec, param)
+                .ok()
+                .map(|arguments| redact_json(&Value::Object(arguments)));
+            return call;
+        }
+        let Some(http) = command_spec.http() else {
+            return call;
+        };
+        let input_map = build_http_input_map(command_spec, param).unwrap_or_default();
+        if let Ok(registry) = self.services.command_registry.lock() {
+            call.catalog = command_catalog_title(&registry, command_spec).map(str::to_string);
+            call.url = registry
+                .resolve_base_url_for_command(command_spec)
+                .map(|base_url| format!("{}{}", base_url.trim_end_matches('/'), build_path(&http.path, &input_map)));
+        }
+        call.http_method = Some(http.method.to_ascii_uppercase());
+        call.body = Some(redact_json(&Value::Object(input_map)));
+        call
+    }
+
+    /// Pauses calls matched by a `requireConfirmation` rule until a human approves them.
+    ///
+    /// Every decision, including timeouts and missing confirmation channels, is audited.
+    async fn confirm_tool_call(&self, mut call: McpPendingToolCall) -> Result<(), ErrorData> {
+        let scope = self.policy_scope();
+        if !scope.requires_confirmation(&call) {
+            return Ok(());
+        }
+        call.client = scope.client_name().map(str::to_string);
+        let decision = request_confirmation(
+            &call,
+            self.confirmation_sender.as_ref(),
+            self.peer.get(),
+            scope.confirmation_timeout(),
+        )
+        .await;
+        self.audit_confirmation(&call, decision).await;
+        if decision.approved {
+            return Ok(());
+        }
+
+        let summary = call.summary();
+        let context = serde_json::json!({
+            "tool": call.tool,
+            "call": summary,
+            "channel": decision.channel.as_str(),
+            "timed_out": decision.timed_out,
+        });
+        Err(match decision.channel {
+            ConfirmationChannel::Unavailable => policy_denied_error(
+                format!("'{summary}' requires operator confirmation, but no confirmation channel is available"),
+                context,
+                "Ask the user to run this from the Oatty TUI, to connect with a client that supports MCP elicitation, or to perform the action directly.",
+            ),
+            _ if decision.timed_out => operator_denied_error(
+                format!("'{summary}' was not confirmed by the operator in time"),
+                context,
+                "Tell the user the call is waiting for their approval, then retry once they are ready.",
+            ),
+            _ => operator_denied_error(
+                format!("'{summary}' was denied by the operator"),
+                context,
+                "Do not retry this call. Explain what you intended to do and ask the user how to proceed.",
+            ),
+        })
+    }
+
+    async fn audit_confirmation(&self, call: &McpPendingToolCall, decision: ConfirmationDecision) {
+        let mut metadata = match serde_json::to_value(call) {
+            Ok(Value::Object(metadata)) => metadata,
+            _ => Map::new(),
+        };
+        metadata.insert("channel".to_string(), Value::String(decision.channel.as_str().to_string()));
+        metadata.insert("timed_out".to_string(), Value::Bool(decision.timed_out));
+        let entry = AuditEntry::operator_confirmation(MCP_SERVER_AUDIT_SOURCE.to_string(), metadata, decision.approved);
+        let _ = self.services.plugin_engine.log_manager().log_audit(entry).await;
+    }
+
+    /// Applies tool-level policy checks before a tool runs.
+    ///
+    /// Covers read-only mode and catalog mutations, the catalog and workflow allowlists for
+    /// identifiers named in the request, and the command allowlists for every step of a
+    /// workflow that is about to run.
+    fn authorize_tool_call(&self, tool_name: &str, request: Option<&Value>) -> Result<(), ErrorData> {
+        let scope = self.policy_scope();
+        scope.check_tool(tool_name)?;
+        let Some(request) = request else {
+            return Ok(());
+        };
+        if let Some(catalog_title) = ["catalog_id", "catalog_title"]
+            .iter()
+            .find_map(|key| request.get(*key).and_then(Value::as_str))
+        {
+            scope.check_catalog(catalog_title)?;
+        }
+        let manifests = requested_manifests(request);
+        for workflow_id in requested_workflow_ids(request, &manifests) {
+            scope.check_workflow(&workflow_id)?;
+        }
+        Ok(())
     }
 
     fn finalize_structured_tool_call(&self, tool_name: &str, request: Option<Value>, result: Result<Value, ErrorData>) -> CallToolResult {
@@ -677,16 +1268,129 @@ impl OattyMcpCore {
     }
 }
 
-#[tool_handler]
+/// Wraps the generated tool router so each call is recorded in the audit trail with its duration.
+struct AuditedToolRouter<'a> {
+    core: &'a OattyMcpCore,
+}
+
+impl AuditedToolRouter<'_> {
+    async fn call(&self, context: ToolCallContext<'_, OattyMcpCore>) -> Result<CallToolResult, ErrorData> {
+        if let Some(session_id) = mcp_session_id(context.request_context()) {
+            let _ = self.core.session_id.set(session_id);
+        }
+        let tool_name = context.name().to_string();
+        let arguments = context.arguments.clone();
+        let started_at = Instant::now();
+        let outcome = if self.core.gateway && !self.core.tool_router.has_route(&tool_name) {
+            Ok(self.core.call_gateway_tool(&tool_name, arguments.clone()).await)
+        } else {
+            self.core.tool_router.call(context).await
+        };
+        self.core
+            .audit_tool_call(&tool_name, arguments.as_ref(), &outcome, started_at.elapsed())
+            .await;
+        outcome
+    }
+
+    async fn list_all(&self) -> Vec<Tool> {
+        let mut tools = self.core.tool_router.list_all();
+        tools.extend(self.core.list_gateway_tools().await);
+        tools
+    }
+
+    fn get(&self, name: &str) -> Option<&Tool> {
+        self.core.tool_router.get(name)
+    }
+}
+
+/// Reads the `Mcp-Session-Id` header the streamable HTTP transport attaches to each request.
+fn mcp_session_id(context: &RequestContext<RoleServer>) -> Option<String> {
+    context
+        .extensions
+        .get::<http::request::Parts>()
+        .and_then(|parts| parts.headers.get("mcp-session-id"))
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Returns the client name bound to the bearer token that authenticated this request, if any.
+fn authenticated_client(context: &RequestContext<RoleServer>) -> Option<String> {
+    context
+        .extensions
+        .get::<http::request::Parts>()
+        .and_then(|parts| parts.extensions.get::<McpAuthenticatedClient>())
+        .map(|client| client.0.clone())
+}
+
+/// Whether an error result was produced by an access policy or operator denial.
+fn is_policy_denial(result: &CallToolResult) -> bool {
+    result
+        .structured_content
+        .as_ref()
+        .and_then(|content| content.get("category"))
+        .and_then(Value::as_str)
+        == Some("policy")
+}
+
 #[allow(deprecated)]
 #[task_handler(processor = self.task_processor)]
 impl ServerHandler for OattyMcpCore {
-    fn list_resources(
+    async fn call_tool(&self, request: CallToolRequestParams, context: RequestContext<RoleServer>) -> Result<CallToolResult, McpError> {
+        self.audited_tool_router().call(ToolCallContext::new(self, request, context)).await
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        Ok(ListToolsResult {
+            tools: self.audited_tool_router().list_all().await,
+            meta: None,
+            next_cursor: None,
+        })
+    }
+
+    fn get_tool(&self, name: &str) -> Option<Tool> {
+        self.audited_tool_router().get(name).cloned()
+    }
+
+    fn initialize(
+        &self,
+        request: InitializeRequestParams,
+        context: RequestContext<rmcp::RoleServer>,
+    ) -> impl Future<Output = Result<InitializeResult, McpError>> + Send + '_ {
+        // A token-authenticated identity cannot be spoofed through `clientInfo`, so it wins and is
+        // the only identity allowed to select a named policy rule.
+        let authenticated_client = authenticated_client(&context);
+        let reported_name = request.client_info.name.clone();
+        let _ = self
+            .policy_scope
+            .set(self.resolve_policy_scope(authenticated_client.as_deref(), Some(reported_name.as_str())));
+        let client_name = authenticated_client.unwrap_or(reported_name);
+        let _ = self.peer.set(context.peer.clone());
+        let _ = self.client_name.set(client_name);
+        if context.peer.peer_info().is_none() {
+            context.peer.set_peer_info(request);
+        }
+        std::future::ready(Ok(self.get_info()))
+    }
+
+    async fn list_resources(
         &self,
         _request: Option<PaginatedRequestParams>,
         _context: RequestContext<rmcp::RoleServer>,
-    ) -> impl Future<Output = Result<ListResourcesResult, McpError>> + Send + '_ {
-        std::future::ready(Ok(list_workflow_resources()))
+    ) -> Result<ListResourcesResult, McpError> {
+        let mut result = list_workflow_resources();
+        result
+            .resources
+            .extend(list_catalog_resources(&self.services.command_registry, self.policy_scope()));
+        if self.gateway {
+            result
+                .resources
+                .extend(list_gateway_resources(&self.services.plugin_engine, self.policy_scope()).await);
+        }
+        Ok(result)
     }
 
     fn list_resource_templates(
@@ -694,31 +1398,93 @@ impl ServerHandler for OattyMcpCore {
         _request: Option<PaginatedRequestParams>,
         _context: RequestContext<rmcp::RoleServer>,
     ) -> impl Future<Output = Result<ListResourceTemplatesResult, McpError>> + Send + '_ {
-        std::future::ready(Ok(list_workflow_resource_templates()))
+        let mut result = list_workflow_resource_templates();
+        result.resource_templates.extend(command_resource_templates());
+        std::future::ready(Ok(result))
     }
 
-    fn read_resource(
+    async fn read_resource(
         &self,
         request: ReadResourceRequestParams,
         _context: RequestContext<rmcp::RoleServer>,
-    ) -> impl Future<Output = Result<ReadResourceResult, McpError>> + Send + '_ {
-        std::future::ready(read_workflow_resource(&request.uri, &self.services.command_registry))
+    ) -> Result<ReadResourceResult, McpError> {
+        if self.gateway
+            && let Some((plugin_name, uri)) = split_gateway_resource_uri(&request.uri)
+        {
+            let started_at = Instant::now();
+            let result = read_gateway_resource(&self.services.plugin_engine, self.policy_scope(), plugin_name, uri).await;
+            self.audit_gateway_access(plugin_name, &result, started_at.elapsed(), |invocation, audit_result| {
+                AuditEntry::resource_read(MCP_SERVER_AUDIT_SOURCE.to_string(), request.uri.clone(), invocation, audit_result)
+            })
+            .await;
+            return result;
+        }
+        if let Some(resource) = CommandResource::parse(&request.uri) {
+            return read_command_resource(&resource, &request.uri, &self.services.command_registry, self.policy_scope());
+        }
+        if workflow_run_id(&request.uri).is_some() {
+            return read_run_resource(&request.uri, &self.workflow_runs, self.policy_scope());
+        }
+        read_workflow_resource(&request.uri, &self.services.command_registry, self.policy_scope())
     }
 
-    fn list_prompts(
+    fn subscribe(
+        &self,
+        request: SubscribeRequestParams,
+        context: RequestContext<rmcp::RoleServer>,
+    ) -> impl Future<Output = Result<(), McpError>> + Send + '_ {
+        if self.resource_subscriptions.subscribe(&request.uri) {
+            self.resource_subscriptions
+                .spawn_registry_listener(Arc::clone(&self.services.command_registry), context.peer);
+        }
+        std::future::ready(Ok(()))
+    }
+
+    fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParams,
+        _context: RequestContext<rmcp::RoleServer>,
+    ) -> impl Future<Output = Result<(), McpError>> + Send + '_ {
+        self.resource_subscriptions.unsubscribe(&request.uri);
+        std::future::ready(Ok(()))
+    }
+
+    async fn list_prompts(
         &self,
         _request: Option<PaginatedRequestParams>,
         _context: RequestContext<rmcp::RoleServer>,
-    ) -> impl Future<Output = Result<ListPromptsResult, McpError>> + Send + '_ {
-        std::future::ready(Ok(list_workflow_prompts()))
+    ) -> Result<ListPromptsResult, McpError> {
+        let mut result = list_workflow_prompts();
+        if self.gateway {
+            result
+                .prompts
+                .extend(list_gateway_prompts(&self.services.plugin_engine, self.policy_scope()).await);
+        }
+        Ok(result)
     }
 
-    fn get_prompt(
+    async fn get_prompt(
         &self,
         request: GetPromptRequestParams,
         _context: RequestContext<rmcp::RoleServer>,
-    ) -> impl Future<Output = Result<GetPromptResult, McpError>> + Send + '_ {
-        std::future::ready(get_workflow_prompt(&request.name, request.arguments.as_ref()))
+    ) -> Result<GetPromptResult, McpError> {
+        let started_at = Instant::now();
+        if self.gateway
+            && let Some((plugin_name, result)) = get_gateway_prompt(
+                &self.services.plugin_engine,
+                self.policy_scope(),
+                &request.name,
+                request.arguments.clone(),
+            )
+            .await
+        {
+            self.audit_gateway_access(&plugin_name, &result, started_at.elapsed(), |invocation, audit_result| {
+                AuditEntry::prompt_get(MCP_SERVER_AUDIT_SOURCE.to_string(), request.name.clone(), invocation, audit_result)
+            })
+            .await;
+            return result;
+        }
+        get_workflow_prompt(&request.name, request.arguments.as_ref())
     }
 
     fn get_info(&self) -> ServerInfo {
@@ -727,6 +1493,7 @@ impl ServerHandler for OattyMcpCore {
                 .enable_tools()
                 .enable_tasks()
                 .enable_resources()
+                .enable_resources_subscribe()
                 .enable_prompts()
                 .build(),
             protocol_version: ProtocolVersion::LATEST,
@@ -788,6 +1555,9 @@ fn build_structured_error_tool_result(error: ErrorData) -> CallToolResult {
             if let Some(violations) = details_object.get("violations") {
                 payload.insert("violations".to_string(), violations.clone());
             }
+            if let Some(retry_after_seconds) = details_object.get("retry_after_seconds") {
+                payload.insert("retry_after_seconds".to_string(), retry_after_seconds.clone());
+            }
         }
 
         payload.insert("details".to_string(), error_data);
@@ -876,7 +1646,7 @@ impl HttpMethodGuard {
     }
 }
 
-fn resolve_command_spec(registry: &Arc<Mutex<CommandRegistry>>, canonical_id: &str) -> Result<CommandSpec, ErrorData> {
+pub(super) fn resolve_command_spec(registry: &Arc<Mutex<CommandRegistry>>, canonical_id: &str) -> Result<CommandSpec, ErrorData> {
     let (group, name) = split_canonical_id(canonical_id)?;
     let registry_guard = registry.lock().map_err(|error| {
         internal_error_with_next_step(
@@ -987,7 +1757,11 @@ async fn list_registry_catalogs(registry: &Arc<Mutex<CommandRegistry>>, plugin_e
     Ok(response)
 }
 
-fn list_command_summaries_by_catalog(registry: &Arc<Mutex<CommandRegistry>>, catalog_title: &str) -> Result<Vec<Value>> {
+pub(super) fn list_command_summaries_by_catalog(
+    registry: &Arc<Mutex<CommandRegistry>>,
+    catalog_title: &str,
+    scope: &McpPolicyScope,
+) -> Result<Vec<Value>> {
     let registry_guard = registry.lock().map_err(|error| anyhow::anyhow!("registry lock failed: {error}"))?;
 
     let catalogs = registry_guard
@@ -1003,6 +1777,7 @@ fn list_command_summaries_by_catalog(registry: &Arc<Mutex<CommandRegistry>>, cat
         .commands
         .iter()
         .filter(|command| command.catalog_identifier == catalog_index)
+        .filter(|command| scope.allows_command(command, Some(catalog_title)))
         .map(|command| build_command_summary(command, OutputSchemaDetail::Paths, ProviderMetadataDetail::None))
         .collect();
     Ok(summaries)
@@ -1047,7 +1822,7 @@ fn search_results_with_inputs(
     Ok(Value::Array(enriched))
 }
 
-fn build_command_summary(
+pub(super) fn build_command_summary(
     command: &CommandSpec,
     output_schema_detail: OutputSchemaDetail,
     provider_metadata_detail: ProviderMetadataDetail,
@@ -1229,7 +2004,7 @@ fn provider_source(provider: Option<&oatty_types::ValueProvider>) -> Option<Stri
     provider.map(|oatty_types::ValueProvider::Command { command_id, .. }| command_id.clone())
 }
 
-fn prune_sparse_json(value: Value) -> Option<Value> {
+pub(super) fn prune_sparse_json(value: Value) -> Option<Value> {
     match value {
         Value::Null => None,
         Value::String(text) => {
@@ -1297,7 +2072,7 @@ fn find_direct_search_hit(registry: &CommandRegistry, query: &str, vendor: Optio
     Some(hit)
 }
 
-fn command_output_schema(command: &CommandSpec) -> Option<&oatty_types::SchemaProperty> {
+pub(super) fn command_output_schema(command: &CommandSpec) -> Option<&oatty_types::SchemaProperty> {
     command
         .http()
         .and_then(|http| http.output_schema.as_ref())
@@ -1736,6 +2511,49 @@ fn exec_outcome_to_value(outcome: ExecOutcome) -> Result<Value, ErrorData> {
     }
 }
 
+/// Returns the owning catalog title for an HTTP-backed command.
+pub(super) fn command_catalog_title<'a>(registry: &'a CommandRegistry, command: &CommandSpec) -> Option<&'a str> {
+    registry
+        .config
+        .catalogs
+        .as_ref()
+        .and_then(|catalogs| catalogs.get(command.catalog_identifier))
+        .map(|catalog| catalog.title.as_str())
+}
+
+/// Whether the policy scope allows a search result's command.
+fn search_result_allowed(registry: &CommandRegistry, scope: &McpPolicyScope, result: &SearchResult) -> bool {
+    let Some((group, name)) = parse_canonical_search_query(&result.canonical_id) else {
+        return false;
+    };
+    registry
+        .find_by_group_and_cmd_ref(&group, &name)
+        .is_ok_and(|command| scope.allows_command(command, command_catalog_title(registry, command)))
+}
+
+/// Parses inline workflow manifests (YAML or JSON) carried by a workflow tool request.
+fn requested_manifests(request: &Value) -> Vec<Value> {
+    ["manifest_content", "repaired_manifest_content"]
+        .iter()
+        .filter_map(|key| request.get(*key).and_then(Value::as_str))
+        .filter_map(|content| serde_yaml::from_str::<Value>(content).ok())
+        .collect()
+}
+
+/// Collects workflow identifiers named by a request, including inline manifest identifiers.
+fn requested_workflow_ids(request: &Value, manifests: &[Value]) -> Vec<String> {
+    ["workflow_id", "new_id"]
+        .iter()
+        .filter_map(|key| request.get(*key).and_then(Value::as_str))
+        .chain(
+            manifests
+                .iter()
+                .filter_map(|manifest| manifest.get("workflow").and_then(Value::as_str)),
+        )
+        .map(str::to_string)
+        .collect()
+}
+
 fn vendor_matches(registry: &CommandRegistry, result: &SearchResult, vendor_name: &str) -> bool {
     let canonical_id = result.canonical_id.as_str();
     let Some(catalogs) = registry.config.catalogs.as_ref() else {
@@ -1804,6 +2622,7 @@ mod tests {
             default_value: None,
             description: None,
             provider: None,
+            schema: None,
         }
     }
 
@@ -1996,6 +2815,60 @@ mod tests {
         assert_eq!(hit.http_method.as_deref(), Some("GET"));
     }
 
+    #[test]
+    fn search_results_are_filtered_by_policy_scope() {
+        let list = CommandSpec::new_http(
+            "apps".to_string(),
+            "apps:list".to_string(),
+            "List apps".to_string(),
+            Vec::new(),
+            Vec::new(),
+            HttpCommandSpec::new("GET", "/apps", None, None),
+            0,
+        );
+        let delete = CommandSpec::new_http(
+            "apps".to_string(),
+            "apps:delete".to_string(),
+            "Delete app".to_string(),
+            Vec::new(),
+            Vec::new(),
+            HttpCommandSpec::new("DELETE", "/apps/{app}", None, None),
+            0,
+        );
+        let mut registry = CommandRegistry::default().with_commands(vec![list, delete]);
+        registry.config = RegistryConfig::default();
+        let policy: McpPolicy = serde_yaml::from_str("defaults:\n  httpMethods: [GET]").expect("policy parses");
+        let scope = policy.scope_for_authenticated_client("agent");
+
+        let allowed: Vec<String> = ["apps apps:list", "apps apps:delete", "apps apps:missing"]
+            .into_iter()
+            .map(|canonical_id| SearchResult {
+                index: 0,
+                canonical_id: canonical_id.to_string(),
+                summary: String::new(),
+                execution_type: "http".to_string(),
+                http_method: None,
+            })
+            .filter(|result| search_result_allowed(&registry, &scope, result))
+            .map(|result| result.canonical_id)
+            .collect();
+        assert_eq!(allowed, vec!["apps apps:list".to_string()]);
+    }
+
+    #[test]
+    fn requested_workflow_ids_include_inline_manifest_identifiers() {
+        let request = serde_json::json!({
+            "workflow_id": "deploy",
+            "manifest_content": "workflow: cleanup\nsteps:\n  - id: remove\n    run: apps apps:delete\n",
+        });
+        let manifests = requested_manifests(&request);
+
+        assert_eq!(
+            requested_workflow_ids(&request, &manifests),
+            vec!["deploy".to_string(), "cleanup".to_string()]
+        );
+    }
+
     #[test]
     fn search_results_with_inputs_omits_index_field() {
         let command = CommandSpec::new_http(
@@ -2055,6 +2928,7 @@ mod tests {
                     command_id: "projects:list".to_string(),
                     binds: Vec::new(),
                 }),
+                schema: None,
             }],
             HttpCommandSpec::new("POST", "/apps", None, None),
             0,
@@ -2179,6 +3053,7 @@ mod tests {
                     command_id: "projects:list".to_string(),
                     binds: Vec::new(),
                 }),
+                schema: None,
             }],
             HttpCommandSpec::new("GET", "/apps", None, None),
             0,
@@ -2223,6 +3098,7 @@ mod tests {
                         command_id: "teams:list".to_string(),
                         binds: Vec::new(),
                     }),
+                    schema: None,
                 },
                 CommandFlag {
                     name: "region".to_string(),
@@ -2236,6 +3112,7 @@ mod tests {
                         command_id: "regions:list".to_string(),
                         binds: Vec::new(),
                     }),
+                    schema: None,
                 },
             ],
             HttpCommandSpec::new("POST", "/apps", None, None),
@@ -7,8 +7,13 @@ use std::sync::{
 };
 use std::time::Duration;
 
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
 use axum::Router;
+use axum::extract::{Request, State};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use http::StatusCode;
+use http::header::{AUTHORIZATION, WWW_AUTHENTICATE};
 use rmcp::transport::streamable_http_server::{StreamableHttpServerConfig, StreamableHttpService, session::local::LocalSessionManager};
 use serde_json::Value;
 use tokio::sync::mpsc::UnboundedSender;
@@ -16,10 +21,21 @@ use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
 use crate::PluginEngine;
+use crate::config::{McpHttpServerConfig, McpHttpTlsConfig, McpRateLimits};
+use crate::server::auth::{McpAuthenticatedClient, McpClientAuthenticator, bearer_token};
+use crate::server::confirmation::McpConfirmationRequest;
 use crate::server::core::{McpToolServices, OattyMcpCore};
+use crate::server::limits::{McpRateLimiter, McpUsageSnapshot};
+use crate::server::policy::McpPolicy;
 use oatty_registry::{CommandRegistry, create_search_handle};
+use rustls::ServerConfig;
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::{CertificateDer, PrivateKeyDer};
 use std::sync::Mutex;
 
+/// Time allowed for in-flight requests to finish when a TLS server stops.
+const TLS_SHUTDOWN_GRACE: Duration = Duration::from_secs(5);
+
 /// Log entry emitted by the local MCP HTTP server.
 #[derive(Debug, Clone)]
 pub struct McpHttpLogEntry {
@@ -42,6 +58,12 @@ pub struct McpHttpServer {
     bind_address: SocketAddr,
     log_sender: Option<UnboundedSender<McpHttpLogEntry>>,
     services: Arc<McpToolServices>,
+    policy: Option<Arc<McpPolicy>>,
+    confirmation_sender: Option<UnboundedSender<McpConfirmationRequest>>,
+    authenticator: Option<Arc<McpClientAuthenticator>>,
+    tls: Option<McpHttpTlsConfig>,
+    gateway: bool,
+    rate_limiter: McpRateLimiter,
 }
 
 impl McpHttpServer {
@@ -53,6 +75,12 @@ impl McpHttpServer {
             bind_address,
             log_sender: None,
             services,
+            policy: None,
+            confirmation_sender: None,
+            authenticator: None,
+            tls: None,
+            gateway: false,
+            rate_limiter: McpRateLimiter::default(),
         }
     }
 
@@ -62,8 +90,50 @@ impl McpHttpServer {
         self
     }
 
+    /// Enforce an access policy for every session served by this instance.
+    pub fn with_policy(mut self, policy: McpPolicy) -> Self {
+        self.policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Ask the hosting UI, rather than the MCP client, to confirm calls matched by policy rules.
+    pub fn with_confirmation_sender(mut self, confirmation_sender: UnboundedSender<McpConfirmationRequest>) -> Self {
+        self.confirmation_sender = Some(confirmation_sender);
+        self
+    }
+
+    /// Reject requests that do not carry a bearer token issued to a known client.
+    ///
+    /// Loopback servers accept unauthenticated requests while the config lists no clients, so the
+    /// first token created while the server runs switches authentication on.
+    pub fn with_client_authentication(mut self, authenticator: McpClientAuthenticator) -> Self {
+        self.authenticator = Some(Arc::new(authenticator));
+        self
+    }
+
+    /// Serve over HTTPS with the given certificate and key.
+    pub fn with_tls(mut self, tls: McpHttpTlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Re-export running plugins' tools, resources, and prompts under namespaced names.
+    pub fn with_gateway(mut self, gateway: bool) -> Self {
+        self.gateway = gateway;
+        self
+    }
+
+    /// Throttle executions per session and per catalog.
+    pub fn with_rate_limits(mut self, limits: McpRateLimits) -> Self {
+        self.rate_limiter = McpRateLimiter::new(limits);
+        self
+    }
+
     /// Start the server and return a handle for runtime inspection and shutdown.
     pub async fn start(self) -> Result<RunningMcpHttpServer> {
+        // Load the certificate before binding so a bad path fails fast with a clear error.
+        let tls_config = self.tls.as_ref().map(load_tls_config).transpose()?;
+
         let cancellation_token = CancellationToken::new();
         let session_manager = Arc::new(LocalSessionManager::default());
         let client_counter = Arc::new(AtomicUsize::new(0));
@@ -75,8 +145,18 @@ impl McpHttpServer {
 
         let log_sender = self.log_sender.clone();
         let services = Arc::clone(&self.services);
+        let policy = self.policy.clone();
+        let confirmation_sender = self.confirmation_sender.clone();
+        let gateway = self.gateway;
+        let rate_limiter = self.rate_limiter.clone();
         let service: StreamableHttpService<OattyMcpCore, LocalSessionManager> = StreamableHttpService::new(
-            move || Ok(OattyMcpCore::new(log_sender.clone(), Arc::clone(&services))),
+            move || {
+                Ok(OattyMcpCore::new(log_sender.clone(), Arc::clone(&services))
+                    .with_policy(policy.clone())
+                    .with_confirmation_sender(confirmation_sender.clone())
+                    .with_gateway(gateway)
+                    .with_rate_limiter(Some(&rate_limiter)))
+            },
             Arc::clone(&session_manager),
             StreamableHttpServerConfig {
                 stateful_mode: true,
@@ -86,27 +166,40 @@ impl McpHttpServer {
             },
         );
 
-        let router = Router::new().nest_service("/mcp", service);
+        let mut router = Router::new().nest_service("/mcp", service);
+        if let Some(authenticator) = self.authenticator.clone() {
+            let gate = ClientTokenGate {
+                authenticator,
+                open_without_clients: is_loopback(self.bind_address.ip()),
+            };
+            router = router.layer(middleware::from_fn_with_state(gate, require_client_token));
+        }
         let listener = tokio::net::TcpListener::bind(self.bind_address).await?;
         let bound_address = listener.local_addr()?;
 
-        let server_handle = tokio::spawn({
-            let shutdown = cancellation_token.child_token();
-            async move {
-                let _ = axum::serve(listener, router)
-                    .with_graceful_shutdown(async move {
-                        shutdown.cancelled().await;
-                    })
-                    .await;
-            }
-        });
+        let uses_tls = tls_config.is_some();
+        let server_handle = match tls_config {
+            Some(tls_config) => spawn_tls_server(listener, router, tls_config, cancellation_token.child_token())?,
+            None => tokio::spawn({
+                let shutdown = cancellation_token.child_token();
+                async move {
+                    let _ = axum::serve(listener, router)
+                        .with_graceful_shutdown(async move {
+                            shutdown.cancelled().await;
+                        })
+                        .await;
+                }
+            }),
+        };
 
         Ok(RunningMcpHttpServer {
             bind_address: bound_address,
+            uses_tls,
             cancellation_token,
             server_handle,
             monitor_handle,
             client_counter,
+            rate_limiter: self.rate_limiter,
         })
     }
 }
@@ -119,6 +212,8 @@ pub struct RunningMcpHttpServer {
     server_handle: JoinHandle<()>,
     monitor_handle: JoinHandle<()>,
     client_counter: Arc<AtomicUsize>,
+    rate_limiter: McpRateLimiter,
+    uses_tls: bool,
 }
 
 impl RunningMcpHttpServer {
@@ -127,11 +222,22 @@ impl RunningMcpHttpServer {
         self.bind_address
     }
 
+    /// Return the URL clients connect to.
+    pub fn endpoint_url(&self) -> String {
+        let scheme = if self.uses_tls { "https" } else { "http" };
+        format!("{scheme}://{}/mcp", self.bind_address)
+    }
+
     /// Return the most recently observed client count.
     pub fn connected_clients(&self) -> usize {
         self.client_counter.load(Ordering::Relaxed)
     }
 
+    /// Return execution usage across connected sessions.
+    pub fn usage(&self) -> McpUsageSnapshot {
+        self.rate_limiter.usage()
+    }
+
     /// Stop the server and wait for background tasks to finish.
     pub async fn stop(self) -> Result<()> {
         self.cancellation_token.cancel();
@@ -145,18 +251,97 @@ impl RunningMcpHttpServer {
     }
 }
 
-/// Resolve a safe local bind address for the MCP HTTP server.
-pub fn resolve_bind_address(bind_address: Option<&str>) -> Result<SocketAddr> {
-    let address = bind_address.unwrap_or("127.0.0.1:0");
+/// Resolve the bind address for the MCP HTTP server.
+///
+/// Loopback addresses are always allowed. Any other interface is refused unless the
+/// configuration enables TLS and issues client tokens.
+pub fn resolve_bind_address(config: &McpHttpServerConfig) -> Result<SocketAddr> {
+    let address = config.bind_address.as_deref().unwrap_or("127.0.0.1:0");
     let parsed: SocketAddr = address
         .parse()
         .map_err(|error| anyhow!("invalid MCP HTTP bind address '{address}': {error}"))?;
-    if !is_loopback(parsed.ip()) {
-        return Err(anyhow!("MCP HTTP server must bind to a loopback address"));
+    if !is_loopback(parsed.ip()) && !config.allows_remote_access() {
+        return Err(anyhow!(
+            "MCP HTTP server must bind to a loopback address unless `httpServer.tls` is set and at least one client token exists"
+        ));
     }
     Ok(parsed)
 }
 
+/// Middleware state for [`require_client_token`].
+#[derive(Clone)]
+struct ClientTokenGate {
+    authenticator: Arc<McpClientAuthenticator>,
+    /// Let requests through while no client is configured (loopback binds only).
+    open_without_clients: bool,
+}
+
+/// Rejects requests without a valid bearer token and tags accepted ones with the client name.
+async fn require_client_token(State(gate): State<ClientTokenGate>, mut request: Request, next: Next) -> Response {
+    let authenticator = &gate.authenticator;
+    if gate.open_without_clients && !authenticator.has_clients() {
+        return next.run(request).await;
+    }
+    let client = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(bearer_token)
+        .and_then(|token| authenticator.authenticate(token));
+    match client {
+        Some(client) => {
+            request.extensions_mut().insert(McpAuthenticatedClient(client));
+            next.run(request).await
+        }
+        None => {
+            tracing::warn!(path = %request.uri().path(), "rejected MCP HTTP request without a valid client token");
+            (
+                StatusCode::UNAUTHORIZED,
+                [(WWW_AUTHENTICATE, "Bearer realm=\"oatty\"")],
+                "missing or invalid bearer token",
+            )
+                .into_response()
+        }
+    }
+}
+
+fn load_tls_config(tls: &McpHttpTlsConfig) -> Result<Arc<ServerConfig>> {
+    let certificates = CertificateDer::pem_file_iter(&tls.cert_file)
+        .and_then(|certificates| certificates.collect::<Result<Vec<_>, _>>())
+        .with_context(|| format!("failed to read TLS certificate {}", tls.cert_file.display()))?;
+    let private_key = PrivateKeyDer::from_pem_file(&tls.key_file)
+        .with_context(|| format!("failed to read TLS private key {}", tls.key_file.display()))?;
+
+    // Both rustls providers are compiled into the binary, so pick one explicitly.
+    let mut config = ServerConfig::builder_with_provider(Arc::new(rustls::crypto::aws_lc_rs::default_provider()))
+        .with_safe_default_protocol_versions()?
+        .with_no_client_auth()
+        .with_single_cert(certificates, private_key)
+        .context("TLS certificate and private key do not match")?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(Arc::new(config))
+}
+
+fn spawn_tls_server(
+    listener: tokio::net::TcpListener,
+    router: Router,
+    tls_config: Arc<ServerConfig>,
+    shutdown: CancellationToken,
+) -> Result<JoinHandle<()>> {
+    let handle = axum_server::Handle::new();
+    let server =
+        axum_server::tls_rustls::from_tcp_rustls(listener.into_std()?, axum_server::tls_rustls::RustlsConfig::from_config(tls_config))?
+            .handle(handle.clone());
+
+    Ok(tokio::spawn(async move {
+        tokio::spawn(async move {
+            shutdown.cancelled().await;
+            handle.graceful_shutdown(Some(TLS_SHUTDOWN_GRACE));
+        });
+        let _ = server.serve(router.into_make_service()).await;
+    }))
+}
+
 fn is_loopback(address: IpAddr) -> bool {
     match address {
         IpAddr::V4(ip) => ip.is_loopback(),
@@ -182,3 +367,112 @@ fn spawn_session_monitor(
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::McpHttpClientToken;
+    use chrono::Utc;
+
+    fn config(bind_address: &str) -> McpHttpServerConfig {
+        McpHttpServerConfig {
+            bind_address: Some(bind_address.to_string()),
+            ..McpHttpServerConfig::default()
+        }
+    }
+
+    #[test]
+    fn loopback_addresses_are_always_allowed() {
+        assert!(resolve_bind_address(&config("127.0.0.1:0")).is_ok());
+        assert!(resolve_bind_address(&config("[::1]:62889")).is_ok());
+    }
+
+    #[test]
+    fn lan_addresses_need_tls_and_client_tokens() {
+        let mut lan = config("0.0.0.0:62889");
+        assert!(resolve_bind_address(&lan).is_err());
+
+        lan.clients.push(McpHttpClientToken {
+            name: "dev-vm".to_string(),
+            created_at: Utc::now(),
+        });
+        assert!(resolve_bind_address(&lan).is_err());
+
+        lan.tls = Some(McpHttpTlsConfig {
+            cert_file: "cert.pem".into(),
+            key_file: "key.pem".into(),
+        });
+        assert_eq!(resolve_bind_address(&lan).unwrap().to_string(), "0.0.0.0:62889");
+    }
+
+    #[tokio::test]
+    async fn requests_without_a_known_token_are_rejected() {
+        let registry = Arc::new(Mutex::new(CommandRegistry::default()));
+        let plugin_engine = Arc::new(PluginEngine::new(crate::McpConfig::default(), Arc::clone(&registry)).unwrap());
+        let server = McpHttpServer::new("127.0.0.1:0".parse().unwrap(), registry, plugin_engine)
+            .with_client_authentication(McpClientAuthenticator::with_tokens(&[("dev-vm", "oatty_mcp_secret")]))
+            .start()
+            .await
+            .unwrap();
+        let client = reqwest::Client::new();
+        let initialize = |token: Option<&str>| {
+            let mut request = client
+                .post(server.endpoint_url())
+                .header("accept", "application/json, text/event-stream")
+                .json(&serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "method": "initialize",
+                    "params": {
+                        "protocolVersion": "2025-06-18",
+                        "capabilities": {},
+                        "clientInfo": { "name": "test", "version": "1.0" }
+                    }
+                }));
+            if let Some(token) = token {
+                request = request.bearer_auth(token);
+            }
+            request.send()
+        };
+
+        assert_eq!(initialize(None).await.unwrap().status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            initialize(Some("oatty_mcp_wrong")).await.unwrap().status(),
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(initialize(Some("oatty_mcp_secret")).await.unwrap().status(), StatusCode::OK);
+
+        server.stop().await.unwrap();
+
+        let registry = Arc::new(Mutex::new(CommandRegistry::default()));
+        let plugin_engine = Arc::new(PluginEngine::new(crate::McpConfig::default(), Arc::clone(&registry)).unwrap());
+        let open_server = McpHttpServer::new("127.0.0.1:0".parse().unwrap(), registry, plugin_engine)
+            .with_client_authentication(McpClientAuthenticator::with_tokens(&[]))
+            .start()
+            .await
+            .unwrap();
+        let response = client
+            .post(open_server.endpoint_url())
+            .header("accept", "application/json, text/event-stream")
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": {
+                    "protocolVersion": "2025-06-18",
+                    "capabilities": {},
+                    "clientInfo": { "name": "test", "version": "1.0" }
+                }
+            }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(
+            response.status(),
+            StatusCode::OK,
+            "loopback servers stay open until a client is configured"
+        );
+
+        open_server.stop().await.unwrap();
+    }
+}
@@ -0,0 +1,366 @@
+//! Execution rate limits and quotas for the MCP HTTP server.
+//!
+//! Each session gets a [`SessionLimiter`] from the server-wide [`McpRateLimiter`]. An execution
+//! asks for an [`ExecutionPermit`] before it starts; the permit counts the call against the
+//! session's window and every matching catalog window, and releases its concurrency slot when
+//! dropped. Denials carry a retry-after hint when waiting would help.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
+
+use rmcp::model::ErrorData;
+
+use crate::config::{McpRateLimitRule, McpRateLimits};
+use crate::server::policy::glob_matches;
+use crate::server::workflow::errors::rate_limited_error;
+
+/// Width of the rolling window used for `callsPerMinute`.
+const CALL_WINDOW: Duration = Duration::from_secs(60);
+/// Retry hint for concurrency denials, which clear as soon as an in-flight call finishes.
+const CONCURRENCY_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Aggregate usage across sessions, shown next to the connected client count.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct McpUsageSnapshot {
+    /// Executions started within the last minute.
+    pub calls_last_minute: usize,
+    /// Executions currently running.
+    pub in_flight: u32,
+    /// Write or destructive executions since the server started.
+    pub write_calls: u32,
+    /// Executions refused by a limit since the server started.
+    pub throttled: u64,
+}
+
+#[derive(Debug, Default)]
+struct UsageWindow {
+    calls: VecDeque<Instant>,
+    in_flight: u32,
+    write_calls: u32,
+}
+
+impl UsageWindow {
+    fn prune(&mut self, now: Instant) {
+        while self
+            .calls
+            .front()
+            .is_some_and(|started| now.duration_since(*started) >= CALL_WINDOW)
+        {
+            self.calls.pop_front();
+        }
+    }
+
+    /// The first limit in `rule` this call would exceed.
+    fn check(&self, rule: &McpRateLimitRule, is_write: bool, now: Instant) -> Option<LimitExceeded> {
+        if let Some(limit) = rule.max_concurrent
+            && self.in_flight >= limit
+        {
+            return Some(LimitExceeded::new("max_concurrent", limit, Some(CONCURRENCY_RETRY_AFTER)));
+        }
+        if let Some(limit) = rule.calls_per_minute
+            && self.calls.len() >= limit as usize
+        {
+            let oldest = self.calls.front().copied().unwrap_or(now);
+            let retry_after = CALL_WINDOW.saturating_sub(now.duration_since(oldest));
+            return Some(LimitExceeded::new("calls_per_minute", limit, Some(retry_after)));
+        }
+        if is_write
+            && let Some(limit) = rule.max_write_calls
+            && self.write_calls >= limit
+        {
+            return Some(LimitExceeded::new("max_write_calls", limit, None));
+        }
+        None
+    }
+
+    fn admit(&mut self, is_write: bool, now: Instant) {
+        self.calls.push_back(now);
+        self.in_flight += 1;
+        if is_write {
+            self.write_calls += 1;
+        }
+    }
+}
+
+/// A limit an execution would exceed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LimitExceeded {
+    limit: &'static str,
+    value: u32,
+    retry_after: Option<Duration>,
+}
+
+impl LimitExceeded {
+    fn new(limit: &'static str, value: u32, retry_after: Option<Duration>) -> Self {
+        Self { limit, value, retry_after }
+    }
+
+    fn into_error(self, scope: &str, catalog: Option<&str>) -> ErrorData {
+        let retry_after_seconds = self.retry_after.map(|duration| duration.as_secs_f64().ceil().max(1.0) as u64);
+        let suggested_action = match (self.limit, retry_after_seconds) {
+            ("max_write_calls", _) => "The write quota is used up; ask the operator to raise the limit or start a new session.".to_string(),
+            (_, Some(seconds)) => format!("Wait {seconds}s before retrying; avoid tight retry loops."),
+            (_, None) => "Retry later.".to_string(),
+        };
+        rate_limited_error(
+            format!("{scope} limit {} ({}) reached", self.limit, self.value),
+            serde_json::json!({ "scope": scope, "catalog": catalog, "limit": self.limit, "limit_value": self.value }),
+            retry_after_seconds,
+            &suggested_action,
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct LimiterState {
+    sessions: HashMap<u64, UsageWindow>,
+    catalogs: HashMap<String, UsageWindow>,
+    next_session: u64,
+    /// Write calls of sessions that have ended, so the snapshot keeps counting them.
+    retired_write_calls: u32,
+    throttled: u64,
+}
+
+/// Server-wide limiter shared by every session of one server instance.
+#[derive(Debug, Clone, Default)]
+pub struct McpRateLimiter {
+    limits: Arc<McpRateLimits>,
+    state: Arc<Mutex<LimiterState>>,
+}
+
+impl McpRateLimiter {
+    pub fn new(limits: McpRateLimits) -> Self {
+        Self {
+            limits: Arc::new(limits),
+            state: Arc::default(),
+        }
+    }
+
+    /// Register a new session.
+    pub(crate) fn session(&self) -> SessionLimiter {
+        let id = self
+            .state
+            .lock()
+            .map(|mut state| {
+                state.next_session += 1;
+                state.next_session
+            })
+            .unwrap_or_default();
+        SessionLimiter {
+            handle: Arc::new(SessionHandle { id, limiter: self.clone() }),
+        }
+    }
+
+    /// Current usage summed over live sessions.
+    pub fn usage(&self) -> McpUsageSnapshot {
+        let Ok(mut state) = self.state.lock() else {
+            return McpUsageSnapshot::default();
+        };
+        let now = Instant::now();
+        let mut snapshot = McpUsageSnapshot {
+            write_calls: state.retired_write_calls,
+            throttled: state.throttled,
+            ..McpUsageSnapshot::default()
+        };
+        for window in state.sessions.values_mut() {
+            window.prune(now);
+            snapshot.calls_last_minute += window.calls.len();
+            snapshot.in_flight += window.in_flight;
+            snapshot.write_calls += window.write_calls;
+        }
+        snapshot
+    }
+
+    fn catalog_rules<'a>(&'a self, catalog: Option<&'a str>) -> impl Iterator<Item = (&'a String, &'a McpRateLimitRule)> + 'a {
+        self.limits
+            .catalogs
+            .iter()
+            .filter(move |(pattern, _)| catalog.is_some_and(|catalog| glob_matches(pattern, catalog)))
+    }
+
+    fn acquire(&self, session: u64, catalog: Option<&str>, is_write: bool) -> Result<ExecutionPermit, ErrorData> {
+        let mut state = self.state.lock().map_err(|error| {
+            rate_limited_error(
+                format!("rate limiter unavailable: {error}"),
+                serde_json::json!({}),
+                None,
+                "Retry later.",
+            )
+        })?;
+        let now = Instant::now();
+        let state = &mut *state;
+
+        let session_window = state.sessions.entry(session).or_default();
+        session_window.prune(now);
+        if let Some(exceeded) = session_window.check(&self.limits.session, is_write, now) {
+            state.throttled += 1;
+            return Err(exceeded.into_error("session", catalog));
+        }
+        let mut catalog_keys = Vec::new();
+        for (pattern, rule) in self.catalog_rules(catalog) {
+            let window = state.catalogs.entry(pattern.clone()).or_default();
+            window.prune(now);
+            if let Some(exceeded) = window.check(rule, is_write, now) {
+                state.throttled += 1;
+                return Err(exceeded.into_error(&format!("catalog '{pattern}'"), catalog));
+            }
+            catalog_keys.push(pattern.clone());
+        }
+
+        state.sessions.entry(session).or_default().admit(is_write, now);
+        for key in &catalog_keys {
+            state.catalogs.entry(key.clone()).or_default().admit(is_write, now);
+        }
+        Ok(ExecutionPermit {
+            state: Arc::downgrade(&self.state),
+            session,
+            catalog_keys,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct SessionHandle {
+    id: u64,
+    limiter: McpRateLimiter,
+}
+
+impl Drop for SessionHandle {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.limiter.state.lock()
+            && let Some(window) = state.sessions.remove(&self.id)
+        {
+            state.retired_write_calls += window.write_calls;
+        }
+    }
+}
+
+/// A session's view of the limiter; the session's usage is dropped with its last clone.
+#[derive(Debug, Clone)]
+pub(crate) struct SessionLimiter {
+    handle: Arc<SessionHandle>,
+}
+
+impl SessionLimiter {
+    /// Admit an execution or return a structured `RATE_LIMITED` error.
+    pub fn acquire(&self, catalog: Option<&str>, is_write: bool) -> Result<ExecutionPermit, ErrorData> {
+        self.handle.limiter.acquire(self.handle.id, catalog, is_write)
+    }
+}
+
+/// Admission for one execution; releases its concurrency slots when dropped.
+#[derive(Debug)]
+pub(crate) struct ExecutionPermit {
+    state: Weak<Mutex<LimiterState>>,
+    session: u64,
+    catalog_keys: Vec<String>,
+}
+
+impl Drop for ExecutionPermit {
+    fn drop(&mut self) {
+        let Some(state) = self.state.upgrade() else {
+            return;
+        };
+        let Ok(mut state) = state.lock() else {
+            return;
+        };
+        if let Some(window) = state.sessions.get_mut(&self.session) {
+            window.in_flight = window.in_flight.saturating_sub(1);
+        }
+        for key in &self.catalog_keys {
+            if let Some(window) = state.catalogs.get_mut(key) {
+                window.in_flight = window.in_flight.saturating_sub(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use super::*;
+
+    fn retry_after(error: &ErrorData) -> Option<u64> {
+        error.data.as_ref()?.get("retry_after_seconds")?.as_u64()
+    }
+
+    #[test]
+    fn session_limits_concurrency_and_releases_on_drop() {
+        let limiter = McpRateLimiter::new(McpRateLimits {
+            session: McpRateLimitRule {
+                max_concurrent: Some(1),
+                ..McpRateLimitRule::default()
+            },
+            ..McpRateLimits::default()
+        });
+        let session = limiter.session();
+
+        let permit = session.acquire(None, false).expect("first call admitted");
+        let error = session.acquire(None, false).expect_err("second concurrent call refused");
+        assert_eq!(retry_after(&error), Some(1));
+        assert_eq!(limiter.usage().in_flight, 1);
+
+        drop(permit);
+        assert!(session.acquire(None, false).is_ok());
+        assert_eq!(limiter.usage().throttled, 1);
+    }
+
+    #[test]
+    fn calls_per_minute_reports_retry_after() {
+        let limiter = McpRateLimiter::new(McpRateLimits {
+            session: McpRateLimitRule {
+                calls_per_minute: Some(2),
+                ..McpRateLimitRule::default()
+            },
+            ..McpRateLimits::default()
+        });
+        let session = limiter.session();
+        drop(session.acquire(None, false));
+        drop(session.acquire(None, false));
+
+        let error = session.acquire(None, false).expect_err("third call in a minute refused");
+        let data = error.data.as_ref().expect("structured error data");
+        assert_eq!(data["error_code"], "RATE_LIMITED");
+        assert_eq!(data["context"]["limit"], "calls_per_minute");
+        assert!(retry_after(&error).is_some_and(|seconds| (1..=60).contains(&seconds)));
+        assert_eq!(limiter.usage().calls_last_minute, 2);
+    }
+
+    #[test]
+    fn write_quota_is_per_session_and_catalog_limits_are_shared() {
+        let mut catalogs = IndexMap::new();
+        catalogs.insert(
+            "prod-*".to_string(),
+            McpRateLimitRule {
+                calls_per_minute: Some(2),
+                ..McpRateLimitRule::default()
+            },
+        );
+        let limiter = McpRateLimiter::new(McpRateLimits {
+            session: McpRateLimitRule {
+                max_write_calls: Some(1),
+                ..McpRateLimitRule::default()
+            },
+            catalogs,
+        });
+        let first = limiter.session();
+        let second = limiter.session();
+
+        drop(first.acquire(Some("staging"), true));
+        let error = first.acquire(Some("staging"), true).expect_err("write quota used up");
+        assert_eq!(retry_after(&error), None);
+        assert!(first.acquire(Some("staging"), false).is_ok());
+
+        drop(first.acquire(Some("prod-api"), false));
+        drop(second.acquire(Some("prod-api"), false));
+        let error = second
+            .acquire(Some("prod-api"), false)
+            .expect_err("catalog window shared across sessions");
+        assert_eq!(error.data.as_ref().unwrap()["context"]["scope"], "catalog 'prod-*'");
+
+        drop(first);
+        assert_eq!(limiter.usage().write_calls, 1);
+    }
+}
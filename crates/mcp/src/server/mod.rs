@@ -1,9 +1,27 @@
+mod auth;
 mod catalog;
+mod command_resources;
+mod confirmation;
 mod core;
+mod gateway;
 mod http;
+mod limits;
 mod log_payload;
+mod policy;
 mod schemas;
+mod subscriptions;
 mod workflow;
 
+pub use auth::{
+    McpAuthenticatedClient, McpClientAuthenticator, create_client_token, list_client_tokens, load_http_server_config, revoke_client_token,
+};
+pub use confirmation::{McpConfirmationRequest, McpPendingToolCall};
 pub use core::OattyMcpCore;
 pub use http::{McpHttpLogEntry, McpHttpServer, RunningMcpHttpServer, resolve_bind_address};
+pub use limits::{McpRateLimiter, McpUsageSnapshot};
+pub use policy::{McpConfirmationRule, McpPolicy, McpPolicyRule, McpPolicyScope};
+pub use workflow::services::history::{WorkflowHistoryEntry, append_history_entry, latest_history_entry};
+pub use workflow::services::storage::{
+    WorkflowManifestFormat, find_manifest_record, remove_manifest, serialize_definition, write_manifest,
+};
+pub use workflow::tools::{types::WorkflowValidateRequest, validate_workflow};
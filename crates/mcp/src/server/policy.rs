@@ -0,0 +1,735 @@
+//! Access policy for the MCP HTTP server.
+//!
+//! A policy file is referenced from `httpServer.policyFile` and scopes what a connected client may
+//! do: which catalogs and canonical command IDs it can see and run, which HTTP methods it may
+//! execute, which workflows it may touch, and whether catalog-mutation tools are available.
+//! Each MCP session resolves its scope once during `initialize`. Sessions authenticated by a
+//! client token overlay the first `clients` entry matching the token's name on top of
+//! `defaults`; sessions without a token always get `defaults`, since `clientInfo.name` is
+//! self-reported and could otherwise be spoofed to pick up a broader rule.
+//!
+//! ```yaml
+//! readOnly: false
+//! defaults:
+//!   catalogs: ["*"]
+//!   httpMethods: [GET]
+//!   catalogMutations: false
+//! clients:
+//!   "claude-*":
+//!     commands: ["apps *", "addons addons:list"]
+//!     httpMethods: [GET, POST]
+//!     workflows: ["deploy-*"]
+//! requireConfirmation:
+//!   - httpMethods: [DELETE]
+//!   - tools: [catalog_remove]
+//! confirmationTimeoutSecs: 60
+//! ```
+//!
+//! `requireConfirmation` rules apply to every client: a matching call pauses until a human
+//! approves it, and is denied if nobody answers within `confirmationTimeoutSecs` (see
+//! [`crate::server::confirmation`]).
+
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use indexmap::IndexMap;
+use oatty_registry::CommandRegistry;
+use oatty_types::{CommandSpec, McpCommandSpec, workflow::RuntimeWorkflow};
+use rmcp::model::ErrorData;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::server::confirmation::{DEFAULT_CONFIRMATION_TIMEOUT, McpPendingToolCall};
+use crate::server::core::command_catalog_title;
+use crate::server::workflow::errors::policy_denied_error;
+
+/// Tools that mutate catalog configuration.
+const CATALOG_MUTATION_TOOLS: &[&str] = &[
+    "catalog_import_openapi",
+    "catalog_apply_patch",
+    "catalog_set_enabled",
+    "catalog_set_base_url",
+    "catalog_edit_headers",
+    "catalog_remove",
+];
+
+/// Tools that write files, execute non-GET requests, or otherwise change state.
+const MUTATING_TOOLS: &[&str] = &[
+    "run_command",
+    "run_destructive_command",
+    "workflow_save",
+    "workflow_export",
+    "workflow_import",
+    "workflow_rename",
+    "workflow_delete",
+    "workflow_run",
+    "workflow_cancel",
+    "workflow_purge_history",
+    "workflow_author_and_run",
+    "workflow_repair_and_rerun",
+];
+
+/// Policy document loaded from the file referenced by `httpServer.policyFile`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct McpPolicy {
+    /// Deny every mutating tool for all clients, regardless of per-client rules.
+    #[serde(default)]
+    pub read_only: bool,
+    /// Rule applied to every client before any client-specific overrides.
+    #[serde(default)]
+    pub defaults: McpPolicyRule,
+    /// Client-specific rules keyed by a glob over the client name bound to the session's bearer
+    /// token. Sessions without a token always use `defaults`; the self-reported MCP
+    /// `clientInfo.name` never selects a rule.
+    #[serde(default)]
+    pub clients: IndexMap<String, McpPolicyRule>,
+    /// Calls that must be approved by a human before they run.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub require_confirmation: Vec<McpConfirmationRule>,
+    /// Seconds a confirmation waits for an answer before the call is denied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirmation_timeout_secs: Option<u64>,
+}
+
+/// Matches tool calls that require operator confirmation.
+///
+/// Every populated field must match; a field the call does not carry (for example an HTTP method
+/// on `catalog_remove`) never matches.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct McpConfirmationRule {
+    /// Tool name globs (for example `run_destructive_command`, `catalog_*`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<String>>,
+    /// HTTP methods (case-insensitive).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_methods: Option<Vec<String>>,
+    /// Catalog title globs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catalogs: Option<Vec<String>>,
+    /// Canonical command ID globs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commands: Option<Vec<String>>,
+}
+
+impl McpConfirmationRule {
+    /// Whether this rule matches the pending call.
+    pub fn matches(&self, call: &McpPendingToolCall) -> bool {
+        let field_matches = |patterns: &Option<Vec<String>>, value: Option<&str>| match (patterns, value) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(patterns), Some(value)) => patterns.iter().any(|pattern| glob_matches(pattern, value)),
+        };
+        let method_matches = match (&self.http_methods, call.http_method.as_deref()) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(methods), Some(method)) => methods.iter().any(|candidate| candidate.trim().eq_ignore_ascii_case(method)),
+        };
+        method_matches
+            && field_matches(&self.tools, Some(call.tool.as_str()))
+            && field_matches(&self.catalogs, call.catalog.as_deref())
+            && field_matches(&self.commands, call.canonical_id.as_deref())
+    }
+}
+
+/// A set of allowlists. Omitted fields inherit from `defaults`; omitted defaults allow everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct McpPolicyRule {
+    /// Deny mutating tools for matching clients.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Catalog title globs (MCP plugin commands match on the plugin name).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catalogs: Option<Vec<String>>,
+    /// Canonical command ID globs (`<group> <command>`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commands: Option<Vec<String>>,
+    /// HTTP methods that may be executed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_methods: Option<Vec<String>>,
+    /// Workflow identifier globs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflows: Option<Vec<String>>,
+    /// Whether catalog-mutation tools (import, patch, enable, headers, remove) are available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catalog_mutations: Option<bool>,
+}
+
+impl McpPolicyRule {
+    fn overlay(&self, overrides: &McpPolicyRule) -> McpPolicyRule {
+        McpPolicyRule {
+            read_only: overrides.read_only.or(self.read_only),
+            catalogs: overrides.catalogs.clone().or_else(|| self.catalogs.clone()),
+            commands: overrides.commands.clone().or_else(|| self.commands.clone()),
+            http_methods: overrides.http_methods.clone().or_else(|| self.http_methods.clone()),
+            workflows: overrides.workflows.clone().or_else(|| self.workflows.clone()),
+            catalog_mutations: overrides.catalog_mutations.or(self.catalog_mutations),
+        }
+    }
+}
+
+impl McpPolicy {
+    /// Load a policy from a YAML or JSON file (selected by extension, YAML otherwise).
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).with_context(|| format!("failed to read MCP policy file '{}'", path.display()))?;
+        let is_json = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+        let policy = if is_json {
+            serde_json::from_str(&content).with_context(|| format!("invalid MCP policy file '{}'", path.display()))?
+        } else {
+            serde_yaml::from_str(&content).with_context(|| format!("invalid MCP policy file '{}'", path.display()))?
+        };
+        Ok(policy)
+    }
+
+    /// Resolve the effective scope for a client authenticated by a bearer token bound to `client_name`.
+    ///
+    /// Only token-bound names select `clients` rules, because a self-reported name can be spoofed.
+    pub fn scope_for_authenticated_client(&self, client_name: &str) -> McpPolicyScope {
+        let matched = self.clients.iter().find(|(pattern, _)| glob_matches(pattern, client_name));
+        self.scope_with_rule(Some(client_name), matched)
+    }
+
+    /// Resolve the scope for a session without a bearer token: always `defaults`.
+    ///
+    /// The self-reported `clientInfo.name` is kept for display and audit purposes only.
+    pub fn scope_for_unauthenticated_client(&self, reported_name: Option<&str>) -> McpPolicyScope {
+        self.scope_with_rule(reported_name, None)
+    }
+
+    fn scope_with_rule(&self, client_name: Option<&str>, matched: Option<(&String, &McpPolicyRule)>) -> McpPolicyScope {
+        let rule = match matched {
+            Some((_, overrides)) => self.defaults.overlay(overrides),
+            None => self.defaults.clone(),
+        };
+        McpPolicyScope {
+            client_name: client_name.map(str::to_string),
+            matched_client_rule: matched.map(|(pattern, _)| pattern.clone()),
+            read_only: self.read_only || rule.read_only.unwrap_or(false),
+            catalog_mutations: rule.catalog_mutations.unwrap_or(true),
+            http_methods: rule
+                .http_methods
+                .map(|methods| methods.iter().map(|method| method.trim().to_ascii_uppercase()).collect()),
+            catalogs: rule.catalogs,
+            commands: rule.commands,
+            workflows: rule.workflows,
+            confirmation_rules: self.require_confirmation.clone(),
+            confirmation_timeout: self
+                .confirmation_timeout_secs
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_CONFIRMATION_TIMEOUT),
+        }
+    }
+}
+
+/// Effective policy for a single MCP session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpPolicyScope {
+    client_name: Option<String>,
+    matched_client_rule: Option<String>,
+    read_only: bool,
+    catalog_mutations: bool,
+    catalogs: Option<Vec<String>>,
+    commands: Option<Vec<String>>,
+    http_methods: Option<Vec<String>>,
+    workflows: Option<Vec<String>>,
+    confirmation_rules: Vec<McpConfirmationRule>,
+    confirmation_timeout: Duration,
+}
+
+impl Default for McpPolicyScope {
+    fn default() -> Self {
+        Self {
+            client_name: None,
+            matched_client_rule: None,
+            read_only: false,
+            catalog_mutations: false,
+            catalogs: None,
+            commands: None,
+            http_methods: None,
+            workflows: None,
+            confirmation_rules: Vec::new(),
+            confirmation_timeout: DEFAULT_CONFIRMATION_TIMEOUT,
+        }
+    }
+}
+
+impl McpPolicyScope {
+    /// Scope used when no policy file is configured.
+    pub fn unrestricted() -> Self {
+        Self {
+            catalog_mutations: true,
+            ..Self::default()
+        }
+    }
+
+    /// Whether mutating tools are denied for this session.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Whether a pending call must be approved by a human before it runs.
+    pub fn requires_confirmation(&self, call: &McpPendingToolCall) -> bool {
+        self.confirmation_rules.iter().any(|rule| rule.matches(call))
+    }
+
+    /// How long a confirmation waits for an answer before the call is denied.
+    pub fn confirmation_timeout(&self) -> Duration {
+        self.confirmation_timeout
+    }
+
+    /// The token-bound or self-reported client name this scope was resolved for.
+    pub fn client_name(&self) -> Option<&str> {
+        self.client_name.as_deref()
+    }
+
+    /// Deny tools that the read-only mode or the catalog-mutation switch disables.
+    pub fn check_tool(&self, tool_name: &str) -> Result<(), ErrorData> {
+        let is_catalog_mutation = CATALOG_MUTATION_TOOLS.contains(&tool_name);
+        if self.read_only && (is_catalog_mutation || MUTATING_TOOLS.contains(&tool_name)) {
+            return Err(self.denial(
+                format!("tool '{tool_name}' is not allowed in read-only mode"),
+                serde_json::json!({ "tool": tool_name, "read_only": true }),
+                "This MCP session is read-only. Use discovery tools and run_safe_command, or ask the user to relax `readOnly` in the MCP policy file.",
+            ));
+        }
+        if is_catalog_mutation && !self.catalog_mutations {
+            return Err(self.denial(
+                format!("catalog mutation tool '{tool_name}' is disabled by policy"),
+                serde_json::json!({ "tool": tool_name, "catalog_mutations": false }),
+                "Ask the user to make the catalog change in Oatty directly, or to set `catalogMutations: true` for this client in the MCP policy file.",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether a catalog title is visible to this session.
+    pub fn allows_catalog(&self, catalog_title: &str) -> bool {
+        matches_any(self.catalogs.as_deref(), catalog_title)
+    }
+
+    /// Deny catalogs outside the allowlist.
+    pub fn check_catalog(&self, catalog_title: &str) -> Result<(), ErrorData> {
+        if self.allows_catalog(catalog_title) {
+            return Ok(());
+        }
+        Err(self.denial(
+            format!("catalog '{catalog_title}' is not allowed by policy"),
+            serde_json::json!({ "catalog": catalog_title, "allowed_catalogs": self.catalogs }),
+            "Use list_command_topics to see the catalogs this session may use.",
+        ))
+    }
+
+    /// Whether a workflow identifier is allowed for this session.
+    pub fn allows_workflow(&self, workflow_id: &str) -> bool {
+        matches_any(self.workflows.as_deref(), workflow_id)
+    }
+
+    /// Deny workflow identifiers outside the allowlist.
+    pub fn check_workflow(&self, workflow_id: &str) -> Result<(), ErrorData> {
+        if self.allows_workflow(workflow_id) {
+            return Ok(());
+        }
+        Err(self.denial(
+            format!("workflow '{workflow_id}' is not allowed by policy"),
+            serde_json::json!({ "workflow_id": workflow_id, "allowed_workflows": self.workflows }),
+            "Use workflow_list to see the workflows this session may use.",
+        ))
+    }
+
+    /// Whether a command is visible and executable for this session.
+    ///
+    /// `catalog_title` is the owning catalog title for HTTP commands; MCP plugin commands are
+    /// matched against their plugin name.
+    pub fn allows_command(&self, command: &CommandSpec, catalog_title: Option<&str>) -> bool {
+        self.check_command(command, catalog_title).is_ok()
+    }
+
+    /// Deny commands whose catalog, canonical ID, or HTTP method falls outside the allowlists.
+    pub fn check_command(&self, command: &CommandSpec, catalog_title: Option<&str>) -> Result<(), ErrorData> {
+        let canonical_id = command.canonical_id();
+        let catalog = command
+            .mcp()
+            .map(|mcp| mcp.plugin_name.as_str())
+            .or(catalog_title)
+            .unwrap_or_default();
+        if self.catalogs.is_some() && !self.allows_catalog(catalog) {
+            return Err(self.denial(
+                format!("command '{canonical_id}' belongs to catalog '{catalog}', which is not allowed by policy"),
+                serde_json::json!({ "canonical_id": canonical_id, "catalog": catalog, "allowed_catalogs": self.catalogs }),
+                "Use search_commands to find an allowed command; results are already filtered by policy.",
+            ));
+        }
+        if !matches_any(self.commands.as_deref(), &canonical_id) {
+            return Err(self.denial(
+                format!("command '{canonical_id}' is not allowed by policy"),
+                serde_json::json!({ "canonical_id": canonical_id, "allowed_commands": self.commands }),
+                "Use search_commands to find an allowed command; results are already filtered by policy.",
+            ));
+        }
+        if let Some(http) = command.http() {
+            let method = http.method.to_ascii_uppercase();
+            let method_allowed = self.http_methods.as_ref().is_none_or(|methods| methods.contains(&method));
+            if !method_allowed {
+                return Err(self.denial(
+                    format!("HTTP method '{method}' is not allowed by policy for '{canonical_id}'"),
+                    serde_json::json!({ "canonical_id": canonical_id, "http_method": method, "allowed_http_methods": self.http_methods }),
+                    "Choose a command whose http_method is allowed for this session.",
+                ));
+            }
+            if self.read_only && method != "GET" {
+                return Err(self.denial(
+                    format!("HTTP method '{method}' is not allowed in read-only mode"),
+                    serde_json::json!({ "canonical_id": canonical_id, "http_method": method, "read_only": true }),
+                    "This MCP session is read-only. Only GET commands may be executed.",
+                ));
+            }
+        } else if self.read_only && !command.mcp().is_some_and(McpCommandSpec::is_read_only) {
+            return Err(self.denial(
+                format!("plugin command '{canonical_id}' is not marked read-only and this session is read-only"),
+                serde_json::json!({ "canonical_id": canonical_id, "catalog": catalog, "read_only": true }),
+                "This MCP session is read-only. Use plugin tools annotated with readOnlyHint, or ask the user to relax `readOnly` in the MCP policy file.",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Deny a workflow run when any of its resolved steps runs a command outside this scope.
+    ///
+    /// `workflow` must be the definition that will actually execute (loaded from disk or the
+    /// request), not a registry copy. Steps whose `run` does not resolve to a loaded command are
+    /// denied, since the command they would reach cannot be checked.
+    pub fn check_workflow_steps(&self, workflow: &RuntimeWorkflow, registry: &CommandRegistry) -> Result<(), ErrorData> {
+        for step in &workflow.steps {
+            let command = step
+                .run
+                .trim()
+                .split_once(' ')
+                .and_then(|(group, name)| registry.find_by_group_and_cmd_ref(group.trim(), name.trim()).ok());
+            let Some(command) = command else {
+                return Err(self.denial(
+                    format!(
+                        "workflow step '{}' runs '{}', which does not resolve to a loaded command",
+                        step.id, step.run
+                    ),
+                    serde_json::json!({ "workflow_id": workflow.identifier, "step_id": step.id, "run": step.run }),
+                    "Fix the step's run identifier using search_commands, then retry the run.",
+                ));
+            };
+            self.check_command(command, command_catalog_title(registry, command))?;
+        }
+        Ok(())
+    }
+
+    /// Deny a plugin tool re-exported in gateway mode.
+    ///
+    /// The plugin name is matched against the catalog allowlist. `command` is the command
+    /// synthesized for the tool, when the registry has one; without it a command allowlist cannot
+    /// be evaluated, so the tool is denied rather than let through. Read-only sessions only see
+    /// tools annotated with `readOnlyHint`.
+    pub fn check_gateway_tool(
+        &self,
+        plugin_name: &str,
+        tool_name: &str,
+        read_only_hint: bool,
+        command: Option<&CommandSpec>,
+    ) -> Result<(), ErrorData> {
+        if self.read_only && !read_only_hint {
+            return Err(self.denial(
+                format!("plugin tool '{tool_name}' is not marked read-only and this session is read-only"),
+                serde_json::json!({ "tool": tool_name, "plugin": plugin_name, "read_only": true }),
+                "This MCP session is read-only. Use plugin tools annotated with readOnlyHint, or ask the user to relax `readOnly` in the MCP policy file.",
+            ));
+        }
+        match command {
+            Some(command) => self.check_command(command, Some(plugin_name)),
+            None if self.commands.is_some() => Err(self.denial(
+                format!("plugin tool '{tool_name}' has no registered command to match against the command allowlist"),
+                serde_json::json!({ "tool": tool_name, "plugin": plugin_name, "allowed_commands": self.commands }),
+                "Wait for the plugin's tools to finish loading, then list tools again.",
+            )),
+            None => self.check_catalog(plugin_name),
+        }
+    }
+
+    fn denial(&self, message: String, mut context: Value, suggested_action: &str) -> ErrorData {
+        if let Some(object) = context.as_object_mut() {
+            object.insert("client".to_string(), serde_json::json!(self.client_name));
+            object.insert("policy_rule".to_string(), serde_json::json!(self.matched_client_rule));
+        }
+        policy_denied_error(message, context, suggested_action)
+    }
+}
+
+fn matches_any(patterns: Option<&[String]>, value: &str) -> bool {
+    patterns.is_none_or(|patterns| patterns.iter().any(|pattern| glob_matches(pattern, value)))
+}
+
+/// Match `value` against a glob where `*` matches any run of characters and `?` a single character.
+pub(crate) fn glob_matches(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+    let (mut pattern_index, mut value_index) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while value_index < value.len() {
+        match pattern.get(pattern_index) {
+            Some('*') => {
+                backtrack = Some((pattern_index, value_index));
+                pattern_index += 1;
+            }
+            Some(&character) if character == '?' || character == value[value_index] => {
+                pattern_index += 1;
+                value_index += 1;
+            }
+            _ => match backtrack {
+                Some((star_index, star_value_index)) => {
+                    pattern_index = star_index + 1;
+                    value_index = star_value_index + 1;
+                    backtrack = Some((star_index, star_value_index + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[pattern_index..].iter().all(|character| *character == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oatty_types::workflow::WorkflowStepDefinition;
+    use oatty_types::{HttpCommandSpec, McpCommandKind, McpCommandSpec};
+
+    fn http_command(group: &str, name: &str, method: &str) -> CommandSpec {
+        CommandSpec::new_http(
+            group.to_string(),
+            name.to_string(),
+            String::new(),
+            Vec::new(),
+            Vec::new(),
+            HttpCommandSpec::new(method, "/apps", None, None),
+            0,
+        )
+    }
+
+    fn policy(yaml: &str) -> McpPolicy {
+        serde_yaml::from_str(yaml).expect("policy parses")
+    }
+
+    #[test]
+    fn glob_matches_wildcards() {
+        assert!(glob_matches("*", "anything"));
+        assert!(glob_matches("apps *", "apps apps:list"));
+        assert!(glob_matches("apps apps:?ist", "apps apps:list"));
+        assert!(glob_matches("claude-*-desktop", "claude-ai-desktop"));
+        assert!(!glob_matches("apps *", "addons addons:list"));
+        assert!(!glob_matches("deploy", "deploy-prod"));
+    }
+
+    #[test]
+    fn client_rules_overlay_defaults_field_by_field() {
+        let policy = policy(
+            r#"
+defaults:
+  httpMethods: [get]
+  catalogMutations: false
+clients:
+  "claude-*":
+    commands: ["apps *"]
+"#,
+        );
+        let scope = policy.scope_for_authenticated_client("claude-code");
+
+        assert!(scope.allows_command(&http_command("apps", "apps:list", "GET"), Some("Heroku")));
+        assert!(!scope.allows_command(&http_command("apps", "apps:create", "POST"), Some("Heroku")));
+        assert!(!scope.allows_command(&http_command("addons", "addons:list", "GET"), Some("Heroku")));
+        assert!(scope.check_tool("catalog_remove").is_err());
+
+        let other = policy.scope_for_authenticated_client("cursor");
+        assert!(other.allows_command(&http_command("addons", "addons:list", "GET"), Some("Heroku")));
+    }
+
+    #[test]
+    fn unauthenticated_sessions_ignore_client_rules_even_with_matching_names() {
+        let policy = policy(
+            r#"
+defaults:
+  httpMethods: [GET]
+clients:
+  "claude-*":
+    httpMethods: [GET, DELETE]
+"#,
+        );
+        let spoofed = policy.scope_for_unauthenticated_client(Some("claude-code"));
+
+        assert_eq!(spoofed.client_name(), Some("claude-code"));
+        assert!(!spoofed.allows_command(&http_command("apps", "apps:delete", "DELETE"), Some("Heroku")));
+        assert_eq!(spoofed.matched_client_rule, None);
+        assert!(
+            policy
+                .scope_for_authenticated_client("claude-code")
+                .allows_command(&http_command("apps", "apps:delete", "DELETE"), Some("Heroku"))
+        );
+    }
+
+    #[test]
+    fn read_only_denies_mutating_tools_and_write_methods() {
+        let scope = policy("readOnly: true").scope_for_unauthenticated_client(None);
+
+        assert!(scope.check_tool("search_commands").is_ok());
+        assert!(scope.check_tool("run_safe_command").is_ok());
+        assert!(scope.check_tool("workflow_save").is_err());
+        assert!(scope.check_tool("catalog_set_enabled").is_err());
+        assert!(!scope.allows_command(&http_command("apps", "apps:delete", "DELETE"), None));
+    }
+
+    #[test]
+    fn read_only_denies_plugin_commands_without_a_read_only_hint() {
+        let scope = policy("readOnly: true").scope_for_unauthenticated_client(None);
+        let plugin_command = |tool_name: &str, kind: McpCommandKind, read_only_hint: bool| {
+            CommandSpec::new_mcp(
+                "github".to_string(),
+                tool_name.to_string(),
+                String::new(),
+                Vec::new(),
+                Vec::new(),
+                McpCommandSpec {
+                    plugin_name: "github".to_string(),
+                    tool_name: tool_name.to_string(),
+                    kind,
+                    read_only_hint,
+                    ..McpCommandSpec::default()
+                },
+            )
+        };
+
+        assert!(!scope.allows_command(&plugin_command("create_issue", McpCommandKind::Tool, false), None));
+        assert!(scope.allows_command(&plugin_command("list_issues", McpCommandKind::Tool, true), None));
+        assert!(scope.allows_command(&plugin_command("triage", McpCommandKind::Prompt, false), None));
+    }
+
+    #[test]
+    fn catalog_allowlist_matches_plugin_name_for_mcp_commands() {
+        let scope = policy("defaults:\n  catalogs: [github]").scope_for_unauthenticated_client(None);
+        let mcp_command = CommandSpec::new_mcp(
+            "github".to_string(),
+            "issues:list".to_string(),
+            String::new(),
+            Vec::new(),
+            Vec::new(),
+            McpCommandSpec {
+                plugin_name: "github".to_string(),
+                tool_name: "list_issues".to_string(),
+                kind: McpCommandKind::Tool,
+                auth_summary: None,
+                output_schema: None,
+                render_hint: None,
+                read_only_hint: false,
+            },
+        );
+
+        assert!(scope.allows_command(&mcp_command, None));
+        assert!(!scope.allows_command(&http_command("apps", "apps:list", "GET"), Some("Heroku")));
+    }
+
+    #[test]
+    fn gateway_tools_respect_read_only_hints_and_command_allowlists() {
+        let scope = policy("readOnly: true\ndefaults:\n  catalogs: [github]").scope_for_unauthenticated_client(None);
+        assert!(scope.check_gateway_tool("github", "github__list_issues", true, None).is_ok());
+        assert!(scope.check_gateway_tool("github", "github__create_issue", false, None).is_err());
+        assert!(scope.check_gateway_tool("linear", "linear__list_issues", true, None).is_err());
+
+        let scope = policy("defaults:\n  commands: [\"github *\"]").scope_for_unauthenticated_client(None);
+        assert!(scope.check_gateway_tool("github", "github__list_issues", false, None).is_err());
+    }
+
+    #[test]
+    fn confirmation_rules_require_every_populated_field_to_match() {
+        let scope = policy(
+            r#"
+requireConfirmation:
+  - httpMethods: [delete]
+  - tools: ["catalog_*"]
+    catalogs: [Heroku]
+"#,
+        )
+        .scope_for_unauthenticated_client(None);
+        let call = |tool: &str, method: Option<&str>, catalog: Option<&str>| McpPendingToolCall {
+            tool: tool.to_string(),
+            http_method: method.map(str::to_string),
+            catalog: catalog.map(str::to_string),
+            ..McpPendingToolCall::default()
+        };
+
+        assert!(scope.requires_confirmation(&call("run_destructive_command", Some("DELETE"), Some("Heroku"))));
+        assert!(!scope.requires_confirmation(&call("run_command", Some("POST"), Some("Heroku"))));
+        assert!(scope.requires_confirmation(&call("catalog_remove", None, Some("Heroku"))));
+        assert!(!scope.requires_confirmation(&call("catalog_remove", None, Some("Vercel"))));
+        assert!(!scope.requires_confirmation(&call("catalog_remove", None, None)));
+        assert_eq!(scope.confirmation_timeout(), DEFAULT_CONFIRMATION_TIMEOUT);
+
+        let scope = policy("confirmationTimeoutSecs: 15").scope_for_unauthenticated_client(None);
+        assert_eq!(scope.confirmation_timeout(), Duration::from_secs(15));
+    }
+
+    #[test]
+    fn workflow_steps_are_checked_against_the_resolved_definition() {
+        let scope = policy("defaults:\n  httpMethods: [GET]").scope_for_unauthenticated_client(None);
+        let registry = CommandRegistry::default().with_commands(vec![
+            http_command("apps", "apps:list", "GET"),
+            http_command("apps", "apps:delete", "DELETE"),
+        ]);
+        let workflow = |runs: &[&str]| RuntimeWorkflow {
+            identifier: "cleanup".into(),
+            title: None,
+            description: None,
+            inputs: IndexMap::new(),
+            steps: runs
+                .iter()
+                .enumerate()
+                .map(|(index, run)| WorkflowStepDefinition {
+                    id: format!("step_{index}"),
+                    run: run.to_string(),
+                    description: None,
+                    depends_on: Vec::new(),
+                    r#if: None,
+                    with: IndexMap::new(),
+                    body: Value::Null,
+                    repeat: None,
+                    output_contract: None,
+                })
+                .collect(),
+            final_output: None,
+            requires: None,
+            schedule: None,
+        };
+
+        assert!(scope.check_workflow_steps(&workflow(&["apps apps:list"]), &registry).is_ok());
+        let denied = scope
+            .check_workflow_steps(&workflow(&["apps apps:list", "apps apps:delete"]), &registry)
+            .expect_err("DELETE step denied");
+        assert!(denied.message.contains("DELETE"));
+        let unresolved = scope
+            .check_workflow_steps(&workflow(&["apps apps:purge"]), &registry)
+            .expect_err("unresolvable step denied");
+        assert_eq!(unresolved.data.expect("error data")["context"]["step_id"], "step_0");
+    }
+
+    #[test]
+    fn denials_carry_policy_error_code_and_hint() {
+        let scope = policy("defaults:\n  workflows: [\"deploy-*\"]").scope_for_authenticated_client("agent");
+        let error = scope.check_workflow("cleanup").expect_err("workflow denied");
+        let data = error.data.expect("error data");
+
+        assert_eq!(data["error_code"], "POLICY_DENIED");
+        assert_eq!(data["context"]["client"], "agent");
+        assert!(data["suggested_action"].as_str().is_some_and(|hint| hint.contains("workflow_list")));
+        assert!(scope.check_workflow("deploy-web").is_ok());
+    }
+}
@@ -0,0 +1,116 @@
+//! Per-session `resources/subscribe` bookkeeping and `resources/updated` delivery.
+//!
+//! Catalog and command resources are refreshed from [`CommandRegistryEvent`]s by a listener that
+//! starts with the session's first subscription; workflow run resources are refreshed by the run
+//! relay as steps finish.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+
+use oatty_registry::CommandRegistry;
+use percent_encoding::percent_decode_str;
+use rmcp::model::ResourceUpdatedNotificationParam;
+use rmcp::{Peer, RoleServer};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::debug;
+
+use crate::server::command_resources::registry_event_resource_uris;
+
+#[derive(Debug, Default)]
+struct SubscriptionState {
+    /// Subscribed URIs keyed by their percent-decoded form, mapped to the URI as the client sent it.
+    uris: HashMap<String, String>,
+    registry_listener_started: bool,
+}
+
+/// Resource URIs a session subscribed to.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ResourceSubscriptions {
+    state: Arc<Mutex<SubscriptionState>>,
+}
+
+impl ResourceSubscriptions {
+    /// Record a subscription. Returns `true` when the registry listener still has to be started.
+    pub fn subscribe(&self, uri: &str) -> bool {
+        let Ok(mut state) = self.state.lock() else {
+            return false;
+        };
+        state.uris.insert(normalize_uri(uri), uri.to_string());
+        !std::mem::replace(&mut state.registry_listener_started, true)
+    }
+
+    pub fn unsubscribe(&self, uri: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state.uris.remove(&normalize_uri(uri));
+        }
+    }
+
+    /// The URI the client subscribed with, when `uri` is subscribed.
+    pub fn subscribed_uri(&self, uri: &str) -> Option<String> {
+        let state = self.state.lock().ok()?;
+        state.uris.get(&normalize_uri(uri)).cloned()
+    }
+
+    /// Send `resources/updated` for `uri` when subscribed. Returns `false` once the peer is gone.
+    pub async fn notify(&self, peer: &Peer<RoleServer>, uri: &str) -> bool {
+        let Some(uri) = self.subscribed_uri(uri) else {
+            return true;
+        };
+        peer.notify_resource_updated(ResourceUpdatedNotificationParam { uri }).await.is_ok()
+    }
+
+    /// Forward registry changes to the session until it ends.
+    pub fn spawn_registry_listener(&self, registry: Arc<Mutex<CommandRegistry>>, peer: Peer<RoleServer>) {
+        let Ok(mut receiver) = registry.lock().map(|mut registry| registry.subscribe()) else {
+            return;
+        };
+        let state: Weak<Mutex<SubscriptionState>> = Arc::downgrade(&self.state);
+        tokio::spawn(async move {
+            loop {
+                let event = match receiver.recv().await {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(skipped)) => {
+                        debug!(skipped, "resource subscription listener lagged behind registry events");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+                let Some(state) = state.upgrade() else { break };
+                let subscriptions = ResourceSubscriptions { state };
+                let uris = match registry.lock() {
+                    Ok(registry) => registry_event_resource_uris(&event, &registry),
+                    Err(_) => continue,
+                };
+                for uri in uris {
+                    if !subscriptions.notify(&peer, &uri).await {
+                        return;
+                    }
+                }
+            }
+        });
+    }
+}
+
+fn normalize_uri(uri: &str) -> String {
+    percent_decode_str(uri).decode_utf8_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscriptions_match_encoded_and_decoded_uris() {
+        let subscriptions = ResourceSubscriptions::default();
+        assert!(subscriptions.subscribe("oatty://command/apps apps:list"));
+        assert!(!subscriptions.subscribe("oatty://catalog/Heroku"));
+
+        assert_eq!(
+            subscriptions.subscribed_uri("oatty://command/apps%20apps:list").as_deref(),
+            Some("oatty://command/apps apps:list")
+        );
+        subscriptions.unsubscribe("oatty://command/apps%20apps:list");
+        assert!(subscriptions.subscribed_uri("oatty://command/apps apps:list").is_none());
+        assert!(subscriptions.subscribed_uri("oatty://catalog/Heroku").is_some());
+    }
+}
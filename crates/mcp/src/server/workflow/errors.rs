@@ -35,6 +35,14 @@ fn derive_failure_stage(category: &str, error_code: &str) -> &'static str {
         return "validation";
     }
 
+    if category == "policy" {
+        return "authorization";
+    }
+
+    if category == "rate_limit" {
+        return "rate_limit";
+    }
+
     if category == "execution" && error_code.contains("PROVIDER") {
         return "provider_http";
     }
@@ -96,6 +104,60 @@ pub fn conflict_error(error_code: &str, message: impl Into<String>, context: Val
     )
 }
 
+pub fn policy_denied_error(message: impl Into<String>, context: Value, suggested_action: &str) -> ErrorData {
+    let message = message.into();
+    ErrorData::invalid_request(
+        message.clone(),
+        Some(build_error_data(
+            "POLICY_DENIED",
+            "policy",
+            &message,
+            context,
+            false,
+            suggested_action,
+            None,
+        )),
+    )
+}
+
+pub fn rate_limited_error(
+    message: impl Into<String>,
+    context: Value,
+    retry_after_seconds: Option<u64>,
+    suggested_action: &str,
+) -> ErrorData {
+    let message = message.into();
+    let mut data = build_error_data(
+        "RATE_LIMITED",
+        "rate_limit",
+        &message,
+        context,
+        retry_after_seconds.is_some(),
+        suggested_action,
+        None,
+    );
+    if let Some(seconds) = retry_after_seconds {
+        data["retry_after_seconds"] = Value::from(seconds);
+    }
+    ErrorData::invalid_request(message, Some(data))
+}
+
+pub fn operator_denied_error(message: impl Into<String>, context: Value, suggested_action: &str) -> ErrorData {
+    let message = message.into();
+    ErrorData::invalid_request(
+        message.clone(),
+        Some(build_error_data(
+            "OPERATOR_DENIED",
+            "policy",
+            &message,
+            context,
+            false,
+            suggested_action,
+            None,
+        )),
+    )
+}
+
 pub fn not_found_error(error_code: &str, message: impl Into<String>, context: Value, suggested_action: &str) -> ErrorData {
     let message = message.into();
     ErrorData::resource_not_found(
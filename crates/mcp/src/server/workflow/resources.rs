@@ -1,7 +1,12 @@
-//! Workflow MCP resources for specs, schema, and manifest/catalog snapshots.
+//! Workflow MCP resources for specs, schema, manifest/catalog snapshots, and run status.
 
+use crate::server::core::command_catalog_title;
+use crate::server::policy::McpPolicyScope;
 use crate::server::workflow::errors::{internal_error, invalid_params_error, not_found_error};
+use crate::server::workflow::services::history::find_history_entry;
+use crate::server::workflow::services::runs::WorkflowRunRegistry;
 use crate::server::workflow::services::storage::list_manifest_records;
+use crate::server::workflow::tools::{run_status, types::WorkflowRunStatusRequest};
 use oatty_registry::CommandRegistry;
 use rmcp::model::{
     AnnotateAble, ListResourceTemplatesResult, ListResourcesResult, RawResource, RawResourceTemplate, ReadResourceResult, ResourceContents,
@@ -16,6 +21,7 @@ const WORKFLOW_MANIFESTS_URI: &str = "oatty://workflow/manifests";
 const WORKFLOW_PROVIDER_CATALOG_URI: &str = "oatty://workflow/provider-catalog";
 const WORKFLOW_COMMAND_CATALOG_URI: &str = "oatty://workflow/command-catalog";
 const WORKFLOW_MANIFEST_URI_PREFIX: &str = "oatty://workflow/manifest/";
+const WORKFLOW_RUN_URI_PREFIX: &str = "oatty://workflow/run/";
 const EMBEDDED_WORKFLOW_SPEC: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../specs/WORKFLOWS.md"));
 const EMBEDDED_WORKFLOW_TUI_SPEC: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../specs/WORKFLOW_TUI.md"));
 const EMBEDDED_WORKFLOW_SCHEMA: &str = include_str!(concat!(env!("OUT_DIR"), "/workflow_definition.schema.json"));
@@ -76,12 +82,97 @@ pub fn list_resource_templates() -> ListResourceTemplatesResult {
             icons: None,
         }
         .no_annotation(),
+        RawResourceTemplate {
+            uri_template: format!("{WORKFLOW_RUN_URI_PREFIX}{{run_id}}"),
+            name: "workflow.run".to_string(),
+            title: Some("Workflow run by id".to_string()),
+            description: Some(
+                "Live status and step results for runs started in this session, or the recorded history entry for earlier runs. Subscribe to receive resources/updated as steps finish.".to_string(),
+            ),
+            mime_type: Some("application/json".to_string()),
+            icons: None,
+        }
+        .no_annotation(),
     ];
     ListResourceTemplatesResult::with_all_items(templates)
 }
 
+/// Resource URI for a workflow run.
+pub fn workflow_run_uri(run_id: &str) -> String {
+    format!("{WORKFLOW_RUN_URI_PREFIX}{run_id}")
+}
+
+/// Extract the run identifier from a workflow run resource URI.
+pub fn workflow_run_id(uri: &str) -> Option<&str> {
+    uri.strip_prefix(WORKFLOW_RUN_URI_PREFIX)
+}
+
+/// Read a workflow run: the live snapshot when the run is tracked in this session, otherwise its
+/// most recent history entry.
+pub fn read_run_resource(
+    uri: &str,
+    runs: &WorkflowRunRegistry,
+    scope: &McpPolicyScope,
+) -> Result<ReadResourceResult, rmcp::model::ErrorData> {
+    let run_id = workflow_run_id(uri).map(str::trim).unwrap_or_default();
+    if run_id.is_empty() {
+        return Err(invalid_params_error(
+            "WORKFLOW_RESOURCE_IDENTIFIER_MISSING",
+            "workflow run URI is missing run identifier",
+            serde_json::json!({ "uri": uri }),
+            "Use oatty://workflow/run/{run_id}.",
+        ));
+    }
+
+    let payload = if runs.snapshot(run_id).is_some() {
+        let request = WorkflowRunStatusRequest {
+            run_id: Some(run_id.to_string()),
+            operation_id: None,
+            include_results: Some(true),
+        };
+        let mut payload = run_status(&request, runs)?;
+        payload["source"] = Value::from("session");
+        payload
+    } else {
+        let entry = find_history_entry(run_id).map_err(|error| {
+            internal_error(
+                "WORKFLOW_RESOURCE_READ_FAILED",
+                error.to_string(),
+                serde_json::json!({ "uri": uri, "run_id": run_id }),
+                "Inspect the workflow history directory and retry.",
+            )
+        })?;
+        let Some(entry) = entry else {
+            return Err(not_found_error(
+                "WORKFLOW_RUN_NOT_FOUND",
+                format!("workflow run '{run_id}' was not found"),
+                serde_json::json!({ "uri": uri, "run_id": run_id }),
+                "Use the run_id returned by workflow_run; history may have been purged.",
+            ));
+        };
+        let mut payload = serde_json::to_value(&entry).unwrap_or_default();
+        payload["source"] = Value::from("history");
+        payload
+    };
+
+    let workflow_id = payload["workflow_id"].as_str().unwrap_or_default().to_string();
+    scope.check_workflow(&workflow_id)?;
+    Ok(text_resource(
+        uri,
+        "application/json",
+        serde_json::to_string_pretty(&payload).unwrap_or_else(|_| "{}".to_string()),
+    ))
+}
+
 /// Read a workflow resource URI and return text content.
-pub fn read_resource(uri: &str, command_registry: &Arc<Mutex<CommandRegistry>>) -> Result<ReadResourceResult, rmcp::model::ErrorData> {
+///
+/// Manifests and catalog snapshots only include workflows and commands the session's policy scope
+/// allows.
+pub fn read_resource(
+    uri: &str,
+    command_registry: &Arc<Mutex<CommandRegistry>>,
+    scope: &McpPolicyScope,
+) -> Result<ReadResourceResult, rmcp::model::ErrorData> {
     match uri {
         WORKFLOW_SPEC_URI => Ok(text_resource(uri, "text/markdown", EMBEDDED_WORKFLOW_SPEC.to_string())),
         WORKFLOW_TUI_SPEC_URI => Ok(text_resource(uri, "text/markdown", EMBEDDED_WORKFLOW_TUI_SPEC.to_string())),
@@ -97,6 +188,7 @@ pub fn read_resource(uri: &str, command_registry: &Arc<Mutex<CommandRegistry>>)
             })?;
             let payload = manifests
                 .into_iter()
+                .filter(|record| scope.allows_workflow(&record.definition.workflow))
                 .map(|record| {
                     serde_json::json!({
                         "workflow_id": record.definition.workflow,
@@ -115,7 +207,7 @@ pub fn read_resource(uri: &str, command_registry: &Arc<Mutex<CommandRegistry>>)
             ))
         }
         WORKFLOW_PROVIDER_CATALOG_URI => {
-            let payload = provider_catalog_resource(command_registry)?;
+            let payload = provider_catalog_resource(command_registry, scope)?;
             Ok(text_resource(
                 uri,
                 "application/json",
@@ -123,7 +215,7 @@ pub fn read_resource(uri: &str, command_registry: &Arc<Mutex<CommandRegistry>>)
             ))
         }
         WORKFLOW_COMMAND_CATALOG_URI => {
-            let payload = command_catalog_resource(command_registry)?;
+            let payload = command_catalog_resource(command_registry, scope)?;
             Ok(text_resource(
                 uri,
                 "application/json",
@@ -140,6 +232,7 @@ pub fn read_resource(uri: &str, command_registry: &Arc<Mutex<CommandRegistry>>)
                     "Use oatty://workflow/manifest/{workflow_id}.",
                 ));
             }
+            scope.check_workflow(workflow_identifier)?;
             let maybe_record = crate::server::workflow::services::storage::find_manifest_record(workflow_identifier).map_err(|error| {
                 internal_error(
                     "WORKFLOW_RESOURCE_READ_FAILED",
@@ -156,6 +249,7 @@ pub fn read_resource(uri: &str, command_registry: &Arc<Mutex<CommandRegistry>>)
                     "Use workflow_list to inspect available workflow identifiers.",
                 ));
             };
+            scope.check_workflow(&record.definition.workflow)?;
 
             let payload = serde_json::json!({
                 "workflow_id": record.definition.workflow,
@@ -205,7 +299,10 @@ fn text_resource(uri: &str, mime_type: &str, text: String) -> ReadResourceResult
     }
 }
 
-fn command_catalog_resource(command_registry: &Arc<Mutex<CommandRegistry>>) -> Result<Value, rmcp::model::ErrorData> {
+fn command_catalog_resource(
+    command_registry: &Arc<Mutex<CommandRegistry>>,
+    scope: &McpPolicyScope,
+) -> Result<Value, rmcp::model::ErrorData> {
     let registry = command_registry.lock().map_err(|error| {
         internal_error(
             "WORKFLOW_COMMAND_CATALOG_LOCK_FAILED",
@@ -218,6 +315,7 @@ fn command_catalog_resource(command_registry: &Arc<Mutex<CommandRegistry>>) -> R
     let payload = registry
         .commands
         .iter()
+        .filter(|command| scope.allows_command(command, command_catalog_title(&registry, command)))
         .map(|command| {
             serde_json::json!({
                 "canonical_id": command.canonical_id(),
@@ -233,7 +331,10 @@ fn command_catalog_resource(command_registry: &Arc<Mutex<CommandRegistry>>) -> R
     Ok(Value::Array(payload))
 }
 
-fn provider_catalog_resource(command_registry: &Arc<Mutex<CommandRegistry>>) -> Result<Value, rmcp::model::ErrorData> {
+fn provider_catalog_resource(
+    command_registry: &Arc<Mutex<CommandRegistry>>,
+    scope: &McpPolicyScope,
+) -> Result<Value, rmcp::model::ErrorData> {
     let registry = command_registry.lock().map_err(|error| {
         internal_error(
             "WORKFLOW_PROVIDER_CATALOG_LOCK_FAILED",
@@ -246,6 +347,7 @@ fn provider_catalog_resource(command_registry: &Arc<Mutex<CommandRegistry>>) ->
     let providers = registry
         .commands
         .iter()
+        .filter(|command| scope.allows_command(command, command_catalog_title(&registry, command)))
         .map(|command| {
             let positional = command
                 .positional_args
@@ -287,3 +389,42 @@ fn execution_type(command_spec: &oatty_types::CommandSpec) -> &'static str {
     }
     "unknown"
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::policy::McpPolicy;
+    use oatty_types::{CommandSpec, HttpCommandSpec};
+
+    #[test]
+    fn command_catalog_only_lists_commands_the_scope_allows() {
+        let command = |name: &str, method: &str| {
+            CommandSpec::new_http(
+                "apps".to_string(),
+                name.to_string(),
+                String::new(),
+                Vec::new(),
+                Vec::new(),
+                HttpCommandSpec::new(method, "/apps", None, None),
+                0,
+            )
+        };
+        let registry = Arc::new(Mutex::new(
+            CommandRegistry::default().with_commands(vec![command("apps:list", "GET"), command("apps:delete", "DELETE")]),
+        ));
+        let policy: McpPolicy = serde_yaml::from_str("defaults:\n  httpMethods: [GET]").unwrap();
+        let scope = policy.scope_for_unauthenticated_client(None);
+
+        let commands = command_catalog_resource(&registry, &scope).unwrap();
+        let canonical_ids: Vec<&str> = commands
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|command| command["canonical_id"].as_str())
+            .collect();
+        assert_eq!(canonical_ids, vec!["apps apps:list"]);
+
+        let providers = provider_catalog_resource(&registry, &scope).unwrap();
+        assert_eq!(providers["providers"].as_array().map(Vec::len), Some(1));
+    }
+}
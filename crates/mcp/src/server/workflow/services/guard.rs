@@ -0,0 +1,294 @@
+//! Session restrictions applied to workflow runs.
+//!
+//! Workflow tools resolve the manifest they execute from disk or from the request, so checks
+//! made against the tool request alone cannot see every step. A [`WorkflowRunGuard`] is handed
+//! to the workflow tools instead: it checks the resolved [`RuntimeWorkflow`] right before it runs,
+//! asks the operator to approve steps matched by a `requireConfirmation` rule, and admits each
+//! step under the session's rate limits as it executes.
+
+use std::sync::Arc;
+use std::thread;
+
+use anyhow::{Result, anyhow};
+use futures_util::future::BoxFuture;
+use oatty_engine::{CommandRunner, RunContext};
+use oatty_registry::CommandRegistry;
+use oatty_types::workflow::{RuntimeWorkflow, WorkflowStepDefinition};
+use oatty_util::redact_json;
+use rmcp::model::ErrorData;
+use serde_json::Value;
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+use crate::server::confirmation::McpPendingToolCall;
+use crate::server::core::command_catalog_title;
+use crate::server::limits::SessionLimiter;
+use crate::server::policy::McpPolicyScope;
+use crate::server::workflow::errors::{internal_error, policy_denied_error};
+
+/// Asks the operator to approve a call matched by a `requireConfirmation` rule.
+pub(crate) type StepConfirmer = Arc<dyn Fn(McpPendingToolCall) -> BoxFuture<'static, Result<(), ErrorData>> + Send + Sync>;
+
+/// Restrictions from the calling MCP session that every workflow step must satisfy.
+#[derive(Clone)]
+pub struct WorkflowRunGuard {
+    scope: McpPolicyScope,
+    rate_limiter: Option<SessionLimiter>,
+    tool_name: String,
+    confirmer: Option<StepConfirmer>,
+}
+
+impl WorkflowRunGuard {
+    /// Guard runs with the session's resolved policy scope.
+    pub fn new(scope: McpPolicyScope) -> Self {
+        Self {
+            scope,
+            rate_limiter: None,
+            tool_name: String::new(),
+            confirmer: None,
+        }
+    }
+
+    /// Ask the operator, on behalf of `tool_name`, to approve steps that require confirmation.
+    ///
+    /// Without a confirmer those steps are denied.
+    pub(crate) fn with_confirmer(mut self, tool_name: &str, confirmer: StepConfirmer) -> Self {
+        self.tool_name = tool_name.to_string();
+        self.confirmer = Some(confirmer);
+        self
+    }
+
+    /// Admit every step under the session's rate limits.
+    pub(crate) fn with_rate_limiter(mut self, rate_limiter: Option<SessionLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Checks every step of the workflow that is about to execute against the session scope.
+    ///
+    /// Steps matched by a `requireConfirmation` rule are confirmed one at a time once every step
+    /// has passed the policy checks, so nothing runs until the operator approved all of them.
+    pub fn authorize_workflow(&self, workflow: &RuntimeWorkflow, registry: &CommandRegistry) -> Result<(), ErrorData> {
+        self.scope.check_workflow(&workflow.identifier)?;
+        self.scope.check_workflow_steps(workflow, registry)?;
+        for step in &workflow.steps {
+            let call = self.step_call(step, registry);
+            if !self.scope.requires_confirmation(&call) {
+                continue;
+            }
+            let Some(confirmer) = self.confirmer.as_ref() else {
+                return Err(policy_denied_error(
+                    format!("workflow step '{}' requires operator confirmation", step.id),
+                    serde_json::json!({ "workflow_id": workflow.identifier, "step_id": step.id, "run": step.run }),
+                    "Ask the user to run this workflow from the Oatty TUI, or to perform the action directly.",
+                ));
+            };
+            wait_for_confirmation(confirmer(call))?;
+        }
+        Ok(())
+    }
+
+    /// The call a step makes, as presented to `requireConfirmation` rules and the operator.
+    fn step_call(&self, step: &WorkflowStepDefinition, registry: &CommandRegistry) -> McpPendingToolCall {
+        let mut call = McpPendingToolCall {
+            tool: self.tool_name.clone(),
+            ..McpPendingToolCall::default()
+        };
+        let Some(command_spec) = step
+            .run
+            .trim()
+            .split_once(' ')
+            .and_then(|(group, name)| registry.find_by_group_and_cmd_ref(group.trim(), name.trim()).ok())
+        else {
+            return call;
+        };
+        call.canonical_id = Some(command_spec.canonical_id());
+        let mut arguments = serde_json::Map::new();
+        arguments.insert("with".to_string(), serde_json::to_value(&step.with).unwrap_or(Value::Null));
+        if !step.body.is_null() {
+            arguments.insert("body".to_string(), step.body.clone());
+        }
+        call.body = Some(redact_json(&Value::Object(arguments)));
+        if let Some(mcp) = command_spec.mcp() {
+            call.catalog = Some(mcp.plugin_name.clone());
+        } else if let Some(http) = command_spec.http() {
+            call.catalog = command_catalog_title(registry, command_spec).map(str::to_string);
+            call.http_method = Some(http.method.to_ascii_uppercase());
+            call.url = registry
+                .resolve_base_url_for_command(command_spec)
+                .map(|base_url| format!("{}{}", base_url.trim_end_matches('/'), http.path));
+        }
+        call
+    }
+
+    /// Wrap `inner` so each step acquires a rate-limit permit for its catalog before it runs.
+    pub(crate) fn limit_runner<R: CommandRunner>(&self, inner: R, registry: CommandRegistry) -> RateLimitedCommandRunner<R> {
+        RateLimitedCommandRunner {
+            inner,
+            registry,
+            rate_limiter: self.rate_limiter.clone(),
+        }
+    }
+}
+
+/// Waits for an operator decision from the synchronous workflow tools.
+///
+/// Mirrors how workflow runs are driven: multi-thread runtimes block in place, anything else
+/// waits on a dedicated runtime thread.
+fn wait_for_confirmation(confirmation: BoxFuture<'static, Result<(), ErrorData>>) -> Result<(), ErrorData> {
+    if let Ok(handle) = Handle::try_current()
+        && handle.runtime_flavor() == RuntimeFlavor::MultiThread
+    {
+        return tokio::task::block_in_place(|| handle.block_on(confirmation));
+    }
+    let confirmation_failed =
+        |message: String| internal_error("WORKFLOW_CONFIRMATION_FAILED", message, Value::Null, "Retry the workflow run.");
+    thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map_err(|error| confirmation_failed(format!("failed to create confirmation runtime: {error}")))?;
+                runtime.block_on(confirmation)
+            })
+            .join()
+            .unwrap_or_else(|_| Err(confirmation_failed("confirmation thread panicked".to_string())))
+    })
+}
+
+/// Runner that holds a rate-limit permit for the duration of every step it executes.
+///
+/// Steps are admitted against their own catalog, and count as writes unless they are HTTP GETs.
+pub(crate) struct RateLimitedCommandRunner<R> {
+    inner: R,
+    registry: CommandRegistry,
+    rate_limiter: Option<SessionLimiter>,
+}
+
+impl<R> RateLimitedCommandRunner<R> {
+    fn step_target(&self, run: &str) -> (Option<String>, bool) {
+        let Some((group, name)) = run.split_once(char::is_whitespace) else {
+            return (None, true);
+        };
+        let Ok(command_spec) = self.registry.find_by_group_and_cmd_ref(group, name.trim()) else {
+            return (None, true);
+        };
+        let catalog = self
+            .registry
+            .config
+            .catalogs
+            .as_ref()
+            .and_then(|catalogs| catalogs.get(command_spec.catalog_identifier))
+            .map(|catalog| catalog.title.clone())
+            .or_else(|| command_spec.mcp().map(|mcp| mcp.plugin_name.clone()));
+        let is_write = command_spec.http().is_none_or(|http| !http.method.eq_ignore_ascii_case("GET"));
+        (catalog, is_write)
+    }
+}
+
+impl<R: CommandRunner> CommandRunner for RateLimitedCommandRunner<R> {
+    fn run(&self, run: &str, with: Option<&Value>, body: Option<&Value>, run_context: &RunContext) -> Result<Value> {
+        let _permit = match self.rate_limiter.as_ref() {
+            Some(rate_limiter) => {
+                let (catalog, is_write) = self.step_target(run);
+                let permit = rate_limiter
+                    .acquire(catalog.as_deref(), is_write)
+                    .map_err(|error| anyhow!("step '{run}' was rate limited: {}", error.message))?;
+                Some(permit)
+            }
+            None => None,
+        };
+        self.inner.run(run, with, body, run_context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{McpRateLimitRule, McpRateLimits};
+    use crate::server::limits::McpRateLimiter;
+    use crate::server::policy::McpPolicy;
+    use crate::server::workflow::errors::operator_denied_error;
+    use oatty_engine::executor::NoopRunner;
+    use oatty_types::{CommandSpec, HttpCommandSpec};
+    use std::sync::Mutex;
+
+    fn http_command(group: &str, name: &str, method: &str) -> CommandSpec {
+        CommandSpec::new_http(
+            group.to_string(),
+            name.to_string(),
+            String::new(),
+            Vec::new(),
+            Vec::new(),
+            HttpCommandSpec::new(method, "/apps", None, None),
+            0,
+        )
+    }
+
+    #[test]
+    fn each_step_is_admitted_under_the_session_write_limit() {
+        let limiter = McpRateLimiter::new(McpRateLimits {
+            session: McpRateLimitRule {
+                max_write_calls: Some(1),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let registry =
+            CommandRegistry::default().with_commands(vec![http_command("apps", "list", "GET"), http_command("apps", "delete", "DELETE")]);
+        let guard = WorkflowRunGuard::new(McpPolicyScope::default()).with_rate_limiter(Some(limiter.session()));
+        let runner = guard.limit_runner(NoopRunner, registry);
+        let run_context = RunContext::default();
+
+        runner
+            .run("apps list", None, None, &run_context)
+            .expect("reads are not write calls");
+        runner
+            .run("apps delete", None, None, &run_context)
+            .expect("first write is admitted");
+        runner.run("apps list", None, None, &run_context).expect("reads stay admitted");
+        let error = runner
+            .run("apps delete", None, None, &run_context)
+            .expect_err("second write exceeds the session limit");
+        assert!(error.to_string().contains("step 'apps delete' was rate limited"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn declined_delete_step_stops_the_workflow_before_it_runs() {
+        let policy: McpPolicy = serde_yaml::from_str("requireConfirmation:\n  - httpMethods: [DELETE]\n").expect("policy");
+        let registry =
+            CommandRegistry::default().with_commands(vec![http_command("apps", "list", "GET"), http_command("apps", "delete", "DELETE")]);
+        let workflow: RuntimeWorkflow = serde_json::from_value(serde_json::json!({
+            "identifier": "cleanup",
+            "title": null,
+            "description": null,
+            "inputs": {},
+            "steps": [
+                { "id": "list", "run": "apps list" },
+                { "id": "delete", "run": "apps delete", "with": { "app": "web" } },
+            ],
+        }))
+        .expect("workflow");
+        let asked = Arc::new(Mutex::new(Vec::new()));
+        let confirmer: StepConfirmer = {
+            let asked = asked.clone();
+            Arc::new(move |call: McpPendingToolCall| {
+                asked.lock().expect("asked").push(call.summary());
+                Box::pin(async move {
+                    Err(operator_denied_error(
+                        format!("'{}' was denied by the operator", call.summary()),
+                        Value::Null,
+                        "Do not retry this call.",
+                    ))
+                })
+            })
+        };
+        let guard = WorkflowRunGuard::new(policy.scope_for_unauthenticated_client(None)).with_confirmer("workflow_run", confirmer);
+
+        let error = guard
+            .authorize_workflow(&workflow, &registry)
+            .expect_err("declined step blocks the run");
+        assert!(error.message.contains("denied by the operator"));
+        assert_eq!(*asked.lock().expect("asked"), vec!["workflow_run apps delete".to_string()]);
+    }
+}
@@ -17,6 +17,9 @@ pub struct WorkflowHistoryEntry {
     pub status: String,
     pub timestamp: DateTime<Utc>,
     pub inputs: Value,
+    /// Origin of the run (for example `schedule`) when it was not started interactively.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<String>,
 }
 
 /// Summary describing removed workflow history records.
@@ -47,6 +50,43 @@ pub fn append_history_entry(entry: &WorkflowHistoryEntry) -> Result<()> {
     Ok(())
 }
 
+/// Returns the most recent history entry recorded for a workflow, if any.
+pub fn latest_history_entry(workflow_id: &str) -> Result<Option<WorkflowHistoryEntry>> {
+    let file_path = history_file_path(workflow_id);
+    if !file_path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&file_path).with_context(|| format!("read history file {}", file_path.display()))?;
+    let Some(line) = content.lines().rev().find(|line| !line.trim().is_empty()) else {
+        return Ok(None);
+    };
+    let entry =
+        serde_json::from_str::<WorkflowHistoryEntry>(line).with_context(|| format!("parse history entry from {}", file_path.display()))?;
+    Ok(Some(entry))
+}
+
+/// Finds the most recent history entry recorded for a run identifier across all workflows.
+pub fn find_history_entry(run_id: &str) -> Result<Option<WorkflowHistoryEntry>> {
+    if !history_directory().exists() {
+        return Ok(None);
+    }
+
+    let mut found: Option<WorkflowHistoryEntry> = None;
+    for file_path in list_history_files(None)? {
+        let content = fs::read_to_string(&file_path).with_context(|| format!("read history file {}", file_path.display()))?;
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            let Ok(entry) = serde_json::from_str::<WorkflowHistoryEntry>(line) else {
+                continue;
+            };
+            if entry.run_id == run_id && found.as_ref().is_none_or(|current| current.timestamp <= entry.timestamp) {
+                found = Some(entry);
+            }
+        }
+    }
+    Ok(found)
+}
+
 /// Purge workflow history entries by workflow id and/or referenced input keys.
 pub fn purge_history(workflow_id: Option<&str>, input_keys: &[String]) -> Result<WorkflowHistoryPurgeSummary> {
     let history_directory = history_directory();
@@ -143,6 +183,7 @@ mod tests {
             status: "succeeded".to_string(),
             timestamp: Utc::now(),
             inputs,
+            trigger: None,
         }
     }
 
@@ -165,6 +206,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn latest_history_entry_returns_last_appended_run() {
+        let temp_directory = tempfile::tempdir().expect("create temp dir");
+        temp_env::with_var(
+            "REGISTRY_WORKFLOWS_PATH",
+            Some(temp_directory.path().to_string_lossy().to_string()),
+            || {
+                assert!(latest_history_entry("demo").expect("read empty history").is_none());
+
+                append_history_entry(&sample_entry("demo", "run-1", serde_json::json!({}))).expect("append first history entry");
+                let mut scheduled = sample_entry("demo", "run-2", serde_json::json!({}));
+                scheduled.trigger = Some("schedule".to_string());
+                append_history_entry(&scheduled).expect("append second history entry");
+
+                let latest = latest_history_entry("demo").expect("read history").expect("entry present");
+                assert_eq!(latest.run_id, "run-2");
+                assert_eq!(latest.trigger.as_deref(), Some("schedule"));
+            },
+        );
+    }
+
     #[test]
     fn purge_history_by_input_key_preserves_non_matching_entries() {
         let temp_directory = tempfile::tempdir().expect("create temp dir");
@@ -183,4 +245,23 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn find_history_entry_searches_every_workflow() {
+        let temp_directory = tempfile::tempdir().expect("create temp dir");
+        temp_env::with_var(
+            "REGISTRY_WORKFLOWS_PATH",
+            Some(temp_directory.path().to_string_lossy().to_string()),
+            || {
+                assert!(find_history_entry("run-2").expect("read empty history").is_none());
+
+                append_history_entry(&sample_entry("alpha", "run-1", serde_json::json!({}))).expect("append alpha entry");
+                append_history_entry(&sample_entry("beta", "run-2", serde_json::json!({ "app": "demo" }))).expect("append beta entry");
+
+                let entry = find_history_entry("run-2").expect("read history").expect("run-2 recorded");
+                assert_eq!(entry.workflow_id, "beta");
+                assert!(find_history_entry("run-3").expect("read history").is_none());
+            },
+        );
+    }
 }
@@ -1,5 +1,7 @@
 //! Workflow backend services.
 
+pub mod guard;
 pub mod history;
+pub mod runs;
 pub mod storage;
 pub mod sync;
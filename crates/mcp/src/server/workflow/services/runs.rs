@@ -0,0 +1,437 @@
+//! Live tracking, progress reporting, and cancellation for in-flight workflow runs.
+//!
+//! The engine runner executes steps synchronously, so run events cannot be observed from the future
+//! that drives it. [`WorkflowRunObserver::relay`] instead forwards events through a dedicated thread
+//! that records partial results in the [`WorkflowRunRegistry`], emits MCP progress notifications,
+//! and converts client cancellation into [`WorkflowRunControl::Cancel`].
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use chrono::{DateTime, Utc};
+use oatty_engine::executor::StepResult;
+use oatty_types::workflow::{WorkflowRunControl, WorkflowRunEvent, WorkflowRunStatus};
+use rmcp::{
+    Peer, RoleServer,
+    model::{ProgressNotificationParam, ProgressToken},
+};
+use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+use tokio_util::sync::CancellationToken;
+
+use crate::server::subscriptions::ResourceSubscriptions;
+use crate::server::workflow::resources::workflow_run_uri;
+use crate::server::workflow::tools::execution::map_engine_step_status;
+
+/// Number of finished runs retained for `workflow_run_status` lookups.
+const FINISHED_RUN_RETENTION: usize = 32;
+
+/// Point-in-time view of a tracked workflow run.
+#[derive(Debug, Clone)]
+pub struct WorkflowRunSnapshot {
+    pub run_id: String,
+    pub workflow_id: String,
+    /// Task operation identifier when the run was started through a task-backed call.
+    pub operation_id: Option<String>,
+    pub status: WorkflowRunStatus,
+    /// Step currently executing, if any.
+    pub current_step: Option<String>,
+    /// Attempt counter of the current repeating step.
+    pub current_attempt: Option<u32>,
+    pub total_steps: usize,
+    /// Results for steps that have finished so far.
+    pub step_results: Vec<StepResult>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug)]
+struct TrackedWorkflowRun {
+    snapshot: WorkflowRunSnapshot,
+    control: UnboundedSender<WorkflowRunControl>,
+}
+
+#[derive(Debug, Default)]
+struct WorkflowRunRegistryState {
+    runs: HashMap<String, TrackedWorkflowRun>,
+    finished_order: VecDeque<String>,
+}
+
+/// Registry of workflow runs started in an MCP session, keyed by run identifier.
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowRunRegistry {
+    state: Arc<Mutex<WorkflowRunRegistryState>>,
+}
+
+impl WorkflowRunRegistry {
+    /// Start tracking a run before it begins executing.
+    pub fn register(
+        &self,
+        run_id: &str,
+        workflow_id: &str,
+        operation_id: Option<String>,
+        total_steps: usize,
+        control: UnboundedSender<WorkflowRunControl>,
+    ) {
+        let snapshot = WorkflowRunSnapshot {
+            run_id: run_id.to_string(),
+            workflow_id: workflow_id.to_string(),
+            operation_id,
+            status: WorkflowRunStatus::Pending,
+            current_step: None,
+            current_attempt: None,
+            total_steps,
+            step_results: Vec::new(),
+            started_at: Utc::now(),
+            finished_at: None,
+        };
+        if let Ok(mut state) = self.state.lock() {
+            state.runs.insert(run_id.to_string(), TrackedWorkflowRun { snapshot, control });
+        }
+    }
+
+    /// Apply a runner event to the tracked run.
+    pub fn record_event(&self, run_id: &str, event: &WorkflowRunEvent) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let Some(run) = state.runs.get_mut(run_id) else {
+            return;
+        };
+        let snapshot = &mut run.snapshot;
+        match event {
+            WorkflowRunEvent::RunStarted { .. } => snapshot.status = WorkflowRunStatus::Running,
+            WorkflowRunEvent::RunStatusChanged { status, .. } => snapshot.status = *status,
+            WorkflowRunEvent::StepStarted { step_id, .. } => {
+                snapshot.current_step = Some(step_id.clone());
+                snapshot.current_attempt = None;
+            }
+            WorkflowRunEvent::StepAttempt { attempt, .. } => snapshot.current_attempt = Some(*attempt),
+            WorkflowRunEvent::StepFinished {
+                step_id,
+                status,
+                output,
+                logs,
+                attempts,
+                ..
+            } => {
+                snapshot.current_step = None;
+                snapshot.current_attempt = None;
+                snapshot.step_results.push(StepResult {
+                    id: step_id.clone(),
+                    status: map_engine_step_status(*status),
+                    output: output.clone(),
+                    logs: logs.clone(),
+                    attempts: *attempts,
+                });
+            }
+            WorkflowRunEvent::RunCompleted { status, finished_at, .. } => {
+                snapshot.status = *status;
+                snapshot.current_step = None;
+                snapshot.finished_at = Some(*finished_at);
+                state.finished_order.push_back(run_id.to_string());
+                while state.finished_order.len() > FINISHED_RUN_RETENTION {
+                    if let Some(evicted) = state.finished_order.pop_front() {
+                        state.runs.remove(&evicted);
+                    }
+                }
+            }
+            WorkflowRunEvent::StepOutputProduced { .. } | WorkflowRunEvent::RunOutputAccumulated { .. } => {}
+        }
+    }
+
+    /// Look up a run by run identifier or task operation identifier.
+    pub fn snapshot(&self, identifier: &str) -> Option<WorkflowRunSnapshot> {
+        let state = self.state.lock().ok()?;
+        find_run(&state, identifier).map(|run| run.snapshot.clone())
+    }
+
+    /// Ask an in-flight run to cancel. Returns `false` when the run is unknown or already finished.
+    pub fn cancel(&self, identifier: &str) -> bool {
+        let Ok(state) = self.state.lock() else {
+            return false;
+        };
+        find_run(&state, identifier)
+            .filter(|run| !run.snapshot.status.is_terminal())
+            .is_some_and(|run| run.control.send(WorkflowRunControl::Cancel).is_ok())
+    }
+}
+
+fn find_run<'a>(state: &'a WorkflowRunRegistryState, identifier: &str) -> Option<&'a TrackedWorkflowRun> {
+    state.runs.get(identifier).or_else(|| {
+        state
+            .runs
+            .values()
+            .find(|run| run.snapshot.operation_id.as_deref() == Some(identifier))
+    })
+}
+
+/// Hooks that observe a workflow run while it executes.
+///
+/// A default observer is detached: events are collected only for the final summary.
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowRunObserver {
+    registry: Option<WorkflowRunRegistry>,
+    operation_id: Option<String>,
+    progress: Option<(Peer<RoleServer>, ProgressToken)>,
+    resource_updates: Option<(Peer<RoleServer>, ResourceSubscriptions)>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl WorkflowRunObserver {
+    /// Track the run in `registry`, optionally associated with a task operation identifier.
+    pub fn with_registry(mut self, registry: WorkflowRunRegistry, operation_id: Option<String>) -> Self {
+        self.registry = Some(registry);
+        self.operation_id = operation_id;
+        self
+    }
+
+    /// Send `notifications/progress` for step events to the requesting client.
+    pub fn with_progress(mut self, peer: Peer<RoleServer>, progress_token: Option<ProgressToken>) -> Self {
+        self.progress = progress_token.map(|token| (peer, token));
+        self
+    }
+
+    /// Send `resources/updated` for the run's resource when the session subscribed to it.
+    pub(crate) fn with_resource_updates(mut self, peer: Peer<RoleServer>, subscriptions: ResourceSubscriptions) -> Self {
+        self.resource_updates = Some((peer, subscriptions));
+        self
+    }
+
+    /// Cancel the run when the client cancels the originating request.
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+
+    fn is_detached(&self) -> bool {
+        self.registry.is_none() && self.progress.is_none() && self.resource_updates.is_none() && self.cancellation.is_none()
+    }
+
+    /// Interpose a relay between the runner and `downstream`.
+    ///
+    /// Returns the sender the runner should emit events into and, for attached observers, the relay
+    /// thread handle. The relay exits once every sender has been dropped.
+    pub fn relay(
+        self,
+        run_id: &str,
+        workflow_id: &str,
+        total_steps: usize,
+        control: UnboundedSender<WorkflowRunControl>,
+        downstream: UnboundedSender<WorkflowRunEvent>,
+    ) -> anyhow::Result<(UnboundedSender<WorkflowRunEvent>, Option<JoinHandle<()>>)> {
+        if self.is_detached() {
+            return Ok((downstream, None));
+        }
+        if let Some(registry) = &self.registry {
+            registry.register(run_id, workflow_id, self.operation_id.clone(), total_steps, control.clone());
+        }
+
+        let (relay_tx, mut relay_rx) = unbounded_channel::<WorkflowRunEvent>();
+        let run_id = run_id.to_string();
+        let handle = thread::Builder::new()
+            .name("workflow-run-relay".to_string())
+            .spawn(move || {
+                let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                    Ok(runtime) => runtime,
+                    Err(error) => {
+                        tracing::warn!(error = %error, "failed to create workflow relay runtime; progress disabled");
+                        while let Some(event) = relay_rx.blocking_recv() {
+                            let _ = downstream.send(event);
+                        }
+                        return;
+                    }
+                };
+                runtime.block_on(async move {
+                    let cancellation = self.cancellation.clone().unwrap_or_default();
+                    let mut cancel_sent = false;
+                    let mut progress = ProgressTracker::new(total_steps);
+                    if let Some((peer, token)) = &self.progress {
+                        let _ = peer
+                            .notify_progress(ProgressNotificationParam {
+                                progress_token: token.clone(),
+                                progress: 0.0,
+                                total: Some(total_steps as f64),
+                                message: Some(format!("workflow run {run_id} started")),
+                            })
+                            .await;
+                    }
+                    loop {
+                        tokio::select! {
+                            biased;
+                            event = relay_rx.recv() => {
+                                let Some(event) = event else { break };
+                                if let Some(registry) = &self.registry {
+                                    registry.record_event(&run_id, &event);
+                                }
+                                if let Some((peer, token)) = &self.progress
+                                    && let Some((value, message)) = progress.observe(&event)
+                                {
+                                    let _ = peer
+                                        .notify_progress(ProgressNotificationParam {
+                                            progress_token: token.clone(),
+                                            progress: value,
+                                            total: Some(total_steps as f64),
+                                            message: Some(message),
+                                        })
+                                        .await;
+                                }
+                                if let Some((peer, subscriptions)) = &self.resource_updates
+                                    && changes_run_resource(&event)
+                                {
+                                    subscriptions.notify(peer, &workflow_run_uri(&run_id)).await;
+                                }
+                                let _ = downstream.send(event);
+                            }
+                            _ = cancellation.cancelled(), if self.cancellation.is_some() && !cancel_sent => {
+                                cancel_sent = true;
+                                let _ = control.send(WorkflowRunControl::Cancel);
+                            }
+                        }
+                    }
+                });
+            })
+            .map_err(|error| anyhow::anyhow!("failed to spawn workflow relay thread: {error}"))?;
+        Ok((relay_tx, Some(handle)))
+    }
+}
+
+/// Whether an event changes what `oatty://workflow/run/{run_id}` reports.
+fn changes_run_resource(event: &WorkflowRunEvent) -> bool {
+    matches!(
+        event,
+        WorkflowRunEvent::RunStarted { .. }
+            | WorkflowRunEvent::RunStatusChanged { .. }
+            | WorkflowRunEvent::StepFinished { .. }
+            | WorkflowRunEvent::RunCompleted { .. }
+    )
+}
+
+/// Maps step events onto a monotonically increasing progress value measured in steps.
+///
+/// Finished steps count as whole units; a started step advances slightly and each repeat attempt
+/// moves closer to (but never reaches) the next whole unit.
+#[derive(Debug)]
+struct ProgressTracker {
+    total_steps: usize,
+    finished_steps: usize,
+}
+
+impl ProgressTracker {
+    fn new(total_steps: usize) -> Self {
+        Self {
+            total_steps,
+            finished_steps: 0,
+        }
+    }
+
+    fn observe(&mut self, event: &WorkflowRunEvent) -> Option<(f64, String)> {
+        let base = self.finished_steps as f64;
+        match event {
+            WorkflowRunEvent::StepStarted { index, step_id, .. } => {
+                Some((base + 0.05, format!("step {}/{} started: {step_id}", index + 1, self.total_steps)))
+            }
+            WorkflowRunEvent::StepAttempt {
+                step_id,
+                attempt,
+                max_attempts,
+            } => {
+                let fraction = 0.05 + 0.9 * (1.0 - 1.0 / (f64::from(*attempt) + 1.0));
+                let limit = max_attempts.map(|max| format!("/{max}")).unwrap_or_default();
+                Some((base + fraction, format!("step {step_id} attempt {attempt}{limit}")))
+            }
+            WorkflowRunEvent::StepFinished { step_id, status, .. } => {
+                self.finished_steps += 1;
+                Some((
+                    self.finished_steps as f64,
+                    format!("step {step_id} finished: {}", serde_json::to_value(status).ok()?.as_str()?),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oatty_engine::StepStatus;
+    use oatty_types::workflow::WorkflowRunStepStatus;
+    use serde_json::Value;
+
+    fn step_finished(step_id: &str) -> WorkflowRunEvent {
+        WorkflowRunEvent::StepFinished {
+            step_id: step_id.to_string(),
+            status: WorkflowRunStepStatus::Succeeded,
+            output: Value::Null,
+            logs: Vec::new(),
+            attempts: 1,
+            duration_ms: 5,
+        }
+    }
+
+    #[test]
+    fn progress_increases_monotonically_across_step_events() {
+        let mut tracker = ProgressTracker::new(2);
+        let events = [
+            WorkflowRunEvent::StepStarted {
+                index: 0,
+                step_id: "poll".to_string(),
+                label: None,
+                started_at: Utc::now(),
+            },
+            WorkflowRunEvent::StepAttempt {
+                step_id: "poll".to_string(),
+                attempt: 1,
+                max_attempts: Some(10),
+            },
+            WorkflowRunEvent::StepAttempt {
+                step_id: "poll".to_string(),
+                attempt: 2,
+                max_attempts: Some(10),
+            },
+            step_finished("poll"),
+        ];
+        let values: Vec<f64> = events
+            .iter()
+            .filter_map(|event| tracker.observe(event))
+            .map(|(value, _)| value)
+            .collect();
+        assert!(values.windows(2).all(|pair| pair[0] < pair[1]), "{values:?}");
+        assert_eq!(values.last().copied(), Some(1.0));
+    }
+
+    #[test]
+    fn registry_tracks_partial_results_and_cancels_in_flight_runs() {
+        let registry = WorkflowRunRegistry::default();
+        let (control_tx, mut control_rx) = unbounded_channel();
+        registry.register("run-1", "deploy", Some("op-7".to_string()), 2, control_tx);
+        registry.record_event(
+            "run-1",
+            &WorkflowRunEvent::RunStatusChanged {
+                status: WorkflowRunStatus::Running,
+                message: None,
+            },
+        );
+        registry.record_event("run-1", &step_finished("build"));
+
+        let snapshot = registry.snapshot("op-7").expect("lookup by operation id");
+        assert_eq!(snapshot.run_id, "run-1");
+        assert_eq!(snapshot.step_results.len(), 1);
+        assert_eq!(snapshot.step_results[0].status, StepStatus::Succeeded);
+
+        assert!(registry.cancel("run-1"));
+        assert!(matches!(control_rx.try_recv(), Ok(WorkflowRunControl::Cancel)));
+
+        registry.record_event(
+            "run-1",
+            &WorkflowRunEvent::RunCompleted {
+                status: WorkflowRunStatus::Canceled,
+                finished_at: Utc::now(),
+                error: None,
+            },
+        );
+        assert!(!registry.cancel("run-1"), "finished runs cannot be cancelled");
+        assert_eq!(registry.snapshot("run-1").map(|run| run.status), Some(WorkflowRunStatus::Canceled));
+    }
+}
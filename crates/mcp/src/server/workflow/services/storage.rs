@@ -82,6 +82,28 @@ pub fn parse_manifest_content(content: &str, format_hint: Option<&str>) -> Resul
     }
 }
 
+/// Parses manifest content like [`parse_manifest_content`], but reports every shape violation
+/// instead of failing on the first one, so validation can return them with other findings.
+pub fn parse_manifest_content_with_shape_violations(
+    content: &str,
+    format_hint: Option<&str>,
+) -> Result<(WorkflowDefinition, WorkflowManifestFormat, Vec<String>)> {
+    match format_hint {
+        Some(hint) => {
+            let format = WorkflowManifestFormat::from_hint(Some(hint))?;
+            let (definition, shape_violations) = parse_definition_with_shape_violations(content, format)?;
+            Ok((definition, format, shape_violations))
+        }
+        None => {
+            if let Ok((definition, shape_violations)) = parse_definition_with_shape_violations(content, WorkflowManifestFormat::Yaml) {
+                return Ok((definition, WorkflowManifestFormat::Yaml, shape_violations));
+            }
+            let (definition, shape_violations) = parse_definition_with_shape_violations(content, WorkflowManifestFormat::Json)?;
+            Ok((definition, WorkflowManifestFormat::Json, shape_violations))
+        }
+    }
+}
+
 pub fn serialize_definition(definition: &WorkflowDefinition, format: WorkflowManifestFormat) -> Result<String> {
     match format {
         WorkflowManifestFormat::Yaml => serde_yaml::to_string(definition).context("serialize workflow to yaml"),
@@ -169,12 +191,29 @@ pub fn compute_version(content: &str) -> String {
 }
 
 fn parse_definition_with_format(content: &str, format: WorkflowManifestFormat) -> Result<WorkflowDefinition> {
+    let (definition, shape_violations) = parse_definition_with_shape_violations(content, format)?;
+    if let Some(violation) = shape_violations.into_iter().next() {
+        bail!(violation);
+    }
+    Ok(definition)
+}
+
+/// Parses a manifest and returns its shape violations alongside the definition.
+///
+/// Shape violations only become a hard error when the definition itself fails to deserialize,
+/// in which case the first one is reported because it usually explains the serde failure.
+fn parse_definition_with_shape_violations(content: &str, format: WorkflowManifestFormat) -> Result<(WorkflowDefinition, Vec<String>)> {
     let manifest_value = parse_manifest_value_with_format(content, format)?;
-    validate_manifest_shape(&manifest_value)?;
+    let shape_violations = collect_manifest_shape_violations(&manifest_value)?;
 
-    match format {
+    let definition = match format {
         WorkflowManifestFormat::Yaml => serde_yaml::from_str(content).map_err(|error| anyhow!(format_yaml_error(error))),
         WorkflowManifestFormat::Json => serde_json::from_str(content).map_err(|error| anyhow!(format_json_error(error))),
+    };
+    match (definition, shape_violations.first()) {
+        (Ok(definition), _) => Ok((definition, shape_violations)),
+        (Err(_), Some(violation)) => bail!(violation.clone()),
+        (Err(error), None) => Err(error),
     }
 }
 
@@ -185,24 +224,27 @@ fn parse_manifest_value_with_format(content: &str, format: WorkflowManifestForma
     }
 }
 
-fn validate_manifest_shape(manifest_value: &serde_json::Value) -> Result<()> {
+/// Collects every manifest shape problem that serde would otherwise accept or misreport.
+///
+/// Only a non-object root is returned as an error, since no further checks apply to it.
+fn collect_manifest_shape_violations(manifest_value: &serde_json::Value) -> Result<Vec<String>> {
     let root_object = manifest_value
         .as_object()
         .ok_or_else(|| anyhow!("workflow manifest root must be an object"))?;
 
-    validate_input_defaults_shape(root_object)?;
-    validate_input_metadata_keys(root_object)?;
-    validate_step_key_shape(root_object)?;
-
-    Ok(())
+    let mut violations = Vec::new();
+    collect_input_defaults_shape_violations(root_object, &mut violations);
+    collect_input_metadata_key_violations(root_object, &mut violations);
+    collect_step_key_shape_violations(root_object, &mut violations);
+    Ok(violations)
 }
 
-fn validate_input_defaults_shape(root_object: &serde_json::Map<String, serde_json::Value>) -> Result<()> {
+fn collect_input_defaults_shape_violations(root_object: &serde_json::Map<String, serde_json::Value>, violations: &mut Vec<String>) {
     let Some(inputs_value) = root_object.get("inputs") else {
-        return Ok(());
+        return;
     };
     let Some(inputs_object) = inputs_value.as_object() else {
-        return Ok(());
+        return;
     };
 
     for (input_name, definition_value) in inputs_object {
@@ -216,25 +258,24 @@ fn validate_input_defaults_shape(root_object: &serde_json::Map<String, serde_jso
             continue;
         }
         let Some(default_object) = default_value.as_object() else {
-            bail!(
+            violations.push(format!(
                 "workflow input '{}.default' must be an object like '{{ from: literal, value: ... }}'",
                 input_name
-            );
+            ));
+            continue;
         };
         if !default_object.contains_key("from") {
-            bail!("workflow input '{}.default' must include a 'from' field", input_name);
+            violations.push(format!("workflow input '{}.default' must include a 'from' field", input_name));
         }
     }
-
-    Ok(())
 }
 
-fn validate_input_metadata_keys(root_object: &serde_json::Map<String, serde_json::Value>) -> Result<()> {
+fn collect_input_metadata_key_violations(root_object: &serde_json::Map<String, serde_json::Value>, violations: &mut Vec<String>) {
     let Some(inputs_value) = root_object.get("inputs") else {
-        return Ok(());
+        return;
     };
     let Some(inputs_object) = inputs_value.as_object() else {
-        return Ok(());
+        return;
     };
 
     for (input_name, definition_value) in inputs_object {
@@ -243,30 +284,26 @@ fn validate_input_metadata_keys(root_object: &serde_json::Map<String, serde_json
         };
 
         if definition_object.contains_key("hints") {
-            bail!(
+            violations.push(format!(
                 "workflow input '{}.hints' is unsupported; did you mean '{}.hint'?",
-                input_name,
-                input_name
-            );
+                input_name, input_name
+            ));
         }
         if definition_object.contains_key("examples") {
-            bail!(
+            violations.push(format!(
                 "workflow input '{}.examples' is unsupported; did you mean '{}.example'?",
-                input_name,
-                input_name
-            );
+                input_name, input_name
+            ));
         }
     }
-
-    Ok(())
 }
 
-fn validate_step_key_shape(root_object: &serde_json::Map<String, serde_json::Value>) -> Result<()> {
+fn collect_step_key_shape_violations(root_object: &serde_json::Map<String, serde_json::Value>, violations: &mut Vec<String>) {
     let Some(steps_value) = root_object.get("steps") else {
-        return Ok(());
+        return;
     };
     let Some(step_list) = steps_value.as_array() else {
-        return Ok(());
+        return;
     };
 
     for (step_index, step_value) in step_list.iter().enumerate() {
@@ -276,43 +313,37 @@ fn validate_step_key_shape(root_object: &serde_json::Map<String, serde_json::Val
         let step_identifier = step_object.get("id").and_then(serde_json::Value::as_str).unwrap_or("<missing-id>");
 
         if step_object.contains_key("flags") || step_object.contains_key("positional_args") {
-            bail!(
+            violations.push(format!(
                 "workflow step '{}'(index {}) must place command arguments under 'with', not 'flags' or 'positional_args'",
-                step_identifier,
-                step_index
-            );
+                step_identifier, step_index
+            ));
         }
         if step_object.contains_key("condition") {
-            bail!(
+            violations.push(format!(
                 "workflow step '{}'(index {}) uses unsupported key 'condition'; use 'if' or 'when'",
-                step_identifier,
-                step_index
-            );
+                step_identifier, step_index
+            ));
         }
         if let Some(with_value) = step_object.get("with")
             && let Some(invalid_path) = find_invalid_with_interpolation_path(with_value, "with")
         {
-            bail!(
+            violations.push(format!(
                 "workflow step '{}'(index {}) likely uses unsupported interpolation syntax '{{{{ ... }}}}' at '{}'; use '${{{{ ... }}}}'",
-                step_identifier,
-                step_index,
-                invalid_path
-            );
+                step_identifier, step_index, invalid_path
+            ));
         }
         if let Some(output_contract_value) = step_object.get("output_contract")
             && let Some(output_contract_object) = output_contract_value.as_object()
             && output_contract_object.contains_key("select")
         {
-            bail!(
+            violations.push(format!(
                 "workflow step '{}'(index {}) uses unsupported key 'output_contract.select'; define output_contract.fields and reference paths directly (for example, '${{{{ steps.{}.field }}}}')",
                 step_identifier,
                 step_index,
                 step_identifier
-            );
+            ));
         }
     }
-
-    Ok(())
 }
 
 fn find_invalid_with_interpolation_path(value: &serde_json::Value, path: &str) -> Option<String> {
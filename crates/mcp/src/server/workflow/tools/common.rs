@@ -1007,6 +1007,7 @@ mod tests {
             ],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let violations = collect_step_template_output_path_violations(&workflow, &registry);
@@ -1072,6 +1073,7 @@ mod tests {
             ],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let violations = collect_step_template_output_path_violations(&workflow, &registry);
@@ -1126,6 +1128,7 @@ mod tests {
             ],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let violations = collect_step_template_output_path_violations(&workflow, &registry);
@@ -1174,6 +1177,7 @@ mod tests {
             ],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let warnings = collect_quoted_template_non_string_binding_warnings(&workflow, &registry);
@@ -1218,6 +1222,7 @@ mod tests {
             ],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let warnings = collect_conditional_dependency_warnings(&workflow);
@@ -1266,6 +1271,7 @@ mod tests {
             ],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let violations = collect_step_template_output_path_violations(&workflow, &registry);
@@ -1313,6 +1319,7 @@ mod tests {
             ],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let violations = collect_step_template_output_path_violations(&workflow, &registry);
@@ -1376,6 +1383,7 @@ mod tests {
             ],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let warnings = collect_step_template_array_index_warnings(&workflow, &registry);
@@ -1408,6 +1416,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let warnings = collect_mutation_preflight_warnings(&workflow, &registry);
@@ -1462,6 +1471,7 @@ mod tests {
             ],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let warnings = collect_mutation_preflight_warnings(&workflow, &registry);
@@ -1517,6 +1527,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let warnings = collect_endpoint_context_warnings(&workflow);
@@ -1561,6 +1572,7 @@ mod tests {
                     default_value: None,
                     description: Some("Include metadata".to_string()),
                     provider: None,
+                    schema: None,
                 }],
                 HttpCommandSpec::new("GET", "/services/{id}/deploys/{deploy_id}", Some(deploy_info_schema), None),
                 1,
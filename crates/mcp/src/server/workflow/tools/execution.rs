@@ -1,12 +1,18 @@
 //! Workflow execution tool handlers.
 
-use crate::server::workflow::errors::{execution_error, internal_error, invalid_params_error};
+use crate::logging::{AuditTrail, AuditedCommandRunner};
+use crate::server::workflow::errors::{
+    execution_error, internal_error, invalid_params_error, not_found_error, validation_error_with_violations,
+};
+use crate::server::workflow::services::guard::WorkflowRunGuard;
 use crate::server::workflow::services::history::{WorkflowHistoryEntry, append_history_entry};
+use crate::server::workflow::services::runs::{WorkflowRunObserver, WorkflowRunRegistry};
 use crate::server::workflow::tools::common::{
     build_preflight_validation_error, collect_workflow_preflight_violations, resolve_runtime_workflow,
 };
+use crate::server::workflow::tools::inputs::{apply_partial_inputs, collect_input_violations, redacted_input_map};
 use crate::server::workflow::tools::types::{
-    WorkflowPreviewRenderedRequest, WorkflowRunExecutionMode, WorkflowRunRequest, WorkflowStepPlanRequest,
+    WorkflowPreviewRenderedRequest, WorkflowRunExecutionMode, WorkflowRunRequest, WorkflowRunStatusRequest, WorkflowStepPlanRequest,
 };
 use oatty_engine::{
     ProviderBindingOutcome, RegistryCommandRunner, StepStatus, WorkflowRunState, drive_workflow_run,
@@ -29,7 +35,13 @@ use std::thread;
 use tokio::runtime::RuntimeFlavor;
 use tokio::sync::mpsc::unbounded_channel;
 
-pub fn run_workflow(request: &WorkflowRunRequest, command_registry: &Arc<Mutex<CommandRegistry>>) -> Result<Value, ErrorData> {
+pub fn run_workflow(
+    request: &WorkflowRunRequest,
+    command_registry: &Arc<Mutex<CommandRegistry>>,
+    audit_trail: &AuditTrail,
+    guard: &WorkflowRunGuard,
+    observer: WorkflowRunObserver,
+) -> Result<Value, ErrorData> {
     let runtime_workflow = resolve_runtime_workflow(
         request.workflow_id.as_deref(),
         request.manifest_content.as_deref(),
@@ -56,11 +68,11 @@ pub fn run_workflow(request: &WorkflowRunRequest, command_registry: &Arc<Mutex<C
     };
 
     let mut state = WorkflowRunState::new(runtime_workflow);
-    if let Some(inputs) = request.inputs.as_ref() {
-        for (input_name, value) in inputs {
-            state.set_input_value(input_name, value.clone());
-        }
-    }
+    let mut input_violations = request
+        .inputs
+        .as_ref()
+        .map(|inputs| apply_partial_inputs(&mut state, inputs))
+        .unwrap_or_default();
     state.apply_input_defaults();
     state.evaluate_input_providers().map_err(|error| {
         execution_error(
@@ -71,6 +83,16 @@ pub fn run_workflow(request: &WorkflowRunRequest, command_registry: &Arc<Mutex<C
             "Inspect provider bindings and input defaults, then retry.",
         )
     })?;
+    input_violations.extend(collect_input_violations(&state));
+    if !input_violations.is_empty() {
+        return Err(validation_error_with_violations(
+            "WORKFLOW_INPUT_VALIDATION_FAILED",
+            "one or more workflow inputs failed validation",
+            serde_json::json!({ "workflow_id": state.workflow.identifier }),
+            "Inspect violations and provide corrected input values.",
+            input_violations,
+        ));
+    }
 
     if let Some(blocked) = state
         .telemetry()
@@ -104,7 +126,12 @@ pub fn run_workflow(request: &WorkflowRunRequest, command_registry: &Arc<Mutex<C
             )
         })?
         .clone();
-    let runner = RegistryCommandRunner::new(registry_snapshot);
+    let runner = AuditedCommandRunner::new(
+        guard.limit_runner(RegistryCommandRunner::new(registry_snapshot.clone()), registry_snapshot.clone()),
+        registry_snapshot.clone(),
+        audit_trail.clone(),
+    )
+    .with_workflow_id(state.workflow.identifier.clone());
     let violations = collect_workflow_preflight_violations(&state.workflow, command_registry)?;
     if let Some(error) = build_preflight_validation_error(
         &state.workflow.identifier,
@@ -115,6 +142,7 @@ pub fn run_workflow(request: &WorkflowRunRequest, command_registry: &Arc<Mutex<C
     ) {
         return Err(error);
     }
+    guard.authorize_workflow(&state.workflow, &registry_snapshot)?;
 
     let run_identifier = format!("run-{}-{}", state.workflow.identifier, chrono::Utc::now().timestamp_millis());
     let engine_run_request = EngineWorkflowRunRequest {
@@ -124,7 +152,7 @@ pub fn run_workflow(request: &WorkflowRunRequest, command_registry: &Arc<Mutex<C
         environment: state.run_context.environment_variables.clone(),
         step_outputs: state.run_context.steps.clone(),
     };
-    let execution_summary = execute_workflow_via_engine_runner(engine_run_request, Arc::new(runner)).map_err(|error| {
+    let execution_summary = execute_workflow_via_engine_runner(engine_run_request, Arc::new(runner), observer).map_err(|error| {
         execution_error(
             "WORKFLOW_RUN_FAILED",
             format!("{error:#}"),
@@ -144,7 +172,7 @@ pub fn run_workflow(request: &WorkflowRunRequest, command_registry: &Arc<Mutex<C
         _ => "succeeded",
     };
     let output_map = execution_summary.output_map;
-    let input_map = state.run_context.inputs.clone();
+    let input_map = redacted_input_map(&state);
     let include_results = request.include_results.unwrap_or(true);
     let include_outputs = request.include_outputs.unwrap_or(false);
     append_history_entry(&WorkflowHistoryEntry {
@@ -152,7 +180,8 @@ pub fn run_workflow(request: &WorkflowRunRequest, command_registry: &Arc<Mutex<C
         run_id: run_identifier.clone(),
         status: run_status.to_string(),
         timestamp: chrono::Utc::now(),
-        inputs: Value::Object(input_map.iter().map(|(key, value)| (key.clone(), value.clone())).collect()),
+        inputs: Value::Object(input_map.clone()),
+        trigger: None,
     })
     .map_err(|error| {
         execution_error(
@@ -199,11 +228,54 @@ pub fn run_workflow(request: &WorkflowRunRequest, command_registry: &Arc<Mutex<C
 pub fn run_with_task_capability_guard(
     request: &WorkflowRunRequest,
     command_registry: &Arc<Mutex<CommandRegistry>>,
+    audit_trail: &AuditTrail,
+    guard: &WorkflowRunGuard,
+    observer: WorkflowRunObserver,
 ) -> Result<Value, ErrorData> {
     // This currently executes synchronously when called directly.
     // When clients invoke this tool with a `task` request envelope, rmcp's task handler enqueues
     // and tracks the execution automatically through the shared OperationProcessor.
-    run_workflow(request, command_registry)
+    run_workflow(request, command_registry, audit_trail, guard, observer)
+}
+
+/// Report the live state of a workflow run started in this session, including partial step results.
+pub fn run_status(request: &WorkflowRunStatusRequest, runs: &WorkflowRunRegistry) -> Result<Value, ErrorData> {
+    let Some(identifier) = request.run_id.as_deref().or(request.operation_id.as_deref()) else {
+        return Err(invalid_params_error(
+            "WORKFLOW_RUN_STATUS_INVALID_REQUEST",
+            "run_id or operation_id is required",
+            serde_json::json!({}),
+            "Provide the run_id from workflow_run or the task operation identifier.",
+        ));
+    };
+    let snapshot = runs.snapshot(identifier).ok_or_else(|| {
+        not_found_error(
+            "WORKFLOW_RUN_NOT_FOUND",
+            format!("workflow run '{identifier}' was not found"),
+            serde_json::json!({ "run_id": request.run_id, "operation_id": request.operation_id }),
+            "Runs are tracked per session; start the run with workflow_run in this session.",
+        )
+    })?;
+
+    let mut response = serde_json::Map::new();
+    response.insert("run_id".to_string(), serde_json::json!(snapshot.run_id));
+    response.insert("workflow_id".to_string(), serde_json::json!(snapshot.workflow_id));
+    response.insert("operation_id".to_string(), serde_json::json!(snapshot.operation_id));
+    response.insert("status".to_string(), serde_json::json!(snapshot.status));
+    response.insert("terminal".to_string(), serde_json::json!(snapshot.status.is_terminal()));
+    response.insert("current_step".to_string(), serde_json::json!(snapshot.current_step));
+    response.insert("current_attempt".to_string(), serde_json::json!(snapshot.current_attempt));
+    response.insert("completed_steps".to_string(), serde_json::json!(snapshot.step_results.len()));
+    response.insert("total_steps".to_string(), serde_json::json!(snapshot.total_steps));
+    response.insert("started_at".to_string(), serde_json::json!(snapshot.started_at));
+    response.insert("finished_at".to_string(), serde_json::json!(snapshot.finished_at));
+    if request.include_results.unwrap_or(true) {
+        response.insert(
+            "results".to_string(),
+            enrich_step_results_with_failure_reasons(&snapshot.step_results),
+        );
+    }
+    Ok(Value::Object(response))
 }
 
 fn execution_mode_label(execution_mode: WorkflowRunExecutionMode) -> &'static str {
@@ -224,11 +296,25 @@ struct WorkflowExecutionSummary {
 fn execute_workflow_via_engine_runner(
     request: EngineWorkflowRunRequest,
     runner: Arc<dyn oatty_engine::CommandRunner + Send + Sync>,
+    observer: WorkflowRunObserver,
 ) -> anyhow::Result<WorkflowExecutionSummary> {
     let initial_step_outputs = request.step_outputs.clone();
     let (event_tx, mut event_rx) = unbounded_channel();
-    let (_control_tx, control_rx) = unbounded_channel();
-    run_drive_workflow_future(request, runner, control_rx, event_tx)?;
+    let (control_tx, control_rx) = unbounded_channel();
+    let (runner_event_tx, relay_handle) = observer.relay(
+        &request.run_id,
+        &request.workflow.identifier,
+        request.workflow.steps.len(),
+        control_tx,
+        event_tx,
+    )?;
+    let drive_result = run_drive_workflow_future(request, runner, control_rx, runner_event_tx);
+    if let Some(relay_handle) = relay_handle
+        && relay_handle.join().is_err()
+    {
+        tracing::warn!("workflow run relay thread panicked");
+    }
+    drive_result?;
     Ok(collect_workflow_execution_summary(&mut event_rx, initial_step_outputs))
 }
 
@@ -328,7 +414,7 @@ fn collect_workflow_execution_summary(
     }
 }
 
-fn map_engine_step_status(step_status: EngineWorkflowRunStepStatus) -> StepStatus {
+pub(crate) fn map_engine_step_status(step_status: EngineWorkflowRunStepStatus) -> StepStatus {
     match step_status {
         EngineWorkflowRunStepStatus::Succeeded => StepStatus::Succeeded,
         EngineWorkflowRunStepStatus::Failed => StepStatus::Failed,
@@ -4,9 +4,10 @@ use crate::server::workflow::errors::{execution_error, invalid_params_error, val
 use crate::server::workflow::tools::common::resolve_runtime_workflow;
 use crate::server::workflow::tools::types::{WorkflowPreviewInputsRequest, WorkflowResolveInputsRequest};
 use oatty_engine::{ProviderBindingOutcome, WorkflowRunState};
-use oatty_types::workflow::validate_candidate_value;
+use oatty_types::workflow::RuntimeWorkflow;
 use rmcp::model::ErrorData;
 use serde_json::Value;
+use std::collections::HashMap;
 
 pub fn preview_inputs(request: &WorkflowPreviewInputsRequest) -> Result<Value, ErrorData> {
     let runtime_workflow = resolve_runtime_workflow(
@@ -27,9 +28,16 @@ pub fn preview_inputs(request: &WorkflowPreviewInputsRequest) -> Result<Value, E
     })?;
 
     let mut state = WorkflowRunState::new(runtime_workflow.clone());
+    let mut coercion_errors = HashMap::new();
     if let Some(partial_inputs) = request.partial_inputs.as_ref() {
         for (input_name, value) in partial_inputs {
-            state.set_input_value(input_name, value.clone());
+            match coerce_partial_input(&runtime_workflow, input_name, value) {
+                Ok(coerced) => state.set_input_value(input_name, coerced),
+                Err(error) => {
+                    coercion_errors.insert(input_name.clone(), error);
+                    state.set_input_value(input_name, value.clone());
+                }
+            }
         }
     }
     state.apply_input_defaults();
@@ -39,14 +47,13 @@ pub fn preview_inputs(request: &WorkflowPreviewInputsRequest) -> Result<Value, E
         .inputs
         .iter()
         .map(|(input_name, definition)| {
-            let value = state.run_context.inputs.get(input_name).cloned();
+            let value = state.run_context.inputs.get(input_name);
             let required = definition.is_required();
-            let validation_error = value.as_ref().and_then(|candidate| {
-                definition
-                    .validate
-                    .as_ref()
-                    .and_then(|validation| validate_candidate_value(candidate, validation).err().map(|error| error.to_string()))
-            });
+            let validation_error = coercion_errors
+                .get(input_name)
+                .cloned()
+                .or_else(|| value.and_then(|candidate| definition.validate_value(candidate).err().map(|error| error.to_string())));
+            let value = value.map(|candidate| definition.redact_value(candidate));
             let status = if value.is_some() {
                 "resolved"
             } else if required {
@@ -58,6 +65,7 @@ pub fn preview_inputs(request: &WorkflowPreviewInputsRequest) -> Result<Value, E
                 "input": input_name,
                 "required": required,
                 "type": definition.r#type,
+                "secret": definition.secret,
                 "description": definition.description,
                 "value": value,
                 "status": status,
@@ -102,11 +110,11 @@ pub fn resolve_inputs(request: &WorkflowResolveInputsRequest) -> Result<Value, E
     })?;
 
     let mut state = WorkflowRunState::new(runtime_workflow.clone());
-    if let Some(partial_inputs) = request.partial_inputs.as_ref() {
-        for (input_name, value) in partial_inputs {
-            state.set_input_value(input_name, value.clone());
-        }
-    }
+    let mut violations = request
+        .partial_inputs
+        .as_ref()
+        .map(|partial_inputs| apply_partial_inputs(&mut state, partial_inputs))
+        .unwrap_or_default();
     state.apply_input_defaults();
     state.evaluate_input_providers().map_err(|error| {
         execution_error(
@@ -118,20 +126,7 @@ pub fn resolve_inputs(request: &WorkflowResolveInputsRequest) -> Result<Value, E
         )
     })?;
 
-    let mut violations = Vec::new();
-    for (input_name, definition) in &runtime_workflow.inputs {
-        if let Some(validation) = definition.validate.as_ref()
-            && let Some(candidate) = state.run_context.inputs.get(input_name)
-            && let Err(error) = validate_candidate_value(candidate, validation)
-        {
-            violations.push(serde_json::json!({
-                "path": format!("inputs.{}", input_name),
-                "rule": "validation",
-                "message": error.to_string(),
-                "actual": candidate,
-            }));
-        }
-    }
+    violations.extend(collect_input_violations(&state));
     if !violations.is_empty() {
         return Err(validation_error_with_violations(
             "WORKFLOW_INPUT_VALIDATION_FAILED",
@@ -189,7 +184,7 @@ pub fn resolve_inputs(request: &WorkflowResolveInputsRequest) -> Result<Value, E
     payload.insert("required_missing".to_string(), serde_json::json!(unresolved_required));
     payload.insert("ready".to_string(), serde_json::json!(ready));
     if include_resolved_inputs {
-        payload.insert("resolved_inputs".to_string(), serde_json::json!(state.run_context.inputs));
+        payload.insert("resolved_inputs".to_string(), Value::Object(redacted_input_map(&state)));
     }
     if include_provider_resolutions {
         payload.insert("provider_resolutions".to_string(), serde_json::json!(provider_resolutions));
@@ -198,6 +193,70 @@ pub fn resolve_inputs(request: &WorkflowResolveInputsRequest) -> Result<Value, E
     Ok(Value::Object(payload))
 }
 
+/// Coerces a caller-supplied input value into the type declared by the workflow.
+///
+/// Undeclared inputs are passed through unchanged so callers can seed ad-hoc context values.
+pub(crate) fn coerce_partial_input(workflow: &RuntimeWorkflow, input_name: &str, value: &Value) -> Result<Value, String> {
+    match workflow.inputs.get(input_name) {
+        Some(definition) => definition.coerce_value(value.clone()).map_err(|error| error.to_string()),
+        None => Ok(value.clone()),
+    }
+}
+
+/// Sets caller-supplied inputs on the run state, returning a `type` violation for each value that
+/// cannot be coerced into its declared type.
+pub(crate) fn apply_partial_inputs(state: &mut WorkflowRunState, partial_inputs: &HashMap<String, Value>) -> Vec<Value> {
+    let mut violations = Vec::new();
+    for (input_name, value) in partial_inputs {
+        match coerce_partial_input(&state.workflow, input_name, value) {
+            Ok(coerced) => state.set_input_value(input_name, coerced),
+            Err(error) => violations.push(serde_json::json!({
+                "path": format!("inputs.{}", input_name),
+                "rule": "type",
+                "message": error,
+                "actual": redact_partial_input(&state.workflow, input_name, value),
+            })),
+        }
+    }
+    violations
+}
+
+/// Validates every populated input against its declared type and `validate` rules.
+pub(crate) fn collect_input_violations(state: &WorkflowRunState) -> Vec<Value> {
+    state
+        .workflow
+        .inputs
+        .iter()
+        .filter_map(|(input_name, definition)| {
+            let candidate = state.run_context.inputs.get(input_name)?;
+            let error = definition.validate_value(candidate).err()?;
+            Some(serde_json::json!({
+                "path": format!("inputs.{}", input_name),
+                "rule": "validation",
+                "message": error.to_string(),
+                "actual": definition.redact_value(candidate),
+            }))
+        })
+        .collect()
+}
+
+/// Returns the input values with secret inputs masked, suitable for responses and run history.
+pub(crate) fn redacted_input_map(state: &WorkflowRunState) -> serde_json::Map<String, Value> {
+    state
+        .run_context
+        .inputs
+        .iter()
+        .map(|(input_name, value)| (input_name.clone(), redact_partial_input(&state.workflow, input_name, value)))
+        .collect()
+}
+
+fn redact_partial_input(workflow: &RuntimeWorkflow, input_name: &str, value: &Value) -> Value {
+    match workflow.inputs.get(input_name) {
+        Some(definition) => definition.redact_value(value),
+        None => value.clone(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -320,4 +379,64 @@ steps:
         assert_eq!(value["ready"], false);
         assert_eq!(value["provider_resolutions"][0]["outcome"]["status"], "prompt");
     }
+
+    #[test]
+    fn resolve_inputs_coerces_typed_values_and_masks_secrets() {
+        let manifest = r#"
+workflow: typed
+inputs:
+  replicas:
+    type: integer
+    validate:
+      minimum: 1
+  token:
+    secret: true
+steps:
+  - id: scale
+    run: apps:scale
+"#;
+        let mut partial_inputs = std::collections::HashMap::new();
+        partial_inputs.insert("replicas".to_string(), serde_json::json!("3"));
+        partial_inputs.insert("token".to_string(), serde_json::json!("abc123"));
+        let request = WorkflowResolveInputsRequest {
+            workflow_id: None,
+            manifest_content: Some(manifest.to_string()),
+            format: Some("yaml".to_string()),
+            partial_inputs: Some(partial_inputs),
+            include_resolved_inputs: Some(true),
+            include_provider_resolutions: None,
+        };
+        let value = resolve_inputs(&request).expect("resolve inputs should succeed");
+        assert_eq!(value["resolved_inputs"]["replicas"], serde_json::json!(3));
+        assert_eq!(
+            value["resolved_inputs"]["token"],
+            serde_json::json!(oatty_types::workflow::SECRET_INPUT_MASK)
+        );
+    }
+
+    #[test]
+    fn resolve_inputs_reports_type_coercion_failures() {
+        let manifest = r#"
+workflow: typed
+inputs:
+  replicas:
+    type: integer
+steps:
+  - id: scale
+    run: apps:scale
+"#;
+        let mut partial_inputs = std::collections::HashMap::new();
+        partial_inputs.insert("replicas".to_string(), serde_json::json!("many"));
+        let request = WorkflowResolveInputsRequest {
+            workflow_id: None,
+            manifest_content: Some(manifest.to_string()),
+            format: Some("yaml".to_string()),
+            partial_inputs: Some(partial_inputs),
+            include_resolved_inputs: None,
+            include_provider_resolutions: None,
+        };
+        let error = resolve_inputs(&request).expect_err("coercion failure should be reported");
+        let data = error.data.expect("error data");
+        assert_eq!(data["violations"][0]["rule"], "type");
+    }
 }
@@ -8,7 +8,7 @@ pub mod manifest;
 pub mod orchestration;
 pub mod types;
 
-pub use execution::{preview_rendered, run_with_task_capability_guard, step_plan};
+pub use execution::{preview_rendered, run_status, run_with_task_capability_guard, step_plan};
 pub use history::purge_workflow_history;
 pub use inputs::{preview_inputs, resolve_inputs};
 pub use manifest::{
@@ -1,6 +1,9 @@
 //! Higher-level workflow orchestration MCP tools.
 
+use crate::logging::AuditTrail;
 use crate::server::workflow::errors::{invalid_params_error, validation_error_with_violations};
+use crate::server::workflow::services::guard::WorkflowRunGuard;
+use crate::server::workflow::services::runs::WorkflowRunObserver;
 use crate::server::workflow::tools::execution::run_workflow;
 use crate::server::workflow::tools::inputs::resolve_inputs;
 use crate::server::workflow::tools::manifest::{save_workflow, validate_workflow};
@@ -14,13 +17,19 @@ use serde_json::Value;
 use std::sync::{Arc, Mutex};
 
 /// Save, resolve inputs, and execute a workflow from a draft manifest.
-pub fn author_and_run(request: &WorkflowAuthorAndRunRequest, command_registry: &Arc<Mutex<CommandRegistry>>) -> Result<Value, ErrorData> {
+pub fn author_and_run(
+    request: &WorkflowAuthorAndRunRequest,
+    command_registry: &Arc<Mutex<CommandRegistry>>,
+    audit_trail: &AuditTrail,
+    guard: &WorkflowRunGuard,
+) -> Result<Value, ErrorData> {
     let validation = validate_workflow(
         &WorkflowValidateRequest {
             workflow_id: None,
             manifest_content: Some(request.manifest_content.clone()),
             input_path: None,
             format: request.format.clone(),
+            lint_format: None,
         },
         command_registry,
     )?;
@@ -149,6 +158,9 @@ pub fn author_and_run(request: &WorkflowAuthorAndRunRequest, command_registry: &
             include_outputs: None,
         },
         command_registry,
+        audit_trail,
+        guard,
+        WorkflowRunObserver::default(),
     )?;
 
     Ok(serde_json::json!({
@@ -164,6 +176,8 @@ pub fn author_and_run(request: &WorkflowAuthorAndRunRequest, command_registry: &
 pub fn repair_and_rerun(
     request: &WorkflowRepairAndRerunRequest,
     command_registry: &Arc<Mutex<CommandRegistry>>,
+    audit_trail: &AuditTrail,
+    guard: &WorkflowRunGuard,
 ) -> Result<Value, ErrorData> {
     let manifest_content = request
         .repaired_manifest_content
@@ -179,7 +193,7 @@ pub fn repair_and_rerun(
         expected_version: request.expected_version.clone(),
         inputs: request.inputs.clone(),
     };
-    let result = author_and_run(&author_request, command_registry)?;
+    let result = author_and_run(&author_request, command_registry, audit_trail, guard)?;
 
     Ok(serde_json::json!({
         "repaired": request.repaired_manifest_content.is_some(),
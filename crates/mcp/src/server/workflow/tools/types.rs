@@ -68,6 +68,9 @@ pub struct WorkflowValidateRequest {
     pub input_path: Option<String>,
     #[schemars(description = "Optional format hint: yaml or json.")]
     pub format: Option<String>,
+    #[schemars(description = "Optional lint output format: json (default) or sarif. sarif adds a SARIF 2.1.0 log as lint_sarif.")]
+    #[serde(default)]
+    pub lint_format: Option<String>,
 }
 
 #[derive(JsonSchema, Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -107,6 +110,16 @@ pub struct WorkflowCancelRequest {
     pub operation_id: String,
 }
 
+#[derive(JsonSchema, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowRunStatusRequest {
+    #[schemars(description = "Run identifier returned by workflow_run or announced in its first progress notification.")]
+    pub run_id: Option<String>,
+    #[schemars(description = "Task operation identifier (the request id of the workflow_run call).")]
+    pub operation_id: Option<String>,
+    #[schemars(description = "Include results for steps finished so far (default true).")]
+    pub include_results: Option<bool>,
+}
+
 #[derive(JsonSchema, Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct WorkflowRunRequest {
     #[schemars(description = "Optional existing workflow identifier.")]
@@ -0,0 +1,116 @@
+//! Resources, resource templates, and prompts published by an MCP plugin.
+//!
+//! Like [`super::tools`], this module converts `rmcp` models into the serde-friendly summaries from
+//! `oatty_types` so the engine and TUI can work with plugin content without depending on `rmcp`.
+
+use oatty_types::plugin::{PluginPromptArgument, PluginPromptSummary, PluginResourceSummary, PluginResourceTemplateSummary};
+use rmcp::model::{Prompt, Resource, ResourceTemplate};
+
+/// Snapshot of the non-tool capabilities a plugin exposes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct McpPluginContent {
+    /// Concrete resources returned by `resources/list`.
+    pub resources: Vec<PluginResourceSummary>,
+    /// Parameterized resources returned by `resources/templates/list`.
+    pub resource_templates: Vec<PluginResourceTemplateSummary>,
+    /// Prompt templates returned by `prompts/list`.
+    pub prompts: Vec<PluginPromptSummary>,
+}
+
+impl McpPluginContent {
+    /// Build a snapshot from raw `rmcp` listings.
+    pub fn from_listings(resources: Vec<Resource>, resource_templates: Vec<ResourceTemplate>, prompts: Vec<Prompt>) -> Self {
+        Self {
+            resources: resources.iter().map(resource_summary).collect(),
+            resource_templates: resource_templates.iter().map(resource_template_summary).collect(),
+            prompts: prompts.iter().map(prompt_summary).collect(),
+        }
+    }
+
+    /// Whether the plugin exposes anything that can be read through `resources/read`.
+    pub fn has_readable_resources(&self) -> bool {
+        !self.resources.is_empty() || !self.resource_templates.is_empty()
+    }
+
+    /// Whether the snapshot contains no resources, templates, or prompts.
+    pub fn is_empty(&self) -> bool {
+        !self.has_readable_resources() && self.prompts.is_empty()
+    }
+}
+
+fn resource_summary(resource: &Resource) -> PluginResourceSummary {
+    PluginResourceSummary {
+        uri: resource.raw.uri.clone(),
+        name: resource.raw.name.clone(),
+        title: resource.raw.title.clone(),
+        description: resource.raw.description.clone(),
+        mime_type: resource.raw.mime_type.clone(),
+        updated_at: None,
+    }
+}
+
+fn resource_template_summary(template: &ResourceTemplate) -> PluginResourceTemplateSummary {
+    PluginResourceTemplateSummary {
+        uri_template: template.raw.uri_template.clone(),
+        name: template.raw.name.clone(),
+        description: template.raw.description.clone(),
+        mime_type: template.raw.mime_type.clone(),
+    }
+}
+
+fn prompt_summary(prompt: &Prompt) -> PluginPromptSummary {
+    PluginPromptSummary {
+        name: prompt.name.clone(),
+        title: prompt.title.clone(),
+        description: prompt.description.clone(),
+        arguments: prompt
+            .arguments
+            .iter()
+            .flatten()
+            .map(|argument| PluginPromptArgument {
+                name: argument.name.clone(),
+                description: argument.description.clone(),
+                required: argument.required.unwrap_or(false),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::{AnnotateAble, PromptArgument, RawResource, RawResourceTemplate};
+
+    #[test]
+    fn listings_convert_to_summaries() {
+        let resource = RawResource::new("file:///notes.md", "notes").no_annotation();
+        let template = RawResourceTemplate {
+            uri_template: "db://tables/{table}".to_string(),
+            name: "table".to_string(),
+            title: None,
+            description: Some("Table rows".to_string()),
+            mime_type: Some("application/json".to_string()),
+            icons: None,
+        }
+        .no_annotation();
+        let prompt = Prompt::new(
+            "summarize",
+            Some("Summarize a document"),
+            Some(vec![PromptArgument {
+                name: "topic".to_string(),
+                title: None,
+                description: None,
+                required: Some(true),
+            }]),
+        );
+
+        let content = McpPluginContent::from_listings(vec![resource], vec![template], vec![prompt]);
+
+        assert_eq!(content.resources[0].uri, "file:///notes.md");
+        assert_eq!(content.resource_templates[0].uri_template, "db://tables/{table}");
+        assert_eq!(content.prompts[0].arguments[0].name, "topic");
+        assert!(content.prompts[0].arguments[0].required);
+        assert!(content.has_readable_resources());
+        assert!(McpPluginContent::default().is_empty());
+    }
+}
@@ -1,11 +1,13 @@
 //! Core types for MCP plugin management.
 
+pub mod content;
 pub mod errors;
 pub mod tools;
 
+pub use content::McpPluginContent;
 pub use errors::{LogError, McpError, PluginError};
 pub use oatty_types::plugin::{
-    AuthStatus, EnvSource, EnvVar, HealthStatus, LogLevel, LogSource, McpLogEntry, PluginDetail, PluginStatus, PluginToolSummary,
-    TransportStatus,
+    AuthStatus, EnvSource, EnvVar, HealthStatus, LogLevel, LogSource, McpLogEntry, PluginDetail, PluginPromptArgument, PluginPromptSummary,
+    PluginResourceSummary, PluginResourceTemplateSummary, PluginStatus, PluginToolSummary, TransportStatus,
 };
 pub use tools::McpToolMetadata;
@@ -316,6 +316,7 @@ fn build_flag_from_parameter(document: &Value, parameter: &Value) -> Option<Comm
         default_value,
         description,
         provider: None,
+        schema: None,
     })
 }
 
@@ -349,6 +350,7 @@ fn build_flags_from_request_body(document: &Value, operation: &Map<String, Value
             let default_value = get_default(&merged_property, document).or_else(|| enum_values.first().cloned());
             let description = get_description(&merged_property, document);
             let is_required = required_names.contains(&name);
+            let schema = structured_value_schema(&schema_type, &value, document);
 
             flags.push(CommandFlag {
                 name,
@@ -359,6 +361,7 @@ fn build_flags_from_request_body(document: &Value, operation: &Map<String, Value
                 default_value,
                 description,
                 provider: None,
+                schema,
             });
         }
     } else {
@@ -367,6 +370,7 @@ fn build_flags_from_request_body(document: &Value, operation: &Map<String, Value
         let default_value = get_default(&merged_schema, document).or_else(|| enum_values.first().cloned());
         let description = get_description(&merged_schema, document);
         let is_required = request_body.get("required").and_then(Value::as_bool).unwrap_or(false);
+        let schema = structured_value_schema(&schema_type, schema, document);
 
         flags.push(CommandFlag {
             name: "body".to_string(),
@@ -377,12 +381,20 @@ fn build_flags_from_request_body(document: &Value, operation: &Map<String, Value
             default_value,
             description,
             provider: None,
+            schema,
         });
     }
 
     flags
 }
 
+/// Resolves the schema of an `object` or `array` body value so the TUI can scaffold it.
+fn structured_value_schema(schema_type: &str, schema: &Value, document: &Value) -> Option<SchemaProperty> {
+    matches!(schema_type, "object" | "array")
+        .then(|| resolve_output_schema(Some(schema), document))
+        .flatten()
+}
+
 fn build_path_template_and_positionals(path: &str, parameters: &[Value], document: &Value) -> (String, Vec<PositionalArgument>) {
     let mut parameter_descriptions: HashMap<String, Option<String>> = HashMap::new();
     for parameter in parameters {
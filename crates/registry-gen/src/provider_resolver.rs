@@ -667,6 +667,7 @@ mod tests {
             default_value: None,
             description: None,
             provider: None,
+            schema: None,
         }
     }
 
@@ -7,6 +7,11 @@ use clap::{Arg, ArgAction, Command as ClapCommand};
 
 use crate::{CommandFlag, CommandRegistry, CommandSpec};
 
+/// Argument id of the per-command `--watch <interval>` flag.
+pub const WATCH_ARG_ID: &str = "oatty-watch";
+/// Argument id of the per-command `--until <condition>` flag.
+pub const UNTIL_ARG_ID: &str = "oatty-until";
+
 /// Builds a complete Clap command tree from the registry's command
 /// specifications.
 ///
@@ -53,7 +58,11 @@ pub fn build_clap(registry: Arc<Mutex<CommandRegistry>>) -> ClapCommand {
     }
 
     root = root.subcommand(build_workflow_root_command());
+    root = root.subcommand(build_scheduler_root_command());
+    root = root.subcommand(build_audit_root_command());
+    root = root.subcommand(build_mcp_root_command());
     root = root.subcommand(build_import_root_command());
+    root = root.subcommand(build_diff_root_command());
     root.subcommand(build_update_root_command())
 }
 
@@ -245,11 +254,178 @@ fn build_workflow_root_command() -> ClapCommand {
                 .action(ArgAction::Append),
         );
 
+    let test_cmd = ClapCommand::new("test")
+        .about("Run a workflow test suite with stubbed command outputs")
+        .arg(
+            Arg::new("suite")
+                .value_name("FILE")
+                .required(true)
+                .help("Path to a YAML or JSON workflow test suite"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .value_parser(["human", "json", "junit"])
+                .default_value("human")
+                .help("Report format"),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .value_name("PATH")
+                .help("Write the report to a file instead of stdout"),
+        );
+
+    let lint_cmd = ClapCommand::new("lint")
+        .about("Lint workflow manifests against authoring rules")
+        .arg(
+            Arg::new("files")
+                .value_name("FILE")
+                .num_args(0..)
+                .help("Workflow manifests to lint (defaults to every registry workflow)"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .value_parser(["human", "json", "sarif"])
+                .default_value("human")
+                .help("Report format"),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .value_name("PATH")
+                .help("Write the report to a file instead of stdout"),
+        );
+
+    let graph_cmd = ClapCommand::new("graph")
+        .about("Export a workflow's step dependency graph")
+        .arg(
+            Arg::new("id")
+                .long("id")
+                .short('i')
+                .value_name("WORKFLOW_ID")
+                .required_unless_present("file")
+                .help("Identifier for a workflow bundled in the registry"),
+        )
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .value_name("PATH")
+                .help("Read the workflow definition from a file")
+                .conflicts_with("id"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .value_parser(["mermaid", "dot"])
+                .default_value("mermaid")
+                .help("Graph format"),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .value_name("PATH")
+                .help("Write the graph to a file instead of stdout"),
+        );
+
     ClapCommand::new("workflow")
         .about("Workflow utilities")
         .subcommand(list_cmd)
         .subcommand(preview_cmd)
         .subcommand(run_cmd)
+        .subcommand(test_cmd)
+        .subcommand(lint_cmd)
+        .subcommand(graph_cmd)
+}
+
+fn build_scheduler_root_command() -> ClapCommand {
+    let run_cmd = ClapCommand::new("run")
+        .about("Run scheduled workflows in the foreground until interrupted")
+        .arg(
+            Arg::new("workflow")
+                .long("workflow")
+                .short('w')
+                .value_name("WORKFLOW_ID")
+                .help("Only run schedules for the given workflow (repeatable)")
+                .action(ArgAction::Append),
+        );
+
+    ClapCommand::new("scheduler")
+        .about("Run workflows on their declared cron schedules")
+        .subcommand_required(true)
+        .subcommand(run_cmd)
+}
+
+fn build_audit_root_command() -> ClapCommand {
+    let query_cmd = ClapCommand::new("query")
+        .about("List audited tool calls, command executions, and workflow steps")
+        .arg(
+            Arg::new("since")
+                .long("since")
+                .value_name("AGE_OR_TIMESTAMP")
+                .help("Only entries newer than a duration (15m, 2h, 7d) or an RFC 3339 timestamp"),
+        )
+        .arg(
+            Arg::new("catalog")
+                .long("catalog")
+                .value_name("TITLE")
+                .help("Only entries for a catalog title or MCP plugin name"),
+        )
+        .arg(
+            Arg::new("status")
+                .long("status")
+                .value_name("STATUS")
+                .help("Only entries with an outcome (success, failure, denied), HTTP status (404), or class (5xx)"),
+        )
+        .arg(
+            Arg::new("limit")
+                .long("limit")
+                .short('n')
+                .value_name("COUNT")
+                .value_parser(clap::value_parser!(usize))
+                .default_value("100")
+                .help("Maximum number of most recent entries to show"),
+        );
+
+    ClapCommand::new("audit")
+        .about("Inspect the audit trail")
+        .subcommand_required(true)
+        .subcommand(query_cmd)
+}
+
+fn build_mcp_root_command() -> ClapCommand {
+    let client_name_arg = || {
+        Arg::new("name")
+            .required(true)
+            .value_name("CLIENT")
+            .help("Client name (lowercase letters, digits, '.', '_', '-')")
+    };
+    let token_cmd = ClapCommand::new("token")
+        .about("Manage bearer tokens for clients of the MCP HTTP server")
+        .subcommand_required(true)
+        .subcommand(
+            ClapCommand::new("create")
+                .about("Issue a token for a client; it is printed once and never stored in clear text")
+                .arg(client_name_arg()),
+        )
+        .subcommand(
+            ClapCommand::new("revoke")
+                .about("Revoke a client's token; a running server rejects it immediately")
+                .arg(client_name_arg()),
+        )
+        .subcommand(ClapCommand::new("list").about("List clients that hold a token"));
+
+    ClapCommand::new("mcp")
+        .about("Configure the Oatty MCP HTTP server")
+        .subcommand_required(true)
+        .subcommand(token_cmd)
 }
 
 fn build_import_root_command() -> ClapCommand {
@@ -307,6 +483,38 @@ fn build_import_root_command() -> ClapCommand {
         )
 }
 
+fn build_diff_root_command() -> ClapCommand {
+    ClapCommand::new("diff")
+        .about("Run a GET command against two targets and print a structural diff of the results")
+        .arg(
+            Arg::new("left")
+                .long("left")
+                .value_name("TARGET")
+                .help("One of the catalog's base URLs, or its index, for the left side (default: selected base URL)"),
+        )
+        .arg(
+            Arg::new("right")
+                .long("right")
+                .value_name("TARGET")
+                .help("One of the catalog's base URLs, or its index, for the right side (default: selected base URL)"),
+        )
+        .arg(
+            Arg::new("key")
+                .long("key")
+                .value_name("FIELD")
+                .help("Field used to align array elements (default: an id-like field present in both results)"),
+        )
+        .arg(
+            Arg::new("command")
+                .required(true)
+                .num_args(1..)
+                .trailing_var_arg(true)
+                .allow_hyphen_values(true)
+                .value_name("COMMAND")
+                .help("Catalog command and its arguments, e.g. `apps list --limit 5`"),
+        )
+}
+
 fn build_update_root_command() -> ClapCommand {
     ClapCommand::new("update")
         .about("Update the oatty binary")
@@ -363,7 +571,33 @@ fn build_subcommand(cmd: &CommandSpec) -> ClapCommand {
     // Add flags
     subcommand = add_flags(subcommand, cmd);
 
+    add_watch_arguments(subcommand, cmd)
+}
+
+/// Adds the `--watch` and `--until` flags to read-only catalog commands.
+///
+/// Only HTTP GET commands are watchable, so repeating a run never repeats a
+/// write. Commands whose specification already defines a `watch` or `until`
+/// flag keep their own meaning and do not get watch mode.
+fn add_watch_arguments(subcommand: ClapCommand, cmd: &CommandSpec) -> ClapCommand {
+    let is_read_only = cmd.http().is_some_and(|http| http.method.eq_ignore_ascii_case("GET"));
+    if !is_read_only || cmd.flags.iter().any(|flag| flag.name == "watch" || flag.name == "until") {
+        return subcommand;
+    }
     subcommand
+        .arg(
+            Arg::new(WATCH_ARG_ID)
+                .long("watch")
+                .value_name("INTERVAL")
+                .help("Re-run the command on an interval (e.g. 5s, 1m) and report changes"),
+        )
+        .arg(
+            Arg::new(UNTIL_ARG_ID)
+                .long("until")
+                .value_name("CONDITION")
+                .requires(WATCH_ARG_ID)
+                .help("Stop watching once the condition holds (e.g. 'steps.result.status == \"succeeded\"')"),
+        )
 }
 
 /// Adds positional arguments to a subcommand.
@@ -591,7 +825,9 @@ fn generate_help_text(flag: &CommandFlag) -> String {
 mod tests {
     use oatty_types::{CommandExecution, command::HttpCommandSpec};
 
-    use super::{build_canonical_identifier_help, build_import_root_command};
+    use super::{
+        UNTIL_ARG_ID, WATCH_ARG_ID, build_canonical_identifier_help, build_diff_root_command, build_import_root_command, build_subcommand,
+    };
     use crate::CommandSpec;
 
     #[test]
@@ -645,4 +881,54 @@ mod tests {
         assert!(argument_ids.contains(&"overwrite"));
         assert!(argument_ids.contains(&"disabled"));
     }
+
+    #[test]
+    fn catalog_commands_accept_watch_and_until() {
+        let command_spec = CommandSpec {
+            group: "jobs".to_string(),
+            name: "info".to_string(),
+            summary: String::new(),
+            positional_args: Vec::new(),
+            flags: Vec::new(),
+            catalog_identifier: 0,
+            execution: CommandExecution::Http(HttpCommandSpec::new("GET", "/jobs", None, None)),
+        };
+        let matches = build_subcommand(&command_spec)
+            .try_get_matches_from(["info", "--watch", "5s", "--until", "steps.result.done"])
+            .expect("watch flags parse");
+        assert_eq!(matches.get_one::<String>(WATCH_ARG_ID).map(String::as_str), Some("5s"));
+        assert_eq!(
+            matches.get_one::<String>(UNTIL_ARG_ID).map(String::as_str),
+            Some("steps.result.done")
+        );
+
+        let without_watch = build_subcommand(&command_spec).try_get_matches_from(["info", "--until", "steps.result.done"]);
+        assert!(without_watch.is_err());
+    }
+
+    #[test]
+    fn write_commands_do_not_accept_watch() {
+        let command_spec = CommandSpec {
+            group: "jobs".to_string(),
+            name: "cancel".to_string(),
+            summary: String::new(),
+            positional_args: Vec::new(),
+            flags: Vec::new(),
+            catalog_identifier: 0,
+            execution: CommandExecution::Http(HttpCommandSpec::new("DELETE", "/jobs", None, None)),
+        };
+        let matches = build_subcommand(&command_spec).try_get_matches_from(["cancel", "--watch", "5s"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn diff_command_keeps_trailing_command_arguments() {
+        let matches = build_diff_root_command()
+            .try_get_matches_from(["diff", "--left", "0", "--key", "id", "apps", "list", "--limit", "5"])
+            .expect("diff arguments parse");
+        assert_eq!(matches.get_one::<String>("left").map(String::as_str), Some("0"));
+        assert_eq!(matches.get_one::<String>("key").map(String::as_str), Some("id"));
+        let command: Vec<&str> = matches.get_many::<String>("command").unwrap().map(String::as_str).collect();
+        assert_eq!(command, vec!["apps", "list", "--limit", "5"]);
+    }
 }
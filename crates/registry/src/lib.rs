@@ -18,7 +18,9 @@ pub use catalog_patch::{
 };
 pub use clap_builder::build_clap;
 pub use config::*;
-pub use models::{CatalogHeaderEditMode, CatalogHeaderEditRow, CatalogMutationError, CatalogMutationResult, CommandRegistry};
+pub use models::{
+    CatalogHeaderEditMode, CatalogHeaderEditRow, CatalogMutationError, CatalogMutationResult, CommandRegistry, CommandRegistryEvent,
+};
 pub use oatty_types::{
     CommandFlag, CommandSpec, ProviderArgumentContract, ProviderContract, ProviderFieldContract, ProviderReturnContract,
 };
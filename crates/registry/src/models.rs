@@ -294,6 +294,12 @@ impl CommandRegistry {
         }
     }
 
+    fn notify_catalog_changed(&self, title: &str) {
+        if let Some(tx) = self.event_tx.as_ref() {
+            let _ = tx.send(CommandRegistryEvent::CatalogChanged(title.to_string()));
+        }
+    }
+
     /// Inserts a catalog into the registry
     pub fn insert_catalog(&mut self, mut catalog: RegistryCatalog) -> Result<()> {
         let catalogs = self.config.catalogs.get_or_insert(Vec::with_capacity(1));
@@ -301,6 +307,7 @@ impl CommandRegistry {
         if catalogs.iter().any(|c| c.title == catalog.title) {
             return Err(anyhow!("Catalog already exists"));
         }
+        let title = catalog.title.clone();
         let catalog_identifier = catalogs.len();
         if catalog.is_enabled
             && let Some(manifest) = catalog.manifest.as_ref()
@@ -325,6 +332,7 @@ impl CommandRegistry {
             .as_mut()
             .ok_or_else(|| anyhow!("expected a catalog to extend but found none"))?
             .push(catalog);
+        self.notify_catalog_changed(&title);
         Ok(())
     }
 
@@ -340,6 +348,7 @@ impl CommandRegistry {
             if std::fs::exists(manifest_path).is_ok() {
                 std::fs::remove_file(manifest_path)?;
             }
+            self.notify_catalog_changed(catalog_title);
             Ok(())
         } else {
             Err(anyhow!("Catalog not found"))
@@ -366,6 +375,7 @@ impl CommandRegistry {
             .unwrap_or_default();
 
         self.remove_commands(command_ids);
+        self.notify_catalog_changed(catalog_title);
         Ok(())
     }
 
@@ -394,6 +404,7 @@ impl CommandRegistry {
             self.provider_contracts.extend(provider_contracts_to_insert);
             sort_and_dedup_commands(&mut self.commands);
         }
+        self.notify_catalog_changed(catalog_identifier);
         Ok(())
     }
 
@@ -402,6 +413,7 @@ impl CommandRegistry {
 
         if let Some(index) = catalogs.iter().position(|c| c.title == title) {
             catalogs[index].base_url_index = base_url_index;
+            self.notify_catalog_changed(title);
             Ok(())
         } else {
             Err(anyhow!("Catalog not found"))
@@ -413,6 +425,7 @@ impl CommandRegistry {
 
         if let Some(index) = catalogs.iter().position(|c| c.title == title) {
             catalogs[index].description = description;
+            self.notify_catalog_changed(title);
             Ok(())
         } else {
             Err(anyhow!("Catalog not found"))
@@ -424,6 +437,7 @@ impl CommandRegistry {
 
         if let Some(index) = catalogs.iter().position(|c| c.title == title) {
             catalogs[index].base_urls = base_urls;
+            self.notify_catalog_changed(title);
             Ok(())
         } else {
             Err(anyhow!("Catalog not found"))
@@ -435,6 +449,7 @@ impl CommandRegistry {
 
         if let Some(index) = catalogs.iter().position(|c| c.title == title) {
             catalogs[index].headers = headers;
+            self.notify_catalog_changed(title);
             Ok(())
         } else {
             Err(anyhow!("Catalog not found"))
@@ -461,6 +476,7 @@ impl CommandRegistry {
                 catalog.base_urls.len().saturating_sub(1)
             });
         catalog.base_url_index = selected_index;
+        self.notify_catalog_changed(title);
         Ok(())
     }
 
@@ -481,6 +497,7 @@ impl CommandRegistry {
             CatalogHeaderEditMode::Remove => apply_header_removals(&catalog.headers, rows),
             CatalogHeaderEditMode::ReplaceAll => build_headers_from_rows(rows)?,
         };
+        self.notify_catalog_changed(title);
         Ok(())
     }
 }
@@ -550,6 +567,8 @@ pub enum CommandRegistryEvent {
     CommandsRemoved(Arc<[CommandSpec]>),
     WorkflowsAdded(Arc<[WorkflowDefinition]>),
     WorkflowsRemoved(Arc<[WorkflowDefinition]>),
+    /// A catalog was added, removed, enabled, disabled, or had its settings edited; carries its title.
+    CatalogChanged(String),
 }
 
 #[cfg(test)]
@@ -641,4 +660,21 @@ mod tests {
         assert_eq!(header.key, "authorization");
         assert_eq!(header.value, "Bearer second");
     }
+
+    #[test]
+    fn catalog_mutations_broadcast_catalog_changed_events() {
+        let mut registry = CommandRegistry::default();
+        registry.config.catalogs = Some(vec![catalog_with_title("alpha")]);
+        let mut receiver = registry.subscribe();
+
+        registry
+            .set_selected_base_url("alpha", "https://api.example.com")
+            .expect("base url update should succeed");
+        registry.disable_catalog("alpha").expect("disable should succeed");
+
+        assert!(matches!(receiver.try_recv(), Ok(CommandRegistryEvent::CatalogChanged(title)) if title == "alpha"));
+        assert!(matches!(receiver.try_recv(), Ok(CommandRegistryEvent::CommandsRemoved(_))));
+        assert!(matches!(receiver.try_recv(), Ok(CommandRegistryEvent::CatalogChanged(title)) if title == "alpha"));
+        assert!(receiver.try_recv().is_err());
+    }
 }
@@ -5,41 +5,46 @@
 //! user interactions, and coordinates between different UI components.
 
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     rc::Rc,
     sync::{Arc, Mutex, atomic::AtomicUsize},
     time::Duration,
 };
 
 use crate::log_persistence::PersistentLogWorker;
+use crate::ui::components::common::{ConfirmationModalButton, ConfirmationModalOpts};
 use crate::ui::components::workflows::run::RunViewState;
 use crate::ui::components::{FilePickerState, common::ConfirmationModalState};
-use crate::ui::components::{LibraryState, nav_bar::VerticalNavBarState};
+use crate::ui::components::{HistoryState, LibraryState, nav_bar::VerticalNavBarState};
 use crate::ui::components::{
     common::manual_entry_modal::state::ManualEntryState,
     workflows::collector::{CollectorApplyTarget, CollectorSelectionSource, SelectorStatus},
 };
-use crate::ui::theme::Theme;
+use crate::ui::keymap::Keymap;
+use crate::ui::theme::theme_helpers::ButtonType;
+use crate::ui::theme::{CustomThemes, Theme};
 use crate::ui::{
     components::{
-        browser::BrowserState, help::HelpState, logs::LogsState, mcp_server::McpHttpServerState, palette::PaletteState,
-        plugins::PluginsState, results::ResultsTableState, theme_picker::ThemePickerState, workflows::WorkflowState,
+        browser::BrowserState, diff::DiffState, help::HelpState, json_editor::JsonEditorState, logs::LogsState,
+        mcp_server::McpHttpServerState, palette::PaletteState, plugins::PluginsState, results::ResultsWorkspace,
+        theme_picker::ThemePickerState, workflows::WorkflowState,
     },
     theme,
 };
 use oatty_engine::provider::{CacheLookupOutcome, PendingProviderFetch, ProviderRegistry};
 use oatty_engine::{RegistryCommandRunner, ValueProvider, WorkflowRunState};
-use oatty_mcp::{McpHttpLogEntry, PluginEngine, RunningMcpHttpServer};
+use oatty_mcp::{McpConfirmationRequest, McpHttpLogEntry, PluginEngine, RunningMcpHttpServer, provider::McpProviderFetcher};
 use oatty_registry::CommandRegistry;
 use oatty_types::workflow::WorkflowStepDefinition;
-use oatty_types::{Effect, LogLevel, Modal, Msg, Route, WorkflowRunEvent, WorkflowRunRequest, WorkflowRunStatus, validate_candidate_value};
+use oatty_types::{Effect, LogLevel, MessageType, Modal, Msg, Route, WorkflowRunEvent, WorkflowRunRequest, WorkflowRunStatus};
 use oatty_util::{
-    DEFAULT_HISTORY_PROFILE, HistoryKey, HistoryStore, InMemoryHistoryStore, JsonHistoryStore, UserPreferences, has_meaningful_value,
-    value_contains_secret, workflow_input_uses_history,
+    DEFAULT_HISTORY_PROFILE, ExecutionJournal, HistoryKey, HistoryStore, InMemoryExecutionJournal, InMemoryHistoryStore, JsonHistoryStore,
+    JsonlExecutionJournal, UserPreferences, has_meaningful_value, value_contains_secret, workflow_input_uses_history,
 };
 use rat_focus::{Focus, FocusBuilder, FocusFlag, HasFocus};
 use ratatui::layout::Rect;
 use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::task::AbortHandle;
 use tracing::warn;
 
 /// Cross-cutting shared context owned by the App.
@@ -62,12 +67,18 @@ pub struct SharedCtx {
     pub history_store: Arc<dyn HistoryStore>,
     /// Identifier representing the active history profile.
     pub history_profile_id: String,
+    /// On-disk journal of palette command executions backing the History route.
+    pub execution_journal: Arc<dyn ExecutionJournal>,
     /// Persisted user preferences (theme picker, appearance decisions, etc.).
     pub preferences: Arc<UserPreferences>,
+    /// User-defined themes discovered in the config directory.
+    pub custom_themes: CustomThemes,
     /// Canonical identifier for the currently loaded theme.
     pub active_theme_id: String,
     /// Whether the runtime can show the theme picker (truecolor terminals only).
     pub theme_picker_available: bool,
+    /// Key bindings from the active preset plus the user's keymap file.
+    pub keymap: Keymap,
     /// Product name derived from the registry's base URL metadata.
     pub product_name: String,
 }
@@ -75,7 +86,12 @@ pub struct SharedCtx {
 impl SharedCtx {
     pub fn new(command_registry: Arc<Mutex<CommandRegistry>>, plugin_engine: Arc<PluginEngine>) -> Self {
         let provider_registry = Arc::new(
-            ProviderRegistry::with_default_http(Arc::clone(&command_registry), Duration::from_secs(30)).expect("provider registry"),
+            ProviderRegistry::new(
+                Arc::clone(&command_registry),
+                Box::new(McpProviderFetcher::new(Arc::clone(&plugin_engine))),
+                Duration::from_secs(30),
+            )
+            .expect("provider registry"),
         );
         let providers: Vec<Arc<dyn ValueProvider>> = vec![provider_registry.clone()];
         let history_store: Arc<dyn HistoryStore> = match JsonHistoryStore::with_defaults() {
@@ -88,6 +104,16 @@ impl SharedCtx {
                 Arc::new(InMemoryHistoryStore::new())
             }
         };
+        let execution_journal: Arc<dyn ExecutionJournal> = match JsonlExecutionJournal::with_defaults() {
+            Ok(journal) => Arc::new(journal),
+            Err(error) => {
+                warn!(
+                    error = %error,
+                    "Failed to initialize execution journal at default path; falling back to in-memory journal."
+                );
+                Arc::new(InMemoryExecutionJournal::new())
+            }
+        };
         let preferences = Arc::new(UserPreferences::new().unwrap_or_else(|error| {
             warn!(
                 error = %error,
@@ -96,8 +122,16 @@ impl SharedCtx {
             UserPreferences::ephemeral()
         }));
         let preferred_theme = preferences.preferred_theme();
-        let loaded_theme = theme::load(preferred_theme.as_deref());
+        let custom_themes = CustomThemes::discover();
+        for error in custom_themes.errors() {
+            warn!(%error, "Skipping user theme");
+        }
+        let loaded_theme = theme::load(preferred_theme.as_deref(), custom_themes.definitions());
         let theme_picker_available = theme::supports_theme_picker();
+        let keymap = Keymap::load();
+        for problem in keymap.problems() {
+            warn!(%problem, "Ignoring keymap entry");
+        }
 
         Self {
             command_registry,
@@ -107,9 +141,12 @@ impl SharedCtx {
             plugin_engine,
             history_store,
             history_profile_id: DEFAULT_HISTORY_PROFILE.to_string(),
+            execution_journal,
             preferences,
+            custom_themes,
             active_theme_id: loaded_theme.definition.id.to_string(),
             theme_picker_available,
+            keymap,
             product_name: "oatty".to_string(),
         }
     }
@@ -145,12 +182,14 @@ pub struct App<'a> {
     pub palette: PaletteState,
     /// Command browser state
     pub browser: BrowserState,
+    /// Execution history state
+    pub history: HistoryState,
     /// File picker state
     pub file_picker: Option<FilePickerState>,
     /// Manual entry state
     pub manual_entry_state: Option<ManualEntryState>,
-    /// Table modal state
-    pub table: ResultsTableState<'a>,
+    /// Results workspace: one tab per response shown in the Results modal
+    pub results: ResultsWorkspace<'a>,
     /// Help modal state
     pub help: HelpState,
     /// Library component state
@@ -167,10 +206,16 @@ pub struct App<'a> {
     pub nav_bar: VerticalNavBarState,
     /// Theme picker / appearance state
     pub theme_picker: ThemePickerState,
+    /// Side-by-side diff modal state
+    pub diff: DiffState,
+    /// JSON editor modal state for object and array flags
+    pub json_editor: JsonEditorState,
     /// Running MCP HTTP server instance
     pub mcp_http_server_runtime: Option<RunningMcpHttpServer>,
     /// Pending MCP HTTP log receiver awaiting runtime registration.
     mcp_http_log_rx: Option<UnboundedReceiver<McpHttpLogEntry>>,
+    /// Pending receiver for MCP tool calls awaiting operator confirmation.
+    mcp_confirmation_rx: Option<UnboundedReceiver<McpConfirmationRequest>>,
     /// Optional background worker for persistent TUI diagnostics logs.
     persistent_log_worker: Option<PersistentLogWorker>,
     /// Count of persistent log entries dropped due to backpressure.
@@ -182,6 +227,8 @@ pub struct App<'a> {
     /// Active execution count used by the event pump to decide whether to
     /// animate
     pub active_exec_count: Arc<AtomicUsize>,
+    /// Abort handles for spawned command executions, keyed by request hash
+    pub running_requests: HashMap<u64, AbortHandle>,
     /// Global focus tree for keyboard/mouse traversal
     pub focus: Rc<Focus>,
     /// Currently active main route for dynamic focus ring building
@@ -217,9 +264,11 @@ impl App<'_> {
             Arc::clone(&ctx.history_store),
             ctx.history_profile_id.clone(),
         );
+        let history = HistoryState::new(Arc::clone(&ctx.execution_journal));
         let mut app = Self {
             ctx,
             browser: BrowserState::new(Arc::clone(&registry)),
+            history,
             file_picker: None,
             manual_entry_state: None,
             confirmation_modal_state: ConfirmationModalState::default(),
@@ -229,13 +278,16 @@ impl App<'_> {
             mcp_http_server: McpHttpServerState::default(),
             library: LibraryState::new(),
             workflows: WorkflowState::new(),
-            table: ResultsTableState::default(),
+            results: ResultsWorkspace::default(),
             palette,
             nav_bar: VerticalNavBarState::defaults_for_views(),
             theme_picker: ThemePickerState::default(),
+            diff: DiffState::default(),
+            json_editor: JsonEditorState::default(),
             executing: false,
             throbber_idx: 0,
             active_exec_count: Arc::new(AtomicUsize::new(0)),
+            running_requests: HashMap::new(),
             focus: Rc::new(Focus::default()),
             app_container_focus: FocusFlag::new().with_name("app.container"),
             current_route: Route::Library,
@@ -244,6 +296,7 @@ impl App<'_> {
             workflow_run_sequence: 0,
             mcp_http_server_runtime: None,
             mcp_http_log_rx: None,
+            mcp_confirmation_rx: None,
             persistent_log_worker: None,
             persistent_log_drop_count: 0,
         };
@@ -252,8 +305,23 @@ impl App<'_> {
         // Initialize rat-focus and set a sensible starting focus inside the palette
         app.focus = Rc::new(FocusBuilder::build_for(&app));
         app.focus.focus(&app.palette);
+        app.theme_picker.set_custom_themes(app.ctx.custom_themes.definitions());
         app.theme_picker.set_active_theme(&app.ctx.active_theme_id);
         app.initialize_persistent_log_writer();
+        let theme_errors: Vec<String> = app.ctx.custom_themes.errors().iter().map(ToString::to_string).collect();
+        for message in theme_errors {
+            app.append_log_message_with_level(Some(LogLevel::Warn), message);
+        }
+        let keymap_problems: Vec<String> = app
+            .ctx
+            .keymap
+            .problems()
+            .iter()
+            .map(|problem| format!("Keymap: {problem}"))
+            .collect();
+        for message in keymap_problems {
+            app.append_log_message_with_level(Some(LogLevel::Warn), message);
+        }
 
         app
     }
@@ -265,14 +333,14 @@ impl App<'_> {
 
     /// Applies the theme selected inside the picker, rebuilds UI focus state, and persists the choice.
     pub fn apply_theme_selection(&mut self, theme_id: &str) {
-        let Some(definition) = theme::catalog::find_by_id(theme_id) else {
+        let Some(definition) = theme::catalog::find_by_id(theme_id, self.ctx.custom_themes.definitions()).cloned() else {
             warn!(theme_id, "Unknown theme id requested; ignoring.");
             return;
         };
 
         self.ctx.theme = definition.build();
         self.ctx.active_theme_id = definition.id.to_string();
-        self.theme_picker.set_active_theme(definition.id);
+        self.theme_picker.set_active_theme(&definition.id);
         if let Err(error) = self.ctx.preferences.set_preferred_theme(Some(definition.id.to_string())) {
             warn!(%error, "Failed to persist preferred theme selection");
         }
@@ -288,6 +356,11 @@ impl App<'_> {
         self.mcp_http_log_rx = Some(receiver);
     }
 
+    /// Registers the MCP HTTP server confirmation stream for runtime consumption.
+    pub fn register_mcp_confirmation_stream(&mut self, receiver: UnboundedReceiver<McpConfirmationRequest>) {
+        self.mcp_confirmation_rx = Some(receiver);
+    }
+
     /// Extracts a pending workflow run event receiver for runtime registration.
     pub fn take_pending_workflow_events(&mut self) -> Option<WorkflowRunEventReceiver> {
         self.workflow_event_rx.take()
@@ -298,6 +371,11 @@ impl App<'_> {
         self.mcp_http_log_rx.take()
     }
 
+    /// Extracts a pending MCP confirmation receiver for runtime registration.
+    pub fn take_pending_mcp_confirmations(&mut self) -> Option<UnboundedReceiver<McpConfirmationRequest>> {
+        self.mcp_confirmation_rx.take()
+    }
+
     /// Updates the application state based on a message.
     ///
     /// This method processes messages and updates the application state
@@ -327,10 +405,57 @@ impl App<'_> {
                 self.handle_provider_values_ready(provider_id.clone(), cache_key.clone())
             }
             Msg::WorkflowRunEvent { run_id, event } => self.process_workflow_run_event(run_id, event),
+            Msg::ConfirmationModalButtonClicked(button_id) if self.mcp_http_server.active_confirmation().is_some() => {
+                let approved = *button_id == self.mcp_http_server.approve_confirmation_focus.widget_id();
+                self.resolve_mcp_confirmation(approved)
+            }
+            Msg::ConfirmationModalClosed if self.mcp_http_server.active_confirmation().is_some() => self.resolve_mcp_confirmation(false),
             _ => Vec::new(),
         }
     }
 
+    /// Queues an MCP tool call for operator confirmation, prompting immediately when none is active.
+    pub fn enqueue_mcp_confirmation(&mut self, request: McpConfirmationRequest) -> Vec<Effect> {
+        if self.mcp_http_server.enqueue_confirmation(request) {
+            return self.show_mcp_confirmation();
+        }
+        Vec::new()
+    }
+
+    fn resolve_mcp_confirmation(&mut self, approved: bool) -> Vec<Effect> {
+        if let Some(request) = self.mcp_http_server.active_confirmation() {
+            let verdict = if approved { "Approved" } else { "Denied" };
+            let summary = request.call.summary();
+            self.append_log_message(format!("{verdict} MCP tool call: {summary}"));
+        }
+        if self.mcp_http_server.resolve_confirmation(approved) {
+            return self.show_mcp_confirmation();
+        }
+        Vec::new()
+    }
+
+    fn show_mcp_confirmation(&mut self) -> Vec<Effect> {
+        let Some(request) = self.mcp_http_server.active_confirmation() else {
+            return Vec::new();
+        };
+        let message = format!("{}\n\nApprove this call?", request.call.describe());
+        let buttons = vec![
+            ConfirmationModalButton::new("Deny", FocusFlag::new(), ButtonType::Secondary),
+            ConfirmationModalButton::new(
+                "Approve",
+                self.mcp_http_server.approve_confirmation_focus.clone(),
+                ButtonType::Destructive,
+            ),
+        ];
+        self.confirmation_modal_state.update_opts(ConfirmationModalOpts {
+            title: Some("Confirm MCP Tool Call".to_string()),
+            message: Some(message),
+            r#type: Some(MessageType::Warning),
+            buttons,
+        });
+        vec![Effect::ShowModal(Modal::Confirmation)]
+    }
+
     /// Handles tick messages for periodic updates and animations.
     ///
     /// This method manages periodic tasks such as animating the execution
@@ -340,6 +465,8 @@ impl App<'_> {
     /// # Arguments
     ///
     fn handle_tick_message(&mut self) -> Vec<Effect> {
+        let mut effects = self.reload_custom_themes_if_changed();
+
         // Animate spinner while executing or while provider-backed suggestions are loading
         if self.executing || self.palette.is_provider_loading() {
             let previous_throbber_index = self.throbber_idx;
@@ -349,21 +476,49 @@ impl App<'_> {
 
         // Periodically refresh plugin statuses when the overlay is visible
         if self.plugins.table.should_refresh() {
-            return vec![Effect::PluginsRefresh];
+            effects.push(Effect::PluginsRefresh);
+            return effects;
         }
 
         if let Some(runtime) = self.mcp_http_server_runtime.as_ref() {
             self.mcp_http_server.update_connected_clients(runtime.connected_clients());
+            self.mcp_http_server.update_usage(runtime.usage());
         }
 
         // If provider-backed suggestions are loading and the popup is open,
         // rebuild suggestions to pick up newly cached results without requiring
         // another keypress
         if self.palette.is_suggestions_open() && self.palette.is_provider_loading() {
-            return self.rebuild_palette_suggestions();
+            effects.extend(self.rebuild_palette_suggestions());
         }
 
-        vec![]
+        effects
+    }
+
+    /// Rescans the user themes directory and re-applies the active theme when
+    /// its file changed. Validation errors and reloads are reported as log effects.
+    fn reload_custom_themes_if_changed(&mut self) -> Vec<Effect> {
+        if !self.ctx.custom_themes.poll() {
+            return Vec::new();
+        }
+        let mut effects: Vec<Effect> = self
+            .ctx
+            .custom_themes
+            .errors()
+            .iter()
+            .map(|error| Effect::Log(error.to_string()))
+            .collect();
+        self.theme_picker.set_custom_themes(self.ctx.custom_themes.definitions());
+        self.theme_picker.set_active_theme(&self.ctx.active_theme_id);
+
+        let active = theme::catalog::find_by_id(&self.ctx.active_theme_id, self.ctx.custom_themes.definitions());
+        if let Some(definition) = active.filter(|definition| definition.source_path().is_some())
+            && (self.ctx.theme_picker_available || definition.is_ansi_fallback)
+        {
+            self.ctx.theme = definition.build();
+            effects.push(Effect::Log(format!("Reloaded theme '{}'", definition.label)));
+        }
+        effects
     }
 
     fn effects_for_pending_fetches(&self, fetches: Vec<PendingProviderFetch>) -> Vec<Effect> {
@@ -545,6 +700,7 @@ impl App<'_> {
     /// Processes an execution outcome and persists any log entries generated by
     /// that outcome.
     pub fn process_general_execution_result(&mut self, execution_outcome: &oatty_types::ExecOutcome) {
+        self.palette.record_result(execution_outcome);
         let original_entry_count = self.logs.rich_entries.len();
         self.logs.process_general_execution_result(execution_outcome);
         self.persist_entries_since(original_entry_count);
@@ -640,9 +796,7 @@ impl App<'_> {
                 continue;
             }
 
-            if let Some(validation) = &definition.validate
-                && let Err(error) = validate_candidate_value(value, validation)
-            {
+            if let Err(error) = definition.validate_value(value) {
                 warn!(
                     input = %input_name,
                     workflow = %run_state.workflow.identifier,
@@ -841,7 +995,7 @@ impl HasFocus for App<'_> {
         if let Some(kind) = &self.open_modal_kind {
             match kind {
                 Modal::Results(_) => {
-                    builder.widget(&self.table);
+                    builder.widget(self.results.table());
                 }
                 Modal::LogDetails => {
                     builder.widget(&self.logs.results_table);
@@ -856,13 +1010,24 @@ impl HasFocus for App<'_> {
                 Modal::Confirmation => {
                     builder.widget(&self.confirmation_modal_state);
                 }
-                Modal::FilePicker(..) if self.file_picker.is_some() => {
+                Modal::FilePicker(..) | Modal::ExportResults | Modal::JsonEditorLoad | Modal::JsonEditorSave
+                    if self.file_picker.is_some() =>
+                {
                     builder.widget(self.file_picker.as_ref().unwrap());
                 }
                 Modal::ManualEntry if self.manual_entry_state.is_some() => {
                     builder.widget(self.manual_entry_state.as_ref().unwrap());
                 }
-                Modal::PluginDetails | Modal::Help | Modal::ThemePicker | Modal::FilePicker(..) | Modal::ManualEntry => {
+                Modal::PluginDetails
+                | Modal::Help
+                | Modal::ThemePicker
+                | Modal::Diff
+                | Modal::JsonEditor
+                | Modal::FilePicker(..)
+                | Modal::ExportResults
+                | Modal::JsonEditorLoad
+                | Modal::JsonEditorSave
+                | Modal::ManualEntry => {
                     // focusable fields TBD; leave the ring empty
                 }
             }
@@ -880,6 +1045,9 @@ impl HasFocus for App<'_> {
             Route::Browser => {
                 builder.widget(&self.browser);
             }
+            Route::History => {
+                builder.widget(&self.history);
+            }
             Route::Plugins => {
                 builder.widget(&self.plugins);
             }
@@ -896,7 +1064,7 @@ impl HasFocus for App<'_> {
                 builder.widget(&self.workflows.list);
                 builder.end(tag);
             }
-            Route::WorkflowInputs | Route::WorkflowRun => {
+            Route::WorkflowInputs | Route::WorkflowRun | Route::WorkflowEditor => {
                 builder.widget(&self.workflows);
             }
             Route::Library => {
@@ -969,6 +1137,7 @@ mod tests {
             ],
             final_output: None,
             requires: None,
+            schedule: None,
         }
     }
 
@@ -21,15 +21,22 @@
 
 use crate::app::App;
 use crate::ui::components::plugins::PluginTransport;
+use crate::ui::components::results::export::ExportFormat;
 use anyhow::Result;
 use anyhow::anyhow;
 use chrono::Utc;
 use indexmap::IndexSet;
 use oatty_engine::{RegistryCommandRunner, drive_workflow_run, provider::ProviderFetchPlan};
 use oatty_mcp::config::{
-    McpServer, default_config_path, load_config_from_path, save_config_to_path, validate_config, validate_server_name,
+    McpAuthConfig, McpServer, default_config_path, load_config_from_path, save_config_to_path, validate_config, validate_server_name,
+};
+use oatty_mcp::logging::{
+    AuditEntry, AuditInitiator, AuditInvocation, AuditQuery, AuditResult, AuditTrail, AuditedCommandRunner, parse_audit_since,
+};
+use oatty_mcp::{
+    McpClientAuthenticator, McpConfig, McpHttpServer, McpPolicy, PluginEngine, WorkflowManifestFormat, create_client_token,
+    load_http_server_config, remove_manifest, resolve_bind_address, revoke_client_token, write_manifest,
 };
-use oatty_mcp::{McpConfig, McpHttpServer, PluginEngine, resolve_bind_address};
 
 use oatty_registry::{
     CommandRegistry, CommandSpec, OpenApiCatalogImportError, OpenApiCatalogImportRequest, import_openapi_catalog_into_registry,
@@ -46,6 +53,9 @@ use oatty_util::determine_env_source;
 use oatty_util::exec_remote_from_shell_command;
 use oatty_util::fetch_static;
 use oatty_util::lex_shell_like;
+use oatty_util::resolve_path;
+use oatty_util::truncate_with_ellipsis;
+use oatty_util::{DEFAULT_RESPONSE_SIZE_CAP, ExecutionJournal, ExecutionRecord, ExecutionRequest, ExecutionStatus};
 use serde_json::Map;
 use serde_json::Value;
 use serde_json::from_str;
@@ -55,10 +65,14 @@ use std::fs::{create_dir_all, read_dir, read_to_string, remove_file, write};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use std::vec;
 use tokio::{sync::mpsc, task::JoinHandle};
 use url::Url;
 
+/// Number of most recent audit entries shown in the audit trail view.
+const AUDIT_VIEW_LIMIT: usize = 500;
+
 /// Represents side-effectful system commands executed outside pure state
 /// updates.
 ///
@@ -79,16 +93,30 @@ pub enum Cmd {
         cache_key: String,
         args: Map<String, Value>,
     },
-    ExecuteMcp(CommandSpec, Map<String, Value>, u64),
+    ExecuteMcp {
+        spec: CommandSpec,
+        input: String,
+        arguments: Map<String, Value>,
+        request_id: u64,
+    },
+    CancelRequests(Vec<u64>),
     LoadPlugins,
     PluginsStart(String),
     PluginsStop(String),
     PluginsRestart(String),
+    PluginsAuthorize(String),
     McpHttpServerStart,
     McpHttpServerStop,
     McpHttpServerSetAutostart {
         auto_start: bool,
     },
+    McpHttpClientTokenCreate,
+    McpHttpClientTokenRevoke(String),
+    McpAuditTrailLoad {
+        since: Option<String>,
+        catalog: Option<String>,
+        status: Option<String>,
+    },
     PluginsLoadDetail(String),
     PluginsRefresh,
     PluginsExportLogsDefault(String),
@@ -99,10 +127,18 @@ pub enum Cmd {
     ReadFileContents(PathBuf),
     ListDirectoryContents(PathBuf),
     ReadRemoteFileContents(Url),
+    ExportResults(PathBuf),
+    SaveJsonEditor(PathBuf),
     ImportRegistryCatalog(String, Option<String>),
     ImportWorkflowManifest(String),
     RemoveCatalog(Cow<'static, str>),
     RemoveWorkflow(Cow<'static, str>),
+    SaveWorkflowManifest {
+        workflow_id: String,
+        content: String,
+        format: String,
+        previous_path: Option<PathBuf>,
+    },
     UpdateCatalogEnabledState {
         is_enabled: bool,
         title: Cow<'static, str>,
@@ -153,9 +189,13 @@ pub async fn run_from_effects(app: &mut App<'_>, effects: Vec<Effect>) -> Comman
             Effect::PluginsStart(name) => Some(vec![Cmd::PluginsStart(name)]),
             Effect::PluginsStop(name) => Some(vec![Cmd::PluginsStop(name)]),
             Effect::PluginsRestart(name) => Some(vec![Cmd::PluginsRestart(name)]),
+            Effect::PluginsAuthorize(name) => Some(vec![Cmd::PluginsAuthorize(name)]),
             Effect::McpHttpServerStart => Some(vec![Cmd::McpHttpServerStart]),
             Effect::McpHttpServerStop => Some(vec![Cmd::McpHttpServerStop]),
             Effect::McpHttpServerSetAutostart { auto_start } => Some(vec![Cmd::McpHttpServerSetAutostart { auto_start }]),
+            Effect::McpHttpClientTokenCreate => Some(vec![Cmd::McpHttpClientTokenCreate]),
+            Effect::McpHttpClientTokenRevoke(name) => Some(vec![Cmd::McpHttpClientTokenRevoke(name)]),
+            Effect::McpAuditTrailRequested { since, catalog, status } => Some(vec![Cmd::McpAuditTrailLoad { since, catalog, status }]),
             Effect::PluginsLoadDetail(name) => {
                 let state = app.plugins.ensure_details_state();
                 state.begin_load(name.clone());
@@ -175,6 +215,7 @@ pub async fn run_from_effects(app: &mut App<'_>, effects: Vec<Effect>) -> Comman
                 hydrated_command,
                 request_hash,
             } => run_command(app, hydrated_command, request_hash),
+            Effect::CancelRequests(request_ids) => Some(vec![Cmd::CancelRequests(request_ids)]),
             Effect::ProviderFetchRequested {
                 provider_id,
                 cache_key,
@@ -196,6 +237,8 @@ pub async fn run_from_effects(app: &mut App<'_>, effects: Vec<Effect>) -> Comman
             Effect::ReadFileContents(path) => Some(vec![Cmd::ReadFileContents(path)]),
             Effect::ListDirectoryContents(path) => Some(vec![Cmd::ListDirectoryContents(path)]),
             Effect::ReadRemoteFileContents(url) => Some(vec![Cmd::ReadRemoteFileContents(url)]),
+            Effect::ExportResults(path) => Some(vec![Cmd::ExportResults(path)]),
+            Effect::SaveJsonEditor(path) => Some(vec![Cmd::SaveJsonEditor(path)]),
             Effect::ImportRegistryCatalog(content, maybe_prefix) => Some(vec![Cmd::ImportRegistryCatalog(content, maybe_prefix)]),
             Effect::ImportWorkflowManifest(content) => Some(vec![Cmd::ImportWorkflowManifest(content)]),
             Effect::UpdateCatalogEnabledState { title, is_enabled } => Some(vec![Cmd::UpdateCatalogEnabledState { title, is_enabled }]),
@@ -207,6 +250,17 @@ pub async fn run_from_effects(app: &mut App<'_>, effects: Vec<Effect>) -> Comman
             Effect::UpdateCatalogHeaders { title, headers } => Some(vec![Cmd::UpdateCatalogHeaders { title, headers }]),
             Effect::RemoveCatalog(title) => Some(vec![Cmd::RemoveCatalog(title)]),
             Effect::RemoveWorkflow(workflow_id) => Some(vec![Cmd::RemoveWorkflow(workflow_id)]),
+            Effect::SaveWorkflowManifest {
+                workflow_id,
+                content,
+                format,
+                previous_path,
+            } => Some(vec![Cmd::SaveWorkflowManifest {
+                workflow_id,
+                content,
+                format,
+                previous_path,
+            }]),
             Effect::Log(message) => Some(vec![Cmd::AppendLog(message)]),
             Effect::SwitchTo(_) | Effect::ShowModal(_) | Effect::CloseModal => None,
         };
@@ -237,6 +291,10 @@ fn handle_send_to_palette(app: &mut App, command_spec: Box<CommandSpec>) -> Opti
 pub async fn run_cmds(app: &mut App<'_>, commands: Vec<Cmd>) -> CommandBatch {
     let mut batch = CommandBatch::default();
     for command in commands {
+        let request_id = match &command {
+            Cmd::ExecuteHttp { request_id, .. } | Cmd::ExecuteMcp { request_id, .. } => Some(*request_id),
+            _ => None,
+        };
         let (immediate, background) = match command {
             Cmd::ApplyPaletteError(error) => (Some(apply_palette_error(app, error)), None),
             Cmd::AppendLog(message) => (Some(append_log_entry(app, message)), None),
@@ -247,13 +305,32 @@ pub async fn run_cmds(app: &mut App<'_>, commands: Vec<Cmd>) -> CommandBatch {
                 cache_key,
                 args,
             } => (None, Some(spawn_fetch_provider_values(app, provider_id, cache_key, args))),
-            Cmd::ExecuteMcp(spec, body, request_id) => (None, Some(spawn_execute_mcp(app, spec, body, request_id))),
+            Cmd::ExecuteMcp {
+                spec,
+                input,
+                arguments,
+                request_id,
+            } => (None, Some(spawn_execute_mcp(app, spec, input, arguments, request_id))),
+            Cmd::CancelRequests(request_ids) => {
+                cancel_requests(app, &request_ids);
+                (None, None)
+            }
             Cmd::PluginsStart(name) => (None, Some(spawn_execute_plugin_action(app, PluginAction::Start, name))),
             Cmd::PluginsStop(name) => (None, Some(spawn_execute_plugin_action(app, PluginAction::Stop, name))),
             Cmd::PluginsRestart(name) => (None, Some(spawn_execute_plugin_action(app, PluginAction::Restart, name))),
+            Cmd::PluginsAuthorize(name) => (
+                Some(ExecOutcome::Log(format!(
+                    "Plugins: authorizing '{}'; finish signing in from your browser",
+                    name
+                ))),
+                Some(spawn_authorize_plugin(app, name)),
+            ),
             Cmd::McpHttpServerStart => (Some(start_mcp_http_server(app).await), None),
             Cmd::McpHttpServerStop => (Some(stop_mcp_http_server(app).await), None),
             Cmd::McpHttpServerSetAutostart { auto_start } => (Some(update_mcp_http_autostart(app, auto_start)), None),
+            Cmd::McpHttpClientTokenCreate => (Some(create_mcp_client_token(app)), None),
+            Cmd::McpHttpClientTokenRevoke(name) => (Some(revoke_mcp_client_token(app, name)), None),
+            Cmd::McpAuditTrailLoad { since, catalog, status } => (None, Some(spawn_load_audit_trail(app, since, catalog, status))),
             Cmd::PluginsLoadDetail(name) => (None, Some(spawn_load_plugin_detail(app, name))),
             Cmd::LoadPlugins => (Some(execute_load_plugins(app).await), None),
             Cmd::PluginsRefresh => (Some(execute_plugins_refresh(app).await), None),
@@ -265,10 +342,27 @@ pub async fn run_cmds(app: &mut App<'_>, commands: Vec<Cmd>) -> CommandBatch {
             Cmd::ReadFileContents(path) => (Some(read_file_contents(path)), None),
             Cmd::ListDirectoryContents(path) => (Some(list_dir_contents(path)), None),
             Cmd::ReadRemoteFileContents(url) => (None, Some(fetch_remote_file_contents(url))),
+            Cmd::ExportResults(path) => (Some(export_results(app, path)), None),
+            Cmd::SaveJsonEditor(path) => (Some(save_json_editor(app, path)), None),
             Cmd::ImportRegistryCatalog(inputs, maybe_prefix) => (Some(import_registry_catalog_from(app, inputs, maybe_prefix)), None),
             Cmd::ImportWorkflowManifest(content) => (Some(import_workflow_manifest(app, content)), None),
             Cmd::RemoveCatalog(title) => (Some(remove_catalog(title, app)), None),
             Cmd::RemoveWorkflow(workflow_id) => (Some(remove_workflow(workflow_id, app)), None),
+            Cmd::SaveWorkflowManifest {
+                workflow_id,
+                content,
+                format,
+                previous_path,
+            } => (
+                Some(save_workflow_manifest(
+                    app,
+                    &workflow_id,
+                    &content,
+                    &format,
+                    previous_path.as_deref(),
+                )),
+                None,
+            ),
             Cmd::UpdateCatalogEnabledState { title, is_enabled } => (Some(update_enabled_then_save(title, is_enabled, app)), None),
             Cmd::UpdateCatalogBaseUrlIndex { base_url_index, title } => {
                 (Some(update_base_url_index_then_save(base_url_index, title, app)), None)
@@ -282,6 +376,9 @@ pub async fn run_cmds(app: &mut App<'_>, commands: Vec<Cmd>) -> CommandBatch {
             batch.immediate.push(immediate);
         }
         if let Some(pending) = background {
+            if let Some(request_id) = request_id {
+                app.running_requests.insert(request_id, pending.abort_handle());
+            }
             batch.pending.push(pending);
         }
     }
@@ -361,8 +458,18 @@ async fn start_mcp_http_server(app: &mut App<'_>) -> ExecOutcome {
         return ExecOutcome::Log("MCP HTTP server is already running".to_string());
     }
 
-    let configured_address = app.mcp_http_server.configured_bind_address.clone();
-    let bind_address = match resolve_bind_address(Some(&configured_address)) {
+    // Re-read the config so tokens and TLS settings changed from the CLI apply on restart.
+    let config_path = default_config_path();
+    let http_config = match load_http_server_config(&config_path) {
+        Ok(http_config) => http_config,
+        Err(error) => {
+            app.mcp_http_server.mark_error(format!("{error:#}"));
+            return ExecOutcome::Log(format!("MCP HTTP server failed to start: {error:#}"));
+        }
+    };
+    app.mcp_http_server.apply_config(&http_config);
+
+    let bind_address = match resolve_bind_address(&http_config) {
         Ok(address) => address,
         Err(error) => {
             app.mcp_http_server.mark_error(error.to_string());
@@ -370,20 +477,51 @@ async fn start_mcp_http_server(app: &mut App<'_>) -> ExecOutcome {
         }
     };
 
+    // Installed even without clients so tokens created while the server runs are enforced.
+    let authenticator = match McpClientAuthenticator::load(config_path) {
+        Ok(authenticator) => authenticator,
+        Err(error) => {
+            app.mcp_http_server.mark_error(format!("{error:#}"));
+            return ExecOutcome::Log(format!("MCP HTTP server failed to start: {error:#}"));
+        }
+    };
+
+    let policy = match http_config.policy_file.as_deref().map(McpPolicy::load).transpose() {
+        Ok(policy) => policy,
+        Err(error) => {
+            app.mcp_http_server.mark_error(format!("{error:#}"));
+            return ExecOutcome::Log(format!("MCP HTTP server failed to start: {error:#}"));
+        }
+    };
+
     let (sender, receiver) = mpsc::unbounded_channel();
-    let server = McpHttpServer::new(
+    let (confirmation_sender, confirmation_receiver) = mpsc::unbounded_channel();
+    let mut server = McpHttpServer::new(
         bind_address,
         Arc::clone(&app.ctx.command_registry),
         Arc::clone(&app.ctx.plugin_engine),
     )
-    .with_log_sender(sender);
+    .with_log_sender(sender)
+    .with_confirmation_sender(confirmation_sender)
+    .with_gateway(http_config.gateway)
+    .with_client_authentication(authenticator);
+    if let Some(policy) = policy {
+        server = server.with_policy(policy);
+    }
+    if let Some(rate_limits) = http_config.rate_limits.clone() {
+        server = server.with_rate_limits(rate_limits);
+    }
+    if let Some(tls) = http_config.tls.clone() {
+        server = server.with_tls(tls);
+    }
     match server.start().await {
         Ok(runtime) => {
-            let bound_address = runtime.bound_address().to_string();
-            app.mcp_http_server.mark_running(bound_address.clone());
+            let endpoint_url = runtime.endpoint_url();
+            app.mcp_http_server.mark_running(runtime.bound_address().to_string());
             app.mcp_http_server_runtime = Some(runtime);
             app.register_mcp_http_log_stream(receiver);
-            ExecOutcome::Log(format!("MCP HTTP server started at http://{bound_address}/mcp"))
+            app.register_mcp_confirmation_stream(confirmation_receiver);
+            ExecOutcome::Log(format!("MCP HTTP server started at {endpoint_url}"))
         }
         Err(error) => {
             app.mcp_http_server.mark_error(error.to_string());
@@ -410,12 +548,23 @@ async fn stop_mcp_http_server(app: &mut App<'_>) -> ExecOutcome {
     }
 }
 
+/// Reads the MCP config that is about to be rewritten.
+///
+/// A missing file starts from defaults, but an unreadable or malformed one is an error so saving
+/// never replaces the user's servers and tokens with an empty config.
+fn read_config_for_update(path: &Path) -> Result<McpConfig> {
+    if !path.exists() {
+        return Ok(McpConfig::default());
+    }
+    let text = read_to_string(path).map_err(|error| anyhow!("failed to read {}: {error}", path.display()))?;
+    from_str::<McpConfig>(&text).map_err(|error| anyhow!("failed to parse {}: {error}", path.display()))
+}
+
 fn update_mcp_http_autostart(app: &mut App<'_>, auto_start: bool) -> ExecOutcome {
     let path = default_config_path();
-    let mut cfg = if let Ok(text) = read_to_string(&path) {
-        from_str::<McpConfig>(&text).unwrap_or_default()
-    } else {
-        McpConfig::default()
+    let mut cfg = match read_config_for_update(&path) {
+        Ok(config) => config,
+        Err(error) => return ExecOutcome::Log(format!("Failed to save MCP HTTP auto-start setting: {error:#}")),
     };
     cfg.http_server.auto_start = auto_start;
 
@@ -426,6 +575,93 @@ fn update_mcp_http_autostart(app: &mut App<'_>, auto_start: bool) -> ExecOutcome
     ExecOutcome::Log(format!("MCP HTTP auto-start {}", if auto_start { "enabled" } else { "disabled" }))
 }
 
+/// Issues a token for the next free `client-N` name and copies it to the clipboard.
+fn create_mcp_client_token(app: &mut App<'_>) -> ExecOutcome {
+    let config_path = default_config_path();
+    let name = app.mcp_http_server.next_client_name();
+    let token = match create_client_token(&config_path, &name) {
+        Ok(token) => token,
+        Err(error) => return ExecOutcome::Log(format!("Failed to create MCP client token: {error:#}")),
+    };
+    refresh_mcp_client_tokens(app, &config_path);
+
+    let copied = arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(token))
+        .is_ok();
+    let message = if copied {
+        format!("Token for '{name}' copied to the clipboard; it will not be shown again")
+    } else {
+        format!("Created '{name}', but the clipboard is unavailable; revoke it and use `oatty mcp token create` instead")
+    };
+    app.mcp_http_server.set_success_message(Cow::Owned(message.clone()));
+    ExecOutcome::Log(message)
+}
+
+fn revoke_mcp_client_token(app: &mut App<'_>, name: String) -> ExecOutcome {
+    let config_path = default_config_path();
+    if let Err(error) = revoke_client_token(&config_path, &name) {
+        return ExecOutcome::Log(format!("Failed to revoke MCP client token: {error:#}"));
+    }
+    refresh_mcp_client_tokens(app, &config_path);
+    app.mcp_http_server
+        .set_success_message(Cow::Owned(format!("Revoked token for '{name}'")));
+    ExecOutcome::Log(format!("Revoked MCP client token for '{name}'"))
+}
+
+/// Reads the audit trail off the UI thread; filters use the `oatty audit query` syntax.
+fn spawn_load_audit_trail(
+    app: &App<'_>,
+    since: Option<String>,
+    catalog: Option<String>,
+    status: Option<String>,
+) -> JoinHandle<ExecOutcome> {
+    let logger = app.ctx.plugin_engine.log_manager().audit_logger().clone();
+    tokio::task::spawn_blocking(move || {
+        let query = || -> Result<AuditQuery> {
+            Ok(AuditQuery {
+                since: since.as_deref().map(|since| parse_audit_since(since, Utc::now())).transpose()?,
+                catalog: catalog.clone(),
+                status: status.as_deref().map(str::parse).transpose()?,
+                limit: Some(AUDIT_VIEW_LIMIT),
+            })
+        };
+        let result = query()
+            .and_then(|query| Ok(logger.query(&query)?))
+            .map(|entries| Value::Array(entries.iter().rev().map(audit_entry_row).collect()))
+            .map_err(|error| error.to_string());
+        let row_count = result.as_ref().ok().and_then(Value::as_array).map_or(0, Vec::len);
+        ExecOutcome::McpAuditTrail {
+            log_entry: format!("Audit trail ({row_count} entries)"),
+            result,
+        }
+    })
+}
+
+/// Flattens an audit entry into a results table row.
+fn audit_entry_row(entry: &AuditEntry) -> Value {
+    let invocation = &entry.invocation;
+    serde_json::json!({
+        "time": entry.timestamp.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        "initiator": invocation.initiator.map(|initiator| format!("{initiator:?}").to_lowercase()),
+        "source": entry.plugin_name,
+        "action": format!("{:?}", entry.action),
+        "subject": entry.subject(),
+        "catalog": invocation.catalog,
+        "status": invocation.status,
+        "result": format!("{:?}", entry.result),
+        "duration_ms": invocation.duration_ms,
+        "client": invocation.client,
+        "session_id": invocation.session_id,
+    })
+}
+
+fn refresh_mcp_client_tokens(app: &mut App<'_>, config_path: &std::path::Path) {
+    match load_http_server_config(config_path) {
+        Ok(http_config) => app.mcp_http_server.set_clients(http_config.clients),
+        Err(error) => app.append_log_message(format!("Failed to reload MCP client tokens: {error:#}")),
+    }
+}
+
 #[derive(Clone, Copy)]
 enum PluginAction {
     Start,
@@ -456,6 +692,47 @@ fn read_file_contents(path: PathBuf) -> ExecOutcome {
     }
 }
 
+/// Aborts command executions that are still in flight.
+fn cancel_requests(app: &mut App, request_ids: &[u64]) {
+    let mut cancelled = 0usize;
+    for request_id in request_ids {
+        if let Some(handle) = app.running_requests.remove(request_id)
+            && !handle.is_finished()
+        {
+            handle.abort();
+            cancelled += 1;
+        }
+    }
+    if cancelled > 0 {
+        app.append_log_message(format!("Cancelled {cancelled} running command(s)"));
+    }
+}
+
+/// Writes the results view at its current drill level to `path`.
+fn export_results(app: &App, path: PathBuf) -> ExecOutcome {
+    let Some(format) = ExportFormat::from_path(&path) else {
+        return ExecOutcome::Log(format!("Export failed: unsupported file type '{}'", path.display()));
+    };
+    let Some((contents, row_count)) = app.results.table().export(format) else {
+        return ExecOutcome::Log("Export failed: no results to export".to_string());
+    };
+    match write(&path, contents) {
+        Ok(()) => ExecOutcome::Log(format!("Exported {row_count} row(s) as {} to {}", format.label(), path.display())),
+        Err(error) => ExecOutcome::Log(format!("Export failed: {error}")),
+    }
+}
+
+fn save_json_editor(app: &mut App, path: PathBuf) -> ExecOutcome {
+    let mut contents = app.json_editor.text();
+    contents.push('\n');
+    let message = match write(&path, contents) {
+        Ok(()) => format!("Saved --{} to {}", app.json_editor.flag_name(), path.display()),
+        Err(error) => format!("Save failed: {error}"),
+    };
+    app.json_editor.set_status(message.clone());
+    ExecOutcome::Log(message)
+}
+
 /// Imports a catalog from OpenAPI content using the shared registry import service.
 fn import_registry_catalog_from(app: &mut App, content: String, maybe_prefix: Option<String>) -> ExecOutcome {
     let Ok(mut registry_guard) = app.ctx.command_registry.try_lock() else {
@@ -589,6 +866,40 @@ where
     }
 }
 
+/// Writes a manifest authored in the workflow editor and refreshes registry workflow state.
+///
+/// When the identifier or format changed since the draft was opened, the previous manifest is
+/// removed so the workflow is not listed twice.
+fn save_workflow_manifest(app: &mut App, workflow_id: &str, content: &str, format: &str, previous_path: Option<&Path>) -> ExecOutcome {
+    let format = match WorkflowManifestFormat::from_hint(Some(format)) {
+        Ok(format) => format,
+        Err(error) => return ExecOutcome::WorkflowOperationError(format!("Failed to save workflow '{workflow_id}': {error}")),
+    };
+    let path = match write_manifest(workflow_id, content, format) {
+        Ok(path) => path,
+        Err(error) => return ExecOutcome::WorkflowOperationError(format!("Failed to save workflow '{workflow_id}': {error:#}")),
+    };
+
+    if let Some(previous_path) = previous_path.filter(|previous_path| *previous_path != path)
+        && let Err(error) = remove_manifest(previous_path)
+    {
+        return ExecOutcome::WorkflowOperationError(format!(
+            "Workflow '{}' was saved but the previous manifest at '{}' could not be removed: {error:#}",
+            workflow_id,
+            previous_path.display()
+        ));
+    }
+
+    if let Err(error) = refresh_workflows_in_registry(app) {
+        return ExecOutcome::WorkflowOperationError(format!("Workflow '{}' was saved but registry refresh failed: {error}", workflow_id));
+    }
+
+    ExecOutcome::WorkflowSaved {
+        workflow_id: workflow_id.to_string(),
+        path,
+    }
+}
+
 /// Parses a workflow definition from either JSON or YAML source content.
 pub(crate) fn parse_workflow_definition(content: &str) -> Result<WorkflowDefinition> {
     from_str::<WorkflowDefinition>(content).or_else(|json_error| {
@@ -837,6 +1148,19 @@ fn spawn_execute_plugin_action(app: &mut App<'_>, action: PluginAction, name: St
     })
 }
 
+/// Run the OAuth authorization flow for an HTTP plugin and report the refreshed detail.
+fn spawn_authorize_plugin(app: &mut App<'_>, name: String) -> JoinHandle<ExecOutcome> {
+    let plugin_engine = app.ctx.plugin_engine.clone();
+    tokio::spawn(async move {
+        let message = match plugin_engine.authorize_plugin(&name).await {
+            Ok(()) => format!("Plugins: authorized '{}'", name),
+            Err(error) => format!("Plugins: authorize '{}' failed: {}", name, error),
+        };
+        let detail = plugin_engine.get_plugin_detail(&name).await.ok();
+        ExecOutcome::PluginDetail { message, detail }
+    })
+}
+
 fn spawn_load_plugin_detail(app: &mut App<'_>, name: String) -> JoinHandle<ExecOutcome> {
     let plugin_engine = app.ctx.plugin_engine.clone();
     tokio::spawn(async move {
@@ -956,6 +1280,7 @@ async fn execute_plugins_save(app: &mut App<'_>) -> ExecOutcome {
     };
     let name = add_view_state.name.trim().to_string();
     let original_name = add_view_state.original_name.as_deref();
+    let oauth_enabled = add_view_state.transport == PluginTransport::Remote && add_view_state.oauth_enabled;
     let mut server = McpServer::default();
     match add_view_state.transport {
         PluginTransport::Remote => {
@@ -1005,11 +1330,26 @@ async fn execute_plugins_save(app: &mut App<'_>) -> ExecOutcome {
 
     // Write to config
     let path = default_config_path();
-    let mut cfg = if let Ok(text) = read_to_string(&path) {
-        from_str::<McpConfig>(&text).unwrap_or_default()
-    } else {
-        McpConfig::default()
+    let mut cfg = match read_config_for_update(&path) {
+        Ok(config) => config,
+        Err(error) => {
+            return ExecOutcome::PluginValidationErr {
+                message: format!("Failed to save MCP configuration: {error:#}"),
+            };
+        }
     };
+    // Keep the existing authorization settings unless the OAuth toggle changed.
+    let existing_auth = cfg
+        .mcp_servers
+        .get(original_name.unwrap_or(&name))
+        .and_then(|existing| existing.auth.clone());
+    if server.is_http() {
+        server.auth = match existing_auth {
+            Some(auth) if auth.is_oauth() == oauth_enabled => Some(auth),
+            _ if oauth_enabled => Some(McpAuthConfig::oauth()),
+            _ => None,
+        };
+    }
     apply_plugin_name_change(&mut cfg, original_name, &name);
     cfg.mcp_servers.insert(name.clone(), server);
     if let Err(e) = validate_config(&cfg) {
@@ -1052,10 +1392,13 @@ async fn execute_plugins_save(app: &mut App<'_>) -> ExecOutcome {
 /// Remove an existing MCP plugin from config and refresh runtime state.
 async fn execute_plugins_delete(app: &mut App<'_>, plugin_name: String) -> ExecOutcome {
     let path = default_config_path();
-    let mut configuration = if let Ok(text) = read_to_string(&path) {
-        from_str::<McpConfig>(&text).unwrap_or_default()
-    } else {
-        McpConfig::default()
+    let mut configuration = match read_config_for_update(&path) {
+        Ok(config) => config,
+        Err(error) => {
+            return ExecOutcome::PluginValidationErr {
+                message: format!("Failed to save MCP configuration after delete: {error:#}"),
+            };
+        }
     };
 
     if configuration.mcp_servers.remove(&plugin_name).is_none() {
@@ -1129,7 +1472,19 @@ fn handle_workflow_run_requested(app: &mut App<'_>, request: WorkflowRunRequest)
         return;
     };
 
-    let runner = Arc::new(RegistryCommandRunner::new(registry_snapshot));
+    let audit_trail = AuditTrail::new(
+        app.ctx.plugin_engine.log_manager().audit_logger().clone(),
+        "tui",
+        AuditInvocation::new(AuditInitiator::Human),
+    );
+    let runner = Arc::new(
+        AuditedCommandRunner::new(
+            RegistryCommandRunner::new(registry_snapshot.clone()),
+            registry_snapshot,
+            audit_trail,
+        )
+        .with_workflow_id(request.workflow.identifier.clone()),
+    );
     let (event_tx, event_rx) = mpsc::unbounded_channel();
     let (control_tx, control_rx) = mpsc::unbounded_channel();
 
@@ -1186,7 +1541,28 @@ fn spawn_execute_http(app: &mut App<'_>, spec: CommandSpec, input: String, reque
         && let Some(base_url) = lock.resolve_base_url_for_command(&spec)
         && let Some(headers) = lock.resolve_headers_for_command(&spec).cloned()
     {
-        return tokio::spawn(async move { execute_http_task(active, spec, input, base_url, &headers, request_id).await });
+        let resolved = resolve_http_input(&spec, &input);
+        let audit = command_audit_invocation(&lock, &spec, resolved.as_ref().map(|(path, _)| path.clone()));
+        let pending_record = pending_execution_record(
+            &spec,
+            &input,
+            audit.catalog.clone(),
+            http_journal_request(&spec, &base_url, resolved),
+        );
+        let journal = Arc::clone(&app.ctx.execution_journal);
+        let engine = app.ctx.plugin_engine.clone();
+        return tokio::spawn(async move {
+            let started_at = Instant::now();
+            let outcome = execute_http_task(active, spec, input, base_url, &headers, request_id).await;
+            let status = match &outcome {
+                ExecOutcome::Http { status_code, .. } => Some(*status_code),
+                _ => None,
+            };
+            let invocation = AuditInvocation { status, ..audit }.with_duration(started_at.elapsed());
+            audit_command_execution(&engine, invocation, !matches!(outcome, ExecOutcome::Log(_))).await;
+            journal_execution(journal.as_ref(), pending_record, &outcome, started_at.elapsed());
+            outcome
+        });
     }
     let err = "base url or headers could not be determined for this command. Check configs and retry".to_string();
     tokio::spawn(async move {
@@ -1216,12 +1592,155 @@ fn spawn_fetch_provider_values(app: &App, provider_id: String, cache_key: String
     })
 }
 
-fn spawn_execute_mcp(app: &mut App<'_>, spec: CommandSpec, arguments: Map<String, Value>, request_id: u64) -> JoinHandle<ExecOutcome> {
+fn spawn_execute_mcp(
+    app: &mut App<'_>,
+    spec: CommandSpec,
+    input: String,
+    arguments: Map<String, Value>,
+    request_id: u64,
+) -> JoinHandle<ExecOutcome> {
     let active = app.active_exec_count.clone();
     active.fetch_add(1, Ordering::Relaxed);
     let engine = app.ctx.plugin_engine.clone();
+    let audit = match app.ctx.command_registry.lock() {
+        Ok(registry) => command_audit_invocation(&registry, &spec, None),
+        Err(_) => AuditInvocation::new(AuditInitiator::Human),
+    };
+    let request = ExecutionRequest {
+        method: "MCP".to_string(),
+        target: spec
+            .mcp()
+            .map(|mcp| format!("{}/{}", mcp.plugin_name, mcp.tool_name))
+            .unwrap_or_default(),
+        body: Value::Object(arguments.clone()),
+    };
+    let pending_record = pending_execution_record(&spec, &input, audit.catalog.clone(), request);
+    let journal = Arc::clone(&app.ctx.execution_journal);
+
+    tokio::spawn(async move {
+        let started_at = Instant::now();
+        let outcome = execute_mcp_task(active, engine.clone(), spec, arguments, request_id).await;
+        let completed = !matches!(outcome, ExecOutcome::Log(_));
+        audit_command_execution(&engine, audit.with_duration(started_at.elapsed()), completed).await;
+        journal_execution(journal.as_ref(), pending_record, &outcome, started_at.elapsed());
+        outcome
+    })
+}
+
+/// Journal fields known before a palette command runs.
+fn pending_execution_record(spec: &CommandSpec, input: &str, catalog: Option<String>, request: ExecutionRequest) -> ExecutionRecord {
+    ExecutionRecord {
+        command_line: input.trim().to_string(),
+        command_id: spec.canonical_id(),
+        catalog,
+        request,
+        status: ExecutionStatus::Failed,
+        status_code: None,
+        started_at: Utc::now(),
+        duration_ms: 0,
+        summary: String::new(),
+        response: Value::Null,
+        response_truncated: false,
+    }
+}
 
-    tokio::spawn(async move { execute_mcp_task(active, engine, spec, arguments, request_id).await })
+/// Build the journal request from the resolved path and body of an HTTP command.
+fn http_journal_request(spec: &CommandSpec, base_url: &str, resolved: Option<(String, Map<String, Value>)>) -> ExecutionRequest {
+    let (Some(http), Some((path, body))) = (spec.http(), resolved) else {
+        return ExecutionRequest::default();
+    };
+    ExecutionRequest {
+        method: http.method.to_ascii_uppercase(),
+        target: format!("{}{}", base_url.trim_end_matches('/'), path),
+        body: if body.is_empty() { Value::Null } else { Value::Object(body) },
+    }
+}
+
+/// Resolves the request path and body the palette input will send.
+///
+/// Falls back to the path template and an empty body when the input does not parse.
+fn resolve_http_input(spec: &CommandSpec, input: &str) -> Option<(String, Map<String, Value>)> {
+    let http = spec.http()?;
+    let tokens = lex_shell_like(input);
+    Some(match spec.parse_arguments(tokens.get(2..).unwrap_or_default()) {
+        Ok((user_flags, user_args)) => (resolve_path(&http.path, &user_args), build_request_body(spec, user_flags)),
+        Err(_) => (http.path.clone(), Map::new()),
+    })
+}
+
+/// Completes a journal record from the execution outcome and appends it.
+fn journal_execution(journal: &dyn ExecutionJournal, pending: ExecutionRecord, outcome: &ExecOutcome, elapsed: Duration) {
+    let mut record = ExecutionRecord {
+        duration_ms: elapsed.as_millis() as u64,
+        ..pending
+    };
+    let response = match outcome {
+        ExecOutcome::Http {
+            status_code,
+            log_entry,
+            payload,
+            ..
+        } => {
+            record.status = ExecutionStatus::from_http_status(*status_code);
+            record.status_code = Some(*status_code);
+            record.summary = log_entry.clone();
+            payload
+        }
+        ExecOutcome::Mcp { log_entry, payload, .. } => {
+            record.status = ExecutionStatus::Success;
+            record.summary = log_entry.clone();
+            payload
+        }
+        ExecOutcome::Log(message) => {
+            record.summary = message.clone();
+            &Value::Null
+        }
+        _ => &Value::Null,
+    };
+    record.summary = truncate_with_ellipsis(record.summary.lines().next().unwrap_or_default(), 200);
+    let record = record.redacted().with_response(response, DEFAULT_RESPONSE_SIZE_CAP);
+    if let Err(error) = journal.append(record) {
+        tracing::warn!(error = %error, "failed to write execution journal entry");
+    }
+}
+
+/// Audit fields known before a palette command runs; `path` is the resolved request path.
+fn command_audit_invocation(registry: &CommandRegistry, spec: &CommandSpec, path: Option<String>) -> AuditInvocation {
+    let catalog = registry
+        .config
+        .catalogs
+        .as_ref()
+        .and_then(|catalogs| catalogs.get(spec.catalog_identifier))
+        .map(|catalog| catalog.title.clone());
+    AuditInvocation {
+        canonical_id: Some(spec.canonical_id()),
+        catalog: catalog.or_else(|| spec.mcp().map(|mcp| mcp.plugin_name.clone())),
+        method: spec.http().map(|http| http.method.to_ascii_uppercase()),
+        path,
+        ..AuditInvocation::new(AuditInitiator::Human)
+    }
+}
+
+/// Records a palette command execution; task failures surface as `ExecOutcome::Log`.
+async fn audit_command_execution(engine: &PluginEngine, invocation: AuditInvocation, completed: bool) {
+    let result = match invocation.status {
+        Some(status) => AuditResult::from_http_status(status),
+        None if completed => AuditResult::Success,
+        None => AuditResult::Failure,
+    };
+    let entry = AuditEntry::command_execution("tui".to_string(), invocation, result);
+    if let Err(error) = engine.log_manager().log_audit(entry).await {
+        tracing::warn!(error = %error, "failed to write command audit entry");
+    }
+}
+
+/// Decrements the active execution count when a task finishes or is aborted.
+struct ActiveExecGuard(Arc<AtomicUsize>);
+
+impl Drop for ActiveExecGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
 }
 
 /// Background task body for executing an HTTP request and translating it into
@@ -1234,12 +1753,9 @@ async fn execute_http_task(
     headers: &IndexSet<EnvVar>,
     request_id: u64,
 ) -> ExecOutcome {
+    let _active = ActiveExecGuard(active_exec_count);
     let result = exec_remote_from_shell_command(&spec, base_url, headers, input, request_id).await;
-    let outcome = result.unwrap_or_else(|err| ExecOutcome::Log(format!("Error: {}", err)));
-
-    active_exec_count.fetch_sub(1, Ordering::Relaxed);
-
-    outcome
+    result.unwrap_or_else(|err| ExecOutcome::Log(format!("Error: {}", err)))
 }
 
 async fn execute_mcp_task(
@@ -1249,12 +1765,9 @@ async fn execute_mcp_task(
     arguments: Map<String, Value>,
     request_id: u64,
 ) -> ExecOutcome {
+    let _active = ActiveExecGuard(active_exec_count);
     let result = engine.execute_tool(&spec, &arguments, request_id).await;
-    let outcome = result.unwrap_or_else(|err| ExecOutcome::Log(format!("Error: {}", err)));
-
-    active_exec_count.fetch_sub(1, Ordering::Relaxed);
-
-    outcome
+    result.unwrap_or_else(|err| ExecOutcome::Log(format!("Error: {}", err)))
 }
 
 /// Executes a command from the palette input.
@@ -1357,7 +1870,12 @@ fn execute_command(command_spec: CommandSpec, hydrated_shell_command: String, re
                 body.insert(arg.to_string(), Value::String(value.to_string()));
             }
 
-            Some(vec![Cmd::ExecuteMcp(command_spec, body, request_id)])
+            Some(vec![Cmd::ExecuteMcp {
+                spec: command_spec,
+                input: hydrated_shell_command,
+                arguments: body,
+                request_id,
+            }])
         }
     }
 }
@@ -1366,6 +1884,45 @@ fn execute_command(command_spec: CommandSpec, hydrated_shell_command: String, re
 mod tests {
     use super::*;
 
+    #[test]
+    fn read_config_for_update_rejects_malformed_config() {
+        let dir = std::env::temp_dir().join(format!("oatty-mcp-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("mcp.json");
+        assert!(read_config_for_update(&path).unwrap().mcp_servers.is_empty());
+
+        std::fs::write(&path, "{ \"mcpServers\": ").unwrap();
+        assert!(read_config_for_update(&path).is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn command_audit_invocation_records_the_resolved_request_path() {
+        let spec = CommandSpec::new_http(
+            "apps".into(),
+            "info".into(),
+            "Show an app".into(),
+            vec![oatty_types::PositionalArgument {
+                name: "app".into(),
+                help: None,
+                provider: None,
+            }],
+            vec![],
+            oatty_types::HttpCommandSpec::new("GET", "/apps/{app}", None, None),
+            0,
+        );
+        let registry = CommandRegistry::default().with_commands(vec![spec.clone()]);
+
+        let resolved = resolve_http_input(&spec, "apps info web");
+        let audit = command_audit_invocation(&registry, &spec, resolved.as_ref().map(|(path, _)| path.clone()));
+
+        assert_eq!(audit.path.as_deref(), Some("/apps/web"));
+        assert_eq!(
+            http_journal_request(&spec, "https://api.example.com/", resolved).target,
+            "https://api.example.com/apps/web"
+        );
+    }
+
     #[test]
     fn sanitize_workflow_file_name_replaces_invalid_characters() {
         assert_eq!(sanitize_workflow_file_name("my workflow/v1"), "my_workflow_v1");
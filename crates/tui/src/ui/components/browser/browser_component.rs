@@ -20,10 +20,12 @@
 use crate::app::App;
 use crate::ui::components::HelpComponent;
 use crate::ui::components::browser::state::CursorDirection;
+use crate::ui::components::json_editor::JsonEditorTarget;
+use crate::ui::keymap::{KeyAction, KeyScope};
 use crate::ui::theme::theme_helpers::{create_list_with_highlight, highlight_segments};
 use crate::ui::{components::component::Component, theme::theme_helpers as th};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
-use oatty_types::{Effect, Route};
+use oatty_types::{Effect, Modal, Route};
 use ratatui::layout::Position;
 use ratatui::style::Modifier;
 use ratatui::symbols::merge::MergeStrategy;
@@ -79,6 +81,9 @@ impl Component for BrowserComponent {
     /// # Returns
     /// * `Vec<Effect>` - Effects to be processed by the runtime
     fn handle_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
+        if app.ctx.keymap.matches(KeyAction::BrowserEditJson, &key) {
+            return self.open_json_editor(app);
+        }
         let effects = self.handle_hot_keys(app, key);
 
         if app.browser.f_search.get() {
@@ -95,6 +100,7 @@ impl Component for BrowserComponent {
         effects
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_mouse_events(&mut self, app: &mut App, mouse: MouseEvent) -> Vec<Effect> {
         let effects = self.help_component.handle_mouse_events(app, mouse);
         let pos = Position {
@@ -175,15 +181,26 @@ impl Component for BrowserComponent {
     /// * `app` - The application state containing theme information
     fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
         let theme = &*app.ctx.theme;
-        let mut hints = vec![("Esc", " Clear ")];
-        if app.browser.f_commands.get() {
-            hints.push(("↑/↓", " Move  "));
-            hints.push(("PgUp/PgDn", " Page  "));
-            hints.push(("Home/End", " Jump  "));
-            hints.push(("Enter", " Send to palette  "));
-        } else {
-            hints.push(("Enter", " Send to palette  "));
+        let keymap = &app.ctx.keymap;
+        let edit_json = keymap.label(KeyAction::BrowserEditJson);
+        let has_structured_flag = app
+            .browser
+            .selected_command()
+            .is_some_and(|spec| spec.flags.iter().any(|flag| matches!(flag.r#type.as_str(), "object" | "array")));
+        let mut hints = vec![("Esc", " Clear "), ("Enter", " Send to palette  ")];
+        if has_structured_flag {
+            hints.push((&edit_json, " Edit body  "));
         }
+        if !app.browser.f_commands.get() {
+            return th::build_hint_spans(theme, &hints);
+        }
+        let scroll = keymap.label_pair(KeyAction::MoveUp, KeyAction::MoveDown);
+        let page = keymap.label_pair(KeyAction::PageUp, KeyAction::PageDown);
+        let jump = keymap.label_pair(KeyAction::ScrollTop, KeyAction::ScrollBottom);
+        hints.splice(
+            1..1,
+            [(scroll.as_str(), " Move  "), (page.as_str(), " Page  "), (jump.as_str(), " Jump  ")],
+        );
         th::build_hint_spans(theme, &hints)
     }
 
@@ -212,6 +229,7 @@ impl BrowserComponent {
     /// # Arguments
     /// * `app` - The application state to modify
     /// * `key` - The key event to process
+    #[allow(clippy::collapsible_match)]
     fn handle_search_keys(&self, app: &mut App, key: KeyEvent) {
         match key.code {
             KeyCode::Esc => {
@@ -255,6 +273,27 @@ impl BrowserComponent {
         Vec::new()
     }
 
+    /// Opens the JSON editor for the selected command's request body: the
+    /// `body` flag when present, otherwise its first object or array flag.
+    fn open_json_editor(&self, app: &mut App) -> Vec<Effect> {
+        let Some(spec) = app.browser.selected_command() else {
+            return Vec::new();
+        };
+        let structured = spec.flags.iter().filter(|flag| matches!(flag.r#type.as_str(), "object" | "array"));
+        let Some(flag) = structured
+            .clone()
+            .find(|flag| flag.name == "body")
+            .or_else(|| structured.clone().next())
+        else {
+            return Vec::new();
+        };
+        let target = JsonEditorTarget::Browser {
+            command: spec.canonical_id(),
+        };
+        app.json_editor.open(target, flag, None);
+        vec![Effect::ShowModal(Modal::JsonEditor)]
+    }
+
     /// Applies the 'enter' keypress action by switching to the palette
     /// and sending the selected command to the input
     fn apply_enter(&self, app: &App) -> Vec<Effect> {
@@ -274,38 +313,39 @@ impl BrowserComponent {
     /// * `app` - The application state to modify
     /// * `key` - The key event to process
     fn handle_commands_keys(&self, app: &mut App, key: KeyEvent) {
-        match key.code {
-            KeyCode::Down => app.browser.move_selection(CursorDirection::Down),
-            KeyCode::Up => app.browser.move_selection(CursorDirection::Up),
-            KeyCode::PageUp => {
+        match app.ctx.keymap.action(KeyScope::Navigation, &key) {
+            Some(KeyAction::MoveDown) => app.browser.move_selection(CursorDirection::Down),
+            Some(KeyAction::MoveUp) => app.browser.move_selection(CursorDirection::Up),
+            Some(KeyAction::PageUp) => {
                 app.browser.list_state.scroll_up_by(10);
                 app.browser.commit_selection();
             }
-            KeyCode::PageDown => {
+            Some(KeyAction::PageDown) => {
                 app.browser.list_state.scroll_down_by(10);
                 app.browser.commit_selection();
             }
-            KeyCode::Home => {
+            Some(KeyAction::ScrollTop) => {
                 if !app.browser.filtered().is_empty() {
                     app.browser.list_state.select(Some(0));
                     app.browser.commit_selection();
                 }
             }
-            KeyCode::End => {
+            Some(KeyAction::ScrollBottom) => {
                 let last_index = app.browser.filtered().len().saturating_sub(1);
                 if !app.browser.filtered().is_empty() {
                     app.browser.list_state.select(Some(last_index));
                     app.browser.commit_selection();
                 }
             }
-            KeyCode::Tab | KeyCode::BackTab => {
-                if key.code == KeyCode::Tab {
+            _ => match key.code {
+                KeyCode::Tab => {
                     app.focus.next();
-                } else {
+                }
+                KeyCode::BackTab => {
                     app.focus.prev();
                 }
-            }
-            _ => {}
+                _ => {}
+            },
         }
     }
 
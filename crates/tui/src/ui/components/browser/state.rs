@@ -126,7 +126,7 @@ impl BrowserState {
                     })
                     .collect();
 
-                scored.sort_by(|a, b| b.0.cmp(&a.0));
+                scored.sort_by_key(|entry| std::cmp::Reverse(entry.0));
                 self.filtered = scored.into_iter().map(|(_, idx)| idx).collect();
             }
         }
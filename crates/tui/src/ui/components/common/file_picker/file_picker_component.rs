@@ -1,4 +1,5 @@
-//! Interactive modal that lets the user browse and preview files before importing them.
+//! Interactive modal that lets the user browse and preview files before importing them,
+//! or choose where to write a file when opened in save mode.
 
 use std::path::{Path, PathBuf};
 
@@ -192,7 +193,8 @@ impl FilePickerModal {
 
         let selected = file_picker.selected_file().is_some() || file_picker.is_path_input_valid();
         let options = ButtonRenderOptions::new(selected, file_picker.f_confirm.get(), false, Borders::ALL, ButtonType::Secondary);
-        render_button(frame, layout.open_button_area, "Open", &*app.ctx.theme, options);
+        let label = if file_picker.is_save_mode() { "Save" } else { "Open" };
+        render_button(frame, layout.open_button_area, label, &*app.ctx.theme, options);
 
         Some(())
     }
@@ -218,6 +220,9 @@ impl FilePickerModal {
 
     fn maybe_commit_selection(&mut self, app: &mut App) -> Option<Vec<Effect>> {
         let file_picker = app.file_picker.as_mut()?;
+        if file_picker.is_save_mode() {
+            return self.maybe_commit_save(app);
+        }
         if let Some(selected_file) = file_picker.selected_file().cloned() {
             return if selected_file.is_directory {
                 file_picker.set_cur_dir(Some(selected_file.path.clone()));
@@ -247,6 +252,36 @@ impl FilePickerModal {
     }
 }
 
+impl FilePickerModal {
+    /// Save mode: a highlighted directory is entered from the list; otherwise the
+    /// path input names the file to write, relative to the listed directory.
+    fn maybe_commit_save(&mut self, app: &mut App) -> Option<Vec<Effect>> {
+        let file_picker = app.file_picker.as_mut()?;
+        if file_picker.f_list.get()
+            && let Some(selected_file) = file_picker.selected_file().filter(|entry| entry.is_directory).cloned()
+        {
+            file_picker.set_cur_dir(Some(selected_file.path.clone()));
+            return Some(vec![Effect::ListDirectoryContents(selected_file.path)]);
+        }
+        let Some(target) = file_picker.save_target() else {
+            file_picker.set_user_input_error(Some("Enter a file name".to_string()));
+            return None;
+        };
+        if target.is_dir() {
+            file_picker.set_cur_dir(Some(target.clone()));
+            file_picker.path_input_state_mut().clear();
+            return Some(vec![Effect::ListDirectoryContents(target)]);
+        }
+        if !file_picker.is_allowed_extension(target.extension()) {
+            let expected = file_picker.allowed_extensions().join(", .");
+            file_picker.set_user_input_error(Some(format!("Unsupported file type; expected .{expected}")));
+            return None;
+        }
+        let save_effect = file_picker.save_effect(target)?;
+        Some(vec![Effect::CloseModal, save_effect])
+    }
+}
+
 impl Component for FilePickerModal {
     fn handle_message(&mut self, app: &mut App, msg: Msg) -> Vec<Effect> {
         let (Msg::ExecCompleted(outcome), Some(file_picker)) = (msg, app.file_picker.as_mut()) else {
@@ -279,6 +314,12 @@ impl Component for FilePickerModal {
                 }
             }
 
+            KeyCode::Enter if file_picker.f_path_input.get() && file_picker.is_save_mode() => {
+                if let Some(effects) = self.maybe_commit_selection(app) {
+                    return effects;
+                }
+            }
+
             KeyCode::Char(character)
                 if file_picker.f_path_input.get()
                     && !character.is_control()
@@ -412,7 +453,12 @@ impl Component for FilePickerModal {
     }
 
     fn render(&mut self, frame: &mut Frame, rect: Rect, app: &mut App) {
-        let block = block(&*app.ctx.theme, Some("File Picker"), true);
+        let title = if app.file_picker.as_ref().is_some_and(|file_picker| file_picker.is_save_mode()) {
+            "Save File"
+        } else {
+            "File Picker"
+        };
+        let block = block(&*app.ctx.theme, Some(title), true);
 
         let mut layout = FilePickerLayout::from(self.get_preferred_layout(app, block.inner(rect)).as_slice());
         frame.render_widget(block, rect);
@@ -440,6 +486,9 @@ impl Component for FilePickerModal {
         if file_picker.f_list.get() || file_picker.f_confirm.get() {
             hints.push((" Enter/Space", " Select"));
         }
+        if file_picker.is_save_mode() && file_picker.f_path_input.get() {
+            hints.push((" Enter", " Save"));
+        }
         build_hint_spans(&*app.ctx.theme, &hints).to_vec()
     }
 
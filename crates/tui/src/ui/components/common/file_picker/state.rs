@@ -1,7 +1,7 @@
 //! State container for the shared file picker modal.
 
 use dirs_next::{desktop_dir, document_dir, download_dir, home_dir};
-use oatty_types::DirectoryEntry;
+use oatty_types::{DirectoryEntry, Effect};
 use rat_focus::{FocusFlag, HasFocus};
 use ratatui::{
     layout::Rect,
@@ -32,9 +32,14 @@ pub struct Shortcut {
 /// and the focus graph that allows keyboard navigation between controls.
 /// It also owns the `TextInputState` used to accept direct paths or URLs and performs
 /// lightweight validation on that user input before a selection is committed.
+///
+/// In save mode (see [`FilePickerState::for_save`]) the path input holds the target
+/// file name and committing emits the effect built by the save action.
 #[derive(Debug, Clone, Default)]
 pub struct FilePickerState {
     cur_dir: Option<PathBuf>,
+    listed_dir: Option<PathBuf>,
+    save_action: Option<fn(PathBuf) -> Effect>,
     dir_contents: Option<Vec<DirectoryEntry>>,
     file_contents: Option<String>,
     line_indices: Vec<(usize, usize)>,
@@ -96,6 +101,40 @@ impl FilePickerState {
         }
     }
 
+    /// Builds a save-mode picker that suggests `file_name` and turns the chosen path into an effect.
+    pub fn for_save(allowed_extensions: Vec<&'static str>, file_name: &str, save_action: fn(PathBuf) -> Effect) -> Self {
+        let mut state = Self::new(allowed_extensions);
+        state.save_action = Some(save_action);
+        state.path_input_state.set_input(file_name);
+        state.path_input_state.set_cursor(file_name.len());
+        state.validate_path_input();
+        state
+    }
+
+    /// Returns `true` when the picker chooses a file to write rather than one to open.
+    pub fn is_save_mode(&self) -> bool {
+        self.save_action.is_some()
+    }
+
+    /// Returns the effect that writes to `path`, when in save mode.
+    pub fn save_effect(&self, path: PathBuf) -> Option<Effect> {
+        self.save_action.map(|save_action| save_action(path))
+    }
+
+    /// Resolves the path input against the listed directory; absolute paths are kept as-is.
+    pub fn save_target(&self) -> Option<PathBuf> {
+        let input = self.path_input_state.input().trim();
+        if input.is_empty() {
+            return None;
+        }
+        let path = Path::new(input);
+        if path.is_absolute() {
+            return Some(path.to_path_buf());
+        }
+        let base = self.listed_dir.as_ref().or(self.cur_dir.as_ref())?;
+        Some(base.join(path))
+    }
+
     /// Returns a mutable reference to the underlying path input widget.
     pub fn path_input_state_mut(&mut self) -> &mut TextInputState {
         &mut self.path_input_state
@@ -121,7 +160,8 @@ impl FilePickerState {
 
     fn validate_path_input(&mut self) {
         let input = self.path_input_state.input();
-        self.is_path_input_valid = !input.is_empty() && (Url::parse(input).is_ok() || Path::new(input).try_exists().is_ok());
+        self.is_path_input_valid =
+            !input.is_empty() && (self.is_save_mode() || Url::parse(input).is_ok() || Path::new(input).try_exists().is_ok());
     }
 
     /// Shows or clears the inline error rendered beneath the path input.
@@ -170,6 +210,9 @@ impl FilePickerState {
 
     /// Replaces the displayed directory entries and clears the current selection.
     pub fn set_dir_contents(&mut self, maybe_contents: Option<Vec<DirectoryEntry>>) {
+        if maybe_contents.is_some() {
+            self.listed_dir = self.cur_dir.clone();
+        }
         self.dir_contents = maybe_contents;
         self.set_selected_index(None);
     }
@@ -226,13 +269,27 @@ impl FilePickerState {
             if proposed.is_directory {
                 self.cur_dir = Some(proposed.path.clone());
             }
-            self.path_input_state.clear();
+            if self.save_action.is_none() {
+                self.path_input_state.clear();
+            } else if !proposed.is_directory
+                && let Some(file_name) = proposed.path.file_name()
+            {
+                // Picking an existing file in save mode targets it for overwrite.
+                let file_name = file_name.to_string_lossy();
+                self.path_input_state.set_input(file_name.as_ref());
+                self.path_input_state.set_cursor(file_name.len());
+            }
             return Some(proposed);
         }
 
         None
     }
 
+    /// Returns the extensions the picker accepts.
+    pub fn allowed_extensions(&self) -> &[&'static str] {
+        &self.allowed_extensions
+    }
+
     /// Returns `true` when the provided extension is part of the allow-list.
     pub fn is_allowed_extension(&self, extension: Option<&OsStr>) -> bool {
         if let Some(ext) = extension
@@ -347,9 +404,12 @@ impl FilePickerState {
             Some(contents) => self.build_list_items_from_paths(contents.iter().map(|c| &c.path).collect(), theme),
         };
 
-        // Reset selection and move to first valid item
+        // Reset selection and move to first valid item; save mode keeps the
+        // suggested file name until the user picks an entry.
         self.list_state.select(None);
-        self.select_next();
+        if !self.is_save_mode() {
+            self.select_next();
+        }
 
         &self.list_items
     }
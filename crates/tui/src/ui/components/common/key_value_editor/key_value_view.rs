@@ -104,6 +104,7 @@ impl KeyValueEditorView {
     /// # Returns
     ///
     /// Outcome metadata describing edits and validation results.
+    #[allow(clippy::collapsible_match)]
     pub fn handle_key_event(&mut self, state: &mut KeyValueEditorState, key_event: KeyEvent, focus: Rc<Focus>) {
         let modifiers = key_event.modifiers;
 
@@ -5,7 +5,7 @@ use crate::ui::theme::theme_helpers::{self as th, ButtonRenderOptions, ButtonTyp
 use anyhow::{Result, anyhow};
 use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use oatty_types::Effect;
-use oatty_types::workflow::validate_candidate_value;
+use oatty_types::workflow::{coerce_input_value, validate_input_value};
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Position, Rect};
 use ratatui::text::{Line, Span};
@@ -49,9 +49,7 @@ impl ManualEntryView {
 
     fn submit(&mut self, state: &mut ManualEntryState) -> Result<Option<Value>> {
         let candidate = build_candidate_value(state)?;
-        if let Some(validation) = state.validation.as_ref() {
-            validate_candidate_value(&candidate, validation)?;
-        }
+        validate_input_value(&candidate, state.value_type, state.validation.as_ref())?;
         Ok(Some(candidate))
     }
 
@@ -68,7 +66,7 @@ impl ManualEntryView {
         }
 
         match kind {
-            ManualEntryKind::Text => {
+            ManualEntryKind::Text | ManualEntryKind::Json => {
                 state.clear_error();
                 handle_text_input(state, key);
             }
@@ -125,7 +123,7 @@ impl ManualEntryView {
                     return Vec::new();
                 }
             }
-            ManualEntryKind::Text | ManualEntryKind::Integer | ManualEntryKind::Number => {
+            ManualEntryKind::Text | ManualEntryKind::Integer | ManualEntryKind::Number | ManualEntryKind::Json => {
                 if self.layout.value_area.contains(pos) {
                     state.clear_error();
                     if let Some(buffer) = state.value.text_buffer_mut() {
@@ -148,7 +146,7 @@ impl ManualEntryView {
 
         let kind = state.kind;
         match kind {
-            ManualEntryKind::Text | ManualEntryKind::Integer | ManualEntryKind::Number => {
+            ManualEntryKind::Text | ManualEntryKind::Integer | ManualEntryKind::Number | ManualEntryKind::Json => {
                 render_text_value(frame, layout.value_area, state, theme);
             }
             ManualEntryKind::Boolean => {
@@ -185,7 +183,7 @@ impl ManualEntryView {
 
     pub fn get_hint_spans(&self, theme: &dyn Theme, state: &ManualEntryState) -> Vec<Span<'_>> {
         match state.kind {
-            ManualEntryKind::Text | ManualEntryKind::Integer | ManualEntryKind::Number => {
+            ManualEntryKind::Text | ManualEntryKind::Integer | ManualEntryKind::Number | ManualEntryKind::Json => {
                 build_hint_spans(theme, &[("Esc", " Cancel  "), ("Enter", " Confirm")])
             }
             ManualEntryKind::Boolean => build_hint_spans(theme, &[("Esc", " Cancel  "), ("Space", " Toggle  "), ("Enter", " Confirm")]),
@@ -250,6 +248,14 @@ fn build_candidate_value(state: &ManualEntryState) -> Result<Value> {
             let value = state.value.boolean().unwrap_or(false);
             Ok(Value::Bool(value))
         }
+        ManualEntryKind::Json => {
+            let buffer = state.value.text_buffer().expect("json manual entry should provide a buffer");
+            coerce_input_value(
+                Value::String(buffer.input().to_string()),
+                state.value_type,
+                state.validation.as_ref(),
+            )
+        }
         ManualEntryKind::Enum => {
             let enum_state = state.value.enum_state().expect("enum kind should expose enum state");
             let Some(index) = enum_state.selected_index() else {
@@ -275,6 +281,7 @@ fn handle_text_input(state: &mut ManualEntryState, key: KeyEvent) {
     }
 }
 
+#[allow(clippy::collapsible_match)]
 fn handle_numeric_input(state: &mut ManualEntryState, key: KeyEvent) {
     let kind = state.kind;
     let Some(buffer) = state.value.text_buffer_mut() else { return };
@@ -301,6 +308,7 @@ fn handle_boolean_input(state: &mut ManualEntryState, key: KeyEvent) {
     }
 }
 
+#[allow(clippy::collapsible_match)]
 fn handle_enum_input(state: &mut ManualEntryState, key: KeyEvent) {
     let Some(enum_state) = state.value.enum_state_mut() else { return };
     match key.code {
@@ -326,6 +334,9 @@ fn render_text_value(frame: &mut Frame, area: Rect, state: &ManualEntryState, th
         } else {
             spans.push(Span::styled("", theme.text_primary_style()));
         }
+    } else if state.secret {
+        let masked = "•".repeat(buffer.input().chars().count());
+        spans.push(Span::styled(masked, theme.text_primary_style()));
     } else {
         spans.push(Span::styled(buffer.input().to_string(), theme.text_primary_style()));
     }
@@ -382,6 +393,7 @@ fn build_value_label(state: &ManualEntryState, theme: &dyn Theme) -> Line<'stati
         ManualEntryKind::Number => "Enter a number",
         ManualEntryKind::Boolean => "Select true or false",
         ManualEntryKind::Enum => "Choose from the available options",
+        ManualEntryKind::Json => "Enter a JSON value",
     };
     Line::from(Span::styled(prompt.to_string(), theme.text_secondary_style()))
 }
@@ -458,6 +470,7 @@ mod tests {
     use crate::ui::components::common::manual_entry_modal::state::{ManualEntryEnumOption, ManualEntryValueState};
 
     use super::*;
+    use oatty_types::workflow::WorkflowInputType;
     use serde_json::json;
 
     #[test]
@@ -489,6 +502,21 @@ mod tests {
         assert!(error.to_string().contains("valid number"));
     }
 
+    #[test]
+    fn build_candidate_value_parses_json_arrays() {
+        let mut state = ManualEntryState {
+            kind: ManualEntryKind::Json,
+            value_type: Some(WorkflowInputType::Array),
+            ..Default::default()
+        };
+        let mut buffer = TextInputState::default();
+        buffer.set_input("us, eu");
+        state.value = ManualEntryValueState::Text(buffer);
+
+        let value = build_candidate_value(&state).expect("array value");
+        assert_eq!(value, json!(["us", "eu"]));
+    }
+
     #[test]
     fn build_candidate_value_returns_selected_enum_option() {
         let mut state = ManualEntryState {
@@ -8,7 +8,7 @@ use serde_json::Value as JsonValue;
 
 use crate::ui::components::common::TextInputState;
 use crate::ui::utils::render_value;
-use oatty_types::workflow::{WorkflowInputDefinition, WorkflowInputValidation};
+use oatty_types::workflow::{WorkflowInputDefinition, WorkflowInputType, WorkflowInputValidation};
 
 /// Identifies the editing mode used by the manual entry modal.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -18,6 +18,8 @@ pub enum ManualEntryKind {
     Number,
     Boolean,
     Enum,
+    /// JSON literal (arrays and objects); arrays also accept comma-separated values.
+    Json,
 }
 
 /// Represents a single selectable literal for enum-style manual entry.
@@ -141,6 +143,10 @@ pub struct ManualEntryState {
     pub example: Option<String>,
     pub error: Option<String>,
     pub validation: Option<WorkflowInputValidation>,
+    /// Declared input type used for coercion and type validation.
+    pub value_type: Option<WorkflowInputType>,
+    /// Masks the typed value when the input is declared `secret: true`.
+    pub secret: bool,
     pub kind: ManualEntryKind,
     pub value: ManualEntryValueState,
     /// Container and widget focus.
@@ -158,6 +164,8 @@ impl Default for ManualEntryState {
             example: None,
             error: None,
             validation: None,
+            value_type: None,
+            secret: false,
             kind: ManualEntryKind::Text,
             value: ManualEntryValueState::Text(TextInputState::default()),
             container_focus: FocusFlag::default(),
@@ -191,6 +199,8 @@ impl ManualEntryState {
             hint: definition.hint.clone(),
             example: definition.example.clone(),
             validation: definition.validate.clone(),
+            value_type: definition.value_type(),
+            secret: definition.secret,
             ..ManualEntryState::default()
         };
 
@@ -204,15 +214,19 @@ impl ManualEntryState {
         state.kind = inferred_kind(definition);
         state.value = match state.kind {
             ManualEntryKind::Boolean => ManualEntryValueState::Boolean(parse_boolean(existing).unwrap_or(false)),
-            ManualEntryKind::Text | ManualEntryKind::Integer | ManualEntryKind::Number => {
+            ManualEntryKind::Text | ManualEntryKind::Integer | ManualEntryKind::Number | ManualEntryKind::Json => {
                 let mut buffer = TextInputState::default();
-                let prefill = existing.and_then(render_existing_scalar).unwrap_or_default();
+                let prefill = match state.kind {
+                    ManualEntryKind::Json => existing.and_then(render_existing_json),
+                    _ => existing.and_then(render_existing_scalar),
+                }
+                .unwrap_or_default();
                 if !prefill.is_empty() {
                     buffer.set_input(prefill.clone());
                     buffer.set_cursor(prefill.len());
                 }
                 match state.kind {
-                    ManualEntryKind::Text => ManualEntryValueState::Text(buffer),
+                    ManualEntryKind::Text | ManualEntryKind::Json => ManualEntryValueState::Text(buffer),
                     ManualEntryKind::Integer | ManualEntryKind::Number => ManualEntryValueState::Number(buffer),
                     ManualEntryKind::Boolean | ManualEntryKind::Enum => unreachable!(),
                 }
@@ -233,11 +247,12 @@ impl ManualEntryState {
 }
 
 fn inferred_kind(definition: &WorkflowInputDefinition) -> ManualEntryKind {
-    match definition.r#type.as_deref().map(str::to_lowercase) {
-        Some(ref ty) if ty == "boolean" => ManualEntryKind::Boolean,
-        Some(ref ty) if ty == "integer" => ManualEntryKind::Integer,
-        Some(ref ty) if ty == "number" || ty == "float" || ty == "double" => ManualEntryKind::Number,
-        _ => ManualEntryKind::Text,
+    match definition.value_type() {
+        Some(WorkflowInputType::Boolean) => ManualEntryKind::Boolean,
+        Some(WorkflowInputType::Integer) => ManualEntryKind::Integer,
+        Some(WorkflowInputType::Number) => ManualEntryKind::Number,
+        Some(WorkflowInputType::Array | WorkflowInputType::Object) => ManualEntryKind::Json,
+        Some(WorkflowInputType::String) | None => ManualEntryKind::Text,
     }
 }
 
@@ -281,6 +296,14 @@ fn render_existing_scalar(existing: &JsonValue) -> Option<String> {
     }
 }
 
+fn render_existing_json(existing: &JsonValue) -> Option<String> {
+    match existing {
+        JsonValue::Null => None,
+        JsonValue::String(text) => Some(text.clone()),
+        other => serde_json::to_string(other).ok(),
+    }
+}
+
 fn parse_boolean(existing: Option<&JsonValue>) -> Option<bool> {
     existing.and_then(|value| {
         value.as_bool().or_else(|| {
@@ -367,4 +390,28 @@ mod tests {
         assert_eq!(state.example.as_deref(), Some("https://github.com/acme/service"));
         assert_eq!(state.placeholder.as_deref(), Some("owner/repo"));
     }
+
+    #[test]
+    fn builder_uses_json_kind_for_structured_types() {
+        let definition = WorkflowInputDefinition {
+            r#type: Some("array".to_string()),
+            ..Default::default()
+        };
+
+        let state = ManualEntryState::from_definition(&definition, "regions", Some(&json!(["us", "eu"])));
+        assert!(matches!(state.kind, ManualEntryKind::Json));
+        let buffer = state.value.text_buffer().expect("buffer present for json");
+        assert_eq!(buffer.input(), r#"["us","eu"]"#);
+    }
+
+    #[test]
+    fn builder_carries_secret_flag() {
+        let definition = WorkflowInputDefinition {
+            secret: true,
+            ..Default::default()
+        };
+
+        let state = ManualEntryState::from_definition(&definition, "token", None);
+        assert!(state.secret);
+    }
 }
@@ -158,13 +158,16 @@ impl ResultsTableView {
                 let (list_area, preview_area) = split_key_value_areas(area, state.should_show_split_preview());
                 self.fallback_list_area = Some(list_area);
                 self.split_preview_area = preview_area;
+                let highlight_changes = !state.is_in_drill_mode();
                 let items: Vec<ListItem> = state
                     .kv_entries()
                     .iter()
                     .map(|entry| {
                         let value_spans = render_value(&entry.key, &entry.raw_value, Some(theme)).into_spans();
                         let mut spans = Vec::with_capacity(value_spans.len() + 2);
-                        if matches!(entry.raw_value, Value::Object(_) | Value::Array(_)) {
+                        if highlight_changes && state.watch_changes().is_key_changed(&entry.key) {
+                            spans.push(Span::styled("● ", theme.status_warning().add_modifier(Modifier::BOLD)));
+                        } else if matches!(entry.raw_value, Value::Object(_) | Value::Array(_)) {
                             spans.push(Span::styled("› ", theme.syntax_type_style().add_modifier(Modifier::BOLD)));
                         } else {
                             spans.push(Span::styled("  ", theme.text_muted_style()));
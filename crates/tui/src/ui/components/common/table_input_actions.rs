@@ -4,7 +4,8 @@
 //! components can reuse consistent scrolling and focus behavior.
 
 use crate::ui::components::results::ResultsTableState;
-use crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind};
+use crate::ui::keymap::{KeyAction, KeyScope, Keymap};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use rat_focus::Focus;
 use rat_focus::ratatui::layout::Rect;
 use ratatui::layout::Position;
@@ -15,46 +16,37 @@ use ratatui::layout::Position;
 ///
 /// # Arguments
 ///
-/// * `key_code` - The keyboard key to handle.
+/// * `keymap` - Bindings resolving the navigation actions.
+/// * `key` - The keyboard event to handle.
 /// * `table_state` - The table state to mutate for scrolling behavior.
 /// * `focus` - The global focus ring used for Tab/BackTab traversal.
-pub fn handle_table_navigation_key(key_code: KeyCode, state: &mut ResultsTableState, focus: &Focus) -> bool {
-    let table_state = &mut state.table_state;
-    match key_code {
+pub fn handle_table_navigation_key(keymap: &Keymap, key: &KeyEvent, state: &mut ResultsTableState, focus: &Focus) -> bool {
+    match key.code {
         KeyCode::BackTab => {
             focus.prev();
+            return true;
         }
         KeyCode::Tab => {
             focus.next();
+            return true;
         }
-        KeyCode::Up => {
-            table_state.scroll_up_by(1);
-        }
-        KeyCode::Down => {
-            table_state.scroll_down_by(1);
-        }
-        KeyCode::Left => {
-            state.move_left();
-        }
-        KeyCode::Right => {
-            state.move_right();
-        }
-        KeyCode::PageUp => {
-            table_state.scroll_up_by(10);
-        }
-        KeyCode::PageDown => {
-            table_state.scroll_down_by(10);
-        }
-        KeyCode::Home => {
-            table_state.scroll_up_by(u16::MAX);
-        }
-        KeyCode::End => {
-            table_state.scroll_down_by(u16::MAX);
-        }
+        _ => {}
+    }
+    let table_state = &mut state.table_state;
+    match keymap.action(KeyScope::Navigation, key) {
+        Some(KeyAction::MoveUp) => table_state.scroll_up_by(1),
+        Some(KeyAction::MoveDown) => table_state.scroll_down_by(1),
+        Some(KeyAction::MoveLeft) => state.move_left(),
+        Some(KeyAction::MoveRight) => state.move_right(),
+        Some(KeyAction::PageUp) => table_state.scroll_up_by(10),
+        Some(KeyAction::PageDown) => table_state.scroll_down_by(10),
+        Some(KeyAction::ScrollTop) => table_state.scroll_up_by(u16::MAX),
+        Some(KeyAction::ScrollBottom) => table_state.scroll_down_by(u16::MAX),
         _ => return false,
     }
     true
 }
+
 pub fn handle_table_mouse_actions(state: &mut ResultsTableState, mouse: MouseEvent, table_area: Rect) -> bool {
     let pos = Position {
         x: mouse.column,
@@ -0,0 +1,155 @@
+use crate::app::App;
+use crate::ui::components::Component;
+use crate::ui::keymap::{KeyAction, KeyScope};
+use crate::ui::theme::Theme;
+use crate::ui::theme::theme_helpers as th;
+use crossterm::event::{KeyCode, KeyEvent};
+use oatty_types::Effect;
+use oatty_util::{JsonChange, JsonChangeKind, format_json_path};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Cell, Paragraph, Row, Table};
+use serde_json::Value;
+
+/// Side-by-side diff modal comparing two JSON payloads.
+#[derive(Debug, Default)]
+pub struct DiffComponent;
+
+impl DiffComponent {
+    fn change_style(theme: &dyn Theme, kind: JsonChangeKind) -> Style {
+        match kind {
+            JsonChangeKind::Added => theme.status_success(),
+            JsonChangeKind::Removed => theme.status_error(),
+            JsonChangeKind::Changed => theme.status_warning(),
+        }
+    }
+
+    fn value_text(value: Option<&Value>) -> String {
+        match value {
+            None => "—".to_string(),
+            Some(Value::String(text)) => text.clone(),
+            Some(other) => other.to_string(),
+        }
+    }
+
+    fn build_row<'a>(theme: &dyn Theme, change: &JsonChange, index: usize) -> Row<'a> {
+        let kind_style = Self::change_style(theme, change.kind);
+        let marker = match change.kind {
+            JsonChangeKind::Added => "+",
+            JsonChangeKind::Removed => "−",
+            JsonChangeKind::Changed => "~",
+        };
+        let before_style = if change.before.is_some() {
+            theme.status_error()
+        } else {
+            theme.text_muted_style()
+        };
+        let after_style = if change.after.is_some() {
+            theme.status_success()
+        } else {
+            theme.text_muted_style()
+        };
+        Row::new(vec![
+            Cell::from(Line::from(vec![
+                Span::styled(format!("{marker} "), kind_style.add_modifier(Modifier::BOLD)),
+                Span::styled(format_json_path(&change.path), theme.syntax_function_style()),
+            ])),
+            Cell::from(Span::styled(Self::value_text(change.before.as_ref()), before_style)),
+            Cell::from(Span::styled(Self::value_text(change.after.as_ref()), after_style)),
+        ])
+        .style(th::table_row_style(theme, index))
+    }
+
+    fn summary_line<'a>(app: &App) -> Line<'a> {
+        let theme = &*app.ctx.theme;
+        let diff = &app.diff;
+        let alignment = match diff.array_key() {
+            Some(key) => format!("arrays aligned by '{key}'"),
+            None if diff.has_key_candidates() => "arrays aligned by index".to_string(),
+            None => "arrays aligned by index (no id-like column)".to_string(),
+        };
+        Line::from(vec![
+            Span::styled(format!("+{} added  ", diff.count(JsonChangeKind::Added)), theme.status_success()),
+            Span::styled(format!("−{} removed  ", diff.count(JsonChangeKind::Removed)), theme.status_error()),
+            Span::styled(format!("~{} changed", diff.count(JsonChangeKind::Changed)), theme.status_warning()),
+            Span::styled(format!("  ·  {alignment}"), theme.text_muted_style()),
+        ])
+    }
+}
+
+impl Component for DiffComponent {
+    fn handle_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
+        if key.code == KeyCode::Esc {
+            return vec![Effect::CloseModal];
+        }
+        match app.ctx.keymap.resolve(KeyScope::Diff, &key) {
+            Some(KeyAction::DiffAlignmentKey) => app.diff.cycle_array_key(),
+            Some(KeyAction::MoveDown) => app.diff.table_state.select_next(),
+            Some(KeyAction::MoveUp) => app.diff.table_state.select_previous(),
+            Some(KeyAction::PageDown) => app.diff.table_state.scroll_down_by(10),
+            Some(KeyAction::PageUp) => app.diff.table_state.scroll_up_by(10),
+            Some(KeyAction::ScrollTop) => app.diff.table_state.select_first(),
+            Some(KeyAction::ScrollBottom) => app.diff.table_state.select_last(),
+            _ => {}
+        }
+        Vec::new()
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app: &mut App) {
+        let theme = &*app.ctx.theme;
+        let block = th::block(theme, Some("Diff"), true);
+        frame.render_widget(block.clone(), area);
+        let inner = block.inner(area);
+        let segments = Layout::vertical([Constraint::Length(4), Constraint::Min(3)]).split(inner);
+
+        let header_lines = vec![
+            Line::from(vec![
+                Span::styled("− left   ", theme.status_error()),
+                Span::styled(app.diff.left_label().to_string(), theme.text_primary_style()),
+            ]),
+            Line::from(vec![
+                Span::styled("+ right  ", theme.status_success()),
+                Span::styled(app.diff.right_label().to_string(), theme.text_primary_style()),
+            ]),
+            Self::summary_line(app),
+        ];
+        frame.render_widget(Paragraph::new(header_lines), segments[0]);
+
+        if app.diff.changes().is_empty() {
+            let empty = Paragraph::new(Line::styled("No differences.", theme.text_muted_style()));
+            frame.render_widget(empty, segments[1]);
+            return;
+        }
+
+        let rows: Vec<Row> = app
+            .diff
+            .changes()
+            .iter()
+            .enumerate()
+            .map(|(index, change)| Self::build_row(theme, change, index))
+            .collect();
+        let header = Row::new(vec!["Path", "Left", "Right"])
+            .style(th::table_header_row_style(theme))
+            .height(1);
+        let table = Table::new(
+            rows,
+            [Constraint::Percentage(34), Constraint::Percentage(33), Constraint::Percentage(33)],
+        )
+        .header(header)
+        .column_spacing(1)
+        .row_highlight_style(th::table_selected_style(theme));
+        frame.render_stateful_widget(table, segments[1], &mut app.diff.table_state);
+    }
+
+    fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
+        let keymap = &app.ctx.keymap;
+        let scroll = format!(" {}", keymap.label_pair(KeyAction::MoveUp, KeyAction::MoveDown));
+        let alignment = format!(" {}", keymap.label(KeyAction::DiffAlignmentKey));
+        th::build_hint_spans(
+            &*app.ctx.theme,
+            &[(&scroll, " Scroll  "), (&alignment, " Alignment key  "), (" Esc", " Close ")],
+        )
+    }
+}
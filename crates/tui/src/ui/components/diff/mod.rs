@@ -0,0 +1,7 @@
+//! Side-by-side JSON diff modal wiring.
+
+mod diff_component;
+mod state;
+
+pub use diff_component::DiffComponent;
+pub use state::DiffState;
@@ -0,0 +1,116 @@
+use crate::ui::components::results::diff_alignment_keys;
+use oatty_util::{JsonChange, JsonChangeKind, JsonDiffOptions, diff_json_with_options};
+use ratatui::widgets::TableState;
+use serde_json::Value;
+
+/// UI state for the side-by-side diff modal.
+///
+/// Holds the two payloads being compared and the changes between them.
+/// Top-level arrays are aligned by the selected key; cycling past the last
+/// candidate falls back to index alignment.
+#[derive(Debug, Default)]
+pub struct DiffState {
+    left_label: String,
+    right_label: String,
+    left: Value,
+    right: Value,
+    key_candidates: Vec<String>,
+    key_index: Option<usize>,
+    changes: Vec<JsonChange>,
+    pub table_state: TableState,
+}
+
+impl DiffState {
+    /// Load two payloads, defaulting array alignment to the best-ranked id-like column.
+    pub fn open(&mut self, left_label: String, left: Value, right_label: String, right: Value) {
+        self.key_candidates = diff_alignment_keys(&left, &right);
+        self.key_index = (!self.key_candidates.is_empty()).then_some(0);
+        self.left_label = left_label;
+        self.right_label = right_label;
+        self.left = left;
+        self.right = right;
+        self.recompute();
+    }
+
+    pub fn left_label(&self) -> &str {
+        &self.left_label
+    }
+
+    pub fn right_label(&self) -> &str {
+        &self.right_label
+    }
+
+    /// Key used to align top-level arrays, or `None` for index alignment.
+    pub fn array_key(&self) -> Option<&str> {
+        self.key_index.and_then(|index| self.key_candidates.get(index)).map(String::as_str)
+    }
+
+    pub fn has_key_candidates(&self) -> bool {
+        !self.key_candidates.is_empty()
+    }
+
+    /// Advance to the next alignment key: candidates in rank order, then index alignment.
+    pub fn cycle_array_key(&mut self) {
+        if self.key_candidates.is_empty() {
+            return;
+        }
+        self.key_index = match self.key_index {
+            None => Some(0),
+            Some(index) if index + 1 < self.key_candidates.len() => Some(index + 1),
+            Some(_) => None,
+        };
+        self.recompute();
+    }
+
+    pub fn changes(&self) -> &[JsonChange] {
+        &self.changes
+    }
+
+    /// Number of changes of `kind`.
+    pub fn count(&self, kind: JsonChangeKind) -> usize {
+        self.changes.iter().filter(|change| change.kind == kind).count()
+    }
+
+    fn recompute(&mut self) {
+        let options = JsonDiffOptions {
+            array_key: self.array_key().map(str::to_string),
+        };
+        self.changes = diff_json_with_options(&self.left, &self.right, &options);
+        self.table_state = TableState::default();
+        if !self.changes.is_empty() {
+            self.table_state.select(Some(0));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn open_aligns_by_id_and_cycles_to_index_alignment() {
+        let mut state = DiffState::default();
+        state.open(
+            "left".into(),
+            json!([{"id": "a", "name": "one"}, {"id": "b", "name": "two"}]),
+            "right".into(),
+            json!([{"id": "b", "name": "two"}, {"id": "a", "name": "uno"}]),
+        );
+        assert_eq!(state.array_key(), Some("id"));
+        assert_eq!(state.changes().len(), 1);
+        assert_eq!(state.count(JsonChangeKind::Changed), 1);
+
+        state.cycle_array_key();
+        assert_eq!(state.array_key(), Some("name"));
+        assert_eq!(state.count(JsonChangeKind::Removed), 1);
+        assert_eq!(state.count(JsonChangeKind::Added), 1);
+
+        state.cycle_array_key();
+        assert_eq!(state.array_key(), None);
+        assert_eq!(state.changes().len(), 4);
+
+        state.cycle_array_key();
+        assert_eq!(state.array_key(), Some("id"));
+    }
+}
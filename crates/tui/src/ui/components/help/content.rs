@@ -1,5 +1,6 @@
-//! Reusable help content builder used by HelpComponent and the Command Browser.
+//! Reusable help content builders used by HelpComponent and the Command Browser.
 
+use crate::ui::keymap::{ACTION_SPECS, KeyScope, Keymap};
 use crate::ui::theme::Theme;
 use oatty_types::{CommandSpec, command::CommandExecution};
 use ratatui::{
@@ -174,3 +175,52 @@ pub(crate) fn build_command_help_text<'a>(theme: &'a dyn Theme, spec: &CommandSp
 
     Text::from(lines)
 }
+
+/// Keys handled directly by inputs and focus management; the keymap file cannot rebind them.
+const FIXED_KEYS: [(&str, &str); 6] = [
+    ("Tab/Shift+Tab", "Move focus between panes"),
+    ("Enter", "Run, open, or confirm"),
+    ("Esc", "Close or cancel"),
+    ("Ctrl+C", "Quit"),
+    ("←/→ Home/End", "Move the cursor in text inputs"),
+    ("Backspace/Del", "Edit text inputs"),
+];
+
+/// Build the key binding reference: every action grouped by scope with its
+/// current chords, followed by the keys that cannot be rebound.
+pub(crate) fn build_keymap_help_text<'a>(theme: &'a dyn Theme, keymap: &Keymap) -> Text<'a> {
+    let heading = theme.text_primary_style().add_modifier(Modifier::BOLD);
+    let source = match keymap.path() {
+        Some(path) if path.exists() => format!("  Preset '{}' with overrides from {}", keymap.preset_kind().id(), path.display()),
+        Some(path) => format!(
+            "  Preset '{}'. Create {} to rebind keys.",
+            keymap.preset_kind().id(),
+            path.display()
+        ),
+        None => format!("  Preset '{}'", keymap.preset_kind().id()),
+    };
+    let mut lines: Vec<Line<'_>> = vec![Line::from(""), Line::styled(source, theme.text_secondary_style())];
+
+    for scope in KeyScope::ALL {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(format!(" {}:", scope.label().to_uppercase()), heading));
+        for spec in ACTION_SPECS.iter().filter(|spec| spec.scope == scope) {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<20}", keymap.label(spec.action)), theme.syntax_keyword_style()),
+                Span::styled(spec.description, theme.text_primary_style()),
+                Span::styled(format!("  {}", spec.id), theme.text_muted_style()),
+            ]));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::styled(" FIXED KEYS:", heading));
+    for (keys, description) in FIXED_KEYS {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {keys:<20}"), theme.syntax_keyword_style()),
+            Span::styled(description, theme.text_primary_style()),
+        ]));
+    }
+
+    Text::from(lines)
+}
@@ -4,11 +4,16 @@
 //! displays comprehensive documentation for Oatty commands, including usage
 //! syntax, arguments, options, and examples.
 
+use crate::ui::keymap::KeyAction;
 use crate::ui::theme::Theme;
 use crate::{
     app::App,
     ui::{
-        components::{common::render_vertical_scrollbar, component::Component, help::content::build_command_help_text},
+        components::{
+            common::render_vertical_scrollbar,
+            component::Component,
+            help::content::{build_command_help_text, build_keymap_help_text},
+        },
         theme::theme_helpers as th,
     },
 };
@@ -62,15 +67,11 @@ pub struct HelpComponent {
 
 impl Component for HelpComponent {
     fn handle_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
-        match key.code {
-            KeyCode::Esc => vec![Effect::CloseModal],
-            other => {
-                if Self::handle_scroll_key(app, other) {
-                    return Vec::new();
-                }
-                Vec::new()
-            }
+        if key.code == KeyCode::Esc {
+            return vec![Effect::CloseModal];
         }
+        Self::handle_scroll_key(app, &key);
+        Vec::new()
     }
 
     fn handle_mouse_events(&mut self, app: &mut App, mouse: MouseEvent) -> Vec<Effect> {
@@ -125,9 +126,13 @@ impl Component for HelpComponent {
     /// draw_help_modal(&mut frame, &app, area);
     /// ```
     fn render(&mut self, frame: &mut Frame, rect: Rect, app: &mut App) {
-        let spec = app.help.spec().or(app.browser.selected_command());
         let theme = &*app.ctx.theme;
-        let (title, text) = self.resolve_title_and_text(spec, theme, &app.ctx.product_name);
+        let (title, text) = if app.help.is_showing_keybindings() {
+            ("Key bindings".to_string(), build_keymap_help_text(theme, &app.ctx.keymap))
+        } else {
+            let spec = app.help.spec().or(app.browser.selected_command());
+            self.resolve_title_and_text(spec, theme, &app.ctx.product_name)
+        };
         let mut block = th::block(theme, Some(&title), self.focused);
         if self.merge_borders {
             block = block.merge_borders(MergeStrategy::Exact);
@@ -160,14 +165,13 @@ impl Component for HelpComponent {
     /// * `app` - The application state containing theme information
     fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
         let theme = &*app.ctx.theme;
+        let keymap = &app.ctx.keymap;
+        let scroll = keymap.label_pair(KeyAction::MoveUp, KeyAction::MoveDown);
+        let page = keymap.label_pair(KeyAction::PageUp, KeyAction::PageDown);
+        let jump = keymap.label_pair(KeyAction::ScrollTop, KeyAction::ScrollBottom);
         th::build_hint_spans(
             theme,
-            &[
-                ("↑/↓", " Scroll  "),
-                ("PgUp/PgDn", " Page  "),
-                ("Home/End", " Jump  "),
-                ("Esc", " Close "),
-            ],
+            &[(&scroll, " Scroll  "), (&page, " Page  "), (&jump, " Jump  "), ("Esc", " Close ")],
         )
     }
 }
@@ -181,33 +185,20 @@ impl HelpComponent {
         self.merge_borders = merge_borders;
     }
 
-    fn handle_scroll_key(app: &mut App, code: KeyCode) -> bool {
-        match code {
-            KeyCode::Up | KeyCode::Char('k') => {
-                app.help.scroll_lines(-1);
-                true
-            }
-            KeyCode::Down | KeyCode::Char('j') => {
-                app.help.scroll_lines(1);
-                true
-            }
-            KeyCode::PageUp => {
-                app.help.scroll_pages(-1);
-                true
-            }
-            KeyCode::PageDown => {
-                app.help.scroll_pages(1);
-                true
-            }
-            KeyCode::Home => {
-                app.help.scroll_to_top();
-                true
-            }
-            KeyCode::End => {
-                app.help.scroll_to_bottom();
-                true
-            }
-            _ => false,
+    fn handle_scroll_key(app: &mut App, key: &KeyEvent) {
+        let keymap = &app.ctx.keymap;
+        if keymap.matches(KeyAction::MoveUp, key) {
+            app.help.scroll_lines(-1);
+        } else if keymap.matches(KeyAction::MoveDown, key) {
+            app.help.scroll_lines(1);
+        } else if keymap.matches(KeyAction::PageUp, key) {
+            app.help.scroll_pages(-1);
+        } else if keymap.matches(KeyAction::PageDown, key) {
+            app.help.scroll_pages(1);
+        } else if keymap.matches(KeyAction::ScrollTop, key) {
+            app.help.scroll_to_top();
+        } else if keymap.matches(KeyAction::ScrollBottom, key) {
+            app.help.scroll_to_bottom();
         }
     }
 
@@ -6,10 +6,12 @@ use crate::ui::components::common::ScrollMetrics;
 ///
 /// The help modal renders potentially long paragraphs of documentation. This
 /// struct tracks which command is being displayed along with the scroll
-/// mechanics required to page through the rendered content.
+/// mechanics required to page through the rendered content. The modal can
+/// also show the key binding reference instead of a command.
 #[derive(Debug, Clone, Default)]
 pub struct HelpState {
     spec: Option<CommandSpec>,
+    showing_keybindings: bool,
     scroll_metrics: ScrollMetrics,
 }
 
@@ -23,9 +25,22 @@ impl HelpState {
     /// modal always opens at the top of the document.
     pub fn set_spec(&mut self, spec: Option<CommandSpec>) {
         self.spec = spec;
+        self.showing_keybindings = false;
         self.scroll_metrics.reset();
     }
 
+    /// Switches the modal to the key binding reference, opened at the top.
+    pub fn show_keybindings(&mut self) {
+        self.spec = None;
+        self.showing_keybindings = true;
+        self.scroll_metrics.reset();
+    }
+
+    /// Whether the modal shows the key binding reference.
+    pub fn is_showing_keybindings(&self) -> bool {
+        self.showing_keybindings
+    }
+
     /// Current vertical scroll offset in terminal rows.
     pub fn scroll_offset(&self) -> u16 {
         self.scroll_metrics.offset()
@@ -0,0 +1,490 @@
+//! Execution history route for revisiting past palette command runs.
+//!
+//! The route lists the persisted execution journal newest first with a search bar
+//! and status/catalog filters. The detail pane shows the resolved request, timing
+//! and a preview of the stored response. From the list, a stored payload can be
+//! reopened in the results table, or the command can be re-run as-is or loaded
+//! back into the palette for editing. Marking one execution and picking another
+//! opens a side-by-side diff of their stored responses.
+
+use std::hash::{DefaultHasher, Hasher};
+
+use crate::app::App;
+use crate::ui::components::common::highlight_pretty_json_lines;
+use crate::ui::components::component::Component;
+use crate::ui::components::history::DiffPick;
+use crate::ui::keymap::{KeyAction, KeyScope};
+use crate::ui::theme::Theme;
+use crate::ui::theme::theme_helpers::{self as th, create_list_with_highlight, highlight_segments};
+use chrono::Local;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use oatty_types::{Effect, ExecOutcome, Modal, Msg, Route};
+use oatty_util::{ExecutionRecord, ExecutionStatus};
+use ratatui::layout::Position;
+use ratatui::style::Modifier;
+use ratatui::symbols::merge::MergeStrategy;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect, Spacing},
+    text::{Line, Span},
+    widgets::*,
+};
+
+/// Marker left by redaction; commands carrying it cannot be replayed verbatim.
+const REDACTION_MARKER: &str = "[REDACTED]";
+/// Number of pretty-printed response lines shown in the detail pane.
+const RESPONSE_PREVIEW_LINES: usize = 40;
+
+#[derive(Debug, Default, Clone, Copy)]
+struct HistoryLayout {
+    search_area: Rect,
+    search_inner_area: Rect,
+    list_area: Rect,
+}
+
+impl From<Vec<Rect>> for HistoryLayout {
+    fn from(value: Vec<Rect>) -> Self {
+        Self {
+            search_area: value[0],
+            search_inner_area: Rect::default(),
+            list_area: value[1],
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct HistoryComponent {
+    layout: HistoryLayout,
+}
+
+impl Component for HistoryComponent {
+    fn handle_message(&mut self, app: &mut App, msg: Msg) -> Vec<Effect> {
+        let Msg::ExecCompleted(outcome) = msg else {
+            return Vec::new();
+        };
+        let request_id = match outcome.as_ref() {
+            ExecOutcome::Http { request_id, .. } | ExecOutcome::Mcp { request_id, .. } => *request_id,
+            _ => return Vec::new(),
+        };
+        app.history.reload();
+        if !app.history.take_pending_rerun(request_id) {
+            return Vec::new();
+        }
+        match outcome.as_ref() {
+            ExecOutcome::Http {
+                status_code, log_entry, ..
+            } if *status_code > 399 => {
+                app.append_log_message(format!("Re-run failed: {log_entry}"));
+                Vec::new()
+            }
+            ExecOutcome::Http { payload, .. } | ExecOutcome::Mcp { payload, .. } if payload.is_null() => {
+                app.append_log_message("Re-run completed (no response body).");
+                Vec::new()
+            }
+            _ => vec![Effect::ShowModal(Modal::Results(Some(outcome)))],
+        }
+    }
+
+    fn handle_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
+        if app.history.f_search.get() {
+            self.handle_search_keys(app, key);
+            return Vec::new();
+        }
+        if app.history.f_list.get() {
+            return self.handle_list_keys(app, key);
+        }
+        match key.code {
+            KeyCode::BackTab => app.focus.prev(),
+            KeyCode::Tab => app.focus.next(),
+            _ => false,
+        };
+        Vec::new()
+    }
+
+    fn handle_mouse_events(&mut self, app: &mut App, mouse: MouseEvent) -> Vec<Effect> {
+        let position = Position {
+            x: mouse.column,
+            y: mouse.row,
+        };
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if self.layout.search_area.contains(position) {
+                    app.focus.focus(&app.history.f_search);
+                    let relative_column = mouse.column.saturating_sub(self.layout.search_inner_area.x);
+                    app.history.set_search_cursor_from_column(relative_column);
+                }
+                if self.layout.list_area.contains(position) {
+                    app.focus.focus(&app.history.f_list);
+                    let row = position.y.saturating_sub(self.layout.list_area.y + 1) as usize + app.history.list_state.offset();
+                    if row < app.history.filtered().len() {
+                        app.history.list_state.select(Some(row));
+                    }
+                }
+            }
+            MouseEventKind::ScrollDown if self.layout.list_area.contains(position) => app.history.list_state.select_next(),
+            MouseEventKind::ScrollUp if self.layout.list_area.contains(position) => app.history.list_state.select_previous(),
+            _ => {}
+        }
+        Vec::new()
+    }
+
+    fn render(&mut self, frame: &mut Frame, rect: Rect, app: &mut App) {
+        let mut layout = HistoryLayout::from(self.get_preferred_layout(app, rect));
+        layout.search_inner_area = self.render_search_panel(frame, app, layout.search_area);
+
+        let main_areas = Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .spacing(Spacing::Overlap(1))
+            .split(layout.list_area);
+        layout.list_area = main_areas[0];
+        self.render_list_panel(frame, app, main_areas[0]);
+        self.render_detail_panel(frame, app, main_areas[1]);
+        self.layout = layout;
+    }
+
+    fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
+        let theme = &*app.ctx.theme;
+        if !app.history.f_list.get() {
+            return th::build_hint_spans(theme, &[("Esc", " Clear  "), ("↓", " Results  "), ("Tab", " Focus  ")]);
+        }
+        let keymap = &app.ctx.keymap;
+        let labels = [
+            keymap.label_pair(KeyAction::MoveUp, KeyAction::MoveDown),
+            keymap.label(KeyAction::HistoryRerun),
+            keymap.label(KeyAction::HistoryEdit),
+            keymap.label(KeyAction::HistoryDiff),
+            keymap.label(KeyAction::HistoryStatusFilter),
+            keymap.label(KeyAction::HistoryCatalogFilter),
+        ];
+        th::build_hint_spans(
+            theme,
+            &[
+                (&labels[0], " Move  "),
+                ("Enter", " Open response  "),
+                (&labels[1], " Re-run  "),
+                (&labels[2], " Edit in palette  "),
+                (&labels[3], " Diff  "),
+                (&labels[4], " Status filter  "),
+                (&labels[5], " Catalog filter  "),
+            ],
+        )
+    }
+
+    fn get_preferred_layout(&self, _app: &App, area: Rect) -> Vec<Rect> {
+        Layout::vertical([
+            Constraint::Length(3), // Search panel
+            Constraint::Min(6),    // List and details
+        ])
+        .split(area)
+        .to_vec()
+    }
+
+    fn on_route_enter(&mut self, app: &mut App) -> Vec<Effect> {
+        app.history.reload();
+        Vec::new()
+    }
+}
+
+impl HistoryComponent {
+    fn handle_search_keys(&self, app: &mut App, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => app.history.clear_search_query(),
+            KeyCode::Char(character) if (key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT) && !character.is_control() => {
+                app.history.append_search_character(character);
+            }
+            KeyCode::Backspace => app.history.remove_search_character(),
+            KeyCode::Left => app.history.move_search_cursor_left(),
+            KeyCode::Right => app.history.move_search_cursor_right(),
+            KeyCode::Down | KeyCode::Enter => app.focus.focus(&app.history.f_list),
+            KeyCode::Tab => {
+                app.focus.next();
+            }
+            KeyCode::BackTab => {
+                app.focus.prev();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_list_keys(&self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
+        match app.ctx.keymap.resolve(KeyScope::History, &key) {
+            Some(KeyAction::MoveDown) => app.history.list_state.select_next(),
+            Some(KeyAction::MoveUp) if app.history.list_state.selected().is_none_or(|selected| selected == 0) => {
+                app.focus.focus(&app.history.f_search);
+            }
+            Some(KeyAction::MoveUp) => app.history.list_state.select_previous(),
+            Some(KeyAction::PageDown) => app.history.list_state.scroll_down_by(10),
+            Some(KeyAction::PageUp) => app.history.list_state.scroll_up_by(10),
+            Some(KeyAction::ScrollTop) => app.history.list_state.select_first(),
+            Some(KeyAction::ScrollBottom) => app.history.list_state.select_last(),
+            Some(KeyAction::HistoryRerun) => return self.rerun(app),
+            Some(KeyAction::HistoryEdit) => return self.edit_in_palette(app),
+            Some(KeyAction::HistoryDiff) => return self.pick_for_diff(app),
+            Some(KeyAction::HistoryStatusFilter) => app.history.cycle_status_filter(),
+            Some(KeyAction::HistoryCatalogFilter) => app.history.cycle_catalog_filter(),
+            _ => return self.handle_list_fixed_keys(app, key),
+        }
+        Vec::new()
+    }
+
+    fn handle_list_fixed_keys(&self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
+        match key.code {
+            KeyCode::Enter => return self.open_response(app),
+            KeyCode::Esc => app.focus.focus(&app.history.f_search),
+            KeyCode::Tab => {
+                app.focus.next();
+            }
+            KeyCode::BackTab => {
+                app.focus.prev();
+            }
+            _ => {}
+        }
+        Vec::new()
+    }
+
+    /// Reopens the stored payload in the results table.
+    fn open_response(&self, app: &mut App) -> Vec<Effect> {
+        let Some(record) = app.history.selected_record() else {
+            return Vec::new();
+        };
+        if record.response.is_null() {
+            app.append_log_message(format!("No stored response for '{}'.", record.command_line));
+            return Vec::new();
+        }
+        let outcome = match record.status_code {
+            Some(status_code) => ExecOutcome::Http {
+                status_code,
+                log_entry: record.summary.clone(),
+                payload: record.response.clone(),
+                request_id: 0,
+            },
+            None => ExecOutcome::Mcp {
+                log_entry: record.summary.clone(),
+                payload: record.response.clone(),
+                request_id: 0,
+            },
+        };
+        let watch_source = (!record.command_line.contains(REDACTION_MARKER)).then(|| record.command_line.clone());
+        app.results.set_pending_source(watch_source);
+        vec![Effect::ShowModal(Modal::Results(Some(Box::new(outcome))))]
+    }
+
+    /// Runs the stored command line again; the outcome opens in the results table.
+    fn rerun(&self, app: &mut App) -> Vec<Effect> {
+        let Some(command_line) = app.history.selected_record().map(|record| record.command_line.clone()) else {
+            return Vec::new();
+        };
+        if command_line.contains(REDACTION_MARKER) {
+            app.append_log_message("This command was stored with redacted values; press 'e' to edit it before running.");
+            return Vec::new();
+        }
+        let mut hasher = DefaultHasher::new();
+        hasher.write(command_line.as_bytes());
+        let request_hash = hasher.finish();
+        app.history.set_pending_rerun(request_hash);
+        app.results.set_pending_source(Some(command_line.clone()));
+        vec![Effect::Run {
+            hydrated_command: command_line,
+            request_hash,
+        }]
+    }
+
+    /// Marks the selected execution for diffing, or diffs it against the marked one.
+    fn pick_for_diff(&self, app: &mut App) -> Vec<Effect> {
+        if app.history.selected_record().is_some_and(|record| record.response.is_null()) {
+            app.append_log_message("No stored response to diff for this execution.");
+            return Vec::new();
+        }
+        match app.history.pick_for_diff() {
+            Some(DiffPick::Marked) => {
+                app.append_log_message("Marked for diff; press 'd' on another execution to compare.");
+                Vec::new()
+            }
+            Some(DiffPick::Cleared) => {
+                app.append_log_message("Diff mark cleared.");
+                Vec::new()
+            }
+            Some(DiffPick::Pair(left, right)) => {
+                app.diff.open(diff_label(&left), left.response, diff_label(&right), right.response);
+                vec![Effect::ShowModal(Modal::Diff)]
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Loads the stored command line into the palette for editing.
+    fn edit_in_palette(&self, app: &mut App) -> Vec<Effect> {
+        let Some(command_line) = app.history.selected_record().map(|record| record.command_line.clone()) else {
+            return Vec::new();
+        };
+        let cursor = command_line.len();
+        app.palette.set_input(command_line);
+        app.palette.set_cursor(cursor);
+        let mut effects = vec![Effect::SwitchTo(Route::Palette)];
+        effects.extend(app.rebuild_palette_suggestions());
+        effects
+    }
+
+    fn render_search_panel(&self, frame: &mut Frame, app: &mut App, area: Rect) -> Rect {
+        let theme = &*app.ctx.theme;
+        let is_focused = app.history.f_search.get();
+        let title = Line::from(Span::styled(
+            "Filter History",
+            theme.text_secondary_style().add_modifier(Modifier::BOLD),
+        ));
+        let block = th::block::<String>(theme, None, is_focused).title(title);
+        let inner_area = block.inner(area);
+        let query = app.history.search_query();
+        let paragraph = Paragraph::new(Line::from(Span::styled(query.to_string(), theme.text_primary_style())))
+            .style(theme.text_primary_style())
+            .block(block);
+        frame.render_widget(paragraph, area);
+        if is_focused {
+            let cursor_x = inner_area.x.saturating_add(app.history.search_cursor_columns() as u16);
+            frame.set_cursor_position((cursor_x, inner_area.y));
+        }
+        inner_area
+    }
+
+    fn render_list_panel(&self, frame: &mut Frame, app: &mut App, area: Rect) {
+        let theme = &*app.ctx.theme;
+        let history = &mut app.history;
+        let status_label = history.status_filter().map_or("all", ExecutionStatus::label);
+        let catalog_label = history.catalog_filter().unwrap_or("all catalogs");
+        let title = format!("Executions ({}) · {} · {}", history.filtered().len(), status_label, catalog_label);
+        let is_focused = history.f_list.get();
+        let block = th::block(theme, Some(&title), is_focused).merge_borders(MergeStrategy::Exact);
+        let query = history.search_query();
+        let items: Vec<ListItem<'_>> = history
+            .filtered()
+            .iter()
+            .filter_map(|index| history.records().get(*index))
+            .map(|record| {
+                let (marker, marker_style) = match record.status {
+                    _ if history.is_diff_base(record) => ("◆", theme.status_warning()),
+                    ExecutionStatus::Success => ("✓", theme.status_success()),
+                    ExecutionStatus::Failed => ("✗", theme.status_error()),
+                };
+                let mut spans = vec![
+                    Span::styled(format!("{marker} "), marker_style),
+                    Span::styled(
+                        format!("{} ", record.started_at.with_timezone(&Local).format("%m-%d %H:%M")),
+                        theme.text_muted_style(),
+                    ),
+                ];
+                spans.extend(highlight_segments(
+                    query,
+                    &record.command_line,
+                    theme.syntax_function_style(),
+                    theme.search_highlight_style(),
+                ));
+                ListItem::new(Line::from(spans)).style(theme.text_primary_style())
+            })
+            .collect();
+        let list = create_list_with_highlight(items, theme, is_focused, Some(block));
+        frame.render_stateful_widget(list, area, &mut history.list_state);
+    }
+
+    fn render_detail_panel(&self, frame: &mut Frame, app: &mut App, area: Rect) {
+        let theme = &*app.ctx.theme;
+        let block = th::block(theme, Some("Details"), false).merge_borders(MergeStrategy::Exact);
+        let Some(record) = app.history.selected_record() else {
+            let placeholder = Paragraph::new(Line::from(Span::styled(
+                "No executions recorded yet. Commands run from the palette appear here.",
+                theme.text_muted_style(),
+            )))
+            .wrap(Wrap { trim: false })
+            .block(block);
+            frame.render_widget(placeholder, area);
+            return;
+        };
+        let pretty_response = if record.response.is_null() {
+            String::new()
+        } else {
+            serde_json::to_string_pretty(&record.response).unwrap_or_default()
+        };
+        let pretty_body = if record.request.body.is_null() {
+            String::new()
+        } else {
+            serde_json::to_string_pretty(&record.request.body).unwrap_or_default()
+        };
+        let mut lines = detail_lines(theme, record);
+        if !pretty_body.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::styled(
+                "Request body",
+                theme.text_secondary_style().add_modifier(Modifier::BOLD),
+            ));
+            lines.extend(highlight_pretty_json_lines(&pretty_body, theme));
+        }
+        lines.push(Line::from(""));
+        let response_title = if record.response_truncated {
+            "Response (truncated)"
+        } else {
+            "Response"
+        };
+        lines.push(Line::styled(
+            response_title,
+            theme.text_secondary_style().add_modifier(Modifier::BOLD),
+        ));
+        if pretty_response.is_empty() {
+            lines.push(Line::styled("No response body stored.", theme.text_muted_style()));
+        } else {
+            lines.extend(
+                highlight_pretty_json_lines(&pretty_response, theme)
+                    .into_iter()
+                    .take(RESPONSE_PREVIEW_LINES),
+            );
+        }
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(block);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+fn diff_label(record: &ExecutionRecord) -> String {
+    let started_at = record.started_at.with_timezone(&Local).format("%m-%d %H:%M:%S");
+    let truncated = if record.response_truncated { " (truncated)" } else { "" };
+    format!("{} · {started_at}{truncated}", record.command_line)
+}
+
+fn detail_lines<'a>(theme: &dyn Theme, record: &'a ExecutionRecord) -> Vec<Line<'a>> {
+    let label = |text: &'a str| Span::styled(format!("{text:<10}"), theme.text_muted_style());
+    let status_text = match record.status_code {
+        Some(code) => format!("{} ({code})", record.status.label()),
+        None => record.status.label().to_string(),
+    };
+    let status_style = match record.status {
+        ExecutionStatus::Success => theme.status_success(),
+        ExecutionStatus::Failed => theme.status_error(),
+    };
+    vec![
+        Line::from(vec![
+            label("Command"),
+            Span::styled(record.command_line.as_str(), theme.syntax_function_style()),
+        ]),
+        Line::from(vec![
+            label("Catalog"),
+            Span::styled(record.catalog.as_deref().unwrap_or("—"), theme.syntax_type_style()),
+        ]),
+        Line::from(vec![
+            label("Request"),
+            Span::styled(format!("{} ", record.request.method), theme.syntax_keyword_style()),
+            Span::styled(record.request.target.as_str(), theme.syntax_string_style()),
+        ]),
+        Line::from(vec![label("Status"), Span::styled(status_text, status_style)]),
+        Line::from(vec![
+            label("Started"),
+            Span::styled(
+                record.started_at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string(),
+                theme.text_primary_style(),
+            ),
+        ]),
+        Line::from(vec![
+            label("Duration"),
+            Span::styled(format!("{} ms", record.duration_ms), theme.text_primary_style()),
+        ]),
+        Line::from(vec![
+            label("Summary"),
+            Span::styled(record.summary.as_str(), theme.text_primary_style()),
+        ]),
+    ]
+}
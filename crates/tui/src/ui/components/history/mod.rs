@@ -0,0 +1,5 @@
+mod history_component;
+mod state;
+
+pub use history_component::HistoryComponent;
+pub use state::{DiffPick, HistoryState};
@@ -0,0 +1,340 @@
+use std::sync::Arc;
+
+use crate::ui::components::common::TextInputState;
+use oatty_util::{ExecutionJournal, ExecutionRecord, ExecutionStatus, fuzzy_score};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use ratatui::layout::Rect;
+use ratatui::widgets::ListState;
+use tracing::warn;
+
+/// State backing the execution History route.
+///
+/// Records are loaded from the execution journal on route entry and after each
+/// palette execution; the list shows them newest first, narrowed by the search
+/// query and the optional status and catalog filters.
+pub struct HistoryState {
+    journal: Arc<dyn ExecutionJournal>,
+    records: Vec<ExecutionRecord>,
+    filtered: Vec<usize>,
+    search_input: TextInputState,
+    status_filter: Option<ExecutionStatus>,
+    catalog_filter: Option<String>,
+    pending_rerun: Option<u64>,
+    diff_base: Option<ExecutionRecord>,
+    pub list_state: ListState,
+
+    container_focus: FocusFlag,
+    pub f_search: FocusFlag,
+    pub f_list: FocusFlag,
+}
+
+impl HistoryState {
+    pub fn new(journal: Arc<dyn ExecutionJournal>) -> Self {
+        Self {
+            journal,
+            records: Vec::new(),
+            filtered: Vec::new(),
+            search_input: TextInputState::new(),
+            status_filter: None,
+            catalog_filter: None,
+            pending_rerun: None,
+            diff_base: None,
+            list_state: ListState::default(),
+            container_focus: FocusFlag::new().with_name("history"),
+            f_search: FocusFlag::new().with_name("history.search"),
+            f_list: FocusFlag::new().with_name("history.list"),
+        }
+    }
+
+    /// Re-read the journal, keeping the current filters.
+    pub fn reload(&mut self) {
+        self.records = match self.journal.records() {
+            Ok(records) => records,
+            Err(error) => {
+                warn!(error = %error, "failed to read execution journal");
+                Vec::new()
+            }
+        };
+        if self
+            .catalog_filter
+            .as_ref()
+            .is_some_and(|catalog| !self.records.iter().any(|record| record.catalog.as_ref() == Some(catalog)))
+        {
+            self.catalog_filter = None;
+        }
+        self.update_filtered();
+    }
+
+    // ========================
+    // Search & Filters
+    // ========================
+    pub fn search_query(&self) -> &str {
+        self.search_input.input()
+    }
+
+    /// Returns the search cursor position in display columns (character count).
+    pub fn search_cursor_columns(&self) -> usize {
+        self.search_input.cursor_columns()
+    }
+
+    /// Sets the search cursor based on a display column within the search input.
+    pub fn set_search_cursor_from_column(&mut self, column: u16) {
+        let cursor = self.search_input.cursor_index_for_column(column);
+        self.search_input.set_cursor(cursor);
+    }
+
+    pub fn move_search_cursor_left(&mut self) {
+        self.search_input.move_left();
+    }
+
+    pub fn move_search_cursor_right(&mut self) {
+        self.search_input.move_right();
+    }
+
+    pub fn append_search_character(&mut self, character: char) {
+        self.search_input.insert_char(character);
+        self.update_filtered();
+    }
+
+    pub fn remove_search_character(&mut self) {
+        self.search_input.backspace();
+        self.update_filtered();
+    }
+
+    pub fn clear_search_query(&mut self) {
+        if self.search_input.input().is_empty() && self.search_input.cursor() == 0 {
+            return;
+        }
+        self.search_input.set_input("");
+        self.search_input.set_cursor(0);
+        self.update_filtered();
+    }
+
+    pub fn status_filter(&self) -> Option<ExecutionStatus> {
+        self.status_filter
+    }
+
+    pub fn catalog_filter(&self) -> Option<&str> {
+        self.catalog_filter.as_deref()
+    }
+
+    /// Cycles the status filter: all → success → failed → all.
+    pub fn cycle_status_filter(&mut self) {
+        self.status_filter = match self.status_filter {
+            None => Some(ExecutionStatus::Success),
+            Some(ExecutionStatus::Success) => Some(ExecutionStatus::Failed),
+            Some(ExecutionStatus::Failed) => None,
+        };
+        self.update_filtered();
+    }
+
+    /// Cycles the catalog filter through the catalogs present in the journal.
+    pub fn cycle_catalog_filter(&mut self) {
+        let mut catalogs: Vec<&String> = self.records.iter().filter_map(|record| record.catalog.as_ref()).collect();
+        catalogs.sort();
+        catalogs.dedup();
+        let next_index = match &self.catalog_filter {
+            None => 0,
+            Some(current) => catalogs.iter().position(|catalog| *catalog == current).map_or(0, |index| index + 1),
+        };
+        self.catalog_filter = catalogs.get(next_index).map(|catalog| catalog.to_string());
+        self.update_filtered();
+    }
+
+    // ========================
+    // Filtered List
+    // ========================
+    pub fn records(&self) -> &[ExecutionRecord] {
+        &self.records
+    }
+
+    pub fn filtered(&self) -> &[usize] {
+        &self.filtered
+    }
+
+    pub fn selected_record(&self) -> Option<&ExecutionRecord> {
+        let selected = self.list_state.selected()?;
+        self.filtered.get(selected).and_then(|index| self.records.get(*index))
+    }
+
+    /// Rebuilds the filtered list, preserving newest-first order.
+    fn update_filtered(&mut self) {
+        let query = self.search_input.input().trim();
+        self.filtered = self
+            .records
+            .iter()
+            .enumerate()
+            .filter(|(_, record)| self.status_filter.is_none_or(|status| record.status == status))
+            .filter(|(_, record)| self.catalog_filter.is_none() || record.catalog == self.catalog_filter)
+            .filter(|(_, record)| query.is_empty() || fuzzy_score(&record.search_text(), query).is_some())
+            .map(|(index, _)| index)
+            .collect();
+
+        let selected = match self.list_state.selected() {
+            _ if self.filtered.is_empty() => None,
+            Some(selected) => Some(selected.min(self.filtered.len() - 1)),
+            None => Some(0),
+        };
+        self.list_state.select(selected);
+    }
+
+    // ========================
+    // Re-run correlation
+    // ========================
+    /// Remember the request id of a re-run so its outcome opens in the results view.
+    pub fn set_pending_rerun(&mut self, request_id: u64) {
+        self.pending_rerun = Some(request_id);
+    }
+
+    /// Returns `true` (and clears the marker) when `request_id` belongs to a re-run started here.
+    pub fn take_pending_rerun(&mut self, request_id: u64) -> bool {
+        if self.pending_rerun == Some(request_id) {
+            self.pending_rerun = None;
+            return true;
+        }
+        false
+    }
+
+    // ========================
+    // Diff selection
+    // ========================
+    /// Returns `true` when `record` is the one marked as the left side of a diff.
+    pub fn is_diff_base(&self, record: &ExecutionRecord) -> bool {
+        self.diff_base.as_ref().is_some_and(|base| base == record)
+    }
+
+    /// Marks the selected record for diffing, or pairs it with the previously marked one.
+    pub fn pick_for_diff(&mut self) -> Option<DiffPick> {
+        let selected = self.selected_record()?.clone();
+        match self.diff_base.take() {
+            None => {
+                self.diff_base = Some(selected);
+                Some(DiffPick::Marked)
+            }
+            Some(base) if base == selected => Some(DiffPick::Cleared),
+            Some(base) => Some(DiffPick::Pair(Box::new(base), Box::new(selected))),
+        }
+    }
+}
+
+/// Result of pressing the diff key on the selected record.
+#[derive(Debug)]
+pub enum DiffPick {
+    /// The selected record became the left side of the next diff.
+    Marked,
+    /// The selected record was already marked; the mark was removed.
+    Cleared,
+    /// A second record was picked: `(marked, selected)`.
+    Pair(Box<ExecutionRecord>, Box<ExecutionRecord>),
+}
+
+impl HasFocus for HistoryState {
+    fn build(&self, builder: &mut FocusBuilder) {
+        let tag = builder.start(self);
+        builder.leaf_widget(&self.f_search);
+        builder.leaf_widget(&self.f_list);
+        builder.end(tag);
+    }
+
+    fn focus(&self) -> FocusFlag {
+        self.container_focus.clone()
+    }
+
+    fn area(&self) -> Rect {
+        Rect::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use oatty_util::{ExecutionRequest, InMemoryExecutionJournal};
+    use serde_json::Value;
+
+    fn record(command_line: &str, catalog: &str, status: ExecutionStatus) -> ExecutionRecord {
+        ExecutionRecord {
+            command_line: command_line.to_string(),
+            command_id: "apps list".to_string(),
+            catalog: Some(catalog.to_string()),
+            request: ExecutionRequest::default(),
+            status,
+            status_code: None,
+            started_at: Utc::now(),
+            duration_ms: 0,
+            summary: String::new(),
+            response: Value::Null,
+            response_truncated: false,
+        }
+    }
+
+    fn build_state() -> HistoryState {
+        let journal = Arc::new(InMemoryExecutionJournal::new());
+        journal.append(record("apps list", "Alpha", ExecutionStatus::Success)).unwrap();
+        journal.append(record("apps info app-1", "Beta", ExecutionStatus::Failed)).unwrap();
+        journal.append(record("apps info app-2", "Alpha", ExecutionStatus::Failed)).unwrap();
+        let mut state = HistoryState::new(journal);
+        state.reload();
+        state
+    }
+
+    fn filtered_commands(state: &HistoryState) -> Vec<&str> {
+        state
+            .filtered()
+            .iter()
+            .map(|index| state.records()[*index].command_line.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn reload_lists_newest_first_and_selects_first() {
+        let state = build_state();
+        assert_eq!(filtered_commands(&state), vec!["apps info app-2", "apps info app-1", "apps list"]);
+        assert_eq!(state.selected_record().unwrap().command_line, "apps info app-2");
+    }
+
+    #[test]
+    fn status_and_catalog_filters_combine_with_search() {
+        let mut state = build_state();
+        state.cycle_status_filter();
+        state.cycle_status_filter();
+        assert_eq!(state.status_filter(), Some(ExecutionStatus::Failed));
+        assert_eq!(filtered_commands(&state), vec!["apps info app-2", "apps info app-1"]);
+
+        state.cycle_catalog_filter();
+        assert_eq!(state.catalog_filter(), Some("Alpha"));
+        assert_eq!(filtered_commands(&state), vec!["apps info app-2"]);
+
+        state.cycle_catalog_filter();
+        state.append_search_character('2');
+        assert_eq!(state.catalog_filter(), Some("Beta"));
+        assert!(filtered_commands(&state).is_empty());
+        assert!(state.selected_record().is_none());
+    }
+
+    #[test]
+    fn diff_pick_marks_then_pairs_records() {
+        let mut state = build_state();
+        assert!(matches!(state.pick_for_diff(), Some(DiffPick::Marked)));
+        assert!(state.is_diff_base(state.selected_record().unwrap()));
+        assert!(matches!(state.pick_for_diff(), Some(DiffPick::Cleared)));
+
+        state.pick_for_diff();
+        state.list_state.select(Some(2));
+        let Some(DiffPick::Pair(left, right)) = state.pick_for_diff() else {
+            panic!("expected a pair");
+        };
+        assert_eq!(left.command_line, "apps info app-2");
+        assert_eq!(right.command_line, "apps list");
+        assert!(!state.is_diff_base(&right));
+    }
+
+    #[test]
+    fn pending_rerun_matches_once() {
+        let mut state = build_state();
+        state.set_pending_rerun(7);
+        assert!(!state.take_pending_rerun(8));
+        assert!(state.take_pending_rerun(7));
+        assert!(!state.take_pending_rerun(7));
+    }
+}
@@ -0,0 +1,243 @@
+use crate::app::App;
+use crate::ui::components::Component;
+use crate::ui::components::common::highlight_pretty_json_lines;
+use crate::ui::components::json_editor::JsonEditorTarget;
+use crate::ui::keymap::{KeyAction, KeyScope};
+use crate::ui::theme::theme_helpers::{self as th, create_list_with_highlight};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use oatty_types::{Effect, ExecOutcome, Modal, Msg, Route};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, ListItem, Paragraph};
+
+/// Rows moved by `PgUp`/`PgDn`.
+const PAGE_ROWS: isize = 10;
+/// Maximum number of completion candidates shown at once.
+const MAX_COMPLETION_ROWS: u16 = 6;
+
+/// Multi-line JSON editor for object and array flags, opened from the palette
+/// or the command browser.
+#[derive(Debug, Default)]
+pub struct JsonEditorComponent {
+    /// Whether a file picked through `json_editor.load` is being read.
+    awaiting_file: bool,
+}
+
+impl JsonEditorComponent {
+    /// Write the value back to the palette as compact JSON, which the palette
+    /// lexer keeps as a single token.
+    fn apply(&self, app: &mut App) -> Vec<Effect> {
+        let value = match app.json_editor.value() {
+            Ok(value) => value,
+            Err(error) => {
+                app.json_editor.set_status(format!("Cannot apply: {}", error.message));
+                return Vec::new();
+            }
+        };
+        let flag = app.json_editor.flag_name().to_string();
+        let mut effects = vec![Effect::CloseModal];
+        if let Some(JsonEditorTarget::Browser { command }) = app.json_editor.target() {
+            app.palette.set_input(command.clone());
+            effects.push(Effect::SwitchTo(Route::Palette));
+        }
+        app.palette.apply_set_flag_value(&flag, &value.to_string());
+        app.palette.reduce_clear_error();
+        effects.extend(app.rebuild_palette_suggestions());
+        effects
+    }
+
+    fn handle_completion_keys(&self, app: &mut App, key: KeyEvent) -> bool {
+        let editor = &mut app.json_editor;
+        if editor.completion_candidates().is_none() {
+            return false;
+        }
+        match key.code {
+            KeyCode::Esc => editor.close_completion(),
+            KeyCode::Enter | KeyCode::Tab => editor.accept_completion(),
+            KeyCode::Up => editor.completion_list.select_previous(),
+            KeyCode::Down => editor.completion_list.select_next(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn handle_editing_keys(&self, app: &mut App, key: KeyEvent) {
+        let editor = &mut app.json_editor;
+        match key.code {
+            KeyCode::Char(character) if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT => editor.insert_char(character),
+            KeyCode::Enter => editor.insert_newline(),
+            KeyCode::Tab => editor.indent(),
+            KeyCode::Backspace => editor.backspace(),
+            KeyCode::Delete => editor.delete(),
+            KeyCode::Left => editor.move_left(),
+            KeyCode::Right => editor.move_right(),
+            KeyCode::Up => editor.move_vertical(-1),
+            KeyCode::Down => editor.move_vertical(1),
+            KeyCode::PageUp => editor.move_vertical(-PAGE_ROWS),
+            KeyCode::PageDown => editor.move_vertical(PAGE_ROWS),
+            KeyCode::Home => editor.move_line_start(),
+            KeyCode::End => editor.move_line_end(),
+            _ => {}
+        }
+    }
+
+    /// Scroll just enough to keep the cursor inside `area`.
+    fn follow_cursor(app: &mut App, area: Rect) {
+        let (row, column) = app.json_editor.cursor();
+        let (top, left) = &mut app.json_editor.scroll;
+        let height = area.height.max(1) as usize;
+        let width = area.width.max(1) as usize;
+        if row < *top as usize {
+            *top = row as u16;
+        } else if row >= *top as usize + height {
+            *top = (row + 1 - height) as u16;
+        }
+        if column < *left as usize {
+            *left = column as u16;
+        } else if column >= *left as usize + width {
+            *left = (column + 1 - width) as u16;
+        }
+    }
+
+    fn status_line<'a>(app: &App) -> Line<'a> {
+        let theme = &*app.ctx.theme;
+        let editor = &app.json_editor;
+        if let Some(error) = editor.error() {
+            return Line::styled(format!("✗ {}", error.message), theme.status_error());
+        }
+        if let Some(status) = editor.status() {
+            return Line::styled(status.to_string(), theme.text_muted_style());
+        }
+        Line::styled(format!("✓ Valid JSON {}", editor.flag_type()), theme.status_success())
+    }
+
+    fn render_completion(&self, frame: &mut Frame, app: &mut App, area: Rect) {
+        let Some(candidates) = app.json_editor.completion_candidates() else {
+            return;
+        };
+        let theme = &*app.ctx.theme;
+        let width = candidates.iter().map(|name| name.chars().count()).max().unwrap_or(0) as u16 + 4;
+        let height = (candidates.len() as u16).min(MAX_COMPLETION_ROWS) + 2;
+        let (row, column) = app.json_editor.cursor();
+        let (top, left) = app.json_editor.scroll;
+        let x = (area.x + (column as u16).saturating_sub(left)).min(area.right().saturating_sub(width));
+        let below = area.y + (row as u16).saturating_sub(top) + 1;
+        let y = if below + height <= area.bottom() {
+            below
+        } else {
+            below.saturating_sub(height + 1).max(area.y)
+        };
+        let popup = Rect::new(x.max(area.x), y, width.min(area.width), height.min(area.height));
+        let items: Vec<ListItem> = candidates
+            .iter()
+            .map(|name| ListItem::new(Span::styled(name.clone(), theme.syntax_string_style())))
+            .collect();
+        let list = create_list_with_highlight(items, theme, true, Some(th::block::<String>(theme, None, true)));
+        frame.render_widget(Clear, popup);
+        frame.render_stateful_widget(list, popup, &mut app.json_editor.completion_list);
+    }
+}
+
+impl Component for JsonEditorComponent {
+    fn handle_message(&mut self, app: &mut App, msg: Msg) -> Vec<Effect> {
+        let Msg::ExecCompleted(outcome) = msg else {
+            return Vec::new();
+        };
+        if !self.awaiting_file {
+            return Vec::new();
+        }
+        match *outcome {
+            ExecOutcome::FileContents(contents, path) => {
+                app.json_editor.set_text(&contents);
+                app.json_editor.set_status(format!("Loaded {}", path.display()));
+            }
+            ExecOutcome::RemoteFileContents(contents, url) => {
+                app.json_editor.set_text(&contents);
+                app.json_editor.set_status(format!("Loaded {url}"));
+            }
+            ExecOutcome::Log(message) => app.json_editor.set_status(message),
+            _ => return Vec::new(),
+        }
+        self.awaiting_file = false;
+        Vec::new()
+    }
+
+    fn handle_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
+        if self.handle_completion_keys(app, key) {
+            return Vec::new();
+        }
+        if key.code == KeyCode::Esc {
+            return vec![Effect::CloseModal];
+        }
+        match app.ctx.keymap.action(KeyScope::JsonEditor, &key) {
+            Some(KeyAction::JsonEditorApply) => return self.apply(app),
+            Some(KeyAction::JsonEditorComplete) => app.json_editor.open_completion(),
+            Some(KeyAction::JsonEditorFormat) => app.json_editor.format(),
+            Some(KeyAction::JsonEditorSkeleton) => app.json_editor.reset_to_skeleton(),
+            Some(KeyAction::JsonEditorLoad) => {
+                self.awaiting_file = true;
+                return vec![Effect::ShowModal(Modal::JsonEditorLoad)];
+            }
+            Some(KeyAction::JsonEditorSave) => return vec![Effect::ShowModal(Modal::JsonEditorSave)],
+            _ => self.handle_editing_keys(app, key),
+        }
+        Vec::new()
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app: &mut App) {
+        let title = format!("JSON · --{} ({})", app.json_editor.flag_name(), app.json_editor.flag_type());
+        let block = th::block(&*app.ctx.theme, Some(title), true);
+        frame.render_widget(block.clone(), area);
+        let inner = block.inner(area);
+        let [editor_area, status_area] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+        let line_count = app.json_editor.lines().len();
+        let gutter_width = line_count.to_string().len() as u16 + 2;
+        let [gutter_area, text_area] = Layout::horizontal([Constraint::Length(gutter_width), Constraint::Min(1)]).areas(editor_area);
+        Self::follow_cursor(app, text_area);
+
+        let theme = &*app.ctx.theme;
+        let (top, left) = app.json_editor.scroll;
+        let error_line = app.json_editor.error().and_then(|error| error.line);
+        let gutter: Vec<Line> = (top as usize..line_count.min(top as usize + gutter_area.height as usize))
+            .map(|index| {
+                let style = if error_line == Some(index + 1) {
+                    theme.status_error()
+                } else {
+                    theme.text_muted_style()
+                };
+                Line::styled(format!("{:>width$} ", index + 1, width = gutter_width as usize - 2), style)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(gutter), gutter_area);
+
+        let text = app.json_editor.text();
+        let lines = highlight_pretty_json_lines(&text, theme);
+        frame.render_widget(Paragraph::new(lines).scroll((top, left)), text_area);
+        frame.render_widget(Paragraph::new(Self::status_line(app)), status_area);
+
+        let (row, column) = app.json_editor.cursor();
+        frame.set_cursor_position((
+            text_area.x + (column as u16).saturating_sub(left),
+            text_area.y + (row as u16).saturating_sub(top),
+        ));
+        self.render_completion(frame, app, text_area);
+    }
+
+    fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
+        let keymap = &app.ctx.keymap;
+        let apply = format!(" {}", keymap.label(KeyAction::JsonEditorApply));
+        let format = keymap.label(KeyAction::JsonEditorFormat);
+        let load = keymap.label(KeyAction::JsonEditorLoad);
+        let save = keymap.label(KeyAction::JsonEditorSave);
+        let mut hints: Vec<(&str, &str)> = vec![(&apply, " Apply  "), (&format, " Format  ")];
+        let complete = keymap.label(KeyAction::JsonEditorComplete);
+        let skeleton = keymap.label(KeyAction::JsonEditorSkeleton);
+        if app.json_editor.has_schema() {
+            hints.push((&complete, " Complete  "));
+            hints.push((&skeleton, " Skeleton  "));
+        }
+        hints.extend([(load.as_str(), " Load  "), (save.as_str(), " Save  "), ("Esc", " Cancel ")]);
+        th::build_hint_spans(&*app.ctx.theme, &hints)
+    }
+}
@@ -0,0 +1,8 @@
+//! Multi-line JSON editor modal for object and array flag values.
+
+mod json_editor_component;
+mod schema;
+mod state;
+
+pub use json_editor_component::JsonEditorComponent;
+pub use state::{JsonEditorState, JsonEditorTarget};
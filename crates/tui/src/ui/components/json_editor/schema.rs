@@ -0,0 +1,251 @@
+//! Schema-driven helpers for the JSON editor.
+//!
+//! Builds a starting skeleton from a flag's [`SchemaProperty`] and works out
+//! which property names can be completed at the cursor. The cursor context is
+//! recovered by scanning the text up to the cursor, so it also works while the
+//! document is incomplete.
+
+use oatty_types::SchemaProperty;
+use serde_json::{Map, Value};
+
+/// Nesting depth past which skeletons stop expanding objects.
+const MAX_SKELETON_DEPTH: usize = 4;
+
+/// Builds a starting value for `schema`: required properties (or all of them
+/// when none are required) with empty values of the declared type.
+pub fn skeleton_value(schema: &SchemaProperty) -> Value {
+    skeleton_at_depth(schema, 0)
+}
+
+fn skeleton_at_depth(schema: &SchemaProperty, depth: usize) -> Value {
+    match schema.r#type.as_str() {
+        "object" => {
+            let mut object = Map::new();
+            if depth >= MAX_SKELETON_DEPTH {
+                return Value::Object(object);
+            }
+            let Some(properties) = schema.properties.as_ref() else {
+                return Value::Object(object);
+            };
+            let include_all = schema.required.is_empty();
+            for (name, property) in properties {
+                if include_all || schema.required.contains(name) {
+                    object.insert(name.clone(), skeleton_at_depth(property, depth + 1));
+                }
+            }
+            Value::Object(object)
+        }
+        "array" => match schema.items.as_deref() {
+            Some(items) if items.r#type == "object" && depth < MAX_SKELETON_DEPTH => {
+                Value::Array(vec![skeleton_at_depth(items, depth + 1)])
+            }
+            _ => Value::Array(Vec::new()),
+        },
+        "string" => Value::String(schema.enum_values.first().cloned().unwrap_or_default()),
+        "integer" | "number" => Value::from(0),
+        "boolean" => Value::Bool(false),
+        _ => Value::Null,
+    }
+}
+
+/// Property-name completion available at the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionContext {
+    /// Byte offset where the completed key replaces the text, including any opening quote.
+    pub replace_from: usize,
+    /// Partially typed key name.
+    pub prefix: String,
+    /// Candidate property names, in schema order, not yet present in the object.
+    pub candidates: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+struct Frame {
+    is_object: bool,
+    expecting_key: bool,
+    current_key: Option<String>,
+    seen_keys: Vec<String>,
+}
+
+/// Returns the property names that may be typed at `cursor`, or `None` when
+/// the cursor is not in a key position of an object described by `schema`.
+pub fn completion_context(text: &str, cursor: usize, schema: &SchemaProperty) -> Option<CompletionContext> {
+    let scanned = &text[..cursor.min(text.len())];
+    let bytes = scanned.as_bytes();
+    let mut frames: Vec<Frame> = Vec::new();
+    let mut open_key: Option<usize> = None;
+    let mut index = 0usize;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'{' => frames.push(Frame {
+                is_object: true,
+                expecting_key: true,
+                ..Frame::default()
+            }),
+            b'[' => frames.push(Frame::default()),
+            b'}' | b']' => {
+                frames.pop();
+            }
+            b',' => {
+                if let Some(frame) = frames.last_mut().filter(|frame| frame.is_object) {
+                    frame.expecting_key = true;
+                    frame.current_key = None;
+                }
+            }
+            b':' => {
+                if let Some(frame) = frames.last_mut().filter(|frame| frame.is_object) {
+                    frame.expecting_key = false;
+                }
+            }
+            b'"' => {
+                let start = index;
+                let Some(end) = string_end(bytes, index) else {
+                    open_key = Some(start);
+                    break;
+                };
+                if let Some(frame) = frames.last_mut().filter(|frame| frame.is_object && frame.expecting_key) {
+                    let key = unescape_key(&scanned[start + 1..end]);
+                    frame.seen_keys.push(key.clone());
+                    frame.current_key = Some(key);
+                }
+                index = end;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    let frame = frames.last().filter(|frame| frame.is_object && frame.expecting_key)?;
+    let object_schema = schema_for_frames(schema, &frames)?;
+    let properties = object_schema.properties.as_ref()?;
+    let (replace_from, prefix) = match open_key {
+        Some(start) => (start, scanned[start + 1..].to_string()),
+        None => (cursor, String::new()),
+    };
+
+    let mut names: Vec<&String> = properties.keys().collect();
+    names.sort_by_key(|name| (!object_schema.required.contains(name), name.as_str()));
+    let candidates = names
+        .into_iter()
+        .filter(|name| !frame.seen_keys.contains(name) && name.starts_with(&prefix))
+        .cloned()
+        .collect();
+    Some(CompletionContext {
+        replace_from,
+        prefix,
+        candidates,
+    })
+}
+
+/// Walks the schema along the open containers, following object keys and array items.
+fn schema_for_frames<'a>(schema: &'a SchemaProperty, frames: &[Frame]) -> Option<&'a SchemaProperty> {
+    let mut current = schema;
+    for frame in &frames[..frames.len().saturating_sub(1)] {
+        current = if frame.is_object {
+            let key = frame.current_key.as_ref()?;
+            current.properties.as_ref()?.get(key)?
+        } else {
+            current.items.as_deref()?
+        };
+    }
+    (current.r#type == "object").then_some(current)
+}
+
+/// Byte index of the closing quote of the string opened at `start`.
+fn string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'"' => return Some(index),
+            _ => index += 1,
+        }
+    }
+    None
+}
+
+fn unescape_key(raw: &str) -> String {
+    serde_json::from_str::<String>(&format!("\"{raw}\"")).unwrap_or_else(|_| raw.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn property(r#type: &str) -> SchemaProperty {
+        SchemaProperty {
+            r#type: r#type.to_string(),
+            description: String::new(),
+            properties: None,
+            required: Vec::new(),
+            items: None,
+            enum_values: Vec::new(),
+            format: None,
+            tags: Vec::new(),
+        }
+    }
+
+    fn object(fields: &[(&str, SchemaProperty)], required: &[&str]) -> SchemaProperty {
+        let properties: HashMap<String, Box<SchemaProperty>> = fields
+            .iter()
+            .map(|(name, schema)| (name.to_string(), Box::new(schema.clone())))
+            .collect();
+        SchemaProperty {
+            properties: Some(properties),
+            required: required.iter().map(|name| name.to_string()).collect(),
+            ..property("object")
+        }
+    }
+
+    fn service_schema() -> SchemaProperty {
+        let env_var = object(&[("key", property("string")), ("value", property("string"))], &["key"]);
+        let mut env_vars = property("array");
+        env_vars.items = Some(Box::new(env_var));
+        let mut plan = property("string");
+        plan.enum_values = vec!["starter".to_string(), "pro".to_string()];
+        object(
+            &[
+                ("name", property("string")),
+                ("plan", plan),
+                ("autoDeploy", property("boolean")),
+                ("envVars", env_vars),
+            ],
+            &["name", "plan", "envVars"],
+        )
+    }
+
+    #[test]
+    fn skeleton_includes_required_properties_with_typed_defaults() {
+        assert_eq!(
+            skeleton_value(&service_schema()),
+            serde_json::json!({"name": "", "plan": "starter", "envVars": [{"key": ""}]})
+        );
+    }
+
+    #[test]
+    fn completion_offers_missing_keys_of_the_enclosing_object() {
+        let schema = service_schema();
+        let text = "{\n  \"name\": \"api\",\n  \"pl";
+        let context = completion_context(text, text.len(), &schema).expect("key position");
+        assert_eq!(context.prefix, "pl");
+        assert_eq!(context.replace_from, text.len() - 3);
+        assert_eq!(context.candidates, vec!["plan".to_string()]);
+
+        let text = "{\"name\": \"api\", ";
+        let context = completion_context(text, text.len(), &schema).expect("key position");
+        assert_eq!(context.candidates, vec!["envVars", "plan", "autoDeploy"]);
+    }
+
+    #[test]
+    fn completion_follows_array_items_and_skips_value_positions() {
+        let schema = service_schema();
+        let text = "{\"envVars\": [{\"key\": \"A\", ";
+        let context = completion_context(text, text.len(), &schema).expect("nested key position");
+        assert_eq!(context.candidates, vec!["value".to_string()]);
+
+        let text = "{\"name\": \"a";
+        assert!(completion_context(text, text.len(), &schema).is_none());
+    }
+}
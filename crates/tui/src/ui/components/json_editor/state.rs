@@ -0,0 +1,422 @@
+use super::schema::{CompletionContext, completion_context, skeleton_value};
+use oatty_types::{CommandFlag, SchemaProperty};
+use ratatui::widgets::ListState;
+use serde_json::Value;
+
+/// Indentation inserted by `Tab` and after opening brackets.
+const INDENT: &str = "  ";
+
+/// Where an applied value goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonEditorTarget {
+    /// Fill the flag in the palette input.
+    Palette,
+    /// Send the command (`<group> <name>`) to the palette with the flag filled.
+    Browser { command: String },
+}
+
+/// A JSON parse or shape error, with a 1-based line when serde reports one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonEditorError {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+/// UI state for the multi-line JSON editor modal.
+///
+/// The text is kept as lines with a `(row, byte column)` cursor. Every edit
+/// re-parses the document so parse errors are shown inline while typing.
+#[derive(Debug, Default)]
+pub struct JsonEditorState {
+    target: Option<JsonEditorTarget>,
+    flag_name: String,
+    flag_type: String,
+    schema: Option<SchemaProperty>,
+    lines: Vec<String>,
+    cursor_row: usize,
+    cursor_column: usize,
+    /// First visible line and column, kept in sync with the cursor by the view.
+    pub scroll: (u16, u16),
+    error: Option<JsonEditorError>,
+    completion: Option<CompletionContext>,
+    pub completion_list: ListState,
+    status: Option<String>,
+}
+
+impl JsonEditorState {
+    /// Open the editor for `flag`, starting from `current` when it holds a
+    /// value and from the schema skeleton otherwise.
+    pub fn open(&mut self, target: JsonEditorTarget, flag: &CommandFlag, current: Option<&str>) {
+        self.target = Some(target);
+        self.flag_name = flag.name.clone();
+        self.flag_type = flag.r#type.clone();
+        self.schema = flag.schema.clone();
+        self.status = None;
+        match current.map(str::trim).filter(|value| !value.is_empty()) {
+            Some(value) => {
+                let text = serde_json::from_str::<Value>(value)
+                    .ok()
+                    .and_then(|parsed| serde_json::to_string_pretty(&parsed).ok())
+                    .unwrap_or_else(|| value.to_string());
+                self.set_text(&text);
+            }
+            None => self.reset_to_skeleton(),
+        }
+    }
+
+    pub fn target(&self) -> Option<&JsonEditorTarget> {
+        self.target.as_ref()
+    }
+
+    pub fn flag_name(&self) -> &str {
+        &self.flag_name
+    }
+
+    pub fn flag_type(&self) -> &str {
+        &self.flag_type
+    }
+
+    pub fn has_schema(&self) -> bool {
+        self.schema.is_some()
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Cursor as `(row, display column)`.
+    pub fn cursor(&self) -> (usize, usize) {
+        let line = &self.lines[self.cursor_row];
+        (self.cursor_row, line[..self.cursor_column].chars().count())
+    }
+
+    pub fn error(&self) -> Option<&JsonEditorError> {
+        self.error.as_ref()
+    }
+
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    pub fn set_status(&mut self, status: String) {
+        self.status = Some(status);
+    }
+
+    /// Replace the whole document and move the cursor to its start.
+    pub fn set_text(&mut self, text: &str) {
+        self.lines = text.lines().map(str::to_string).collect();
+        if self.lines.is_empty() {
+            self.lines.push(String::new());
+        }
+        self.cursor_row = 0;
+        self.cursor_column = 0;
+        self.scroll = (0, 0);
+        self.completion = None;
+        self.validate();
+    }
+
+    /// Replace the document with a skeleton generated from the flag's schema.
+    pub fn reset_to_skeleton(&mut self) {
+        let skeleton = match (&self.schema, self.flag_type.as_str()) {
+            (Some(schema), _) => skeleton_value(schema),
+            (None, "array") => Value::Array(Vec::new()),
+            (None, _) => Value::Object(Default::default()),
+        };
+        let text = serde_json::to_string_pretty(&skeleton).unwrap_or_default();
+        self.set_text(&text);
+    }
+
+    /// Pretty-print the document when it parses.
+    pub fn format(&mut self) {
+        if let Ok(value) = serde_json::from_str::<Value>(&self.text())
+            && let Ok(text) = serde_json::to_string_pretty(&value)
+        {
+            self.set_text(&text);
+        }
+    }
+
+    /// The parsed value, or the error that prevents applying it.
+    pub fn value(&self) -> Result<Value, JsonEditorError> {
+        let value = serde_json::from_str::<Value>(&self.text()).map_err(|error| JsonEditorError {
+            line: (error.line() > 0).then_some(error.line()),
+            message: error.to_string(),
+        })?;
+        let shape_matches = match self.flag_type.as_str() {
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            _ => true,
+        };
+        if !shape_matches {
+            return Err(JsonEditorError {
+                line: None,
+                message: format!("--{} expects a JSON {}", self.flag_name, self.flag_type),
+            });
+        }
+        Ok(value)
+    }
+
+    fn validate(&mut self) {
+        self.error = self.value().err();
+    }
+
+    fn edited(&mut self) {
+        self.status = None;
+        self.validate();
+        if self.completion.is_some() {
+            self.open_completion();
+        }
+    }
+
+    pub fn insert_char(&mut self, character: char) {
+        self.lines[self.cursor_row].insert(self.cursor_column, character);
+        self.cursor_column += character.len_utf8();
+        self.edited();
+    }
+
+    pub fn insert_str(&mut self, text: &str) {
+        self.lines[self.cursor_row].insert_str(self.cursor_column, text);
+        self.cursor_column += text.len();
+        self.edited();
+    }
+
+    /// Split the line at the cursor, keeping its indentation and indenting
+    /// one level further after an opening bracket.
+    pub fn insert_newline(&mut self) {
+        let line = &self.lines[self.cursor_row];
+        let rest = line[self.cursor_column..].to_string();
+        let head = &line[..self.cursor_column];
+        let mut indent: String = head.chars().take_while(|character| *character == ' ').collect();
+        if head.trim_end().ends_with(['{', '[']) {
+            indent.push_str(INDENT);
+        }
+        self.lines[self.cursor_row].truncate(self.cursor_column);
+        self.lines.insert(self.cursor_row + 1, format!("{indent}{}", rest.trim_start()));
+        self.cursor_row += 1;
+        self.cursor_column = indent.len();
+        self.edited();
+    }
+
+    pub fn indent(&mut self) {
+        self.insert_str(INDENT);
+    }
+
+    pub fn backspace(&mut self) {
+        if self.cursor_column > 0 {
+            let previous = previous_boundary(&self.lines[self.cursor_row], self.cursor_column);
+            self.lines[self.cursor_row].replace_range(previous..self.cursor_column, "");
+            self.cursor_column = previous;
+        } else if self.cursor_row > 0 {
+            let line = self.lines.remove(self.cursor_row);
+            self.cursor_row -= 1;
+            self.cursor_column = self.lines[self.cursor_row].len();
+            self.lines[self.cursor_row].push_str(&line);
+        } else {
+            return;
+        }
+        self.edited();
+    }
+
+    pub fn delete(&mut self) {
+        let line_length = self.lines[self.cursor_row].len();
+        if self.cursor_column < line_length {
+            let next = next_boundary(&self.lines[self.cursor_row], self.cursor_column);
+            self.lines[self.cursor_row].replace_range(self.cursor_column..next, "");
+        } else if self.cursor_row + 1 < self.lines.len() {
+            let next_line = self.lines.remove(self.cursor_row + 1);
+            self.lines[self.cursor_row].push_str(&next_line);
+        } else {
+            return;
+        }
+        self.edited();
+    }
+
+    pub fn move_left(&mut self) {
+        if self.cursor_column > 0 {
+            self.cursor_column = previous_boundary(&self.lines[self.cursor_row], self.cursor_column);
+        } else if self.cursor_row > 0 {
+            self.cursor_row -= 1;
+            self.cursor_column = self.lines[self.cursor_row].len();
+        }
+        self.completion = None;
+    }
+
+    pub fn move_right(&mut self) {
+        if self.cursor_column < self.lines[self.cursor_row].len() {
+            self.cursor_column = next_boundary(&self.lines[self.cursor_row], self.cursor_column);
+        } else if self.cursor_row + 1 < self.lines.len() {
+            self.cursor_row += 1;
+            self.cursor_column = 0;
+        }
+        self.completion = None;
+    }
+
+    /// Move the cursor `rows` lines up (negative) or down, keeping its display column.
+    pub fn move_vertical(&mut self, rows: isize) {
+        let (_, column) = self.cursor();
+        let last_row = self.lines.len() - 1;
+        self.cursor_row = self.cursor_row.saturating_add_signed(rows).min(last_row);
+        let line = &self.lines[self.cursor_row];
+        self.cursor_column = line.char_indices().nth(column).map_or(line.len(), |(index, _)| index);
+        self.completion = None;
+    }
+
+    pub fn move_line_start(&mut self) {
+        self.cursor_column = 0;
+        self.completion = None;
+    }
+
+    pub fn move_line_end(&mut self) {
+        self.cursor_column = self.lines[self.cursor_row].len();
+        self.completion = None;
+    }
+
+    // ----- Completion -----
+
+    fn cursor_offset(&self) -> usize {
+        self.lines[..self.cursor_row].iter().map(|line| line.len() + 1).sum::<usize>() + self.cursor_column
+    }
+
+    /// Look up property names for the cursor position; the popup stays closed
+    /// when there is nothing to offer.
+    pub fn open_completion(&mut self) {
+        let context = self
+            .schema
+            .as_ref()
+            .and_then(|schema| completion_context(&self.text(), self.cursor_offset(), schema))
+            .filter(|context| !context.candidates.is_empty());
+        self.completion_list.select(context.as_ref().map(|_| 0));
+        self.completion = context;
+    }
+
+    pub fn close_completion(&mut self) {
+        self.completion = None;
+    }
+
+    pub fn completion_candidates(&self) -> Option<&[String]> {
+        self.completion.as_ref().map(|context| context.candidates.as_slice())
+    }
+
+    /// Replace the partially typed key with the selected candidate as `"name": `.
+    pub fn accept_completion(&mut self) {
+        let Some(context) = self.completion.take() else {
+            return;
+        };
+        let Some(name) = self.completion_list.selected().and_then(|index| context.candidates.get(index)) else {
+            return;
+        };
+        let line_start = self.cursor_offset() - self.cursor_column;
+        let replace_from = context.replace_from.saturating_sub(line_start).min(self.cursor_column);
+        let line = &mut self.lines[self.cursor_row];
+        let rest = line[self.cursor_column..].trim_start_matches(['"', ':', ' ']).to_string();
+        let key = serde_json::to_string(name).unwrap_or_default();
+        line.truncate(replace_from);
+        line.push_str(&key);
+        line.push_str(": ");
+        self.cursor_column = line.len();
+        line.push_str(&rest);
+        self.edited();
+    }
+}
+
+fn previous_boundary(line: &str, index: usize) -> usize {
+    line[..index].char_indices().next_back().map_or(0, |(position, _)| position)
+}
+
+fn next_boundary(line: &str, index: usize) -> usize {
+    line[index..].chars().next().map_or(index, |character| index + character.len_utf8())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flag(r#type: &str, schema: Option<SchemaProperty>) -> CommandFlag {
+        CommandFlag {
+            name: "body".to_string(),
+            short_name: None,
+            required: false,
+            r#type: r#type.to_string(),
+            enum_values: Vec::new(),
+            default_value: None,
+            description: None,
+            provider: None,
+            schema,
+        }
+    }
+
+    fn schema_with_properties(names: &[&str]) -> SchemaProperty {
+        let string = SchemaProperty {
+            r#type: "string".to_string(),
+            description: String::new(),
+            properties: None,
+            required: Vec::new(),
+            items: None,
+            enum_values: Vec::new(),
+            format: None,
+            tags: Vec::new(),
+        };
+        SchemaProperty {
+            r#type: "object".to_string(),
+            properties: Some(names.iter().map(|name| (name.to_string(), Box::new(string.clone()))).collect()),
+            required: names.iter().map(|name| name.to_string()).collect(),
+            ..string
+        }
+    }
+
+    #[test]
+    fn open_pretty_prints_current_value_and_reports_shape_errors() {
+        let mut state = JsonEditorState::default();
+        state.open(JsonEditorTarget::Palette, &flag("object", None), Some(r#"{"name":"api"}"#));
+        assert_eq!(state.lines(), ["{", "  \"name\": \"api\"", "}"]);
+        assert!(state.error().is_none());
+
+        state.open(JsonEditorTarget::Palette, &flag("array", None), Some(r#"{"name":"api"}"#));
+        assert_eq!(
+            state.error().map(|error| error.message.as_str()),
+            Some("--body expects a JSON array")
+        );
+
+        state.open(JsonEditorTarget::Palette, &flag("array", None), None);
+        assert_eq!(state.text(), "[]");
+    }
+
+    #[test]
+    fn edits_track_parse_errors_with_line_numbers() {
+        let mut state = JsonEditorState::default();
+        state.open(JsonEditorTarget::Palette, &flag("object", None), None);
+        state.move_right();
+        state.insert_newline();
+        assert_eq!(state.lines(), ["{", "  }"]);
+        state.insert_str("\"a\": ");
+        assert_eq!(state.error().and_then(|error| error.line), Some(2));
+        state.insert_char('1');
+        assert!(state.error().is_none());
+        assert_eq!(state.value().ok(), Some(serde_json::json!({"a": 1})));
+
+        state.move_line_start();
+        state.backspace();
+        assert_eq!(state.lines(), ["{  \"a\": 1}"]);
+    }
+
+    #[test]
+    fn accept_completion_replaces_partial_key() {
+        let mut state = JsonEditorState::default();
+        state.open(
+            JsonEditorTarget::Palette,
+            &flag("object", Some(schema_with_properties(&["name", "region"]))),
+            Some("{}"),
+        );
+        state.set_text("{\n  \"re\n}");
+        state.move_vertical(1);
+        state.move_line_end();
+        state.open_completion();
+        assert_eq!(state.completion_candidates(), Some(&["region".to_string()][..]));
+        state.accept_completion();
+        state.insert_str("\"eu\"");
+        assert_eq!(state.value().ok(), Some(serde_json::json!({"region": "eu"})));
+    }
+}
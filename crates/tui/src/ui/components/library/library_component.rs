@@ -18,6 +18,7 @@ use crate::{
             },
             library::{CatalogProjection, state::LibraryEditorField, types::CatalogValidationError},
         },
+        keymap::{KeyAction, KeyScope},
         theme::{
             Theme,
             theme_helpers::{
@@ -27,7 +28,7 @@ use crate::{
         },
     },
 };
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use oatty_registry::CommandRegistry;
 use oatty_types::{Effect, ExecOutcome, MessageType, Modal, Msg, TransientMessage};
 use oatty_util::truncate_with_ellipsis;
@@ -698,6 +699,25 @@ impl Component for LibraryComponent {
             return self.track_lost_kv_focus(app);
         }
 
+        match app.ctx.keymap.action(KeyScope::Library, &key) {
+            Some(KeyAction::LibraryImport) => return self.handle_import(),
+            Some(KeyAction::LibraryRemove) => {
+                if let Some(idx) = app.library.api_selected_index() {
+                    return self.prompt_remove_catalog(app, idx);
+                }
+                return Vec::new();
+            }
+            Some(KeyAction::LibraryAddUrl) if app.library.f_url_list_container.get() => {
+                app.library.url_add_row();
+                return Vec::new();
+            }
+            Some(KeyAction::LibraryRemoveUrl) if app.library.f_url_list_container.get() => {
+                app.library.remove_url_row();
+                return Vec::new();
+            }
+            _ => {}
+        }
+
         match key.code {
             KeyCode::Tab => {
                 app.focus.next();
@@ -737,20 +757,6 @@ impl Component for LibraryComponent {
                     }
                 }
             }
-            KeyCode::Char('o') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                return self.handle_import();
-            }
-            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                if let Some(idx) = app.library.api_selected_index() {
-                    return self.prompt_remove_catalog(app, idx);
-                }
-            }
-            KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) && app.library.f_url_list_container.get() => {
-                app.library.url_add_row();
-            }
-            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) && app.library.f_url_list_container.get() => {
-                app.library.remove_url_row();
-            }
             KeyCode::Char(c) => {
                 app.library.insert_character_for_active_field(c);
             }
@@ -912,6 +918,11 @@ impl Component for LibraryComponent {
             return spans;
         }
 
+        let keymap = &app.ctx.keymap;
+        let import = keymap.label(KeyAction::LibraryImport);
+        let remove = keymap.label(KeyAction::LibraryRemove);
+        let add_url = keymap.label(KeyAction::LibraryAddUrl);
+        let remove_url = keymap.label(KeyAction::LibraryRemoveUrl);
         let mut hints = Vec::new();
         if app.library.f_api_list.get() {
             hints.push(("↑/↓", " Navigate catalogs "));
@@ -922,8 +933,8 @@ impl Component for LibraryComponent {
             hints.push(("↑/↓", " Navigate base URLs "));
             hints.push(("PgUp/PgDn", " Page base URLs "));
             hints.push(("Home/End", " Jump base URLs "));
-            hints.push(("Ctrl+N", " Add base URL "));
-            hints.push(("Ctrl+D", " Delete base URL "));
+            hints.push((add_url.as_str(), " Add base URL "));
+            hints.push((remove_url.as_str(), " Delete base URL "));
         }
         if app.library.f_add_url_button.get() {
             hints.push(("Enter/Space", " Add base URL "));
@@ -935,9 +946,9 @@ impl Component for LibraryComponent {
             hints.push(("Enter/Space", " Import catalog "));
         }
 
-        hints.push(("Ctrl+O", " Import catalog "));
+        hints.push((import.as_str(), " Import catalog "));
         if app.library.api_selected_index().is_some() {
-            hints.push(("Ctrl+R", " Remove catalog "));
+            hints.push((remove.as_str(), " Remove catalog "));
 
             if app.library.f_api_list.get() {
                 hints.push(("Enter/Space", " Toggle enabled "));
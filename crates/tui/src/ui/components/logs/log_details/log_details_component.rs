@@ -5,6 +5,7 @@
 //! actions.
 
 use crate::ui::components::common::handle_table_mouse_actions;
+use crate::ui::keymap::{KeyAction, KeyScope, Keymap};
 use crate::ui::theme::Theme;
 use crate::{
     app::App,
@@ -184,34 +185,17 @@ impl LogDetailsComponent {
         self.text_scroll_metrics.scroll_to_bottom();
     }
 
-    fn handle_text_scroll_key(&mut self, code: KeyCode) -> bool {
-        match code {
-            KeyCode::Up | KeyCode::Char('k') => {
-                self.scroll_text_lines(-1);
-                true
-            }
-            KeyCode::Down | KeyCode::Char('j') => {
-                self.scroll_text_lines(1);
-                true
-            }
-            KeyCode::PageUp => {
-                self.scroll_text_pages(-1);
-                true
-            }
-            KeyCode::PageDown => {
-                self.scroll_text_pages(1);
-                true
-            }
-            KeyCode::Home => {
-                self.scroll_text_to_top();
-                true
-            }
-            KeyCode::End => {
-                self.scroll_text_to_bottom();
-                true
-            }
-            _ => false,
+    fn handle_text_scroll_key(&mut self, keymap: &Keymap, key: &KeyEvent) -> bool {
+        match keymap.action(KeyScope::Navigation, key) {
+            Some(KeyAction::MoveUp) => self.scroll_text_lines(-1),
+            Some(KeyAction::MoveDown) => self.scroll_text_lines(1),
+            Some(KeyAction::PageUp) => self.scroll_text_pages(-1),
+            Some(KeyAction::PageDown) => self.scroll_text_pages(1),
+            Some(KeyAction::ScrollTop) => self.scroll_text_to_top(),
+            Some(KeyAction::ScrollBottom) => self.scroll_text_to_bottom(),
+            _ => return false,
         }
+        true
     }
 
     fn render_text_scrollbar(&self, frame: &mut Frame, area: Rect, app: &App) {
@@ -411,14 +395,15 @@ impl Component for LogDetailsComponent {
             effects.push(Effect::CloseModal);
             return effects;
         }
+        let keymap = &app.ctx.keymap;
         if self.detail_view_mode == DetailViewMode::Table {
-            if handle_table_navigation_key(key.code, &mut app.logs.results_table, app.focus.as_ref()) {
+            if handle_table_navigation_key(keymap, &key, &mut app.logs.results_table, app.focus.as_ref()) {
                 return effects;
             }
-        } else if self.handle_text_scroll_key(key.code) {
+        } else if self.handle_text_scroll_key(keymap, &key) {
             return effects;
         }
-        if let KeyCode::Char('c') = key.code {
+        if keymap.matches(KeyAction::LogDetailsCopy, &key) {
             effects.push(Effect::CopyLogsRequested(build_copy_text(app)));
         }
 
@@ -450,28 +435,25 @@ impl Component for LogDetailsComponent {
 
     fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
         let theme = &*app.ctx.theme;
+        let keymap = &app.ctx.keymap;
+        let copy = keymap.label(KeyAction::LogDetailsCopy);
         match self.detail_view_mode {
-            DetailViewMode::Table => build_hint_spans(
-                theme,
-                &[
-                    ("↑/↓", " Scroll  "),
-                    ("PgUp/PgDn", " Page  "),
-                    ("Home/End", " Jump  "),
-                    ("Esc", " Close  "),
-                    ("C", " Copy  "),
-                ],
-            ),
-            DetailViewMode::Text => build_hint_spans(
-                theme,
-                &[
-                    ("↑/↓", " Scroll  "),
-                    ("PgUp/PgDn", " Page  "),
-                    ("Home/End", " Jump  "),
-                    ("Esc", " Close  "),
-                    ("C", " Copy  "),
-                ],
-            ),
-            DetailViewMode::Empty => build_hint_spans(theme, &[("Esc", " Close  "), ("C", " Copy  ")]),
+            DetailViewMode::Table | DetailViewMode::Text => {
+                let scroll = keymap.label_pair(KeyAction::MoveUp, KeyAction::MoveDown);
+                let page = keymap.label_pair(KeyAction::PageUp, KeyAction::PageDown);
+                let jump = keymap.label_pair(KeyAction::ScrollTop, KeyAction::ScrollBottom);
+                build_hint_spans(
+                    theme,
+                    &[
+                        (&scroll, " Scroll  "),
+                        (&page, " Page  "),
+                        (&jump, " Jump  "),
+                        ("Esc", " Close  "),
+                        (&copy, " Copy  "),
+                    ],
+                )
+            }
+            DetailViewMode::Empty => build_hint_spans(theme, &[("Esc", " Close  "), (&copy, " Copy  ")]),
         }
     }
 }
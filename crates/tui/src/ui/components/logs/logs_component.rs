@@ -15,6 +15,7 @@
 
 use super::state::LogEntry;
 use crate::app::App;
+use crate::ui::keymap::{KeyAction, KeyScope};
 use crate::ui::theme::theme_helpers::{create_list_with_highlight, highlight_segments};
 use crate::ui::{components::component::Component, theme::theme_helpers as th, utils::build_copy_text};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
@@ -87,6 +88,7 @@ impl LogsComponent {
         )
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_search_keys(&self, app: &mut App, key: KeyEvent) {
         match key.code {
             KeyCode::Esc => {
@@ -161,6 +163,7 @@ impl Component for LogsComponent {
     /// # Returns
     ///
     /// A vector of effects to be processed by the application
+    #[allow(clippy::collapsible_match)]
     fn handle_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
         let mut effects = Vec::new();
 
@@ -169,62 +172,44 @@ impl Component for LogsComponent {
             return effects;
         }
 
-        // Handle main navigation and action keys
-        match key.code {
-            // tab navigation
-            KeyCode::BackTab => {
-                app.focus.prev();
-            }
-            KeyCode::Tab => {
-                app.focus.next();
-            }
-            KeyCode::Esc => {
-                if app.logs.has_search_query() {
-                    app.logs.clear_search_query();
-                    app.logs.activate_search();
-                }
-            }
-            KeyCode::Char('/') => {
-                app.logs.activate_search();
-            }
-            KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                app.logs.activate_search();
-            }
-            KeyCode::Up => {
-                app.logs.list_state.select_previous();
-            }
-            KeyCode::Down => {
-                app.logs.list_state.select_next();
-            }
-            KeyCode::PageUp => {
-                app.logs.list_state.scroll_up_by(10);
-            }
-            KeyCode::PageDown => {
-                app.logs.list_state.scroll_down_by(10);
-            }
-            KeyCode::Home => {
-                app.logs.list_state.scroll_up_by(u16::MAX);
-            }
-            KeyCode::End => {
-                app.logs.list_state.scroll_down_by(u16::MAX);
-            }
-            KeyCode::Enter => {
-                if self.selected_entry(app).is_some() {
-                    self.apply_results_table_for_selected_entry(app);
-                    effects.push(Effect::ShowModal(Modal::LogDetails));
-                }
-            }
-            KeyCode::Char('c') => {
+        match app.ctx.keymap.resolve(KeyScope::Logs, &key) {
+            Some(KeyAction::LogsSearch) => app.logs.activate_search(),
+            Some(KeyAction::MoveUp) => app.logs.list_state.select_previous(),
+            Some(KeyAction::MoveDown) => app.logs.list_state.select_next(),
+            Some(KeyAction::PageUp) => app.logs.list_state.scroll_up_by(10),
+            Some(KeyAction::PageDown) => app.logs.list_state.scroll_down_by(10),
+            Some(KeyAction::ScrollTop) => app.logs.list_state.scroll_up_by(u16::MAX),
+            Some(KeyAction::ScrollBottom) => app.logs.list_state.scroll_down_by(u16::MAX),
+            Some(KeyAction::LogsCopy) => {
                 let text = build_copy_text(app);
                 effects.push(Effect::CopyLogsRequested(text));
             }
-            KeyCode::Char('v') => {
+            Some(KeyAction::LogsPrettyJson) => {
                 if self.selection_supports_pretty_toggle(app) {
                     app.logs.pretty_json = !app.logs.pretty_json;
                 }
-                return effects;
             }
-            _ => {}
+            _ => match key.code {
+                KeyCode::BackTab => {
+                    app.focus.prev();
+                }
+                KeyCode::Tab => {
+                    app.focus.next();
+                }
+                KeyCode::Esc => {
+                    if app.logs.has_search_query() {
+                        app.logs.clear_search_query();
+                        app.logs.activate_search();
+                    }
+                }
+                KeyCode::Enter => {
+                    if self.selected_entry(app).is_some() {
+                        self.apply_results_table_for_selected_entry(app);
+                        effects.push(Effect::ShowModal(Modal::LogDetails));
+                    }
+                }
+                _ => {}
+            },
         }
         effects
     }
@@ -374,23 +359,33 @@ impl Component for LogsComponent {
         }
 
         let theme = &*app.ctx.theme;
+        let keymap = &app.ctx.keymap;
+        let labels = [
+            keymap.label(KeyAction::LogsSearch),
+            keymap.label_pair(KeyAction::MoveUp, KeyAction::MoveDown),
+            keymap.label_pair(KeyAction::PageUp, KeyAction::PageDown),
+            keymap.label_pair(KeyAction::ScrollTop, KeyAction::ScrollBottom),
+            keymap.label(KeyAction::LogsCopy),
+        ];
         let mut spans = th::build_hint_spans(
             theme,
             &[
-                ("/", " Search  "),
-                ("Ctrl+F", " Focus Search  "),
-                ("↑/↓", " Move  "),
-                ("PgUp/PgDn", " Page  "),
-                ("Home/End", " Jump  "),
+                (&labels[0], " Search  "),
+                (&labels[1], " Move  "),
+                (&labels[2], " Page  "),
+                (&labels[3], " Jump  "),
                 ("Enter", " Open  "),
-                ("C", " Copy  "),
+                (&labels[4], " Copy  "),
             ],
         );
         if app.logs.is_search_active() || app.logs.has_search_query() {
             spans.extend(th::build_hint_spans(theme, &[("Esc", " Clear Search  ")]));
         }
         if show_pretty_toggle {
-            spans.push(Span::styled("V ", theme.accent_emphasis_style()));
+            spans.push(Span::styled(
+                format!("{} ", keymap.label(KeyAction::LogsPrettyJson)),
+                theme.accent_emphasis_style(),
+            ));
             // Show current mode with green highlight
             if app.logs.pretty_json {
                 spans.push(Span::styled("pretty", Style::default().fg(theme.roles().success)));
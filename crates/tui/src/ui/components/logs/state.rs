@@ -207,7 +207,7 @@ impl LogsState {
                 .enumerate()
                 .filter_map(|(index, entry)| fuzzy_score(&entry.to_string(), query).map(|score| (score, index)))
                 .collect();
-            scored.sort_by(|left, right| right.0.cmp(&left.0));
+            scored.sort_by_key(|entry| std::cmp::Reverse(entry.0));
             self.filtered_indices = scored.into_iter().map(|(_, index)| index).collect();
         }
 
@@ -267,6 +267,9 @@ impl LogsState {
             ExecOutcome::WorkflowImported { workflow_id, path } => {
                 self.append_text_entry(format!("Workflow '{}' imported successfully at '{}'", workflow_id, path.display()));
             }
+            ExecOutcome::WorkflowSaved { workflow_id, path } => {
+                self.append_text_entry(format!("Workflow '{}' saved at '{}'", workflow_id, path.display()));
+            }
             ExecOutcome::WorkflowRemoved { workflow_id } => {
                 self.append_text_entry(format!("Workflow '{}' removed successfully", workflow_id));
             }
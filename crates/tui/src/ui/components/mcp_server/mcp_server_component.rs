@@ -5,9 +5,10 @@ use std::borrow::Cow;
 use crate::app::App;
 use crate::ui::components::Component;
 use crate::ui::components::mcp_server::state::{McpHttpServerState, McpHttpServerStatus};
+use crate::ui::keymap::{KeyAction, KeyScope};
 use crate::ui::theme::theme_helpers::{self as th, ButtonRenderOptions, ButtonType, create_checkbox, render_button};
 use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
-use oatty_types::Effect;
+use oatty_types::{Effect, ExecOutcome, LogLevel, Modal, Msg};
 use ratatui::text::{Line, Span};
 use ratatui::{
     Frame,
@@ -31,6 +32,8 @@ pub struct McpHttpServerLayout {
     pub details_area: Rect,
     pub config_area: Rect,
     pub config_copy_areas: Vec<(usize, Rect)>,
+    pub client_list_area: Rect,
+    pub client_row_areas: Vec<(usize, Rect)>,
 }
 
 impl From<Vec<Rect>> for McpHttpServerLayout {
@@ -44,6 +47,8 @@ impl From<Vec<Rect>> for McpHttpServerLayout {
             message_area: value[5],
             config_area: value[6],
             config_copy_areas: Vec::new(),
+            client_list_area: value[7],
+            client_row_areas: Vec::new(),
         }
     }
 }
@@ -60,44 +65,69 @@ impl McpHttpServerComponent {
         } else {
             &state.configured_bind_address
         };
-        format!("http://{address}/mcp")
+        state.endpoint_url(address)
     }
 
     fn build_client_config_snippets(app: &App) -> Vec<McpClientConfigSnippet> {
         let endpoint_url = Self::resolve_mcp_endpoint_url(&app.mcp_http_server);
+        let requires_token = !app.mcp_http_server.clients().is_empty();
+        // JSON clients accept a `headers` map next to `url`; these lines are empty without tokens.
+        let json_headers = if requires_token {
+            ",\n      \"headers\": {\n        \"Authorization\": \"Bearer <token>\"\n      }"
+        } else {
+            ""
+        };
         vec![
             McpClientConfigSnippet {
                 title: "Shared connection settings",
-                content: format!("URL: {endpoint_url}"),
+                content: if requires_token {
+                    format!("URL: {endpoint_url}\nHeader: Authorization: Bearer <token>")
+                } else {
+                    format!("URL: {endpoint_url}")
+                },
             },
             McpClientConfigSnippet {
                 title: "Codex",
-                content: format!("[mcp_servers.oatty]\nurl = \"{endpoint_url}\""),
+                content: if requires_token {
+                    format!("[mcp_servers.oatty]\nurl = \"{endpoint_url}\"\nbearer_token_env_var = \"OATTY_MCP_TOKEN\"")
+                } else {
+                    format!("[mcp_servers.oatty]\nurl = \"{endpoint_url}\"")
+                },
             },
             McpClientConfigSnippet {
                 title: "Claude Desktop (claude_desktop_config.json)",
-                content: format!(
-                    "{{\n  \"mcpServers\": {{\n    \"oatty\": {{\n      \"command\": \"npx\",\n      \"args\": [\n        \"mcp-remote\",\n        \"{endpoint_url}\"\n      ]\n    }}\n  }}\n}}"
-                ),
+                content: if requires_token {
+                    format!(
+                        "{{\n  \"mcpServers\": {{\n    \"oatty\": {{\n      \"command\": \"npx\",\n      \"args\": [\n        \"mcp-remote\",\n        \"{endpoint_url}\",\n        \"--header\",\n        \"Authorization:${{OATTY_AUTH}}\"\n      ],\n      \"env\": {{\n        \"OATTY_AUTH\": \"Bearer <token>\"\n      }}\n    }}\n  }}\n}}"
+                    )
+                } else {
+                    format!(
+                        "{{\n  \"mcpServers\": {{\n    \"oatty\": {{\n      \"command\": \"npx\",\n      \"args\": [\n        \"mcp-remote\",\n        \"{endpoint_url}\"\n      ]\n    }}\n  }}\n}}"
+                    )
+                },
             },
             McpClientConfigSnippet {
                 title: "Cursor (.cursor/mcp.json)",
-                content: format!("{{\n  \"mcpServers\": {{\n    \"oatty\": {{\n      \"url\": \"{endpoint_url}\"\n    }}\n  }}\n}}"),
+                content: format!(
+                    "{{\n  \"mcpServers\": {{\n    \"oatty\": {{\n      \"url\": \"{endpoint_url}\"{json_headers}\n    }}\n  }}\n}}"
+                ),
             },
             McpClientConfigSnippet {
                 title: "Cline / Roo Code (mcp_settings.json)",
-                content: format!("{{\n  \"mcpServers\": {{\n    \"oatty\": {{\n      \"url\": \"{endpoint_url}\"\n    }}\n  }}\n}}"),
+                content: format!(
+                    "{{\n  \"mcpServers\": {{\n    \"oatty\": {{\n      \"url\": \"{endpoint_url}\"{json_headers}\n    }}\n  }}\n}}"
+                ),
             },
             McpClientConfigSnippet {
                 title: "VS Code MCP config (.vscode/mcp.json)",
                 content: format!(
-                    "{{\n  \"servers\": {{\n    \"oatty\": {{\n      \"type\": \"http\",\n      \"url\": \"{endpoint_url}\"\n    }}\n  }}\n}}"
+                    "{{\n  \"servers\": {{\n    \"oatty\": {{\n      \"type\": \"http\",\n      \"url\": \"{endpoint_url}\"{json_headers}\n    }}\n  }}\n}}"
                 ),
             },
             McpClientConfigSnippet {
                 title: "Generic Streamable HTTP MCP client",
                 content: format!(
-                    "{{\n  \"servers\": {{\n    \"oatty\": {{\n      \"transport\": \"streamable-http\",\n      \"url\": \"{endpoint_url}\"\n    }}\n  }}\n}}"
+                    "{{\n  \"servers\": {{\n    \"oatty\": {{\n      \"transport\": \"streamable-http\",\n      \"url\": \"{endpoint_url}\"{json_headers}\n    }}\n  }}\n}}"
                 ),
             },
         ]
@@ -229,6 +259,39 @@ impl McpHttpServerComponent {
         }
     }
 
+    /// Loads the audit trail in the background with the filters chosen in this view.
+    fn open_audit_trail(&self, app: &mut App) -> Vec<Effect> {
+        let filter = app.mcp_http_server.audit_filter().clone();
+        vec![Effect::McpAuditTrailRequested {
+            since: filter.since,
+            catalog: filter.catalog,
+            status: filter.status,
+        }]
+    }
+
+    fn cycle_audit_catalog(&self, app: &mut App) {
+        let mut catalogs: Vec<String> = match app.ctx.command_registry.lock() {
+            Ok(registry) => registry
+                .config
+                .catalogs
+                .iter()
+                .flatten()
+                .map(|catalog| catalog.title.clone())
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        catalogs.sort();
+        catalogs.dedup();
+        app.mcp_http_server.cycle_audit_catalog(&catalogs);
+    }
+
+    fn handle_client_revoke(&self, app: &mut App) -> Vec<Effect> {
+        match app.mcp_http_server.selected_client() {
+            Some(client) => vec![Effect::McpHttpClientTokenRevoke(client.name.clone())],
+            None => Vec::new(),
+        }
+    }
+
     fn handle_config_copy(&self, app: &mut App, config_index: usize) -> Vec<Effect> {
         let snippets = Self::build_client_config_snippets(app);
         let Some(snippet) = snippets.get(config_index) else {
@@ -310,7 +373,7 @@ impl McpHttpServerComponent {
         let configured = &app.mcp_http_server.configured_bind_address;
         let bound = app.mcp_http_server.bound_address.as_deref().unwrap_or("not running");
         let endpoint = if matches!(app.mcp_http_server.status, McpHttpServerStatus::Running) {
-            format!("http://{bound}/mcp")
+            app.mcp_http_server.endpoint_url(bound)
         } else {
             "not running".to_string()
         };
@@ -323,9 +386,52 @@ impl McpHttpServerComponent {
                 Span::styled("Active endpoint: ", theme.text_muted_style()),
                 Span::styled(endpoint, theme.syntax_string_style()),
             ]),
+            Line::from(vec![
+                Span::styled("Policy: ", theme.text_muted_style()),
+                match app.mcp_http_server.configured_policy_file.as_ref() {
+                    Some(path) => Span::styled(path.display().to_string(), theme.syntax_string_style()),
+                    None => Span::styled("none (all catalogs and tools allowed)", theme.text_muted_style()),
+                },
+            ]),
+            Line::from(vec![
+                Span::styled("Auth: ", theme.text_muted_style()),
+                match app.mcp_http_server.clients().len() {
+                    0 => Span::styled("none (loopback only)", theme.text_muted_style()),
+                    count => Span::styled(format!("bearer token ({count} clients)"), theme.syntax_string_style()),
+                },
+            ]),
+            Line::from(vec![
+                Span::styled("TLS: ", theme.text_muted_style()),
+                match app.mcp_http_server.configured_tls_cert.as_ref() {
+                    Some(path) => Span::styled(path.display().to_string(), theme.syntax_string_style()),
+                    None => Span::styled("off", theme.text_muted_style()),
+                },
+            ]),
+            Line::from(vec![
+                Span::styled("Gateway: ", theme.text_muted_style()),
+                if app.mcp_http_server.configured_gateway {
+                    Span::styled("on (plugin tools, resources, and prompts re-exported)", theme.syntax_string_style())
+                } else {
+                    Span::styled("off", theme.text_muted_style())
+                },
+            ]),
             Line::from(vec![
                 Span::styled("Connected clients: ", theme.text_muted_style()),
                 Span::styled(app.mcp_http_server.connected_clients.to_string(), theme.syntax_number_style()),
+                Span::styled("  Usage: ", theme.text_muted_style()),
+                Span::styled(
+                    format!(
+                        "{} calls/min, {} running, {} writes",
+                        app.mcp_http_server.usage.calls_last_minute,
+                        app.mcp_http_server.usage.in_flight,
+                        app.mcp_http_server.usage.write_calls
+                    ),
+                    theme.syntax_number_style(),
+                ),
+                match app.mcp_http_server.usage.throttled {
+                    0 => Span::raw(""),
+                    throttled => Span::styled(format!(", {throttled} throttled"), theme.status_warning()),
+                },
             ]),
         ];
         if let Some(error) = app.mcp_http_server.last_error.as_ref() {
@@ -338,6 +444,44 @@ impl McpHttpServerComponent {
         frame.render_widget(paragraph, self.layout.details_area);
     }
 
+    fn render_client_list(&mut self, frame: &mut Frame, app: &App) {
+        let theme = &*app.ctx.theme;
+        let state = &app.mcp_http_server;
+        let block = th::block(theme, Some("Client Tokens"), state.client_list_focus.get());
+        let inner = block.inner(self.layout.client_list_area);
+        frame.render_widget(block, self.layout.client_list_area);
+
+        self.layout.client_row_areas.clear();
+        if inner.height == 0 || inner.width == 0 {
+            return;
+        }
+        if state.clients().is_empty() {
+            let empty = Paragraph::new(Span::styled("No tokens. Press n to issue one.", theme.text_muted_style()));
+            frame.render_widget(empty, inner);
+            return;
+        }
+
+        let visible_rows = inner.height as usize;
+        let first_row = state.selected_client_index.saturating_sub(visible_rows.saturating_sub(1));
+        for (row, (client_index, client)) in state.clients().iter().enumerate().skip(first_row).take(visible_rows).enumerate() {
+            let name_style = if client_index == state.selected_client_index {
+                theme.status_success()
+            } else {
+                theme.text_primary_style()
+            };
+            let line = Line::from(vec![
+                Span::styled(client.name.clone(), name_style),
+                Span::styled(
+                    format!("  issued {}", client.created_at.format("%Y-%m-%d %H:%M")),
+                    theme.text_muted_style(),
+                ),
+            ]);
+            let row_area = Rect::new(inner.x, inner.y + row as u16, inner.width, 1);
+            frame.render_widget(Paragraph::new(line), row_area);
+            self.layout.client_row_areas.push((client_index, row_area));
+        }
+    }
+
     fn render_config_list(&mut self, frame: &mut Frame, app: &mut App) {
         let theme = &*app.ctx.theme;
         let is_focused = app.mcp_http_server.config_list_focus.get();
@@ -399,7 +543,52 @@ impl McpHttpServerComponent {
 }
 
 impl Component for McpHttpServerComponent {
+    fn handle_message(&mut self, app: &mut App, msg: Msg) -> Vec<Effect> {
+        let Msg::ExecCompleted(outcome) = msg else {
+            return Vec::new();
+        };
+        let ExecOutcome::McpAuditTrail { log_entry, result } = *outcome else {
+            return Vec::new();
+        };
+        match result {
+            Ok(payload) => {
+                let outcome = ExecOutcome::Http {
+                    status_code: 200,
+                    log_entry,
+                    payload,
+                    request_id: 0,
+                };
+                vec![Effect::ShowModal(Modal::Results(Some(Box::new(outcome))))]
+            }
+            Err(error) => {
+                app.append_log_message_with_level(Some(LogLevel::Error), format!("Failed to read the audit trail: {error}"));
+                Vec::new()
+            }
+        }
+    }
+
+    #[allow(clippy::collapsible_match)]
     fn handle_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
+        match app.ctx.keymap.action(KeyScope::McpServer, &key) {
+            Some(KeyAction::McpAuditTrail) => return self.open_audit_trail(app),
+            Some(KeyAction::McpAuditSinceFilter) => {
+                app.mcp_http_server.cycle_audit_since();
+                return Vec::new();
+            }
+            Some(KeyAction::McpAuditStatusFilter) => {
+                app.mcp_http_server.cycle_audit_status();
+                return Vec::new();
+            }
+            Some(KeyAction::McpAuditCatalogFilter) => {
+                self.cycle_audit_catalog(app);
+                return Vec::new();
+            }
+            Some(KeyAction::McpNewToken) => return vec![Effect::McpHttpClientTokenCreate],
+            Some(KeyAction::McpRevokeClient) if app.mcp_http_server.client_list_focus.get() => {
+                return self.handle_client_revoke(app);
+            }
+            _ => {}
+        }
         match key.code {
             KeyCode::Tab => {
                 app.focus.next();
@@ -419,12 +608,18 @@ impl Component for McpHttpServerComponent {
                 }
             }
             KeyCode::Down => {
+                if app.mcp_http_server.client_list_focus.get() {
+                    app.mcp_http_server.select_client_relative(1);
+                }
                 if app.mcp_http_server.config_list_focus.get() {
                     Self::select_next_config(app);
                     Self::ensure_selected_config_visible(app);
                 }
             }
             KeyCode::Up => {
+                if app.mcp_http_server.client_list_focus.get() {
+                    app.mcp_http_server.select_client_relative(-1);
+                }
                 if app.mcp_http_server.config_list_focus.get() {
                     Self::select_previous_config(app);
                     Self::ensure_selected_config_visible(app);
@@ -456,6 +651,13 @@ impl Component for McpHttpServerComponent {
                 app.focus.focus(&app.mcp_http_server.auto_start_focus);
                 return self.handle_auto_start_toggle(app);
             }
+            if self.layout.client_list_area.contains(pos) {
+                app.focus.focus(&app.mcp_http_server.client_list_focus);
+                if let Some((client_index, _)) = self.layout.client_row_areas.iter().find(|(_, area)| area.contains(pos)) {
+                    app.mcp_http_server.selected_client_index = *client_index;
+                }
+                return Vec::new();
+            }
             if let Some((config_index, _)) = self.layout.config_copy_areas.iter().find(|(_, area)| area.contains(pos)) {
                 app.focus.focus(&app.mcp_http_server.config_list_focus);
                 return self.handle_config_copy(app, *config_index);
@@ -503,10 +705,25 @@ impl Component for McpHttpServerComponent {
         self.render_controls(frame, app);
         self.render_message(frame, app);
         self.render_details(frame, app);
+        self.render_client_list(frame, app);
         self.render_config_list(frame, app);
     }
 
     fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
+        let keymap = &app.ctx.keymap;
+        let revoke = keymap.label(KeyAction::McpRevokeClient);
+        let new_token = keymap.label(KeyAction::McpNewToken);
+        let audit_trail = keymap.label(KeyAction::McpAuditTrail);
+        let audit_filters = format!(
+            "{}/{}/{}",
+            keymap.label(KeyAction::McpAuditSinceFilter),
+            keymap.label(KeyAction::McpAuditStatusFilter),
+            keymap.label(KeyAction::McpAuditCatalogFilter)
+        );
+        let audit_trail_hint = match app.mcp_http_server.audit_filter().describe() {
+            Some(filters) => format!(" Audit trail ({filters}) "),
+            None => " Audit trail ".to_string(),
+        };
         let mut hints = Vec::new();
         if app.mcp_http_server.start_stop_focus.get() {
             hints.push(("Enter/Space", " Start/Stop "));
@@ -514,11 +731,18 @@ impl Component for McpHttpServerComponent {
         if app.mcp_http_server.auto_start_focus.get() {
             hints.push(("Enter/Space", " Toggle auto-start "));
         }
+        if app.mcp_http_server.client_list_focus.get() {
+            hints.push(("↑/↓", " Select client "));
+            hints.push((revoke.as_str(), " Revoke token "));
+        }
         if app.mcp_http_server.config_list_focus.get() {
             hints.push(("↑/↓", " Select config "));
             hints.push(("PgUp/PgDn", " Scroll configs "));
             hints.push(("Enter/Space", " Copy selected config "));
         }
+        hints.push((new_token.as_str(), " New client token "));
+        hints.push((audit_trail.as_str(), audit_trail_hint.as_str()));
+        hints.push((audit_filters.as_str(), " Audit time/status/catalog filter "));
         th::build_hint_spans(&*app.ctx.theme, &hints)
     }
 
@@ -532,7 +756,8 @@ impl Component for McpHttpServerComponent {
         let sections = Layout::vertical([
             Constraint::Length(1), // Status
             Constraint::Length(3), // Controls
-            Constraint::Min(1),    // Details
+            Constraint::Length(8), // Details
+            Constraint::Min(3),    // Client tokens
         ])
         .split(cols[0]);
 
@@ -558,6 +783,7 @@ impl Component for McpHttpServerComponent {
             controls[2],      // Auto-start Checkbox
             config_layout[0], // Message above the config list
             config_layout[2], // Config list
+            sections[3],      // Client tokens
         ]
     }
 }
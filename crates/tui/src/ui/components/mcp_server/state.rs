@@ -1,12 +1,51 @@
 //! State for the MCP HTTP server view.
 
 use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::path::PathBuf;
 
 use crate::ui::components::common::ScrollMetrics;
+use oatty_mcp::{McpConfirmationRequest, McpHttpClientToken, McpHttpServerConfig, McpUsageSnapshot};
 use oatty_types::{MessageType, TransientMessage};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
 use ratatui::layout::Rect;
 
+/// Ages cycled by the audit trail time filter.
+const AUDIT_SINCE_PRESETS: &[&str] = &["1h", "24h", "7d"];
+/// Outcomes and status classes cycled by the audit trail status filter.
+const AUDIT_STATUS_PRESETS: &[&str] = &["failure", "denied", "4xx", "5xx"];
+
+/// Filters applied when the audit trail is loaded, in `oatty audit query` syntax.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditTrailFilter {
+    pub since: Option<String>,
+    pub catalog: Option<String>,
+    pub status: Option<String>,
+}
+
+impl AuditTrailFilter {
+    /// Short description of the active filters, e.g. `since 24h, status 5xx`.
+    pub fn describe(&self) -> Option<String> {
+        let parts: Vec<String> = [("since", &self.since), ("catalog", &self.catalog), ("status", &self.status)]
+            .into_iter()
+            .filter_map(|(label, value)| value.as_ref().map(|value| format!("{label} {value}")))
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+}
+
+/// Returns the option after `current`, wrapping back to no filter after the last one.
+fn next_filter_value<S: AsRef<str>>(current: Option<&str>, options: &[S]) -> Option<String> {
+    let next_index = match current {
+        None => 0,
+        Some(current) => options
+            .iter()
+            .position(|option| option.as_ref() == current)
+            .map_or(0, |index| index + 1),
+    };
+    options.get(next_index).map(|option| option.as_ref().to_string())
+}
+
 /// High-level lifecycle status for the local MCP HTTP server.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum McpHttpServerStatus {
@@ -44,10 +83,24 @@ pub struct McpHttpServerState {
     pub auto_start: bool,
     /// Configured bind address (for example, "127.0.0.1:0").
     pub configured_bind_address: String,
+    /// Policy file that scopes what connected clients may do, when configured.
+    pub configured_policy_file: Option<PathBuf>,
+    /// Certificate file served over TLS, when configured.
+    pub configured_tls_cert: Option<PathBuf>,
+    /// Whether running plugins are re-exported through the server.
+    pub configured_gateway: bool,
+    /// Clients holding a bearer token, in issue order.
+    clients: Vec<McpHttpClientToken>,
+    /// Selected index within the client token list.
+    pub selected_client_index: usize,
+    /// Focus flag for the client token list.
+    pub client_list_focus: FocusFlag,
     /// Bound address when the server is running.
     pub bound_address: Option<String>,
     /// Latest observed connected client count.
     pub connected_clients: usize,
+    /// Latest observed execution usage across sessions.
+    pub usage: McpUsageSnapshot,
     /// Last error message captured from the server lifecycle.
     pub last_error: Option<String>,
     /// Focus flag for the start/stop button.
@@ -64,6 +117,14 @@ pub struct McpHttpServerState {
     config_scroll_metrics: ScrollMetrics,
     /// Transient status message shown in the MCP server view.
     pub message: Option<TransientMessage>,
+    /// Tool call currently shown to the operator for confirmation.
+    active_confirmation: Option<McpConfirmationRequest>,
+    /// Tool calls waiting behind the active confirmation.
+    pending_confirmations: VecDeque<McpConfirmationRequest>,
+    /// Focus flag for the approve button in the confirmation modal.
+    pub approve_confirmation_focus: FocusFlag,
+    /// Filters used the next time the audit trail is opened.
+    audit_filter: AuditTrailFilter,
 }
 
 impl McpHttpServerState {
@@ -95,6 +156,7 @@ impl McpHttpServerState {
         self.status = McpHttpServerStatus::Stopped;
         self.bound_address = None;
         self.connected_clients = 0;
+        self.usage = McpUsageSnapshot::default();
     }
 
     /// Mark the server as errored with a descriptive message.
@@ -103,9 +165,54 @@ impl McpHttpServerState {
         self.last_error = Some(message);
     }
 
-    /// Update the configured bind address string.
-    pub fn set_configured_bind_address(&mut self, address: String) {
-        self.configured_bind_address = address;
+    /// Mirror the `httpServer` section of the MCP config.
+    pub fn apply_config(&mut self, config: &McpHttpServerConfig) {
+        if let Some(bind_address) = config.bind_address.as_ref() {
+            self.configured_bind_address = bind_address.clone();
+        }
+        self.configured_policy_file = config.policy_file.clone();
+        self.configured_tls_cert = config.tls.as_ref().map(|tls| tls.cert_file.clone());
+        self.configured_gateway = config.gateway;
+        self.auto_start = config.auto_start;
+        self.set_clients(config.clients.clone());
+    }
+
+    /// Replace the client token list, keeping the selection in range.
+    pub fn set_clients(&mut self, clients: Vec<McpHttpClientToken>) {
+        self.clients = clients;
+        self.selected_client_index = self.selected_client_index.min(self.clients.len().saturating_sub(1));
+    }
+
+    /// Clients holding a bearer token.
+    pub fn clients(&self) -> &[McpHttpClientToken] {
+        &self.clients
+    }
+
+    /// The client selected in the token list.
+    pub fn selected_client(&self) -> Option<&McpHttpClientToken> {
+        self.clients.get(self.selected_client_index)
+    }
+
+    /// Move the client token selection by `delta` rows.
+    pub fn select_client_relative(&mut self, delta: isize) {
+        if self.clients.is_empty() {
+            return;
+        }
+        self.selected_client_index = self.selected_client_index.saturating_add_signed(delta).min(self.clients.len() - 1);
+    }
+
+    /// First `client-N` name not already holding a token.
+    pub fn next_client_name(&self) -> String {
+        (1..)
+            .map(|index| format!("client-{index}"))
+            .find(|name| self.clients.iter().all(|client| &client.name != name))
+            .unwrap_or_default()
+    }
+
+    /// URL clients connect to at `address`.
+    pub fn endpoint_url(&self, address: &str) -> String {
+        let scheme = if self.configured_tls_cert.is_some() { "https" } else { "http" };
+        format!("{scheme}://{address}/mcp")
     }
 
     /// Update the live connected client count.
@@ -113,6 +220,11 @@ impl McpHttpServerState {
         self.connected_clients = count;
     }
 
+    /// Update the live execution usage counters.
+    pub fn update_usage(&mut self, usage: McpUsageSnapshot) {
+        self.usage = usage;
+    }
+
     /// Set the currently selected client config snippet index.
     pub fn set_selected_config_index(&mut self, index: usize) {
         self.selected_config_index = index;
@@ -172,6 +284,55 @@ impl McpHttpServerState {
     pub fn message_ref(&self) -> Option<&TransientMessage> {
         self.message.as_ref()
     }
+
+    /// Queue a tool call for operator confirmation.
+    ///
+    /// Returns `true` when the request became the active confirmation and should be shown.
+    pub fn enqueue_confirmation(&mut self, request: McpConfirmationRequest) -> bool {
+        self.pending_confirmations.push_back(request);
+        self.active_confirmation.is_none() && self.activate_next_confirmation()
+    }
+
+    /// Returns the tool call currently awaiting the operator's decision.
+    pub fn active_confirmation(&self) -> Option<&McpConfirmationRequest> {
+        self.active_confirmation.as_ref()
+    }
+
+    /// Answer the active confirmation and promote the next queued request.
+    ///
+    /// Returns `true` when another request became active and should be shown.
+    pub fn resolve_confirmation(&mut self, approved: bool) -> bool {
+        if let Some(request) = self.active_confirmation.take() {
+            request.respond(approved);
+        }
+        self.activate_next_confirmation()
+    }
+
+    /// Filters used the next time the audit trail is opened.
+    pub fn audit_filter(&self) -> &AuditTrailFilter {
+        &self.audit_filter
+    }
+
+    /// Cycles the audit time filter: all → 1h → 24h → 7d → all.
+    pub fn cycle_audit_since(&mut self) {
+        self.audit_filter.since = next_filter_value(self.audit_filter.since.as_deref(), AUDIT_SINCE_PRESETS);
+    }
+
+    /// Cycles the audit status filter: all → failure → denied → 4xx → 5xx → all.
+    pub fn cycle_audit_status(&mut self) {
+        self.audit_filter.status = next_filter_value(self.audit_filter.status.as_deref(), AUDIT_STATUS_PRESETS);
+    }
+
+    /// Cycles the audit catalog filter through the given catalog titles.
+    pub fn cycle_audit_catalog(&mut self, catalogs: &[String]) {
+        self.audit_filter.catalog = next_filter_value(self.audit_filter.catalog.as_deref(), catalogs);
+    }
+
+    fn activate_next_confirmation(&mut self) -> bool {
+        self.pending_confirmations.retain(|request| !request.is_expired());
+        self.active_confirmation = self.pending_confirmations.pop_front();
+        self.active_confirmation.is_some()
+    }
 }
 
 impl Default for McpHttpServerState {
@@ -180,8 +341,15 @@ impl Default for McpHttpServerState {
             status: McpHttpServerStatus::Stopped,
             auto_start: false,
             configured_bind_address: "127.0.0.1:62889".to_string(),
+            configured_policy_file: None,
+            configured_tls_cert: None,
+            configured_gateway: false,
+            clients: Vec::new(),
+            selected_client_index: 0,
+            client_list_focus: FocusFlag::new().with_name("mcp_http.client_list"),
             bound_address: None,
             connected_clients: 0,
+            usage: McpUsageSnapshot::default(),
             last_error: None,
             start_stop_focus: FocusFlag::new().with_name("mcp_http.start_stop"),
             auto_start_focus: FocusFlag::new().with_name("mcp_http.auto_start"),
@@ -190,6 +358,10 @@ impl Default for McpHttpServerState {
             selected_config_index: 0,
             config_scroll_metrics: ScrollMetrics::default(),
             message: None,
+            active_confirmation: None,
+            pending_confirmations: VecDeque::new(),
+            approve_confirmation_focus: FocusFlag::new().with_name("mcp_http.approve_confirmation"),
+            audit_filter: AuditTrailFilter::default(),
         };
         state.start_stop_focus.set(true);
         state
@@ -201,6 +373,7 @@ impl HasFocus for McpHttpServerState {
         let tag = builder.start(self);
         builder.leaf_widget(&self.start_stop_focus);
         builder.leaf_widget(&self.auto_start_focus);
+        builder.leaf_widget(&self.client_list_focus);
         builder.leaf_widget(&self.config_list_focus);
         builder.end(tag);
     }
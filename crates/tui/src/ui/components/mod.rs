@@ -1,7 +1,10 @@
 pub mod browser;
 pub mod common;
 pub mod component;
+pub mod diff;
 pub mod help;
+pub mod history;
+pub mod json_editor;
 pub mod library;
 pub mod logs;
 pub mod mcp_server;
@@ -16,6 +19,7 @@ pub use browser::BrowserComponent;
 pub use common::{FilePickerModal, FilePickerState};
 pub use component::{Component, find_target_index_by_mouse_position};
 pub use help::HelpComponent;
+pub use history::{HistoryComponent, HistoryState};
 pub use library::{LibraryComponent, LibraryState};
 pub use logs::LogsComponent;
 pub use mcp_server::McpHttpServerComponent;
@@ -94,6 +94,7 @@ impl VerticalNavBarState {
             NavItem::for_route("≡ Lib", "Library", Route::Library),
             NavItem::for_route("› Run", "Command Runner", Route::Palette),
             NavItem::for_route("⌕ Fnd", "Find", Route::Browser),
+            NavItem::for_route("◷ His", "Execution History", Route::History),
             NavItem::for_route("↺ Wkf", "Workflows", Route::Workflows),
             NavItem::for_route("↯ MCP", "Model Context Protocol Servers", Route::Plugins),
             NavItem::for_route("⇄ Srv", "MCP HTTP Server", Route::McpHttpServer),
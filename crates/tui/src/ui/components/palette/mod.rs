@@ -1,4 +1,5 @@
 pub mod palette_component;
+pub mod result_refs;
 pub mod state;
 pub mod suggestion_engine;
 
@@ -27,7 +27,9 @@ use serde_json::{Map as JsonMap, Value as JsonValue};
 use crate::app::App;
 use crate::ui::components::common::text_input::cursor_index_for_column;
 use crate::ui::components::common::{ConfirmationModalButton, ConfirmationModalOpts};
+use crate::ui::components::json_editor::JsonEditorTarget;
 use crate::ui::components::palette::suggestion_engine::{build_inputs_map_for_flag, build_inputs_map_for_positional};
+use crate::ui::keymap::KeyAction;
 use crate::ui::theme::theme_helpers::{ButtonType, create_list_with_highlight};
 use crate::ui::{
     components::component::Component,
@@ -82,6 +84,9 @@ pub struct PaletteComponent {
     /// Focus flag for confirming a destructive command
     /// used in the confirmation modal
     confirm_button: FocusFlag,
+    /// Command line most recently sent for execution; becomes the watch source
+    /// when its results open in the results modal.
+    executed_command: Option<String>,
 }
 
 impl PaletteComponent {
@@ -234,6 +239,18 @@ impl PaletteComponent {
         effects
     }
 
+    /// Opens the JSON editor for the object or array flag under the cursor.
+    fn open_json_editor(&self, app: &mut App) -> Vec<Effect> {
+        let Some((flag, value)) = app.palette.structured_flag_at_cursor() else {
+            app.palette
+                .apply_error("Move the cursor onto an object or array flag to edit it as JSON".to_string());
+            return Vec::new();
+        };
+        app.json_editor.open(JsonEditorTarget::Palette, &flag, value.as_deref());
+        app.palette.set_is_suggestions_open(false);
+        vec![Effect::ShowModal(Modal::JsonEditor)]
+    }
+
     /// Handles backspace key press in the command palette.
     ///
     /// This function removes the character before the current cursor position,
@@ -324,7 +341,7 @@ impl PaletteComponent {
     /// Handles the Enter keypress.
     fn handle_enter(&mut self, app: &mut App) -> Vec<Effect> {
         if let Some(cmd) = self.execute_command(app) {
-            if app.palette.is_destructive_command() {
+            if !cmd.is_empty() && app.palette.is_destructive_command() {
                 return self.confirm_destructive_command(app);
             }
             return cmd;
@@ -430,32 +447,26 @@ impl PaletteComponent {
     }
 
     fn confirm_destructive_command(&mut self, app: &mut App) -> Vec<Effect> {
-        let buttons = vec![
-            ConfirmationModalButton::new("Cancel", FocusFlag::new(), ButtonType::Secondary),
-            ConfirmationModalButton::new("Confirm", self.confirm_button.clone(), ButtonType::Destructive),
-        ];
-        let command = app.palette.input().to_string();
-        let message = format!(
-            "You are about to run a destructive action that cannot be undone.\nAre you sure you want to run `{}`?",
-            command
-        );
-        app.confirmation_modal_state.update_opts(ConfirmationModalOpts {
-            title: Some("Destructive Action".to_string()),
-            message: Some(message),
-            r#type: Some(MessageType::Warning),
-            buttons,
-        });
+        let opts = destructive_command_confirmation(app.palette.input(), self.confirm_button.clone());
+        app.confirmation_modal_state.update_opts(opts);
 
         vec![Effect::ShowModal(Modal::Confirmation)]
     }
 
     fn execute_command(&mut self, app: &mut App) -> Option<Vec<Effect>> {
-        let cmd = app.palette.input().to_string();
         if !app.palette.is_suggestions_open() {
+            let cmd = match app.palette.resolved_input() {
+                Ok(cmd) => cmd,
+                Err(error) => {
+                    app.palette.apply_error(error);
+                    return Some(Vec::new());
+                }
+            };
             let mut hasher = DefaultHasher::new();
             hasher.write(cmd.as_bytes());
             let hash = hasher.finish();
             app.palette.set_cmd_exec_hash(hash);
+            self.executed_command = Some(cmd.clone());
             return Some(vec![Effect::Run {
                 hydrated_command: cmd,
                 request_hash: hash,
@@ -465,6 +476,24 @@ impl PaletteComponent {
     }
 }
 
+/// Confirmation prompt shown before running a destructive command; `confirm_button` runs it.
+pub(crate) fn destructive_command_confirmation(command: &str, confirm_button: FocusFlag) -> ConfirmationModalOpts {
+    let buttons = vec![
+        ConfirmationModalButton::new("Cancel", FocusFlag::new(), ButtonType::Secondary),
+        ConfirmationModalButton::new("Confirm", confirm_button, ButtonType::Destructive),
+    ];
+    let message = format!(
+        "You are about to run a destructive action that cannot be undone.\nAre you sure you want to run `{}`?",
+        command
+    );
+    ConfirmationModalOpts {
+        title: Some("Destructive Action".to_string()),
+        message: Some(message),
+        r#type: Some(MessageType::Warning),
+        buttons,
+    }
+}
+
 fn resolve_palette_command_spec(app: &App, command_key: &str) -> Option<CommandSpec> {
     let (group, name) = command_key.split_once(char::is_whitespace)?;
     let lock = app.ctx.command_registry.lock().ok()?;
@@ -534,7 +563,13 @@ impl Component for PaletteComponent {
                     app.palette.handle_provider_fetch_failure(log_message, &*app.ctx.theme);
                     Vec::new()
                 }
-                _ => app.palette.process_general_execution_result(*outcome),
+                _ => {
+                    let effects = app.palette.process_general_execution_result(*outcome);
+                    if effects.iter().any(|effect| matches!(effect, Effect::ShowModal(Modal::Results(_)))) {
+                        app.results.set_pending_source(self.executed_command.clone());
+                    }
+                    effects
+                }
             },
             Msg::ConfirmationModalButtonClicked(id) if id == self.confirm_button.widget_id() => {
                 self.execute_command(app).unwrap_or_default()
@@ -565,7 +600,8 @@ impl Component for PaletteComponent {
     /// - **Arrow keys**: Navigate through suggestions (Up/Down) or move cursor
     ///   (Left/Right)
     /// - **Tab**: Trigger the suggestions list
-    /// - **F1**: Open help for the current command or top suggestion
+    /// - **F1** (`palette.help` in the keymap): Open help for the current command or top suggestion
+    /// - **Ctrl+E** (`palette.edit_json`): Edit the object or array flag under the cursor as JSON
     /// - **Ctrl+F**: Open the command browser
     /// - **Enter**: Execute the current command (if complete) or insert selected suggestion
     /// - **Escape**: Clear the palette input and close suggestions
@@ -594,14 +630,18 @@ impl Component for PaletteComponent {
             };
         }
 
+        if app.ctx.keymap.matches(KeyAction::PaletteHelp, &key) {
+            return self.handle_help_request(app);
+        }
+        if app.ctx.keymap.matches(KeyAction::PaletteEditJson, &key) {
+            return self.open_json_editor(app);
+        }
+
         let mut effects: Vec<Effect> = vec![];
         match key.code {
             KeyCode::Char(c) if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT => {
                 self.handle_character_input(app, c);
             }
-            KeyCode::F(1) => {
-                effects.extend(self.handle_help_request(app));
-            }
             KeyCode::Backspace => {
                 self.handle_backspace(app);
             }
@@ -649,6 +689,7 @@ impl Component for PaletteComponent {
         }
         effects
     }
+    #[allow(clippy::collapsible_match)]
     fn handle_mouse_events(&mut self, app: &mut App, mouse: MouseEvent) -> Vec<Effect> {
         let PaletteLayout {
             suggestions_area,
@@ -760,20 +801,22 @@ impl Component for PaletteComponent {
 
     fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
         let theme = &*app.ctx.theme;
-        th::build_hint_spans(
-            theme,
-            if app.palette.f_message.get() {
-                &[("Enter", " Toggle logs "), ("Tab/Shift+Tab", " Move focus ")]
-            } else {
-                &[
-                    ("Tab", " Completions "),
-                    ("↑/↓", " Cycle  "),
-                    ("Enter", " Accept  "),
-                    ("F1", " Help  "),
-                    ("Esc", " Cancel"),
-                ]
-            },
-        )
+        if app.palette.f_message.get() {
+            return th::build_hint_spans(theme, &[("Enter", " Toggle logs "), ("Tab/Shift+Tab", " Move focus ")]);
+        }
+        let help = app.ctx.keymap.label(KeyAction::PaletteHelp);
+        let mut hints = vec![
+            ("Tab", " Completions "),
+            ("↑/↓", " Cycle  "),
+            ("Enter", " Accept  "),
+            (help.as_str(), " Help  "),
+        ];
+        let edit_json = app.ctx.keymap.label(KeyAction::PaletteEditJson);
+        if app.palette.structured_flag_at_cursor().is_some() {
+            hints.push((&edit_json, " Edit JSON  "));
+        }
+        hints.push(("Esc", " Cancel"));
+        th::build_hint_spans(theme, &hints)
     }
 
     fn get_preferred_layout(&self, app: &App, area: Rect) -> Vec<Rect> {
@@ -0,0 +1,378 @@
+//! Result references for chaining palette commands.
+//!
+//! Recent HTTP and MCP payloads are kept in a small [`ResultHistory`]. Palette input may
+//! refer to them with `$last`, `$rN` (1-based, newest first; `$r1` is `$last`) or the
+//! equivalent `$results[N]`, followed by a field path such as `.items[0].name`. References
+//! are substituted with the scalar they resolve to right before the command runs.
+
+use std::collections::VecDeque;
+
+use oatty_types::{ItemKind, SuggestionItem};
+use oatty_util::truncate_with_ellipsis;
+use serde_json::Value;
+
+/// Number of payloads retained for `$rN` references.
+pub const RESULT_HISTORY_LIMIT: usize = 10;
+/// Maximum array indices offered as completions for one array.
+const INDEX_SUGGESTION_LIMIT: usize = 10;
+
+/// A recorded payload together with a short label for completions.
+#[derive(Clone, Debug)]
+pub struct ResultEntry {
+    pub label: String,
+    pub payload: Value,
+}
+
+/// Recent command payloads addressable by result references, newest first.
+#[derive(Clone, Debug, Default)]
+pub struct ResultHistory {
+    entries: VecDeque<ResultEntry>,
+}
+
+impl ResultHistory {
+    /// Record a payload as the new `$last`; `null` payloads are ignored.
+    pub fn record(&mut self, label: &str, payload: &Value) {
+        if payload.is_null() {
+            return;
+        }
+        let label = truncate_with_ellipsis(label.lines().next().unwrap_or_default().trim(), 48);
+        self.entries.push_front(ResultEntry {
+            label,
+            payload: payload.clone(),
+        });
+        self.entries.truncate(RESULT_HISTORY_LIMIT);
+    }
+
+    /// Entry `position` steps back from the newest (1 = newest).
+    pub fn get(&self, position: usize) -> Option<&ResultEntry> {
+        position.checked_sub(1).and_then(|index| self.entries.get(index))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// A reference parsed from palette input.
+#[derive(Debug, PartialEq, Eq)]
+struct ParsedReference {
+    /// 1-based position in the result history.
+    position: usize,
+    path: Vec<PathSegment>,
+    /// Byte length of the reference text.
+    len: usize,
+    /// Byte length of the root (`$last`, `$r3`, `$results[3]`).
+    root_len: usize,
+}
+
+fn is_key_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-'
+}
+
+fn leading_digits(text: &str) -> usize {
+    text.bytes().take_while(u8::is_ascii_digit).count()
+}
+
+/// Parse the reference root at the start of `text` (which begins with `$`).
+fn parse_root(text: &str) -> Option<(usize, usize)> {
+    let rest = text.strip_prefix('$')?;
+    if rest.starts_with("last") && !rest[4..].bytes().next().is_some_and(is_key_byte) {
+        return Some((1, 5));
+    }
+    if let Some(after) = rest.strip_prefix("results[") {
+        let digits = leading_digits(after);
+        if digits > 0 && after[digits..].starts_with(']') {
+            return Some((after[..digits].parse().ok()?, "$results[".len() + digits + 1));
+        }
+        return None;
+    }
+    let after = rest.strip_prefix('r')?;
+    let digits = leading_digits(after);
+    if digits == 0 || after[digits..].bytes().next().is_some_and(is_key_byte) {
+        return None;
+    }
+    Some((after[..digits].parse().ok()?, 2 + digits))
+}
+
+/// Parse a complete reference at the start of `text`, consuming every well-formed path segment.
+fn parse_reference(text: &str) -> Option<ParsedReference> {
+    let (position, root_len) = parse_root(text)?;
+    let bytes = text.as_bytes();
+    let mut index = root_len;
+    let mut path = Vec::new();
+    loop {
+        match bytes.get(index) {
+            Some(b'.') => {
+                let key_len = text[index + 1..].bytes().take_while(|byte| is_key_byte(*byte)).count();
+                if key_len == 0 {
+                    break;
+                }
+                path.push(PathSegment::Key(text[index + 1..index + 1 + key_len].to_string()));
+                index += 1 + key_len;
+            }
+            Some(b'[') => {
+                let digits = leading_digits(&text[index + 1..]);
+                if digits == 0 || bytes.get(index + 1 + digits) != Some(&b']') {
+                    break;
+                }
+                path.push(PathSegment::Index(text[index + 1..index + 1 + digits].parse().ok()?));
+                index += digits + 2;
+            }
+            _ => break,
+        }
+    }
+    Some(ParsedReference {
+        position,
+        path,
+        len: index,
+        root_len,
+    })
+}
+
+fn walk<'a>(value: &'a Value, path: &[PathSegment]) -> Result<&'a Value, String> {
+    let mut current = value;
+    for segment in path {
+        current = match (segment, current) {
+            (PathSegment::Key(key), Value::Object(map)) => map.get(key).ok_or_else(|| format!("field '{key}' not found"))?,
+            (PathSegment::Index(index), Value::Array(items)) => items
+                .get(*index)
+                .ok_or_else(|| format!("index {index} out of range (length {})", items.len()))?,
+            (PathSegment::Key(key), _) => return Err(format!("cannot read field '{key}' of a non-object")),
+            (PathSegment::Index(index), _) => return Err(format!("cannot index [{index}] into a non-array")),
+        };
+    }
+    Ok(current)
+}
+
+fn scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
+
+/// Replace every result reference in `input` with the scalar it resolves to.
+///
+/// Text after a `$` that is not a reference is left untouched.
+pub fn resolve_result_references(input: &str, history: &ResultHistory) -> Result<String, String> {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(offset) = rest.find('$') {
+        output.push_str(&rest[..offset]);
+        let candidate = &rest[offset..];
+        let Some(reference) = parse_reference(candidate) else {
+            output.push('$');
+            rest = &candidate[1..];
+            continue;
+        };
+        let text = &candidate[..reference.len];
+        let entry = history.get(reference.position).ok_or_else(|| match history.len() {
+            0 => format!("{text}: no results recorded yet"),
+            count => format!("{text}: only {count} result(s) recorded"),
+        })?;
+        let value = walk(&entry.payload, &reference.path).map_err(|error| format!("{text}: {error}"))?;
+        let resolved = scalar_text(value).ok_or_else(|| {
+            let kind = if value.is_array() {
+                "an array"
+            } else if value.is_object() {
+                "an object"
+            } else {
+                "null"
+            };
+            format!("{text} resolves to {kind}; select a scalar field")
+        })?;
+        if resolved.chars().any(char::is_whitespace) {
+            return Err(format!("{text} resolves to a value containing whitespace"));
+        }
+        output.push_str(&resolved);
+        rest = &candidate[reference.len..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+fn value_preview(value: &Value) -> String {
+    match value {
+        Value::Object(map) => format!("object · {} fields", map.len()),
+        Value::Array(items) => format!("array · {} items", items.len()),
+        Value::Null => "null".to_string(),
+        other => truncate_with_ellipsis(&scalar_text(other).unwrap_or_default(), 32),
+    }
+}
+
+fn reference_item(insert_text: String, meta: String, score: i64) -> SuggestionItem {
+    SuggestionItem {
+        display: insert_text.clone(),
+        insert_text,
+        kind: ItemKind::Value,
+        meta: Some(meta),
+        score,
+    }
+}
+
+/// Completions for the result reference being typed at the end of `token`.
+///
+/// Returns `None` when the token does not contain a reference. `token` may carry a
+/// prefix such as `--app=`; insert texts keep it so accepting replaces the whole token.
+pub fn suggest_result_references(history: &ResultHistory, token: &str) -> Option<Vec<SuggestionItem>> {
+    let dollar = token.rfind('$')?;
+    let (prefix, partial) = token.split_at(dollar);
+    let Some(reference) = parse_reference(partial) else {
+        // Still typing the root: offer `$last` and `$rN`.
+        let roots = std::iter::once(("$last".to_string(), history.get(1)))
+            .chain((1..=history.len()).map(|position| (format!("$r{position}"), history.get(position))));
+        let items = roots
+            .filter(|(root, _)| root.starts_with(partial))
+            .filter_map(|(root, entry)| entry.map(|entry| (root, entry)))
+            .enumerate()
+            .map(|(rank, (root, entry))| reference_item(format!("{prefix}{root}"), entry.label.clone(), -(rank as i64)))
+            .collect();
+        return Some(items);
+    };
+
+    let Some(entry) = history.get(reference.position) else {
+        return Some(Vec::new());
+    };
+    let trailing = &partial[reference.len..];
+    let complete = &partial[..reference.len];
+    let (parent_path, key_partial) = match trailing.strip_prefix('.') {
+        Some(key_partial) if key_partial.bytes().all(is_key_byte) => (reference.path.as_slice(), key_partial),
+        _ if trailing.is_empty() && reference.len > reference.root_len => {
+            // A complete trailing key is still open for completion (`$last.na` → `name`).
+            match reference.path.split_last() {
+                Some((PathSegment::Key(key), parent)) => (parent, key.as_str()),
+                _ => (reference.path.as_slice(), ""),
+            }
+        }
+        _ if trailing.is_empty() || trailing == "[" => (reference.path.as_slice(), ""),
+        _ => return Some(Vec::new()),
+    };
+    let Ok(parent) = walk(&entry.payload, parent_path) else {
+        return Some(Vec::new());
+    };
+    let parent_text = if parent_path.len() == reference.path.len() {
+        complete.to_string()
+    } else {
+        complete[..complete.rfind('.').unwrap_or(complete.len())].to_string()
+    };
+
+    let items = match parent {
+        Value::Object(map) => map
+            .iter()
+            .filter(|(key, _)| key.starts_with(key_partial) && key.bytes().all(is_key_byte))
+            .enumerate()
+            .map(|(rank, (key, value))| reference_item(format!("{prefix}{parent_text}.{key}"), value_preview(value), -(rank as i64)))
+            .collect(),
+        Value::Array(items) if key_partial.is_empty() => items
+            .iter()
+            .take(INDEX_SUGGESTION_LIMIT)
+            .enumerate()
+            .map(|(index, value)| reference_item(format!("{prefix}{parent_text}[{index}]"), value_preview(value), -(index as i64)))
+            .collect(),
+        _ => Vec::new(),
+    };
+    Some(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn history() -> ResultHistory {
+        let mut history = ResultHistory::default();
+        history.record(
+            "apps list",
+            &json!([{ "id": "app-1", "name": "alpha" }, { "id": "app-2", "name": "beta" }]),
+        );
+        history.record(
+            "apps info",
+            &json!({ "id": "app-9", "owner": { "email": "ops@example.com" }, "tags": ["a b"] }),
+        );
+        history
+    }
+
+    #[test]
+    fn resolves_last_and_numbered_references() {
+        let history = history();
+        assert_eq!(
+            resolve_result_references("apps info $last.id", &history).unwrap(),
+            "apps info app-9"
+        );
+        assert_eq!(
+            resolve_result_references("apps info --app=$r2[1].name", &history).unwrap(),
+            "apps info --app=beta"
+        );
+        assert_eq!(
+            resolve_result_references("users get $results[1].owner.email", &history).unwrap(),
+            "users get ops@example.com"
+        );
+        assert_eq!(resolve_result_references("echo $HOME $5", &history).unwrap(), "echo $HOME $5");
+    }
+
+    #[test]
+    fn reports_unresolvable_references() {
+        let history = history();
+        assert!(resolve_result_references("x y $r5.id", &history).unwrap_err().contains("only 2"));
+        assert!(
+            resolve_result_references("x y $last.missing", &history)
+                .unwrap_err()
+                .contains("'missing'")
+        );
+        assert!(
+            resolve_result_references("x y $last.owner", &history)
+                .unwrap_err()
+                .contains("an object")
+        );
+        assert!(
+            resolve_result_references("x y $last.tags[0]", &history)
+                .unwrap_err()
+                .contains("whitespace")
+        );
+        assert!(
+            resolve_result_references("x y $last", &ResultHistory::default())
+                .unwrap_err()
+                .contains("no results")
+        );
+    }
+
+    #[test]
+    fn suggests_roots_fields_and_indices() {
+        let history = history();
+        let displays = |items: Vec<SuggestionItem>| items.into_iter().map(|item| item.insert_text).collect::<Vec<_>>();
+
+        assert_eq!(
+            displays(suggest_result_references(&history, "$").unwrap()),
+            vec!["$last", "$r1", "$r2"]
+        );
+        assert_eq!(
+            displays(suggest_result_references(&history, "--app=$last.o").unwrap()),
+            vec!["--app=$last.owner"]
+        );
+        assert_eq!(
+            displays(suggest_result_references(&history, "$last.owner.").unwrap()),
+            vec!["$last.owner.email"]
+        );
+        assert_eq!(
+            displays(suggest_result_references(&history, "$r2").unwrap()),
+            vec!["$r2[0]", "$r2[1]"]
+        );
+        assert_eq!(
+            displays(suggest_result_references(&history, "$r2[0].n").unwrap()),
+            vec!["$r2[0].name"]
+        );
+        assert!(suggest_result_references(&history, "plain").is_none());
+    }
+}
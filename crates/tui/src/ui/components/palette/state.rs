@@ -23,16 +23,17 @@ use std::{
 use crate::ui::theme::Theme;
 use crate::ui::{
     components::palette::suggestion_engine::SuggestionEngine,
-    utils::{SpanCollector, truncate_to_width},
+    utils::{SpanCollector, is_destructive_command_line, truncate_to_width},
 };
 use chrono::Utc;
 
+use super::result_refs::{ResultHistory, resolve_result_references};
 use super::suggestion_engine::{parse_user_flags_args, required_flags_remaining};
 use crate::ui::components::common::TextInputState;
 use crate::ui::theme::theme_helpers::{create_spans_with_match, highlight_segments};
 use oatty_engine::provider::{PendingProviderFetch, ValueProvider};
 use oatty_registry::CommandRegistry;
-use oatty_types::{CommandExecution, CommandSpec, Effect, ExecOutcome, ItemKind, MessageType, Modal, SuggestionItem, TransientMessage};
+use oatty_types::{CommandFlag, CommandSpec, Effect, ExecOutcome, ItemKind, MessageType, Modal, SuggestionItem, TransientMessage};
 use oatty_util::{
     HistoryKey, HistoryScope, HistoryScopeKind, HistoryStore, StoredHistoryValue, has_meaningful_value, lex_shell_like,
     lex_shell_like_ranged, value_contains_secret,
@@ -124,6 +125,8 @@ pub struct PaletteState {
     pending_command_id: Option<String>,
     /// Pending command input captured at dispatch time.
     pending_command_input: Option<String>,
+    /// Recent command payloads addressable through `$last` / `$rN` references.
+    result_history: ResultHistory,
 }
 
 impl PaletteState {
@@ -155,6 +158,7 @@ impl PaletteState {
             stored_commands: HashMap::new(),
             pending_command_id: None,
             pending_command_input: None,
+            result_history: ResultHistory::default(),
         };
         state.load_persisted_history();
         state
@@ -385,19 +389,8 @@ impl PaletteState {
         self.is_destructive
     }
     fn update_is_destructive(&mut self) {
-        if let [group, name, ..] = &lex_shell_like(&self.input)[..] {
-            let Ok(lock) = self.registry.try_lock() else { return };
-            let Ok(CommandSpec {
-                execution: CommandExecution::Http(execution),
-                ..
-            }) = lock.find_by_group_and_cmd_ref(group, name)
-            else {
-                return;
-            };
-            self.is_destructive = execution.method == "DELETE";
-        } else {
-            self.is_destructive = false;
-        }
+        let Ok(lock) = self.registry.try_lock() else { return };
+        self.is_destructive = is_destructive_command_line(&lock, &self.input);
     }
 
     /// Insert text at the end of the input with a separating space and advance
@@ -506,7 +499,7 @@ impl PaletteState {
     /// Finalize the suggestions list for the UI: rank, truncate, ghost text, and
     /// state flags.
     fn finalize_suggestions(&mut self, items: &mut [SuggestionItem], theme: &dyn Theme) {
-        items.sort_by(|a, b| b.score.cmp(&a.score));
+        items.sort_by_key(|item| std::cmp::Reverse(item.score));
 
         self.suggestions = items.to_vec();
         self.is_suggestions_open = !self.suggestions.is_empty();
@@ -902,7 +895,8 @@ impl PaletteState {
             lock.commands.clone()
         };
         let mut items = {
-            let result = SuggestionEngine::build(&commands_snapshot, providers, &self.input);
+            let result = SuggestionEngine::build_result_references(&self.result_history, &self.input)
+                .unwrap_or_else(|| SuggestionEngine::build(&commands_snapshot, providers, &self.input));
             let mut items = result.items;
             pending_fetches = result.pending_fetches;
             self.provider_loading = result.provider_loading || !pending_fetches.is_empty();
@@ -1045,7 +1039,7 @@ impl PaletteState {
             .filter(|record| matches!(record.key.scope, HistoryScope::PaletteCommand { .. }))
             .collect();
 
-        filtered.sort_by(|a, b| a.value.updated_at.cmp(&b.value.updated_at));
+        filtered.sort_by_key(|entry| entry.value.updated_at);
 
         for record in filtered {
             if let HistoryScope::PaletteCommand { command_id } = record.key.scope
@@ -1058,6 +1052,124 @@ impl PaletteState {
         }
     }
 
+    /// Record a successful HTTP/MCP payload as the new `$last` result.
+    pub(crate) fn record_result(&mut self, execution_outcome: &ExecOutcome) {
+        match execution_outcome {
+            ExecOutcome::Http {
+                status_code,
+                log_entry,
+                payload,
+                ..
+            } if *status_code < 400 => self.result_history.record(log_entry, payload),
+            ExecOutcome::Mcp { log_entry, payload, .. } => self.result_history.record(log_entry, payload),
+            _ => {}
+        }
+    }
+
+    /// The current input with every result reference substituted.
+    pub(crate) fn resolved_input(&self) -> Result<String, String> {
+        resolve_result_references(&self.input, &self.result_history)
+    }
+
+    /// Fill the pending flag with `value`, or append `--flag value` when no flag awaits one.
+    pub(crate) fn apply_insert_flag_value(&mut self, flag: &str, value: &str) {
+        let tokens = lex_shell_like(&self.input);
+        let spec = match (tokens.first(), tokens.get(1), self.registry.lock()) {
+            (Some(group), Some(name), Ok(lock)) => lock.find_by_group_and_cmd_cloned(group, name).ok(),
+            _ => None,
+        };
+        let awaiting_value = tokens.len() > 2
+            && tokens.last().is_some_and(|token| {
+                token.starts_with("--")
+                    && !token.contains('=')
+                    && spec.as_ref().is_some_and(|spec| {
+                        spec.flags
+                            .iter()
+                            .any(|candidate| candidate.name == token.trim_start_matches('-') && candidate.r#type != "boolean")
+                    })
+            });
+        let addition = if awaiting_value {
+            value.to_string()
+        } else {
+            format!("--{flag} {value}")
+        };
+        let mut input = self.input.trim_end().to_string();
+        if !input.is_empty() {
+            input.push(' ');
+        }
+        input.push_str(&addition);
+        input.push(' ');
+        self.cursor_position = input.len();
+        self.input = input;
+        self.update_is_destructive();
+    }
+
+    /// The object or array flag under the cursor and its current value.
+    ///
+    /// The cursor may sit on the `--flag` token, on its value, or past a
+    /// `--flag` that still awaits one.
+    pub(crate) fn structured_flag_at_cursor(&self) -> Option<(CommandFlag, Option<String>)> {
+        let tokens = lex_shell_like_ranged(&self.input);
+        let (group, name) = (tokens.first()?, tokens.get(1)?);
+        let spec = self
+            .registry
+            .lock()
+            .ok()?
+            .find_by_group_and_cmd_cloned(group.text, name.text)
+            .ok()?;
+        let index = token_index_at_cursor(&self.input, self.cursor_position)?;
+        [Some(index), index.checked_sub(1)]
+            .into_iter()
+            .flatten()
+            .filter(|flag_index| *flag_index >= 2)
+            .find_map(|flag_index| {
+                let token = tokens[flag_index].text.strip_prefix("--")?;
+                let (flag_name, inline_value) = match token.split_once('=') {
+                    Some((flag_name, value)) if flag_index == index => (flag_name, Some(value)),
+                    Some(_) => return None,
+                    None => (token, None),
+                };
+                let flag = spec
+                    .flags
+                    .iter()
+                    .find(|flag| flag.name == flag_name && matches!(flag.r#type.as_str(), "object" | "array"))?;
+                let value = inline_value.or_else(|| {
+                    tokens
+                        .get(flag_index + 1)
+                        .map(|token| token.text)
+                        .filter(|text| !text.starts_with('-'))
+                });
+                Some((flag.clone(), value.map(str::to_string)))
+            })
+    }
+
+    /// Set `--flag` to `value`, replacing its current value, or append it when absent.
+    ///
+    /// `value` must be a single palette token, such as compact JSON.
+    pub(crate) fn apply_set_flag_value(&mut self, flag: &str, value: &str) {
+        let flag_token = format!("--{flag}");
+        let tokens = lex_shell_like_ranged(&self.input);
+        let existing = tokens
+            .iter()
+            .enumerate()
+            .skip(2)
+            .find(|(_, token)| token.text == flag_token || token.text.starts_with(&format!("{flag_token}=")));
+        let Some((index, token)) = existing else {
+            self.insert_with_space(&format!("{flag_token} {value}"));
+            return;
+        };
+        let (range, replacement) = if token.text != flag_token {
+            (token.start..token.end, format!("{flag_token} {value}"))
+        } else if let Some(next) = tokens.get(index + 1).filter(|next| !next.text.starts_with('-')) {
+            (next.start..next.end, value.to_string())
+        } else {
+            (token.end..token.end, format!(" {value}"))
+        };
+        self.cursor_position = range.start + replacement.len();
+        self.input.replace_range(range, &replacement);
+        self.update_is_destructive();
+    }
+
     pub(crate) fn record_pending_execution(&mut self, command_id: String, input: String) {
         self.pending_command_id = Some(command_id);
         self.pending_command_input = Some(input.trim().to_string());
@@ -1148,7 +1260,7 @@ impl PaletteState {
         }
 
         self.finalize_successful_execution("Command completed successfully");
-        effects.push(Effect::ShowModal(Modal::Results(Box::new(execution_outcome))));
+        effects.push(Effect::ShowModal(Modal::Results(Some(Box::new(execution_outcome)))));
 
         effects
     }
@@ -1608,6 +1720,75 @@ mod tests {
         assert_eq!(st.input(), "apps info sample-prod ");
     }
 
+    #[test]
+    fn recorded_results_resolve_and_cells_insert_as_flags() {
+        use serde_json::json;
+
+        let mut st = make_palette_state();
+        st.record_result(&ExecOutcome::Http {
+            status_code: 200,
+            log_entry: "apps list".into(),
+            payload: json!([{"name": "sample-prod"}]),
+            request_id: 1,
+        });
+        st.record_result(&ExecOutcome::Http {
+            status_code: 404,
+            log_entry: "missing".into(),
+            payload: json!({"name": "ignored"}),
+            request_id: 2,
+        });
+        st.set_input("apps info $last[0].name".into());
+        assert_eq!(st.resolved_input().as_deref(), Ok("apps info sample-prod"));
+
+        st.set_input("apps info".into());
+        st.apply_insert_flag_value("app", "sample-prod");
+        assert_eq!(st.input(), "apps info --app sample-prod ");
+    }
+
+    #[test]
+    fn structured_flag_under_cursor_is_found_and_replaced() {
+        use oatty_types::HttpCommandSpec;
+
+        let flag = |name: &str, r#type: &str| CommandFlag {
+            name: name.into(),
+            short_name: None,
+            required: false,
+            r#type: r#type.into(),
+            enum_values: vec![],
+            default_value: None,
+            description: None,
+            provider: None,
+            schema: None,
+        };
+        let spec = CommandSpec::new_http(
+            "services".into(),
+            "create".into(),
+            "Create a service".into(),
+            vec![],
+            vec![flag("name", "string"), flag("body", "object")],
+            HttpCommandSpec::new("POST", "/services", None, None),
+            0,
+        );
+        let registry = CommandRegistry::default().with_commands(vec![spec]);
+        let mut st = palette_state_with_registry(Arc::new(Mutex::new(registry)));
+
+        st.set_input("services create --name api --body ".into());
+        st.set_cursor(st.input().len());
+        let (found, value) = st.structured_flag_at_cursor().expect("pending body flag");
+        assert_eq!((found.name.as_str(), value), ("body", None));
+        st.apply_set_flag_value("body", r#"{"plan":"starter plan"}"#);
+        assert_eq!(st.input(), r#"services create --name api --body {"plan":"starter plan"} "#);
+
+        st.set_cursor(st.input().len() - 3);
+        let (_, value) = st.structured_flag_at_cursor().expect("cursor on body value");
+        assert_eq!(value.as_deref(), Some(r#"{"plan":"starter plan"}"#));
+        st.apply_set_flag_value("body", "{}");
+        assert_eq!(st.input(), "services create --name api --body {} ");
+
+        st.set_cursor("services create --na".len());
+        assert!(st.structured_flag_at_cursor().is_none());
+    }
+
     #[test]
     fn handle_provider_fetch_failure_clears_loading_placeholder_and_sets_error() {
         let registry = Arc::new(Mutex::new(CommandRegistry::from_config().expect("embedded registry")));
@@ -4,6 +4,8 @@ use std::sync::Arc;
 
 use oatty_engine::provider::{PendingProviderFetch, ProviderSuggestionSet, ValueProvider};
 
+use super::result_refs::{ResultHistory, suggest_result_references};
+
 // ===== Types =====
 
 /// Result of a suggestion build operation containing suggestion items and loading state.
@@ -165,6 +167,27 @@ impl SuggestionEngine {
         }
     }
 
+    /// Builds field-path completions when the token being typed is a result reference
+    /// (`$last`, `$rN`, `$results[N]`).
+    ///
+    /// Returns `None` when the input does not end in a reference so callers fall back
+    /// to [`SuggestionEngine::build`].
+    pub fn build_result_references(results: &ResultHistory, input: &str) -> Option<SuggestionResult> {
+        if results.is_empty() || input.ends_with(char::is_whitespace) {
+            return None;
+        }
+        let tokens = lex_shell_like(input);
+        if tokens.len() < 3 {
+            return None;
+        }
+        let items = suggest_result_references(results, tokens.last()?)?;
+        Some(SuggestionResult {
+            items,
+            provider_loading: false,
+            pending_fetches: Vec::new(),
+        })
+    }
+
     /// Builds suggestions based on the current input, command registry, and value providers.
     ///
     /// This is the main entry point for generating suggestions. It analyzes the input
@@ -862,6 +885,7 @@ mod tests {
                     default_value: None,
                     description: None,
                     provider: None,
+                    schema: None,
                 },
                 CommandFlag {
                     name: "app".into(),
@@ -875,6 +899,7 @@ mod tests {
                         command_id: "apps:list".into(),
                         binds: vec![],
                     }),
+                    schema: None,
                 },
             ],
         };
@@ -976,6 +1001,7 @@ mod tests {
                     command_id: "apps:list".into(),
                     binds: vec![],
                 }),
+                schema: None,
             }],
             execution: CommandExecution::Http(HttpCommandSpec {
                 method: "GET".into(),
@@ -1027,6 +1053,7 @@ mod tests {
                     command_id: "apps:list".into(),
                     binds: vec![],
                 }),
+                schema: None,
             }],
             execution: CommandExecution::Http(HttpCommandSpec {
                 method: "GET".into(),
@@ -5,13 +5,17 @@ use crate::{
     app::App,
     ui::{
         components::{common::render_vertical_scrollbar, component::Component, plugins::PluginDetailsLoadState},
+        keymap::{KeyAction, KeyScope, Keymap},
         theme::{Theme, theme_helpers as th},
     },
 };
 use chrono::{DateTime, Local};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
-use oatty_mcp::{EnvVar, McpLogEntry, PluginDetail, PluginStatus};
-use oatty_types::Effect;
+use oatty_mcp::{AuthStatus, EnvVar, McpLogEntry, PluginDetail, PluginStatus};
+use oatty_types::{
+    Effect,
+    plugin::{PluginPromptSummary, PluginResourceSummary, PluginResourceTemplateSummary},
+};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Position, Rect, Spacing},
@@ -55,16 +59,14 @@ impl Component for PluginsDetailsComponent {
     ///   - Pressing `Esc` closes the modal (via `Effect::CloseModal`).
     ///   - Pressing the `Up` arrow scrolls the logs up by one line if log data is loaded.
     ///   - Pressing the `Down` arrow scrolls the logs down by one line if log data is loaded.
-    ///   - Pressing `Ctrl + r` reloads the selected plugin's details if a plugin is selected, triggering a new load process.
-    ///   - Pressing `r` restarts the selected plugin if a plugin is selected.
-    ///   - Pressing `s` starts the selected plugin if a plugin is selected.
-    ///   - Pressing `t` stops the selected plugin if a plugin is selected.
+    ///   - The `plugin_details.*` keymap actions reload details, restart, start, stop, or authorize
+    ///     the selected plugin.
     ///   - Unhandled keys are ignored.
     ///
     /// # Implementation Details
     ///
     /// - When scrolling logs, assumes the rendering layer will clamp and handle any overflow.
-    /// - Checks whether the `Control` modifier is pressed to distinguish between shortcut actions (e.g., `Ctrl + r` vs `r`).
+    /// - Resolves plugin actions through the keymap before falling back to the fixed scroll keys.
     /// - Ensures the `PluginDetailsLoadState::Loaded` state before attempting to interact with log data.
     ///
     /// # Example
@@ -88,7 +90,24 @@ impl Component for PluginsDetailsComponent {
             return effects;
         };
 
-        let control_pressed = key.modifiers.contains(KeyModifiers::CONTROL);
+        if let Some(action) = app.ctx.keymap.action(KeyScope::PluginDetails, &key) {
+            let selected_plugin = details_state.selected_plugin().map(ToOwned::to_owned);
+            if let Some(name) = selected_plugin {
+                match action {
+                    KeyAction::PluginDetailsReload => {
+                        details_state.begin_load(name.clone());
+                        effects.push(Effect::PluginsLoadDetail(name));
+                    }
+                    KeyAction::PluginDetailsRestart => effects.push(Effect::PluginsRestart(name)),
+                    KeyAction::PluginDetailsStart => effects.push(Effect::PluginsStart(name)),
+                    KeyAction::PluginDetailsStop => effects.push(Effect::PluginsStop(name)),
+                    KeyAction::PluginDetailsAuthorize => effects.push(Effect::PluginsAuthorize(name)),
+                    _ => {}
+                }
+            }
+            return effects;
+        }
+
         let alternate_pressed = key.modifiers.contains(KeyModifiers::ALT);
         match key.code {
             KeyCode::Esc => effects.push(Effect::CloseModal),
@@ -134,27 +153,6 @@ impl Component for PluginsDetailsComponent {
                     details_state.scroll_logs_to_bottom();
                 }
             }
-            KeyCode::Char('r') if !control_pressed => {
-                if let Some(name) = details_state.selected_plugin().map(ToOwned::to_owned) {
-                    details_state.begin_load(name.clone());
-                    effects.push(Effect::PluginsLoadDetail(name));
-                }
-            }
-            KeyCode::Char('r') if control_pressed => {
-                if let Some(name) = details_state.selected_plugin() {
-                    effects.push(Effect::PluginsRestart(name.to_string()));
-                }
-            }
-            KeyCode::Char('s') if control_pressed => {
-                if let Some(name) = details_state.selected_plugin() {
-                    effects.push(Effect::PluginsStart(name.to_string()));
-                }
-            }
-            KeyCode::Char('t') if control_pressed => {
-                if let Some(name) = details_state.selected_plugin() {
-                    effects.push(Effect::PluginsStop(name.to_string()));
-                }
-            }
             _ => {}
         }
 
@@ -215,7 +213,10 @@ impl Component for PluginsDetailsComponent {
             .border_style(theme.border_style(true))
             .style(th::panel_style(theme))
             .merge_borders(MergeStrategy::Exact)
-            .title(Span::styled("Tools", theme.text_secondary_style().add_modifier(Modifier::BOLD)));
+            .title(Span::styled(
+                "Capabilities",
+                theme.text_secondary_style().add_modifier(Modifier::BOLD),
+            ));
         let tools_inner = tools_block.inner(cols[1]);
         frame.render_widget(tools_block, cols[1]);
 
@@ -229,7 +230,7 @@ impl Component for PluginsDetailsComponent {
             .split(left_inner);
 
         self.render_header(frame, left_layout[0], theme, app.plugins.details.as_ref());
-        self.render_content(frame, left_layout[1], theme, app.plugins.details.as_mut());
+        self.render_content(frame, left_layout[1], theme, &app.ctx.keymap, app.plugins.details.as_mut());
 
         // Render tools list spanning the full right column height when data is loaded
         if let Some(details_state) = app.plugins.details.as_mut() {
@@ -300,20 +301,29 @@ impl Component for PluginsDetailsComponent {
             &[
                 ("Esc", " Close  "),
                 ("↑/↓", " Scroll logs  "),
-                ("Alt+↑/↓", " Scroll tools  "),
+                ("Alt+↑/↓", " Scroll capabilities  "),
                 ("PgUp/PgDn", " Page logs  "),
-                ("Alt+PgUp/PgDn", " Page tools  "),
-                ("R", " Refresh  "),
+                ("Alt+PgUp/PgDn", " Page capabilities  "),
             ],
         );
+        let keymap = &app.ctx.keymap;
+        let reload = keymap.label(KeyAction::PluginDetailsReload);
+        spans.extend(th::build_hint_spans(theme, &[(&reload, " Refresh  ")]));
 
         if let Some(details) = app.plugins.details.as_ref()
-            && matches!(details.load_state(), PluginDetailsLoadState::Loaded(_))
+            && let PluginDetailsLoadState::Loaded(data) = details.load_state()
         {
+            let restart = keymap.label(KeyAction::PluginDetailsRestart);
+            let start = keymap.label(KeyAction::PluginDetailsStart);
+            let stop = keymap.label(KeyAction::PluginDetailsStop);
             spans.extend(th::build_hint_spans(
                 theme,
-                &[("Ctrl+R", " Restart  "), ("Ctrl+S", " Start  "), ("Ctrl+T", " Stop  ")],
+                &[(&restart, " Restart  "), (&start, " Start  "), (&stop, " Stop  ")],
             ));
+            if data.detail.auth_status != AuthStatus::Unknown {
+                let authorize = keymap.label(KeyAction::PluginDetailsAuthorize);
+                spans.extend(th::build_hint_spans(theme, &[(&authorize, " Authorize  ")]));
+            }
         }
 
         spans
@@ -354,7 +364,14 @@ impl PluginsDetailsComponent {
         frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), area);
     }
 
-    fn render_content(&mut self, frame: &mut Frame, area: Rect, theme: &dyn Theme, details_state: Option<&mut PluginDetailsModalState>) {
+    fn render_content(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn Theme,
+        keymap: &Keymap,
+        details_state: Option<&mut PluginDetailsModalState>,
+    ) {
         let Some(state) = details_state else {
             frame.render_widget(
                 Paragraph::new(Line::from(Span::styled(
@@ -402,7 +419,7 @@ impl PluginsDetailsComponent {
                     ])
                     .split(area);
 
-                self.render_overview(frame, left_chunks[0], theme, data);
+                self.render_overview(frame, left_chunks[0], theme, keymap, data);
                 self.render_hrule(frame, left_chunks[1], theme);
                 self.render_health(frame, left_chunks[2], theme, data);
                 self.render_hrule(frame, left_chunks[3], theme);
@@ -446,7 +463,7 @@ impl PluginsDetailsComponent {
         }
     }
 
-    fn render_overview(&self, frame: &mut Frame, area: Rect, theme: &dyn Theme, data: &PluginDetailsData) {
+    fn render_overview(&self, frame: &mut Frame, area: Rect, theme: &dyn Theme, keymap: &Keymap, data: &PluginDetailsData) {
         let detail = &data.detail;
         let mut lines = Vec::new();
 
@@ -477,20 +494,48 @@ impl PluginsDetailsComponent {
             },
         ));
 
+        if let Some(scheme) = &detail.auth_scheme {
+            lines.push(Line::from(vec![
+                Span::styled("Auth      ", theme.accent_emphasis_style()),
+                Span::styled(format!("{scheme} "), theme.text_muted_style()),
+                Span::styled(detail.auth_status.to_string(), auth_status_style(theme, detail.auth_status)),
+            ]));
+        }
+
         lines.push(Line::from(vec![
             Span::styled("Actions   ", theme.accent_emphasis_style()),
-            Span::styled("[R]", theme.accent_emphasis_style()),
+            Span::styled(
+                format!("[{}]", keymap.label(KeyAction::PluginDetailsRestart)),
+                theme.accent_emphasis_style(),
+            ),
             Span::styled(" Restart   ", theme.text_muted_style()),
-            Span::styled("[S]", theme.accent_emphasis_style()),
+            Span::styled(
+                format!("[{}]", keymap.label(KeyAction::PluginDetailsStart)),
+                theme.accent_emphasis_style(),
+            ),
             Span::styled(" Start   ", theme.text_muted_style()),
-            Span::styled("[T]", theme.accent_emphasis_style()),
+            Span::styled(
+                format!("[{}]", keymap.label(KeyAction::PluginDetailsStop)),
+                theme.accent_emphasis_style(),
+            ),
             Span::styled(" Stop", theme.text_muted_style()),
         ]));
-        lines.push(Line::from(vec![
+        let mut secondary_actions = vec![
             Span::styled("           ", theme.accent_emphasis_style()),
-            Span::styled("[Ctrl+R]", theme.accent_emphasis_style()),
+            Span::styled(
+                format!("[{}]", keymap.label(KeyAction::PluginDetailsReload)),
+                theme.accent_emphasis_style(),
+            ),
             Span::styled(" Refresh", theme.text_muted_style()),
-        ]));
+        ];
+        if detail.auth_status != AuthStatus::Unknown {
+            secondary_actions.push(Span::styled(
+                format!("   [{}]", keymap.label(KeyAction::PluginDetailsAuthorize)),
+                theme.accent_emphasis_style(),
+            ));
+            secondary_actions.push(Span::styled(" Authorize", theme.text_muted_style()));
+        }
+        lines.push(Line::from(secondary_actions));
 
         frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), area);
     }
@@ -606,7 +651,10 @@ impl PluginsDetailsComponent {
             &plugin_details.tools
         };
 
-        if tools.is_empty() {
+        let detail = &plugin_details.detail;
+        let has_content = !detail.resources.is_empty() || !detail.resource_templates.is_empty() || !detail.prompts.is_empty();
+
+        if tools.is_empty() && !has_content {
             frame.render_widget(
                 Paragraph::new(Line::from(Span::styled("No tools exposed", theme.text_muted_style()))),
                 area,
@@ -615,11 +663,36 @@ impl PluginsDetailsComponent {
         }
 
         let mut lines: Vec<Line> = Vec::new();
-        for (index, tool) in tools.iter().enumerate() {
-            lines.extend(format_tool_lines(theme, tool, area));
-            if index + 1 < tools.len() {
-                lines.push(Line::from(Span::raw("")));
-            }
+        if has_content {
+            lines.push(section_header_line(theme, "Tools", tools.len()));
+        }
+        push_section_lines(&mut lines, tools.iter().map(|tool| format_tool_lines(theme, tool, area)));
+        if !detail.resources.is_empty() {
+            lines.push(Line::from(Span::raw("")));
+            lines.push(section_header_line(theme, "Resources", detail.resources.len()));
+            push_section_lines(
+                &mut lines,
+                detail.resources.iter().map(|resource| format_resource_lines(theme, resource, area)),
+            );
+        }
+        if !detail.resource_templates.is_empty() {
+            lines.push(Line::from(Span::raw("")));
+            lines.push(section_header_line(theme, "Resource templates", detail.resource_templates.len()));
+            push_section_lines(
+                &mut lines,
+                detail
+                    .resource_templates
+                    .iter()
+                    .map(|template| format_resource_template_lines(theme, template, area)),
+            );
+        }
+        if !detail.prompts.is_empty() {
+            lines.push(Line::from(Span::raw("")));
+            lines.push(section_header_line(theme, "Prompts", detail.prompts.len()));
+            push_section_lines(
+                &mut lines,
+                detail.prompts.iter().map(|prompt| format_prompt_lines(theme, prompt, area)),
+            );
         }
 
         details_state.update_tools_viewport_height(area.height);
@@ -690,6 +763,15 @@ fn modal_title(details_state: Option<&PluginDetailsModalState>) -> String {
         .unwrap_or_else(|| "Plugin Details".to_string())
 }
 
+fn auth_status_style(theme: &dyn Theme, status: AuthStatus) -> Style {
+    match status {
+        AuthStatus::Authorized => theme.status_success(),
+        AuthStatus::Required => theme.status_warning(),
+        AuthStatus::Failed => theme.status_error(),
+        AuthStatus::Unknown => theme.text_muted_style(),
+    }
+}
+
 fn status_style(theme: &dyn Theme, status: PluginStatus) -> Style {
     match status {
         PluginStatus::Running => theme.status_success(),
@@ -722,6 +804,85 @@ fn format_log(theme: &dyn Theme, log: &McpLogEntry) -> ListItem<'static> {
     ]))
 }
 
+fn section_header_line(theme: &dyn Theme, label: &str, count: usize) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("{label} ({count})"),
+        theme.text_secondary_style().add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// Append entries to a capability section, separating them with blank lines.
+fn push_section_lines(lines: &mut Vec<Line<'static>>, entries: impl Iterator<Item = Vec<Line<'static>>>) {
+    for (index, entry) in entries.enumerate() {
+        if index > 0 {
+            lines.push(Line::from(Span::raw("")));
+        }
+        lines.extend(entry);
+    }
+}
+
+fn wrapped_muted_lines(theme: &dyn Theme, text: &str, area: Rect) -> Vec<Line<'static>> {
+    let wrap_width = usize::from(area.width.max(1));
+    wrap(text, wrap_width)
+        .into_iter()
+        .map(|line| Line::from(Span::styled(line.into_owned(), theme.text_muted_style())))
+        .collect()
+}
+
+fn format_resource_lines(theme: &dyn Theme, resource: &PluginResourceSummary, area: Rect) -> Vec<Line<'static>> {
+    let label = resource.title.clone().unwrap_or_else(|| resource.name.clone());
+    let mut lines = vec![Line::from(Span::styled(label, theme.accent_emphasis_style()))];
+    lines.extend(wrapped_muted_lines(theme, &resource.uri, area));
+    if let Some(description) = &resource.description {
+        lines.extend(wrapped_muted_lines(theme, description, area));
+    }
+    if let Some(updated_at) = resource.updated_at {
+        let updated_at: DateTime<Local> = DateTime::from(updated_at);
+        lines.push(Line::from(Span::styled(
+            format!("Updated: {}", updated_at.format("%Y-%m-%d %H:%M:%S")),
+            theme.status_info(),
+        )));
+    }
+    lines
+}
+
+fn format_resource_template_lines(theme: &dyn Theme, template: &PluginResourceTemplateSummary, area: Rect) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(Span::styled(template.name.clone(), theme.accent_emphasis_style()))];
+    lines.extend(wrapped_muted_lines(theme, &template.uri_template, area));
+    if let Some(description) = &template.description {
+        lines.extend(wrapped_muted_lines(theme, description, area));
+    }
+    lines
+}
+
+fn format_prompt_lines(theme: &dyn Theme, prompt: &PluginPromptSummary, area: Rect) -> Vec<Line<'static>> {
+    let mut title_spans = vec![Span::styled(prompt.name.clone(), theme.accent_emphasis_style())];
+    if let Some(title) = &prompt.title {
+        title_spans.push(Span::raw(" - "));
+        title_spans.push(Span::styled(title.clone(), theme.text_muted_style()));
+    }
+    let mut lines = vec![Line::from(title_spans)];
+    if let Some(description) = &prompt.description {
+        lines.extend(wrapped_muted_lines(theme, description, area));
+    }
+    if !prompt.arguments.is_empty() {
+        let arguments = prompt
+            .arguments
+            .iter()
+            .map(|argument| {
+                if argument.required {
+                    format!("<{}>", argument.name)
+                } else {
+                    format!("[{}]", argument.name)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        lines.push(Line::from(Span::styled(format!("Args: {arguments}"), theme.text_muted_style())));
+    }
+    lines
+}
+
 fn format_tool_lines(theme: &dyn Theme, tool: &PluginToolSummary, area: Rect) -> Vec<Line<'static>> {
     let mut title_spans = Vec::new();
     title_spans.push(Span::styled(tool.name.clone(), theme.accent_emphasis_style()));
@@ -7,6 +7,7 @@
 
 use super::state::{PluginEditViewState, PluginTransport};
 use crate::ui::components::common::key_value_editor::KeyValueEditorView;
+use crate::ui::keymap::KeyAction;
 use crate::ui::theme::theme_helpers::create_labeled_input_field;
 use crate::{
     app::App,
@@ -14,7 +15,7 @@ use crate::{
         components::{common::TextInputState, component::Component, find_target_index_by_mouse_position},
         theme::{
             Theme,
-            theme_helpers::{self, ButtonRenderOptions, ButtonType, create_radio_button, render_button},
+            theme_helpers::{self, ButtonRenderOptions, ButtonType, create_checkbox, create_radio_button, render_button},
         },
     },
 };
@@ -26,6 +27,7 @@ use ratatui::layout::Position;
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
     text::{Line, Span},
     widgets::{Borders, Paragraph},
 };
@@ -37,12 +39,14 @@ struct EditPluginFormLayout {
     command_area: Rect,
     args_area: Rect,
     base_url_area: Rect,
+    oauth_area: Rect,
 }
 
 struct ActionButtonLayout {
     btn_validate_area: Rect,
     btn_save_area: Rect,
     btn_cancel_area: Rect,
+    btn_authorize_area: Rect,
 }
 
 struct RadioButtonLayout {
@@ -59,14 +63,16 @@ struct PluginEditorLayout {
     command_area: Rect,
     args_area: Rect,
     base_url_area: Rect,
+    oauth_area: Rect,
     kv_editor_area: Rect,
     validate_button_area: Rect,
     save_button_area: Rect,
     cancel_button_area: Rect,
+    authorize_button_area: Rect,
 }
 
 impl PluginEditorLayout {
-    fn focus_areas(&self) -> [Rect; 9] {
+    fn focus_areas(&self) -> [Rect; 11] {
         [
             self.transport_local_area,
             self.transport_remote_area,
@@ -77,6 +83,8 @@ impl PluginEditorLayout {
             self.validate_button_area,
             self.save_button_area,
             self.cancel_button_area,
+            self.oauth_area,
+            self.authorize_button_area,
         ]
     }
 }
@@ -205,12 +213,20 @@ impl Component for PluginsEditComponent {
         };
         // Use focus flags directly to avoid building a focus ring repeatedly
         let is_transport_focused = edit_state.f_transport.get();
+        let is_oauth_focused = edit_state.f_oauth.get();
         if edit_state.kv_editor.is_focused() {
             self.kv_component
                 .handle_key_event(&mut edit_state.kv_editor, key_event, Rc::clone(&app.focus));
             return Vec::new();
         }
 
+        if app.ctx.keymap.matches(KeyAction::PluginEditorValidate, &key_event) {
+            return vec![Effect::PluginsValidateAdd];
+        }
+        if app.ctx.keymap.matches(KeyAction::PluginEditorSave, &key_event) {
+            return vec![Effect::PluginsSave];
+        }
+
         match key_event.code {
             KeyCode::Esc => {
                 app.plugins.plugin_edit_state = None;
@@ -234,11 +250,8 @@ impl Component for PluginsEditComponent {
                 };
                 edit_state.update_key_value_table_label();
             }
-            KeyCode::Char('v') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-                return vec![Effect::PluginsValidateAdd];
-            }
-            KeyCode::Char('s') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-                return vec![Effect::PluginsSave];
+            KeyCode::Char(' ') if is_oauth_focused => {
+                edit_state.oauth_enabled = !edit_state.oauth_enabled;
             }
             KeyCode::Enter => {
                 return handle_enter_key(app);
@@ -289,6 +302,8 @@ impl Component for PluginsEditComponent {
                 6 => &edit_state.f_btn_validate,
                 7 => &edit_state.f_btn_save,
                 8 => &edit_state.f_btn_cancel,
+                9 => &edit_state.f_oauth,
+                10 => &edit_state.f_btn_authorize,
                 _ => return Vec::new(),
             };
             app.focus.focus(focused_flag);
@@ -332,8 +347,8 @@ impl Component for PluginsEditComponent {
                 }
                 _ => {}
             }
-            // Buttons
-            if (6..=8).contains(&idx) {
+            // Buttons and the OAuth checkbox
+            if (6..=10).contains(&idx) {
                 return handle_enter_key(app);
             }
         }
@@ -380,10 +395,12 @@ impl Component for PluginsEditComponent {
             command_area: form_layout.command_area,
             args_area: form_layout.args_area,
             base_url_area: form_layout.base_url_area,
+            oauth_area: form_layout.oauth_area,
             kv_editor_area: layout[2],
             validate_button_area: button_layout.btn_validate_area,
             save_button_area: button_layout.btn_save_area,
             cancel_button_area: button_layout.btn_cancel_area,
+            authorize_button_area: button_layout.btn_authorize_area,
         };
     }
 
@@ -392,7 +409,7 @@ impl Component for PluginsEditComponent {
         let add_state = app.plugins.plugin_edit_state.as_ref().expect("add state should be something");
         let mut spans = vec![];
 
-        if add_state.f_transport.get() {
+        if add_state.f_transport.get() || add_state.f_oauth.get() {
             spans.extend(theme_helpers::build_hint_spans(theme, &[("Space bar", " Toggle ")]));
         }
 
@@ -402,10 +419,12 @@ impl Component for PluginsEditComponent {
             spans.extend(theme_helpers::build_hint_spans(theme, &[("Esc", " Cancel ")]));
             let (validate_enabled, save_enabled) = add_state.compute_button_enablement();
             if validate_enabled {
-                spans.extend(theme_helpers::build_hint_spans(theme, &[("Ctrl+V", " Validate ")]));
+                let validate = app.ctx.keymap.label(KeyAction::PluginEditorValidate);
+                spans.extend(theme_helpers::build_hint_spans(theme, &[(&validate, " Validate ")]));
             }
             if save_enabled {
-                spans.extend(theme_helpers::build_hint_spans(theme, &[("Ctrl+S", " Save ")]));
+                let save = app.ctx.keymap.label(KeyAction::PluginEditorSave);
+                spans.extend(theme_helpers::build_hint_spans(theme, &[(&save, " Save ")]));
             }
         }
 
@@ -447,6 +466,17 @@ fn handle_enter_key(app: &mut App) -> Vec<Effect> {
         app.plugins.plugin_edit_state = None;
         return vec![];
     }
+    if add_state.f_btn_authorize.get() {
+        return if add_state.can_authorize() {
+            vec![Effect::PluginsAuthorize(add_state.name.trim().to_string())]
+        } else {
+            Vec::new()
+        };
+    }
+    if add_state.f_oauth.get() {
+        add_state.oauth_enabled = !add_state.oauth_enabled;
+        return Vec::new();
+    }
     if add_state.f_transport.get() {
         add_state.transport = match add_state.transport {
             PluginTransport::Local => PluginTransport::Remote,
@@ -526,21 +556,35 @@ fn render_form_fields(
                 "https://mcp.example.com",
                 add_state.f_base_url.get(),
             );
+            render_oauth_checkbox(frame, sections[2], theme, add_state);
         }
     }
     render_validation_message(frame, sections[3], theme, &add_state.kv_editor.validate_focused_row());
-    let (command_area, args_area, base_url_area) = match add_state.transport {
-        PluginTransport::Local => (sections[1], sections[2], Rect::default()),
-        PluginTransport::Remote => (Rect::default(), Rect::default(), sections[1]),
+    let (command_area, args_area, base_url_area, oauth_area) = match add_state.transport {
+        PluginTransport::Local => (sections[1], sections[2], Rect::default(), Rect::default()),
+        PluginTransport::Remote => (Rect::default(), Rect::default(), sections[1], sections[2]),
     };
     EditPluginFormLayout {
         name_area: sections[0],
         command_area,
         args_area,
         base_url_area,
+        oauth_area,
     }
 }
 
+/// Render the OAuth toggle shown for remote plugins.
+fn render_oauth_checkbox(frame: &mut Frame, area: Rect, theme: &dyn Theme, add_state: &PluginEditViewState) {
+    let focused = add_state.f_oauth.get();
+    let mut spans = vec![
+        theme_helpers::build_focus_indicator_span(focused, theme),
+        theme_helpers::build_label_span("Auth", theme),
+    ];
+    spans.extend(create_checkbox(Some("OAuth (sign in with browser)"), add_state.oauth_enabled, focused, theme).spans);
+    let style = if focused { theme.selection_style() } else { Style::default() };
+    frame.render_widget(Paragraph::new(Line::from(spans)).style(style), area);
+}
+
 fn render_radio_buttons(frame: &mut Frame, area: Rect, theme: &dyn Theme, add_state: &PluginEditViewState) -> RadioButtonLayout {
     // Transport selection row
     let transport_layout = Layout::horizontal([
@@ -624,6 +668,8 @@ fn render_action_buttons(frame: &mut Frame, area: Rect, theme: &dyn Theme, add_s
         Constraint::Length(12), // Save button
         Constraint::Length(2),  // Spacer
         Constraint::Length(12), // Cancel button
+        Constraint::Length(2),  // Spacer
+        Constraint::Length(13), // Authorize button
     ])
     .split(area);
     render_button(
@@ -653,10 +699,29 @@ fn render_action_buttons(frame: &mut Frame, area: Rect, theme: &dyn Theme, add_s
         theme,
         ButtonRenderOptions::new(true, add_state.f_btn_cancel.get(), false, Borders::ALL, ButtonType::Secondary),
     );
+    let btn_authorize_area = if add_state.transport == PluginTransport::Remote && add_state.oauth_enabled {
+        render_button(
+            frame,
+            button_columns[6],
+            "Authorize",
+            theme,
+            ButtonRenderOptions::new(
+                add_state.can_authorize(),
+                add_state.f_btn_authorize.get(),
+                false,
+                Borders::ALL,
+                ButtonType::Secondary,
+            ),
+        );
+        button_columns[6]
+    } else {
+        Rect::default()
+    };
     ActionButtonLayout {
         btn_validate_area: button_columns[0],
         btn_save_area: button_columns[2],
         btn_cancel_area: button_columns[4],
+        btn_authorize_area,
     }
 }
 
@@ -22,6 +22,8 @@ pub struct PluginEditViewState {
     pub args_cursor: usize,
     pub base_url: String,
     pub base_url_cursor: usize,
+    /// Whether the remote plugin authorizes through the OAuth browser flow.
+    pub oauth_enabled: bool,
     /// Editor state for environment variables on local transports.
     pub kv_editor: KeyValueEditorState,
     // Focus flags for focusable controls
@@ -31,9 +33,11 @@ pub struct PluginEditViewState {
     pub f_command: FocusFlag,
     pub f_args: FocusFlag,
     pub f_base_url: FocusFlag,
+    pub f_oauth: FocusFlag,
     pub f_btn_validate: FocusFlag,
     pub f_btn_save: FocusFlag,
     pub f_btn_cancel: FocusFlag,
+    pub f_btn_authorize: FocusFlag,
 }
 
 impl PluginEditViewState {
@@ -46,9 +50,11 @@ impl PluginEditViewState {
             f_command: FocusFlag::new().with_name("plugins.add.command"),
             f_args: FocusFlag::new().with_name("plugins.add.args"),
             f_base_url: FocusFlag::new().with_name("plugins.add.base_url"),
+            f_oauth: FocusFlag::new().with_name("plugins.add.oauth"),
             f_btn_validate: FocusFlag::new().with_name("plugins.add.btn.validate"),
             f_btn_save: FocusFlag::new().with_name("plugins.add.btn.save"),
             f_btn_cancel: FocusFlag::new().with_name("plugins.add.btn.cancel"),
+            f_btn_authorize: FocusFlag::new().with_name("plugins.add.btn.authorize"),
             kv_editor: KeyValueEditorState::new(Cow::from("Env Vars"), Cow::from("Key"), Cow::from("Value")),
             ..Default::default()
         };
@@ -64,6 +70,10 @@ impl PluginEditViewState {
         instance.name = client.name.clone();
         instance.name_cursor = instance.name.len();
 
+        instance.oauth_enabled = client
+            .auth_scheme
+            .as_deref()
+            .is_some_and(|scheme| scheme.eq_ignore_ascii_case("oauth") || scheme.eq_ignore_ascii_case("oauth2"));
         instance.args = client.args.unwrap_or_default();
         instance.args_cursor = instance.args.len();
         let rows: Vec<EnvRow> = client
@@ -117,6 +127,16 @@ impl PluginEditViewState {
         }
     }
 
+    /// Whether the Authorize button is available.
+    ///
+    /// Authorization runs against the saved configuration, so it is offered only for remote
+    /// plugins that already exist under their current name with OAuth enabled.
+    pub fn can_authorize(&self) -> bool {
+        self.transport == PluginTransport::Remote
+            && self.oauth_enabled
+            && self.original_name.as_deref().is_some_and(|original| original == self.name.trim())
+    }
+
     /// Provides a transport-specific label for the key/value results.
     pub fn update_key_value_table_label(&mut self) {
         let label = match self.transport {
@@ -158,6 +178,7 @@ impl HasFocus for PluginEditViewState {
             }
             PluginTransport::Remote => {
                 builder.leaf_widget(&self.f_base_url);
+                builder.leaf_widget(&self.f_oauth);
             }
         }
         builder.widget(&self.kv_editor);
@@ -173,6 +194,9 @@ impl HasFocus for PluginEditViewState {
         }
         // Cancel always present
         builder.leaf_widget(&self.f_btn_cancel);
+        if self.can_authorize() {
+            builder.leaf_widget(&self.f_btn_authorize);
+        }
 
         builder.end(tag);
     }
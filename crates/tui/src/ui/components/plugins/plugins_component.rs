@@ -8,6 +8,7 @@
 
 use super::{PluginsEditComponent, PluginsTableComponent};
 use crate::ui::components::plugins::plugin_editor::state::PluginEditViewState;
+use crate::ui::keymap::KeyAction;
 use crate::{
     app::App,
     ui::{
@@ -18,7 +19,7 @@ use crate::{
         theme::theme_helpers::{ButtonType, build_hint_spans},
     },
 };
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
 use oatty_types::{Effect, MessageType, Modal, Msg};
 use rat_focus::FocusFlag;
 use ratatui::{
@@ -167,7 +168,11 @@ impl Component for PluginsComponent {
     /// * `app` - Mutable reference to the app state
     fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
         let theme = &*app.ctx.theme;
-        let mut spans = build_hint_spans(theme, &[("Ctrl+F", " Search  "), ("Ctrl+A", " Add  ")]);
+        let keymap = &app.ctx.keymap;
+        let search = keymap.label(KeyAction::PluginsSearch);
+        let add = keymap.label(KeyAction::PluginsAdd);
+        let validate = keymap.label(KeyAction::PluginEditorValidate);
+        let mut spans = build_hint_spans(theme, &[(&search, " Search  "), (&add, " Add  ")]);
 
         // The add component is visible
         if let Some(add_state) = app.plugins.plugin_edit_state.as_ref() {
@@ -176,7 +181,7 @@ impl Component for PluginsComponent {
                 spans.extend(self.edit_component.get_hint_spans(app));
                 return spans;
             }
-            spans.extend(build_hint_spans(theme, &[("Esc", " Back  "), ("Ctrl+V", " Validate  ")]));
+            spans.extend(build_hint_spans(theme, &[("Esc", " Back  "), (&validate, " Validate  ")]));
         } else {
             // the add component is not visible
             spans.extend(build_hint_spans(theme, &[("Esc", " Clear  ")]));
@@ -286,35 +291,30 @@ impl PluginsComponent {
     /// or `None` if the shortcut was not recognized or handled.
     fn handle_control_shortcuts(&mut self, app: &mut App, key_event: KeyEvent) -> Vec<Effect> {
         let mut effects: Vec<Effect> = Vec::with_capacity(1);
-        let control_pressed: bool = key_event.modifiers.contains(KeyModifiers::CONTROL);
-        match key_event.code {
-            KeyCode::Char('f') if control_pressed => {
-                self.handle_search_shortcut(app);
-            }
-            KeyCode::Esc => {
-                self.handle_clear_filter_shortcut(app);
-            }
-
-            KeyCode::Char('v') if control_pressed && app.plugins.plugin_edit_state.is_some() => {
-                effects.push(Effect::PluginsValidateAdd);
-            }
+        if key_event.code == KeyCode::Esc {
+            self.handle_clear_filter_shortcut(app);
+            return effects;
+        }
+        let keymap = &app.ctx.keymap;
+        if keymap.matches(KeyAction::PluginsSearch, &key_event) {
+            self.handle_search_shortcut(app);
+        } else if keymap.matches(KeyAction::PluginEditorValidate, &key_event) && app.plugins.plugin_edit_state.is_some() {
+            effects.push(Effect::PluginsValidateAdd);
+        } else if keymap.matches(KeyAction::PluginsAdd, &key_event) {
             // Also available when the results component is focused
-            KeyCode::Char('a') if control_pressed => {
-                let edit_view_state = PluginEditViewState::new();
-                app.focus.focus(&edit_view_state.f_transport);
-                app.plugins.plugin_edit_state = Some(edit_view_state);
-            }
-            _ => {}
+            let edit_view_state = PluginEditViewState::new();
+            app.focus.focus(&edit_view_state.f_transport);
+            app.plugins.plugin_edit_state = Some(edit_view_state);
         }
         effects
     }
 
-    /// Handles the search shortcut (Ctrl+F) which activates search in the appropriate context.
+    /// Handles the search shortcut (`plugins.search`) which activates search in the appropriate context.
     fn handle_search_shortcut(&mut self, app: &mut App) {
         app.focus.focus(&app.plugins.table.f_search);
     }
 
-    /// Handles the clear filter shortcut (Ctrl+K) which clears the search filter.
+    /// Handles the clear filter shortcut (Esc) which clears the search filter.
     fn handle_clear_filter_shortcut(&mut self, app: &mut App) {
         if app.plugins.table.f_search.get() {
             app.plugins.table.clear_filter();
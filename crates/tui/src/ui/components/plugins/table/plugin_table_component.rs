@@ -1,3 +1,4 @@
+use crate::ui::keymap::{KeyAction, KeyScope};
 use crate::ui::theme::Theme;
 use crate::ui::theme::theme_helpers::{ButtonRenderOptions, ButtonType, render_button};
 use crate::ui::{
@@ -75,15 +76,15 @@ impl PluginTableHitArea {
         }
     }
 
-    fn key_code(&self) -> Option<KeyCode> {
+    fn action(&self) -> Option<KeyAction> {
         match self {
             PluginTableHitArea::Search(_, _) => None,
             PluginTableHitArea::Table(_, _) => None,
-            PluginTableHitArea::AddButton(_, _) => Some(KeyCode::Char('a')),
-            PluginTableHitArea::StartButton(_, _) => Some(KeyCode::Char('s')),
-            PluginTableHitArea::StopButton(_, _) => Some(KeyCode::Char('t')),
-            PluginTableHitArea::EditButton(_, _) => Some(KeyCode::Char('e')),
-            PluginTableHitArea::DeleteButton(_, _) => Some(KeyCode::Char('d')),
+            PluginTableHitArea::AddButton(_, _) => Some(KeyAction::PluginsAdd),
+            PluginTableHitArea::StartButton(_, _) => Some(KeyAction::PluginsStart),
+            PluginTableHitArea::StopButton(_, _) => Some(KeyAction::PluginsStop),
+            PluginTableHitArea::EditButton(_, _) => Some(KeyAction::PluginsEdit),
+            PluginTableHitArea::DeleteButton(_, _) => Some(KeyAction::PluginsDelete),
         }
     }
 }
@@ -280,135 +281,74 @@ impl PluginsTableComponent {
             Constraint::Percentage(TAGS_COLUMN_PERCENTAGE),
         ]
     }
-    /// Handles key events for the grid view and performs actions based on the event.
+    /// Handles key events for the grid view.
     ///
-    /// This function operates primarily in the context of managing plugins in the application.
-    /// Key events are mapped to specific actions, which include moving selection, showing modals,
-    /// and invoking plugin operations such as start, stop, restart, and more. Control modifiers
-    /// often trigger specific behaviors when combined with certain keys.
-    ///
-    /// # Parameters
-    ///
-    /// - `app`: A mutable reference to the `App` instance, which holds the application state
-    ///   and manages plugin operations.
-    /// - `key`: The key event received, containing the key code and any active modifiers.
-    ///
-    /// # Returns
-    ///
-    /// A vector of `Effect` instances representing the actions to be performed as a result
-    /// of the key event. Effects may include showing a modal, loading plugin details, or
-    /// initiating plugin control operations.
-    ///
-    /// # Key Behaviors
-    ///
-    /// - **Navigation:**
-    ///   - `Up`: Moves the selection up in the grid if the grid flag is enabled.
-    ///   - `Down`: Moves the selection down in the grid if the grid flag is enabled.
-    ///
-    /// - **Plugin Details:**
-    ///   - `Enter`: Opens the plugin details modal and loads the selected plugin's details.
-    ///   - `Ctrl + O`: Same behavior as `Enter`.
-    ///
-    /// - **Plugin Operations:**
-    ///   - `Ctrl + S`: Starts the selected plugin.
-    ///   - `Ctrl + T`: Stops the selected plugin.
-    ///   - `Ctrl + R`: Restarts the selected plugin.
-    ///
-    /// - **Logs:**
-    ///   - `Ctrl + L`: Opens the logs for the selected plugin and marks logs as open.
-    ///
-    /// - **Plugin Management:**
-    ///   - `Ctrl + A`: Opens the add-plugin view if allowed.
-    ///   - `Ctrl + E`: Opens the edit view with the currently selected plugin's details if allowed.
-    ///   - `Ctrl + D`: Deletes the selected plugin configuration entry.
-    ///
-    /// - **Ignored Inputs:**
-    ///   - Any key events not specifically matched in the logic are ignored.
-    ///
-    /// # Notes
-    ///
-    /// - The `control_pressed` flag is derived from the key event's modifier state and is used
-    ///   to differentiate between basic and modified key behaviors.
-    /// - The function directly modifies the application state (`app`) and returns effects that
-    ///   can be processed further by the caller.
+    /// Navigation actions move the selection, `Enter` opens the details modal,
+    /// and the `plugins.*` actions from the keymap control the selected plugin
+    /// (see [`Self::run_action`]).
     fn handle_grid_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
-        let mut effects = vec![];
-        let control_pressed = key.modifiers.contains(KeyModifiers::CONTROL);
-        match key.code {
-            KeyCode::Up if app.plugins.table.f_grid.get() => {
-                Self::move_selection_up(app);
-            }
-            KeyCode::Down if app.plugins.table.f_grid.get() => {
-                Self::move_selection_down(app);
-            }
-            KeyCode::PageUp if app.plugins.table.f_grid.get() => {
-                Self::move_selection_by_page(app, false);
-            }
-            KeyCode::PageDown if app.plugins.table.f_grid.get() => {
-                Self::move_selection_by_page(app, true);
-            }
-            KeyCode::Home if app.plugins.table.f_grid.get() => {
-                Self::move_selection_to_first(app);
-            }
-            KeyCode::End if app.plugins.table.f_grid.get() => {
-                Self::move_selection_to_last(app);
-            }
-            KeyCode::Enter => {
-                effects.push(Effect::ShowModal(Modal::PluginDetails));
-                app.plugins.ensure_details_state();
-                if let Some(selected_item) = app.plugins.table.selected_item() {
-                    effects.push(Effect::PluginsLoadDetail(selected_item.name.clone()));
+        if key.code == KeyCode::Enter {
+            return self.run_action(app, KeyAction::PluginsDetails);
+        }
+        match app.ctx.keymap.action(KeyScope::Navigation, &key) {
+            Some(KeyAction::MoveUp) => Self::move_selection_up(app),
+            Some(KeyAction::MoveDown) => Self::move_selection_down(app),
+            Some(KeyAction::PageUp) => Self::move_selection_by_page(app, false),
+            Some(KeyAction::PageDown) => Self::move_selection_by_page(app, true),
+            Some(KeyAction::ScrollTop) => Self::move_selection_to_first(app),
+            Some(KeyAction::ScrollBottom) => Self::move_selection_to_last(app),
+            _ => {
+                if let Some(action) = app.ctx.keymap.action(KeyScope::Plugins, &key) {
+                    return self.run_action(app, action);
                 }
             }
-            KeyCode::Char('o') if control_pressed => {
+        }
+        Vec::new()
+    }
+
+    /// Runs a `plugins.*` action against the selected plugin.
+    ///
+    /// Details opens the modal and loads the plugin; start, stop, restart and
+    /// delete emit the matching effect; add and edit open the plugin editor.
+    fn run_action(&mut self, app: &mut App, action: KeyAction) -> Vec<Effect> {
+        let mut effects = vec![];
+        let selected_name = app.plugins.table.selected_item().map(|item| item.name.clone());
+        match action {
+            KeyAction::PluginsDetails => {
                 app.plugins.ensure_details_state();
                 effects.push(Effect::ShowModal(Modal::PluginDetails));
-                if let Some(selected_item) = app.plugins.table.selected_item() {
-                    effects.push(Effect::PluginsLoadDetail(selected_item.name.clone()));
-                }
-            }
-            KeyCode::Char('s') if control_pressed => {
-                if let Some(selected_item) = app.plugins.table.selected_item() {
-                    effects.push(Effect::PluginsStart(selected_item.name.clone()));
-                }
-            }
-            KeyCode::Char('t') if control_pressed => {
-                if let Some(selected_item) = app.plugins.table.selected_item() {
-                    effects.push(Effect::PluginsStop(selected_item.name.clone()));
-                }
-            }
-            KeyCode::Char('r') if control_pressed => {
-                if let Some(selected_item) = app.plugins.table.selected_item() {
-                    effects.push(Effect::PluginsRestart(selected_item.name.clone()));
+                if let Some(name) = selected_name {
+                    effects.push(Effect::PluginsLoadDetail(name));
                 }
             }
-            KeyCode::Char('a') if control_pressed => {
+            KeyAction::PluginsStart => effects.extend(selected_name.map(Effect::PluginsStart)),
+            KeyAction::PluginsStop => effects.extend(selected_name.map(Effect::PluginsStop)),
+            KeyAction::PluginsRestart => effects.extend(selected_name.map(Effect::PluginsRestart)),
+            KeyAction::PluginsDelete => effects.extend(selected_name.map(Effect::PluginsDelete)),
+            KeyAction::PluginsAdd => {
                 app.plugins.plugin_edit_state = Some(PluginEditViewState::new());
             }
-            KeyCode::Char('e') if control_pressed => {
+            KeyAction::PluginsEdit => {
                 if let Some(detail) = app.plugins.table.selected_item() {
                     app.plugins.plugin_edit_state = Some(PluginEditViewState::from_detail(detail.clone()));
                 }
             }
-            KeyCode::Char('d') if control_pressed => {
-                if let Some(selected_item) = app.plugins.table.selected_item() {
-                    effects.push(Effect::PluginsDelete(selected_item.name.clone()));
-                }
-            }
             _ => {}
-        };
+        }
         effects
     }
+
     /// Handles search navigation for the plugin results.
     ///
     /// When the search box has focus, printable characters update the filter and backspace removes
     /// the previous character. Outside of search mode, arrow keys move the selected results row, and
-    /// `Ctrl+A` opens the add-plugin workflow.
+    /// `plugins.add` (Ctrl+A by default) opens the add-plugin workflow.
     fn handle_search_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
+        if app.ctx.keymap.matches(KeyAction::PluginsAdd, &key) {
+            app.plugins.plugin_edit_state = Some(PluginEditViewState::new());
+            return Vec::new();
+        }
         match key.code {
-            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                app.plugins.plugin_edit_state = Some(PluginEditViewState::new());
-            }
             KeyCode::Backspace if app.plugins.table.f_search.get() => {
                 Self::remove_last_filter_character(app);
             }
@@ -444,7 +384,7 @@ impl PluginsTableComponent {
         let idx = mouse_position.y.saturating_sub(table_area.y) as usize + list_offset;
         if app.plugins.table.filtered_indices().get(idx).is_some() {
             if app.plugins.table.table_state.selected() == Some(idx) {
-                return self.handle_grid_key_events(app, KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+                return self.run_action(app, KeyAction::PluginsDetails);
             }
             app.plugins.table.set_selected_index(Some(idx));
         }
@@ -472,16 +412,14 @@ impl Component for PluginsTableComponent {
             effects.extend(self.handle_search_key_events(app, key));
         }
 
-        // If enter is pressed on any of the buttons,
-        // map them to the hot key events and process them in
-        // the handle_grid_key_events function.
+        // If enter is pressed on any of the buttons, run the action the button stands for.
         if key.code == KeyCode::Enter
-            && let Some(key_code) = self
+            && let Some(action) = self
                 .hit_areas
                 .iter()
-                .find_map(|h| if h.focus_flag(app).get() { h.key_code() } else { None })
+                .find_map(|h| if h.focus_flag(app).get() { h.action() } else { None })
         {
-            effects.extend(self.handle_grid_key_events(app, KeyEvent::new(key_code, KeyModifiers::CONTROL)));
+            effects.extend(self.run_action(app, action));
         }
 
         effects
@@ -599,23 +537,37 @@ impl Component for PluginsTableComponent {
         let theme = &*app.ctx.theme;
         let mut spans = Vec::with_capacity(8);
 
+        let keymap = &app.ctx.keymap;
+
         if app.plugins.table.f_grid.get() {
+            let scroll = keymap.label_pair(KeyAction::MoveUp, KeyAction::MoveDown);
+            let page = keymap.label_pair(KeyAction::PageUp, KeyAction::PageDown);
+            let jump = keymap.label_pair(KeyAction::ScrollTop, KeyAction::ScrollBottom);
             spans.extend(theme_helpers::build_hint_spans(
                 theme,
-                &[("↑/↓", " Move  "), ("PgUp/PgDn", " Page  "), ("Home/End", " Jump  ")],
+                &[(&scroll, " Move  "), (&page, " Page  "), (&jump, " Jump  ")],
             ));
         }
 
         if app.plugins.table.selected_item().is_some() {
+            let details = format!("Enter/{}", keymap.label(KeyAction::PluginsDetails));
+            let labels = [
+                KeyAction::PluginsEdit,
+                KeyAction::PluginsDelete,
+                KeyAction::PluginsStart,
+                KeyAction::PluginsStop,
+                KeyAction::PluginsRestart,
+            ]
+            .map(|action| keymap.label(action));
             spans.extend(theme_helpers::build_hint_spans(
                 theme,
                 &[
-                    ("Ctrl+E", " Edit  "),
-                    ("Ctrl+D", " Delete  "),
-                    ("Enter/Ctrl+O", " Details  "),
-                    ("Ctrl+S", " start  "),
-                    ("Ctrl+T", " Stop  "),
-                    ("Ctrl+R", " Restart  "),
+                    (&labels[0], " Edit  "),
+                    (&labels[1], " Delete  "),
+                    (&details, " Details  "),
+                    (&labels[2], " start  "),
+                    (&labels[3], " Stop  "),
+                    (&labels[4], " Restart  "),
                 ],
             ));
         }
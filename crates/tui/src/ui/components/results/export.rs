@@ -0,0 +1,206 @@
+//! File export for the Results modal.
+//!
+//! Exports the payload at the current drill level. JSON and NDJSON write the
+//! values as-is; CSV and Markdown write a table over the table's column set,
+//! with nested objects flattened into dotted sub-columns (`owner.email`).
+
+use std::path::Path;
+
+use serde_json::Value;
+
+/// Extensions offered by the export file picker.
+pub const EXPORT_EXTENSIONS: [&str; 5] = ["json", "ndjson", "jsonl", "csv", "md"];
+
+/// Output format, chosen from the target file's extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Ndjson,
+    Csv,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "json" => Some(Self::Json),
+            "ndjson" | "jsonl" => Some(Self::Ndjson),
+            "csv" => Some(Self::Csv),
+            "md" | "markdown" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Ndjson => "NDJSON",
+            Self::Csv => "CSV",
+            Self::Markdown => "Markdown",
+        }
+    }
+}
+
+/// Renders `value` in `format`. `columns` is the table's column set and
+/// applies to CSV and Markdown; when empty, the keys of the first row are used.
+pub fn export_value(value: &Value, columns: &[String], format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Json => serde_json::to_string_pretty(value).unwrap_or_default() + "\n",
+        ExportFormat::Ndjson => tabular_rows(value).iter().map(|row| format!("{row}\n")).collect(),
+        ExportFormat::Csv => {
+            let (headers, rows) = flattened_table(value, columns);
+            let mut output = String::new();
+            for line in std::iter::once(headers).chain(rows) {
+                let cells: Vec<String> = line.iter().map(|cell| escape_csv(cell)).collect();
+                output.push_str(&cells.join(","));
+                output.push('\n');
+            }
+            output
+        }
+        ExportFormat::Markdown => {
+            let (headers, rows) = flattened_table(value, columns);
+            let markdown_row = |cells: &[String]| {
+                let cells: Vec<String> = cells.iter().map(|cell| escape_markdown(cell)).collect();
+                format!("| {} |\n", cells.join(" | "))
+            };
+            let mut output = markdown_row(&headers);
+            output.push_str(&format!("|{}\n", " --- |".repeat(headers.len())));
+            for row in rows {
+                output.push_str(&markdown_row(&row));
+            }
+            output
+        }
+    }
+}
+
+/// Number of rows an export of `value` contains.
+pub fn export_row_count(value: &Value) -> usize {
+    tabular_rows(value).len()
+}
+
+fn tabular_rows(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().collect(),
+        other => vec![other],
+    }
+}
+
+/// Header labels and cell text for CSV and Markdown.
+fn flattened_table(value: &Value, columns: &[String]) -> (Vec<String>, Vec<Vec<String>>) {
+    let rows = tabular_rows(value);
+    if !rows.iter().any(|row| row.is_object()) {
+        let cells = rows.iter().map(|row| vec![cell_text(Some(row))]).collect();
+        return (vec!["value".to_string()], cells);
+    }
+
+    let column_keys: Vec<String> = if columns.is_empty() {
+        rows.iter()
+            .find_map(|row| row.as_object())
+            .map(|map| map.keys().cloned().collect())
+            .unwrap_or_default()
+    } else {
+        columns.to_vec()
+    };
+    let mut paths: Vec<Vec<String>> = Vec::new();
+    for key in &column_keys {
+        let mut column_paths = Vec::new();
+        for row in &rows {
+            collect_leaf_paths(row.get(key), vec![key.clone()], &mut column_paths);
+        }
+        if column_paths.is_empty() {
+            column_paths.push(vec![key.clone()]);
+        }
+        paths.extend(column_paths);
+    }
+
+    let headers = paths.iter().map(|path| path.join(".")).collect();
+    let cells = rows
+        .iter()
+        .map(|row| paths.iter().map(|path| cell_text(lookup(row, path))).collect())
+        .collect();
+    (headers, cells)
+}
+
+/// Appends the leaf paths below `value` in first-seen order; arrays count as leaves.
+fn collect_leaf_paths(value: Option<&Value>, path: Vec<String>, paths: &mut Vec<Vec<String>>) {
+    match value {
+        Some(Value::Object(map)) if !map.is_empty() => {
+            for (key, nested) in map {
+                let mut nested_path = path.clone();
+                nested_path.push(key.clone());
+                collect_leaf_paths(Some(nested), nested_path, paths);
+            }
+        }
+        Some(_) if !paths.contains(&path) => paths.push(path),
+        _ => {}
+    }
+}
+
+fn lookup<'a>(value: &'a Value, path: &[String]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |current, key| current.get(key))
+}
+
+fn cell_text(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(text)) => text.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+fn escape_csv(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+fn escape_markdown(cell: &str) -> String {
+    cell.replace('|', "\\|").replace("\r\n", "<br>").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rows() -> Value {
+        json!([
+            {"id": "a", "owner": {"email": "x@example.com", "team": "core"}, "note": "has, comma"},
+            {"id": "b", "owner": {"email": "y@example.com"}, "tags": ["t1"]}
+        ])
+    }
+
+    #[test]
+    fn format_follows_extension() {
+        assert_eq!(ExportFormat::from_path(Path::new("out.JSONL")), Some(ExportFormat::Ndjson));
+        assert_eq!(ExportFormat::from_path(Path::new("out.md")), Some(ExportFormat::Markdown));
+        assert_eq!(ExportFormat::from_path(Path::new("out.txt")), None);
+    }
+
+    #[test]
+    fn csv_flattens_nested_columns_in_column_order() {
+        let columns = vec!["id".to_string(), "owner".to_string(), "note".to_string()];
+        let csv = export_value(&rows(), &columns, ExportFormat::Csv);
+        assert_eq!(
+            csv,
+            "id,owner.email,owner.team,note\na,x@example.com,core,\"has, comma\"\nb,y@example.com,,\n"
+        );
+    }
+
+    #[test]
+    fn markdown_and_ndjson_render_rows() {
+        let columns = vec!["id".to_string(), "tags".to_string()];
+        let markdown = export_value(&rows(), &columns, ExportFormat::Markdown);
+        assert_eq!(markdown, "| id | tags |\n| --- | --- |\n| a |  |\n| b | [\"t1\"] |\n");
+
+        let ndjson = export_value(&rows(), &columns, ExportFormat::Ndjson);
+        assert_eq!(ndjson.lines().count(), 2);
+        assert!(ndjson.starts_with("{\"id\":\"a\""));
+
+        let scalars = export_value(&json!(["a|b", 2]), &[], ExportFormat::Markdown);
+        assert_eq!(scalars, "| value |\n| --- |\n| a\\|b |\n| 2 |\n");
+    }
+}
@@ -1,5 +1,9 @@
+pub mod export;
 pub mod results_table_component;
 pub mod state;
+pub mod watch;
+pub mod workspace;
 
 pub use results_table_component::TableComponent;
-pub use state::{ResultsTableState, build_key_value_entries};
+pub use state::{ResultsTableState, build_key_value_entries, diff_alignment_keys};
+pub use workspace::ResultsWorkspace;
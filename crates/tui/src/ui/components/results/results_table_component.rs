@@ -4,6 +4,7 @@
 //! displays JSON results from command execution in a tabular format with
 //! scrolling and navigation capabilities.
 use crate::app::App;
+use crate::ui::keymap::{KeyAction, KeyScope, Keymap};
 use crate::ui::{
     components::{
         common::{ResultsTableView, handle_table_navigation_key},
@@ -12,13 +13,17 @@ use crate::ui::{
     theme::theme_helpers as th,
 };
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
-use oatty_types::{Effect, Msg};
+use oatty_types::{Effect, Modal, Msg, Route};
 
 use crate::ui::components::common::handle_table_mouse_actions;
-use rat_focus::Focus;
+use crate::ui::components::palette::palette_component::destructive_command_confirmation;
+use crate::ui::components::results::watch::DEFAULT_WATCH_INTERVAL;
+use crate::ui::utils::is_destructive_command_line;
+use rat_focus::{Focus, FocusFlag};
 use ratatui::layout::Position;
 use ratatui::widgets::{Borders, Padding};
 use ratatui::{Frame, layout::Rect, text::Span};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use unicode_width::UnicodeWidthStr;
 
@@ -42,20 +47,37 @@ use unicode_width::UnicodeWidthStr;
 /// - **Page Up/Down**: Scroll faster through the results
 /// - **Home/End**: Jump to the beginning /end of the results
 /// - **Escape**: Close the results modal
+/// - **`[` / `]`**: Switch between result tabs; `b` pins, `Ctrl+W` closes,
+///   `r` re-runs and `s` cancels the active tab's command
 #[derive(Debug, Default)]
 pub struct TableComponent {
     view: ResultsTableView,
     table_area: Rect,
     breadcrumb_area: Rect,
+    tab_area: Rect,
     last_click: Option<(usize, usize, Instant)>,
+    /// Confirm button of the prompt shown before re-running a destructive command.
+    rerun_confirm_button: FocusFlag,
 }
 
 impl Component for TableComponent {
     fn handle_message(&mut self, app: &mut App, msg: Msg) -> Vec<Effect> {
-        if let Msg::ExecCompleted(exec_outcome) = msg {
-            app.table.process_general_execution_result(*exec_outcome, &*app.ctx.theme);
-        }
-        Vec::new()
+        // Results arrive as tabs through `Modal::Results`, and re-runs are routed
+        // to their tab by the runtime; only watch scheduling and confirmed
+        // re-runs happen here.
+        match msg {
+            Msg::Tick => {}
+            Msg::ConfirmationModalButtonClicked(id) if id == self.rerun_confirm_button.widget_id() => return rerun_active_tab(app),
+            _ => return Vec::new(),
+        }
+        app.results
+            .poll_watches(Instant::now())
+            .into_iter()
+            .map(|(hydrated_command, request_hash)| Effect::Run {
+                hydrated_command,
+                request_hash,
+            })
+            .collect()
     }
 
     /// Handle key events for the result results modal.
@@ -65,57 +87,86 @@ impl Component for TableComponent {
     /// otherwise `Ok(false)`.
     fn handle_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
         let mut effects: Vec<Effect> = vec![];
-        if key.code == KeyCode::Esc && app.table.drill_up(&*app.ctx.theme) {
+        if key.code == KeyCode::Esc && app.results.table_mut().drill_up(&*app.ctx.theme) {
             return effects;
         }
         if key.code == KeyCode::Esc {
             return vec![Effect::CloseModal];
         }
-        if key.code == KeyCode::Enter && app.table.drill_into_selection(&*app.ctx.theme) {
+        if key.code == KeyCode::Enter && app.results.table_mut().drill_into_selection(&*app.ctx.theme) {
             return effects;
         }
-        if app.table.has_rows() && handle_table_navigation_key(key.code, &mut app.table, app.focus.as_ref()) {
+        let keymap = &app.ctx.keymap;
+        if app.results.table().has_rows() && handle_table_navigation_key(keymap, &key, app.results.table_mut(), app.focus.as_ref()) {
             return effects;
         }
-        if !app.table.has_rows() && matches!(key.code, KeyCode::Char('v') | KeyCode::Char('V')) {
-            app.table.toggle_split_preview_pinned();
+        let action = keymap.action(KeyScope::Results, &key);
+        if action == Some(KeyAction::ResultsRerun) {
+            return self.rerun_or_confirm(app);
+        }
+        if let Some(tab_effects) = handle_tab_action(app, action) {
+            return tab_effects;
+        }
+        let keymap = &app.ctx.keymap;
+        if !app.results.table().has_rows() && action == Some(KeyAction::ResultsPinPreview) {
+            app.results.table_mut().toggle_split_preview_pinned();
             return effects;
         }
-        if !app.table.has_rows() && handle_split_preview_navigation_key(key, &mut app.table) {
+        if !app.results.table().has_rows() && handle_split_preview_navigation_key(key, app.results.table_mut()) {
             return effects;
         }
-        if !app.table.has_rows() && handle_fallback_navigation_key(key.code, &mut app.table, app.focus.as_ref()) {
+        if !app.results.table().has_rows() && handle_fallback_navigation_key(keymap, &key, app.results.table_mut(), app.focus.as_ref()) {
             return effects;
         }
-        if let KeyCode::Char('c') = key.code {
-            if let Some(idx) = app.table.table_state.selected()
-                && let Some(value) = app.table.selected_data(idx)
-            {
-                let s = serde_json::to_string(value).ok().unwrap_or_default();
-                effects.push(Effect::CopyToClipboardRequested(s));
-            } else if let Some(idx) = app.table.list_state.selected()
-                && let Some(entry) = app.table.selected_kv_entry(idx)
-            {
-                let serialized = serde_json::to_string(&entry.raw_value).unwrap_or_else(|_| entry.raw_value.to_string());
-                let payload = format!("{}: {}", entry.key, serialized);
-                effects.push(Effect::CopyToClipboardRequested(payload));
+        match action {
+            Some(KeyAction::ResultsWatch) => {
+                let registry = Arc::clone(&app.ctx.command_registry);
+                let toggled = match registry.lock() {
+                    Ok(registry) => app
+                        .results
+                        .table_mut()
+                        .toggle_watch(&registry, DEFAULT_WATCH_INTERVAL, Instant::now()),
+                    Err(_) => Err("Command registry is unavailable".to_string()),
+                };
+                match toggled {
+                    Ok(true) => app.append_log_message(format!("Watching every {}s", DEFAULT_WATCH_INTERVAL.as_secs())),
+                    Ok(false) => app.append_log_message("Watch stopped"),
+                    Err(error) => app.append_log_message(error),
+                }
             }
+            Some(KeyAction::ResultsSendToPalette) => return send_selected_cell_to_palette(app),
+            Some(KeyAction::ResultsExport) => return vec![Effect::ShowModal(Modal::ExportResults)],
+            Some(KeyAction::ResultsCopy) => {
+                if let Some(idx) = app.results.table().table_state.selected()
+                    && let Some(value) = app.results.table().selected_data(idx)
+                {
+                    let s = serde_json::to_string(value).ok().unwrap_or_default();
+                    effects.push(Effect::CopyToClipboardRequested(s));
+                } else if let Some(idx) = app.results.table().list_state.selected()
+                    && let Some(entry) = app.results.table().selected_kv_entry(idx)
+                {
+                    let serialized = serde_json::to_string(&entry.raw_value).unwrap_or_else(|_| entry.raw_value.to_string());
+                    let payload = format!("{}: {}", entry.key, serialized);
+                    effects.push(Effect::CopyToClipboardRequested(payload));
+                }
+            }
+            _ => {}
         }
         effects
     }
 
     fn handle_mouse_events(&mut self, app: &mut App, mouse: MouseEvent) -> Vec<Effect> {
-        if self.try_handle_breadcrumb_click(app, mouse) {
+        if self.try_handle_tab_click(app, mouse) || self.try_handle_breadcrumb_click(app, mouse) {
             return Vec::new();
         }
-        if app.table.has_rows() {
-            handle_table_mouse_actions(&mut app.table, mouse, self.table_area);
+        if app.results.table().has_rows() {
+            handle_table_mouse_actions(app.results.table_mut(), mouse, self.table_area);
             self.select_table_cell_from_mouse(app, mouse);
             self.try_handle_double_click_drill(app, mouse);
         } else {
             let list_area = self.view.fallback_list_area().unwrap_or(self.table_area);
             let preview_area = self.view.split_preview_area();
-            handle_fallback_mouse_actions(&mut app.table, mouse, list_area, preview_area);
+            handle_fallback_mouse_actions(app.results.table_mut(), mouse, list_area, preview_area);
             let position = Position {
                 x: mouse.column,
                 y: mouse.row,
@@ -143,73 +194,284 @@ impl Component for TableComponent {
     /// * `app` - The application state containing result data
     fn render(&mut self, frame: &mut Frame, rect: Rect, app: &mut App) {
         // Large modal to maximize space for tables
-        let title = if app.table.is_in_drill_mode() {
-            "Results  [Esc] Up  [Enter] Drill  ↑/↓ Scroll"
-        } else {
-            "Results  [Esc] Close  [Enter] Drill  ↑/↓ Scroll"
+        let mut title = match app.results.active_tab() {
+            Some(tab) => format!("Results · {}", tab.label()),
+            None => "Results".to_string(),
         };
-        let block = th::block(&*app.ctx.theme, Some(title), app.table.container_focus.get());
+        if app.results.table().is_in_drill_mode() {
+            title.push_str("  [Esc] Up  [Enter] Drill  ↑/↓ Scroll");
+        } else {
+            title.push_str("  [Esc] Close  [Enter] Drill  ↑/↓ Scroll");
+        }
+        if let Some(watch) = app.results.table().watch() {
+            title.push_str(&format!(
+                "  ● Watching every {}s · run {} · {} change(s)",
+                watch.interval().as_secs(),
+                watch.runs(),
+                app.results.table().watch_changes().count()
+            ));
+        }
+        if let Some(tab) = app.results.active_tab() {
+            if tab.is_running() {
+                title.push_str("  ⟳ Re-running");
+            } else if let Some(error) = tab.last_error() {
+                title.push_str(&format!("  ✗ {error}"));
+            }
+        }
+        let block = th::block(&*app.ctx.theme, Some(title.as_str()), app.results.table().container_focus.get());
 
         frame.render_widget(&block, rect);
         let inner = block.inner(rect);
-        let layout_chunks =
-            ratatui::layout::Layout::vertical([ratatui::layout::Constraint::Length(1), ratatui::layout::Constraint::Min(1)]).split(inner);
-        let breadcrumb_area = layout_chunks[0];
-        let content_area = layout_chunks[1];
+        let layout_chunks = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Length(1),
+            ratatui::layout::Constraint::Length(1),
+            ratatui::layout::Constraint::Min(1),
+        ])
+        .split(inner);
+        let tab_area = layout_chunks[0];
+        let breadcrumb_area = layout_chunks[1];
+        let content_area = layout_chunks[2];
+        self.render_tabs(frame, tab_area, app);
         self.render_breadcrumbs(frame, breadcrumb_area, app);
         // Split for content + pagination and footer
-        let is_grid_focused = app.table.grid_f.get();
+        let is_grid_focused = app.results.table().grid_f.get();
         let table_block = th::block::<String>(&*app.ctx.theme, None, is_grid_focused)
             .borders(Borders::NONE)
             .padding(Padding::uniform(1));
         let table_inner = table_block.inner(content_area);
         frame.render_widget(table_block, content_area);
         self.view
-            .render_results(frame, table_inner, &mut app.table, is_grid_focused, &*app.ctx.theme);
+            .render_results(frame, table_inner, app.results.table_mut(), is_grid_focused, &*app.ctx.theme);
 
         self.table_area = table_inner;
         self.breadcrumb_area = breadcrumb_area;
+        self.tab_area = tab_area;
     }
 
     fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
-        let has_rows = app.table.has_rows();
-        let has_kv = !app.table.kv_entries().is_empty();
+        let has_rows = app.results.table().has_rows();
+        let has_kv = !app.results.table().kv_entries().is_empty();
         if !has_rows && !has_kv {
             return Vec::new();
         }
 
         let theme = &*app.ctx.theme;
+        let keymap = &app.ctx.keymap;
+        let send = keymap.label(KeyAction::ResultsSendToPalette);
+        let watch = keymap.label(KeyAction::ResultsWatch);
+        let export = keymap.label(KeyAction::ResultsExport);
+        let scroll = keymap.label_pair(KeyAction::MoveUp, KeyAction::MoveDown);
+        let tabs = keymap.label_pair(KeyAction::ResultsPreviousTab, KeyAction::ResultsNextTab);
+        let pin = keymap.label(KeyAction::ResultsPinTab);
+        let close = keymap.label(KeyAction::ResultsCloseTab);
+        let rerun = keymap.label(KeyAction::ResultsRerun);
+        let cancel = keymap.label(KeyAction::ResultsCancel);
+        let tab_hints = [
+            (tabs.as_str(), " tabs "),
+            (pin.as_str(), " pin "),
+            (close.as_str(), " close tab "),
+            (rerun.as_str(), " re-run "),
+            (cancel.as_str(), " cancel  "),
+        ];
         if has_rows {
-            return th::build_hint_spans(
-                theme,
-                &[
-                    ("Esc", if app.table.is_in_drill_mode() { " up " } else { " close " }),
-                    ("Enter", " drill "),
-                    ("C", " copy row "),
-                    ("↑/↓", " scroll  "),
-                    ("PgUp/PgDn", " faster  "),
-                    ("Home/End", " jump"),
-                ],
-            );
-        }
-
-        th::build_hint_spans(
-            theme,
-            &[
-                ("Esc", if app.table.is_in_drill_mode() { " up " } else { " close " }),
+            let copy = keymap.label(KeyAction::ResultsCopy);
+            let page = keymap.label_pair(KeyAction::PageUp, KeyAction::PageDown);
+            let jump = keymap.label_pair(KeyAction::ScrollTop, KeyAction::ScrollBottom);
+            let mut hints = vec![
+                (
+                    "Esc",
+                    if app.results.table().is_in_drill_mode() {
+                        " up "
+                    } else {
+                        " close "
+                    },
+                ),
                 ("Enter", " drill "),
-                ("V", " preview  "),
-                ("↑/↓", " list  "),
-                ("Ctrl+↑/↓", " preview  "),
-                ("Ctrl+PgUp/PgDn", " preview page"),
-            ],
-        )
+                (copy.as_str(), " copy row "),
+                (send.as_str(), " send to palette "),
+                (watch.as_str(), " watch "),
+                (export.as_str(), " export "),
+            ];
+            hints.extend(tab_hints);
+            hints.extend([
+                (scroll.as_str(), " scroll  "),
+                (page.as_str(), " faster  "),
+                (jump.as_str(), " jump"),
+            ]);
+            return th::build_hint_spans(theme, &hints);
+        }
+
+        let preview = keymap.label(KeyAction::ResultsPinPreview);
+        let mut hints = vec![
+            (
+                "Esc",
+                if app.results.table().is_in_drill_mode() {
+                    " up "
+                } else {
+                    " close "
+                },
+            ),
+            ("Enter", " drill "),
+            (preview.as_str(), " preview  "),
+            (send.as_str(), " send to palette "),
+            (watch.as_str(), " watch "),
+            (export.as_str(), " export "),
+        ];
+        hints.extend(tab_hints);
+        hints.extend([
+            (scroll.as_str(), " list  "),
+            ("Ctrl+↑/↓", " preview  "),
+            ("Ctrl+PgUp/PgDn", " preview page"),
+        ]);
+        th::build_hint_spans(theme, &hints)
+    }
+}
+
+/// Sends the selected cell (or key-value entry) to the palette as a flag value.
+///
+/// The column (or entry) key becomes the flag name. Values containing
+/// whitespace are rejected because the palette lexer keeps quotes verbatim.
+fn send_selected_cell_to_palette(app: &mut App) -> Vec<Effect> {
+    let selection = if let Some(row) = app.results.table().table_state.selected() {
+        app.results.table().selected_column_key().and_then(|key| {
+            app.results
+                .table()
+                .selected_data(row)
+                .and_then(|data| data.get(&key))
+                .map(|value| (key, value.clone()))
+        })
+    } else if let Some(idx) = app.results.table().list_state.selected() {
+        app.results
+            .table()
+            .selected_kv_entry(idx)
+            .map(|entry| (entry.key.clone(), entry.raw_value.clone()))
+    } else {
+        None
+    };
+    let Some((key, value)) = selection else {
+        return Vec::new();
+    };
+    let text = match value {
+        serde_json::Value::String(text) => text,
+        other => other.to_string(),
+    };
+    if text.is_empty() || text.chars().any(char::is_whitespace) {
+        app.append_log_message(format!("Cannot send '{key}' to the palette: value is empty or contains whitespace"));
+        return Vec::new();
     }
+    app.palette.apply_insert_flag_value(&key, &text);
+    let mut effects = vec![Effect::CloseModal, Effect::SwitchTo(Route::Palette)];
+    effects.extend(app.rebuild_palette_suggestions());
+    effects
+}
+
+/// Starts a re-run of the active tab's command.
+fn rerun_active_tab(app: &mut App) -> Vec<Effect> {
+    match app.results.rerun_active() {
+        Ok((hydrated_command, request_hash)) => vec![Effect::Run {
+            hydrated_command,
+            request_hash,
+        }],
+        Err(error) => {
+            app.append_log_message(error);
+            Vec::new()
+        }
+    }
+}
+
+/// Applies the tab actions of the results workspace; returns `None` for other actions.
+fn handle_tab_action(app: &mut App, action: Option<KeyAction>) -> Option<Vec<Effect>> {
+    let mut effects = Vec::new();
+    match action? {
+        KeyAction::ResultsNextTab => app.results.select_next(),
+        KeyAction::ResultsPreviousTab => app.results.select_previous(),
+        KeyAction::ResultsPinTab => match app.results.toggle_pin() {
+            Some(true) => app.append_log_message("Pinned results tab"),
+            Some(false) => app.append_log_message("Unpinned results tab"),
+            None => {}
+        },
+        KeyAction::ResultsCloseTab => {
+            let in_flight = app.results.close_active();
+            if !in_flight.is_empty() {
+                effects.push(Effect::CancelRequests(in_flight));
+            }
+            if app.results.tabs().is_empty() {
+                effects.push(Effect::CloseModal);
+            }
+        }
+        KeyAction::ResultsCancel => {
+            let in_flight = app.results.cancel_active();
+            if in_flight.is_empty() {
+                app.append_log_message("Nothing is running in this results tab");
+            } else {
+                effects.push(Effect::CancelRequests(in_flight));
+            }
+        }
+        _ => return None,
+    }
+    Some(effects)
+}
+
+/// Tab bar titles: a pin marker, the command and time, and a running marker.
+fn tab_titles(app: &App) -> Vec<String> {
+    app.results
+        .tabs()
+        .iter()
+        .map(|tab| {
+            let pin = if tab.is_pinned() { "★ " } else { "" };
+            let running = if tab.is_running() || tab.table.is_watching() { " ⟳" } else { "" };
+            format!("{pin}{}{running}", tab.title())
+        })
+        .collect()
 }
 
 impl TableComponent {
+    /// Re-runs the active tab's command, asking for confirmation first when it is destructive.
+    fn rerun_or_confirm(&self, app: &mut App) -> Vec<Effect> {
+        let destructive_command = app.results.table().watch_source().filter(|command| {
+            app.ctx
+                .command_registry
+                .lock()
+                .is_ok_and(|registry| is_destructive_command_line(&registry, command))
+        });
+        let Some(command) = destructive_command else {
+            return rerun_active_tab(app);
+        };
+        let opts = destructive_command_confirmation(command, self.rerun_confirm_button.clone());
+        app.confirmation_modal_state.update_opts(opts);
+        vec![Effect::ShowModal(Modal::Confirmation)]
+    }
+
+    fn render_tabs(&self, frame: &mut Frame, area: Rect, app: &App) {
+        let theme = &*app.ctx.theme;
+        let titles = tab_titles(app).into_iter().map(Span::raw).collect();
+        frame.render_widget(th::tabs(theme, titles, app.results.active_index()), area);
+    }
+
+    /// Selects the tab under a left click; the tab bar pads each title by one
+    /// column on both sides and separates titles with a one-column divider.
+    fn try_handle_tab_click(&mut self, app: &mut App, mouse: MouseEvent) -> bool {
+        let position = Position {
+            x: mouse.column,
+            y: mouse.row,
+        };
+        if mouse.kind != MouseEventKind::Down(crossterm::event::MouseButton::Left) || !self.tab_area.contains(position) {
+            return false;
+        }
+        let mut cursor_x = self.tab_area.x;
+        for (index, title) in tab_titles(app).iter().enumerate() {
+            let width = title.width() as u16 + 2;
+            if position.x >= cursor_x && position.x < cursor_x.saturating_add(width) {
+                app.results.select(index);
+                return true;
+            }
+            cursor_x = cursor_x.saturating_add(width + 1);
+        }
+        true
+    }
+
     fn render_breadcrumbs(&self, frame: &mut Frame, area: Rect, app: &App) {
-        let breadcrumbs = app.table.breadcrumbs();
+        let breadcrumbs = app.results.table().breadcrumbs();
         let mut spans = Vec::new();
         for (index, breadcrumb) in breadcrumbs.iter().enumerate() {
             let style = if index + 1 == breadcrumbs.len() {
@@ -237,12 +499,12 @@ impl TableComponent {
             return false;
         }
 
-        let breadcrumbs = app.table.breadcrumbs();
+        let breadcrumbs = app.results.table().breadcrumbs();
         let mut cursor_x = self.breadcrumb_area.x;
         for (index, label) in breadcrumbs.iter().enumerate() {
             let width = label.width() as u16;
             if position.x >= cursor_x && position.x < cursor_x.saturating_add(width) {
-                return app.table.drill_to_breadcrumb(index, &*app.ctx.theme);
+                return app.results.table_mut().drill_to_breadcrumb(index, &*app.ctx.theme);
             }
             cursor_x = cursor_x.saturating_add(width);
             if index + 1 < breadcrumbs.len() {
@@ -257,18 +519,18 @@ impl TableComponent {
             return;
         }
         let now = Instant::now();
-        let (row_index, column_index) = if app.table.has_rows() {
-            let Some(row_index) = app.table.table_state.selected() else {
+        let (row_index, column_index) = if app.results.table().has_rows() {
+            let Some(row_index) = app.results.table().table_state.selected() else {
                 self.last_click = None;
                 return;
             };
-            let Some(column_index) = app.table.table_state.selected_column() else {
+            let Some(column_index) = app.results.table().table_state.selected_column() else {
                 self.last_click = None;
                 return;
             };
             (row_index, column_index)
         } else {
-            let Some(row_index) = app.table.list_state.selected() else {
+            let Some(row_index) = app.results.table().list_state.selected() else {
                 self.last_click = None;
                 return;
             };
@@ -279,7 +541,7 @@ impl TableComponent {
             && last_column_index == column_index
             && now.duration_since(last_instant) <= Duration::from_millis(350)
         {
-            let _ = app.table.drill_into_selection(&*app.ctx.theme);
+            let _ = app.results.table_mut().drill_into_selection(&*app.ctx.theme);
             self.last_click = None;
             return;
         }
@@ -297,13 +559,17 @@ impl TableComponent {
         if !self.table_area.contains(position) {
             return;
         }
-        let row_index = find_table_row_index(self.table_area, position, app.table.table_state.offset());
+        let row_index = find_table_row_index(self.table_area, position, app.results.table().table_state.offset());
         let Some(row_index) = row_index else {
             return;
         };
         let relative_x = position.x.saturating_sub(self.table_area.x);
-        let column_index = app.table.hit_test_column(relative_x, self.table_area.width).unwrap_or(0usize);
-        app.table.select_cell(row_index, column_index, self.table_area.width);
+        let column_index = app
+            .results
+            .table()
+            .hit_test_column(relative_x, self.table_area.width)
+            .unwrap_or(0usize);
+        app.results.table_mut().select_cell(row_index, column_index, self.table_area.width);
     }
 }
 
@@ -315,36 +581,30 @@ fn find_table_row_index(table_area: Rect, position: Position, table_offset: usiz
 }
 
 fn handle_fallback_navigation_key(
-    key_code: KeyCode,
+    keymap: &Keymap,
+    key: &KeyEvent,
     state: &mut crate::ui::components::results::state::ResultsTableState,
     focus: &Focus,
 ) -> bool {
-    let list_state = &mut state.list_state;
-    match key_code {
+    match key.code {
         KeyCode::BackTab => {
             focus.prev();
+            return true;
         }
         KeyCode::Tab => {
             focus.next();
+            return true;
         }
-        KeyCode::Up => {
-            list_state.scroll_up_by(1);
-        }
-        KeyCode::Down => {
-            list_state.scroll_down_by(1);
-        }
-        KeyCode::PageUp => {
-            list_state.scroll_up_by(10);
-        }
-        KeyCode::PageDown => {
-            list_state.scroll_down_by(10);
-        }
-        KeyCode::Home => {
-            list_state.scroll_up_by(u16::MAX);
-        }
-        KeyCode::End => {
-            list_state.scroll_down_by(u16::MAX);
-        }
+        _ => {}
+    }
+    let list_state = &mut state.list_state;
+    match keymap.action(KeyScope::Navigation, key) {
+        Some(KeyAction::MoveUp) => list_state.scroll_up_by(1),
+        Some(KeyAction::MoveDown) => list_state.scroll_down_by(1),
+        Some(KeyAction::PageUp) => list_state.scroll_up_by(10),
+        Some(KeyAction::PageDown) => list_state.scroll_down_by(10),
+        Some(KeyAction::ScrollTop) => list_state.scroll_up_by(u16::MAX),
+        Some(KeyAction::ScrollBottom) => list_state.scroll_down_by(u16::MAX),
         _ => return false,
     }
     true
@@ -422,3 +682,75 @@ fn handle_split_preview_navigation_key(key_event: KeyEvent, state: &mut crate::u
     }
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Local;
+    use oatty_mcp::{PluginEngine, config::McpConfig};
+    use oatty_registry::CommandRegistry;
+    use oatty_types::{CommandSpec, ExecOutcome, HttpCommandSpec};
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    fn build_app() -> App<'static> {
+        let command = |name: &str, method: &str| {
+            CommandSpec::new_http(
+                "jobs".to_string(),
+                name.to_string(),
+                String::new(),
+                Vec::new(),
+                Vec::new(),
+                HttpCommandSpec::new(method, "/jobs", None, None),
+                0,
+            )
+        };
+        let registry = Arc::new(Mutex::new(
+            CommandRegistry::default().with_commands(vec![command("list", "GET"), command("delete", "DELETE")]),
+        ));
+        let engine = Arc::new(PluginEngine::new(McpConfig::default(), Arc::clone(&registry)).expect("engine"));
+        App::new(registry, engine)
+    }
+
+    fn open_tab(app: &mut App, source: &str) {
+        app.results.set_pending_source(Some(source.to_string()));
+        let outcome = ExecOutcome::Http {
+            status_code: 200,
+            log_entry: String::new(),
+            payload: json!({"id": "job-1"}),
+            request_id: 0,
+        };
+        app.results.open_tab(outcome, &*app.ctx.theme, Local::now());
+    }
+
+    fn rerun_key() -> KeyEvent {
+        KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE)
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn rerun_of_a_destructive_command_asks_for_confirmation() {
+        let mut app = build_app();
+        let mut component = TableComponent::default();
+        open_tab(&mut app, "jobs delete job-1");
+
+        let effects = component.handle_key_events(&mut app, rerun_key());
+        assert!(matches!(effects.as_slice(), [Effect::ShowModal(Modal::Confirmation)]));
+        assert!(!app.results.active_tab().expect("tab").is_running());
+
+        let effects = component.handle_message(
+            &mut app,
+            Msg::ConfirmationModalButtonClicked(component.rerun_confirm_button.widget_id()),
+        );
+        assert!(matches!(effects.as_slice(), [Effect::Run { hydrated_command, .. }] if hydrated_command == "jobs delete job-1"));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn rerun_of_a_read_command_runs_immediately() {
+        let mut app = build_app();
+        let mut component = TableComponent::default();
+        open_tab(&mut app, "jobs list");
+
+        let effects = component.handle_key_events(&mut app, rerun_key());
+        assert!(matches!(effects.as_slice(), [Effect::Run { hydrated_command, .. }] if hydrated_command == "jobs list"));
+    }
+}
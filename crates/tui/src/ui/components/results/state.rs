@@ -1,3 +1,5 @@
+use super::export::{ExportFormat, export_row_count, export_value};
+use super::watch::{ResultsWatch, WatchChanges};
 use crate::ui::theme::Theme;
 use crate::ui::utils::normalize_result_payload_owned;
 use crate::ui::{
@@ -7,22 +9,25 @@ use crate::ui::{
         theme_helpers::{table_header_style, table_row_style},
     },
     utils::{
-        ColumnWithSize, KeyScoreContext, get_scored_keys, get_scored_keys_with_context, infer_columns_with_sizes_from_json, is_status_like,
-        normalize_header, render_value, status_color_for_value,
+        ColumnWithSize, KeyScoreContext, command_line_http_method, get_scored_keys, get_scored_keys_with_context,
+        infer_columns_with_sizes_from_json, is_status_like, normalize_header, render_value, status_color_for_value,
     },
 };
+use oatty_registry::CommandRegistry;
 use oatty_types::ExecOutcome;
+use oatty_util::can_align_by_key;
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
 use ratatui::layout::Rect;
 use ratatui::prelude::{Line, Span};
 use ratatui::widgets::{ListState, TableState};
 use ratatui::{
     layout::{Constraint, Layout},
-    style::Style,
+    style::{Modifier, Style},
     widgets::{Cell, Row},
 };
 use serde_json::Value;
 use std::borrow::Cow;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone)]
 pub struct DrillFrame {
@@ -52,6 +57,9 @@ pub struct ResultsTableState<'a> {
     split_preview_pinned: bool,
     split_preview_scroll_metrics: ScrollMetrics,
     selected_kv_value_overflows: bool,
+    watch_source: Option<String>,
+    watch: Option<ResultsWatch>,
+    watch_changes: WatchChanges,
 }
 
 impl<'a> Default for ResultsTableState<'a> {
@@ -74,6 +82,9 @@ impl<'a> Default for ResultsTableState<'a> {
             split_preview_pinned: false,
             split_preview_scroll_metrics: ScrollMetrics::default(),
             selected_kv_value_overflows: false,
+            watch_source: None,
+            watch: None,
+            watch_changes: WatchChanges::default(),
         }
     }
 }
@@ -226,6 +237,7 @@ impl<'a> ResultsTableState<'a> {
 
     pub fn apply_result_json(&mut self, value: Option<Value>, theme: &dyn UiTheme, rerank_columns: bool) {
         self.result_json = value;
+        self.watch_changes = WatchChanges::default();
         self.drill_stack.clear();
         let json_array = Self::array_from_json(self.current_result_json());
         self.columns = self.create_columns(json_array, rerank_columns);
@@ -419,7 +431,8 @@ impl<'a> ResultsTableState<'a> {
         {
             let columns: &Vec<ColumnWithSize> = self.columns.as_ref().unwrap();
             let mut rows: Vec<Vec<Cell>> = Vec::with_capacity(value.len());
-            for item in value.iter() {
+            let highlight_changes = self.drill_stack.is_empty();
+            for (row_index, item) in value.iter().enumerate() {
                 let mut cells: Vec<Cell> = Vec::with_capacity(value.len());
                 for col in columns.iter() {
                     let key = &col.key;
@@ -436,7 +449,13 @@ impl<'a> ResultsTableState<'a> {
                     if is_status_like(key)
                         && let Some(color) = status_color_for_value(&display_text, theme)
                     {
-                        spans = vec![Span::styled(Cow::from(display_text), Style::default().fg(color))];
+                        spans = vec![Span::styled(Cow::from(display_text.clone()), Style::default().fg(color))];
+                    }
+                    if highlight_changes && self.watch_changes.is_cell_changed(row_index, key) {
+                        spans = vec![Span::styled(
+                            Cow::from(display_text),
+                            theme.status_warning().add_modifier(Modifier::BOLD),
+                        )];
                     }
                     let cell = Cell::from(Line::from(spans)).style(theme.text_primary_style());
                     cells.push(cell);
@@ -630,6 +649,122 @@ impl<'a> ResultsTableState<'a> {
         }
     }
 
+    // ========================
+    // Export
+    // ========================
+    /// Renders the current drill level in `format`, or `None` when there is nothing to export.
+    ///
+    /// Tables export their column set; key/value views export the listed keys.
+    pub fn export(&self, format: ExportFormat) -> Option<(String, usize)> {
+        let value = self.current_result_json()?;
+        let columns: Vec<String> = match self.columns.as_ref() {
+            Some(columns) => columns.iter().map(|column| column.key.clone()).collect(),
+            None => self.kv_entries.iter().map(|entry| entry.key.clone()).collect(),
+        };
+        Some((export_value(value, &columns, format), export_row_count(value)))
+    }
+
+    // ========================
+    // Watch mode
+    // ========================
+    /// Sets the command line that produced the current results, enabling watch mode.
+    pub fn set_watch_source(&mut self, command: Option<String>) {
+        self.watch_source = command;
+    }
+
+    pub fn watch(&self) -> Option<&ResultsWatch> {
+        self.watch.as_ref()
+    }
+
+    pub fn is_watching(&self) -> bool {
+        self.watch.is_some()
+    }
+
+    pub fn watch_changes(&self) -> &WatchChanges {
+        &self.watch_changes
+    }
+
+    /// Starts or stops watching the source command; returns whether a watch is now active.
+    ///
+    /// Only HTTP GET commands can be watched, so a watch never repeats a write.
+    pub fn toggle_watch(&mut self, registry: &CommandRegistry, interval: Duration, now: Instant) -> Result<bool, String> {
+        if self.watch.take().is_some() {
+            return Ok(false);
+        }
+        let command = self
+            .watch_source
+            .clone()
+            .ok_or_else(|| "Watch is only available for results of a palette or history command".to_string())?;
+        if !command_line_http_method(registry, &command).is_some_and(|method| method.eq_ignore_ascii_case("GET")) {
+            return Err("Watch is only available for read-only (GET) commands".to_string());
+        }
+        self.watch = Some(ResultsWatch::new(command, interval, now));
+        Ok(true)
+    }
+
+    pub fn watch_source(&self) -> Option<&str> {
+        self.watch_source.as_deref()
+    }
+
+    /// Stops any watch but keeps the source command; returns the id of a run still in flight.
+    pub fn stop_watch(&mut self) -> Option<u64> {
+        self.watch.take().and_then(|watch| watch.pending_request())
+    }
+
+    /// Returns the command and request id of the next watch run, when one is due.
+    pub fn poll_watch(&mut self, now: Instant) -> Option<(String, u64)> {
+        let watch = self.watch.as_mut()?;
+        let request_id = watch.poll(now)?;
+        Some((watch.command().to_string(), request_id))
+    }
+
+    /// Applies the outcome of a watch run, highlighting what changed since the previous payload.
+    ///
+    /// Returns `false` when the outcome does not belong to the watch so the caller can
+    /// handle it as a regular result. The current selection is kept across refreshes.
+    pub fn apply_watch_outcome(&mut self, execution_outcome: &ExecOutcome, theme: &dyn UiTheme, now: Instant) -> bool {
+        let (request_id, payload) = match execution_outcome {
+            ExecOutcome::Http { request_id, payload, .. } | ExecOutcome::Mcp { request_id, payload, .. } => (*request_id, payload),
+            _ => return false,
+        };
+        if !self.watch.as_mut().is_some_and(|watch| watch.complete(request_id, now)) {
+            return false;
+        }
+        self.refresh_result_json(payload.clone(), theme);
+        true
+    }
+
+    /// Replaces the payload with a newer run of the same command, highlighting
+    /// what changed and keeping the current selection.
+    pub fn refresh_result_json(&mut self, payload: Value, theme: &dyn UiTheme) {
+        let current = normalize_result_payload_owned(payload);
+        let changes = self
+            .result_json
+            .as_ref()
+            .map(|previous| WatchChanges::between(previous, &current))
+            .unwrap_or_default();
+        let selected_row = self.table_state.selected();
+        let selected_column = self.table_state.selected_column();
+        let selected_entry = self.list_state.selected();
+
+        self.result_json = Some(current);
+        self.drill_stack.clear();
+        let json_array = Self::array_from_json(self.current_result_json());
+        self.columns = self.create_columns(json_array, false);
+        self.watch_changes = changes;
+        self.reset_render_cache(theme);
+
+        let row_count = self.num_rows();
+        self.table_state
+            .select(selected_row.filter(|_| row_count > 0).map(|row| row.min(row_count - 1)));
+        self.table_state.select_column(selected_column);
+        let entry_count = self.kv_entries.len();
+        if entry_count > 0 {
+            self.list_state
+                .select(selected_entry.map(|entry| entry.min(entry_count - 1)).or(Some(0)));
+        }
+    }
+
     fn current_result_json(&self) -> Option<&Value> {
         if let Some(frame) = self.drill_stack.last() {
             return Some(&frame.value);
@@ -715,6 +850,23 @@ pub fn build_key_value_entries_with_context(value: &Value, context: KeyScoreCont
     }
 }
 
+/// Columns that can align two top-level result arrays for diffing, best first.
+///
+/// Uses the same ranking as value selection so `id` and `*_id` columns lead;
+/// only columns holding a unique scalar in every row of both arrays qualify.
+pub fn diff_alignment_keys(before: &Value, after: &Value) -> Vec<String> {
+    let (Value::Array(before_rows), Value::Array(after_rows)) = (before, after) else {
+        return Vec::new();
+    };
+    let Some(first_row) = before_rows.iter().chain(after_rows).find_map(Value::as_object) else {
+        return Vec::new();
+    };
+    get_scored_keys_with_context(first_row, KeyScoreContext::ValueSelection)
+        .into_iter()
+        .filter(|key| can_align_by_key(before_rows, after_rows, key))
+        .collect()
+}
+
 impl HasFocus for ResultsTableState<'_> {
     fn build(&self, builder: &mut FocusBuilder) {
         let tag = builder.start(self);
@@ -736,6 +888,7 @@ impl HasFocus for ResultsTableState<'_> {
 mod tests {
     use super::ResultsTableState;
     use crate::ui::theme::dracula::DraculaTheme;
+    use oatty_registry::CommandRegistry;
     use serde_json::json;
 
     #[test]
@@ -779,6 +932,110 @@ mod tests {
         assert!(!table.is_in_drill_mode());
     }
 
+    #[test]
+    fn export_uses_the_current_drill_level() {
+        use crate::ui::components::results::export::ExportFormat;
+
+        let mut table = ResultsTableState::default();
+        let theme = DraculaTheme::new();
+        table.apply_result_json(
+            Some(json!([
+                {"service": {"id": "srv-1", "name": "api"}, "status": "ok"}
+            ])),
+            &theme,
+            true,
+        );
+        let (root_csv, root_rows) = table.export(ExportFormat::Csv).expect("root export");
+        assert_eq!(root_rows, 1);
+        assert!(root_csv.lines().next().unwrap().contains("service.id"));
+
+        table.table_state.select(Some(0));
+        table.table_state.select_column(Some(0));
+        assert!(table.drill_into_selection(&theme));
+        let (drilled_json, drilled_rows) = table.export(ExportFormat::Json).expect("drilled export");
+        assert_eq!(drilled_rows, 1);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&drilled_json).unwrap(),
+            json!({"id": "srv-1", "name": "api"})
+        );
+    }
+
+    #[test]
+    fn watch_outcome_highlights_changes_and_keeps_selection() {
+        use oatty_types::ExecOutcome;
+        use std::time::{Duration, Instant};
+
+        let mut table = ResultsTableState::default();
+        let theme = DraculaTheme::new();
+        table.apply_result_json(
+            Some(json!([{"id": "job-1", "state": "running"}, {"id": "job-2", "state": "queued"}])),
+            &theme,
+            true,
+        );
+        table.table_state.select(Some(1));
+        let registry = jobs_registry();
+        assert!(table.toggle_watch(&registry, Duration::from_secs(5), Instant::now()).is_err());
+
+        table.set_watch_source(Some("jobs list".to_string()));
+        let start = Instant::now();
+        assert_eq!(table.toggle_watch(&registry, Duration::from_secs(5), start), Ok(true));
+        let (command, request_id) = table.poll_watch(start + Duration::from_secs(5)).expect("run due");
+        assert_eq!(command, "jobs list");
+
+        let unrelated = ExecOutcome::Http {
+            status_code: 200,
+            log_entry: String::new(),
+            payload: json!([]),
+            request_id: request_id.wrapping_add(1),
+        };
+        assert!(!table.apply_watch_outcome(&unrelated, &theme, start));
+
+        let outcome = ExecOutcome::Http {
+            status_code: 200,
+            log_entry: String::new(),
+            payload: json!([{"id": "job-1", "state": "done"}, {"id": "job-2", "state": "queued"}]),
+            request_id,
+        };
+        assert!(table.apply_watch_outcome(&outcome, &theme, start + Duration::from_secs(6)));
+        assert_eq!(table.watch_changes().count(), 1);
+        assert!(table.watch_changes().is_cell_changed(0, "state"));
+        assert_eq!(table.table_state.selected(), Some(1));
+
+        assert_eq!(table.stop_watch(), None);
+        assert!(!table.is_watching());
+    }
+
+    #[test]
+    fn watch_refuses_commands_that_are_not_get() {
+        use std::time::{Duration, Instant};
+
+        let mut table = ResultsTableState::default();
+        let registry = jobs_registry();
+        table.set_watch_source(Some("jobs delete job-1".to_string()));
+        assert!(table.toggle_watch(&registry, Duration::from_secs(5), Instant::now()).is_err());
+        assert!(!table.is_watching());
+
+        table.set_watch_source(Some("unknown command".to_string()));
+        assert!(table.toggle_watch(&registry, Duration::from_secs(5), Instant::now()).is_err());
+    }
+
+    fn jobs_registry() -> CommandRegistry {
+        use oatty_types::{CommandSpec, HttpCommandSpec};
+
+        let command = |name: &str, method: &str| {
+            CommandSpec::new_http(
+                "jobs".to_string(),
+                name.to_string(),
+                String::new(),
+                Vec::new(),
+                Vec::new(),
+                HttpCommandSpec::new(method, "/jobs", None, None),
+                0,
+            )
+        };
+        CommandRegistry::default().with_commands(vec![command("list", "GET"), command("delete", "DELETE")])
+    }
+
     #[test]
     fn breadcrumbs_include_root_and_nested_labels() {
         let mut table = ResultsTableState::default();
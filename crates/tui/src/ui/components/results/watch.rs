@@ -0,0 +1,176 @@
+//! Watch mode for the Results modal.
+//!
+//! A watch re-runs the command that produced the current results on an
+//! interval. Each new payload is diffed against the previous one so the table
+//! can highlight the rows, cells, and keys that changed.
+
+use std::collections::HashSet;
+use std::hash::{DefaultHasher, Hasher};
+use std::time::{Duration, Instant};
+
+use oatty_util::{JsonChangeKind, JsonPathSegment, diff_json};
+use serde_json::Value;
+
+/// Interval used when watch mode is toggled on from the Results modal.
+pub const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_secs(5);
+/// A run still in flight after this long is treated as lost so polling resumes.
+const PENDING_RUN_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Scheduling state for a watched command.
+#[derive(Debug)]
+pub struct ResultsWatch {
+    command: String,
+    interval: Duration,
+    next_run_at: Instant,
+    pending_run: Option<(u64, Instant)>,
+    runs: u32,
+}
+
+impl ResultsWatch {
+    pub fn new(command: String, interval: Duration, now: Instant) -> Self {
+        Self {
+            command,
+            interval,
+            next_run_at: now + interval,
+            pending_run: None,
+            runs: 0,
+        }
+    }
+
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Number of re-runs issued since the watch started.
+    pub fn runs(&self) -> u32 {
+        self.runs
+    }
+
+    /// Request id of the run in flight, if any.
+    pub fn pending_request(&self) -> Option<u64> {
+        self.pending_run.map(|(request_id, _)| request_id)
+    }
+
+    /// Returns the request id for the next run when one is due and none is in flight.
+    pub fn poll(&mut self, now: Instant) -> Option<u64> {
+        if let Some((_, issued_at)) = self.pending_run {
+            if now.duration_since(issued_at) < PENDING_RUN_TIMEOUT {
+                return None;
+            }
+            self.pending_run = None;
+        }
+        if now < self.next_run_at {
+            return None;
+        }
+        self.runs += 1;
+        let mut hasher = DefaultHasher::new();
+        hasher.write(self.command.as_bytes());
+        hasher.write_u32(self.runs);
+        let request_id = hasher.finish();
+        self.pending_run = Some((request_id, now));
+        Some(request_id)
+    }
+
+    /// Returns `true` (and schedules the next run) when `request_id` is the run in flight.
+    pub fn complete(&mut self, request_id: u64, now: Instant) -> bool {
+        if self.pending_run.is_none_or(|(pending, _)| pending != request_id) {
+            return false;
+        }
+        self.pending_run = None;
+        self.next_run_at = now + self.interval;
+        true
+    }
+}
+
+/// Rows, cells, and keys that changed between two watched payloads.
+#[derive(Debug, Default)]
+pub struct WatchChanges {
+    rows: HashSet<usize>,
+    cells: HashSet<(usize, String)>,
+    keys: HashSet<String>,
+    count: usize,
+}
+
+impl WatchChanges {
+    /// Maps the structural diff onto the table layout: array payloads mark
+    /// `(row, column)` cells (or whole rows when a row is new or replaced);
+    /// object payloads mark top-level keys.
+    pub fn between(previous: &Value, current: &Value) -> Self {
+        let mut changes = Self::default();
+        for change in diff_json(previous, current) {
+            changes.count += 1;
+            if change.kind == JsonChangeKind::Removed && change.path.len() == 1 {
+                // Removed rows and keys still count but are no longer on screen.
+                continue;
+            }
+            match change.path.as_slice() {
+                [JsonPathSegment::Index(row)] => {
+                    changes.rows.insert(*row);
+                }
+                [JsonPathSegment::Index(row), JsonPathSegment::Key(key), ..] => {
+                    changes.cells.insert((*row, key.clone()));
+                }
+                [JsonPathSegment::Key(key), ..] => {
+                    changes.keys.insert(key.clone());
+                }
+                _ => {}
+            }
+        }
+        changes
+    }
+
+    /// Total number of structural changes, including removals.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_cell_changed(&self, row: usize, key: &str) -> bool {
+        self.rows.contains(&row) || self.cells.contains(&(row, key.to_string()))
+    }
+
+    pub fn is_key_changed(&self, key: &str) -> bool {
+        self.keys.contains(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn poll_waits_for_interval_and_in_flight_run() {
+        let start = Instant::now();
+        let mut watch = ResultsWatch::new("jobs info job-1".into(), Duration::from_secs(5), start);
+        assert_eq!(watch.poll(start), None);
+
+        let due = start + Duration::from_secs(5);
+        let request_id = watch.poll(due).expect("run due");
+        assert_eq!(watch.poll(due + Duration::from_secs(10)), None);
+        assert!(!watch.complete(request_id.wrapping_add(1), due));
+        assert!(watch.complete(request_id, due + Duration::from_secs(1)));
+        assert_eq!(watch.poll(due + Duration::from_secs(2)), None);
+        assert!(watch.poll(due + Duration::from_secs(6)).is_some());
+        assert_eq!(watch.runs(), 2);
+    }
+
+    #[test]
+    fn changes_map_to_cells_rows_and_keys() {
+        let table = WatchChanges::between(
+            &json!([{"id": 1, "state": "running"}]),
+            &json!([{"id": 1, "state": "done"}, {"id": 2, "state": "queued"}]),
+        );
+        assert_eq!(table.count(), 2);
+        assert!(table.is_cell_changed(0, "state"));
+        assert!(!table.is_cell_changed(0, "id"));
+        assert!(table.is_cell_changed(1, "id"));
+
+        let object = WatchChanges::between(&json!({"status": "running", "id": 1}), &json!({"status": "done", "id": 1}));
+        assert!(object.is_key_changed("status"));
+        assert!(!object.is_key_changed("id"));
+    }
+}
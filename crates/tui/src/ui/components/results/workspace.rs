@@ -0,0 +1,365 @@
+//! Tabbed workspace behind the Results modal.
+//!
+//! Every response shown in the Results modal opens as a tab labelled by the
+//! command that produced it and the time it arrived. Each tab owns its own
+//! [`ResultsTableState`], so drill-down breadcrumbs, the selected row and
+//! column, and any watch survive switching between tabs. Only the most recent
+//! unpinned tabs are kept; pinned tabs stay until they are closed.
+
+use std::hash::{DefaultHasher, Hasher};
+use std::time::Instant;
+
+use chrono::{DateTime, Local};
+use oatty_types::ExecOutcome;
+
+use super::state::ResultsTableState;
+use crate::ui::theme::Theme;
+
+/// Number of unpinned tabs kept before the oldest is dropped.
+pub const MAX_UNPINNED_TABS: usize = 8;
+/// Longest command prefix shown in a tab title.
+const MAX_TITLE_CHARS: usize = 32;
+
+/// A response kept in the results workspace.
+#[derive(Debug)]
+pub struct ResultsTab<'a> {
+    label: String,
+    opened_at: DateTime<Local>,
+    pinned: bool,
+    running_request: Option<u64>,
+    last_error: Option<String>,
+    pub table: ResultsTableState<'a>,
+}
+
+impl ResultsTab<'_> {
+    /// Command line (or description) of the response.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// Whether a re-run of this tab's command is in flight.
+    pub fn is_running(&self) -> bool {
+        self.running_request.is_some()
+    }
+
+    /// Error reported by the latest re-run, if it failed.
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    /// Short title for the tab bar: `command · HH:MM:SS`.
+    pub fn title(&self) -> String {
+        let mut label: String = self.label.chars().take(MAX_TITLE_CHARS).collect();
+        if self.label.chars().count() > MAX_TITLE_CHARS {
+            label.push('…');
+        }
+        format!("{label} · {}", self.opened_at.format("%H:%M:%S"))
+    }
+}
+
+/// Open result tabs and the one currently shown.
+#[derive(Debug, Default)]
+pub struct ResultsWorkspace<'a> {
+    tabs: Vec<ResultsTab<'a>>,
+    active: usize,
+    pending_source: Option<String>,
+    run_sequence: u32,
+    /// Shown when no tab is open so callers always have a table to render.
+    empty: ResultsTableState<'a>,
+}
+
+impl<'a> ResultsWorkspace<'a> {
+    pub fn tabs(&self) -> &[ResultsTab<'a>] {
+        &self.tabs
+    }
+
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    pub fn active_tab(&self) -> Option<&ResultsTab<'a>> {
+        self.tabs.get(self.active)
+    }
+
+    /// Table of the active tab.
+    pub fn table(&self) -> &ResultsTableState<'a> {
+        self.tabs.get(self.active).map_or(&self.empty, |tab| &tab.table)
+    }
+
+    pub fn table_mut(&mut self) -> &mut ResultsTableState<'a> {
+        match self.tabs.get_mut(self.active) {
+            Some(tab) => &mut tab.table,
+            None => &mut self.empty,
+        }
+    }
+
+    /// Records the command line behind the next tab to open. It labels the tab
+    /// and enables re-runs and watch mode for it.
+    pub fn set_pending_source(&mut self, command: Option<String>) {
+        self.pending_source = command;
+    }
+
+    /// Opens `outcome` in a new, active tab and drops the oldest unpinned tabs
+    /// beyond [`MAX_UNPINNED_TABS`].
+    pub fn open_tab(&mut self, outcome: ExecOutcome, theme: &dyn Theme, opened_at: DateTime<Local>) {
+        let source = self.pending_source.take();
+        let label = source.clone().unwrap_or_else(|| outcome_label(&outcome));
+        let mut table = ResultsTableState::default();
+        table.process_general_execution_result(outcome, theme);
+        table.set_watch_source(source);
+        self.tabs.push(ResultsTab {
+            label,
+            opened_at,
+            pinned: false,
+            running_request: None,
+            last_error: None,
+            table,
+        });
+        self.active = self.tabs.len() - 1;
+        self.evict_unpinned();
+    }
+
+    fn evict_unpinned(&mut self) {
+        while self.tabs.iter().filter(|tab| !tab.pinned).count() > MAX_UNPINNED_TABS {
+            let Some(oldest) = self
+                .tabs
+                .iter()
+                .position(|tab| !tab.pinned && !tab.is_running() && !tab.table.is_watching())
+            else {
+                return;
+            };
+            self.tabs.remove(oldest);
+            if self.active > oldest {
+                self.active -= 1;
+            }
+        }
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if index < self.tabs.len() {
+            self.active = index;
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.tabs.is_empty() {
+            self.active = (self.active + 1) % self.tabs.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.tabs.is_empty() {
+            self.active = (self.active + self.tabs.len() - 1) % self.tabs.len();
+        }
+    }
+
+    /// Pins or unpins the active tab; returns whether it is now pinned.
+    pub fn toggle_pin(&mut self) -> Option<bool> {
+        let tab = self.tabs.get_mut(self.active)?;
+        tab.pinned = !tab.pinned;
+        let pinned = tab.pinned;
+        self.evict_unpinned();
+        Some(pinned)
+    }
+
+    /// Closes the active tab and returns the request ids it still had in flight.
+    pub fn close_active(&mut self) -> Vec<u64> {
+        if self.active >= self.tabs.len() {
+            return Vec::new();
+        }
+        let mut tab = self.tabs.remove(self.active);
+        self.active = self.active.min(self.tabs.len().saturating_sub(1));
+        tab.running_request.into_iter().chain(tab.table.stop_watch()).collect()
+    }
+
+    /// Starts a re-run of the active tab's command and returns the command and its request id.
+    pub fn rerun_active(&mut self) -> Result<(String, u64), String> {
+        let tab = self.tabs.get_mut(self.active).ok_or_else(|| "No results tab is open".to_string())?;
+        if tab.running_request.is_some() {
+            return Err("This tab is already running".to_string());
+        }
+        let command = tab
+            .table
+            .watch_source()
+            .map(str::to_string)
+            .ok_or_else(|| "Re-run is only available for results of a palette or history command".to_string())?;
+        self.run_sequence += 1;
+        let mut hasher = DefaultHasher::new();
+        hasher.write(command.as_bytes());
+        hasher.write_u32(self.run_sequence);
+        hasher.write(b"results-tab");
+        let request_id = hasher.finish();
+        tab.running_request = Some(request_id);
+        tab.last_error = None;
+        Ok((command, request_id))
+    }
+
+    /// Cancels the active tab's re-run and watch; returns the request ids to abort.
+    pub fn cancel_active(&mut self) -> Vec<u64> {
+        let Some(tab) = self.tabs.get_mut(self.active) else {
+            return Vec::new();
+        };
+        tab.running_request.take().into_iter().chain(tab.table.stop_watch()).collect()
+    }
+
+    /// Routes a completed execution to the tab that requested it.
+    ///
+    /// Returns `false` when no tab is waiting for the outcome so it can be
+    /// delivered to the views as usual.
+    pub fn complete_run(&mut self, outcome: &ExecOutcome, theme: &dyn Theme, now: Instant) -> bool {
+        let (request_id, status_code, log_entry, payload) = match outcome {
+            ExecOutcome::Http {
+                request_id,
+                status_code,
+                log_entry,
+                payload,
+            } => (*request_id, *status_code, log_entry, payload),
+            ExecOutcome::Mcp {
+                request_id,
+                log_entry,
+                payload,
+            } => (*request_id, 200, log_entry, payload),
+            _ => return false,
+        };
+        for tab in &mut self.tabs {
+            if tab.table.apply_watch_outcome(outcome, theme, now) {
+                return true;
+            }
+            if tab.running_request != Some(request_id) {
+                continue;
+            }
+            tab.running_request = None;
+            if status_code > 399 {
+                tab.last_error = Some(format!("Re-run failed: status {status_code} - {log_entry}"));
+            } else {
+                tab.table.refresh_result_json(payload.clone(), theme);
+                tab.opened_at = Local::now();
+            }
+            return true;
+        }
+        false
+    }
+
+    /// Returns the watch runs that are due across all tabs.
+    pub fn poll_watches(&mut self, now: Instant) -> Vec<(String, u64)> {
+        self.tabs.iter_mut().filter_map(|tab| tab.table.poll_watch(now)).collect()
+    }
+
+    /// Stops every watch (used when the modal closes) and returns runs still in flight.
+    pub fn stop_watches(&mut self) -> Vec<u64> {
+        self.tabs.iter_mut().filter_map(|tab| tab.table.stop_watch()).collect()
+    }
+
+    pub fn is_watching(&self) -> bool {
+        self.tabs.iter().any(|tab| tab.table.is_watching())
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.tabs.iter().any(ResultsTab::is_running)
+    }
+}
+
+/// Fallback tab label for results that were not produced by a command line.
+fn outcome_label(outcome: &ExecOutcome) -> String {
+    match outcome {
+        ExecOutcome::Http { log_entry, .. } | ExecOutcome::Mcp { log_entry, .. } if !log_entry.trim().is_empty() => {
+            log_entry.lines().next().unwrap_or_default().trim().to_string()
+        }
+        _ => "Results".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::theme::dracula::DraculaTheme;
+    use serde_json::{Value, json};
+
+    fn outcome(payload: Value, request_id: u64) -> ExecOutcome {
+        ExecOutcome::Http {
+            status_code: 200,
+            log_entry: "Workflow execution plan".to_string(),
+            payload,
+            request_id,
+        }
+    }
+
+    fn open(workspace: &mut ResultsWorkspace, source: Option<&str>, payload: Value) {
+        workspace.set_pending_source(source.map(str::to_string));
+        workspace.open_tab(outcome(payload, 0), &DraculaTheme::new(), Local::now());
+    }
+
+    #[test]
+    fn tabs_keep_their_own_drill_and_column_selection() {
+        let theme = DraculaTheme::new();
+        let mut workspace = ResultsWorkspace::default();
+        open(&mut workspace, Some("apps list"), json!([{"app": {"id": "a-1"}}]));
+        open(&mut workspace, None, json!([{"id": "s-1"}, {"id": "s-2"}]));
+        assert_eq!(workspace.tabs()[0].label(), "apps list");
+        assert_eq!(workspace.tabs()[1].label(), "Workflow execution plan");
+
+        workspace.select(0);
+        let table = workspace.table_mut();
+        table.table_state.select(Some(0));
+        table.table_state.select_column(Some(0));
+        assert!(table.drill_into_selection(&theme));
+
+        workspace.select_next();
+        workspace.table_mut().table_state.select(Some(1));
+        workspace.select_previous();
+        assert_eq!(workspace.table().breadcrumbs(), vec!["Root".to_string(), "app".to_string()]);
+        workspace.table_mut().drill_up(&theme);
+        workspace.select_next();
+        assert_eq!(workspace.table().table_state.selected(), Some(1));
+    }
+
+    #[test]
+    fn oldest_unpinned_tabs_are_dropped_and_pinned_tabs_survive() {
+        let mut workspace = ResultsWorkspace::default();
+        open(&mut workspace, Some("apps info pinned"), json!({"id": 0}));
+        assert_eq!(workspace.toggle_pin(), Some(true));
+        for index in 1..=MAX_UNPINNED_TABS + 2 {
+            open(&mut workspace, Some(&format!("apps info {index}")), json!({"id": index}));
+        }
+        let labels: Vec<&str> = workspace.tabs().iter().map(ResultsTab::label).collect();
+        assert_eq!(labels.len(), MAX_UNPINNED_TABS + 1);
+        assert_eq!(labels[0], "apps info pinned");
+        assert_eq!(labels[1], "apps info 3");
+        assert_eq!(workspace.active_index(), MAX_UNPINNED_TABS);
+
+        workspace.select(0);
+        assert!(workspace.close_active().is_empty());
+        assert_eq!(workspace.tabs().len(), MAX_UNPINNED_TABS);
+        assert_eq!(workspace.active_tab().map(ResultsTab::label), Some("apps info 3"));
+    }
+
+    #[test]
+    fn reruns_complete_in_their_own_tab_and_can_be_cancelled() {
+        let theme = DraculaTheme::new();
+        let mut workspace = ResultsWorkspace::default();
+        open(&mut workspace, None, json!([{"id": "plan"}]));
+        assert!(workspace.rerun_active().is_err());
+
+        open(&mut workspace, Some("jobs list"), json!([{"id": "job-1", "state": "queued"}]));
+        let (command, request_id) = workspace.rerun_active().expect("rerun");
+        assert_eq!(command, "jobs list");
+        assert!(workspace.is_running());
+
+        // Reading another tab does not affect the run.
+        workspace.select(0);
+        let done = outcome(json!([{"id": "job-1", "state": "done"}]), request_id);
+        assert!(workspace.complete_run(&done, &theme, Instant::now()));
+        assert!(!workspace.is_running());
+        assert_eq!(workspace.tabs()[1].table.watch_changes().count(), 1);
+        assert!(!workspace.complete_run(&done, &theme, Instant::now()));
+
+        workspace.select(1);
+        let (_, request_id) = workspace.rerun_active().expect("rerun");
+        assert_eq!(workspace.cancel_active(), vec![request_id]);
+        assert!(!workspace.is_running());
+    }
+}
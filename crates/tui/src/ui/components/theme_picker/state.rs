@@ -3,7 +3,7 @@ use crate::ui::theme::catalog::{self, ThemeDefinition};
 /// UI state for the theme picker modal.
 #[derive(Debug, Clone)]
 pub struct ThemePickerState {
-    options: Vec<&'static ThemeDefinition>,
+    options: Vec<ThemeDefinition>,
     pub selected_index: usize,
 }
 
@@ -14,21 +14,27 @@ impl Default for ThemePickerState {
 }
 
 impl ThemePickerState {
-    /// Build a state instance seeded with all available theme definitions.
+    /// Build a state instance seeded with the built-in theme definitions.
     pub fn new() -> Self {
         Self {
-            options: catalog::all().iter().collect(),
+            options: catalog::all().to_vec(),
             selected_index: 0,
         }
     }
 
+    /// Replace the user-defined options listed after the built-ins.
+    pub fn set_custom_themes(&mut self, custom_themes: &[ThemeDefinition]) {
+        self.options = catalog::all().iter().chain(custom_themes).cloned().collect();
+        self.selected_index = self.selected_index.min(self.options.len().saturating_sub(1));
+    }
+
     /// Returns the currently selected definition, if any.
-    pub fn selected_option(&self) -> Option<&'static ThemeDefinition> {
-        self.options.get(self.selected_index).copied()
+    pub fn selected_option(&self) -> Option<&ThemeDefinition> {
+        self.options.get(self.selected_index)
     }
 
     /// All available theme definitions.
-    pub fn options(&self) -> &[&'static ThemeDefinition] {
+    pub fn options(&self) -> &[ThemeDefinition] {
         &self.options
     }
 
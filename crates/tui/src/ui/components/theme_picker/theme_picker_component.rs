@@ -1,5 +1,6 @@
 use crate::app::App;
 use crate::ui::components::Component;
+use crate::ui::keymap::{KeyAction, KeyScope};
 use crate::ui::theme::catalog::ThemeDefinition;
 use crate::ui::theme::theme_helpers as th;
 use crossterm::event::{KeyCode, KeyEvent};
@@ -16,8 +17,8 @@ pub struct ThemePickerComponent;
 
 impl ThemePickerComponent {
     fn apply_selection(&self, app: &mut App) {
-        if let Some(option) = app.theme_picker.selected_option() {
-            app.apply_theme_selection(option.id);
+        if let Some(theme_id) = app.theme_picker.selected_option().map(|option| option.id.to_string()) {
+            app.apply_theme_selection(&theme_id);
         }
     }
 
@@ -36,18 +37,22 @@ impl ThemePickerComponent {
             label_style = label_style.add_modifier(Modifier::BOLD);
         }
         line_spans.push(Span::styled(format!("{:<25}", definition.label.to_string()), label_style));
-        let mut padding = 25;
+        let mut padding: usize = 25;
         if definition.is_high_contrast {
             line_spans.push(Span::styled(format!("{:<8}", "[HC]"), theme.text_secondary_style()));
-            padding -= 8;
+            padding = padding.saturating_sub(8);
         }
         if definition.is_ansi_fallback {
             line_spans.push(Span::styled(format!("{:<8}", "[ANSI]"), theme.text_secondary_style()));
-            padding -= 8;
+            padding = padding.saturating_sub(8);
+        }
+        if definition.source_path().is_some() {
+            line_spans.push(Span::styled(format!("{:<8}", "[User]"), theme.text_secondary_style()));
+            padding = padding.saturating_sub(8);
         }
         if definition.id.eq_ignore_ascii_case(&app.ctx.active_theme_id) {
             line_spans.push(Span::styled(format!("{:<9}", "● Active"), theme.status_success()));
-            padding -= 9;
+            padding = padding.saturating_sub(9);
         }
 
         line_spans.push(Span::raw(format!("{:>padding$}", " ")));
@@ -79,13 +84,11 @@ impl Component for ThemePickerComponent {
                 self.apply_selection(app);
                 return vec![Effect::CloseModal];
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                app.theme_picker.select_previous();
-            }
-            KeyCode::Down | KeyCode::Char('j') => {
-                app.theme_picker.select_next();
-            }
-            _ => {}
+            _ => match app.ctx.keymap.action(KeyScope::Navigation, &key) {
+                Some(KeyAction::MoveUp) => app.theme_picker.select_previous(),
+                Some(KeyAction::MoveDown) => app.theme_picker.select_next(),
+                _ => {}
+            },
         }
         Vec::new()
     }
@@ -128,9 +131,10 @@ impl Component for ThemePickerComponent {
     }
 
     fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
+        let navigate = format!(" {}", app.ctx.keymap.label_pair(KeyAction::MoveUp, KeyAction::MoveDown));
         th::build_hint_spans(
             &*app.ctx.theme,
-            &[(" ↑/↓", " Navigate  "), (" Enter", " Apply  "), (" Esc", " Close ")],
+            &[(&navigate, " Navigate  "), (" Enter", " Apply  "), (" Esc", " Close ")],
         )
     }
 }
@@ -7,6 +7,7 @@ use crate::ui::components::workflows::collector::{
     CollectorApplyTarget, CollectorSelectionSource, CollectorStagedSelection, CollectorViewState, SelectorStatus,
 };
 use crate::ui::components::workflows::view_utils::{classify_json_value, style_for_role};
+use crate::ui::keymap::{KeyAction, Keymap};
 use crate::ui::theme::Theme;
 use crate::ui::theme::theme_helpers::{self as th, ButtonRenderOptions, ButtonType, build_hint_spans};
 use crate::ui::utils::{KeyScoreContext, get_scored_keys_with_context, render_value};
@@ -113,6 +114,7 @@ impl Component for WorkflowCollectorComponent {
         effects
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_mouse_events(&mut self, app: &mut App, mouse: MouseEvent) -> Vec<Effect> {
         if app.workflows.manual_entry_state().is_some() {
             return self.manual_entry.handle_mouse_events(app, mouse);
@@ -249,7 +251,7 @@ impl Component for WorkflowCollectorComponent {
             return self.manual_entry.get_hint_spans(app);
         }
         if let Some(selector) = app.workflows.collector_state() {
-            return Self::selector_hint_spans(theme, selector);
+            return Self::selector_hint_spans(theme, &app.ctx.keymap, selector);
         }
         Vec::new()
     }
@@ -434,8 +436,9 @@ impl WorkflowCollectorComponent {
         }
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_table_keys(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
-        if matches!(key.code, KeyCode::Char('r') | KeyCode::Char('R')) {
+        if app.ctx.keymap.matches(KeyAction::CollectorRefresh, &key) {
             if let Some(collector) = app.workflows.collector_state_mut() {
                 collector.status = SelectorStatus::Loading;
                 collector.error_message = None;
@@ -654,6 +657,13 @@ impl WorkflowCollectorComponent {
                 app.palette.set_is_suggestions_open(false);
                 effects.push(Effect::CloseModal);
             }
+            CollectorApplyTarget::WorkflowEditor => {
+                if let Some(editor) = app.workflows.editor.as_mut() {
+                    editor.apply_provider_value(selected_value);
+                    editor.refresh_if_changed(&app.ctx.command_registry);
+                }
+                effects.push(Effect::CloseModal);
+            }
         }
 
         effects
@@ -696,7 +706,7 @@ impl WorkflowCollectorComponent {
         if f_manual {
             if key.code == KeyCode::Enter {
                 effects.extend(self.apply_selection_to_run_state(app));
-            } else if key.code == KeyCode::Char('o') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            } else if app.ctx.keymap.matches(KeyAction::CollectorPickFile, &key) {
                 if let Some(collector) = app.workflows.collector_state_mut()
                     && matches!(collector.apply_target, CollectorApplyTarget::WorkflowInput)
                 {
@@ -1010,7 +1020,7 @@ impl WorkflowCollectorComponent {
         Line::from(spans)
     }
 
-    fn selector_hint_spans(theme: &dyn Theme, collector: &CollectorViewState<'_>) -> Vec<Span<'static>> {
+    fn selector_hint_spans(theme: &dyn Theme, keymap: &Keymap, collector: &CollectorViewState<'_>) -> Vec<Span<'static>> {
         if collector.f_filter.get() {
             return build_hint_spans(
                 theme,
@@ -1024,12 +1034,13 @@ impl WorkflowCollectorComponent {
         }
 
         if collector.f_table.get() {
+            let refresh = keymap.label(KeyAction::CollectorRefresh);
             let mut hints = vec![
                 ("Esc", " Cancel  "),
                 ("↑/↓", " Row  "),
                 ("Space", " Stage selection  "),
                 ("Enter", " Apply/Stage  "),
-                ("R", " Refresh  "),
+                (refresh.as_str(), " Refresh  "),
                 ("Tab", " Next focus  "),
                 ("Shift+Tab", " Previous focus"),
             ];
@@ -1040,6 +1051,7 @@ impl WorkflowCollectorComponent {
         }
 
         if collector.f_manual.get() {
+            let pick_file = keymap.label(KeyAction::CollectorPickFile);
             let mut hints = vec![
                 ("Esc", " Cancel  "),
                 ("Enter", " Apply  "),
@@ -1048,7 +1060,7 @@ impl WorkflowCollectorComponent {
                 ("Shift+Tab", " Previous focus"),
             ];
             if matches!(collector.apply_target, CollectorApplyTarget::WorkflowInput) {
-                hints.insert(2, ("Ctrl+O", " Select file  "));
+                hints.insert(2, (pick_file.as_str(), " Select file  "));
             }
             return build_hint_spans(theme, &hints);
         }
@@ -105,6 +105,7 @@ mod tests {
             steps: Vec::new(),
             final_output: None,
             requires: None,
+            schedule: None,
         };
         WorkflowRunState::new(workflow)
     }
@@ -60,6 +60,8 @@ pub enum CollectorApplyTarget {
         /// Whether the target in palette input is positional (`true`) or flag value (`false`).
         positional: bool,
     },
+    /// Apply selection to the step argument the workflow editor asked for.
+    WorkflowEditor,
 }
 
 /// Source currently selected for the Apply action.
@@ -157,7 +159,7 @@ impl<'a> CollectorViewState<'a> {
             })
             .filter(|(score, _)| *score > 0)
             .collect();
-        scores.sort_by(|a, b| b.0.cmp(&a.0));
+        scores.sort_by_key(|entry| std::cmp::Reverse(entry.0));
         let dataset = scores.into_iter().map(|(_, index)| items[index].clone()).collect();
 
         let json = Value::Array(dataset);
@@ -0,0 +1,743 @@
+//! Renders the guided workflow editor: an outline of inputs and steps, a form for the selected
+//! entry, live validation feedback, and overlays for text entry, command search, and bindings.
+
+use crate::app::App;
+use crate::ui::components::component::Component;
+use crate::ui::components::workflows::editor::state::{
+    CommandSearchPurpose, EditorField, EditorFormRow, EditorOverlay, EditorValidation, OutlineEntry, WorkflowEditorState,
+};
+use crate::ui::keymap::{KeyAction, KeyScope};
+use crate::ui::theme::theme_helpers::create_list_with_highlight;
+use crate::ui::theme::{
+    roles::Theme,
+    theme_helpers::{self as th, ButtonRenderOptions, ButtonType},
+};
+use crate::ui::utils::centered_rect;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use futures_util::FutureExt;
+use oatty_engine::provider::parse_provider_group_and_command;
+use oatty_registry::create_search_handle;
+use oatty_types::{CommandSpec, Effect, ExecOutcome, Modal, Msg, Route};
+use ratatui::layout::Position;
+use ratatui::widgets::{Clear, ListState};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect, Spacing},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Borders, ListItem, Paragraph, Wrap},
+};
+
+/// Captures layout metadata from the most recent render pass for hit detection.
+#[derive(Debug, Default, Clone, Copy)]
+struct WorkflowEditorLayout {
+    header_area: Rect,
+    outline_area: Rect,
+    form_area: Rect,
+    validation_area: Rect,
+    cancel_button_area: Rect,
+    save_button_area: Rect,
+    status_line_area: Rect,
+}
+
+impl From<Vec<Rect>> for WorkflowEditorLayout {
+    fn from(value: Vec<Rect>) -> Self {
+        Self {
+            header_area: value[0],
+            outline_area: value[1],
+            form_area: value[2],
+            validation_area: value[3],
+            cancel_button_area: value[4],
+            save_button_area: value[5],
+            status_line_area: value[6],
+        }
+    }
+}
+
+/// Controller for the workflow authoring route.
+#[derive(Debug, Default)]
+pub struct WorkflowEditorComponent {
+    layout: WorkflowEditorLayout,
+}
+
+impl Component for WorkflowEditorComponent {
+    fn handle_message(&mut self, app: &mut App, message: Msg) -> Vec<Effect> {
+        let Msg::ExecCompleted(outcome) = message else {
+            return Vec::new();
+        };
+        match *outcome {
+            ExecOutcome::WorkflowSaved { workflow_id, path } => {
+                if let Some(state) = app.workflows.editor_state_mut()
+                    && state.definition().workflow == workflow_id
+                {
+                    state.mark_saved(path);
+                }
+            }
+            ExecOutcome::WorkflowOperationError(message) => {
+                if let Some(state) = app.workflows.editor_state_mut() {
+                    state.set_status(message);
+                }
+            }
+            _ => {}
+        }
+        Vec::new()
+    }
+
+    fn handle_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
+        if app.workflows.editor_state().is_none() {
+            return Vec::new();
+        }
+        let effects = self.dispatch_key(app, key);
+        let registry = app.ctx.command_registry.clone();
+        if let Some(state) = app.workflows.editor_state_mut() {
+            state.refresh_if_changed(&registry);
+        }
+        effects
+    }
+
+    fn handle_mouse_events(&mut self, app: &mut App, mouse: MouseEvent) -> Vec<Effect> {
+        let position = Position {
+            x: mouse.column,
+            y: mouse.row,
+        };
+        let MouseEventKind::Down(MouseButton::Left) = mouse.kind else {
+            return Vec::new();
+        };
+        if self.layout.cancel_button_area.contains(position) {
+            return self.leave(app);
+        }
+        if self.layout.save_button_area.contains(position) {
+            return save(app);
+        }
+        let Some(state) = app.workflows.editor_state_mut() else {
+            return Vec::new();
+        };
+        if state.overlay.is_some() {
+            return Vec::new();
+        }
+        if self.layout.outline_area.contains(position) {
+            let flag = state.f_outline.clone();
+            if let Some(index) = hit_test_list(position, self.layout.outline_area, &state.outline_state) {
+                state.select_outline_offset(index as isize - state.outline_state.selected().unwrap_or(0) as isize);
+            }
+            app.focus.focus(&flag);
+        } else if self.layout.form_area.contains(position) {
+            let flag = state.f_form.clone();
+            if let Some(index) = hit_test_list(position, self.layout.form_area, &state.form_state) {
+                state.select_form_offset(index as isize - state.form_state.selected().unwrap_or(0) as isize);
+            }
+            app.focus.focus(&flag);
+        }
+        Vec::new()
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app: &mut App) {
+        let registry = app.ctx.command_registry.clone();
+        let layout = WorkflowEditorLayout::from(self.get_preferred_layout(app, area));
+        let theme = &*app.ctx.theme;
+        let Some(state) = app.workflows.editor.as_mut() else {
+            frame.render_widget(th::block(theme, Some("Workflow Editor"), false), area);
+            return;
+        };
+        state.refresh_if_changed(&registry);
+
+        let block = th::block(theme, Some("Workflow Editor"), false);
+        frame.render_widget(block, area);
+        render_header(frame, layout.header_area, state, theme);
+        render_outline(frame, layout.outline_area, state, theme);
+        render_form(frame, layout.form_area, state, theme);
+        render_validation(frame, layout.validation_area, state.validation(), theme);
+        render_footer(frame, &layout, state, theme);
+        render_overlay(frame, layout.form_area, state, theme);
+        self.layout = layout;
+    }
+
+    fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
+        let theme = &*app.ctx.theme;
+        let Some(state) = app.workflows.editor_state() else {
+            return Vec::new();
+        };
+        let keymap = &app.ctx.keymap;
+        let save = keymap.label(KeyAction::EditorSave);
+        let labels = if state.f_outline.get() {
+            [KeyAction::EditorAddStep, KeyAction::EditorAddInput, KeyAction::EditorRemove]
+        } else {
+            [
+                KeyAction::EditorFieldPicker,
+                KeyAction::EditorProviderValues,
+                KeyAction::EditorClearField,
+            ]
+        }
+        .map(|action| keymap.label(action));
+        let hints: &[(&str, &str)] = match &state.overlay {
+            Some(EditorOverlay::Text { .. }) => &[("Esc", " Cancel  "), ("Enter", " Apply")],
+            Some(EditorOverlay::CommandSearch { .. }) => &[("Esc", " Cancel  "), ("↑/↓", " Select  "), ("Enter", " Use command")],
+            Some(EditorOverlay::FieldPicker { .. }) => &[
+                ("Esc", " Cancel  "),
+                ("↑/↓", " Select  "),
+                ("←/→", " Collapse/expand  "),
+                ("Enter", " Insert binding"),
+            ],
+            None if state.f_outline.get() => &[
+                ("Esc", " Close  "),
+                (&labels[0], " Add step  "),
+                (&labels[1], " Add input  "),
+                (&labels[2], " Delete  "),
+                ("Shift+↑/↓", " Reorder  "),
+                (&save, " Save"),
+            ],
+            None if state.f_form.get() => &[
+                ("Esc", " Close  "),
+                ("Enter", " Edit  "),
+                (&labels[0], " Bind field  "),
+                (&labels[1], " Pick value  "),
+                (&labels[2], " Clear  "),
+                (&save, " Save"),
+            ],
+            None => &[("Esc", " Close  "), ("Enter", " Activate  "), (&save, " Save")],
+        };
+        th::build_hint_spans(theme, hints)
+    }
+
+    fn get_preferred_layout(&self, app: &App, area: Rect) -> Vec<Rect> {
+        let block = th::block(&*app.ctx.theme, Some("Workflow Editor"), false);
+        let inner = block.inner(area);
+        let main = Layout::vertical([
+            Constraint::Length(1), // header
+            Constraint::Min(6),    // outline + form
+            Constraint::Length(6), // validation
+            Constraint::Length(3), // footer
+        ])
+        .split(inner);
+        let content = Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)])
+            .spacing(Spacing::Overlap(1))
+            .split(main[1]);
+        let footer = Layout::horizontal([
+            Constraint::Length(12), // cancel
+            Constraint::Length(12), // save
+            Constraint::Length(2),  // padding
+            Constraint::Min(0),     // status line
+        ])
+        .split(main[3]);
+        vec![main[0], content[0], content[1], main[2], footer[0], footer[1], footer[3]]
+    }
+
+    fn on_route_exit(&mut self, app: &mut App) -> Vec<Effect> {
+        app.workflows.close_editor();
+        Vec::new()
+    }
+}
+
+impl WorkflowEditorComponent {
+    fn dispatch_key(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
+        let has_overlay = app.workflows.editor_state().is_some_and(|state| state.overlay.is_some());
+        if has_overlay {
+            return handle_overlay_key(app, key);
+        }
+        if app.ctx.keymap.matches(KeyAction::EditorSave, &key) {
+            return save(app);
+        }
+        match key.code {
+            KeyCode::Tab => {
+                app.focus.next();
+                return Vec::new();
+            }
+            KeyCode::BackTab => {
+                app.focus.prev();
+                return Vec::new();
+            }
+            KeyCode::Esc => return self.leave(app),
+            _ => {}
+        }
+
+        let Some(state) = app.workflows.editor_state() else {
+            return Vec::new();
+        };
+        if state.f_outline.get() {
+            handle_outline_key(app, key)
+        } else if state.f_form.get() {
+            handle_form_key(app, key)
+        } else if state.f_cancel_button.get() && is_activation_key(key.code) {
+            self.leave(app)
+        } else if state.f_save_button.get() && is_activation_key(key.code) {
+            save(app)
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn leave(&mut self, app: &mut App) -> Vec<Effect> {
+        let Some(state) = app.workflows.editor_state_mut() else {
+            return vec![Effect::SwitchTo(Route::Workflows)];
+        };
+        if state.request_discard() {
+            vec![Effect::SwitchTo(Route::Workflows)]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+fn is_activation_key(code: KeyCode) -> bool {
+    matches!(code, KeyCode::Enter | KeyCode::Char(' '))
+}
+
+fn save(app: &mut App) -> Vec<Effect> {
+    let Some(state) = app.workflows.editor_state_mut() else {
+        return Vec::new();
+    };
+    match state.save_effect() {
+        Ok(effect) => vec![effect],
+        Err(message) => {
+            state.set_status(message);
+            Vec::new()
+        }
+    }
+}
+
+fn handle_outline_key(app: &mut App, key: KeyEvent) -> Vec<Effect> {
+    let action = app.ctx.keymap.action(KeyScope::WorkflowOutline, &key);
+    let Some(state) = app.workflows.editor_state_mut() else {
+        return Vec::new();
+    };
+    match action {
+        Some(KeyAction::EditorAddStep) => open_command_search(state, CommandSearchPurpose::NewStep),
+        Some(KeyAction::EditorAddInput) => state.add_input(),
+        Some(KeyAction::EditorRemove) => state.remove_selected(),
+        _ => handle_outline_navigation_key(app, key),
+    }
+    Vec::new()
+}
+
+fn handle_outline_navigation_key(app: &mut App, key: KeyEvent) {
+    let Some(state) = app.workflows.editor_state_mut() else {
+        return;
+    };
+    let reorder = key.modifiers.intersects(KeyModifiers::SHIFT | KeyModifiers::ALT);
+    match key.code {
+        KeyCode::Up if reorder => state.move_selected(-1),
+        KeyCode::Down if reorder => state.move_selected(1),
+        KeyCode::Up => state.select_outline_offset(-1),
+        KeyCode::Down => state.select_outline_offset(1),
+        KeyCode::Enter | KeyCode::Right => {
+            let flag = state.f_form.clone();
+            app.focus.focus(&flag);
+        }
+        _ => {}
+    }
+}
+
+fn handle_form_key(app: &mut App, key: KeyEvent) -> Vec<Effect> {
+    let action = app.ctx.keymap.action(KeyScope::WorkflowForm, &key);
+    let Some(state) = app.workflows.editor_state_mut() else {
+        return Vec::new();
+    };
+    match action {
+        Some(KeyAction::EditorFieldPicker) => {
+            state.open_field_picker();
+            return Vec::new();
+        }
+        Some(KeyAction::EditorProviderValues) => {
+            match state.provider_request() {
+                Ok((provider_id, arguments)) => {
+                    app.workflows.open_selector_for_editor(provider_id, arguments);
+                    let mut effects = app.prepare_selector_fetch();
+                    effects.push(Effect::ShowModal(Modal::WorkflowCollector));
+                    return effects;
+                }
+                Err(message) => state.set_status(message),
+            }
+            return Vec::new();
+        }
+        Some(KeyAction::EditorClearField) => {
+            state.clear_selected();
+            return Vec::new();
+        }
+        _ => {}
+    }
+    match key.code {
+        KeyCode::Up => state.select_form_offset(-1),
+        KeyCode::Down => state.select_form_offset(1),
+        KeyCode::Left => {
+            let flag = state.f_outline.clone();
+            app.focus.focus(&flag);
+        }
+        KeyCode::Enter => match (state.selected_entry(), state.selected_row().map(|row| row.field)) {
+            (OutlineEntry::Step(index), Some(EditorField::StepRun)) => open_command_search(state, CommandSearchPurpose::ReplaceRun(index)),
+            (OutlineEntry::Input(index), Some(EditorField::InputProvider)) => {
+                open_command_search(state, CommandSearchPurpose::InputProvider(index))
+            }
+            (_, Some(EditorField::InputOptional)) => state.toggle_selected(),
+            (_, Some(_)) => state.begin_text_edit(),
+            (_, None) => {}
+        },
+        _ => {}
+    }
+    Vec::new()
+}
+
+fn open_command_search(state: &mut WorkflowEditorState, purpose: CommandSearchPurpose) {
+    state.overlay = Some(EditorOverlay::CommandSearch {
+        purpose,
+        query: Default::default(),
+        results: Vec::new(),
+        list_state: ListState::default(),
+    });
+}
+
+fn handle_overlay_key(app: &mut App, key: KeyEvent) -> Vec<Effect> {
+    let registry = app.ctx.command_registry.clone();
+    let Some(state) = app.workflows.editor_state_mut() else {
+        return Vec::new();
+    };
+    if key.code == KeyCode::Esc {
+        state.overlay = None;
+        return Vec::new();
+    }
+    match state.overlay.as_mut() {
+        Some(EditorOverlay::Text { input, .. }) => match key.code {
+            KeyCode::Enter => state.commit_text_edit(),
+            KeyCode::Left => input.move_left(),
+            KeyCode::Right => input.move_right(),
+            KeyCode::Home => input.set_cursor(0),
+            KeyCode::End => input.set_cursor(input.input().len()),
+            KeyCode::Backspace => input.backspace(),
+            KeyCode::Delete => input.delete(),
+            KeyCode::Char(character) if !key.modifiers.contains(KeyModifiers::CONTROL) => input.insert_char(character),
+            _ => {}
+        },
+        Some(EditorOverlay::CommandSearch {
+            purpose,
+            query,
+            results,
+            list_state,
+        }) => {
+            let purpose = *purpose;
+            let query_changed = match key.code {
+                KeyCode::Up => {
+                    list_state.select_previous();
+                    false
+                }
+                KeyCode::Down => {
+                    list_state.select_next();
+                    false
+                }
+                KeyCode::Left => {
+                    query.move_left();
+                    false
+                }
+                KeyCode::Right => {
+                    query.move_right();
+                    false
+                }
+                KeyCode::Backspace => {
+                    query.backspace();
+                    true
+                }
+                KeyCode::Char(character) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    query.insert_char(character);
+                    true
+                }
+                KeyCode::Enter => {
+                    let chosen = list_state
+                        .selected()
+                        .and_then(|index| results.get(index))
+                        .map(|result| result.canonical_id.clone());
+                    if let Some(command_id) = chosen {
+                        state.overlay = None;
+                        apply_command_choice(state, purpose, &command_id, &registry);
+                    }
+                    return Vec::new();
+                }
+                _ => false,
+            };
+            if query_changed {
+                // The in-memory search never awaits, so it resolves on the first poll.
+                *results = create_search_handle(registry.clone())
+                    .search(query.input())
+                    .now_or_never()
+                    .and_then(Result::ok)
+                    .unwrap_or_default();
+                list_state.select((!results.is_empty()).then_some(0));
+            }
+        }
+        Some(EditorOverlay::FieldPicker { pane, .. }) => match key.code {
+            KeyCode::Up => pane.select_prev(),
+            KeyCode::Down => pane.select_next(),
+            KeyCode::Left => pane.collapse_selected(),
+            KeyCode::Right => pane.expand_selected(),
+            KeyCode::Backspace => pane.pop_filter_char(),
+            KeyCode::Enter => {
+                if let Some(path) = pane.current_path() {
+                    state.apply_binding(&path);
+                }
+            }
+            KeyCode::Char(character) if !key.modifiers.contains(KeyModifiers::CONTROL) => pane.push_filter_char(character),
+            _ => {}
+        },
+        None => {}
+    }
+    Vec::new()
+}
+
+fn apply_command_choice(
+    state: &mut WorkflowEditorState,
+    purpose: CommandSearchPurpose,
+    command_id: &str,
+    registry: &std::sync::Arc<std::sync::Mutex<oatty_registry::CommandRegistry>>,
+) {
+    if let CommandSearchPurpose::InputProvider(index) = purpose {
+        state.set_input_provider(index, command_id);
+        return;
+    }
+    let spec: Option<CommandSpec> = parse_provider_group_and_command(command_id).and_then(|(group, name)| {
+        let registry = registry.lock().ok()?;
+        registry.find_by_group_and_cmd_cloned(&group, &name).ok()
+    });
+    let Some(spec) = spec else {
+        state.set_status(format!("Command '{command_id}' is no longer registered"));
+        return;
+    };
+    match purpose {
+        CommandSearchPurpose::NewStep => state.add_step(&spec),
+        CommandSearchPurpose::ReplaceRun(index) => state.set_step_command(index, &spec),
+        CommandSearchPurpose::InputProvider(_) => {}
+    }
+}
+
+fn hit_test_list(position: Position, area: Rect, list_state: &ListState) -> Option<usize> {
+    let first_row = area.y + 1;
+    if position.y < first_row || position.y >= area.y + area.height.saturating_sub(1) {
+        return None;
+    }
+    Some(list_state.offset() + (position.y - first_row) as usize)
+}
+
+fn render_header(frame: &mut Frame, area: Rect, state: &WorkflowEditorState, theme: &dyn Theme) {
+    let definition = state.definition();
+    let mut spans = vec![
+        Span::styled(
+            format!("Workflow: {}", definition.workflow),
+            theme.text_primary_style().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" • {} inputs • {} steps", definition.inputs.len(), definition.steps.len()),
+            theme.text_secondary_style(),
+        ),
+    ];
+    if state.is_dirty() {
+        spans.push(Span::styled(" • unsaved", theme.status_warning()));
+    }
+    if let Some(path) = state.origin_path() {
+        spans.push(Span::styled(format!(" • {}", path.display()), theme.text_muted_style()));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn render_outline(frame: &mut Frame, area: Rect, state: &mut WorkflowEditorState, theme: &dyn Theme) {
+    let focused = state.f_outline.get();
+    let definition = state.definition();
+    let items: Vec<ListItem> = state
+        .outline_entries()
+        .into_iter()
+        .map(|entry| match entry {
+            OutlineEntry::Workflow => ListItem::new(Line::from(Span::styled("Workflow settings", theme.syntax_keyword_style()))),
+            OutlineEntry::Input(index) => {
+                let name = definition
+                    .inputs
+                    .get_index(index)
+                    .map(|(name, _)| name.as_str())
+                    .unwrap_or_default();
+                ListItem::new(Line::from(vec![
+                    Span::styled("input  ", theme.text_muted_style()),
+                    Span::styled(name.to_string(), theme.syntax_type_style()),
+                ]))
+            }
+            OutlineEntry::Step(index) => {
+                let step = &definition.steps[index];
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{:>2}. ", index + 1), theme.text_muted_style()),
+                    Span::styled(step.id.clone(), theme.syntax_function_style()),
+                    Span::styled(format!("  {}", step.run), theme.text_secondary_style()),
+                ]))
+            }
+        })
+        .collect();
+    let block = th::block(theme, Some("Outline"), focused);
+    let list = create_list_with_highlight(items, theme, focused, Some(block));
+    frame.render_stateful_widget(list, area, &mut state.outline_state);
+}
+
+fn render_form(frame: &mut Frame, area: Rect, state: &mut WorkflowEditorState, theme: &dyn Theme) {
+    let focused = state.f_form.get();
+    let rows = state.form_rows();
+    let label_width = rows.iter().map(|row| row.label.len()).max().unwrap_or(0) + 2;
+    let items: Vec<ListItem> = rows.iter().map(|row| form_row_item(row, label_width, theme)).collect();
+    let title = match state.selected_entry() {
+        OutlineEntry::Workflow => "Workflow".to_string(),
+        OutlineEntry::Input(_) => "Input".to_string(),
+        OutlineEntry::Step(index) => state
+            .definition()
+            .steps
+            .get(index)
+            .and_then(|step| state.command_spec(&step.run))
+            .map(|spec| format!("Step • {}", spec.summary))
+            .unwrap_or_else(|| "Step".to_string()),
+    };
+    let block = th::block(theme, Some(&title), focused);
+    let list = create_list_with_highlight(items, theme, focused, Some(block));
+    frame.render_stateful_widget(list, area, &mut state.form_state);
+}
+
+fn form_row_item<'a>(row: &EditorFormRow, label_width: usize, theme: &dyn Theme) -> ListItem<'a> {
+    let label_style = if row.required {
+        theme.syntax_keyword_style()
+    } else {
+        theme.syntax_type_style()
+    };
+    let mut spans = vec![Span::styled(format!("{:<label_width$}", row.label), label_style)];
+    if row.value.is_empty() {
+        let placeholder = if row.required { "required" } else { "—" };
+        spans.push(Span::styled(placeholder, theme.text_muted_style()));
+    } else if row.value.contains("${{") {
+        spans.push(Span::styled(row.value.clone(), theme.syntax_string_style()));
+    } else {
+        spans.push(Span::styled(row.value.clone(), theme.text_primary_style()));
+    }
+    if row.provider.is_some() {
+        spans.push(Span::styled("  ⇄", theme.text_secondary_style()));
+    }
+    if let Some(description) = &row.description {
+        spans.push(Span::styled(format!("  {description}"), theme.text_muted_style()));
+    }
+    ListItem::new(Line::from(spans))
+}
+
+fn render_validation(frame: &mut Frame, area: Rect, validation: &EditorValidation, theme: &dyn Theme) {
+    let mut lines = Vec::new();
+    match validation {
+        EditorValidation::Pending => lines.push(Line::from(Span::styled("Validating…", theme.text_muted_style()))),
+        EditorValidation::Valid { warnings } => {
+            lines.push(Line::from(Span::styled("✓ Valid", theme.status_success())));
+            lines.extend(
+                warnings
+                    .iter()
+                    .map(|warning| Line::from(Span::styled(format!("⚠ {warning}"), theme.status_warning()))),
+            );
+        }
+        EditorValidation::Invalid { message, violations } => {
+            lines.push(Line::from(Span::styled(format!("✗ {message}"), theme.status_error())));
+            lines.extend(
+                violations
+                    .iter()
+                    .filter(|violation| *violation != message)
+                    .map(|violation| Line::from(Span::styled(format!("  {violation}"), theme.text_secondary_style()))),
+            );
+        }
+    }
+    let block = th::block(theme, Some("Validation"), false);
+    frame.render_widget(Paragraph::new(lines).block(block).wrap(Wrap { trim: true }), area);
+}
+
+fn render_footer(frame: &mut Frame, layout: &WorkflowEditorLayout, state: &WorkflowEditorState, theme: &dyn Theme) {
+    let cancel_focused = state.f_cancel_button.get();
+    let cancel_options = ButtonRenderOptions::new(true, cancel_focused, cancel_focused, Borders::ALL, ButtonType::Secondary);
+    th::render_button(frame, layout.cancel_button_area, "Close", theme, cancel_options);
+
+    let save_focused = state.f_save_button.get();
+    let save_options = ButtonRenderOptions::new(
+        state.validation().is_valid(),
+        save_focused,
+        save_focused,
+        Borders::ALL,
+        ButtonType::Primary,
+    );
+    th::render_button(frame, layout.save_button_area, "Save", theme, save_options);
+
+    if let Some(status) = state.status() {
+        let mut status_area = layout.status_line_area;
+        status_area.y += 1;
+        let paragraph = Paragraph::new(Span::styled(status.to_string(), theme.text_secondary_style())).wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, status_area);
+    }
+}
+
+fn render_overlay(frame: &mut Frame, form_area: Rect, state: &mut WorkflowEditorState, theme: &dyn Theme) {
+    let Some(overlay) = state.overlay.as_mut() else {
+        return;
+    };
+    match overlay {
+        EditorOverlay::Text { field, input } => {
+            let area = Rect {
+                y: form_area.y + form_area.height.saturating_sub(3),
+                height: 3.min(form_area.height),
+                ..form_area
+            };
+            frame.render_widget(Clear, area);
+            let title = format!("Edit {}", field_label(field));
+            let block = th::block(theme, Some(&title), true);
+            let inner = block.inner(area);
+            frame.render_widget(Paragraph::new(input.input().to_string()).block(block), area);
+            frame.set_cursor_position((inner.x + input.cursor_columns() as u16, inner.y));
+        }
+        EditorOverlay::CommandSearch {
+            query,
+            results,
+            list_state,
+            ..
+        } => {
+            let area = centered_rect(90, 80, form_area);
+            frame.render_widget(Clear, area);
+            let block = th::block(theme, Some("Pick a command"), true);
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            let [query_area, results_area] = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(inner);
+            frame.render_widget(
+                Paragraph::new(Line::from(vec![
+                    Span::styled("Search: ", theme.text_muted_style()),
+                    Span::styled(query.input().to_string(), theme.text_primary_style()),
+                ])),
+                query_area,
+            );
+            frame.set_cursor_position((query_area.x + 8 + query.cursor_columns() as u16, query_area.y));
+            let items: Vec<ListItem> = results
+                .iter()
+                .map(|result| {
+                    ListItem::new(Line::from(vec![
+                        Span::styled(result.canonical_id.clone(), theme.syntax_function_style()),
+                        Span::styled(format!("  {}", result.summary), theme.text_muted_style()),
+                    ]))
+                })
+                .collect();
+            let list = create_list_with_highlight(items, theme, true, None);
+            frame.render_stateful_widget(list, results_area, list_state);
+        }
+        EditorOverlay::FieldPicker { field, pane } => {
+            let area = centered_rect(90, 80, form_area);
+            frame.render_widget(Clear, area);
+            let title = format!("Bind {}", field_label(field));
+            let block = th::block(theme, Some(&title), true);
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            pane.render_inline(frame, inner, theme, true);
+        }
+    }
+}
+
+fn field_label(field: &EditorField) -> &str {
+    match field {
+        EditorField::WorkflowId | EditorField::StepId => "id",
+        EditorField::WorkflowTitle => "title",
+        EditorField::WorkflowDescription | EditorField::InputDescription | EditorField::StepDescription => "description",
+        EditorField::InputName => "name",
+        EditorField::InputLabel => "label",
+        EditorField::InputType => "type",
+        EditorField::InputOptional => "optional",
+        EditorField::InputDefault => "default",
+        EditorField::InputProvider => "provider",
+        EditorField::StepRun => "run",
+        EditorField::StepCondition => "if",
+        EditorField::StepDependsOn => "depends_on",
+        EditorField::StepArgument(key) => key,
+    }
+}
@@ -0,0 +1,5 @@
+mod editor_component;
+pub mod state;
+
+pub use editor_component::WorkflowEditorComponent;
+pub use state::WorkflowEditorState;
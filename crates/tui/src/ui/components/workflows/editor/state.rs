@@ -0,0 +1,1141 @@
+//! Draft state for the guided workflow editor.
+//!
+//! The editor mutates a [`WorkflowDefinition`] in place. Every change bumps a revision counter so
+//! the component can re-run the `workflow_validate` checks once per edit, and step rows are
+//! derived from the cached [`CommandSpec`] of each step's `run` command.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use indexmap::IndexMap;
+use oatty_engine::provider::parse_provider_group_and_command;
+use oatty_mcp::{WorkflowManifestFormat, WorkflowValidateRequest, serialize_definition, validate_workflow};
+use oatty_registry::CommandRegistry;
+use oatty_types::workflow::{
+    WorkflowDefaultSource, WorkflowDefinition, WorkflowInputDefault, WorkflowInputDefinition, WorkflowStepDefinition, WorkflowValueProvider,
+};
+use oatty_types::{CommandSpec, Effect, SearchResult, ValueProvider};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use ratatui::layout::Rect;
+use ratatui::widgets::ListState;
+use serde_json::{Map as JsonMap, Value};
+
+use crate::ui::components::common::TextInputState;
+use crate::ui::components::workflows::field_picker::{FieldPickerPane, sample_value_from_schema};
+
+/// Identifier given to drafts created from scratch.
+const NEW_WORKFLOW_IDENTIFIER: &str = "new_workflow";
+
+/// Entry in the editor outline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutlineEntry {
+    /// Workflow identifier, title, and description.
+    Workflow,
+    /// Input at the given position in `inputs`.
+    Input(usize),
+    /// Step at the given position in `steps`.
+    Step(usize),
+}
+
+/// Field edited by a form row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditorField {
+    WorkflowId,
+    WorkflowTitle,
+    WorkflowDescription,
+    InputName,
+    InputLabel,
+    InputDescription,
+    InputType,
+    InputOptional,
+    InputDefault,
+    InputProvider,
+    StepId,
+    StepRun,
+    StepDescription,
+    StepCondition,
+    StepDependsOn,
+    /// Positional argument or flag stored under this key in the step's `with` map.
+    StepArgument(String),
+}
+
+/// One row of the form pane.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditorFormRow {
+    pub field: EditorField,
+    pub label: String,
+    pub value: String,
+    /// Required by the step's command.
+    pub required: bool,
+    /// Provider command backing the argument, when the command declares one.
+    pub provider: Option<String>,
+    /// Value type declared by the command (`string`, `boolean`, ...).
+    pub type_hint: Option<String>,
+    pub description: Option<String>,
+}
+
+impl EditorFormRow {
+    fn new(field: EditorField, label: &str, value: String) -> Self {
+        Self {
+            field,
+            label: label.to_string(),
+            value,
+            required: false,
+            provider: None,
+            type_hint: None,
+            description: None,
+        }
+    }
+
+    /// Whether the row accepts a `${{ ... }}` binding from the field picker.
+    pub fn accepts_binding(&self) -> bool {
+        matches!(self.field, EditorField::StepArgument(_) | EditorField::StepCondition)
+    }
+}
+
+/// What a command chosen in the search overlay is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandSearchPurpose {
+    /// Append a new step running the command.
+    NewStep,
+    /// Replace the `run` command of the step at this index.
+    ReplaceRun(usize),
+    /// Use the command as the provider of the input at this index.
+    InputProvider(usize),
+}
+
+/// Overlay drawn above the form while it owns the keyboard.
+#[derive(Debug)]
+pub enum EditorOverlay {
+    /// Single-line text editing of a form field.
+    Text { field: EditorField, input: TextInputState },
+    /// Command search backed by the registry search handle.
+    CommandSearch {
+        purpose: CommandSearchPurpose,
+        query: TextInputState,
+        results: Vec<SearchResult>,
+        list_state: ListState,
+    },
+    /// Binding picker over upstream step outputs and workflow inputs.
+    FieldPicker { field: EditorField, pane: FieldPickerPane },
+}
+
+/// Outcome of the last validation pass.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum EditorValidation {
+    #[default]
+    Pending,
+    /// The draft passes validation; warnings and lint findings may remain.
+    Valid { warnings: Vec<String> },
+    /// The draft would be rejected by `workflow_validate`.
+    Invalid { message: String, violations: Vec<String> },
+}
+
+impl EditorValidation {
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid { .. })
+    }
+}
+
+/// Editable workflow draft plus the editor's view state.
+#[derive(Debug)]
+pub struct WorkflowEditorState {
+    definition: WorkflowDefinition,
+    /// Manifest path the draft was loaded from or last saved to.
+    origin_path: Option<PathBuf>,
+    format: WorkflowManifestFormat,
+    revision: u64,
+    validated_revision: Option<u64>,
+    saved_revision: u64,
+    /// Command specs keyed by step `run` value.
+    command_specs: HashMap<String, CommandSpec>,
+    validation: EditorValidation,
+    /// Step index and `with` key awaiting a provider selection.
+    pending_provider_field: Option<(usize, String)>,
+    status: Option<String>,
+    discard_armed: bool,
+    pub outline_state: ListState,
+    pub form_state: ListState,
+    pub overlay: Option<EditorOverlay>,
+    pub container_focus: FocusFlag,
+    pub f_outline: FocusFlag,
+    pub f_form: FocusFlag,
+    pub f_cancel_button: FocusFlag,
+    pub f_save_button: FocusFlag,
+}
+
+impl WorkflowEditorState {
+    fn with_definition(definition: WorkflowDefinition, origin_path: Option<PathBuf>, format: WorkflowManifestFormat) -> Self {
+        let mut outline_state = ListState::default();
+        outline_state.select(Some(0));
+        let mut form_state = ListState::default();
+        form_state.select(Some(0));
+        Self {
+            definition,
+            origin_path,
+            format,
+            revision: 0,
+            validated_revision: None,
+            saved_revision: 0,
+            command_specs: HashMap::new(),
+            validation: EditorValidation::Pending,
+            pending_provider_field: None,
+            status: None,
+            discard_armed: false,
+            outline_state,
+            form_state,
+            overlay: None,
+            container_focus: FocusFlag::new().with_name("workflow.editor"),
+            f_outline: FocusFlag::new().with_name("workflow.editor.outline"),
+            f_form: FocusFlag::new().with_name("workflow.editor.form"),
+            f_cancel_button: FocusFlag::new().with_name("workflow.editor.actions.cancel"),
+            f_save_button: FocusFlag::new().with_name("workflow.editor.actions.save"),
+        }
+    }
+
+    /// Starts an empty draft that has not been saved yet.
+    pub fn new_draft() -> Self {
+        let definition = WorkflowDefinition {
+            workflow: NEW_WORKFLOW_IDENTIFIER.to_string(),
+            title: None,
+            description: None,
+            inputs: IndexMap::new(),
+            steps: Vec::new(),
+            final_output: None,
+            requires: None,
+            schedule: None,
+        };
+        let mut state = Self::with_definition(definition, None, WorkflowManifestFormat::Yaml);
+        // A fresh draft counts as unsaved so leaving it asks for confirmation once.
+        state.revision = 1;
+        state
+    }
+
+    /// Opens an existing manifest for editing; saving keeps its format.
+    pub fn from_manifest(definition: WorkflowDefinition, path: PathBuf, format: WorkflowManifestFormat) -> Self {
+        Self::with_definition(definition, Some(path), format)
+    }
+
+    pub fn definition(&self) -> &WorkflowDefinition {
+        &self.definition
+    }
+
+    pub fn origin_path(&self) -> Option<&Path> {
+        self.origin_path.as_deref()
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.revision != self.saved_revision
+    }
+
+    pub fn validation(&self) -> &EditorValidation {
+        &self.validation
+    }
+
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    pub fn set_status(&mut self, message: impl Into<String>) {
+        self.status = Some(message.into());
+    }
+
+    /// Cached spec for a step's `run` command.
+    pub fn command_spec(&self, run: &str) -> Option<&CommandSpec> {
+        self.command_specs.get(run)
+    }
+
+    fn mark_changed(&mut self) {
+        self.revision += 1;
+        self.discard_armed = false;
+        self.status = None;
+    }
+
+    /// Returns `true` when leaving should proceed; the first request on a dirty draft only arms
+    /// the discard and reports it in the status line.
+    pub fn request_discard(&mut self) -> bool {
+        if !self.is_dirty() || self.discard_armed {
+            return true;
+        }
+        self.discard_armed = true;
+        self.status = Some("Unsaved changes. Press Esc again to discard them.".to_string());
+        false
+    }
+
+    // ----- Outline -----
+
+    pub fn outline_entries(&self) -> Vec<OutlineEntry> {
+        let mut entries = vec![OutlineEntry::Workflow];
+        entries.extend((0..self.definition.inputs.len()).map(OutlineEntry::Input));
+        entries.extend((0..self.definition.steps.len()).map(OutlineEntry::Step));
+        entries
+    }
+
+    pub fn selected_entry(&self) -> OutlineEntry {
+        let entries = self.outline_entries();
+        let index = self.outline_state.selected().unwrap_or(0).min(entries.len() - 1);
+        entries[index]
+    }
+
+    fn select_entry(&mut self, entry: OutlineEntry) {
+        let index = self.outline_entries().iter().position(|candidate| *candidate == entry).unwrap_or(0);
+        self.outline_state.select(Some(index));
+        self.form_state.select(Some(0));
+    }
+
+    pub fn select_outline_offset(&mut self, delta: isize) {
+        let count = self.outline_entries().len();
+        let current = self.outline_state.selected().unwrap_or(0);
+        let next = current.saturating_add_signed(delta).min(count - 1);
+        self.outline_state.select(Some(next));
+        self.form_state.select(Some(0));
+    }
+
+    pub fn select_form_offset(&mut self, delta: isize) {
+        let count = self.form_rows().len();
+        if count == 0 {
+            return;
+        }
+        let current = self.form_state.selected().unwrap_or(0);
+        self.form_state.select(Some(current.saturating_add_signed(delta).min(count - 1)));
+    }
+
+    /// Adds an input with a unique placeholder name and selects it.
+    pub fn add_input(&mut self) {
+        let name = unique_identifier("input", |candidate| self.definition.inputs.contains_key(candidate));
+        self.definition.inputs.insert(
+            name,
+            WorkflowInputDefinition {
+                r#type: Some("string".to_string()),
+                ..WorkflowInputDefinition::default()
+            },
+        );
+        self.mark_changed();
+        self.select_entry(OutlineEntry::Input(self.definition.inputs.len() - 1));
+    }
+
+    /// Adds a step running `spec` after the selected step, pre-filling required arguments from
+    /// same-named inputs or command defaults.
+    pub fn add_step(&mut self, spec: &CommandSpec) {
+        let run = spec.canonical_id();
+        let base = spec
+            .name
+            .chars()
+            .map(|character| {
+                if character.is_ascii_alphanumeric() {
+                    character.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        let id = unique_identifier(&base, |candidate| self.definition.steps.iter().any(|step| step.id == candidate));
+
+        let mut with = IndexMap::new();
+        for argument in &spec.positional_args {
+            if self.definition.inputs.contains_key(&argument.name) {
+                with.insert(argument.name.clone(), input_binding(&argument.name));
+            }
+        }
+        for flag in spec.flags.iter().filter(|flag| flag.required) {
+            if self.definition.inputs.contains_key(&flag.name) {
+                with.insert(flag.name.clone(), input_binding(&flag.name));
+            } else if let Some(default_value) = &flag.default_value {
+                with.insert(flag.name.clone(), parse_field_value(default_value, Some(flag.r#type.as_str())));
+            }
+        }
+
+        let insert_at = match self.selected_entry() {
+            OutlineEntry::Step(index) => index + 1,
+            _ => self.definition.steps.len(),
+        };
+        self.definition.steps.insert(
+            insert_at,
+            WorkflowStepDefinition {
+                id,
+                run: run.clone(),
+                description: None,
+                depends_on: Vec::new(),
+                with,
+                body: Value::Null,
+                r#if: None,
+                repeat: None,
+                output_contract: None,
+            },
+        );
+        self.command_specs.insert(run, spec.clone());
+        self.mark_changed();
+        self.select_entry(OutlineEntry::Step(insert_at));
+    }
+
+    /// Points the step at a different command, keeping its existing arguments.
+    pub fn set_step_command(&mut self, index: usize, spec: &CommandSpec) {
+        let Some(step) = self.definition.steps.get_mut(index) else {
+            return;
+        };
+        step.run = spec.canonical_id();
+        self.command_specs.insert(step.run.clone(), spec.clone());
+        self.mark_changed();
+    }
+
+    /// Sets the provider command of the input at `index`.
+    pub fn set_input_provider(&mut self, index: usize, command_id: &str) {
+        let Some((_, input)) = self.definition.inputs.get_index_mut(index) else {
+            return;
+        };
+        match &mut input.provider {
+            Some(WorkflowValueProvider::Detailed(detail)) => detail.id = command_id.to_string(),
+            provider => *provider = Some(WorkflowValueProvider::Id(command_id.to_string())),
+        }
+        self.mark_changed();
+    }
+
+    /// Removes the selected input or step.
+    pub fn remove_selected(&mut self) {
+        match self.selected_entry() {
+            OutlineEntry::Workflow => return,
+            OutlineEntry::Input(index) => {
+                self.definition.inputs.shift_remove_index(index);
+            }
+            OutlineEntry::Step(index) => {
+                self.definition.steps.remove(index);
+            }
+        }
+        self.mark_changed();
+        let last = self.outline_entries().len() - 1;
+        let selected = self.outline_state.selected().unwrap_or(0).min(last);
+        self.outline_state.select(Some(selected));
+        self.form_state.select(Some(0));
+    }
+
+    /// Moves the selected input or step up (`-1`) or down (`1`) within its section.
+    pub fn move_selected(&mut self, delta: isize) {
+        match self.selected_entry() {
+            OutlineEntry::Workflow => {}
+            OutlineEntry::Input(index) => {
+                let Some(target) = index
+                    .checked_add_signed(delta)
+                    .filter(|target| *target < self.definition.inputs.len())
+                else {
+                    return;
+                };
+                self.definition.inputs.move_index(index, target);
+                self.mark_changed();
+                self.select_entry(OutlineEntry::Input(target));
+            }
+            OutlineEntry::Step(index) => {
+                let Some(target) = index
+                    .checked_add_signed(delta)
+                    .filter(|target| *target < self.definition.steps.len())
+                else {
+                    return;
+                };
+                self.definition.steps.swap(index, target);
+                self.mark_changed();
+                self.select_entry(OutlineEntry::Step(target));
+            }
+        }
+    }
+
+    // ----- Form -----
+
+    /// Rows for the selected outline entry.
+    pub fn form_rows(&self) -> Vec<EditorFormRow> {
+        match self.selected_entry() {
+            OutlineEntry::Workflow => {
+                let definition = &self.definition;
+                vec![
+                    EditorFormRow::new(EditorField::WorkflowId, "id", definition.workflow.clone()),
+                    EditorFormRow::new(EditorField::WorkflowTitle, "title", definition.title.clone().unwrap_or_default()),
+                    EditorFormRow::new(
+                        EditorField::WorkflowDescription,
+                        "description",
+                        definition.description.clone().unwrap_or_default(),
+                    ),
+                ]
+            }
+            OutlineEntry::Input(index) => {
+                let Some((name, input)) = self.definition.inputs.get_index(index) else {
+                    return Vec::new();
+                };
+                vec![
+                    EditorFormRow::new(EditorField::InputName, "name", name.clone()),
+                    EditorFormRow::new(EditorField::InputLabel, "label", input.name.clone().unwrap_or_default()),
+                    EditorFormRow::new(
+                        EditorField::InputDescription,
+                        "description",
+                        input.description.clone().unwrap_or_default(),
+                    ),
+                    EditorFormRow::new(EditorField::InputType, "type", input.r#type.clone().unwrap_or_default()),
+                    EditorFormRow::new(
+                        EditorField::InputOptional,
+                        "optional",
+                        if input.optional { "yes" } else { "no" }.to_string(),
+                    ),
+                    EditorFormRow::new(EditorField::InputDefault, "default", describe_default(input.default.as_ref())),
+                    EditorFormRow::new(
+                        EditorField::InputProvider,
+                        "provider",
+                        provider_identifier(input).unwrap_or_default(),
+                    ),
+                ]
+            }
+            OutlineEntry::Step(index) => self.step_rows(index),
+        }
+    }
+
+    fn step_rows(&self, index: usize) -> Vec<EditorFormRow> {
+        let Some(step) = self.definition.steps.get(index) else {
+            return Vec::new();
+        };
+        let mut rows = vec![
+            EditorFormRow::new(EditorField::StepId, "id", step.id.clone()),
+            EditorFormRow::new(EditorField::StepRun, "run", step.run.clone()),
+            EditorFormRow::new(
+                EditorField::StepDescription,
+                "description",
+                step.description.clone().unwrap_or_default(),
+            ),
+            EditorFormRow::new(EditorField::StepCondition, "if", step.r#if.clone().unwrap_or_default()),
+            EditorFormRow::new(EditorField::StepDependsOn, "depends_on", step.depends_on.join(", ")),
+        ];
+
+        let argument_value = |name: &str| step.with.get(name).map(display_value).unwrap_or_default();
+        let mut declared = Vec::new();
+        if let Some(spec) = self.command_specs.get(&step.run) {
+            for argument in &spec.positional_args {
+                let mut row = EditorFormRow::new(
+                    EditorField::StepArgument(argument.name.clone()),
+                    &argument.name,
+                    argument_value(&argument.name),
+                );
+                row.required = true;
+                row.provider = provider_command_id(argument.provider.as_ref());
+                row.description = argument.help.clone();
+                rows.push(row);
+                declared.push(argument.name.as_str());
+            }
+            for flag in &spec.flags {
+                let mut row = EditorFormRow::new(EditorField::StepArgument(flag.name.clone()), &flag.name, argument_value(&flag.name));
+                row.required = flag.required;
+                row.provider = provider_command_id(flag.provider.as_ref());
+                row.type_hint = Some(flag.r#type.clone()).filter(|kind| !kind.is_empty());
+                row.description = flag.description.clone();
+                rows.push(row);
+                declared.push(flag.name.as_str());
+            }
+        }
+        for (key, value) in &step.with {
+            if declared.contains(&key.as_str()) {
+                continue;
+            }
+            let mut row = EditorFormRow::new(EditorField::StepArgument(key.clone()), key, display_value(value));
+            row.description = Some("Not declared by the command".to_string());
+            rows.push(row);
+        }
+        rows
+    }
+
+    pub fn selected_row(&self) -> Option<EditorFormRow> {
+        self.form_rows().into_iter().nth(self.form_state.selected().unwrap_or(0))
+    }
+
+    /// Opens the text overlay for the selected row, seeded with its current value.
+    pub fn begin_text_edit(&mut self) {
+        let Some(row) = self.selected_row() else {
+            return;
+        };
+        let mut input = TextInputState::new();
+        input.set_input(row.value);
+        input.set_cursor(input.input().len());
+        self.overlay = Some(EditorOverlay::Text { field: row.field, input });
+    }
+
+    /// Applies the text overlay to its field and closes it.
+    pub fn commit_text_edit(&mut self) {
+        let Some(EditorOverlay::Text { field, input }) = self.overlay.take() else {
+            return;
+        };
+        let type_hint = self
+            .form_rows()
+            .into_iter()
+            .find(|row| row.field == field)
+            .and_then(|row| row.type_hint);
+        self.set_field_value(&field, input.input(), type_hint.as_deref());
+    }
+
+    /// Flips a yes/no field.
+    pub fn toggle_selected(&mut self) {
+        let OutlineEntry::Input(index) = self.selected_entry() else {
+            return;
+        };
+        if let Some((_, input)) = self.definition.inputs.get_index_mut(index) {
+            input.optional = !input.optional;
+            self.mark_changed();
+        }
+    }
+
+    /// Clears the selected field; step arguments are removed from `with`.
+    pub fn clear_selected(&mut self) {
+        if let Some(row) = self.selected_row() {
+            self.set_field_value(&row.field, "", None);
+        }
+    }
+
+    /// Writes `text` into `field` of the selected entry. Empty text clears optional fields.
+    pub fn set_field_value(&mut self, field: &EditorField, text: &str, type_hint: Option<&str>) {
+        let trimmed = text.trim();
+        let optional_text = || Some(trimmed.to_string()).filter(|value| !value.is_empty());
+        match self.selected_entry() {
+            OutlineEntry::Workflow => match field {
+                EditorField::WorkflowId => self.definition.workflow = trimmed.to_string(),
+                EditorField::WorkflowTitle => self.definition.title = optional_text(),
+                EditorField::WorkflowDescription => self.definition.description = optional_text(),
+                _ => return,
+            },
+            OutlineEntry::Input(index) => {
+                if *field == EditorField::InputName {
+                    if trimmed.is_empty() || self.definition.inputs.contains_key(trimmed) {
+                        self.status = Some(format!("Input name '{trimmed}' is empty or already used"));
+                        return;
+                    }
+                    let Some((_, input)) = self.definition.inputs.shift_remove_index(index) else {
+                        return;
+                    };
+                    self.definition.inputs.shift_insert(index, trimmed.to_string(), input);
+                } else {
+                    let Some((_, input)) = self.definition.inputs.get_index_mut(index) else {
+                        return;
+                    };
+                    match field {
+                        EditorField::InputLabel => input.name = optional_text(),
+                        EditorField::InputDescription => input.description = optional_text(),
+                        EditorField::InputType => input.r#type = optional_text(),
+                        EditorField::InputOptional => input.optional = matches!(trimmed, "yes" | "true"),
+                        EditorField::InputDefault => {
+                            input.default = optional_text().map(|value| WorkflowInputDefault {
+                                from: WorkflowDefaultSource::Literal,
+                                value: Some(parse_field_value(&value, input.r#type.as_deref())),
+                            })
+                        }
+                        EditorField::InputProvider => {
+                            input.provider = optional_text().map(WorkflowValueProvider::Id);
+                        }
+                        _ => return,
+                    }
+                }
+            }
+            OutlineEntry::Step(index) => {
+                if *field == EditorField::StepId {
+                    self.rename_step(index, trimmed);
+                    return;
+                }
+                let Some(step) = self.definition.steps.get_mut(index) else {
+                    return;
+                };
+                match field {
+                    EditorField::StepRun => step.run = trimmed.to_string(),
+                    EditorField::StepDescription => step.description = optional_text(),
+                    EditorField::StepCondition => step.r#if = optional_text(),
+                    EditorField::StepDependsOn => {
+                        step.depends_on = trimmed
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|dependency| !dependency.is_empty())
+                            .map(str::to_string)
+                            .collect();
+                    }
+                    EditorField::StepArgument(key) => {
+                        if trimmed.is_empty() {
+                            step.with.shift_remove(key);
+                        } else {
+                            step.with.insert(key.clone(), parse_field_value(trimmed, type_hint));
+                        }
+                    }
+                    _ => return,
+                }
+            }
+        }
+        self.mark_changed();
+    }
+
+    /// Renames a step and rewrites `depends_on` entries that referenced the old identifier.
+    fn rename_step(&mut self, index: usize, new_id: &str) {
+        if new_id.is_empty()
+            || self
+                .definition
+                .steps
+                .iter()
+                .enumerate()
+                .any(|(other, step)| other != index && step.id == new_id)
+        {
+            self.status = Some(format!("Step id '{new_id}' is empty or already used"));
+            return;
+        }
+        let Some(old_id) = self.definition.steps.get(index).map(|step| step.id.clone()) else {
+            return;
+        };
+        for step in &mut self.definition.steps {
+            if step.id == old_id {
+                step.id = new_id.to_string();
+            }
+            for dependency in &mut step.depends_on {
+                if *dependency == old_id {
+                    *dependency = new_id.to_string();
+                }
+            }
+        }
+        self.mark_changed();
+    }
+
+    // ----- Bindings -----
+
+    /// Inputs and upstream step outputs available to the step at `index`, shaped for the field
+    /// picker. Step outputs are sampled from the command output schema when one is declared.
+    pub fn binding_context(&self, index: usize) -> (JsonMap<String, Value>, JsonMap<String, Value>) {
+        let inputs = self
+            .definition
+            .inputs
+            .iter()
+            .map(|(name, input)| (name.clone(), sample_value_for_type(input.r#type.as_deref())))
+            .collect();
+        let steps = self
+            .definition
+            .steps
+            .iter()
+            .take(index)
+            .map(|step| {
+                let sample = self
+                    .command_specs
+                    .get(&step.run)
+                    .and_then(output_schema)
+                    .map(sample_value_from_schema)
+                    .unwrap_or(Value::Null);
+                (step.id.clone(), sample)
+            })
+            .collect();
+        (inputs, steps)
+    }
+
+    /// Opens the field picker for the selected row when it accepts bindings.
+    pub fn open_field_picker(&mut self) -> bool {
+        let (OutlineEntry::Step(index), Some(row)) = (self.selected_entry(), self.selected_row()) else {
+            return false;
+        };
+        if !row.accepts_binding() {
+            self.status = Some("Bindings apply to step arguments and conditions".to_string());
+            return false;
+        }
+        let (inputs, steps) = self.binding_context(index);
+        if inputs.is_empty() && steps.is_empty() {
+            self.status = Some("No inputs or upstream steps to bind".to_string());
+            return false;
+        }
+        let mut pane = FieldPickerPane::default();
+        pane.sync_from_context(inputs, steps);
+        self.overlay = Some(EditorOverlay::FieldPicker { field: row.field, pane });
+        true
+    }
+
+    /// Writes `${{ <path> }}` into the field the picker was opened for and closes it.
+    pub fn apply_binding(&mut self, path: &str) {
+        let Some(EditorOverlay::FieldPicker { field, .. }) = self.overlay.take() else {
+            return;
+        };
+        self.set_field_value(&field, &format!("${{{{ {path} }}}}"), None);
+    }
+
+    // ----- Providers -----
+
+    /// Provider command and arguments for the selected argument row. Provider arguments are bound
+    /// from literal sibling values; templated or missing values are reported as errors.
+    pub fn provider_request(&mut self) -> Result<(String, JsonMap<String, Value>), String> {
+        let (OutlineEntry::Step(index), Some(row)) = (self.selected_entry(), self.selected_row()) else {
+            return Err("Select a step argument with a provider".to_string());
+        };
+        let EditorField::StepArgument(key) = &row.field else {
+            return Err("Select a step argument with a provider".to_string());
+        };
+        let step = &self.definition.steps[index];
+        let binding = self.command_specs.get(&step.run).and_then(|spec| {
+            spec.positional_args
+                .iter()
+                .find(|argument| argument.name == *key)
+                .and_then(|argument| argument.provider.as_ref())
+                .or_else(|| {
+                    spec.flags
+                        .iter()
+                        .find(|flag| flag.name == *key)
+                        .and_then(|flag| flag.provider.as_ref())
+                })
+        });
+        let Some(ValueProvider::Command { command_id, binds }) = binding else {
+            return Err(format!("'{key}' has no value provider"));
+        };
+
+        let mut arguments = JsonMap::new();
+        for bind in binds {
+            match step.with.get(&bind.from) {
+                Some(value) if !display_value(value).contains("${{") && !value.is_null() => {
+                    arguments.insert(bind.provider_key.clone(), Value::String(display_value(value)));
+                }
+                Some(_) => return Err(format!("'{}' is bound to a template; enter '{key}' manually", bind.from)),
+                None => return Err(format!("Set '{}' before picking '{key}'", bind.from)),
+            }
+        }
+        self.pending_provider_field = Some((index, key.clone()));
+        Ok((command_id.clone(), arguments))
+    }
+
+    /// Stores a value chosen in the provider collector.
+    pub fn apply_provider_value(&mut self, value: Value) {
+        let Some((index, key)) = self.pending_provider_field.take() else {
+            return;
+        };
+        if let Some(step) = self.definition.steps.get_mut(index) {
+            step.with.insert(key, value);
+            self.mark_changed();
+        }
+    }
+
+    // ----- Validation and persistence -----
+
+    /// Re-resolves step commands and re-runs validation when the draft changed.
+    pub fn refresh_if_changed(&mut self, registry: &Arc<Mutex<CommandRegistry>>) {
+        if self.validated_revision == Some(self.revision) {
+            return;
+        }
+        self.validated_revision = Some(self.revision);
+        if let Ok(registry) = registry.lock() {
+            for step in &self.definition.steps {
+                if self.command_specs.contains_key(&step.run) {
+                    continue;
+                }
+                if let Some(spec) = lookup_command_spec(&registry, &step.run) {
+                    self.command_specs.insert(step.run.clone(), spec);
+                }
+            }
+        }
+        self.validation = validate_definition(&self.definition, registry);
+    }
+
+    /// Builds the save effect, or explains why the draft cannot be saved yet.
+    pub fn save_effect(&self) -> Result<Effect, String> {
+        if let EditorValidation::Invalid { message, .. } = &self.validation {
+            return Err(format!("Fix validation errors before saving: {message}"));
+        }
+        let content = serialize_definition(&self.definition, self.format).map_err(|error| format!("{error:#}"))?;
+        let format = match self.format {
+            WorkflowManifestFormat::Yaml => "yaml",
+            WorkflowManifestFormat::Json => "json",
+        };
+        Ok(Effect::SaveWorkflowManifest {
+            workflow_id: self.definition.workflow.clone(),
+            content,
+            format: format.to_string(),
+            previous_path: self.origin_path.clone(),
+        })
+    }
+
+    /// Records a successful save of the current revision.
+    pub fn mark_saved(&mut self, path: PathBuf) {
+        self.origin_path = Some(path);
+        self.saved_revision = self.revision;
+        self.discard_armed = false;
+        self.status = Some("Saved".to_string());
+    }
+}
+
+impl HasFocus for WorkflowEditorState {
+    fn build(&self, builder: &mut FocusBuilder) {
+        let tag = builder.start(self);
+        builder.leaf_widget(&self.f_outline);
+        builder.leaf_widget(&self.f_form);
+        builder.leaf_widget(&self.f_cancel_button);
+        builder.leaf_widget(&self.f_save_button);
+        builder.end(tag);
+    }
+
+    fn focus(&self) -> FocusFlag {
+        self.container_focus.clone()
+    }
+
+    fn area(&self) -> Rect {
+        Rect::default()
+    }
+}
+
+/// Runs the `workflow_validate` checks against the serialized draft.
+fn validate_definition(definition: &WorkflowDefinition, registry: &Arc<Mutex<CommandRegistry>>) -> EditorValidation {
+    let manifest = match serialize_definition(definition, WorkflowManifestFormat::Yaml) {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            return EditorValidation::Invalid {
+                message: format!("{error:#}"),
+                violations: Vec::new(),
+            };
+        }
+    };
+    let request = WorkflowValidateRequest {
+        workflow_id: None,
+        manifest_content: Some(manifest),
+        input_path: None,
+        format: Some("yaml".to_string()),
+        lint_format: None,
+    };
+    match validate_workflow(&request, registry) {
+        Ok(payload) => {
+            let mut warnings = messages(payload.get("warnings"));
+            warnings.extend(messages(payload.pointer("/lint/findings")));
+            EditorValidation::Valid { warnings }
+        }
+        Err(error) => EditorValidation::Invalid {
+            message: error.message.to_string(),
+            violations: messages(error.data.as_ref().and_then(|data| data.get("violations"))),
+        },
+    }
+}
+
+/// `path: message` lines from an array of warning, finding, or violation objects.
+fn messages(entries: Option<&Value>) -> Vec<String> {
+    entries
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let message = entry.get("message")?.as_str()?;
+            Some(match entry.get("path").and_then(Value::as_str) {
+                Some(path) if path != "$" => format!("{path}: {message}"),
+                _ => message.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn lookup_command_spec(registry: &CommandRegistry, run: &str) -> Option<CommandSpec> {
+    let (group, name) = parse_provider_group_and_command(run)?;
+    registry.find_by_group_and_cmd_cloned(&group, &name).ok()
+}
+
+fn output_schema(spec: &CommandSpec) -> Option<&oatty_types::SchemaProperty> {
+    spec.http()
+        .and_then(|http| http.output_schema.as_ref())
+        .or_else(|| spec.mcp().and_then(|mcp| mcp.output_schema.as_ref()))
+}
+
+fn provider_command_id(provider: Option<&ValueProvider>) -> Option<String> {
+    provider.map(|ValueProvider::Command { command_id, .. }| command_id.clone())
+}
+
+fn provider_identifier(input: &WorkflowInputDefinition) -> Option<String> {
+    input.provider.as_ref().map(|provider| match provider {
+        WorkflowValueProvider::Id(id) => id.clone(),
+        WorkflowValueProvider::Detailed(detail) => detail.id.clone(),
+    })
+}
+
+fn describe_default(default: Option<&WorkflowInputDefault>) -> String {
+    let Some(default) = default else {
+        return String::new();
+    };
+    let value = default.value.as_ref().map(display_value).unwrap_or_default();
+    match default.from {
+        WorkflowDefaultSource::Literal => value,
+        WorkflowDefaultSource::History => "(from history)".to_string(),
+        WorkflowDefaultSource::Env => format!("(from env) {value}"),
+        WorkflowDefaultSource::WorkflowOutput => format!("(from workflow output) {value}"),
+    }
+}
+
+fn input_binding(name: &str) -> Value {
+    Value::String(format!("${{{{ inputs.{name} }}}}"))
+}
+
+/// First of `base`, `base_2`, `base_3`, ... that `is_taken` rejects.
+fn unique_identifier(base: &str, is_taken: impl Fn(&str) -> bool) -> String {
+    if !is_taken(base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|suffix| format!("{base}_{suffix}"))
+        .find(|candidate| !is_taken(candidate))
+        .unwrap_or_else(|| base.to_string())
+}
+
+/// Text shown for a stored value; strings are shown without quotes.
+pub fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Parses form text into a JSON value. Booleans and numbers are kept typed when the command
+/// declares them, and JSON arrays or objects are accepted verbatim; everything else is a string.
+pub fn parse_field_value(text: &str, type_hint: Option<&str>) -> Value {
+    let typed = matches!(type_hint, Some("boolean" | "integer" | "number"));
+    let structured = text.starts_with('[') || text.starts_with('{');
+    if (typed || structured)
+        && let Ok(value) = serde_json::from_str::<Value>(text)
+    {
+        return value;
+    }
+    Value::String(text.to_string())
+}
+
+fn sample_value_for_type(kind: Option<&str>) -> Value {
+    match kind.unwrap_or("string") {
+        "integer" | "number" => Value::from(0),
+        "boolean" => Value::Bool(false),
+        "array" => Value::Array(Vec::new()),
+        "object" => Value::Object(JsonMap::new()),
+        other => Value::String(format!("<{other}>")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oatty_types::{Bind, CommandExecution, CommandFlag, PositionalArgument};
+    use serde_json::json;
+
+    fn create_spec() -> CommandSpec {
+        CommandSpec {
+            group: "apps".to_string(),
+            name: "apps:create".to_string(),
+            catalog_identifier: 0,
+            summary: "Create an app".to_string(),
+            positional_args: vec![PositionalArgument {
+                name: "team".to_string(),
+                help: None,
+                provider: None,
+            }],
+            flags: vec![
+                CommandFlag {
+                    name: "region".to_string(),
+                    short_name: None,
+                    required: true,
+                    r#type: "string".to_string(),
+                    enum_values: Vec::new(),
+                    default_value: Some("us".to_string()),
+                    description: None,
+                    provider: None,
+                    schema: None,
+                },
+                CommandFlag {
+                    name: "stack".to_string(),
+                    short_name: None,
+                    required: false,
+                    r#type: "string".to_string(),
+                    enum_values: Vec::new(),
+                    default_value: None,
+                    description: None,
+                    provider: Some(ValueProvider::Command {
+                        command_id: "stacks stacks:list".to_string(),
+                        binds: vec![Bind {
+                            provider_key: "region".to_string(),
+                            from: "region".to_string(),
+                        }],
+                    }),
+                    schema: None,
+                },
+            ],
+            execution: CommandExecution::default(),
+        }
+    }
+
+    #[test]
+    fn add_step_prefills_required_arguments_and_picks_unique_ids() {
+        let mut state = WorkflowEditorState::new_draft();
+        state.add_input();
+        state.select_entry(OutlineEntry::Workflow);
+        state.set_field_value(&EditorField::WorkflowId, "deploy", None);
+        state.select_entry(OutlineEntry::Input(0));
+        state.set_field_value(&EditorField::InputName, "team", None);
+
+        state.add_step(&create_spec());
+        state.add_step(&create_spec());
+
+        let steps = &state.definition().steps;
+        assert_eq!(steps[0].id, "apps_create");
+        assert_eq!(steps[1].id, "apps_create_2");
+        assert_eq!(steps[0].run, "apps apps:create");
+        assert_eq!(steps[0].with["team"], json!("${{ inputs.team }}"));
+        assert_eq!(steps[0].with["region"], json!("us"));
+        assert!(!steps[0].with.contains_key("stack"));
+        assert_eq!(state.selected_entry(), OutlineEntry::Step(1));
+    }
+
+    #[test]
+    fn move_selected_reorders_steps_and_rename_updates_dependencies() {
+        let mut state = WorkflowEditorState::new_draft();
+        state.add_step(&create_spec());
+        state.add_step(&create_spec());
+        state.definition.steps[1].depends_on = vec!["apps_create".to_string()];
+
+        state.move_selected(-1);
+        assert_eq!(state.selected_entry(), OutlineEntry::Step(0));
+        assert_eq!(state.definition().steps[0].id, "apps_create_2");
+
+        state.select_entry(OutlineEntry::Step(1));
+        state.set_field_value(&EditorField::StepId, "create", None);
+        assert_eq!(state.definition().steps[0].depends_on, vec!["create".to_string()]);
+    }
+
+    #[test]
+    fn step_rows_follow_the_command_spec_and_bindings_insert_templates() {
+        let mut state = WorkflowEditorState::new_draft();
+        state.add_step(&create_spec());
+        state.add_step(&create_spec());
+        let labels: Vec<String> = state.form_rows().into_iter().map(|row| row.label).collect();
+        assert_eq!(
+            labels,
+            vec!["id", "run", "description", "if", "depends_on", "team", "region", "stack"]
+        );
+
+        let (_, steps) = state.binding_context(1);
+        assert!(steps.contains_key("apps_create"));
+
+        state.form_state.select(Some(5));
+        assert!(state.open_field_picker());
+        state.apply_binding("steps.apps_create.name");
+        assert_eq!(state.definition().steps[1].with["team"], json!("${{ steps.apps_create.name }}"));
+    }
+
+    #[test]
+    fn provider_request_binds_literal_arguments() {
+        let mut state = WorkflowEditorState::new_draft();
+        state.add_step(&create_spec());
+        state.form_state.select(Some(7));
+        let (provider, arguments) = state.provider_request().expect("stack has a provider");
+        assert_eq!(provider, "stacks stacks:list");
+        assert_eq!(arguments["region"], json!("us"));
+
+        state.apply_provider_value(json!("heroku-24"));
+        assert_eq!(state.definition().steps[0].with["stack"], json!("heroku-24"));
+
+        state.set_field_value(&EditorField::StepArgument("region".to_string()), "${{ inputs.region }}", None);
+        assert!(state.provider_request().is_err());
+    }
+
+    #[test]
+    fn parse_field_value_respects_type_hints() {
+        assert_eq!(parse_field_value("true", Some("boolean")), json!(true));
+        assert_eq!(parse_field_value("true", Some("string")), json!("true"));
+        assert_eq!(parse_field_value("[1, 2]", None), json!([1, 2]));
+        assert_eq!(parse_field_value("${{ inputs.app }}", Some("integer")), json!("${{ inputs.app }}"));
+    }
+
+    #[test]
+    fn drafts_without_steps_fail_validation_and_cannot_be_saved() {
+        let registry = Arc::new(Mutex::new(CommandRegistry::default()));
+        let mut state = WorkflowEditorState::new_draft();
+        state.refresh_if_changed(&registry);
+        assert!(matches!(state.validation(), EditorValidation::Invalid { .. }));
+        assert!(state.save_effect().is_err());
+        assert!(!state.request_discard());
+        assert!(state.request_discard());
+    }
+}
@@ -7,6 +7,7 @@
 #![allow(dead_code)]
 
 use oatty_engine::WorkflowRunState;
+use oatty_types::SchemaProperty;
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
@@ -180,24 +181,27 @@ struct PickerTree {
 
 impl PickerTree {
     fn rebuild(&mut self, state: &WorkflowRunState) {
+        let mut steps = JsonMap::new();
+        for (key, value) in state.run_context.steps.iter() {
+            steps.insert(key.clone(), value.clone());
+        }
+        self.rebuild_from_context(state.run_context.inputs.clone(), steps);
+    }
+
+    fn rebuild_from_context(&mut self, inputs: JsonMap<String, JsonValue>, steps: JsonMap<String, JsonValue>) {
         let previous_selection = self.selected_path.clone();
         self.nodes.clear();
         self.roots.clear();
 
-        if !state.run_context.inputs.is_empty() {
+        if !inputs.is_empty() {
             self.expanded_paths.insert("inputs".to_string());
-            let root_value = JsonValue::Object(state.run_context.inputs.clone());
-            let root_index = self.add_node(None, "inputs".to_string(), "inputs".to_string(), root_value, 0);
+            let root_index = self.add_node(None, "inputs".to_string(), "inputs".to_string(), JsonValue::Object(inputs), 0);
             self.roots.push(root_index);
         }
 
-        if !state.run_context.steps.is_empty() {
+        if !steps.is_empty() {
             self.expanded_paths.insert("steps".to_string());
-            let mut map = JsonMap::new();
-            for (key, value) in state.run_context.steps.iter() {
-                map.insert(key.clone(), value.clone());
-            }
-            let root_index = self.add_node(None, "steps".to_string(), "steps".to_string(), JsonValue::Object(map), 0);
+            let root_index = self.add_node(None, "steps".to_string(), "steps".to_string(), JsonValue::Object(steps), 0);
             self.roots.push(root_index);
         }
 
@@ -418,6 +422,15 @@ impl FieldPickerPane {
         self.tree.set_filter(filter);
     }
 
+    /// Rebuilds the tree from explicit input and step values instead of a live run.
+    ///
+    /// The workflow editor uses this with sample values derived from upstream output schemas.
+    pub fn sync_from_context(&mut self, inputs: JsonMap<String, JsonValue>, steps: JsonMap<String, JsonValue>) {
+        self.tree.rebuild_from_context(inputs, steps);
+        let filter = if self.filter.is_empty() { None } else { Some(self.filter.clone()) };
+        self.tree.set_filter(filter);
+    }
+
     pub fn select_next(&mut self) {
         self.tree.select_next();
     }
@@ -438,6 +451,11 @@ impl FieldPickerPane {
         self.tree.current_node().map(|node| node.value.clone())
     }
 
+    /// Dotted path of the selected node, for example `steps.create.id` or `inputs.app`.
+    pub fn current_path(&self) -> Option<String> {
+        self.tree.current_node().map(|node| node.path.clone())
+    }
+
     pub fn clear_filter(&mut self) {
         if self.filter.is_empty() {
             return;
@@ -487,6 +505,28 @@ struct PickerNode {
     has_children: bool,
 }
 
+/// Builds a placeholder value shaped like `schema` so schema fields can be browsed in the picker.
+///
+/// String leaves read `<format>` or `<type>`; arrays hold a single sample item.
+pub fn sample_value_from_schema(schema: &SchemaProperty) -> JsonValue {
+    match schema.r#type.as_str() {
+        "object" => {
+            let mut properties: Vec<_> = schema.properties.iter().flatten().collect();
+            properties.sort_by(|left, right| left.0.cmp(right.0));
+            JsonValue::Object(
+                properties
+                    .into_iter()
+                    .map(|(name, property)| (name.clone(), sample_value_from_schema(property)))
+                    .collect(),
+            )
+        }
+        "array" => JsonValue::Array(schema.items.iter().map(|items| sample_value_from_schema(items)).collect()),
+        "integer" | "number" => JsonValue::from(0),
+        "boolean" => JsonValue::Bool(false),
+        other => JsonValue::String(format!("<{}>", schema.format.as_deref().unwrap_or(other))),
+    }
+}
+
 fn value_type_label(value: &JsonValue) -> &'static str {
     match value {
         JsonValue::String(_) => "string",
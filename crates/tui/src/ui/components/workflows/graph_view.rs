@@ -0,0 +1,269 @@
+//! Box-drawing renderer for workflow dependency graphs.
+//!
+//! Nodes are laid out by graph layer: each layer becomes one row of boxes and a `▼` connector
+//! is drawn beneath boxes with downstream edges. Incoming edges are listed inside each box
+//! (`←` for `depends_on`, `⇠` for inferred `steps.*` references) so the DAG stays readable
+//! without routing arbitrary lines. During a run, borders take the colour of the step status.
+
+use oatty_engine::workflow::graph::{WorkflowGraph, WorkflowGraphEdgeKind, WorkflowGraphNode};
+use oatty_types::workflow::WorkflowRunStepStatus;
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::ui::theme::roles::Theme;
+
+const MIN_BOX_CONTENT_WIDTH: usize = 14;
+const MAX_BOX_CONTENT_WIDTH: usize = 32;
+
+/// Builds the lines for a workflow graph.
+///
+/// `status_of` returns the live status of a step, or `None` when no run is active.
+pub fn build_workflow_graph_lines(
+    graph: &WorkflowGraph,
+    theme: &dyn Theme,
+    status_of: &dyn Fn(&str) -> Option<WorkflowRunStepStatus>,
+) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(Span::styled(
+        "← depends_on   ⇠ steps.* reference",
+        theme.text_muted_style(),
+    ))];
+
+    for (layer_index, layer) in graph.layers().into_iter().enumerate() {
+        let boxes: Vec<NodeBox> = layer
+            .iter()
+            .map(|node| NodeBox::new(graph, node, theme, status_of(&node.id)))
+            .collect();
+        let height = boxes.iter().map(NodeBox::height).max().unwrap_or(0);
+
+        if layer_index > 0 {
+            lines.push(Line::from(""));
+        }
+        for row_index in 0..height {
+            let mut spans = Vec::new();
+            for node_box in &boxes {
+                spans.extend(node_box.row(row_index, height));
+                spans.push(Span::raw(" "));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        let connector: Vec<Span<'static>> = boxes
+            .iter()
+            .map(|node_box| {
+                let width = node_box.outer_width();
+                let text = if graph.has_outgoing(&node_box.step_id) {
+                    let left = width / 2;
+                    format!("{}▼{}", " ".repeat(left), " ".repeat(width - left))
+                } else {
+                    " ".repeat(width + 1)
+                };
+                Span::styled(text, theme.text_muted_style())
+            })
+            .collect();
+        if boxes.iter().any(|node_box| graph.has_outgoing(&node_box.step_id)) {
+            lines.push(Line::from(connector));
+        }
+    }
+
+    lines
+}
+
+/// Glyph appended to the box title for a live step status.
+pub fn step_status_glyph(status: WorkflowRunStepStatus) -> &'static str {
+    match status {
+        WorkflowRunStepStatus::Pending => "·",
+        WorkflowRunStepStatus::Running => "◐",
+        WorkflowRunStepStatus::Succeeded => "✓",
+        WorkflowRunStepStatus::Failed => "✗",
+        WorkflowRunStepStatus::Skipped => "↷",
+    }
+}
+
+fn border_style_for_status(theme: &dyn Theme, status: Option<WorkflowRunStepStatus>) -> Style {
+    match status {
+        None | Some(WorkflowRunStepStatus::Pending) => theme.border_style(false),
+        Some(WorkflowRunStepStatus::Running) => theme.status_warning(),
+        Some(WorkflowRunStepStatus::Succeeded) => theme.status_success(),
+        Some(WorkflowRunStepStatus::Failed) => theme.status_error(),
+        Some(WorkflowRunStepStatus::Skipped) => theme.text_muted_style(),
+    }
+}
+
+/// Pre-rendered content rows for a single node box.
+struct NodeBox {
+    step_id: String,
+    title: String,
+    content_width: usize,
+    border_style: Style,
+    title_style: Style,
+    rows: Vec<(String, Style)>,
+}
+
+impl NodeBox {
+    fn new(graph: &WorkflowGraph, node: &WorkflowGraphNode, theme: &dyn Theme, status: Option<WorkflowRunStepStatus>) -> Self {
+        let title = match status {
+            Some(status) => format!("{} {}", node.id, step_status_glyph(status)),
+            None => node.id.clone(),
+        };
+
+        let mut rows = vec![(node.run.clone(), theme.text_primary_style())];
+        if let Some(condition) = node.condition.as_deref() {
+            rows.push((format!("if {condition}"), theme.syntax_keyword_style()));
+        }
+        let incoming_for = |kind: WorkflowGraphEdgeKind| -> Vec<&str> {
+            graph
+                .incoming(&node.id)
+                .filter(|edge| edge.kind == kind)
+                .map(|edge| edge.from.as_str())
+                .collect()
+        };
+        let dependencies = incoming_for(WorkflowGraphEdgeKind::DependsOn);
+        if !dependencies.is_empty() {
+            rows.push((format!("← {}", dependencies.join(", ")), theme.text_muted_style()));
+        }
+        let references = incoming_for(WorkflowGraphEdgeKind::Reference);
+        if !references.is_empty() {
+            rows.push((format!("⇠ {}", references.join(", ")), theme.text_muted_style()));
+        }
+
+        let widest = rows
+            .iter()
+            .map(|(text, _)| text.width())
+            .chain(std::iter::once(title.width() + 2))
+            .max()
+            .unwrap_or(0);
+        let content_width = widest.clamp(MIN_BOX_CONTENT_WIDTH, MAX_BOX_CONTENT_WIDTH);
+        let rows = rows
+            .into_iter()
+            .map(|(text, style)| (truncate_to_width(&text, content_width), style))
+            .collect();
+
+        Self {
+            step_id: node.id.clone(),
+            title: truncate_to_width(&title, content_width.saturating_sub(2)),
+            content_width,
+            border_style: border_style_for_status(theme, status),
+            title_style: theme.text_primary_style().patch(border_style_for_status(theme, status)),
+            rows,
+        }
+    }
+
+    /// Box height including the top and bottom borders.
+    fn height(&self) -> usize {
+        self.rows.len() + 2
+    }
+
+    /// Total width including borders and padding.
+    fn outer_width(&self) -> usize {
+        self.content_width + 4
+    }
+
+    /// Returns spans for one visual row; boxes shorter than `height` are padded.
+    fn row(&self, row_index: usize, height: usize) -> Vec<Span<'static>> {
+        let inner_width = self.content_width + 2;
+        if row_index == 0 {
+            let fill = inner_width.saturating_sub(self.title.width() + 3);
+            return vec![
+                Span::styled("┌─ ", self.border_style),
+                Span::styled(self.title.clone(), self.title_style),
+                Span::styled(format!(" {}┐", "─".repeat(fill)), self.border_style),
+            ];
+        }
+        if row_index + 1 == height {
+            return vec![Span::styled(format!("└{}┘", "─".repeat(inner_width)), self.border_style)];
+        }
+
+        let (text, style) = self
+            .rows
+            .get(row_index - 1)
+            .cloned()
+            .unwrap_or_else(|| (String::new(), self.border_style));
+        let padding = self.content_width.saturating_sub(text.width());
+        vec![
+            Span::styled("│ ", self.border_style),
+            Span::styled(text, style),
+            Span::styled(format!("{} │", " ".repeat(padding)), self.border_style),
+        ]
+    }
+}
+
+fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+    let mut truncated = String::new();
+    for character in text.chars() {
+        if truncated.width() + 1 >= max_width {
+            break;
+        }
+        truncated.push(character);
+    }
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::theme::dracula::DraculaTheme;
+    use indexmap::IndexMap;
+    use oatty_types::workflow::WorkflowStepDefinition;
+
+    fn step(id: &str, depends_on: &[&str], with: Option<(&str, &str)>) -> WorkflowStepDefinition {
+        let mut with_map = IndexMap::new();
+        if let Some((key, value)) = with {
+            with_map.insert(key.to_string(), serde_json::Value::String(value.to_string()));
+        }
+        WorkflowStepDefinition {
+            id: id.to_string(),
+            run: format!("apps {id}"),
+            description: None,
+            depends_on: depends_on.iter().map(|value| value.to_string()).collect(),
+            r#if: None,
+            with: with_map,
+            body: serde_json::Value::Null,
+            repeat: None,
+            output_contract: None,
+        }
+    }
+
+    fn line_text(line: &Line<'_>) -> String {
+        line.spans.iter().map(|span| span.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn renders_layers_with_connectors_and_incoming_edges() {
+        let graph = WorkflowGraph::from_steps(
+            "demo",
+            &[
+                step("create", &[], None),
+                step("attach", &["create"], Some(("db", "${{ steps.create.id }}"))),
+            ],
+        );
+        let theme = DraculaTheme::new();
+        let lines: Vec<String> = build_workflow_graph_lines(&graph, &theme, &|_| None)
+            .iter()
+            .map(line_text)
+            .collect();
+
+        assert!(lines[1].starts_with("┌─ create ─"));
+        assert!(lines[2].starts_with("│ apps create"));
+        assert!(lines[4].trim_start().starts_with('▼'));
+        assert!(lines.iter().any(|line| line.contains("← create")));
+        let widths: Vec<usize> = lines[1..4].iter().map(|line| line.width()).collect();
+        assert!(widths.windows(2).all(|pair| pair[0] == pair[1]), "box rows should align: {lines:?}");
+    }
+
+    #[test]
+    fn marks_live_status_in_box_titles() {
+        let graph = WorkflowGraph::from_steps("demo", &[step("create", &[], None)]);
+        let theme = DraculaTheme::new();
+        let lines = build_workflow_graph_lines(&graph, &theme, &|_| Some(WorkflowRunStepStatus::Failed));
+
+        assert!(line_text(&lines[1]).starts_with("┌─ create ✗ ─"));
+        assert_eq!(lines[1].spans[0].style, theme.status_error());
+    }
+}
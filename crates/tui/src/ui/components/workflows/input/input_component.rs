@@ -7,12 +7,13 @@ use crate::ui::components::component::Component;
 use crate::ui::components::workflows::WorkflowInputViewState;
 use crate::ui::components::workflows::input::state::{InputStatus, WorkflowInputRow};
 use crate::ui::components::workflows::view_utils::style_for_role;
+use crate::ui::keymap::KeyAction;
 use crate::ui::theme::theme_helpers::create_list_with_highlight;
 use crate::ui::theme::{
     roles::Theme,
     theme_helpers::{self as th, ButtonRenderOptions, ButtonType},
 };
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use oatty_engine::WorkflowRunState;
 use oatty_types::{Effect, ExecOutcome, Modal, Msg, Route};
 use rat_focus::HasFocus;
@@ -84,6 +85,9 @@ impl Component for WorkflowInputsComponent {
         let focus_snapshot = determine_input_focus(app);
 
         if focus_snapshot.list_focused {
+            if app.ctx.keymap.matches(KeyAction::WorkflowInputsManualEntry, &key) {
+                return open_manual_entry_for_active_input(app);
+            }
             return handle_list_focused_key(app, key.code);
         }
 
@@ -239,14 +243,16 @@ impl Component for WorkflowInputsComponent {
                 );
             }
         }
+        let manual_entry = format!(" {}", app.ctx.keymap.label(KeyAction::WorkflowInputsManualEntry));
+        let step_plan = format!(" {}", app.ctx.keymap.label(KeyAction::WorkflowInputsStepPlan));
         th::build_hint_spans(
             theme,
             &[
                 ("Esc", " Cancel"),
                 (" ↑/↓", " Navigate"),
                 (" Enter", " Collect input"),
-                (" F2", " Manual entry"),
-                (" Ctrl+P", " View plan"),
+                (&manual_entry, " Manual entry"),
+                (&step_plan, " View plan"),
             ],
         )
     }
@@ -365,7 +371,7 @@ fn get_block_container(app: &App) -> Block<'static> {
     th::block(&*app.ctx.theme, Some("Pre-run Input Viewer"), is_focused)
 }
 fn handle_global_key_event(app: &mut App, key: KeyEvent) -> Option<Vec<Effect>> {
-    if key.modifiers.contains(KeyModifiers::CONTROL) && matches!(key.code, KeyCode::Char('p') | KeyCode::Char('P')) {
+    if app.ctx.keymap.matches(KeyAction::WorkflowInputsStepPlan, &key) {
         return Some(show_pre_run_step_plan(app));
     }
 
@@ -429,7 +435,7 @@ fn show_pre_run_step_plan(app: &App) -> Vec<Effect> {
         payload,
         request_id: 0,
     };
-    vec![Effect::ShowModal(Modal::Results(Box::new(outcome)))]
+    vec![Effect::ShowModal(Modal::Results(Some(Box::new(outcome))))]
 }
 
 fn determine_input_focus(app: &App) -> InputFocusSnapshot {
@@ -570,19 +576,18 @@ fn handle_list_focused_key(app: &mut App, key_code: KeyCode) -> Vec<Effect> {
             }
             effects
         }
-        KeyCode::F(2) => {
-            let mut effects = Vec::new();
-            if app.workflows.active_run_state.is_some() && current_row_block_reason(app).is_some() {
-                return effects;
-            }
-            app.workflows.open_manual_for_active_input();
-            effects.push(Effect::ShowModal(Modal::WorkflowCollector));
-            effects
-        }
         _ => Vec::new(),
     }
 }
 
+fn open_manual_entry_for_active_input(app: &mut App) -> Vec<Effect> {
+    if app.workflows.active_run_state.is_some() && current_row_block_reason(app).is_some() {
+        return Vec::new();
+    }
+    app.workflows.open_manual_for_active_input();
+    vec![Effect::ShowModal(Modal::WorkflowCollector)]
+}
+
 fn handle_details_focused_key(app: &mut App, key_code: KeyCode) -> Vec<Effect> {
     let Some(state) = app.workflows.input_view_state_mut() else {
         return Vec::new();
@@ -1,7 +1,7 @@
 use crate::ui::components::common::ScrollMetrics;
 use crate::ui::components::workflows::{JsonSyntaxRole, classify_json_value, format_preview};
 use oatty_engine::{ProviderBindingOutcome, WorkflowRunState};
-use oatty_types::{WorkflowInputDefinition, WorkflowProviderArgumentValue, WorkflowValueProvider, validate_candidate_value};
+use oatty_types::{WorkflowInputDefinition, WorkflowProviderArgumentValue, WorkflowValueProvider};
 use oatty_util::has_meaningful_value;
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
 use ratatui::layout::Rect;
@@ -143,20 +143,15 @@ impl WorkflowInputViewState {
             // Preserve error state and explanatory message coming from provider resolution.
         } else if has_value {
             if let Some(value) = raw_value {
-                if let Some(validation) = &definition.validate {
-                    match validate_candidate_value(value, validation) {
-                        Ok(()) => {
-                            status = InputStatus::Resolved;
-                            status_message = None;
-                        }
-                        Err(message) => {
-                            status = InputStatus::Error;
-                            status_message = Some(format!("{}", message));
-                        }
+                match definition.validate_value(value) {
+                    Ok(()) => {
+                        status = InputStatus::Resolved;
+                        status_message = None;
+                    }
+                    Err(message) => {
+                        status = InputStatus::Error;
+                        status_message = Some(format!("{}", message));
                     }
-                } else {
-                    status = InputStatus::Resolved;
-                    status_message = None;
                 }
             }
         } else {
@@ -171,7 +166,10 @@ impl WorkflowInputViewState {
             }
         }
 
-        let current_value = raw_value.map(|value| WorkflowValuePreview::new(format_preview(value), classify_json_value(value)));
+        let current_value = raw_value.map(|value| {
+            let displayed = definition.redact_value(value);
+            WorkflowValuePreview::new(format_preview(&displayed), classify_json_value(&displayed))
+        });
         WorkflowInputRow {
             name: display_name,
             required,
@@ -423,6 +421,7 @@ mod tests {
             steps: Vec::new(),
             final_output: None,
             requires: None,
+            schedule: None,
         })
     }
 
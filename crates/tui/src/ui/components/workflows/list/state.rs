@@ -5,12 +5,22 @@
 
 use crate::ui::components::common::TextInputState;
 use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
 use oatty_engine::workflow::document::runtime_workflow_from_definition;
+use oatty_engine::workflow::lint::{WorkflowLintReport, lint_workflow};
+use oatty_engine::workflow::schedule::next_scheduled_run;
+use oatty_mcp::{WorkflowHistoryEntry, latest_history_entry};
 use oatty_registry::CommandRegistry;
 use oatty_types::workflow::{RuntimeWorkflow, WorkflowDefinition};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
 use ratatui::{layout::Rect, widgets::ListState};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Minimum interval between reloads of scheduled workflow run metadata.
+const SCHEDULE_STATUS_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
 
 /// Maintains the workflow catalogue, filtered view, and list selection state for the picker UI.
 #[derive(Debug, Default)]
@@ -25,12 +35,25 @@ pub struct WorkflowListState {
     search_input: TextInputState,
     list_state: ListState,
     container_focus: FocusFlag,
+    schedule_statuses: HashMap<String, WorkflowScheduleStatus>,
+    schedule_statuses_refreshed_at: Option<Instant>,
+    lint_reports: HashMap<String, WorkflowLintReport>,
+    show_graph: bool,
+}
+
+/// Next and last run metadata for a scheduled workflow.
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowScheduleStatus {
+    /// Next time the scheduler will start the workflow.
+    pub next_run: Option<DateTime<Utc>>,
+    /// Most recent run recorded in the workflow history.
+    pub last_run: Option<WorkflowHistoryEntry>,
 }
 
 /// Represents one row in the workflows list.
 #[derive(Debug, Clone)]
 pub enum WorkflowListEntry {
-    Valid(RuntimeWorkflow),
+    Valid(Box<RuntimeWorkflow>),
     Invalid(InvalidWorkflowEntry),
 }
 
@@ -90,6 +113,10 @@ impl WorkflowListState {
             list_state: ListState::default(),
             container_focus: FocusFlag::new().with_name("root.workflows"),
             f_list: FocusFlag::new().with_name("root.workflows.list"),
+            schedule_statuses: HashMap::new(),
+            schedule_statuses_refreshed_at: None,
+            lint_reports: HashMap::new(),
+            show_graph: false,
         }
     }
 
@@ -109,6 +136,8 @@ impl WorkflowListState {
                 .unwrap_or(true);
 
             if !definitions_changed && !self.entries.is_empty() {
+                drop(registry_guard);
+                self.refresh_schedule_statuses_if_stale();
                 return Ok(());
             }
 
@@ -120,7 +149,7 @@ impl WorkflowListState {
 
         for definition in &definitions_snapshot {
             match runtime_workflow_from_definition(definition) {
-                Ok(workflow) => entries.push(WorkflowListEntry::Valid(workflow)),
+                Ok(workflow) => entries.push(WorkflowListEntry::Valid(Box::new(workflow))),
                 Err(error) => {
                     let identifier = definition.workflow.trim().to_string();
                     let fallback_identifier = if identifier.is_empty() {
@@ -143,22 +172,95 @@ impl WorkflowListState {
             }
         }
 
+        let lint_reports = {
+            let registry_guard = registry.lock().map_err(|_| anyhow!("could not lock registry"))?;
+            entries
+                .iter()
+                .filter_map(|entry| match entry {
+                    WorkflowListEntry::Valid(workflow) => Some(lint_workflow(workflow, Some(&registry_guard))),
+                    WorkflowListEntry::Invalid(_) => None,
+                })
+                .filter(|report| !report.findings.is_empty())
+                .map(|report| (report.workflow_id.clone(), report))
+                .collect::<HashMap<_, _>>()
+        };
+        for report in lint_reports.values().filter(|report| !report.is_clean()) {
+            load_messages.push(format!(
+                "Workflow '{}' has {} lint error(s); run `oatty workflow lint` for details",
+                report.workflow_id,
+                report.error_count()
+            ));
+        }
+
         entries.sort_by_key(|entry| entry.display_identifier().to_lowercase());
         self.entries = entries;
+        self.lint_reports = lint_reports;
         self.pending_load_messages = load_messages;
         self.cached_workflow_definitions = Some(definitions_snapshot);
         self.rebuild_filter();
+        self.refresh_schedule_statuses();
 
         Ok(())
     }
 
+    /// Returns next and last run metadata for a scheduled workflow.
+    pub fn schedule_status(&self, workflow_id: &str) -> Option<&WorkflowScheduleStatus> {
+        self.schedule_statuses.get(workflow_id)
+    }
+
+    /// Returns lint findings for a workflow, or `None` when it linted clean.
+    pub fn lint_report(&self, workflow_id: &str) -> Option<&WorkflowLintReport> {
+        self.lint_reports.get(workflow_id)
+    }
+
+    /// Returns `true` when the selected workflow's dependency graph is shown beside the list.
+    pub fn is_graph_visible(&self) -> bool {
+        self.show_graph
+    }
+
+    /// Shows or hides the dependency graph panel.
+    pub fn toggle_graph(&mut self) {
+        self.show_graph = !self.show_graph;
+    }
+
+    fn refresh_schedule_statuses_if_stale(&mut self) {
+        let is_stale = self
+            .schedule_statuses_refreshed_at
+            .is_none_or(|refreshed_at| refreshed_at.elapsed() >= SCHEDULE_STATUS_REFRESH_INTERVAL);
+        if is_stale {
+            self.refresh_schedule_statuses();
+        }
+    }
+
+    /// Recomputes next run times and reloads the latest history entry for scheduled workflows.
+    fn refresh_schedule_statuses(&mut self) {
+        let now = Utc::now();
+        let mut statuses = HashMap::new();
+        for entry in &self.entries {
+            let WorkflowListEntry::Valid(workflow) = entry else {
+                continue;
+            };
+            let Some(schedule) = workflow.schedule.as_ref().filter(|schedule| schedule.enabled) else {
+                continue;
+            };
+            let next_run = next_scheduled_run(schedule, now).ok().flatten();
+            let last_run = latest_history_entry(&workflow.identifier).unwrap_or_else(|error| {
+                warn!(workflow = %workflow.identifier, error = %error, "failed to read workflow run history");
+                None
+            });
+            statuses.insert(workflow.identifier.clone(), WorkflowScheduleStatus { next_run, last_run });
+        }
+        self.schedule_statuses = statuses;
+        self.schedule_statuses_refreshed_at = Some(Instant::now());
+    }
+
     /// Returns the selected workflow from the filtered list, if one is available.
     pub fn selected_workflow(&self) -> Option<&RuntimeWorkflow> {
         self.filtered_indices
             .get(self.selected)
             .and_then(|index| self.entries.get(*index))
             .and_then(|entry| match entry {
-                WorkflowListEntry::Valid(workflow) => Some(workflow),
+                WorkflowListEntry::Valid(workflow) => Some(workflow.as_ref()),
                 WorkflowListEntry::Invalid(_) => None,
             })
     }
@@ -376,6 +478,7 @@ mod tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         }
     }
 
@@ -400,6 +503,53 @@ mod tests {
         assert_eq!(refreshed_title.as_deref(), Some("Deploy v2"));
     }
 
+    #[test]
+    fn ensure_loaded_computes_next_run_for_scheduled_workflows() {
+        let mut scheduled = workflow_definition("nightly", Some("Nightly"));
+        scheduled.schedule = Some(oatty_types::workflow::WorkflowSchedule {
+            cron: "0 2 * * *".to_string(),
+            timezone: Default::default(),
+            inputs: IndexMap::new(),
+            environment: IndexMap::new(),
+            overlap: Default::default(),
+            enabled: true,
+        });
+        let mut registry_value = CommandRegistry::default();
+        registry_value.workflows = vec![scheduled, workflow_definition("manual", None)];
+        let registry = Arc::new(Mutex::new(registry_value));
+
+        let mut state = WorkflowListState::new();
+        state.ensure_loaded(&registry).expect("load should succeed");
+
+        let status = state.schedule_status("nightly").expect("scheduled workflow should have status");
+        assert!(status.next_run.is_some_and(|next_run| next_run > Utc::now()));
+        assert!(state.schedule_status("manual").is_none());
+    }
+
+    #[test]
+    fn ensure_loaded_lints_valid_workflows_against_the_registry() {
+        let mut linted = workflow_definition("cleanup", None);
+        linted
+            .inputs
+            .insert("unused".to_string(), serde_json::from_value(serde_json::json!({})).expect("input"));
+        let mut registry_value = CommandRegistry::default();
+        registry_value.workflows = vec![linted];
+        let registry = Arc::new(Mutex::new(registry_value));
+
+        let mut state = WorkflowListState::new();
+        state.ensure_loaded(&registry).expect("load should succeed");
+
+        let report = state.lint_report("cleanup").expect("lint findings should be recorded");
+        let rules: Vec<&str> = report.findings.iter().map(|finding| finding.rule.as_str()).collect();
+        assert_eq!(rules, vec!["command-readiness", "unused-input"]);
+        assert!(
+            state
+                .take_load_messages()
+                .iter()
+                .any(|message| message.contains("Workflow 'cleanup' has 1 lint error(s)"))
+        );
+    }
+
     #[test]
     fn invalid_workflow_is_listed_and_marked_invalid() {
         let mut registry_value = CommandRegistry::default();
@@ -423,6 +573,7 @@ mod tests {
                 }],
                 final_output: None,
                 requires: None,
+                schedule: None,
             },
         ];
 
@@ -1,5 +1,7 @@
 pub(crate) mod collector;
+pub mod editor;
 pub mod field_picker;
+pub mod graph_view;
 mod input;
 pub mod list;
 pub mod run;
@@ -8,6 +10,7 @@ pub mod view_utils;
 mod workflows_component;
 
 pub use collector::WorkflowCollectorComponent;
+pub use editor::WorkflowEditorComponent;
 pub use input::*;
 #[allow(unused_imports)]
 pub use run::RunViewComponent;
@@ -6,11 +6,13 @@
 //! paths, keeping behavior consistent with other workflow views.
 
 use crate::app::App;
+use crate::ui::components::workflows::graph_view::build_workflow_graph_lines;
 use crate::ui::components::workflows::run::state::{RunExecutionStatus, RunViewState};
 use crate::ui::components::{
     common::ResultsTableView,
     component::{Component, find_target_index_by_mouse_position},
 };
+use crate::ui::keymap::{KeyAction, KeyScope, Keymap};
 use crate::ui::theme::{
     Theme,
     theme_helpers::{self as th, ButtonRenderOptions, ButtonType, build_hint_spans},
@@ -131,7 +133,9 @@ impl Component for RunViewComponent {
         let run_id = run_state.run_id().to_string();
 
         match () {
-            _ if focus_snapshot.0 => self.handle_steps_table_keys(app.workflows.run_view_state_mut().expect("run view state"), key.code),
+            _ if focus_snapshot.0 => {
+                self.handle_steps_table_keys(&app.ctx.keymap, app.workflows.run_view_state_mut().expect("run view state"), &key)
+            }
             _ if focus_snapshot.1 => self.handle_cancel_button_keys(app, run_status, &run_id, key.code),
             _ if focus_snapshot.2 => self.handle_pause_button_keys(app, run_status, &run_id, key.code),
             _ if focus_snapshot.3 => self.handle_view_details_button_keys(app, &run_id, key.code),
@@ -212,13 +216,18 @@ impl Component for RunViewComponent {
             return build_hint_spans(theme, &[(" Enter/Space", " Close run ")]);
         }
 
+        let keymap = &app.ctx.keymap;
+        let navigate = format!(" {}", keymap.label_pair(KeyAction::MoveUp, KeyAction::MoveDown));
+        let logs = format!(" {}", keymap.label(KeyAction::ToggleLogs));
+        let graph = format!(" {}", keymap.label(KeyAction::RunToggleGraph));
         build_hint_spans(
             theme,
             &[
                 (" Esc", " Close detail "),
-                (" ↑/↓", " Navigate "),
+                (&navigate, " Navigate "),
                 (" Enter", " View detail "),
-                (" L", " View logs "),
+                (&logs, " View logs "),
+                (&graph, " Toggle graph "),
             ],
         )
     }
@@ -287,17 +296,18 @@ impl RunViewComponent {
         }
     }
 
-    fn handle_steps_table_keys(&mut self, run_state: &mut RunViewState, code: KeyCode) -> Vec<Effect> {
+    fn handle_steps_table_keys(&mut self, keymap: &Keymap, run_state: &mut RunViewState, key: &KeyEvent) -> Vec<Effect> {
         let mut effects = Vec::new();
         let table_state = &mut run_state.steps_table.table_state;
-        match code {
-            KeyCode::Up => table_state.scroll_up_by(1),
-            KeyCode::Down => table_state.scroll_down_by(1),
-            KeyCode::PageUp => table_state.scroll_up_by(5),
-            KeyCode::PageDown => table_state.scroll_down_by(5),
-            KeyCode::Home => table_state.scroll_up_by(u16::MAX),
-            KeyCode::End => table_state.scroll_down_by(u16::MAX),
-            KeyCode::Enter => {
+        match keymap.resolve(KeyScope::WorkflowRun, key) {
+            Some(KeyAction::MoveUp) => table_state.scroll_up_by(1),
+            Some(KeyAction::MoveDown) => table_state.scroll_down_by(1),
+            Some(KeyAction::PageUp) => table_state.scroll_up_by(5),
+            Some(KeyAction::PageDown) => table_state.scroll_down_by(5),
+            Some(KeyAction::ScrollTop) => table_state.scroll_up_by(u16::MAX),
+            Some(KeyAction::ScrollBottom) => table_state.scroll_down_by(u16::MAX),
+            Some(KeyAction::RunToggleGraph) => run_state.toggle_graph(),
+            _ if key.code == KeyCode::Enter => {
                 effects.extend(self.show_step_output(run_state));
             }
             _ => {}
@@ -406,7 +416,7 @@ impl RunViewComponent {
                 payload: value,
                 request_id: 0,
             };
-            effects.push(Effect::ShowModal(Modal::Results(Box::new(outcome))));
+            effects.push(Effect::ShowModal(Modal::Results(Some(Box::new(outcome)))));
         }
         effects
     }
@@ -564,6 +574,15 @@ fn render_header(frame: &mut Frame, area: Rect, theme: &dyn Theme, run_state: &R
 
 fn render_steps_table(frame: &mut Frame, area: Rect, theme: &dyn Theme, run_state: &mut RunViewState, view: &mut ResultsTableView) {
     let steps_focused = run_state.steps_table.focus().get();
+    if run_state.is_graph_visible()
+        && let Some(graph) = run_state.graph()
+    {
+        let graph_block = th::block(theme, Some("Step Graph"), steps_focused).merge_borders(MergeStrategy::Exact);
+        let lines = build_workflow_graph_lines(graph, theme, &|step_id| run_state.step_status(step_id));
+        frame.render_widget(Paragraph::new(lines).block(graph_block), area);
+        return;
+    }
+
     let inner_block = th::block(theme, Some("Steps"), steps_focused).merge_borders(MergeStrategy::Exact);
     let inner_area = inner_block.inner(area);
     frame.render_widget(inner_block, area);
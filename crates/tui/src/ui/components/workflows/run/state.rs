@@ -8,6 +8,7 @@
 
 use crate::ui::{components::results::state::ResultsTableState, theme::Theme};
 use chrono::{DateTime, Duration, Utc};
+use oatty_engine::workflow::graph::WorkflowGraph;
 use oatty_types::workflow::{WorkflowRunControl, WorkflowRunStatus, WorkflowRunStepStatus, WorkflowStepDefinition};
 use oatty_util::format_duration_short;
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
@@ -79,6 +80,9 @@ pub struct RunViewState {
     step_descriptions: HashMap<String, Option<String>>,
     step_repeat_limits: HashMap<String, Option<u32>>,
     running_repeat_steps: HashMap<String, RepeatAnimationState>,
+    graph: Option<WorkflowGraph>,
+    step_statuses: HashMap<String, WorkflowRunStepStatus>,
+    show_graph: bool,
 }
 
 impl RunViewState {
@@ -109,6 +113,9 @@ impl RunViewState {
             step_descriptions: HashMap::new(),
             step_repeat_limits: HashMap::new(),
             running_repeat_steps: HashMap::new(),
+            graph: None,
+            step_statuses: HashMap::new(),
+            show_graph: false,
         }
     }
 
@@ -204,6 +211,8 @@ impl RunViewState {
         self.step_descriptions.clear();
         self.step_repeat_limits.clear();
         self.running_repeat_steps.clear();
+        self.step_statuses.clear();
+        self.graph = Some(WorkflowGraph::from_steps(&self.workflow_identifier, steps));
 
         for (index, step) in steps.iter().enumerate() {
             let mut row = JsonMap::new();
@@ -215,6 +224,7 @@ impl RunViewState {
             self.step_rows.push(Value::Object(row));
             self.step_indices.insert(step.id.clone(), index);
             self.step_descriptions.insert(step.id.clone(), step.description.clone());
+            self.step_statuses.insert(step.id.clone(), WorkflowRunStepStatus::Pending);
             if let Some(repeat) = step.repeat.as_ref() {
                 self.step_repeat_limits.insert(step.id.clone(), repeat.max_attempts);
             }
@@ -225,6 +235,7 @@ impl RunViewState {
 
     /// Marks a step as running using its index within the workflow definition order.
     pub fn mark_step_running(&mut self, index: usize, step_id: &str, theme: &dyn Theme) {
+        self.step_statuses.insert(step_id.to_string(), WorkflowRunStepStatus::Running);
         if let Some(row) = self.step_rows.get_mut(index).and_then(Value::as_object_mut) {
             row.insert("Status".into(), Value::String("running".into()));
             if self.step_repeat_limits.contains_key(step_id) {
@@ -258,6 +269,7 @@ impl RunViewState {
             .and_then(|value| value.clone())
             .unwrap_or_default();
 
+        self.step_statuses.insert(step_id.to_string(), data.status);
         let mut row = JsonMap::new();
         row.insert("Step".into(), Value::String(step_id.to_string()));
         row.insert("Status".into(), Value::String(step_status_label(data.status).to_string()));
@@ -280,6 +292,26 @@ impl RunViewState {
         self.rebuild_steps_table(theme);
     }
 
+    /// Returns the dependency graph of the running workflow, once steps are initialized.
+    pub fn graph(&self) -> Option<&WorkflowGraph> {
+        self.graph.as_ref()
+    }
+
+    /// Returns the live status of a step.
+    pub fn step_status(&self, step_id: &str) -> Option<WorkflowRunStepStatus> {
+        self.step_statuses.get(step_id).copied()
+    }
+
+    /// Returns `true` when the steps area shows the dependency graph instead of the table.
+    pub fn is_graph_visible(&self) -> bool {
+        self.show_graph
+    }
+
+    /// Switches the steps area between the table and the dependency graph.
+    pub fn toggle_graph(&mut self) {
+        self.show_graph = !self.show_graph;
+    }
+
     /// Records that the run has started.
     pub fn handle_run_started(&mut self, timestamp: DateTime<Utc>) {
         self.set_started_at(timestamp);
@@ -578,6 +610,26 @@ mod tests {
         assert_eq!(state.output_by_index(0), Some(json!({"result": "ok"})));
     }
 
+    #[test]
+    fn step_statuses_track_graph_nodes_during_a_run() {
+        let theme = DraculaTheme::new();
+        let mut dependent = make_step("beta", None);
+        dependent.depends_on = vec!["alpha".into()];
+        let mut state = RunViewState::new("run-1".into(), "workflow".into(), None);
+        state.initialize_steps(&[make_step("alpha", None), dependent], &theme);
+
+        let graph = state.graph().expect("graph is built from steps");
+        assert_eq!(graph.layers().len(), 2);
+        assert_eq!(state.step_status("beta"), Some(WorkflowRunStepStatus::Pending));
+
+        state.mark_step_running(0, "alpha", &theme);
+        assert_eq!(state.step_status("alpha"), Some(WorkflowRunStepStatus::Running));
+
+        assert!(!state.is_graph_visible());
+        state.toggle_graph();
+        assert!(state.is_graph_visible());
+    }
+
     #[test]
     fn repeat_steps_show_animated_running_status() {
         let theme = DraculaTheme::new();
@@ -2,11 +2,13 @@ use crate::ui::components::common::TextInputState;
 use crate::ui::components::common::manual_entry_modal::state::ManualEntryState;
 use crate::ui::components::results::ResultsTableState;
 use crate::ui::components::workflows::collector::{CollectorApplyTarget, CollectorSelectionSource, CollectorViewState, SelectorStatus};
+use crate::ui::components::workflows::editor::WorkflowEditorState;
 use crate::ui::components::workflows::input::WorkflowInputViewState;
-use crate::ui::components::workflows::list::{WorkflowListEntry, WorkflowListState};
+use crate::ui::components::workflows::list::{WorkflowListEntry, WorkflowListState, WorkflowScheduleStatus};
 use crate::ui::components::workflows::run::{RunViewState, StepFinishedData, WorkflowRunControlHandle};
 use crate::ui::theme::Theme;
 use anyhow::Result;
+use oatty_engine::workflow::lint::WorkflowLintReport;
 use oatty_engine::{ProviderBindingOutcome, WorkflowRunState, resolve::interpolate_value};
 use oatty_registry::CommandRegistry;
 use oatty_types::workflow::{
@@ -39,6 +41,8 @@ pub struct WorkflowState {
     pub active_run_state: Option<Rc<RefCell<WorkflowRunState>>>,
     input_view: Option<WorkflowInputViewState>,
     run_view: Option<RunViewState>,
+    /// Authoring draft state while the editor route is open.
+    pub editor: Option<WorkflowEditorState>,
     active_run_id: Option<String>,
     run_control: Option<WorkflowRunControlHandle>,
 }
@@ -51,6 +55,7 @@ impl WorkflowState {
             active_run_state: None,
             input_view: None,
             run_view: None,
+            editor: None,
             manual_entry: None,
             collector: None,
             container_focus: FocusFlag::new().with_name("workflow.container"),
@@ -144,6 +149,16 @@ impl WorkflowState {
         self.list.entry_by_index(index)
     }
 
+    /// Returns next and last run metadata for a scheduled workflow.
+    pub fn schedule_status(&self, workflow_id: &str) -> Option<&WorkflowScheduleStatus> {
+        self.list.schedule_status(workflow_id)
+    }
+
+    /// Returns lint findings for a workflow, or `None` when it linted clean.
+    pub fn lint_report(&self, workflow_id: &str) -> Option<&WorkflowLintReport> {
+        self.list.lint_report(workflow_id)
+    }
+
     /// Returns the currently selected workflow from the filtered view.
     pub fn selected_workflow(&self) -> Option<&RuntimeWorkflow> {
         self.list.selected_workflow()
@@ -201,6 +216,27 @@ impl WorkflowState {
         self.input_view.as_ref()
     }
 
+    /// Returns the workflow editor state when the editor is open.
+    pub fn editor_state(&self) -> Option<&WorkflowEditorState> {
+        self.editor.as_ref()
+    }
+
+    /// Returns a mutable reference to the workflow editor state when the editor is open.
+    pub fn editor_state_mut(&mut self) -> Option<&mut WorkflowEditorState> {
+        self.editor.as_mut()
+    }
+
+    /// Opens the editor on `editor`, replacing any previous draft.
+    pub fn begin_editor(&mut self, editor: WorkflowEditorState) {
+        self.editor = Some(editor);
+    }
+
+    /// Drops the editor draft along with any selector opened from it.
+    pub fn close_editor(&mut self) {
+        self.editor = None;
+        self.collector = None;
+    }
+
     pub fn is_running(&self) -> bool {
         self.run_control.is_some()
     }
@@ -368,7 +404,9 @@ fn infer_step_failure_reason(logs: &[String]) -> Option<String> {
 
 impl HasFocus for WorkflowState {
     fn build(&self, builder: &mut FocusBuilder) {
-        if let Some(run_view) = &self.run_view {
+        if let Some(editor) = &self.editor {
+            editor.build(builder);
+        } else if let Some(run_view) = &self.run_view {
             run_view.build(builder);
         } else if let Some(view) = &self.input_view {
             view.build(builder);
@@ -383,7 +421,9 @@ impl HasFocus for WorkflowState {
     }
 
     fn focus(&self) -> FocusFlag {
-        if let Some(run_view) = &self.run_view {
+        if let Some(editor) = &self.editor {
+            editor.focus()
+        } else if let Some(run_view) = &self.run_view {
             run_view.focus()
         } else if let Some(view) = &self.input_view {
             view.focus()
@@ -393,7 +433,9 @@ impl HasFocus for WorkflowState {
     }
 
     fn area(&self) -> Rect {
-        if let Some(run_view) = &self.run_view {
+        if let Some(editor) = &self.editor {
+            editor.area()
+        } else if let Some(run_view) = &self.run_view {
             run_view.area()
         } else if let Some(view) = &self.input_view {
             view.area()
@@ -650,6 +692,20 @@ impl WorkflowState {
             ..Default::default()
         });
     }
+
+    /// Initializes the provider selector for a step argument in the workflow editor.
+    pub fn open_selector_for_editor(&mut self, provider_id: String, resolved_args: serde_json::Map<String, Value>) {
+        self.collector = Some(CollectorViewState {
+            provider_id,
+            resolved_args,
+            status: SelectorStatus::Loading,
+            apply_target: CollectorApplyTarget::WorkflowEditor,
+            filter: TextInputState::new(),
+            manual_override: TextInputState::new(),
+            selection_source: CollectorSelectionSource::Table,
+            ..Default::default()
+        });
+    }
 }
 
 #[cfg(test)]
@@ -687,6 +743,7 @@ mod workflow_run_tests {
                 "step_ok": "${{ steps.first.ok }}"
             })),
             requires: None,
+            schedule: None,
         }
     }
 
@@ -723,6 +780,7 @@ mod workflow_run_tests {
             }],
             final_output: None,
             requires: None,
+            schedule: None,
         };
 
         let mut run_state = WorkflowRunState::new(workflow.clone());
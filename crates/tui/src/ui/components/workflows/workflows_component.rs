@@ -2,15 +2,22 @@ use crate::app::App;
 use crate::cmd::parse_workflow_definition;
 use crate::ui::components::common::{ConfirmationModalButton, ConfirmationModalOpts};
 use crate::ui::components::component::Component;
-use crate::ui::components::workflows::list::WorkflowListEntry;
+use crate::ui::components::workflows::editor::WorkflowEditorState;
+use crate::ui::components::workflows::graph_view::build_workflow_graph_lines;
+use crate::ui::components::workflows::list::{WorkflowListEntry, WorkflowScheduleStatus};
+use crate::ui::keymap::{KeyAction, KeyScope};
 use crate::ui::theme::theme_helpers as th;
 use crate::ui::theme::theme_helpers::ButtonType;
 use crate::ui::theme::theme_helpers::create_spans_with_match;
 use anyhow::{Result, anyhow};
+use chrono::{DateTime, Local, Utc};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use oatty_engine::WorkflowRunState;
+use oatty_engine::workflow::graph::WorkflowGraph;
+use oatty_engine::workflow::lint::WorkflowLintReport;
+use oatty_mcp::find_manifest_record;
 use oatty_types::workflow::{WorkflowCatalogRequirement, WorkflowCatalogRequirementSourceType, collect_missing_catalog_requirements};
-use oatty_types::{Effect, ExecOutcome, MessageType, Modal, Msg, Route, validate_candidate_value};
+use oatty_types::{Effect, ExecOutcome, MessageType, Modal, Msg, Route};
 use oatty_util::{HistoryKey, expand_tilde, value_contains_secret, workflow_input_uses_history};
 use ratatui::layout::Position;
 use ratatui::widgets::ListItem;
@@ -120,7 +127,14 @@ impl WorkflowsComponent {
 
         let search_inner_area = self.render_search_bar(frame, layout[0], app);
         let (import_button_area, remove_button_area) = self.render_action_buttons(frame, layout[1], app);
-        let list_area = self.render_workflow_list(frame, layout[2], app, &title);
+        let list_panel_area = if app.workflows.list.is_graph_visible() {
+            let columns = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(layout[2]);
+            self.render_workflow_graph(frame, columns[1], app);
+            columns[0]
+        } else {
+            layout[2]
+        };
+        let list_area = self.render_workflow_list(frame, list_panel_area, app, &title);
         self.layout = WorkflowsLayout {
             search_area: layout[0],
             search_inner_area,
@@ -205,6 +219,7 @@ impl WorkflowsComponent {
         let filter_input = app.workflows.search_query();
         let identifier_style = theme.syntax_type_style();
         let summary_style = theme.syntax_string_style();
+        let schedule_style = theme.text_muted_style();
         let highlight_style = theme.search_highlight_style();
         let (items, filtered_count) = {
             let state = &app.workflows;
@@ -227,9 +242,29 @@ impl WorkflowsComponent {
                             ),
                             width = title_width
                         );
-                        let summary = Self::summarize_workflow_entry(entry, available_summary_width);
+                        let schedule_summary = state
+                            .schedule_status(entry.display_identifier())
+                            .map(Self::describe_schedule_status)
+                            .filter(|_| !entry.is_invalid());
+                        let lint_badge = state.lint_report(entry.display_identifier()).map(|report| {
+                            let style = if report.is_clean() {
+                                theme.status_warning()
+                            } else {
+                                theme.status_error()
+                            };
+                            (Self::describe_lint_report(report), style)
+                        });
+                        let schedule_width = schedule_summary.as_ref().map(|text| text.chars().count()).unwrap_or(0)
+                            + lint_badge.as_ref().map(|(text, _)| text.chars().count()).unwrap_or(0);
+                        let summary = Self::summarize_workflow_entry(entry, available_summary_width.saturating_sub(schedule_width));
                         let needle = filter_input.to_string();
                         let mut spans = create_spans_with_match(needle.clone(), identifier_cell, identifier_style, highlight_style);
+                        if let Some((lint_badge, lint_style)) = lint_badge {
+                            spans.push(Span::styled(lint_badge, lint_style));
+                        }
+                        if let Some(schedule_summary) = schedule_summary {
+                            spans.push(Span::styled(schedule_summary, schedule_style));
+                        }
                         spans.extend(create_spans_with_match(needle, summary, summary_style, highlight_style));
                         let mut list_item = ListItem::from(Line::from(spans));
                         if self.mouse_over_idx.is_some_and(|mouse_idx| mouse_idx == idx) {
@@ -263,6 +298,44 @@ impl WorkflowsComponent {
         list_area
     }
 
+    /// Renders the dependency graph of the selected workflow.
+    fn render_workflow_graph(&self, frame: &mut Frame, area: Rect, app: &App) {
+        let theme = &*app.ctx.theme;
+        let block = th::block(theme, Some("Step Graph"), false);
+        let lines = match app.workflows.selected_workflow() {
+            Some(workflow) => build_workflow_graph_lines(&WorkflowGraph::from_workflow(workflow), theme, &|_| None),
+            None => vec![Line::from(Span::styled(
+                "Select a valid workflow to view its step graph.",
+                theme.text_muted_style(),
+            ))],
+        };
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    /// Formats the next and last run times of a scheduled workflow in local time.
+    fn describe_schedule_status(status: &WorkflowScheduleStatus) -> String {
+        let format_time = |time: DateTime<Utc>| time.with_timezone(&Local).format("%m-%d %H:%M").to_string();
+        let next_run = status.next_run.map(format_time).unwrap_or_else(|| "—".to_string());
+        let last_run = status
+            .last_run
+            .as_ref()
+            .map(|entry| format!("{} {}", entry.status, format_time(entry.timestamp)))
+            .unwrap_or_else(|| "never".to_string());
+        format!("⏱ next {next_run} · last {last_run}  ")
+    }
+
+    /// Formats lint finding counts as a compact badge, e.g. `✗1 ⚠2`.
+    fn describe_lint_report(report: &WorkflowLintReport) -> String {
+        let mut parts = Vec::new();
+        if report.error_count() > 0 {
+            parts.push(format!("✗{}", report.error_count()));
+        }
+        if report.warning_count() > 0 {
+            parts.push(format!("⚠{}", report.warning_count()));
+        }
+        format!("{}  ", parts.join(" "))
+    }
+
     fn summarize_workflow_entry(entry: &WorkflowListEntry, max_width: usize) -> String {
         let summary_source = entry
             .display_description()
@@ -307,9 +380,7 @@ impl WorkflowsComponent {
                     if stored.value.is_null() || value_contains_secret(&stored.value) {
                         continue;
                     }
-                    if let Some(validation) = &definition.validate
-                        && let Err(error) = validate_candidate_value(&stored.value, validation)
-                    {
+                    if let Err(error) = definition.validate_value(&stored.value) {
                         let message = format!("History default for '{}' failed validation: {}", input_name, error);
                         warn!(
                             input = %input_name,
@@ -369,6 +440,26 @@ impl WorkflowsComponent {
         vec![Effect::ShowModal(Modal::Confirmation)]
     }
 
+    /// Opens the selected workflow's manifest in the authoring editor.
+    fn edit_selected_workflow(&mut self, app: &mut App) -> Vec<Effect> {
+        let Some((workflow_id, _)) = app.workflows.selected_workflow_removal_target() else {
+            return Vec::new();
+        };
+        match find_manifest_record(&workflow_id) {
+            Ok(Some(record)) => open_editor(
+                app,
+                WorkflowEditorState::from_manifest(record.definition, record.path, record.format),
+            ),
+            Ok(None) => vec![Effect::Log(format!(
+                "Workflow '{workflow_id}' has no manifest in the workflows directory to edit."
+            ))],
+            Err(error) => vec![Effect::Log(format!(
+                "Failed to open workflow '{workflow_id}' for editing: {error:#}"
+            ))],
+        }
+    }
+
+    #[allow(clippy::collapsible_match)]
     fn handle_exec_completed(&mut self, outcome: ExecOutcome, app: &mut App) -> Vec<Effect> {
         match outcome {
             ExecOutcome::FileContents(contents, _) | ExecOutcome::RemoteFileContents(contents, _) => {
@@ -642,6 +733,13 @@ impl WorkflowsComponent {
     }
 }
 
+/// Switches to the editor route with `editor` as the active draft.
+fn open_editor(app: &mut App, mut editor: WorkflowEditorState) -> Vec<Effect> {
+    editor.refresh_if_changed(&app.ctx.command_registry);
+    app.workflows.begin_editor(editor);
+    vec![Effect::SwitchTo(Route::WorkflowEditor)]
+}
+
 impl Component for WorkflowsComponent {
     fn handle_message(&mut self, app: &mut App, msg: Msg) -> Vec<Effect> {
         match msg {
@@ -655,13 +753,14 @@ impl Component for WorkflowsComponent {
         }
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_key_events(&mut self, app: &mut App, key: KeyEvent) -> Vec<Effect> {
-        if key.modifiers.contains(KeyModifiers::CONTROL) {
-            match key.code {
-                KeyCode::Char('o') => return self.handle_import_workflow(),
-                KeyCode::Char('r') => return self.prompt_remove_workflow(app),
-                _ => {}
-            }
+        match app.ctx.keymap.action(KeyScope::Workflows, &key) {
+            Some(KeyAction::WorkflowsImport) => return self.handle_import_workflow(),
+            Some(KeyAction::WorkflowsRemove) => return self.prompt_remove_workflow(app),
+            Some(KeyAction::WorkflowsNew) => return open_editor(app, WorkflowEditorState::new_draft()),
+            Some(KeyAction::WorkflowsEdit) => return self.edit_selected_workflow(app),
+            _ => {}
         }
 
         // Handle tab/backtab to switch focus between focusable fields.
@@ -700,35 +799,38 @@ impl Component for WorkflowsComponent {
         }
 
         // Handle key events for the list.
-        match key.code {
-            KeyCode::Esc => {
-                if !app.workflows.search_query().is_empty() {
-                    app.workflows.clear_search();
-                    app.focus.focus(&app.workflows.f_search);
-                }
-            }
-            KeyCode::Down => app.workflows.select_next(),
-            KeyCode::Up => app.workflows.select_prev(),
-            KeyCode::PageUp => {
+        match app.ctx.keymap.resolve(KeyScope::Workflows, &key) {
+            Some(KeyAction::MoveDown) => app.workflows.select_next(),
+            Some(KeyAction::MoveUp) => app.workflows.select_prev(),
+            Some(KeyAction::WorkflowsToggleGraph) => app.workflows.list.toggle_graph(),
+            Some(KeyAction::PageUp) => {
                 app.workflows.list_state().scroll_up_by(10);
             }
-            KeyCode::PageDown => {
+            Some(KeyAction::PageDown) => {
                 app.workflows.list_state().scroll_down_by(10);
             }
-            KeyCode::Home => {
+            Some(KeyAction::ScrollTop) => {
                 app.workflows.list_state().scroll_up_by(u16::MAX);
             }
-            KeyCode::End => {
+            Some(KeyAction::ScrollBottom) => {
                 app.workflows.list_state().scroll_down_by(u16::MAX);
             }
-            KeyCode::Char(' ') | KeyCode::Enter => {
-                if let Err(error) = self.open_workflow_inputs(app) {
-                    effects.push(Effect::Log(format!("Failed to open workflow inputs: {error}")));
-                } else {
-                    effects.push(Effect::SwitchTo(Route::WorkflowInputs));
+            _ => match key.code {
+                KeyCode::Esc => {
+                    if !app.workflows.search_query().is_empty() {
+                        app.workflows.clear_search();
+                        app.focus.focus(&app.workflows.f_search);
+                    }
                 }
-            }
-            _ => {}
+                KeyCode::Char(' ') | KeyCode::Enter => {
+                    if let Err(error) = self.open_workflow_inputs(app) {
+                        effects.push(Effect::Log(format!("Failed to open workflow inputs: {error}")));
+                    } else {
+                        effects.push(Effect::SwitchTo(Route::WorkflowInputs));
+                    }
+                }
+                _ => {}
+            },
         }
 
         effects
@@ -801,6 +903,15 @@ impl Component for WorkflowsComponent {
 
     fn get_hint_spans(&self, app: &App) -> Vec<Span<'_>> {
         let theme = &*app.ctx.theme;
+        let keymap = &app.ctx.keymap;
+        let scroll = keymap.label_pair(KeyAction::MoveUp, KeyAction::MoveDown);
+        let page = keymap.label_pair(KeyAction::PageUp, KeyAction::PageDown);
+        let jump = keymap.label_pair(KeyAction::ScrollTop, KeyAction::ScrollBottom);
+        let graph = keymap.label(KeyAction::WorkflowsToggleGraph);
+        let import = keymap.label(KeyAction::WorkflowsImport);
+        let new = keymap.label(KeyAction::WorkflowsNew);
+        let edit = keymap.label(KeyAction::WorkflowsEdit);
+        let remove = keymap.label(KeyAction::WorkflowsRemove);
         let mut hints: Vec<(&str, &str)> = Vec::new();
 
         let search_focused = app.workflows.f_search.get();
@@ -818,14 +929,19 @@ impl Component for WorkflowsComponent {
             hints.push(("Enter/Space", " Remove workflow  "));
         }
         if app.workflows.list.f_list.get() {
-            hints.push(("↑/↓", " Select  "));
-            hints.push(("PgUp/PgDn", " Page  "));
-            hints.push(("Home/End", " Jump  "));
+            hints.push((&scroll, " Select  "));
+            hints.push((&page, " Page  "));
+            hints.push((&jump, " Jump  "));
+            hints.push((&graph, " Toggle graph  "));
             hints.push(("Enter", " Open inputs"));
         }
-        hints.push(("Ctrl+O", " Import workflow  "));
+        hints.push((&import, " Import workflow  "));
+        hints.push((&new, " New workflow  "));
+        if app.workflows.selected_workflow_removal_target().is_some() {
+            hints.push((&edit, " Edit workflow  "));
+        }
         if app.workflows.selected_workflow_removal_target().is_some() {
-            hints.push(("Ctrl+R", " Remove workflow"));
+            hints.push((&remove, " Remove workflow"));
         }
 
         th::build_hint_spans(theme, &hints)
@@ -0,0 +1,747 @@
+//! Bindable actions, the scopes they are active in, and their preset chords.
+
+/// Where an action is active. Conflicts are only possible between actions
+/// whose scopes can receive the same key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyScope {
+    Global,
+    Navigation,
+    Palette,
+    Browser,
+    History,
+    Results,
+    Diff,
+    JsonEditor,
+    Logs,
+    LogDetails,
+    Workflows,
+    WorkflowInputs,
+    WorkflowCollector,
+    WorkflowRun,
+    WorkflowEditor,
+    WorkflowOutline,
+    WorkflowForm,
+    Plugins,
+    PluginDetails,
+    PluginEditor,
+    Library,
+    McpServer,
+}
+
+impl KeyScope {
+    /// Display order for the key binding reference.
+    pub const ALL: [KeyScope; 22] = [
+        KeyScope::Global,
+        KeyScope::Navigation,
+        KeyScope::Palette,
+        KeyScope::Browser,
+        KeyScope::History,
+        KeyScope::Results,
+        KeyScope::Diff,
+        KeyScope::JsonEditor,
+        KeyScope::Logs,
+        KeyScope::LogDetails,
+        KeyScope::Workflows,
+        KeyScope::WorkflowInputs,
+        KeyScope::WorkflowCollector,
+        KeyScope::WorkflowRun,
+        KeyScope::WorkflowEditor,
+        KeyScope::WorkflowOutline,
+        KeyScope::WorkflowForm,
+        KeyScope::Plugins,
+        KeyScope::PluginDetails,
+        KeyScope::PluginEditor,
+        KeyScope::Library,
+        KeyScope::McpServer,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            KeyScope::Global => "Global",
+            KeyScope::Navigation => "Lists and scrolling",
+            KeyScope::Palette => "Command palette",
+            KeyScope::Browser => "Command browser",
+            KeyScope::History => "History",
+            KeyScope::Results => "Results",
+            KeyScope::Diff => "Diff",
+            KeyScope::JsonEditor => "JSON editor",
+            KeyScope::Logs => "Logs",
+            KeyScope::LogDetails => "Log details",
+            KeyScope::Workflows => "Workflows",
+            KeyScope::WorkflowInputs => "Workflow inputs",
+            KeyScope::WorkflowCollector => "Workflow value collector",
+            KeyScope::WorkflowRun => "Workflow run",
+            KeyScope::WorkflowEditor => "Workflow editor",
+            KeyScope::WorkflowOutline => "Workflow editor outline",
+            KeyScope::WorkflowForm => "Workflow editor fields",
+            KeyScope::Plugins => "Plugins",
+            KeyScope::PluginDetails => "Plugin details",
+            KeyScope::PluginEditor => "Plugin editor",
+            KeyScope::Library => "Library",
+            KeyScope::McpServer => "MCP server",
+        }
+    }
+
+    /// Whether views in this scope also respond to the navigation bindings.
+    pub fn uses_navigation(self) -> bool {
+        matches!(
+            self,
+            KeyScope::Browser
+                | KeyScope::History
+                | KeyScope::Results
+                | KeyScope::Diff
+                | KeyScope::Logs
+                | KeyScope::LogDetails
+                | KeyScope::Workflows
+                | KeyScope::WorkflowRun
+        )
+    }
+
+    /// Whether this scope lives in a modal, where global bindings do not apply.
+    pub fn is_modal(self) -> bool {
+        matches!(
+            self,
+            KeyScope::Results
+                | KeyScope::Diff
+                | KeyScope::JsonEditor
+                | KeyScope::LogDetails
+                | KeyScope::WorkflowCollector
+                | KeyScope::PluginDetails
+        )
+    }
+
+    /// The enclosing scope for panes of a larger view, whose bindings stay active.
+    pub fn parent(self) -> Option<KeyScope> {
+        match self {
+            KeyScope::WorkflowOutline | KeyScope::WorkflowForm => Some(KeyScope::WorkflowEditor),
+            _ => None,
+        }
+    }
+
+    /// Whether a key press can reach actions of both scopes.
+    pub fn overlaps(self, other: KeyScope) -> bool {
+        let reaches = |scope: KeyScope, shared: KeyScope| match shared {
+            KeyScope::Navigation => scope.uses_navigation(),
+            KeyScope::Global => !scope.is_modal(),
+            _ => scope.parent() == Some(shared),
+        };
+        self == other || reaches(self, other) || reaches(other, self)
+    }
+}
+
+/// A user-visible command that can be bound to one or more key chords.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum KeyAction {
+    ToggleLogs,
+    OpenThemePicker,
+    ShowKeybindings,
+    OpenResults,
+
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    PageUp,
+    PageDown,
+    ScrollTop,
+    ScrollBottom,
+
+    PaletteHelp,
+    PaletteEditJson,
+
+    BrowserEditJson,
+
+    HistoryRerun,
+    HistoryEdit,
+    HistoryDiff,
+    HistoryStatusFilter,
+    HistoryCatalogFilter,
+
+    ResultsWatch,
+    ResultsSendToPalette,
+    ResultsExport,
+    ResultsCopy,
+    ResultsPinPreview,
+    ResultsNextTab,
+    ResultsPreviousTab,
+    ResultsPinTab,
+    ResultsCloseTab,
+    ResultsRerun,
+    ResultsCancel,
+
+    DiffAlignmentKey,
+
+    JsonEditorApply,
+    JsonEditorComplete,
+    JsonEditorFormat,
+    JsonEditorSkeleton,
+    JsonEditorLoad,
+    JsonEditorSave,
+
+    LogsSearch,
+    LogsCopy,
+    LogsPrettyJson,
+    LogDetailsCopy,
+
+    WorkflowsImport,
+    WorkflowsRemove,
+    WorkflowsNew,
+    WorkflowsEdit,
+    WorkflowsToggleGraph,
+    WorkflowInputsStepPlan,
+    WorkflowInputsManualEntry,
+    CollectorRefresh,
+    CollectorPickFile,
+    RunToggleGraph,
+    EditorSave,
+    EditorAddStep,
+    EditorAddInput,
+    EditorRemove,
+    EditorFieldPicker,
+    EditorProviderValues,
+    EditorClearField,
+
+    PluginsSearch,
+    PluginsAdd,
+    PluginsDetails,
+    PluginsStart,
+    PluginsStop,
+    PluginsRestart,
+    PluginsEdit,
+    PluginsDelete,
+    PluginDetailsReload,
+    PluginDetailsRestart,
+    PluginDetailsStart,
+    PluginDetailsStop,
+    PluginDetailsAuthorize,
+    PluginEditorValidate,
+    PluginEditorSave,
+
+    LibraryImport,
+    LibraryRemove,
+    LibraryAddUrl,
+    LibraryRemoveUrl,
+
+    McpAuditTrail,
+    McpAuditSinceFilter,
+    McpAuditStatusFilter,
+    McpAuditCatalogFilter,
+    McpNewToken,
+    McpRevokeClient,
+}
+
+/// Static description of an action: config id, scope, help text, and preset chords.
+#[derive(Debug)]
+pub struct ActionSpec {
+    pub action: KeyAction,
+    /// Identifier used in the keymap file, e.g. `results.export`.
+    pub id: &'static str,
+    pub scope: KeyScope,
+    pub description: &'static str,
+    pub default_chords: &'static [&'static str],
+    /// Chords used by the vi preset when they differ from the defaults.
+    pub vi_chords: Option<&'static [&'static str]>,
+}
+
+const fn spec(
+    action: KeyAction,
+    id: &'static str,
+    scope: KeyScope,
+    description: &'static str,
+    default_chords: &'static [&'static str],
+) -> ActionSpec {
+    ActionSpec {
+        action,
+        id,
+        scope,
+        description,
+        default_chords,
+        vi_chords: None,
+    }
+}
+
+const fn vi(mut spec: ActionSpec, vi_chords: &'static [&'static str]) -> ActionSpec {
+    spec.vi_chords = Some(vi_chords);
+    spec
+}
+
+use KeyAction as A;
+use KeyScope as S;
+
+/// Every bindable action in reference order.
+pub const ACTION_SPECS: &[ActionSpec] = &[
+    spec(A::ToggleLogs, "global.toggle_logs", S::Global, "Toggle the logs panel", &["ctrl+l"]),
+    spec(
+        A::OpenThemePicker,
+        "global.theme_picker",
+        S::Global,
+        "Open the theme picker",
+        &["ctrl+t"],
+    ),
+    spec(
+        A::ShowKeybindings,
+        "global.keybindings",
+        S::Global,
+        "Show key bindings",
+        &["ctrl+k"],
+    ),
+    spec(
+        A::OpenResults,
+        "global.results",
+        S::Global,
+        "Open the results workspace",
+        &["ctrl+b"],
+    ),
+    spec(A::MoveUp, "navigation.up", S::Navigation, "Move up", &["up", "k"]),
+    spec(A::MoveDown, "navigation.down", S::Navigation, "Move down", &["down", "j"]),
+    vi(
+        spec(A::MoveLeft, "navigation.left", S::Navigation, "Move left", &["left"]),
+        &["left", "h"],
+    ),
+    vi(
+        spec(A::MoveRight, "navigation.right", S::Navigation, "Move right", &["right"]),
+        &["right", "l"],
+    ),
+    vi(
+        spec(A::PageUp, "navigation.page_up", S::Navigation, "Page up", &["pageup"]),
+        &["pageup", "ctrl+u"],
+    ),
+    vi(
+        spec(A::PageDown, "navigation.page_down", S::Navigation, "Page down", &["pagedown"]),
+        &["pagedown", "ctrl+d"],
+    ),
+    vi(
+        spec(A::ScrollTop, "navigation.top", S::Navigation, "Jump to the top", &["home"]),
+        &["home", "g"],
+    ),
+    vi(
+        spec(A::ScrollBottom, "navigation.bottom", S::Navigation, "Jump to the bottom", &["end"]),
+        &["end", "G"],
+    ),
+    spec(
+        A::PaletteHelp,
+        "palette.help",
+        S::Palette,
+        "Show help for the current command",
+        &["f1"],
+    ),
+    spec(
+        A::PaletteEditJson,
+        "palette.edit_json",
+        S::Palette,
+        "Edit the object or array flag under the cursor as JSON",
+        &["ctrl+e"],
+    ),
+    spec(
+        A::BrowserEditJson,
+        "browser.edit_json",
+        S::Browser,
+        "Edit the request body of the selected command as JSON",
+        &["ctrl+e"],
+    ),
+    spec(A::HistoryRerun, "history.rerun", S::History, "Re-run the selected command", &["r"]),
+    spec(
+        A::HistoryEdit,
+        "history.edit",
+        S::History,
+        "Edit the command in the palette",
+        &["e"],
+    ),
+    spec(A::HistoryDiff, "history.diff", S::History, "Mark or compare for diff", &["d"]),
+    spec(
+        A::HistoryStatusFilter,
+        "history.status_filter",
+        S::History,
+        "Cycle the status filter",
+        &["s"],
+    ),
+    spec(
+        A::HistoryCatalogFilter,
+        "history.catalog_filter",
+        S::History,
+        "Cycle the catalog filter",
+        &["c"],
+    ),
+    spec(A::ResultsWatch, "results.watch", S::Results, "Toggle watch mode", &["w"]),
+    spec(
+        A::ResultsSendToPalette,
+        "results.send_to_palette",
+        S::Results,
+        "Send the selected cell to the palette",
+        &["p"],
+    ),
+    spec(A::ResultsExport, "results.export", S::Results, "Export results to a file", &["x"]),
+    spec(A::ResultsCopy, "results.copy", S::Results, "Copy the selected row", &["c"]),
+    spec(
+        A::ResultsPinPreview,
+        "results.pin_preview",
+        S::Results,
+        "Pin the JSON preview",
+        &["v", "V"],
+    ),
+    spec(
+        A::ResultsNextTab,
+        "results.next_tab",
+        S::Results,
+        "Show the next results tab",
+        &["]"],
+    ),
+    spec(
+        A::ResultsPreviousTab,
+        "results.previous_tab",
+        S::Results,
+        "Show the previous results tab",
+        &["["],
+    ),
+    spec(
+        A::ResultsPinTab,
+        "results.pin_tab",
+        S::Results,
+        "Pin or unpin the results tab",
+        &["b"],
+    ),
+    spec(
+        A::ResultsCloseTab,
+        "results.close_tab",
+        S::Results,
+        "Close the results tab",
+        &["ctrl+w"],
+    ),
+    spec(
+        A::ResultsRerun,
+        "results.rerun",
+        S::Results,
+        "Re-run the command of the results tab",
+        &["r"],
+    ),
+    spec(
+        A::ResultsCancel,
+        "results.cancel",
+        S::Results,
+        "Cancel the run or watch of the results tab",
+        &["s"],
+    ),
+    spec(
+        A::DiffAlignmentKey,
+        "diff.alignment_key",
+        S::Diff,
+        "Cycle the array alignment key",
+        &["a"],
+    ),
+    spec(
+        A::JsonEditorApply,
+        "json_editor.apply",
+        S::JsonEditor,
+        "Apply the value to the command",
+        &["ctrl+s"],
+    ),
+    spec(
+        A::JsonEditorComplete,
+        "json_editor.complete",
+        S::JsonEditor,
+        "Complete a property name from the schema",
+        &["ctrl+space"],
+    ),
+    spec(
+        A::JsonEditorFormat,
+        "json_editor.format",
+        S::JsonEditor,
+        "Pretty-print the value",
+        &["ctrl+f"],
+    ),
+    spec(
+        A::JsonEditorSkeleton,
+        "json_editor.skeleton",
+        S::JsonEditor,
+        "Replace the value with a skeleton from the schema",
+        &["ctrl+r"],
+    ),
+    spec(
+        A::JsonEditorLoad,
+        "json_editor.load",
+        S::JsonEditor,
+        "Load the value from a file",
+        &["ctrl+o"],
+    ),
+    spec(
+        A::JsonEditorSave,
+        "json_editor.save",
+        S::JsonEditor,
+        "Save the value to a file",
+        &["ctrl+w"],
+    ),
+    spec(A::LogsSearch, "logs.search", S::Logs, "Search logs", &["/", "ctrl+f"]),
+    spec(A::LogsCopy, "logs.copy", S::Logs, "Copy the selected entries", &["c"]),
+    spec(A::LogsPrettyJson, "logs.pretty_json", S::Logs, "Toggle pretty JSON", &["v"]),
+    spec(A::LogDetailsCopy, "log_details.copy", S::LogDetails, "Copy the entry", &["c"]),
+    spec(
+        A::WorkflowsImport,
+        "workflows.import",
+        S::Workflows,
+        "Import a workflow",
+        &["ctrl+o"],
+    ),
+    spec(
+        A::WorkflowsRemove,
+        "workflows.remove",
+        S::Workflows,
+        "Remove the selected workflow",
+        &["ctrl+r"],
+    ),
+    spec(A::WorkflowsNew, "workflows.new", S::Workflows, "Create a workflow", &["ctrl+n"]),
+    spec(
+        A::WorkflowsEdit,
+        "workflows.edit",
+        S::Workflows,
+        "Edit the selected workflow",
+        &["ctrl+e"],
+    ),
+    vi(
+        spec(
+            A::WorkflowsToggleGraph,
+            "workflows.toggle_graph",
+            S::Workflows,
+            "Toggle the step graph",
+            &["g"],
+        ),
+        &["v"],
+    ),
+    spec(
+        A::WorkflowInputsStepPlan,
+        "workflow_inputs.step_plan",
+        S::WorkflowInputs,
+        "Show the pre-run step plan",
+        &["ctrl+p"],
+    ),
+    spec(
+        A::WorkflowInputsManualEntry,
+        "workflow_inputs.manual_entry",
+        S::WorkflowInputs,
+        "Enter a value manually",
+        &["f2"],
+    ),
+    spec(
+        A::CollectorRefresh,
+        "workflow_collector.refresh",
+        S::WorkflowCollector,
+        "Reload provider values",
+        &["r", "R"],
+    ),
+    spec(
+        A::CollectorPickFile,
+        "workflow_collector.pick_file",
+        S::WorkflowCollector,
+        "Load a manual value from a file",
+        &["ctrl+o"],
+    ),
+    vi(
+        spec(
+            A::RunToggleGraph,
+            "workflow_run.toggle_graph",
+            S::WorkflowRun,
+            "Toggle the step graph",
+            &["g"],
+        ),
+        &["v"],
+    ),
+    spec(
+        A::EditorSave,
+        "workflow_editor.save",
+        S::WorkflowEditor,
+        "Save the workflow",
+        &["ctrl+s"],
+    ),
+    spec(
+        A::EditorAddStep,
+        "workflow_editor.add_step",
+        S::WorkflowOutline,
+        "Add a step",
+        &["a"],
+    ),
+    spec(
+        A::EditorAddInput,
+        "workflow_editor.add_input",
+        S::WorkflowOutline,
+        "Add an input",
+        &["i"],
+    ),
+    spec(
+        A::EditorRemove,
+        "workflow_editor.remove",
+        S::WorkflowOutline,
+        "Remove the selected step or input",
+        &["d", "delete"],
+    ),
+    spec(
+        A::EditorFieldPicker,
+        "workflow_editor.field_picker",
+        S::WorkflowForm,
+        "Pick a field from step outputs",
+        &["f"],
+    ),
+    spec(
+        A::EditorProviderValues,
+        "workflow_editor.provider_values",
+        S::WorkflowForm,
+        "Choose a provider value",
+        &["p"],
+    ),
+    spec(
+        A::EditorClearField,
+        "workflow_editor.clear_field",
+        S::WorkflowForm,
+        "Clear the selected field",
+        &["x", "delete"],
+    ),
+    spec(A::PluginsSearch, "plugins.search", S::Plugins, "Search plugins", &["ctrl+f"]),
+    spec(A::PluginsAdd, "plugins.add", S::Plugins, "Add a plugin", &["ctrl+a"]),
+    spec(A::PluginsDetails, "plugins.details", S::Plugins, "Open plugin details", &["ctrl+o"]),
+    spec(
+        A::PluginsStart,
+        "plugins.start",
+        S::Plugins,
+        "Start the selected plugin",
+        &["ctrl+s"],
+    ),
+    spec(A::PluginsStop, "plugins.stop", S::Plugins, "Stop the selected plugin", &["ctrl+x"]),
+    spec(
+        A::PluginsRestart,
+        "plugins.restart",
+        S::Plugins,
+        "Restart the selected plugin",
+        &["ctrl+r"],
+    ),
+    spec(A::PluginsEdit, "plugins.edit", S::Plugins, "Edit the selected plugin", &["ctrl+e"]),
+    spec(
+        A::PluginsDelete,
+        "plugins.delete",
+        S::Plugins,
+        "Delete the selected plugin",
+        &["ctrl+d"],
+    ),
+    spec(
+        A::PluginDetailsReload,
+        "plugin_details.reload",
+        S::PluginDetails,
+        "Reload details",
+        &["r"],
+    ),
+    spec(
+        A::PluginDetailsRestart,
+        "plugin_details.restart",
+        S::PluginDetails,
+        "Restart the plugin",
+        &["ctrl+r"],
+    ),
+    spec(
+        A::PluginDetailsStart,
+        "plugin_details.start",
+        S::PluginDetails,
+        "Start the plugin",
+        &["ctrl+s"],
+    ),
+    spec(
+        A::PluginDetailsStop,
+        "plugin_details.stop",
+        S::PluginDetails,
+        "Stop the plugin",
+        &["ctrl+t"],
+    ),
+    spec(
+        A::PluginDetailsAuthorize,
+        "plugin_details.authorize",
+        S::PluginDetails,
+        "Authorize the plugin",
+        &["ctrl+a"],
+    ),
+    spec(
+        A::PluginEditorValidate,
+        "plugin_editor.validate",
+        S::PluginEditor,
+        "Validate the plugin",
+        &["ctrl+v"],
+    ),
+    spec(
+        A::PluginEditorSave,
+        "plugin_editor.save",
+        S::PluginEditor,
+        "Save the plugin",
+        &["ctrl+s"],
+    ),
+    spec(A::LibraryImport, "library.import", S::Library, "Import a catalog", &["ctrl+o"]),
+    spec(
+        A::LibraryRemove,
+        "library.remove",
+        S::Library,
+        "Remove the selected catalog",
+        &["ctrl+r"],
+    ),
+    spec(A::LibraryAddUrl, "library.add_url", S::Library, "Add a base URL", &["ctrl+n"]),
+    spec(
+        A::LibraryRemoveUrl,
+        "library.remove_url",
+        S::Library,
+        "Remove the selected base URL",
+        &["ctrl+d"],
+    ),
+    spec(
+        A::McpAuditTrail,
+        "mcp_server.audit_trail",
+        S::McpServer,
+        "Open the audit trail",
+        &["a"],
+    ),
+    spec(
+        A::McpAuditSinceFilter,
+        "mcp_server.audit_since_filter",
+        S::McpServer,
+        "Cycle the audit trail time filter",
+        &["t"],
+    ),
+    spec(
+        A::McpAuditStatusFilter,
+        "mcp_server.audit_status_filter",
+        S::McpServer,
+        "Cycle the audit trail status filter",
+        &["s"],
+    ),
+    spec(
+        A::McpAuditCatalogFilter,
+        "mcp_server.audit_catalog_filter",
+        S::McpServer,
+        "Cycle the audit trail catalog filter",
+        &["c"],
+    ),
+    spec(
+        A::McpNewToken,
+        "mcp_server.new_token",
+        S::McpServer,
+        "Create a client token",
+        &["n"],
+    ),
+    spec(
+        A::McpRevokeClient,
+        "mcp_server.revoke_client",
+        S::McpServer,
+        "Revoke the selected client",
+        &["d", "delete"],
+    ),
+];
+
+impl KeyAction {
+    pub fn spec(self) -> &'static ActionSpec {
+        ACTION_SPECS
+            .iter()
+            .find(|spec| spec.action == self)
+            .expect("every action has a spec")
+    }
+
+    pub fn id(self) -> &'static str {
+        self.spec().id
+    }
+
+    /// Looks up an action by its keymap file id.
+    pub fn from_id(id: &str) -> Option<Self> {
+        ACTION_SPECS.iter().find(|spec| spec.id == id).map(|spec| spec.action)
+    }
+}
@@ -0,0 +1,193 @@
+//! Key chords: a key code plus modifiers, parsed from and rendered as text
+//! such as `ctrl+l`, `shift+tab`, `f2`, or `G`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Modifiers that take part in matching; lock and keypad states are ignored.
+const MATCHED_MODIFIERS: KeyModifiers = KeyModifiers::CONTROL.union(KeyModifiers::ALT).union(KeyModifiers::SHIFT);
+
+/// A single key press bound to an action.
+///
+/// Characters carry their own case, so `G` and `shift+g` are the same chord
+/// and the Shift modifier is never stored for character keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let mut modifiers = modifiers & MATCHED_MODIFIERS;
+        let code = match code {
+            KeyCode::Char(character) if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::Char(character.to_ascii_uppercase())
+            }
+            KeyCode::BackTab => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::BackTab
+            }
+            other => other,
+        };
+        Self { code, modifiers }
+    }
+
+    /// Whether `key` is this chord.
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        *self == Self::new(key.code, key.modifiers)
+    }
+}
+
+impl From<&KeyEvent> for KeyChord {
+    fn from(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err("empty key chord".to_string());
+        }
+        // A trailing `+` is the plus key itself (`ctrl++`).
+        let (modifier_text, key_text) = match trimmed.strip_suffix("++") {
+            Some(prefix) => (Some(prefix), "+"),
+            None => match trimmed.rsplit_once('+') {
+                Some((prefix, key)) if !key.is_empty() => (Some(prefix), key),
+                _ => (None, trimmed),
+            },
+        };
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in modifier_text.into_iter().flat_map(|prefix| prefix.split('+')) {
+            modifiers |= match modifier.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" | "option" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                other => return Err(format!("unknown modifier '{other}' in '{trimmed}'")),
+            };
+        }
+        let code = parse_key_code(key_text.trim()).ok_or_else(|| format!("unknown key '{key_text}' in '{trimmed}'"))?;
+        let code = match code {
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            // Control chords arrive as lowercase letters.
+            KeyCode::Char(character) if modifiers.contains(KeyModifiers::CONTROL) && !modifiers.contains(KeyModifiers::SHIFT) => {
+                KeyCode::Char(character.to_ascii_lowercase())
+            }
+            other => other,
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+fn parse_key_code(text: &str) -> Option<KeyCode> {
+    let mut characters = text.chars();
+    if let (Some(character), None) = (characters.next(), characters.next()) {
+        return Some(KeyCode::Char(character));
+    }
+    let lowercase = text.to_ascii_lowercase();
+    let code = match lowercase.as_str() {
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "space" => KeyCode::Char(' '),
+        "plus" => KeyCode::Char('+'),
+        function if function.starts_with('f') => KeyCode::F(function[1..].parse().ok().filter(|number| (1..=24).contains(number))?),
+        _ => return None,
+    };
+    Some(code)
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            formatter.write_str("Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            formatter.write_str("Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            formatter.write_str("Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => formatter.write_str("Space"),
+            KeyCode::Char(character) if self.modifiers.contains(KeyModifiers::CONTROL) && character.is_ascii_uppercase() => {
+                write!(formatter, "Shift+{character}")
+            }
+            KeyCode::Char(character) if self.modifiers.contains(KeyModifiers::CONTROL) => {
+                write!(formatter, "{}", character.to_ascii_uppercase())
+            }
+            KeyCode::Char(character) => write!(formatter, "{character}"),
+            KeyCode::Up => formatter.write_str("↑"),
+            KeyCode::Down => formatter.write_str("↓"),
+            KeyCode::Left => formatter.write_str("←"),
+            KeyCode::Right => formatter.write_str("→"),
+            KeyCode::PageUp => formatter.write_str("PgUp"),
+            KeyCode::PageDown => formatter.write_str("PgDn"),
+            KeyCode::Home => formatter.write_str("Home"),
+            KeyCode::End => formatter.write_str("End"),
+            KeyCode::Enter => formatter.write_str("Enter"),
+            KeyCode::Esc => formatter.write_str("Esc"),
+            KeyCode::Tab => formatter.write_str("Tab"),
+            KeyCode::BackTab => formatter.write_str("Shift+Tab"),
+            KeyCode::Backspace => formatter.write_str("Backspace"),
+            KeyCode::Delete => formatter.write_str("Del"),
+            KeyCode::Insert => formatter.write_str("Ins"),
+            KeyCode::F(number) => write!(formatter, "F{number}"),
+            other => write!(formatter, "{other:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(text: &str) -> KeyChord {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_modifiers_named_keys_and_characters() {
+        assert_eq!(chord("ctrl+L"), KeyChord::new(KeyCode::Char('l'), KeyModifiers::CONTROL));
+        assert_eq!(chord("shift+tab"), KeyChord::new(KeyCode::BackTab, KeyModifiers::NONE));
+        assert_eq!(chord("shift+g"), chord("G"));
+        assert_eq!(chord("F2"), KeyChord::new(KeyCode::F(2), KeyModifiers::NONE));
+        assert_eq!(chord("ctrl++"), KeyChord::new(KeyCode::Char('+'), KeyModifiers::CONTROL));
+        assert_eq!(chord("/"), KeyChord::new(KeyCode::Char('/'), KeyModifiers::NONE));
+        assert!("hyper+x".parse::<KeyChord>().is_err());
+        assert!("f99".parse::<KeyChord>().is_err());
+        assert!("".parse::<KeyChord>().is_err());
+    }
+
+    #[test]
+    fn matches_terminal_events_and_renders_labels() {
+        let shifted_g = KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT);
+        assert!(chord("G").matches(&shifted_g));
+        assert!(!chord("g").matches(&shifted_g));
+        assert!(chord("shift+tab").matches(&KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT)));
+        assert!(!chord("r").matches(&KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL)));
+
+        assert_eq!(chord("ctrl+l").to_string(), "Ctrl+L");
+        assert_eq!(chord("pagedown").to_string(), "PgDn");
+        assert_eq!(chord("space").to_string(), "Space");
+    }
+}
@@ -0,0 +1,411 @@
+//! Configurable key bindings.
+//!
+//! Every rebindable shortcut is a [`KeyAction`] with a scope and preset
+//! chords. The keymap starts from a preset and applies overrides from
+//! `~/.config/oatty/keymap.toml` (or the file named by `OATTY_KEYMAP_PATH`):
+//!
+//! ```toml
+//! preset = "vi"
+//!
+//! [bindings]
+//! "global.toggle_logs" = "ctrl+g"
+//! "results.export" = ["x", "ctrl+e"]
+//! "history.diff" = []   # unbind
+//! ```
+//!
+//! Unknown actions, malformed chords, and overrides that collide with
+//! another binding in an overlapping scope are skipped and reported through
+//! [`Keymap::problems`]; the rest of the file still applies.
+
+mod action;
+mod chord;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crossterm::event::KeyEvent;
+use oatty_util::expand_tilde;
+use serde::Deserialize;
+
+pub use action::{ACTION_SPECS, KeyAction, KeyScope};
+pub use chord::KeyChord;
+
+/// Environment variable overriding the keymap file location.
+pub const KEYMAP_PATH_ENV: &str = "OATTY_KEYMAP_PATH";
+
+/// Built-in binding sets a keymap file can start from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeymapPreset {
+    #[default]
+    Default,
+    /// Adds `h`/`l`, `g`/`G`, and `Ctrl+U`/`Ctrl+D` navigation.
+    Vi,
+}
+
+impl KeymapPreset {
+    pub fn id(self) -> &'static str {
+        match self {
+            KeymapPreset::Default => "default",
+            KeymapPreset::Vi => "vi",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        match id.trim().to_ascii_lowercase().as_str() {
+            "default" => Some(KeymapPreset::Default),
+            "vi" | "vim" => Some(KeymapPreset::Vi),
+            _ => None,
+        }
+    }
+}
+
+/// Two actions that share a chord while both can receive the key press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyConflict {
+    pub first: KeyAction,
+    pub second: KeyAction,
+    pub chord: KeyChord,
+}
+
+impl fmt::Display for KeyConflict {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{} is bound to both '{}' and '{}'",
+            self.chord,
+            self.first.id(),
+            self.second.id()
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KeymapFile {
+    preset: Option<String>,
+    #[serde(default)]
+    bindings: BTreeMap<String, ChordList>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ChordList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl ChordList {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            ChordList::One(chord) => vec![chord],
+            ChordList::Many(chords) => chords,
+        }
+    }
+}
+
+/// Resolved chords for every [`KeyAction`].
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    preset: KeymapPreset,
+    path: Option<PathBuf>,
+    bindings: BTreeMap<KeyAction, Vec<KeyChord>>,
+    problems: Vec<String>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::preset(KeymapPreset::Default)
+    }
+}
+
+impl Keymap {
+    /// The bindings of `preset` with no overrides.
+    pub fn preset(preset: KeymapPreset) -> Self {
+        let bindings = ACTION_SPECS
+            .iter()
+            .map(|spec| {
+                let chords = match preset {
+                    KeymapPreset::Vi => spec.vi_chords.unwrap_or(spec.default_chords),
+                    KeymapPreset::Default => spec.default_chords,
+                };
+                let chords = chords.iter().map(|chord| chord.parse().expect("preset chords are valid")).collect();
+                (spec.action, chords)
+            })
+            .collect();
+        Self {
+            preset,
+            path: None,
+            bindings,
+            problems: Vec::new(),
+        }
+    }
+
+    /// Load the keymap file from its default location. A missing file yields
+    /// the default preset; an unreadable or malformed one is reported as a problem.
+    pub fn load() -> Self {
+        let Some(path) = default_keymap_path() else {
+            return Self::default();
+        };
+        match fs::read_to_string(&path) {
+            Ok(contents) => Self::from_toml(&contents, Some(path)),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Self {
+                path: Some(path),
+                ..Self::default()
+            },
+            Err(error) => {
+                let mut keymap = Self::default();
+                keymap.problems.push(format!("failed to read {}: {error}", path.display()));
+                keymap.path = Some(path);
+                keymap
+            }
+        }
+    }
+
+    /// Build a keymap from keymap file contents.
+    pub fn from_toml(contents: &str, path: Option<PathBuf>) -> Self {
+        let file: KeymapFile = match toml::from_str(contents) {
+            Ok(file) => file,
+            Err(error) => {
+                let mut keymap = Self::default();
+                keymap.problems.push(format!("invalid keymap file: {}", error.message()));
+                keymap.path = path;
+                return keymap;
+            }
+        };
+
+        let mut problems = Vec::new();
+        let preset = match file.preset.as_deref() {
+            None => KeymapPreset::Default,
+            Some(id) => KeymapPreset::from_id(id).unwrap_or_else(|| {
+                problems.push(format!("unknown preset '{id}' (expected 'default' or 'vi')"));
+                KeymapPreset::Default
+            }),
+        };
+        let mut keymap = Self::preset(preset);
+        keymap.path = path;
+        keymap.problems = problems;
+        for (id, chords) in file.bindings {
+            keymap.apply_override(&id, chords.into_vec());
+        }
+        keymap
+    }
+
+    fn apply_override(&mut self, id: &str, chord_texts: Vec<String>) {
+        let Some(action) = KeyAction::from_id(id) else {
+            self.problems.push(format!("unknown action '{id}'"));
+            return;
+        };
+        let mut chords: Vec<KeyChord> = Vec::new();
+        for text in chord_texts {
+            match text.parse::<KeyChord>() {
+                Ok(chord) if !chords.contains(&chord) => chords.push(chord),
+                Ok(_) => {}
+                Err(error) => {
+                    self.problems.push(format!("'{id}': {error}"));
+                    return;
+                }
+            }
+        }
+
+        let previous = self.bindings.insert(action, chords).unwrap_or_default();
+        let conflicts: Vec<KeyConflict> = self
+            .conflicts()
+            .into_iter()
+            .filter(|conflict| conflict.first == action || conflict.second == action)
+            .collect();
+        if !conflicts.is_empty() {
+            self.bindings.insert(action, previous);
+            for conflict in conflicts {
+                let other = if conflict.first == action {
+                    conflict.second
+                } else {
+                    conflict.first
+                };
+                self.problems.push(format!(
+                    "'{id}': {} conflicts with '{}'; keeping the preset binding",
+                    conflict.chord,
+                    other.id()
+                ));
+            }
+        }
+    }
+
+    pub fn preset_kind(&self) -> KeymapPreset {
+        self.preset
+    }
+
+    /// The keymap file this keymap was loaded from, whether or not it exists.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Problems found while loading the keymap file.
+    pub fn problems(&self) -> &[String] {
+        &self.problems
+    }
+
+    /// Chords bound to `action`; empty when unbound.
+    pub fn chords(&self, action: KeyAction) -> &[KeyChord] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Whether `key` triggers `action`.
+    pub fn matches(&self, action: KeyAction, key: &KeyEvent) -> bool {
+        self.chords(action).iter().any(|chord| chord.matches(key))
+    }
+
+    /// The action of `scope` that `key` triggers, if any.
+    pub fn action(&self, scope: KeyScope, key: &KeyEvent) -> Option<KeyAction> {
+        ACTION_SPECS
+            .iter()
+            .filter(|spec| spec.scope == scope)
+            .map(|spec| spec.action)
+            .find(|action| self.matches(*action, key))
+    }
+
+    /// The action `key` triggers in a view of `scope`, falling back to the
+    /// navigation bindings for scopes that use them.
+    pub fn resolve(&self, scope: KeyScope, key: &KeyEvent) -> Option<KeyAction> {
+        self.action(scope, key)
+            .or_else(|| scope.uses_navigation().then(|| self.action(KeyScope::Navigation, key)).flatten())
+    }
+
+    /// Hint label for `action`, e.g. `Ctrl+L` or `↑/k`.
+    pub fn label(&self, action: KeyAction) -> String {
+        let chords = self.chords(action);
+        if chords.is_empty() {
+            return "unbound".to_string();
+        }
+        chords.iter().map(ToString::to_string).collect::<Vec<_>>().join("/")
+    }
+
+    /// Hint label for a pair of opposite actions, e.g. `↑/↓` or `PgUp/PgDn`.
+    pub fn label_pair(&self, first: KeyAction, second: KeyAction) -> String {
+        format!("{}/{}", self.label(first), self.label(second))
+    }
+
+    /// Chords shared by two actions that can receive the same key press.
+    pub fn conflicts(&self) -> Vec<KeyConflict> {
+        let mut conflicts = Vec::new();
+        for (index, first) in ACTION_SPECS.iter().enumerate() {
+            for second in &ACTION_SPECS[index + 1..] {
+                if !first.scope.overlaps(second.scope) {
+                    continue;
+                }
+                for chord in self.chords(first.action) {
+                    if self.chords(second.action).contains(chord) {
+                        conflicts.push(KeyConflict {
+                            first: first.action,
+                            second: second.action,
+                            chord: *chord,
+                        });
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+fn default_keymap_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var(KEYMAP_PATH_ENV) {
+        let trimmed = path.trim();
+        if !trimmed.is_empty() {
+            return Some(expand_tilde(trimmed));
+        }
+    }
+    dirs_next::config_dir().map(|dir| dir.join("oatty").join("keymap.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn presets_are_conflict_free_and_cover_every_action() {
+        for preset in [KeymapPreset::Default, KeymapPreset::Vi] {
+            let keymap = Keymap::preset(preset);
+            assert_eq!(keymap.conflicts(), Vec::new(), "{preset:?}");
+            for spec in ACTION_SPECS {
+                assert!(!keymap.chords(spec.action).is_empty(), "{} is unbound", spec.id);
+                assert_eq!(KeyAction::from_id(spec.id), Some(spec.action));
+            }
+        }
+
+        let vi = Keymap::preset(KeymapPreset::Vi);
+        assert!(vi.matches(KeyAction::ScrollBottom, &key(KeyCode::Char('G'), KeyModifiers::SHIFT)));
+        assert!(!Keymap::default().matches(KeyAction::ScrollBottom, &key(KeyCode::Char('G'), KeyModifiers::SHIFT)));
+        assert_eq!(vi.label(KeyAction::PageDown), "PgDn/Ctrl+D");
+    }
+
+    #[test]
+    fn file_overrides_rebind_and_unbind_actions() {
+        let keymap = Keymap::from_toml(
+            r#"
+preset = "vi"
+
+[bindings]
+"global.toggle_logs" = "ctrl+g"
+"results.export" = ["x", "ctrl+e"]
+"history.diff" = []
+"#,
+            None,
+        );
+        assert!(keymap.problems().is_empty(), "{:?}", keymap.problems());
+        assert_eq!(keymap.preset_kind(), KeymapPreset::Vi);
+        assert!(keymap.matches(KeyAction::ToggleLogs, &key(KeyCode::Char('g'), KeyModifiers::CONTROL)));
+        assert!(!keymap.matches(KeyAction::ToggleLogs, &key(KeyCode::Char('l'), KeyModifiers::CONTROL)));
+        assert_eq!(keymap.label(KeyAction::ResultsExport), "x/Ctrl+E");
+        assert_eq!(keymap.label(KeyAction::HistoryDiff), "unbound");
+        assert_eq!(
+            keymap.action(KeyScope::Results, &key(KeyCode::Char('e'), KeyModifiers::CONTROL)),
+            Some(KeyAction::ResultsExport)
+        );
+    }
+
+    #[test]
+    fn invalid_entries_and_conflicts_are_reported_and_skipped() {
+        let keymap = Keymap::from_toml(
+            r#"
+preset = "emacs"
+
+[bindings]
+"history.rerun" = "j"
+"results.teleport" = "t"
+"logs.copy" = "hyper+c"
+"workflows.new" = "ctrl+l"
+"diff.alignment_key" = "ctrl+l"
+"#,
+            None,
+        );
+        let problems = keymap.problems().join("\n");
+        assert!(problems.contains("unknown preset 'emacs'"), "{problems}");
+        assert!(problems.contains("unknown action 'results.teleport'"), "{problems}");
+        assert!(problems.contains("unknown modifier 'hyper'"), "{problems}");
+        assert!(
+            problems.contains("'history.rerun': j conflicts with 'navigation.down'"),
+            "{problems}"
+        );
+        assert!(
+            problems.contains("'workflows.new': Ctrl+L conflicts with 'global.toggle_logs'"),
+            "{problems}"
+        );
+        assert_eq!(keymap.label(KeyAction::HistoryRerun), "r");
+        assert_eq!(keymap.label(KeyAction::WorkflowsNew), "Ctrl+N");
+        // The diff modal does not see global bindings, so Ctrl+L is free there.
+        assert_eq!(keymap.label(KeyAction::DiffAlignmentKey), "Ctrl+L");
+        assert!(keymap.conflicts().is_empty());
+
+        let malformed = Keymap::from_toml("bindings = 3", None);
+        assert_eq!(malformed.problems().len(), 1);
+        assert_eq!(malformed.preset_kind(), KeymapPreset::Default);
+    }
+}
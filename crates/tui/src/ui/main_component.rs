@@ -10,14 +10,20 @@ use super::utils::centered_rect;
 use crate::app::App;
 use crate::ui::components::common::ConfirmationModal;
 use crate::ui::components::common::manual_entry_modal::DefaultManualEntryComponent;
+use crate::ui::components::diff::DiffComponent;
+use crate::ui::components::json_editor::JsonEditorComponent;
 use crate::ui::components::palette::PaletteComponent;
+use crate::ui::components::results::export::EXPORT_EXTENSIONS;
 use crate::ui::components::theme_picker::ThemePickerComponent;
-use crate::ui::components::workflows::{RunViewComponent, WorkflowInputsComponent};
+use crate::ui::components::workflows::{RunViewComponent, WorkflowEditorComponent, WorkflowInputsComponent};
 use crate::ui::components::{
-    BrowserComponent, FilePickerModal, FilePickerState, LibraryComponent, McpHttpServerComponent, PluginsComponent, WorkflowsComponent,
+    BrowserComponent, FilePickerModal, FilePickerState, HistoryComponent, LibraryComponent, McpHttpServerComponent, PluginsComponent,
+    WorkflowsComponent,
 };
+use crate::ui::keymap::{KeyAction, KeyScope};
 use crate::ui::utils::centered_min_max;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+use chrono::Local;
+use crossterm::event::{KeyEvent, MouseEvent};
 use oatty_types::{Effect, Modal, Msg, Route};
 use rat_focus::{FocusBuilder, HasFocus};
 use ratatui::widgets::Clear;
@@ -51,6 +57,9 @@ pub struct MainView {
 
     /// the widget_id of the focus just before a modal is opened
     transient_focus_id: Option<usize>,
+    /// Modal set aside while a child modal (e.g. the export file picker) is open;
+    /// restored instead of closing when the child closes.
+    suspended_modal: Option<(Modal, ModalView)>,
 }
 
 impl MainView {
@@ -61,6 +70,7 @@ impl MainView {
             nav_bar_view: VerticalNavBarComponent::new(),
             logs_view: LogsComponent::default(),
             transient_focus_id: None,
+            suspended_modal: None,
         }
     }
     /// Updates the current route of the application and performs necessary state transitions.
@@ -105,12 +115,14 @@ impl MainView {
 
         let (view, state): (Box<dyn Component>, Box<&dyn HasFocus>) = match route {
             Route::Browser => (Box::new(BrowserComponent::default()), Box::new(&app.browser)),
+            Route::History => (Box::new(HistoryComponent::default()), Box::new(&app.history)),
             Route::Palette => (Box::new(PaletteComponent::default()), Box::new(&app.palette)),
             Route::Plugins => (Box::new(PluginsComponent::default()), Box::new(&app.plugins)),
             Route::McpHttpServer => (Box::new(McpHttpServerComponent::default()), Box::new(&app.mcp_http_server)),
             Route::WorkflowInputs => (Box::new(WorkflowInputsComponent::default()), Box::new(&app.workflows)),
             Route::Workflows => (Box::new(WorkflowsComponent::default()), Box::new(&app.workflows)),
             Route::WorkflowRun => (Box::new(RunViewComponent::default()), Box::new(&app.workflows)),
+            Route::WorkflowEditor => (Box::new(WorkflowEditorComponent::default()), Box::new(&app.workflows)),
             Route::Library => (Box::new(LibraryComponent::default()), Box::new(&app.library)),
         };
 
@@ -124,8 +136,22 @@ impl MainView {
         }
     }
 
+    /// Sets the open modal aside so it is restored when the next modal closes.
+    pub fn suspend_open_modal(&mut self, app: &mut App) {
+        if let Some((modal_kind, modal_view)) = app.open_modal_kind.take().zip(self.modal_view.take()) {
+            self.suspended_modal = Some((modal_kind, modal_view));
+        }
+    }
+
     /// Update the open modal kind (use None to clear).
     pub fn set_open_modal_kind(&mut self, app: &mut App, modal: Option<Modal>) {
+        if modal.is_none()
+            && let Some((modal_kind, modal_view)) = self.suspended_modal.take()
+        {
+            self.modal_view = Some(modal_view);
+            app.open_modal_kind = Some(modal_kind);
+            return;
+        }
         if let Some(modal_kind) = modal.as_ref() {
             let modal_view: ModalView = match modal_kind {
                 Modal::Help => (
@@ -133,9 +159,13 @@ impl MainView {
                     ModalLayout(Box::new(|rect| centered_rect(80, 70, rect))),
                 ),
                 Modal::Results(exec_outcome) => {
-                    let mut table = TableComponent::default();
-                    table.handle_message(app, Msg::ExecCompleted(exec_outcome.clone()));
-                    (Box::new(table), ModalLayout(Box::new(|rect| centered_rect(96, 90, rect))))
+                    if let Some(exec_outcome) = exec_outcome {
+                        app.results.open_tab(*exec_outcome.clone(), &*app.ctx.theme, Local::now());
+                    }
+                    (
+                        Box::new(TableComponent::default()),
+                        ModalLayout(Box::new(|rect| centered_rect(96, 90, rect))),
+                    )
                 }
                 Modal::LogDetails => (
                     Box::new(LogDetailsComponent::default()),
@@ -152,6 +182,11 @@ impl MainView {
                         ModalLayout(Box::new(|rect| centered_rect(70, 80, rect))),
                     )
                 }
+                Modal::Diff => (Box::new(DiffComponent), ModalLayout(Box::new(|rect| centered_rect(96, 90, rect)))),
+                Modal::JsonEditor => (
+                    Box::new(JsonEditorComponent::default()),
+                    ModalLayout(Box::new(|rect| centered_rect(80, 80, rect))),
+                ),
                 Modal::WorkflowCollector => {
                     let component: Box<dyn Component> = Box::new(WorkflowCollectorComponent::default());
                     let layout = if app.workflows.manual_entry_state().is_some() {
@@ -177,14 +212,47 @@ impl MainView {
                         })),
                     )
                 }
+                Modal::ExportResults => {
+                    app.file_picker = Some(FilePickerState::for_save(
+                        EXPORT_EXTENSIONS.to_vec(),
+                        "results.json",
+                        Effect::ExportResults,
+                    ));
+                    (
+                        Box::new(FilePickerModal::default()),
+                        ModalLayout(Box::new(|rect| {
+                            centered_min_max(75, 95, Rect::new(0, 0, 80, 15), Rect::new(0, 0, 160, 150), rect)
+                        })),
+                    )
+                }
+                Modal::JsonEditorLoad | Modal::JsonEditorSave => {
+                    app.file_picker = Some(if matches!(modal_kind, Modal::JsonEditorSave) {
+                        FilePickerState::for_save(
+                            vec!["json"],
+                            &format!("{}.json", app.json_editor.flag_name()),
+                            Effect::SaveJsonEditor,
+                        )
+                    } else {
+                        FilePickerState::new(vec!["json"])
+                    });
+                    (
+                        Box::new(FilePickerModal::default()),
+                        ModalLayout(Box::new(|rect| {
+                            centered_min_max(75, 95, Rect::new(0, 0, 80, 15), Rect::new(0, 0, 160, 150), rect)
+                        })),
+                    )
+                }
                 Modal::ManualEntry => (
                     Box::new(DefaultManualEntryComponent::default()),
                     ModalLayout(Box::new(|rect| centered_rect(55, 45, rect))),
                 ),
             };
             self.modal_view = Some(modal_view);
-            // save the current focus to restore when the modal is closed
-            self.transient_focus_id = app.focus.focused().map(|focus| focus.widget_id());
+            // save the current focus to restore when the modal is closed; a suspended
+            // modal already holds the focus that predates it
+            if self.suspended_modal.is_none() {
+                self.transient_focus_id = app.focus.focused().map(|focus| focus.widget_id());
+            }
         } else {
             self.modal_view = None;
         }
@@ -233,16 +301,29 @@ impl Component for MainView {
             return target.0.handle_key_events(app, key);
         }
 
-        if key.code == KeyCode::Char('l') && key.modifiers.contains(KeyModifiers::CONTROL) {
-            app.logs.toggle_visible();
-            return Vec::new();
-        }
-
-        if key.code == KeyCode::Char('t') && key.modifiers.contains(KeyModifiers::CONTROL) {
-            if app.ctx.theme_picker_available {
-                return vec![Effect::ShowModal(Modal::ThemePicker)];
+        match app.ctx.keymap.action(KeyScope::Global, &key) {
+            Some(KeyAction::ToggleLogs) => {
+                app.logs.toggle_visible();
+                return Vec::new();
+            }
+            Some(KeyAction::OpenThemePicker) => {
+                if app.ctx.theme_picker_available {
+                    return vec![Effect::ShowModal(Modal::ThemePicker)];
+                }
+                return Vec::new();
             }
-            return Vec::new();
+            Some(KeyAction::ShowKeybindings) => {
+                app.help.show_keybindings();
+                return vec![Effect::ShowModal(Modal::Help)];
+            }
+            Some(KeyAction::OpenResults) => {
+                if app.results.tabs().is_empty() {
+                    app.append_log_message("No results yet; run a command to open the results workspace.");
+                    return Vec::new();
+                }
+                return vec![Effect::ShowModal(Modal::Results(None))];
+            }
+            _ => {}
         }
 
         if app.nav_bar.container_focus.get() {
@@ -343,10 +424,20 @@ impl Component for MainView {
             hint_spans.extend(content.get_hint_spans(app));
         }
 
-        hint_spans.extend(th::build_hint_spans(
-            &*app.ctx.theme,
-            &[(" Ctrl+L", " Toggle logs "), ("Ctrl+T", " Theme picker ")],
-        ));
+        let keymap = &app.ctx.keymap;
+        let toggle_logs = format!(" {}", keymap.label(KeyAction::ToggleLogs));
+        let theme_picker = keymap.label(KeyAction::OpenThemePicker);
+        let keybindings = keymap.label(KeyAction::ShowKeybindings);
+        let results = keymap.label(KeyAction::OpenResults);
+        let mut global_hints = vec![
+            (toggle_logs.as_str(), " Toggle logs "),
+            (theme_picker.as_str(), " Theme picker "),
+            (keybindings.as_str(), " Keys "),
+        ];
+        if !app.results.tabs().is_empty() {
+            global_hints.push((results.as_str(), " Results "));
+        }
+        hint_spans.extend(th::build_hint_spans(&*app.ctx.theme, &global_hints));
 
         hint_spans
     }
@@ -4,6 +4,7 @@
 //! including the main layout, modals, components, and utilities.
 
 pub mod components;
+pub mod keymap;
 pub mod main_component;
 pub mod runtime;
 pub mod theme;
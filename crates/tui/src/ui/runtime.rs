@@ -162,10 +162,7 @@ pub async fn run_app(registry: Arc<Mutex<CommandRegistry>>, plugin_engine: Arc<P
     effects.push(Effect::PluginsLoadRequested);
 
     let mcp_config = app.ctx.plugin_engine.config_snapshot().await;
-    if let Some(bind_address) = mcp_config.http_server.bind_address.as_ref() {
-        app.mcp_http_server.set_configured_bind_address(bind_address.clone());
-    }
-    app.mcp_http_server.auto_start = mcp_config.http_server.auto_start;
+    app.mcp_http_server.apply_config(&mcp_config.http_server);
     if app.mcp_http_server.auto_start {
         app.mcp_http_server.mark_starting();
         effects.push(Effect::McpHttpServerStart);
@@ -173,6 +170,7 @@ pub async fn run_app(registry: Arc<Mutex<CommandRegistry>>, plugin_engine: Arc<P
 
     let mut workflow_events: Option<WorkflowRunEventReceiver> = None;
     let mut mcp_http_logs = app.take_pending_mcp_http_logs();
+    let mut mcp_confirmations = app.take_pending_mcp_confirmations();
 
     // Ticking strategy: fast while animating, very slow when idle.
     let fast_interval = Duration::from_millis(100);
@@ -193,7 +191,12 @@ pub async fn run_app(registry: Arc<Mutex<CommandRegistry>>, plugin_engine: Arc<P
     while loop_error.is_none() {
         // Determine if we need animation ticks and adjust the ticker dynamically.
         // note this is a candidate for optimization; it does not scale well.
-        let needs_animation = app.executing || !effects.is_empty() || app.palette.is_provider_loading() || app.workflows.is_running();
+        let needs_animation = app.executing
+            || !effects.is_empty()
+            || app.palette.is_provider_loading()
+            || app.workflows.is_running()
+            || app.results.is_watching()
+            || app.results.is_running();
         let target_interval = if needs_animation { fast_interval } else { idle_interval };
         if target_interval != current_interval {
             current_interval = target_interval;
@@ -227,6 +230,22 @@ pub async fn run_app(registry: Arc<Mutex<CommandRegistry>>, plugin_engine: Arc<P
                     }
                 }
             }
+            maybe_confirmation = async {
+                match mcp_confirmations.as_mut() {
+                    Some(receiver) => receiver.recv().await,
+                    None => None,
+                }
+            }, if mcp_confirmations.is_some() => {
+                match maybe_confirmation {
+                    Some(request) => {
+                        effects.extend(app.enqueue_mcp_confirmation(request));
+                        needs_render = true;
+                    }
+                    None => {
+                        mcp_confirmations = None;
+                    }
+                }
+            }
             // Terminal input events
             maybe_event = input_receiver.recv() => {
                 if let Some(event) = maybe_event {
@@ -257,13 +276,22 @@ pub async fn run_app(registry: Arc<Mutex<CommandRegistry>>, plugin_engine: Arc<P
             }
 
             Some(joined) = pending_execs.next(), if !pending_execs.is_empty() => {
-                let outcome = joined.unwrap_or_else(|error| ExecOutcome::Log(format!("Execution task failed: {error}")));
-                match outcome {
-                    ExecOutcome::ProviderValues { provider_id, cache_key, .. } => {
+                app.running_requests.retain(|_, handle| !handle.is_finished());
+                match joined {
+                    // Cancelled from the results workspace; nothing is waiting for it.
+                    Err(error) if error.is_cancelled() => {}
+                    Err(error) => {
+                        let outcome = ExecOutcome::Log(format!("Execution task failed: {error}"));
+                        effects.extend(main_view.handle_message(&mut app, Msg::ExecCompleted(Box::new(outcome))));
+                    }
+                    Ok(ExecOutcome::ProviderValues { provider_id, cache_key, .. }) => {
                         effects.extend(main_view.handle_message(&mut app, Msg::ProviderValuesReady { provider_id, cache_key }));
                     }
-                    other => {
-                        effects.extend(main_view.handle_message(&mut app, Msg::ExecCompleted(Box::new(other))));
+                    Ok(other) => {
+                        // Re-runs and watches of results tabs land in their tab, even while the workspace is closed.
+                        if !app.results.complete_run(&other, &*app.ctx.theme, Instant::now()) {
+                            effects.extend(main_view.handle_message(&mut app, Msg::ExecCompleted(Box::new(other))));
+                        }
                     }
                 }
                 let still_running = app.active_exec_count.load(Ordering::Relaxed) > 0 || !pending_execs.is_empty();
@@ -301,6 +329,9 @@ pub async fn run_app(registry: Arc<Mutex<CommandRegistry>>, plugin_engine: Arc<P
         if let Some(new_receiver) = app.take_pending_mcp_http_logs() {
             mcp_http_logs = Some(new_receiver);
         }
+        if let Some(new_receiver) = app.take_pending_mcp_confirmations() {
+            mcp_confirmations = Some(new_receiver);
+        }
 
         // Fallback: detect terminal size changes even if no explicit Resize
         // event was received. This handles terminals that miss SIGWINCH or
@@ -357,6 +388,10 @@ fn handle_navigation_effects(app: &mut App<'_>, main_view: &mut MainView, effect
                 }
             }
             Effect::ShowModal(modal) => {
+                let confirms_results = matches!(modal, Modal::Confirmation) && matches!(app.open_modal_kind, Some(Modal::Results(_)));
+                if confirms_results || matches!(modal, Modal::ExportResults | Modal::JsonEditorLoad | Modal::JsonEditorSave) {
+                    main_view.suspend_open_modal(app);
+                }
                 if let Some((mut view, _)) = main_view.modal_view.take() {
                     queued_effects.extend(view.on_route_enter(app));
                 }
@@ -371,6 +406,12 @@ fn handle_navigation_effects(app: &mut App<'_>, main_view: &mut MainView, effect
                     queued_effects.extend(view.on_route_enter(app));
                 }
                 main_view.set_open_modal_kind(app, None);
+                if matches!(closed_modal_kind, Some(Modal::Results(_))) {
+                    let in_flight = app.results.stop_watches();
+                    if !in_flight.is_empty() {
+                        queued_effects.push(Effect::CancelRequests(in_flight));
+                    }
+                }
                 if app.current_route == Route::Palette && matches!(closed_modal_kind, Some(Modal::Results(_))) {
                     app.palette.replay_pending_success_message();
                 }
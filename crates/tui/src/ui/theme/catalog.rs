@@ -1,20 +1,27 @@
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
 use ratatui::style::Color;
 
+use super::roles::ThemeRoles;
 use super::{
     Ansi256Theme, Ansi256ThemeHighContrast, CyberpunkTheme, CyberpunkThemeHighContrast, DraculaTheme, DraculaThemeHighContrast, NordTheme,
-    NordThemeHighContrast, Theme,
+    NordThemeHighContrast, Theme, custom::CustomTheme,
 };
 
 /// Describes a selectable theme inside the TUI.
-#[derive(Clone, Copy, Debug)]
+///
+/// Built-in definitions are `'static`; user-defined themes loaded from the
+/// config directory own their strings and roles.
+#[derive(Clone, Debug)]
 pub struct ThemeDefinition {
     /// Canonical identifier used for persistence.
-    pub id: &'static str,
+    pub id: Cow<'static, str>,
     /// Human-friendly display name.
-    pub label: &'static str,
+    pub label: Cow<'static, str>,
     /// Short description rendered in the picker.
     #[allow(dead_code)]
-    pub description: &'static str,
+    pub description: Cow<'static, str>,
     /// Hex-style color chips shown inside the picker.
     pub swatch: ThemeSwatch,
     /// Theme aliases (e.g., env overrides) that map back to this definition.
@@ -23,13 +30,38 @@ pub struct ThemeDefinition {
     pub is_high_contrast: bool,
     /// Whether the palette targets ANSI/8-bit terminals.
     pub is_ansi_fallback: bool,
-    factory: fn() -> Box<dyn Theme>,
+    pub(crate) factory: ThemeFactory,
+}
+
+/// How a definition produces its theme.
+#[derive(Clone, Debug)]
+pub(crate) enum ThemeFactory {
+    /// Compiled-in palette.
+    Builtin(fn() -> Box<dyn Theme>),
+    /// Palette parsed from a user theme file.
+    Custom { roles: ThemeRoles, path: PathBuf },
 }
 
 impl ThemeDefinition {
     /// Instantiate the theme represented by this definition.
     pub fn build(&self) -> Box<dyn Theme> {
-        (self.factory)()
+        match &self.factory {
+            ThemeFactory::Builtin(factory) => factory(),
+            ThemeFactory::Custom { roles, .. } => Box::new(CustomTheme::new(roles.clone())),
+        }
+    }
+
+    /// File the theme was loaded from; `None` for built-in themes.
+    pub fn source_path(&self) -> Option<&Path> {
+        match &self.factory {
+            ThemeFactory::Builtin(_) => None,
+            ThemeFactory::Custom { path, .. } => Some(path),
+        }
+    }
+
+    /// Whether `name` matches the id or one of the aliases (case-insensitive).
+    fn matches(&self, name: &str) -> bool {
+        self.id.eq_ignore_ascii_case(name) || self.aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name))
     }
 }
 
@@ -44,9 +76,9 @@ pub struct ThemeSwatch {
 /// Ordered list of selectable themes surfaced inside the picker and loaders.
 pub const THEME_DEFINITIONS: &[ThemeDefinition] = &[
     ThemeDefinition {
-        id: "dracula",
-        label: "Dracula",
-        description: "High-contrast default tuned for dark terminals.",
+        id: Cow::Borrowed("dracula"),
+        label: Cow::Borrowed("Dracula"),
+        description: Cow::Borrowed("High-contrast default tuned for dark terminals."),
         swatch: ThemeSwatch {
             background: Color::Rgb(0x28, 0x2A, 0x36),
             accent: Color::Rgb(0xFF, 0x79, 0xC6),
@@ -55,12 +87,12 @@ pub const THEME_DEFINITIONS: &[ThemeDefinition] = &[
         aliases: &["dracula"],
         is_high_contrast: false,
         is_ansi_fallback: false,
-        factory: || Box::new(DraculaTheme::new()),
+        factory: ThemeFactory::Builtin(|| Box::new(DraculaTheme::new())),
     },
     ThemeDefinition {
-        id: "dracula_hc",
-        label: "Dracula High Contrast",
-        description: "Sharper borders and brighter copy for dim displays.",
+        id: Cow::Borrowed("dracula_hc"),
+        label: Cow::Borrowed("Dracula High Contrast"),
+        description: Cow::Borrowed("Sharper borders and brighter copy for dim displays."),
         swatch: ThemeSwatch {
             background: Color::Rgb(0x28, 0x2A, 0x36),
             accent: Color::Rgb(0xBD, 0x93, 0xF9),
@@ -69,12 +101,12 @@ pub const THEME_DEFINITIONS: &[ThemeDefinition] = &[
         aliases: &["dracula_hc", "dracula-high-contrast", "dracula-hc", "draculahc"],
         is_high_contrast: true,
         is_ansi_fallback: false,
-        factory: || Box::new(DraculaThemeHighContrast::new()),
+        factory: ThemeFactory::Builtin(|| Box::new(DraculaThemeHighContrast::new())),
     },
     ThemeDefinition {
-        id: "nord",
-        label: "Nord",
-        description: "Calm polar blues with aurora semantic accents.",
+        id: Cow::Borrowed("nord"),
+        label: Cow::Borrowed("Nord"),
+        description: Cow::Borrowed("Calm polar blues with aurora semantic accents."),
         swatch: ThemeSwatch {
             background: Color::Rgb(0x2E, 0x34, 0x40),
             accent: Color::Rgb(0x88, 0xC0, 0xD0),
@@ -83,12 +115,12 @@ pub const THEME_DEFINITIONS: &[ThemeDefinition] = &[
         aliases: &["nord"],
         is_high_contrast: false,
         is_ansi_fallback: false,
-        factory: || Box::new(NordTheme::new()),
+        factory: ThemeFactory::Builtin(|| Box::new(NordTheme::new())),
     },
     ThemeDefinition {
-        id: "nord_hc",
-        label: "Nord High Contrast",
-        description: "Nord surfaces with stronger borders and body text.",
+        id: Cow::Borrowed("nord_hc"),
+        label: Cow::Borrowed("Nord High Contrast"),
+        description: Cow::Borrowed("Nord surfaces with stronger borders and body text."),
         swatch: ThemeSwatch {
             background: Color::Rgb(0x2E, 0x34, 0x40),
             accent: Color::Rgb(0x5E, 0x81, 0xAC),
@@ -97,12 +129,12 @@ pub const THEME_DEFINITIONS: &[ThemeDefinition] = &[
         aliases: &["nord_hc", "nord-high-contrast", "nord-hc", "nordhc"],
         is_high_contrast: true,
         is_ansi_fallback: false,
-        factory: || Box::new(NordThemeHighContrast::new()),
+        factory: ThemeFactory::Builtin(|| Box::new(NordThemeHighContrast::new())),
     },
     ThemeDefinition {
-        id: "cyberpunk",
-        label: "Cyberpunk",
-        description: "Neon purples with electric cyan focus cues.",
+        id: Cow::Borrowed("cyberpunk"),
+        label: Cow::Borrowed("Cyberpunk"),
+        description: Cow::Borrowed("Neon purples with electric cyan focus cues."),
         swatch: ThemeSwatch {
             background: Color::Rgb(0x0D, 0x02, 0x21),
             accent: Color::Rgb(0x00, 0xF6, 0xFF),
@@ -111,12 +143,12 @@ pub const THEME_DEFINITIONS: &[ThemeDefinition] = &[
         aliases: &["cyberpunk"],
         is_high_contrast: false,
         is_ansi_fallback: false,
-        factory: || Box::new(CyberpunkTheme::new()),
+        factory: ThemeFactory::Builtin(|| Box::new(CyberpunkTheme::new())),
     },
     ThemeDefinition {
-        id: "cyberpunk_hc",
-        label: "Cyberpunk High Contrast",
-        description: "Neon palette with amplified borders and text weight.",
+        id: Cow::Borrowed("cyberpunk_hc"),
+        label: Cow::Borrowed("Cyberpunk High Contrast"),
+        description: Cow::Borrowed("Neon palette with amplified borders and text weight."),
         swatch: ThemeSwatch {
             background: Color::Rgb(0x0D, 0x02, 0x21),
             accent: Color::Rgb(0xFF, 0x4E, 0xCD),
@@ -125,12 +157,12 @@ pub const THEME_DEFINITIONS: &[ThemeDefinition] = &[
         aliases: &["cyberpunk_hc", "cyberpunk-high-contrast", "cyberpunk-hc", "cyberpunkhc"],
         is_high_contrast: true,
         is_ansi_fallback: false,
-        factory: || Box::new(CyberpunkThemeHighContrast::new()),
+        factory: ThemeFactory::Builtin(|| Box::new(CyberpunkThemeHighContrast::new())),
     },
     ThemeDefinition {
-        id: "ansi256",
-        label: "ANSI 256",
-        description: "Indexed fallback for 8-bit terminals.",
+        id: Cow::Borrowed("ansi256"),
+        label: Cow::Borrowed("ANSI 256"),
+        description: Cow::Borrowed("Indexed fallback for 8-bit terminals."),
         swatch: ThemeSwatch {
             background: Color::Indexed(236),
             accent: Color::Indexed(212),
@@ -139,12 +171,12 @@ pub const THEME_DEFINITIONS: &[ThemeDefinition] = &[
         aliases: &["ansi256"],
         is_high_contrast: false,
         is_ansi_fallback: true,
-        factory: || Box::new(Ansi256Theme::new()),
+        factory: ThemeFactory::Builtin(|| Box::new(Ansi256Theme::new())),
     },
     ThemeDefinition {
-        id: "ansi256_hc",
-        label: "ANSI 256 High Contrast",
-        description: "ANSI fallback with brighter borders and text.",
+        id: Cow::Borrowed("ansi256_hc"),
+        label: Cow::Borrowed("ANSI 256 High Contrast"),
+        description: Cow::Borrowed("ANSI fallback with brighter borders and text."),
         swatch: ThemeSwatch {
             background: Color::Indexed(236),
             accent: Color::Indexed(141),
@@ -153,26 +185,26 @@ pub const THEME_DEFINITIONS: &[ThemeDefinition] = &[
         aliases: &["ansi256_hc", "ansi256-high-contrast", "ansi256-hc", "ansi256hc"],
         is_high_contrast: true,
         is_ansi_fallback: true,
-        factory: || Box::new(Ansi256ThemeHighContrast::new()),
+        factory: ThemeFactory::Builtin(|| Box::new(Ansi256ThemeHighContrast::new())),
     },
 ];
 
-/// Iterate over all available definitions.
+/// Iterate over all built-in definitions.
 pub fn all() -> &'static [ThemeDefinition] {
     THEME_DEFINITIONS
 }
 
-/// Locate a definition by canonical id.
-pub fn find_by_id(id: &str) -> Option<&'static ThemeDefinition> {
-    THEME_DEFINITIONS.iter().find(|definition| definition.id.eq_ignore_ascii_case(id))
+/// Locate a definition by canonical id among the built-ins and `custom`.
+pub fn find_by_id<'a>(id: &str, custom: &'a [ThemeDefinition]) -> Option<&'a ThemeDefinition> {
+    THEME_DEFINITIONS
+        .iter()
+        .chain(custom)
+        .find(|definition| definition.id.eq_ignore_ascii_case(id))
 }
 
-/// Locate a definition by alias (case-insensitive).
-pub fn resolve(name: &str) -> Option<&'static ThemeDefinition> {
-    let normalized = name.to_ascii_lowercase();
-    THEME_DEFINITIONS.iter().find(|definition| {
-        definition.aliases.iter().any(|alias| alias.eq_ignore_ascii_case(&normalized)) || definition.id.eq_ignore_ascii_case(&normalized)
-    })
+/// Locate a definition by id or alias (case-insensitive) among the built-ins and `custom`.
+pub fn resolve<'a>(name: &str, custom: &'a [ThemeDefinition]) -> Option<&'a ThemeDefinition> {
+    THEME_DEFINITIONS.iter().chain(custom).find(|definition| definition.matches(name))
 }
 
 /// Preferred default for truecolor terminals.
@@ -0,0 +1,538 @@
+//! User-defined themes loaded from TOML or JSON files.
+//!
+//! Every `*.toml` / `*.json` file in `~/.config/oatty/themes` (or the directory
+//! named by `OATTY_THEMES_DIR`) defines one theme. The file stem is the theme
+//! id; the file supplies every role in [`ThemeRoles`] plus the three picker
+//! swatch colors:
+//!
+//! ```toml
+//! label = "Solarized Dark"
+//! description = "Low-glare blues."
+//! high_contrast = false
+//!
+//! [swatch]
+//! background = "#002b36"
+//! accent = "#268bd2"
+//! selection = "#073642"
+//!
+//! [roles]
+//! background = "#002b36"
+//! text = "#eee8d5"
+//! # ...every other role
+//! ```
+//!
+//! Colors accept `#rrggbb`, ANSI color names (`light-blue`), or 256-color
+//! indices (`"236"`). Files are rejected with an error naming the problem when
+//! roles are missing or unknown, colors do not parse, or a text/background
+//! pair falls below its minimum contrast ratio. A theme whose roles are all
+//! named or indexed colors is ANSI-safe and stays usable on 256-color
+//! terminals.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant, SystemTime};
+
+use oatty_util::expand_tilde;
+use ratatui::style::Color;
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::catalog::{self, ThemeDefinition, ThemeFactory, ThemeSwatch};
+use super::roles::{Theme, ThemeRoles};
+
+/// Environment variable overriding the directory scanned for theme files.
+pub const THEMES_DIR_ENV: &str = "OATTY_THEMES_DIR";
+
+/// Minimum delay between two scans of the themes directory.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Role names accepted in the `[roles]` table, matching the fields of [`ThemeRoles`].
+pub const ROLE_NAMES: [&str; 29] = [
+    "background",
+    "surface",
+    "surface_muted",
+    "border",
+    "divider",
+    "text",
+    "text_secondary",
+    "text_muted",
+    "accent_primary",
+    "accent_secondary",
+    "accent_subtle",
+    "info",
+    "success",
+    "warning",
+    "error",
+    "selection_bg",
+    "selection_fg",
+    "focus",
+    "search_highlight",
+    "syntax_keyword",
+    "syntax_function",
+    "syntax_string",
+    "syntax_number",
+    "syntax_type",
+    "modal_bg",
+    "scrollbar_track",
+    "scrollbar_thumb",
+    "table_row_even",
+    "table_row_odd",
+];
+
+const SWATCH_NAMES: [&str; 3] = ["background", "accent", "selection"];
+
+/// Foreground/background role pairs that must stay legible, with the minimum
+/// WCAG contrast ratio for each. Pairs involving named or indexed colors are
+/// skipped because their RGB value depends on the terminal palette.
+const CONTRAST_PAIRS: [(&str, &str, f64); 5] = [
+    ("text", "background", 4.5),
+    ("text", "surface", 4.5),
+    ("selection_fg", "selection_bg", 4.5),
+    ("text_secondary", "surface", 3.0),
+    ("text_muted", "surface", 2.0),
+];
+
+/// Reasons a theme file could not be loaded.
+#[derive(Debug, Error)]
+pub enum CustomThemeError {
+    #[error("failed to read theme file {}: {source}", .path.display())]
+    Read { path: PathBuf, source: std::io::Error },
+    #[error("failed to parse theme file {}: {message}", .path.display())]
+    Parse { path: PathBuf, message: String },
+    #[error("invalid theme file {}: {}", .path.display(), .problems.join("; "))]
+    Invalid { path: PathBuf, problems: Vec<String> },
+}
+
+/// Theme backed by roles parsed from a user theme file.
+#[derive(Debug, Clone)]
+pub struct CustomTheme {
+    roles: ThemeRoles,
+}
+
+impl CustomTheme {
+    pub fn new(roles: ThemeRoles) -> Self {
+        Self { roles }
+    }
+}
+
+impl Theme for CustomTheme {
+    fn roles(&self) -> &ThemeRoles {
+        &self.roles
+    }
+}
+
+/// On-disk layout shared by the TOML and JSON formats.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    label: Option<String>,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    high_contrast: bool,
+    #[serde(default)]
+    swatch: BTreeMap<String, String>,
+    #[serde(default)]
+    roles: BTreeMap<String, String>,
+}
+
+/// Theme definitions discovered in the themes directory, plus the files that
+/// failed validation. [`CustomThemes::poll`] rescans when files change.
+#[derive(Debug, Default)]
+pub struct CustomThemes {
+    dir: Option<PathBuf>,
+    definitions: Vec<ThemeDefinition>,
+    errors: Vec<CustomThemeError>,
+    snapshot: Vec<(PathBuf, Option<SystemTime>)>,
+    last_poll: Option<Instant>,
+}
+
+impl CustomThemes {
+    /// Load themes from the default themes directory.
+    pub fn discover() -> Self {
+        Self::load_from(default_themes_dir())
+    }
+
+    /// Load themes from `dir`; `None` yields an empty set.
+    pub fn load_from(dir: Option<PathBuf>) -> Self {
+        let mut themes = Self { dir, ..Self::default() };
+        themes.reload();
+        themes
+    }
+
+    /// Valid user-defined themes, ordered by file name.
+    pub fn definitions(&self) -> &[ThemeDefinition] {
+        &self.definitions
+    }
+
+    /// Files rejected during the last scan.
+    pub fn errors(&self) -> &[CustomThemeError] {
+        &self.errors
+    }
+
+    /// Rescan the directory when theme files were added, removed, or modified
+    /// since the last scan. Checks at most once per second; returns `true`
+    /// when the themes were reloaded.
+    pub fn poll(&mut self) -> bool {
+        if self.last_poll.is_some_and(|last_poll| last_poll.elapsed() < POLL_INTERVAL) {
+            return false;
+        }
+        self.last_poll = Some(Instant::now());
+        let Some(dir) = self.dir.as_deref() else {
+            return false;
+        };
+        if scan_theme_files(dir) == self.snapshot {
+            return false;
+        }
+        self.reload();
+        true
+    }
+
+    fn reload(&mut self) {
+        self.definitions.clear();
+        self.errors.clear();
+        self.snapshot = self.dir.as_deref().map(scan_theme_files).unwrap_or_default();
+        for (path, _) in &self.snapshot {
+            match load_theme_file(path) {
+                Ok(definition) => match self.definitions.iter().find(|existing| existing.id == definition.id) {
+                    Some(existing) => self.errors.push(CustomThemeError::Invalid {
+                        path: path.clone(),
+                        problems: vec![format!(
+                            "theme id '{}' is already defined by {}",
+                            definition.id,
+                            existing.source_path().unwrap_or(path).display()
+                        )],
+                    }),
+                    None => self.definitions.push(definition),
+                },
+                Err(error) => self.errors.push(error),
+            }
+        }
+    }
+}
+
+fn default_themes_dir() -> Option<PathBuf> {
+    if let Ok(path) = env::var(THEMES_DIR_ENV) {
+        let trimmed = path.trim();
+        if !trimmed.is_empty() {
+            return Some(expand_tilde(trimmed));
+        }
+    }
+    dirs_next::config_dir().map(|dir| dir.join("oatty").join("themes"))
+}
+
+/// Theme files in `dir` with their modification times, sorted by path.
+fn scan_theme_files(dir: &Path) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(PathBuf, Option<SystemTime>)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && ThemeFormat::from_path(path).is_some())
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|metadata| metadata.modified()).ok();
+            (path, modified)
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ThemeFormat {
+    Toml,
+    Json,
+}
+
+impl ThemeFormat {
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "toml" => Some(Self::Toml),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+fn load_theme_file(path: &Path) -> Result<ThemeDefinition, CustomThemeError> {
+    let contents = fs::read_to_string(path).map_err(|source| CustomThemeError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    parse_theme(path, &contents)
+}
+
+/// Parse and validate the contents of the theme file at `path`.
+pub fn parse_theme(path: &Path, contents: &str) -> Result<ThemeDefinition, CustomThemeError> {
+    let parse_error = |message: String| CustomThemeError::Parse {
+        path: path.to_path_buf(),
+        message,
+    };
+    let file: ThemeFile = match ThemeFormat::from_path(path) {
+        Some(ThemeFormat::Toml) => toml::from_str(contents).map_err(|error| parse_error(error.message().to_string()))?,
+        Some(ThemeFormat::Json) => serde_json::from_str(contents).map_err(|error| parse_error(error.to_string()))?,
+        None => return Err(parse_error("expected a .toml or .json file".to_string())),
+    };
+    let id = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default().to_string();
+    build_definition(id, path, file).map_err(|problems| CustomThemeError::Invalid {
+        path: path.to_path_buf(),
+        problems,
+    })
+}
+
+fn build_definition(id: String, path: &Path, file: ThemeFile) -> Result<ThemeDefinition, Vec<String>> {
+    let mut problems = Vec::new();
+    if catalog::all().iter().any(|definition| definition.id.eq_ignore_ascii_case(&id)) {
+        problems.push(format!("theme id '{id}' is used by a built-in theme; rename the file"));
+    }
+    let roles = parse_colors("role", &file.roles, &ROLE_NAMES, &mut problems);
+    let swatch = parse_colors("swatch color", &file.swatch, &SWATCH_NAMES, &mut problems);
+    if !problems.is_empty() {
+        return Err(problems);
+    }
+    problems.extend(contrast_problems(&roles));
+    if !problems.is_empty() {
+        return Err(problems);
+    }
+
+    let is_ansi_fallback = roles.values().all(|color| !matches!(color, Color::Rgb(..)));
+    let role = |name: &str| roles[name];
+    let theme_roles = ThemeRoles {
+        background: role("background"),
+        surface: role("surface"),
+        surface_muted: role("surface_muted"),
+        border: role("border"),
+        divider: role("divider"),
+        text: role("text"),
+        text_secondary: role("text_secondary"),
+        text_muted: role("text_muted"),
+        accent_primary: role("accent_primary"),
+        accent_secondary: role("accent_secondary"),
+        accent_subtle: role("accent_subtle"),
+        info: role("info"),
+        success: role("success"),
+        warning: role("warning"),
+        error: role("error"),
+        selection_bg: role("selection_bg"),
+        selection_fg: role("selection_fg"),
+        focus: role("focus"),
+        search_highlight: role("search_highlight"),
+        syntax_keyword: role("syntax_keyword"),
+        syntax_function: role("syntax_function"),
+        syntax_string: role("syntax_string"),
+        syntax_number: role("syntax_number"),
+        syntax_type: role("syntax_type"),
+        modal_bg: role("modal_bg"),
+        scrollbar_track: role("scrollbar_track"),
+        scrollbar_thumb: role("scrollbar_thumb"),
+        table_row_even: role("table_row_even"),
+        table_row_odd: role("table_row_odd"),
+    };
+    Ok(ThemeDefinition {
+        label: file.label.unwrap_or_else(|| id.clone()).into(),
+        id: id.into(),
+        description: file.description.into(),
+        swatch: ThemeSwatch {
+            background: swatch["background"],
+            accent: swatch["accent"],
+            selection: swatch["selection"],
+        },
+        aliases: &[],
+        is_high_contrast: file.high_contrast,
+        is_ansi_fallback,
+        factory: ThemeFactory::Custom {
+            roles: theme_roles,
+            path: path.to_path_buf(),
+        },
+    })
+}
+
+/// Parse `values`, recording unknown keys, unparsable colors, and missing names as problems.
+fn parse_colors<'a>(
+    kind: &str,
+    values: &BTreeMap<String, String>,
+    names: &[&'a str],
+    problems: &mut Vec<String>,
+) -> BTreeMap<&'a str, Color> {
+    let mut colors = BTreeMap::new();
+    for (key, value) in values {
+        let Some(name) = names.iter().find(|name| **name == key.as_str()) else {
+            problems.push(format!("unknown {kind} '{key}'"));
+            continue;
+        };
+        match Color::from_str(value.trim()) {
+            Ok(color) => {
+                colors.insert(*name, color);
+            }
+            Err(_) => problems.push(format!("{kind} '{key}' has invalid color '{value}'")),
+        }
+    }
+    let missing: Vec<&str> = names.iter().copied().filter(|name| !values.contains_key(*name)).collect();
+    if !missing.is_empty() {
+        problems.push(format!("missing {kind}s: {}", missing.join(", ")));
+    }
+    colors
+}
+
+fn contrast_problems(roles: &BTreeMap<&str, Color>) -> Vec<String> {
+    CONTRAST_PAIRS
+        .iter()
+        .filter_map(|(foreground, background, minimum)| {
+            let ratio = contrast_ratio(roles[foreground], roles[background])?;
+            (ratio < *minimum)
+                .then(|| format!("low contrast between {foreground} and {background}: {ratio:.1}:1, needs at least {minimum:.1}:1"))
+        })
+        .collect()
+}
+
+/// WCAG 2 contrast ratio between two RGB colors; `None` for named or indexed colors.
+fn contrast_ratio(first: Color, second: Color) -> Option<f64> {
+    let (first, second) = (relative_luminance(first)?, relative_luminance(second)?);
+    let (lighter, darker) = if first >= second { (first, second) } else { (second, first) };
+    Some((lighter + 0.05) / (darker + 0.05))
+}
+
+fn relative_luminance(color: Color) -> Option<f64> {
+    let Color::Rgb(red, green, blue) = color else {
+        return None;
+    };
+    let channel = |value: u8| {
+        let value = f64::from(value) / 255.0;
+        if value <= 0.03928 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    Some(0.2126 * channel(red) + 0.7152 * channel(green) + 0.0722 * channel(blue))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roles_toml(overrides: &[(&str, &str)]) -> String {
+        let mut lines = vec![
+            "label = \"Paper\"".to_string(),
+            "[swatch]".to_string(),
+            "background = \"#fdf6e3\"".to_string(),
+            "accent = \"#268bd2\"".to_string(),
+            "selection = \"#eee8d5\"".to_string(),
+            "[roles]".to_string(),
+        ];
+        for name in ROLE_NAMES {
+            let value = overrides
+                .iter()
+                .find(|(role, _)| *role == name)
+                .map(|(_, value)| *value)
+                .unwrap_or(match name {
+                    "text" | "text_secondary" | "text_muted" | "selection_fg" => "#1a1a1a",
+                    _ => "#fdf6e3",
+                });
+            lines.push(format!("{name} = \"{value}\""));
+        }
+        lines.join("\n")
+    }
+
+    #[test]
+    fn parses_toml_theme_into_definition() {
+        let definition = parse_theme(Path::new("/themes/paper.toml"), &roles_toml(&[("accent_primary", "light-blue")])).unwrap();
+        assert_eq!(definition.id, "paper");
+        assert_eq!(definition.label, "Paper");
+        assert!(!definition.is_ansi_fallback);
+        assert_eq!(definition.source_path(), Some(Path::new("/themes/paper.toml")));
+        let theme = definition.build();
+        assert_eq!(theme.roles().text, Color::Rgb(0x1a, 0x1a, 0x1a));
+        assert_eq!(theme.roles().accent_primary, Color::LightBlue);
+    }
+
+    #[test]
+    fn json_theme_with_indexed_colors_is_ansi_safe() {
+        let roles: serde_json::Map<String, serde_json::Value> = ROLE_NAMES
+            .iter()
+            .map(|name| {
+                (
+                    name.to_string(),
+                    serde_json::Value::from(if name.starts_with("text") { "255" } else { "236" }),
+                )
+            })
+            .collect();
+        let contents = serde_json::json!({
+            "swatch": {"background": "236", "accent": "212", "selection": "239"},
+            "roles": roles,
+        });
+        let definition = parse_theme(Path::new("term.json"), &contents.to_string()).unwrap();
+        assert_eq!(definition.label, "term");
+        assert!(definition.is_ansi_fallback);
+    }
+
+    #[test]
+    fn rejects_missing_unknown_and_invalid_roles() {
+        let contents = roles_toml(&[("text", "not-a-color")])
+            .replace("focus = ", "focuss = ")
+            .replace("modal_bg = \"#fdf6e3\"\n", "");
+        let error = parse_theme(Path::new("broken.toml"), &contents).unwrap_err().to_string();
+        assert!(error.contains("unknown role 'focuss'"), "{error}");
+        assert!(error.contains("role 'text' has invalid color 'not-a-color'"), "{error}");
+        assert!(error.contains("missing roles: focus, modal_bg"), "{error}");
+    }
+
+    #[test]
+    fn rejects_low_contrast_pairs_and_builtin_ids() {
+        let error = parse_theme(Path::new("dracula.toml"), &roles_toml(&[("text", "#eeeeee")]))
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("theme id 'dracula' is used by a built-in theme"), "{error}");
+
+        let error = parse_theme(Path::new("faded.toml"), &roles_toml(&[("text", "#eeeeee")]))
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("low contrast between text and background"), "{error}");
+        assert!(!error.contains("selection_fg"), "{error}");
+    }
+
+    #[test]
+    fn builtin_truecolor_themes_pass_contrast_checks() {
+        for definition in catalog::all().iter().filter(|definition| !definition.is_ansi_fallback) {
+            let theme = definition.build();
+            let roles = theme.roles();
+            let colors = BTreeMap::from([
+                ("text", roles.text),
+                ("text_secondary", roles.text_secondary),
+                ("text_muted", roles.text_muted),
+                ("background", roles.background),
+                ("surface", roles.surface),
+                ("selection_fg", roles.selection_fg),
+                ("selection_bg", roles.selection_bg),
+            ]);
+            assert_eq!(contrast_problems(&colors), Vec::<String>::new(), "{}", definition.id);
+        }
+    }
+
+    #[test]
+    fn poll_reloads_changed_files() {
+        let dir = env::temp_dir().join(format!("oatty-themes-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("paper.toml"), roles_toml(&[])).unwrap();
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let mut themes = CustomThemes::load_from(Some(dir.clone()));
+        assert_eq!(themes.definitions().len(), 1);
+        assert!(themes.errors().is_empty());
+
+        themes.last_poll = None;
+        assert!(!themes.poll());
+
+        fs::write(dir.join("broken.json"), "{").unwrap();
+        themes.last_poll = None;
+        assert!(themes.poll());
+        assert_eq!(themes.definitions().len(), 1);
+        assert!(matches!(themes.errors(), [CustomThemeError::Parse { .. }]));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -6,14 +6,14 @@ use tracing::debug;
 
 /// Loaded theme plus metadata about which definition produced it.
 pub struct LoadedTheme {
-    pub definition: &'static ThemeDefinition,
+    pub definition: ThemeDefinition,
     pub theme: Box<dyn Theme>,
 }
 
 impl LoadedTheme {
-    fn from_definition(definition: &'static ThemeDefinition) -> Self {
+    fn from_definition(definition: &ThemeDefinition) -> Self {
         Self {
-            definition,
+            definition: definition.clone(),
             theme: definition.build(),
         }
     }
@@ -26,26 +26,25 @@ enum ColorCapability {
 }
 
 /// Selects a theme from explicit overrides, user preference, and terminal capability.
-pub fn load(preferred_theme: Option<&str>) -> LoadedTheme {
-    let capability = detect_color_capability();
-    if matches!(capability, ColorCapability::Ansi256) {
-        debug!("ANSI-only terminal detected; ignoring theme overrides and forcing fallback palette.");
-        return LoadedTheme::from_definition(catalog::default_ansi());
-    }
-
-    if let Ok(theme_name) = env::var("TUI_THEME")
-        && let Some(definition) = catalog::resolve(theme_name.trim())
-    {
-        return LoadedTheme::from_definition(definition);
-    }
+///
+/// `custom_themes` are user-defined definitions resolvable alongside the built-ins.
+/// On ANSI-only terminals only ANSI-safe palettes are honoured; anything else
+/// falls back to the ANSI default.
+pub fn load(preferred_theme: Option<&str>, custom_themes: &[ThemeDefinition]) -> LoadedTheme {
+    let requested: Option<&ThemeDefinition> = env::var("TUI_THEME")
+        .ok()
+        .and_then(|theme_name| catalog::resolve(theme_name.trim(), custom_themes))
+        .or_else(|| preferred_theme.and_then(|name| catalog::resolve(name.trim(), custom_themes)));
 
-    if let Some(name) = preferred_theme
-        && let Some(definition) = catalog::resolve(name.trim())
-    {
-        return LoadedTheme::from_definition(definition);
+    if matches!(detect_color_capability(), ColorCapability::Ansi256) {
+        if let Some(definition) = requested.filter(|definition| definition.is_ansi_fallback) {
+            return LoadedTheme::from_definition(definition);
+        }
+        debug!("ANSI-only terminal detected; ignoring truecolor theme overrides and forcing fallback palette.");
+        return LoadedTheme::from_definition(catalog::default_ansi());
     }
 
-    LoadedTheme::from_definition(catalog::default_truecolor())
+    LoadedTheme::from_definition(requested.unwrap_or(catalog::default_truecolor()))
 }
 
 fn detect_color_capability() -> ColorCapability {
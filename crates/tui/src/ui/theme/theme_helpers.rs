@@ -231,7 +231,6 @@ pub fn table_selected_style(theme: &dyn Theme) -> Style {
 }
 
 /// Build tabs with active/inactive styles.
-#[allow(dead_code)]
 pub fn tabs<'a>(theme: &dyn Theme, titles: Vec<Span<'a>>, index: usize) -> Tabs<'a> {
     Tabs::new(titles)
         .select(index)
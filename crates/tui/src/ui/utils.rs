@@ -5,8 +5,9 @@
 //! and other common functionality needed for UI rendering.
 
 use heck::ToTitleCase;
+use oatty_registry::CommandRegistry;
 use oatty_util::http::extract_collection_items;
-use oatty_util::{format_date_mmddyyyy, is_date_like_key, redact_json, redact_sensitive, truncate_with_ellipsis};
+use oatty_util::{format_date_mmddyyyy, is_date_like_key, lex_shell_like, redact_json, redact_sensitive, truncate_with_ellipsis};
 use ratatui::prelude::*;
 use serde_json::{Map, Value};
 use std::collections::{BTreeSet, HashMap};
@@ -151,7 +152,7 @@ pub fn infer_columns(arr: &[Value]) -> Vec<String> {
         .into_iter()
         .map(|header| (header.clone(), *score.get(&header).unwrap_or(&0)))
         .collect();
-    keys.sort_by(|a, b| b.1.cmp(&a.1));
+    keys.sort_by_key(|entry| std::cmp::Reverse(entry.1));
     let mut cols: Vec<String> = keys.into_iter().take(6).map(|(header, _)| header).collect();
     if cols.len() < 4 {
         // Ensure at least 4 columns by adding additional keys by frequency of
@@ -165,7 +166,7 @@ pub fn infer_columns(arr: &[Value]) -> Vec<String> {
             }
         }
         let mut extras: Vec<(String, usize)> = freq.into_iter().filter(|(header, _)| !cols.contains(header)).collect();
-        extras.sort_by(|a, b| b.1.cmp(&a.1));
+        extras.sort_by_key(|entry| std::cmp::Reverse(entry.1));
         for (header, _) in extras.into_iter() {
             cols.push(header);
             if cols.len() >= 4 {
@@ -622,6 +623,20 @@ pub fn build_copy_text(app: &app::App) -> String {
     redact_sensitive(line)
 }
 
+/// HTTP method of the catalog command that `command_line` runs, when it resolves to one.
+pub fn command_line_http_method(registry: &CommandRegistry, command_line: &str) -> Option<String> {
+    let [group, name, ..] = &lex_shell_like(command_line)[..] else {
+        return None;
+    };
+    let command_spec = registry.find_by_group_and_cmd_ref(group, name).ok()?;
+    command_spec.http().map(|http| http.method.to_ascii_uppercase())
+}
+
+/// Whether `command_line` runs a destructive (DELETE) catalog command.
+pub fn is_destructive_command_line(registry: &CommandRegistry, command_line: &str) -> bool {
+    command_line_http_method(registry, command_line).is_some_and(|method| method == "DELETE")
+}
+
 /// Normalize execution payloads so list wrappers render in the results table.
 ///
 /// This delegates to shared collection extraction heuristics used by provider fetch to keep
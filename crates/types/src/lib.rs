@@ -261,6 +261,10 @@ pub mod command {
         /// Optional `ValueProvider` that supplies dynamic values for this flag.
         #[serde(default)]
         pub provider: Option<ValueProvider>,
+        /// Shape of the value for `object` and `array` flags, used to scaffold and
+        /// complete JSON values in the TUI editor.
+        #[serde(default)]
+        pub schema: Option<SchemaProperty>,
     }
 
     /// Represents a positional argument for a command, including its name and help text.
@@ -344,6 +348,7 @@ pub mod command {
     ///     auth_summary: Some("Needs OAuth".into()),
     ///     output_schema: None,
     ///     render_hint: None,
+    ///     ..Default::default()
     /// };
     /// let spec = CommandSpec::new_mcp(
     ///     "mcp.demo".into(),
@@ -673,8 +678,11 @@ pub mod command {
     pub struct McpCommandSpec {
         /// Name of the plugin that owns the tool.
         pub plugin_name: String,
-        /// Identifier for the tool within the plugin.
+        /// Identifier for the tool within the plugin, or the prompt name for prompt commands.
         pub tool_name: String,
+        /// Which plugin capability the command invokes.
+        #[serde(default)]
+        pub kind: McpCommandKind,
         /// Optional summary describing authentication requirements.
         #[serde(default)]
         pub auth_summary: Option<String>,
@@ -683,6 +691,30 @@ pub mod command {
         /// Optional hint indicating how the UI should render results (for example, "results").
         #[serde(default)]
         pub render_hint: Option<String>,
+        /// Whether the plugin annotated the tool with `readOnlyHint`.
+        #[serde(default)]
+        pub read_only_hint: bool,
+    }
+
+    impl McpCommandSpec {
+        /// Whether the command has no side effects: resource reads and prompts never do, and
+        /// tools only when the plugin marked them with `readOnlyHint`.
+        pub fn is_read_only(&self) -> bool {
+            self.kind != McpCommandKind::Tool || self.read_only_hint
+        }
+    }
+
+    /// Plugin capability invoked by an MCP-backed command.
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+    #[serde(rename_all = "snake_case")]
+    pub enum McpCommandKind {
+        /// Call the tool named by `tool_name`.
+        #[default]
+        Tool,
+        /// Read the resource whose URI is passed as the `uri` argument.
+        ResourceRead,
+        /// Render the prompt named by `tool_name` with the remaining arguments.
+        Prompt,
     }
 
     /// Represents a single input field for a command parameter.
@@ -741,6 +773,13 @@ pub mod execution {
             /// Path of the persisted runtime workflow manifest.
             path: PathBuf,
         },
+        /// Result from saving a workflow manifest authored in the editor.
+        WorkflowSaved {
+            /// Identifier for the saved workflow.
+            workflow_id: String,
+            /// Path of the persisted runtime workflow manifest.
+            path: PathBuf,
+        },
         /// Result from removing a workflow manifest from runtime storage.
         WorkflowRemoved {
             /// Identifier for the removed workflow.
@@ -805,6 +844,13 @@ pub mod execution {
             /// Result of fetching detail information.
             result: Result<PluginDetail, String>,
         },
+        /// Audit trail rows loaded for the MCP server view, newest first.
+        McpAuditTrail {
+            /// Human-readable summary of the applied filters.
+            log_entry: String,
+            /// Flattened audit entries, or the error that prevented loading them.
+            result: Result<Value, String>,
+        },
         /// Result from refreshing the plugins.
         /// Contains a log message and the entire
         /// list of PluginDetail objects.
@@ -868,6 +914,8 @@ pub mod messaging {
         Palette,
         /// Browser view for inspecting command specifications.
         Browser,
+        /// Execution history view for revisiting past command runs.
+        History,
         /// Plugins view for managing MCP plugins.
         Plugins,
         /// Local MCP HTTP server view for discovery and execution.
@@ -880,6 +928,8 @@ pub mod messaging {
         WorkflowInputs,
         /// Workflow run view displaying live execution status.
         WorkflowRun,
+        /// Guided workflow authoring editor.
+        WorkflowEditor,
     }
 
     /// Modal overlays that can be displayed on top of the main UI.
@@ -887,10 +937,16 @@ pub mod messaging {
     pub enum Modal {
         /// File picker modal for selecting files.
         FilePicker(Vec<&'static str>),
+        /// File picker in save mode for exporting the open results view.
+        ExportResults,
+        /// File picker for loading a JSON file into the open JSON editor.
+        JsonEditorLoad,
+        /// File picker in save mode for writing the open JSON editor to a file.
+        JsonEditorSave,
         /// Help modal displaying shortcuts and usage tips.
         Help,
-        /// Results modal showing API responses in a results.
-        Results(Box<ExecOutcome>),
+        /// Tabbed results workspace; a provided outcome opens in a new tab.
+        Results(Option<Box<ExecOutcome>>),
         /// Log details modal revealing the full log entry.
         LogDetails,
         /// Guided Input Collector for resolving workflow inputs.
@@ -899,6 +955,10 @@ pub mod messaging {
         PluginDetails,
         /// Theme picker modal allowing runtime palette switching.
         ThemePicker,
+        /// Side-by-side structural diff of two JSON payloads.
+        Diff,
+        /// Multi-line JSON editor for an object or array flag value.
+        JsonEditor,
         /// Confirmation modal prompting the user to confirm an action.
         Confirmation,
         /// Manual entry modal for entering values.
@@ -980,6 +1040,8 @@ pub mod messaging {
         /// Request to run the current command in the palette
         /// with the hydrated command string and u64 hash of the request.
         Run { hydrated_command: String, request_hash: u64 },
+        /// Abort the command executions started with these request hashes.
+        CancelRequests(Vec<u64>),
         /// Request to copy the current command to the clipboard.
         CopyToClipboardRequested(String),
         /// Request to copy the current logs selection (already rendered/redacted).
@@ -990,6 +1052,10 @@ pub mod messaging {
         ReadRemoteFileContents(Url),
         /// List the contents of a directory.
         ListDirectoryContents(PathBuf),
+        /// Write the current results view to a file; the format follows the extension.
+        ExportResults(PathBuf),
+        /// Write the JSON editor contents to a file.
+        SaveJsonEditor(PathBuf),
         /// Parse a RegistryCatalog from the given contents
         ///  and optional command prefix override
         ImportRegistryCatalog(String, Option<String>),
@@ -1005,6 +1071,8 @@ pub mod messaging {
         PluginsStop(String),
         /// Restart the selected plugin.
         PluginsRestart(String),
+        /// Run the OAuth authorization flow for the named HTTP plugin.
+        PluginsAuthorize(String),
         /// Start the local MCP HTTP server.
         McpHttpServerStart,
         /// Stop the local MCP HTTP server.
@@ -1014,6 +1082,19 @@ pub mod messaging {
             /// Whether the server should auto-start with the TUI.
             auto_start: bool,
         },
+        /// Issue a bearer token for a new MCP HTTP server client and copy it to the clipboard.
+        McpHttpClientTokenCreate,
+        /// Revoke the bearer token held by the named MCP HTTP server client.
+        McpHttpClientTokenRevoke(String),
+        /// Load the audit trail in the background, using the `oatty audit query` filter syntax.
+        McpAuditTrailRequested {
+            /// Relative age (`24h`) or RFC 3339 timestamp.
+            since: Option<String>,
+            /// Catalog title or plugin name.
+            catalog: Option<String>,
+            /// Outcome name, HTTP status, or status class (`5xx`).
+            status: Option<String>,
+        },
         /// Export logs for a plugin to a default location (redacted).
         PluginsExportLogsDefault(String),
         /// Validate fields in the added plugin view.
@@ -1069,6 +1150,17 @@ pub mod messaging {
         RemoveCatalog(Cow<'static, str>),
         /// Remove a workflow manifest from runtime storage by identifier.
         RemoveWorkflow(Cow<'static, str>),
+        /// Persist a manifest authored in the workflow editor.
+        SaveWorkflowManifest {
+            /// Workflow identifier the manifest is stored under.
+            workflow_id: String,
+            /// Serialized manifest content.
+            content: String,
+            /// Manifest format (`yaml` or `json`).
+            format: String,
+            /// Manifest the draft was opened from, removed when the save lands at a different path.
+            previous_path: Option<PathBuf>,
+        },
     }
 
     /// Messages that can be sent to update the application state.
@@ -1438,7 +1530,6 @@ pub mod plugin {
         }
     }
 
-    /// Detailed information about a plugin.
     /// Summary information describing a tool exposed by a plugin.
     #[derive(Debug, Clone, Serialize, Deserialize, Default)]
     pub struct PluginToolSummary {
@@ -1455,6 +1546,72 @@ pub mod plugin {
         pub auth_summary: Option<String>,
     }
 
+    /// Summary information describing a resource exposed by a plugin.
+    #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+    pub struct PluginResourceSummary {
+        /// Resource URI.
+        pub uri: String,
+        /// Resource name supplied by the server.
+        pub name: String,
+        /// Optional human-friendly title.
+        #[serde(default)]
+        pub title: Option<String>,
+        /// Optional description of the resource contents.
+        #[serde(default)]
+        pub description: Option<String>,
+        /// MIME type of the contents, when known.
+        #[serde(default)]
+        pub mime_type: Option<String>,
+        /// When the server last reported a change to a subscribed resource.
+        #[serde(default)]
+        pub updated_at: Option<DateTime<Utc>>,
+    }
+
+    /// Summary information describing a parameterized resource exposed by a plugin.
+    #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+    pub struct PluginResourceTemplateSummary {
+        /// RFC 6570 URI template (for example, `postgres://db/tables/{table}`).
+        pub uri_template: String,
+        /// Template name supplied by the server.
+        pub name: String,
+        /// Optional description of the resources the template addresses.
+        #[serde(default)]
+        pub description: Option<String>,
+        /// MIME type of the contents, when known.
+        #[serde(default)]
+        pub mime_type: Option<String>,
+    }
+
+    /// Summary information describing a prompt template exposed by a plugin.
+    #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+    pub struct PluginPromptSummary {
+        /// Prompt name supplied by the server.
+        pub name: String,
+        /// Optional human-friendly title.
+        #[serde(default)]
+        pub title: Option<String>,
+        /// Optional description of what the prompt produces.
+        #[serde(default)]
+        pub description: Option<String>,
+        /// Arguments the prompt accepts.
+        #[serde(default)]
+        pub arguments: Vec<PluginPromptArgument>,
+    }
+
+    /// A single argument accepted by a plugin prompt.
+    #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+    pub struct PluginPromptArgument {
+        /// Argument name.
+        pub name: String,
+        /// Optional description shown as help text.
+        #[serde(default)]
+        pub description: Option<String>,
+        /// Whether the prompt requires the argument.
+        #[serde(default)]
+        pub required: bool,
+    }
+
+    /// Detailed information about a plugin.
     #[derive(Debug, Default, Clone, Serialize, Deserialize)]
     pub struct PluginDetail {
         /// Plugin name.
@@ -1483,11 +1640,23 @@ pub mod plugin {
         pub handshake_latency: Option<u64>,
         /// Authentication status for the plugin.
         pub auth_status: AuthStatus,
+        /// Configured authorization scheme (for example `oauth2`), if any.
+        #[serde(default)]
+        pub auth_scheme: Option<String>,
         /// Number of tools currently exposed by this plugin.
         pub tool_count: usize,
         /// Summaries for tools currently exposed by this plugin.
         #[serde(default)]
         pub tools: Vec<PluginToolSummary>,
+        /// Resources currently exposed by this plugin.
+        #[serde(default)]
+        pub resources: Vec<PluginResourceSummary>,
+        /// Resource templates currently exposed by this plugin.
+        #[serde(default)]
+        pub resource_templates: Vec<PluginResourceTemplateSummary>,
+        /// Prompt templates currently exposed by this plugin.
+        #[serde(default)]
+        pub prompts: Vec<PluginPromptSummary>,
     }
 
     impl PluginDetail {
@@ -14,7 +14,7 @@ use serde_json::{Map as JsonMap, Value as JsonValue};
 use std::{borrow::Cow, collections::HashMap};
 
 pub mod validation;
-pub use validation::validate_candidate_value;
+pub use validation::{coerce_input_value, validate_candidate_value, validate_input_value};
 
 /// Fully resolved workflow ready for runtime consumption.
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
@@ -34,6 +34,9 @@ pub struct RuntimeWorkflow {
     pub final_output: Option<JsonValue>,
     /// Optional external catalog requirements needed before execution.
     pub requires: Option<WorkflowRequirements>,
+    /// Optional recurring trigger executed by the local scheduler.
+    #[serde(default)]
+    pub schedule: Option<WorkflowSchedule>,
 }
 
 /// Describes a fully authored workflow, including metadata, inputs, and sequential steps.
@@ -60,6 +63,53 @@ pub struct WorkflowDefinition {
     /// Optional external catalog requirements needed before execution.
     #[serde(default)]
     pub requires: Option<WorkflowRequirements>,
+    /// Optional recurring trigger executed by `oatty scheduler run`.
+    #[serde(default)]
+    pub schedule: Option<WorkflowSchedule>,
+}
+
+/// Recurring trigger that runs a workflow unattended.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, JsonSchema)]
+pub struct WorkflowSchedule {
+    /// Five-field cron expression (`minute hour day-of-month month day-of-week`).
+    pub cron: String,
+    /// Time zone the cron expression is evaluated in.
+    #[serde(default)]
+    pub timezone: WorkflowScheduleTimezone,
+    /// Fixed input values supplied to every scheduled run.
+    #[serde(default = "default_value_map")]
+    pub inputs: IndexMap<String, JsonValue>,
+    /// Environment variables made available to every scheduled run.
+    #[serde(default)]
+    pub environment: IndexMap<String, String>,
+    /// Behavior when a run is due while the previous run is still executing.
+    #[serde(default)]
+    pub overlap: WorkflowScheduleOverlapPolicy,
+    /// Set to false to keep the schedule declared but inactive.
+    #[serde(default = "default_schedule_enabled")]
+    pub enabled: bool,
+}
+
+/// Time zones supported when evaluating schedule cron expressions.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowScheduleTimezone {
+    /// Coordinated Universal Time.
+    #[default]
+    Utc,
+    /// The local time zone of the machine running the scheduler.
+    Local,
+}
+
+/// Overlap handling for scheduled runs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowScheduleOverlapPolicy {
+    /// Drop the due run while a previous run is still executing.
+    #[default]
+    Skip,
+    /// Start the due run as soon as the previous run finishes.
+    Queue,
 }
 
 /// Optional workflow-level dependency requirements.
@@ -178,6 +228,9 @@ fn build_missing_catalog_reason(requirement: &WorkflowCatalogRequirement) -> Str
     format!("missing required catalog for vendor '{}'", requirement.vendor)
 }
 
+/// Placeholder rendered in place of values supplied for `secret: true` inputs.
+pub const SECRET_INPUT_MASK: &str = "••••••••";
+
 /// Defines metadata for a single workflow input, including provider bindings and validation.
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, JsonSchema)]
 pub struct WorkflowInputDefinition {
@@ -239,6 +292,9 @@ pub struct WorkflowInputDefinition {
     #[serde(rename = "enum")]
     #[serde(default)]
     pub enumerated_values: Vec<JsonValue>,
+    /// When true, the value is masked in UIs and never persisted to input history.
+    #[serde(default)]
+    pub secret: bool,
 }
 
 impl Default for WorkflowInputDefinition {
@@ -262,6 +318,7 @@ impl Default for WorkflowInputDefinition {
             hint: None,
             example: None,
             enumerated_values: Vec::new(),
+            secret: false,
         }
     }
 }
@@ -357,8 +414,56 @@ pub struct WorkflowJoinConfiguration {
     pub wrap_each: Option<String>,
 }
 
+/// Primitive value types supported by typed workflow inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkflowInputType {
+    /// Free-form text (the default when no type is declared).
+    String,
+    /// Whole numbers.
+    Integer,
+    /// Integers or floating point numbers.
+    Number,
+    /// `true` or `false`.
+    Boolean,
+    /// JSON arrays, optionally constrained by `validate.items`.
+    Array,
+    /// JSON objects, optionally constrained by `validate.properties`.
+    Object,
+}
+
+impl WorkflowInputType {
+    /// Parses an authored `type` value, accepting common aliases such as `float` and `bool`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "string" | "text" => Some(Self::String),
+            "integer" | "int" => Some(Self::Integer),
+            "number" | "float" | "double" => Some(Self::Number),
+            "boolean" | "bool" => Some(Self::Boolean),
+            "array" | "list" => Some(Self::Array),
+            "object" | "map" => Some(Self::Object),
+            _ => None,
+        }
+    }
+
+    /// Returns the canonical JSON Schema name of this type.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Integer => "integer",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Array => "array",
+            Self::Object => "object",
+        }
+    }
+}
+
 /// Declarative validation settings attached to an input.
-#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, JsonSchema)]
+///
+/// The fields follow a subset of JSON Schema: string rules (`pattern`, lengths, `format`), numeric
+/// bounds, array rules (`items`, item counts, uniqueness), and object rules (`properties`,
+/// `required_properties`, `additional_properties`).
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, JsonSchema, Default)]
 pub struct WorkflowInputValidation {
     /// Whether a value must be provided.
     #[serde(default)]
@@ -376,6 +481,53 @@ pub struct WorkflowInputValidation {
     /// Maximum length for string inputs, when specified.
     #[serde(default)]
     pub max_length: Option<usize>,
+    /// Named string format (`uri`, `email`, `date-time`, `date`, `uuid`, `hostname`, `ipv4`, `ipv6`).
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Inclusive lower bound for numeric inputs.
+    #[serde(default)]
+    pub minimum: Option<serde_json::Number>,
+    /// Inclusive upper bound for numeric inputs.
+    #[serde(default)]
+    pub maximum: Option<serde_json::Number>,
+    /// Exclusive lower bound for numeric inputs.
+    #[serde(default)]
+    pub exclusive_minimum: Option<serde_json::Number>,
+    /// Exclusive upper bound for numeric inputs.
+    #[serde(default)]
+    pub exclusive_maximum: Option<serde_json::Number>,
+    /// Minimum number of items for array inputs.
+    #[serde(default)]
+    pub min_items: Option<usize>,
+    /// Maximum number of items for array inputs.
+    #[serde(default)]
+    pub max_items: Option<usize>,
+    /// Whether array items must be distinct.
+    #[serde(default)]
+    pub unique_items: bool,
+    /// Schema applied to every item of an array input.
+    #[serde(default)]
+    pub items: Option<Box<WorkflowValueSchema>>,
+    /// Schemas applied to individual properties of an object input.
+    #[serde(default)]
+    pub properties: IndexMap<String, WorkflowValueSchema>,
+    /// Property names that must be present on object inputs.
+    #[serde(default)]
+    pub required_properties: Vec<String>,
+    /// When `false`, object inputs may not carry properties beyond `properties`.
+    #[serde(default)]
+    pub additional_properties: Option<bool>,
+}
+
+/// Nested schema describing array items or object properties of a typed input.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, JsonSchema, Default)]
+pub struct WorkflowValueSchema {
+    /// Declared type of the nested value.
+    #[serde(default)]
+    pub r#type: Option<String>,
+    /// Validation rules applied to the nested value.
+    #[serde(default, flatten)]
+    pub validate: WorkflowInputValidation,
 }
 
 /// Value assigned to a provider argument, either as a literal or as a structured binding.
@@ -671,6 +823,10 @@ fn default_value_map() -> IndexMap<String, JsonValue> {
     IndexMap::new()
 }
 
+fn default_schedule_enabled() -> bool {
+    true
+}
+
 impl WorkflowInputDefinition {
     /// Returns true when this input should not block readiness.
     ///
@@ -687,6 +843,30 @@ impl WorkflowInputDefinition {
         !self.optional
     }
 
+    /// Returns the declared value type, when it is one of the supported primitives.
+    pub fn value_type(&self) -> Option<WorkflowInputType> {
+        self.r#type.as_deref().and_then(WorkflowInputType::parse)
+    }
+
+    /// Coerces a raw value (for example a `--input KEY=VALUE` string) into the declared type.
+    pub fn coerce_value(&self, value: JsonValue) -> anyhow::Result<JsonValue> {
+        validation::coerce_input_value(value, self.value_type(), self.validate.as_ref())
+    }
+
+    /// Checks the candidate against the declared type and `validate` rules.
+    pub fn validate_value(&self, candidate: &JsonValue) -> anyhow::Result<()> {
+        validation::validate_input_value(candidate, self.value_type(), self.validate.as_ref())
+    }
+
+    /// Returns the value as it may be displayed or reported, masking secret inputs.
+    pub fn redact_value(&self, value: &JsonValue) -> JsonValue {
+        if self.secret && !value.is_null() {
+            JsonValue::String(SECRET_INPUT_MASK.to_string())
+        } else {
+            value.clone()
+        }
+    }
+
     /// Returns the preferred human-readable label for this input.
     ///
     /// When the author supplied an explicit `name`, that value is returned after trimming. If the
@@ -2,19 +2,127 @@
 //!
 //! These routines ensure that default values sourced from history, environment
 //! variables, or provider selections obey the declarative constraints supplied
-//! in the workflow definition.
+//! in the workflow definition. The same coercion and validation rules back the
+//! CLI `--input` flag, the TUI manual entry modal, and the MCP input tools.
+
+use std::collections::HashSet;
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 use anyhow::{Result, anyhow};
+use chrono::{DateTime, NaiveDate, NaiveTime};
 use regex::Regex;
-use serde_json::Value;
+use serde_json::{Map, Number, Value};
+
+use super::{WorkflowInputType, WorkflowInputValidation};
+
+/// Validate a candidate against the declared input type and validation rules.
+///
+/// Type checks run first so that authors receive a type mismatch message before any
+/// rule-specific error.
+pub fn validate_input_value(
+    candidate: &Value,
+    value_type: Option<WorkflowInputType>,
+    validation: Option<&WorkflowInputValidation>,
+) -> Result<()> {
+    if let Some(value_type) = value_type {
+        validate_value_type(candidate, value_type)?;
+    }
+    if let Some(validation) = validation {
+        validate_candidate_value(candidate, validation)?;
+    }
+    Ok(())
+}
+
+/// Coerce a raw value into the declared input type.
+///
+/// Text supplied for non-string inputs is parsed (`"42"` becomes `42`, `"yes"` becomes `true`,
+/// JSON literals become arrays or objects). Arrays also accept comma-separated text. Array items
+/// and object properties are coerced recursively when `validation` declares their types. Values
+/// that already have the right shape are returned unchanged.
+pub fn coerce_input_value(
+    value: Value,
+    value_type: Option<WorkflowInputType>,
+    validation: Option<&WorkflowInputValidation>,
+) -> Result<Value> {
+    let Some(value_type) = value_type else {
+        return Ok(value);
+    };
+
+    let coerced = match (value_type, value) {
+        (WorkflowInputType::Integer, Value::String(text)) => parse_integer(&text)?,
+        (WorkflowInputType::Number, Value::String(text)) => parse_number(&text)?,
+        (WorkflowInputType::Boolean, Value::String(text)) => Value::Bool(parse_boolean(&text)?),
+        (WorkflowInputType::Array, Value::String(text)) => parse_array(&text)?,
+        (WorkflowInputType::Object, Value::String(text)) => parse_object(&text)?,
+        (_, other) => other,
+    };
+
+    let Some(validation) = validation else {
+        return Ok(coerced);
+    };
+
+    match coerced {
+        Value::Array(items) => {
+            let Some(item_schema) = validation.items.as_deref() else {
+                return Ok(Value::Array(items));
+            };
+            let item_type = item_schema.r#type.as_deref().and_then(WorkflowInputType::parse);
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    coerce_input_value(item, item_type, Some(&item_schema.validate)).map_err(|error| anyhow!("item {}: {}", index, error))
+                })
+                .collect::<Result<Vec<Value>>>()
+                .map(Value::Array)
+        }
+        Value::Object(map) if !validation.properties.is_empty() => {
+            let mut coerced_map = Map::with_capacity(map.len());
+            for (key, property_value) in map {
+                let property_value = match validation.properties.get(&key) {
+                    Some(property_schema) => {
+                        let property_type = property_schema.r#type.as_deref().and_then(WorkflowInputType::parse);
+                        coerce_input_value(property_value, property_type, Some(&property_schema.validate))
+                            .map_err(|error| anyhow!("property '{}': {}", key, error))?
+                    }
+                    None => property_value,
+                };
+                coerced_map.insert(key, property_value);
+            }
+            Ok(Value::Object(coerced_map))
+        }
+        other => Ok(other),
+    }
+}
 
-use super::WorkflowInputValidation;
+/// Ensure the candidate has the JSON shape implied by the declared type.
+pub fn validate_value_type(candidate: &Value, value_type: WorkflowInputType) -> Result<()> {
+    let matches = match value_type {
+        WorkflowInputType::String => candidate.is_string(),
+        WorkflowInputType::Integer => is_integer(candidate),
+        WorkflowInputType::Number => candidate.is_number(),
+        WorkflowInputType::Boolean => candidate.is_boolean(),
+        WorkflowInputType::Array => candidate.is_array(),
+        WorkflowInputType::Object => candidate.is_object(),
+    };
+    if matches {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "expected {} value but received {}",
+            value_type.as_str(),
+            describe_kind(candidate)
+        ))
+    }
+}
 
 /// Validate a JSON candidate against the declarative workflow rules.
 ///
 /// The checks mirror the behaviour expected by the TUI and CLI:
 /// - Enumerations must include the candidate.
-/// - Patterns, minimum length, and maximum length only apply to strings.
+/// - Patterns, minimum length, maximum length, and formats only apply to strings.
+/// - Numeric bounds apply to numbers and to text that parses as a number.
+/// - Item rules apply to arrays and property rules apply to objects.
 /// - Non-string values are allowed when the validation metadata does not
 ///   specify string-specific requirements.
 pub fn validate_candidate_value(candidate: &Value, validation: &WorkflowInputValidation) -> Result<()> {
@@ -28,6 +136,10 @@ pub fn validate_candidate_value(candidate: &Value, validation: &WorkflowInputVal
         }
     }
 
+    if has_numeric_bounds(validation) {
+        validate_numeric_bounds(candidate, validation)?;
+    }
+
     match candidate {
         Value::String(text) => {
             if let Some(min_length) = validation.min_length
@@ -48,12 +160,23 @@ pub fn validate_candidate_value(candidate: &Value, validation: &WorkflowInputVal
                     return Err(anyhow!("value must match the pattern {}", pattern));
                 }
             }
+
+            if let Some(format) = &validation.format {
+                validate_format(text, format)?;
+            }
             Ok(())
         }
+        Value::Array(items) => {
+            reject_text_rules(validation)?;
+            validate_array(items, validation)
+        }
+        Value::Object(map) => {
+            reject_text_rules(validation)?;
+            validate_object(map, validation)
+        }
         other => {
-            if validation.pattern.is_some() || validation.min_length.is_some() || validation.max_length.is_some() {
-                Err(anyhow!("value must be text to satisfy validation rules"))
-            } else if validation.allowed_values.is_empty() || validation.allowed_values.iter().any(|item| item == other) {
+            reject_text_rules(validation)?;
+            if validation.allowed_values.is_empty() || validation.allowed_values.iter().any(|item| item == other) {
                 Ok(())
             } else {
                 Err(anyhow!("value is not in the allowed set"))
@@ -62,6 +185,229 @@ pub fn validate_candidate_value(candidate: &Value, validation: &WorkflowInputVal
     }
 }
 
+fn reject_text_rules(validation: &WorkflowInputValidation) -> Result<()> {
+    if validation.pattern.is_some() || validation.min_length.is_some() || validation.max_length.is_some() || validation.format.is_some() {
+        Err(anyhow!("value must be text to satisfy validation rules"))
+    } else {
+        Ok(())
+    }
+}
+
+fn has_numeric_bounds(validation: &WorkflowInputValidation) -> bool {
+    validation.minimum.is_some()
+        || validation.maximum.is_some()
+        || validation.exclusive_minimum.is_some()
+        || validation.exclusive_maximum.is_some()
+}
+
+fn validate_numeric_bounds(candidate: &Value, validation: &WorkflowInputValidation) -> Result<()> {
+    let numeric = match candidate {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| anyhow!("value must be numeric to satisfy validation rules"))?;
+
+    if let Some(minimum) = &validation.minimum
+        && numeric < number_as_f64(minimum)
+    {
+        return Err(anyhow!("value must be at least {}", minimum));
+    }
+    if let Some(maximum) = &validation.maximum
+        && numeric > number_as_f64(maximum)
+    {
+        return Err(anyhow!("value must be at most {}", maximum));
+    }
+    if let Some(exclusive_minimum) = &validation.exclusive_minimum
+        && numeric <= number_as_f64(exclusive_minimum)
+    {
+        return Err(anyhow!("value must be greater than {}", exclusive_minimum));
+    }
+    if let Some(exclusive_maximum) = &validation.exclusive_maximum
+        && numeric >= number_as_f64(exclusive_maximum)
+    {
+        return Err(anyhow!("value must be less than {}", exclusive_maximum));
+    }
+    Ok(())
+}
+
+fn validate_array(items: &[Value], validation: &WorkflowInputValidation) -> Result<()> {
+    if let Some(min_items) = validation.min_items
+        && items.len() < min_items
+    {
+        return Err(anyhow!("value must contain at least {} items", min_items));
+    }
+    if let Some(max_items) = validation.max_items
+        && items.len() > max_items
+    {
+        return Err(anyhow!("value must contain at most {} items", max_items));
+    }
+    if validation.unique_items {
+        let mut seen = HashSet::with_capacity(items.len());
+        if items.iter().any(|item| !seen.insert(item.to_string())) {
+            return Err(anyhow!("value must not contain duplicate items"));
+        }
+    }
+    if let Some(item_schema) = validation.items.as_deref() {
+        let item_type = item_schema.r#type.as_deref().and_then(WorkflowInputType::parse);
+        for (index, item) in items.iter().enumerate() {
+            validate_input_value(item, item_type, Some(&item_schema.validate)).map_err(|error| anyhow!("item {}: {}", index, error))?;
+        }
+    }
+    Ok(())
+}
+
+fn validate_object(map: &Map<String, Value>, validation: &WorkflowInputValidation) -> Result<()> {
+    if let Some(missing) = validation.required_properties.iter().find(|name| !map.contains_key(*name)) {
+        return Err(anyhow!("missing required property '{}'", missing));
+    }
+    if validation.additional_properties == Some(false)
+        && let Some(unexpected) = map.keys().find(|key| !validation.properties.contains_key(*key))
+    {
+        return Err(anyhow!("property '{}' is not allowed", unexpected));
+    }
+    for (name, property_schema) in &validation.properties {
+        let Some(property_value) = map.get(name) else {
+            continue;
+        };
+        let property_type = property_schema.r#type.as_deref().and_then(WorkflowInputType::parse);
+        validate_input_value(property_value, property_type, Some(&property_schema.validate))
+            .map_err(|error| anyhow!("property '{}': {}", name, error))?;
+    }
+    Ok(())
+}
+
+fn validate_format(text: &str, format: &str) -> Result<()> {
+    let valid = match format.to_ascii_lowercase().as_str() {
+        "uri" | "url" => url::Url::parse(text).is_ok(),
+        "email" => is_email(text),
+        "date-time" => DateTime::parse_from_rfc3339(text).is_ok(),
+        "date" => NaiveDate::parse_from_str(text, "%Y-%m-%d").is_ok(),
+        "time" => NaiveTime::parse_from_str(text, "%H:%M:%S%.f").is_ok(),
+        "uuid" => is_uuid(text),
+        "hostname" => is_hostname(text),
+        "ipv4" => text.parse::<Ipv4Addr>().is_ok(),
+        "ipv6" => text.parse::<Ipv6Addr>().is_ok(),
+        // Unknown formats are annotations only, matching JSON Schema semantics.
+        _ => true,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(anyhow!("value must be a valid {}", format))
+    }
+}
+
+fn is_email(text: &str) -> bool {
+    let Some((local, domain)) = text.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !text.chars().any(char::is_whitespace)
+}
+
+fn is_uuid(text: &str) -> bool {
+    let groups: Vec<&str> = text.split('-').collect();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip([8, 4, 4, 4, 12])
+            .all(|(group, length)| group.len() == length && group.chars().all(|character| character.is_ascii_hexdigit()))
+}
+
+fn is_hostname(text: &str) -> bool {
+    !text.is_empty()
+        && text.len() <= 253
+        && text.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|character| character.is_ascii_alphanumeric() || character == '-')
+        })
+}
+
+fn is_integer(candidate: &Value) -> bool {
+    match candidate {
+        Value::Number(number) => number.is_i64() || number.is_u64() || number.as_f64().is_some_and(|value| value.fract() == 0.0),
+        _ => false,
+    }
+}
+
+fn number_as_f64(number: &Number) -> f64 {
+    number.as_f64().unwrap_or_default()
+}
+
+fn describe_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn parse_integer(text: &str) -> Result<Value> {
+    let trimmed = text.trim();
+    if let Ok(parsed) = trimmed.parse::<i64>() {
+        return Ok(Value::Number(Number::from(parsed)));
+    }
+    trimmed
+        .parse::<u64>()
+        .map(|parsed| Value::Number(Number::from(parsed)))
+        .map_err(|_| anyhow!("'{}' is not a valid integer", trimmed))
+}
+
+fn parse_number(text: &str) -> Result<Value> {
+    let trimmed = text.trim();
+    if let Ok(integer) = parse_integer(trimmed) {
+        return Ok(integer);
+    }
+    let parsed: f64 = trimmed.parse().map_err(|_| anyhow!("'{}' is not a valid number", trimmed))?;
+    Number::from_f64(parsed)
+        .map(Value::Number)
+        .ok_or_else(|| anyhow!("'{}' is not representable in JSON", trimmed))
+}
+
+fn parse_boolean(text: &str) -> Result<bool> {
+    match text.trim().to_ascii_lowercase().as_str() {
+        "true" | "yes" | "y" | "1" | "on" => Ok(true),
+        "false" | "no" | "n" | "0" | "off" => Ok(false),
+        other => Err(anyhow!("'{}' is not a valid boolean", other)),
+    }
+}
+
+fn parse_array(text: &str) -> Result<Value> {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Ok(Value::Array(Vec::new()));
+    }
+    if trimmed.starts_with('[') {
+        return match serde_json::from_str::<Value>(trimmed) {
+            Ok(value @ Value::Array(_)) => Ok(value),
+            Ok(_) => Err(anyhow!("expected a JSON array")),
+            Err(error) => Err(anyhow!("invalid JSON array: {}", error)),
+        };
+    }
+    Ok(Value::Array(
+        trimmed.split(',').map(|item| Value::String(item.trim().to_string())).collect(),
+    ))
+}
+
+fn parse_object(text: &str) -> Result<Value> {
+    match serde_json::from_str::<Value>(text.trim()) {
+        Ok(value @ Value::Object(_)) => Ok(value),
+        Ok(_) => Err(anyhow!("expected a JSON object")),
+        Err(error) => Err(anyhow!("invalid JSON object: {}", error)),
+    }
+}
+
 fn json_values_match(expected: &Value, candidate: &Value) -> bool {
     if expected == candidate {
         return true;
@@ -84,14 +430,11 @@ fn json_values_match(expected: &Value, candidate: &Value) -> bool {
 mod tests {
     use super::*;
 
+    use crate::workflow::WorkflowValueSchema;
+    use serde_json::json;
+
     fn base_validation() -> WorkflowInputValidation {
-        WorkflowInputValidation {
-            required: false,
-            allowed_values: Vec::new(),
-            pattern: None,
-            min_length: None,
-            max_length: None,
-        }
+        WorkflowInputValidation::default()
     }
 
     #[test]
@@ -127,4 +470,124 @@ mod tests {
         validation.min_length = Some(2);
         assert!(validate_candidate_value(&Value::Number(serde_json::Number::from(12)), &validation).is_err());
     }
+
+    #[test]
+    fn numeric_bounds_apply_to_numbers_and_numeric_text() {
+        let mut validation = base_validation();
+        validation.minimum = Some(Number::from(1));
+        validation.exclusive_maximum = Some(Number::from(10));
+
+        assert!(validate_candidate_value(&json!(1), &validation).is_ok());
+        assert!(validate_candidate_value(&json!("9.5"), &validation).is_ok());
+        assert!(validate_candidate_value(&json!(0), &validation).is_err());
+        assert!(validate_candidate_value(&json!(10), &validation).is_err());
+        assert!(validate_candidate_value(&json!(true), &validation).is_err());
+    }
+
+    #[test]
+    fn string_formats_are_enforced() {
+        let mut validation = base_validation();
+        validation.format = Some("email".to_string());
+        assert!(validate_candidate_value(&json!("dev@example.com"), &validation).is_ok());
+        assert!(validate_candidate_value(&json!("not-an-email"), &validation).is_err());
+
+        validation.format = Some("date-time".to_string());
+        assert!(validate_candidate_value(&json!("2024-05-01T12:00:00Z"), &validation).is_ok());
+        assert!(validate_candidate_value(&json!("2024-05-01"), &validation).is_err());
+
+        validation.format = Some("uri".to_string());
+        assert!(validate_candidate_value(&json!("https://example.com/path"), &validation).is_ok());
+        assert!(validate_candidate_value(&json!("example"), &validation).is_err());
+    }
+
+    #[test]
+    fn array_items_and_counts_are_validated() {
+        let mut validation = base_validation();
+        validation.min_items = Some(1);
+        validation.unique_items = true;
+        validation.items = Some(Box::new(WorkflowValueSchema {
+            r#type: Some("integer".to_string()),
+            validate: WorkflowInputValidation {
+                minimum: Some(Number::from(0)),
+                ..Default::default()
+            },
+        }));
+
+        assert!(validate_input_value(&json!([1, 2]), Some(WorkflowInputType::Array), Some(&validation)).is_ok());
+        assert!(validate_input_value(&json!([]), Some(WorkflowInputType::Array), Some(&validation)).is_err());
+        assert!(validate_input_value(&json!([1, 1]), Some(WorkflowInputType::Array), Some(&validation)).is_err());
+        let error = validate_input_value(&json!([1, -2]), Some(WorkflowInputType::Array), Some(&validation)).unwrap_err();
+        assert!(error.to_string().starts_with("item 1:"));
+    }
+
+    #[test]
+    fn object_properties_are_validated() {
+        let mut validation = base_validation();
+        validation.required_properties = vec!["name".to_string()];
+        validation.additional_properties = Some(false);
+        validation.properties.insert(
+            "name".to_string(),
+            WorkflowValueSchema {
+                r#type: Some("string".to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert!(validate_input_value(&json!({"name": "api"}), Some(WorkflowInputType::Object), Some(&validation)).is_ok());
+        assert!(validate_input_value(&json!({}), Some(WorkflowInputType::Object), Some(&validation)).is_err());
+        assert!(validate_input_value(&json!({"name": 1}), Some(WorkflowInputType::Object), Some(&validation)).is_err());
+        assert!(
+            validate_input_value(
+                &json!({"name": "api", "extra": true}),
+                Some(WorkflowInputType::Object),
+                Some(&validation)
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn type_mismatch_is_reported() {
+        let error = validate_input_value(&json!("abc"), Some(WorkflowInputType::Integer), None).unwrap_err();
+        assert_eq!(error.to_string(), "expected integer value but received string");
+        assert!(validate_input_value(&json!(3.0), Some(WorkflowInputType::Integer), None).is_ok());
+    }
+
+    #[test]
+    fn coercion_parses_text_into_declared_types() {
+        assert_eq!(
+            coerce_input_value(json!("42"), Some(WorkflowInputType::Integer), None).unwrap(),
+            json!(42)
+        );
+        assert_eq!(
+            coerce_input_value(json!("1.5"), Some(WorkflowInputType::Number), None).unwrap(),
+            json!(1.5)
+        );
+        assert_eq!(
+            coerce_input_value(json!("yes"), Some(WorkflowInputType::Boolean), None).unwrap(),
+            json!(true)
+        );
+        assert_eq!(
+            coerce_input_value(json!("{\"a\":1}"), Some(WorkflowInputType::Object), None).unwrap(),
+            json!({"a": 1})
+        );
+        assert_eq!(coerce_input_value(json!("abc"), None, None).unwrap(), json!("abc"));
+        assert!(coerce_input_value(json!("abc"), Some(WorkflowInputType::Integer), None).is_err());
+    }
+
+    #[test]
+    fn array_coercion_splits_text_and_coerces_items() {
+        let validation = WorkflowInputValidation {
+            items: Some(Box::new(WorkflowValueSchema {
+                r#type: Some("integer".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        let coerced = coerce_input_value(json!("1, 2,3"), Some(WorkflowInputType::Array), Some(&validation)).unwrap();
+        assert_eq!(coerced, json!([1, 2, 3]));
+        let coerced = coerce_input_value(json!("[\"4\"]"), Some(WorkflowInputType::Array), Some(&validation)).unwrap();
+        assert_eq!(coerced, json!([4]));
+    }
 }
@@ -0,0 +1,398 @@
+//! Persisted journal of palette command executions.
+//!
+//! Each execution is appended as one JSON line so a crash mid-write loses at most the
+//! newest record. The file is compacted back down to the retention limit once it has
+//! grown to twice that size. Request bodies and responses are redacted before they are
+//! written, and responses are capped so one large listing cannot bloat the journal.
+
+use crate::history_store::{HistoryStoreError, expand_tilde_path};
+use crate::text_processing::{redact_json, redact_sensitive};
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use dirs_next::config_dir;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::warn;
+
+/// Environment variable controlling the execution journal location.
+pub const EXECUTION_JOURNAL_PATH_ENV: &str = "OATTY_EXECUTION_JOURNAL_PATH";
+
+/// Default filename for the persisted execution journal.
+pub const EXECUTION_JOURNAL_FILE_NAME: &str = "executions.jsonl";
+
+/// Maximum number of executions retained by the journal.
+pub const DEFAULT_EXECUTION_JOURNAL_LIMIT: usize = 200;
+
+/// Maximum serialized size of a stored response body, in bytes.
+pub const DEFAULT_RESPONSE_SIZE_CAP: usize = 64 * 1024;
+
+/// Outcome classification used for filtering.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionStatus {
+    /// The command completed and the service reported success.
+    Success,
+    /// The service returned an error status or the command could not run.
+    Failed,
+}
+
+impl ExecutionStatus {
+    /// Classify an HTTP status code.
+    pub fn from_http_status(status_code: u16) -> Self {
+        if (200..400).contains(&status_code) {
+            Self::Success
+        } else {
+            Self::Failed
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// The request as it was sent, after argument resolution.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionRequest {
+    /// HTTP method, or `MCP` for plugin tools.
+    pub method: String,
+    /// Absolute URL for HTTP commands, `plugin/tool` for MCP commands.
+    pub target: String,
+    /// Redacted request body or tool arguments.
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub body: Value,
+}
+
+/// A single journaled execution.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionRecord {
+    /// Palette input that started the execution, redacted.
+    pub command_line: String,
+    /// Canonical command identifier (`group name`).
+    pub command_id: String,
+    /// Catalog title or plugin name the command belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catalog: Option<String>,
+    /// Resolved request.
+    pub request: ExecutionRequest,
+    /// Outcome classification.
+    pub status: ExecutionStatus,
+    /// HTTP status code when the command is HTTP-backed and a response arrived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+    /// When the execution started.
+    #[serde(with = "ts_milliseconds")]
+    pub started_at: DateTime<Utc>,
+    /// Wall-clock duration in milliseconds.
+    pub duration_ms: u64,
+    /// One-line summary of the outcome, redacted.
+    pub summary: String,
+    /// Redacted response payload, possibly truncated.
+    #[serde(default)]
+    pub response: Value,
+    /// Whether `response` was cut down to fit the size cap.
+    #[serde(default)]
+    pub response_truncated: bool,
+}
+
+impl ExecutionRecord {
+    /// Redact the command line, request body and summary in place.
+    pub fn redacted(mut self) -> Self {
+        self.command_line = redact_sensitive(&self.command_line);
+        self.request.body = redact_json(&self.request.body);
+        self.summary = redact_sensitive(&self.summary);
+        self
+    }
+
+    /// Store a redacted copy of `response`, truncated to at most `size_cap` serialized bytes.
+    ///
+    /// Arrays keep as many leading items as fit so the stored payload still renders as a
+    /// table; any other oversized payload is stored as a truncated JSON string.
+    pub fn with_response(mut self, response: &Value, size_cap: usize) -> Self {
+        let redacted = redact_json(response);
+        let (response, truncated) = cap_response(redacted, size_cap);
+        self.response = response;
+        self.response_truncated = truncated;
+        self
+    }
+
+    /// Text matched by the History route search.
+    pub fn search_text(&self) -> String {
+        format!(
+            "{} {} {} {}",
+            self.command_line,
+            self.catalog.as_deref().unwrap_or_default(),
+            self.request.target,
+            self.summary
+        )
+    }
+}
+
+fn cap_response(response: Value, size_cap: usize) -> (Value, bool) {
+    let serialized_len = serde_json::to_string(&response).map(|text| text.len()).unwrap_or(0);
+    if serialized_len <= size_cap {
+        return (response, false);
+    }
+    match response {
+        Value::Array(items) => {
+            let mut kept = Vec::new();
+            let mut used = 2;
+            for item in items {
+                let item_len = serde_json::to_string(&item).map(|text| text.len()).unwrap_or(0) + 1;
+                if used + item_len > size_cap {
+                    break;
+                }
+                used += item_len;
+                kept.push(item);
+            }
+            (Value::Array(kept), true)
+        }
+        other => {
+            let text = serde_json::to_string(&other).unwrap_or_default();
+            let mut end = size_cap.min(text.len());
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+            (Value::String(text[..end].to_string()), true)
+        }
+    }
+}
+
+/// Shared trait implemented by execution journal backends.
+pub trait ExecutionJournal: Send + Sync {
+    /// Append a record, evicting the oldest once the retention limit is reached.
+    fn append(&self, record: ExecutionRecord) -> Result<(), HistoryStoreError>;
+
+    /// List records ordered from most recent to oldest.
+    fn records(&self) -> Result<Vec<ExecutionRecord>, HistoryStoreError>;
+}
+
+/// JSONL-backed execution journal persisted on disk.
+pub struct JsonlExecutionJournal {
+    path: PathBuf,
+    state: Mutex<JournalState>,
+    max_entries: usize,
+}
+
+#[derive(Default)]
+struct JournalState {
+    records: VecDeque<ExecutionRecord>,
+    lines_on_disk: usize,
+}
+
+impl JsonlExecutionJournal {
+    /// Open the journal at the provided path (or the default path when omitted).
+    pub fn new<P: Into<Option<PathBuf>>>(path: P, max_entries: usize) -> Result<Self, HistoryStoreError> {
+        let resolved_path = match path.into() {
+            Some(path) => expand_tilde_path(path),
+            None => default_execution_journal_path(),
+        };
+        let state = load_journal(&resolved_path, max_entries)?;
+        Ok(Self {
+            path: resolved_path,
+            state: Mutex::new(state),
+            max_entries,
+        })
+    }
+
+    /// Open the journal using the default settings.
+    pub fn with_defaults() -> Result<Self, HistoryStoreError> {
+        Self::new(None::<PathBuf>, DEFAULT_EXECUTION_JOURNAL_LIMIT)
+    }
+
+    /// Access the underlying journal path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn compact_locked(&self, state: &mut JournalState) -> Result<(), HistoryStoreError> {
+        let mut content = String::new();
+        for record in state.records.iter().rev() {
+            content.push_str(&serde_json::to_string(record)?);
+            content.push('\n');
+        }
+        fs::write(&self.path, content)?;
+        state.lines_on_disk = state.records.len();
+        Ok(())
+    }
+}
+
+impl ExecutionJournal for JsonlExecutionJournal {
+    fn append(&self, record: ExecutionRecord) -> Result<(), HistoryStoreError> {
+        let mut state = self.state.lock().expect("execution journal lock poisoned");
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let line = serde_json::to_string(&record)?;
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{line}")?;
+        state.lines_on_disk += 1;
+        state.records.push_front(record);
+        state.records.truncate(self.max_entries);
+        if state.lines_on_disk > self.max_entries.saturating_mul(2) {
+            self.compact_locked(&mut state)?;
+        }
+        Ok(())
+    }
+
+    fn records(&self) -> Result<Vec<ExecutionRecord>, HistoryStoreError> {
+        let state = self.state.lock().expect("execution journal lock poisoned");
+        Ok(state.records.iter().cloned().collect())
+    }
+}
+
+/// In-memory execution journal primarily used for unit testing.
+#[derive(Default)]
+pub struct InMemoryExecutionJournal {
+    records: Mutex<VecDeque<ExecutionRecord>>,
+}
+
+impl InMemoryExecutionJournal {
+    /// Create an empty in-memory journal.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ExecutionJournal for InMemoryExecutionJournal {
+    fn append(&self, record: ExecutionRecord) -> Result<(), HistoryStoreError> {
+        let mut records = self.records.lock().expect("execution journal lock poisoned");
+        records.push_front(record);
+        records.truncate(DEFAULT_EXECUTION_JOURNAL_LIMIT);
+        Ok(())
+    }
+
+    fn records(&self) -> Result<Vec<ExecutionRecord>, HistoryStoreError> {
+        let records = self.records.lock().expect("execution journal lock poisoned");
+        Ok(records.iter().cloned().collect())
+    }
+}
+
+fn default_execution_journal_path() -> PathBuf {
+    if let Ok(path) = env::var(EXECUTION_JOURNAL_PATH_ENV)
+        && !path.trim().is_empty()
+    {
+        return expand_tilde_path(PathBuf::from(path));
+    }
+
+    config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("oatty")
+        .join(EXECUTION_JOURNAL_FILE_NAME)
+}
+
+fn load_journal(path: &Path, max_entries: usize) -> Result<JournalState, HistoryStoreError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(JournalState::default()),
+        Err(error) => return Err(HistoryStoreError::Io(error)),
+    };
+    let mut state = JournalState::default();
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        state.lines_on_disk += 1;
+        match serde_json::from_str::<ExecutionRecord>(line) {
+            Ok(record) => state.records.push_front(record),
+            Err(error) => warn!("Skipping unreadable execution journal line in {}: {}", path.display(), error),
+        }
+    }
+    state.records.truncate(max_entries);
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    fn record(command_line: &str) -> ExecutionRecord {
+        ExecutionRecord {
+            command_line: command_line.to_string(),
+            command_id: "apps list".to_string(),
+            catalog: Some("Example".to_string()),
+            request: ExecutionRequest {
+                method: "GET".to_string(),
+                target: "https://api.example.com/apps".to_string(),
+                body: Value::Null,
+            },
+            status: ExecutionStatus::Success,
+            status_code: Some(200),
+            started_at: Utc::now(),
+            duration_ms: 12,
+            summary: "200 OK".to_string(),
+            response: Value::Null,
+            response_truncated: false,
+        }
+    }
+
+    #[test]
+    fn jsonl_journal_persists_most_recent_first() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("executions.jsonl");
+        let journal = JsonlExecutionJournal::new(Some(path.clone()), 10).unwrap();
+        journal.append(record("apps list")).unwrap();
+        journal.append(record("apps info app-1")).unwrap();
+
+        drop(journal);
+        let reloaded = JsonlExecutionJournal::new(Some(path), 10).unwrap();
+        let commands: Vec<String> = reloaded.records().unwrap().into_iter().map(|r| r.command_line).collect();
+        assert_eq!(commands, vec!["apps info app-1", "apps list"]);
+    }
+
+    #[test]
+    fn jsonl_journal_compacts_past_twice_the_limit() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("executions.jsonl");
+        let journal = JsonlExecutionJournal::new(Some(path.clone()), 2).unwrap();
+        for index in 0..5 {
+            journal.append(record(&format!("apps info app-{index}"))).unwrap();
+        }
+
+        assert_eq!(journal.records().unwrap().len(), 2);
+        let lines = fs::read_to_string(&path).unwrap().lines().count();
+        assert!(lines <= 4, "expected compaction, found {lines} lines");
+        let reloaded = JsonlExecutionJournal::new(Some(path), 2).unwrap();
+        assert_eq!(reloaded.records().unwrap()[0].command_line, "apps info app-4");
+    }
+
+    #[test]
+    fn unreadable_lines_are_skipped() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("executions.jsonl");
+        let valid = serde_json::to_string(&record("apps list")).unwrap();
+        fs::write(&path, format!("not json\n{valid}\n")).unwrap();
+
+        let journal = JsonlExecutionJournal::new(Some(path), 10).unwrap();
+        assert_eq!(journal.records().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn responses_are_redacted_and_capped() {
+        let items: Vec<Value> = (0..100).map(|index| json!({ "name": format!("app-{index}") })).collect();
+        let capped = record("apps list").with_response(&Value::Array(items), 200);
+        assert!(capped.response_truncated);
+        let kept = capped.response.as_array().unwrap();
+        assert!(!kept.is_empty() && kept.len() < 100);
+        assert!(serde_json::to_string(&capped.response).unwrap().len() <= 200);
+
+        let secret = record("apps list").with_response(&json!({ "token": "oatty_api_token=abc123def456ghi789" }), 1024);
+        assert!(!secret.response.to_string().contains("abc123def456ghi789"));
+    }
+
+    #[test]
+    fn default_path_honors_env_override() {
+        let override_path = "~/custom/executions.jsonl";
+        temp_env::with_var(EXECUTION_JOURNAL_PATH_ENV, Some(override_path), || {
+            assert_eq!(default_execution_journal_path(), expand_tilde_path(PathBuf::from(override_path)));
+        });
+    }
+}
@@ -286,7 +286,7 @@ impl HistoryStore for InMemoryHistoryStore {
     }
 }
 
-fn expand_tilde_path(path: PathBuf) -> PathBuf {
+pub(crate) fn expand_tilde_path(path: PathBuf) -> PathBuf {
     if let Some(first) = path.components().next()
         && first.as_os_str() != "~"
     {
@@ -349,11 +349,15 @@ pub fn value_contains_secret(value: &Value) -> bool {
 }
 
 /// Returns `true` when the workflow input definition declares a history-based default.
+///
+/// Inputs marked `secret: true` never participate in history, so their values are neither
+/// seeded from nor written to the history store.
 pub fn workflow_input_uses_history(definition: &WorkflowInputDefinition) -> bool {
-    matches!(
-        definition.default.as_ref().map(|default| &default.from),
-        Some(WorkflowDefaultSource::History)
-    )
+    !definition.secret
+        && matches!(
+            definition.default.as_ref().map(|default| &default.from),
+            Some(WorkflowDefaultSource::History)
+        )
 }
 
 #[cfg(test)]
@@ -467,6 +471,10 @@ mod tests {
 
         assert!(workflow_input_uses_history(&definition));
 
+        definition.secret = true;
+        assert!(!workflow_input_uses_history(&definition));
+        definition.secret = false;
+
         definition.default = Some(oatty_types::workflow::WorkflowInputDefault {
             from: WorkflowDefaultSource::Literal,
             value: None,
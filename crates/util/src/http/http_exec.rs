@@ -623,6 +623,7 @@ mod tests {
             default_value: None,
             description: None,
             provider: None,
+            schema: None,
         }
     }
 
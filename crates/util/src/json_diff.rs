@@ -0,0 +1,333 @@
+//! Structural diffing of JSON values.
+//!
+//! Produces a flat list of changes (added, removed, changed) addressed by
+//! their path from the document root. Objects are compared key by key, with
+//! nested containers recursed into. Arrays are aligned by index, or by an
+//! identifying field (such as `id`) when [`JsonDiffOptions::array_key`] is set
+//! and every element carries a unique scalar value for it.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+/// One step of a path into a JSON document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonPathSegment {
+    /// Object member name.
+    Key(String),
+    /// Array index.
+    Index(usize),
+    /// Array element matched by an identifying field, rendered as `[key=value]`.
+    Keyed { key: String, value: String },
+}
+
+/// How a value differs between the two documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonChangeKind {
+    /// Present only in the newer document.
+    Added,
+    /// Present only in the older document.
+    Removed,
+    /// Present in both with a different scalar value or type.
+    Changed,
+}
+
+impl JsonChangeKind {
+    /// Short lowercase label used in human-readable output.
+    pub fn label(self) -> &'static str {
+        match self {
+            JsonChangeKind::Added => "added",
+            JsonChangeKind::Removed => "removed",
+            JsonChangeKind::Changed => "changed",
+        }
+    }
+}
+
+/// A single difference between two JSON documents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonChange {
+    pub path: Vec<JsonPathSegment>,
+    pub kind: JsonChangeKind,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl JsonChange {
+    /// Machine-readable form: `{"path", "kind", "before", "after"}` with the path rendered as text.
+    pub fn to_json(&self) -> Value {
+        serde_json::json!({
+            "path": format_json_path(&self.path),
+            "kind": self.kind.label(),
+            "before": self.before,
+            "after": self.after,
+        })
+    }
+}
+
+impl fmt::Display for JsonChange {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = format_json_path(&self.path);
+        match (&self.before, &self.after) {
+            (Some(before), Some(after)) => write!(formatter, "~ {path}: {before} -> {after}"),
+            (None, Some(after)) => write!(formatter, "+ {path}: {after}"),
+            (Some(before), None) => write!(formatter, "- {path}: {before}"),
+            (None, None) => write!(formatter, "  {path}"),
+        }
+    }
+}
+
+/// Renders a path as `.key[0].nested`, or `.` for the document root.
+pub fn format_json_path(path: &[JsonPathSegment]) -> String {
+    if path.is_empty() {
+        return ".".to_string();
+    }
+    let mut output = String::new();
+    for segment in path {
+        match segment {
+            JsonPathSegment::Key(key) => {
+                output.push('.');
+                output.push_str(key);
+            }
+            JsonPathSegment::Index(index) => {
+                output.push_str(&format!("[{index}]"));
+            }
+            JsonPathSegment::Keyed { key, value } => {
+                output.push_str(&format!("[{key}={value}]"));
+            }
+        }
+    }
+    output
+}
+
+/// Options controlling how [`diff_json_with_options`] aligns arrays.
+#[derive(Debug, Clone, Default)]
+pub struct JsonDiffOptions {
+    /// Field used to match array elements across documents. Arrays whose
+    /// elements cannot all be matched by it fall back to index alignment.
+    pub array_key: Option<String>,
+}
+
+/// Computes the structural differences from `before` to `after`, aligning arrays by index.
+///
+/// Changes are reported in document order; object keys follow the iteration
+/// order of `before`, with keys only present in `after` appended.
+pub fn diff_json(before: &Value, after: &Value) -> Vec<JsonChange> {
+    diff_json_with_options(before, after, &JsonDiffOptions::default())
+}
+
+/// Computes the structural differences from `before` to `after` using `options`.
+///
+/// Key-aligned arrays report matched and removed elements in the order of
+/// `before`, followed by elements only present in `after`.
+pub fn diff_json_with_options(before: &Value, after: &Value, options: &JsonDiffOptions) -> Vec<JsonChange> {
+    let mut changes = Vec::new();
+    let mut path = Vec::new();
+    diff_into(before, after, options, &mut path, &mut changes);
+    changes
+}
+
+/// Returns `true` when every element of both arrays is an object with a unique
+/// scalar value under `key`, so the arrays can be aligned by it.
+pub fn can_align_by_key(before: &[Value], after: &[Value], key: &str) -> bool {
+    [before, after].iter().all(|items| {
+        let mut seen = HashSet::new();
+        items
+            .iter()
+            .all(|item| alignment_value(item, key).is_some_and(|value| seen.insert(value)))
+    })
+}
+
+/// Picks the first candidate key that can align the top-level arrays of both documents.
+pub fn find_alignment_key<I>(before: &Value, after: &Value, candidates: I) -> Option<String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let (Value::Array(before_items), Value::Array(after_items)) = (before, after) else {
+        return None;
+    };
+    if before_items.is_empty() && after_items.is_empty() {
+        return None;
+    }
+    candidates
+        .into_iter()
+        .find(|candidate| can_align_by_key(before_items, after_items, candidate))
+}
+
+/// Keys of `item` that look like element identifiers, best first: `id`, then
+/// `*_id` / `*Id`, `uuid`, `key`, `slug`, and `name`.
+pub fn id_like_keys(item: &Map<String, Value>) -> Vec<String> {
+    let rank = |key: &str| -> Option<u8> {
+        let lower = key.to_ascii_lowercase();
+        match lower.as_str() {
+            "id" => Some(0),
+            _ if lower.ends_with("_id") || key.ends_with("Id") => Some(1),
+            "uuid" => Some(2),
+            "key" => Some(3),
+            "slug" => Some(4),
+            "name" => Some(5),
+            _ => None,
+        }
+    };
+    let mut keys: Vec<(u8, &String)> = item.keys().filter_map(|key| rank(key).map(|score| (score, key))).collect();
+    keys.sort();
+    keys.into_iter().map(|(_, key)| key.clone()).collect()
+}
+
+fn alignment_value(item: &Value, key: &str) -> Option<String> {
+    match item.get(key)? {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
+
+fn diff_into(before: &Value, after: &Value, options: &JsonDiffOptions, path: &mut Vec<JsonPathSegment>, changes: &mut Vec<JsonChange>) {
+    match (before, after) {
+        (Value::Object(before_map), Value::Object(after_map)) => {
+            for (key, before_value) in before_map {
+                path.push(JsonPathSegment::Key(key.clone()));
+                match after_map.get(key) {
+                    Some(after_value) => diff_into(before_value, after_value, options, path, changes),
+                    None => changes.push(change(path, JsonChangeKind::Removed, Some(before_value), None)),
+                }
+                path.pop();
+            }
+            for (key, after_value) in after_map.iter().filter(|(key, _)| !before_map.contains_key(*key)) {
+                path.push(JsonPathSegment::Key(key.clone()));
+                changes.push(change(path, JsonChangeKind::Added, None, Some(after_value)));
+                path.pop();
+            }
+        }
+        (Value::Array(before_items), Value::Array(after_items))
+            if options
+                .array_key
+                .as_deref()
+                .is_some_and(|key| can_align_by_key(before_items, after_items, key)) =>
+        {
+            let key = options.array_key.as_deref().unwrap_or_default();
+            let after_by_value: HashMap<String, &Value> = after_items
+                .iter()
+                .filter_map(|item| alignment_value(item, key).map(|value| (value, item)))
+                .collect();
+            let mut matched = HashSet::new();
+            for before_item in before_items {
+                let value = alignment_value(before_item, key).unwrap_or_default();
+                path.push(JsonPathSegment::Keyed {
+                    key: key.to_string(),
+                    value: value.clone(),
+                });
+                match after_by_value.get(&value) {
+                    Some(after_item) => diff_into(before_item, after_item, options, path, changes),
+                    None => changes.push(change(path, JsonChangeKind::Removed, Some(before_item), None)),
+                }
+                matched.insert(value);
+                path.pop();
+            }
+            for after_item in after_items {
+                let value = alignment_value(after_item, key).unwrap_or_default();
+                if matched.contains(&value) {
+                    continue;
+                }
+                path.push(JsonPathSegment::Keyed {
+                    key: key.to_string(),
+                    value,
+                });
+                changes.push(change(path, JsonChangeKind::Added, None, Some(after_item)));
+                path.pop();
+            }
+        }
+        (Value::Array(before_items), Value::Array(after_items)) => {
+            for index in 0..before_items.len().max(after_items.len()) {
+                path.push(JsonPathSegment::Index(index));
+                match (before_items.get(index), after_items.get(index)) {
+                    (Some(before_item), Some(after_item)) => diff_into(before_item, after_item, options, path, changes),
+                    (Some(before_item), None) => changes.push(change(path, JsonChangeKind::Removed, Some(before_item), None)),
+                    (None, Some(after_item)) => changes.push(change(path, JsonChangeKind::Added, None, Some(after_item))),
+                    (None, None) => {}
+                }
+                path.pop();
+            }
+        }
+        _ if before != after => changes.push(change(path, JsonChangeKind::Changed, Some(before), Some(after))),
+        _ => {}
+    }
+}
+
+fn change(path: &[JsonPathSegment], kind: JsonChangeKind, before: Option<&Value>, after: Option<&Value>) -> JsonChange {
+    JsonChange {
+        path: path.to_vec(),
+        kind,
+        before: before.cloned(),
+        after: after.cloned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn identical_documents_have_no_changes() {
+        let value = json!({"id": 1, "tags": ["a", "b"], "nested": {"ok": true}});
+        assert!(diff_json(&value, &value).is_empty());
+    }
+
+    #[test]
+    fn reports_changed_added_and_removed_paths() {
+        let before = json!({"status": "pending", "items": [{"id": 1}, {"id": 2}], "old": true});
+        let after = json!({"status": "done", "items": [{"id": 1, "extra": "x"}], "new": 1});
+        let rendered: Vec<String> = diff_json(&before, &after).iter().map(ToString::to_string).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "+ .items[0].extra: \"x\"",
+                "- .items[1]: {\"id\":2}",
+                "- .old: true",
+                "~ .status: \"pending\" -> \"done\"",
+                "+ .new: 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn key_alignment_matches_reordered_elements() {
+        let before = json!([{"id": "a", "state": "up"}, {"id": "b", "state": "up"}]);
+        let after = json!([{"id": "c", "state": "up"}, {"id": "b", "state": "down"}, {"id": "a", "state": "up"}]);
+        let options = JsonDiffOptions {
+            array_key: Some("id".to_string()),
+        };
+        let rendered: Vec<String> = diff_json_with_options(&before, &after, &options)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            rendered,
+            vec!["~ [id=b].state: \"up\" -> \"down\"", "+ [id=c]: {\"id\":\"c\",\"state\":\"up\"}",]
+        );
+
+        // Duplicate key values fall back to index alignment.
+        let duplicated = json!([{"id": "a"}, {"id": "a"}]);
+        assert!(!can_align_by_key(duplicated.as_array().unwrap(), &[], "id"));
+    }
+
+    #[test]
+    fn alignment_key_prefers_id_like_candidates() {
+        let before = json!([{"name": "x", "app_id": 1, "id": 10}]);
+        let after = json!([{"name": "x", "app_id": 1, "id": 11}]);
+        let item = before[0].as_object().unwrap();
+        assert_eq!(id_like_keys(item), vec!["id", "app_id", "name"]);
+        assert_eq!(find_alignment_key(&before, &after, id_like_keys(item)), Some("id".to_string()));
+        assert_eq!(find_alignment_key(&json!({}), &after, id_like_keys(item)), None);
+    }
+
+    #[test]
+    fn type_changes_are_reported_at_the_container() {
+        let changes = diff_json(&json!({"value": [1]}), &json!({"value": {"a": 1}}));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, JsonChangeKind::Changed);
+        assert_eq!(format_json_path(&changes[0].path), ".value");
+    }
+}
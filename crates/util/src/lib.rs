@@ -29,8 +29,10 @@
 pub mod async_runtime;
 pub mod command_vec_utils;
 pub mod date_handling;
+pub mod execution_journal;
 pub mod history_store;
 pub mod http;
+pub mod json_diff;
 pub mod keystore;
 pub mod openapi_validation;
 pub mod path_processing;
@@ -42,8 +44,10 @@ pub mod text_processing;
 pub use async_runtime::*;
 pub use command_vec_utils::*;
 pub use date_handling::*;
+pub use execution_journal::*;
 pub use history_store::*;
 pub use http::*;
+pub use json_diff::*;
 pub use keystore::*;
 pub use openapi_validation::*;
 pub use path_processing::*;